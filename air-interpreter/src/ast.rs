@@ -15,6 +15,8 @@
  */
 
 use air::parser::parse;
+use air::parser::print;
+use air::parser::Instruction;
 
 /// Parse AIR script and return it as minified JSON
 pub fn ast(script: String) -> String {
@@ -28,3 +30,11 @@ pub fn ast(script: String) -> String {
         Err(err) => err.to_string(),
     }
 }
+
+/// Print AST given as JSON back to AIR script
+pub fn ast_to_air(ast: String) -> String {
+    match serde_json::from_str::<Box<Instruction<'_>>>(&ast) {
+        Ok(ast) => print(&ast),
+        Err(err) => err.to_string(),
+    }
+}
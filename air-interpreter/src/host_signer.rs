@@ -0,0 +1,47 @@
+/*
+ * Copyright 2023 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use air::interpreter_signatures::Signer;
+use marine_rs_sdk::marine;
+
+#[marine]
+#[link(wasm_import_module = "host")]
+extern "C" {
+    /// Signs a message with a secret key of a current peer, the key is held by a host.
+    fn sign(message: Vec<u8>) -> Vec<u8>;
+}
+
+/// Signs call results of a current peer through the host import,
+/// so a secret key never enters the interpreter.
+pub(crate) struct HostSigner {
+    peer_id: String,
+}
+
+impl HostSigner {
+    pub(crate) fn new(peer_id: String) -> Self {
+        Self { peer_id }
+    }
+}
+
+impl Signer for HostSigner {
+    fn peer_id(&self) -> String {
+        self.peer_id.clone()
+    }
+
+    fn sign(&self, message: &[u8]) -> Vec<u8> {
+        unsafe { sign(message.to_vec()) }
+    }
+}
@@ -27,11 +27,14 @@
 )]
 
 mod ast;
+mod host_signer;
 mod logger;
 
 use air::execute_air;
+use air::execute_air_with_signer;
 use air::InterpreterOutcome;
 use air::RunParameters;
+use host_signer::HostSigner;
 use marine_rs_sdk::marine;
 use marine_rs_sdk::module_manifest;
 
@@ -49,7 +52,7 @@ pub fn invoke(
     params: RunParameters,
     call_results: Vec<u8>,
 ) -> InterpreterOutcome {
-    execute_air(air, prev_data, data, params, call_results)
+    execute(air, prev_data, data, params, call_results)
 }
 
 #[marine]
@@ -63,10 +66,31 @@ pub fn invoke_tracing(
     tracing_output_mode: u8,
 ) -> InterpreterOutcome {
     logger::init_tracing(tracing_params, tracing_output_mode);
-    execute_air(air, prev_data, data, params, call_results)
+    execute(air, prev_data, data, params, call_results)
+}
+
+/// A host that requires signatures signs results of a current peer through its import.
+fn execute(
+    air: String,
+    prev_data: Vec<u8>,
+    data: Vec<u8>,
+    params: RunParameters,
+    call_results: Vec<u8>,
+) -> InterpreterOutcome {
+    if params.require_signatures {
+        let signer = HostSigner::new(params.current_peer_id.clone());
+        execute_air_with_signer(air, prev_data, data, params, call_results, Box::new(signer))
+    } else {
+        execute_air(air, prev_data, data, params, call_results)
+    }
 }
 
 #[marine]
 pub fn ast(script: String) -> String {
     ast::ast(script)
 }
+
+#[marine]
+pub fn ast_to_air(ast: String) -> String {
+    ast::ast_to_air(ast)
+}
@@ -74,3 +74,8 @@ pub fn invoke(
 pub fn ast(script: String) -> String {
     ast::ast(script)
 }
+
+#[wasm_bindgen]
+pub fn ast_to_air(ast: String) -> String {
+    ast::ast_to_air(ast)
+}
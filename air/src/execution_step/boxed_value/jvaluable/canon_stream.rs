@@ -51,7 +51,7 @@ impl JValuable for &CanonStream {
             }
             None => SecurityTetraplet::new(exec_ctx.run_parameters.current_peer_id.to_string(), "", "", ""),
         };
-        let tetraplet = populate_tetraplet_with_lambda(tetraplet, lambda);
+        let tetraplet = populate_tetraplet_with_lambda(tetraplet, lambda, self.len());
 
         Ok((select_result.result, tetraplet))
     }
@@ -46,7 +46,7 @@ impl JValuable for std::cell::Ref<'_, Vec<ValueAggregate>> {
         let tetraplet = match select_result.tetraplet_idx {
             Some(idx) => {
                 let tetraplet = &self[idx].tetraplet;
-                populate_tetraplet_with_lambda(tetraplet.as_ref().clone(), lambda)
+                populate_tetraplet_with_lambda(tetraplet.as_ref().clone(), lambda, self.len())
             }
             None => SecurityTetraplet::new(exec_ctx.run_parameters.current_peer_id.to_string(), "", "", ""),
         };
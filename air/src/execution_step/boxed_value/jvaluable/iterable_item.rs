@@ -56,7 +56,8 @@ impl<'ctx> JValuable for IterableItem<'ctx> {
         };
 
         let selected_value = select_by_lambda_from_scalar(jvalue, lambda, exec_ctx)?;
-        let tetraplet = populate_tetraplet_with_lambda(tetraplet.as_ref().clone(), lambda);
+        let source_len = jvalue.as_array().map_or(0, Vec::len);
+        let tetraplet = populate_tetraplet_with_lambda(tetraplet.as_ref().clone(), lambda, source_len);
 
         Ok((selected_value, tetraplet))
     }
@@ -40,7 +40,8 @@ impl JValuable for ValueAggregate {
         exec_ctx: &ExecutionCtx<'_>,
     ) -> ExecutionResult<(Cow<'_, JValue>, SecurityTetraplet)> {
         let selected_value = select_by_lambda_from_scalar(&self.result, lambda, exec_ctx)?;
-        let tetraplet = populate_tetraplet_with_lambda(self.tetraplet.as_ref().clone(), lambda);
+        let source_len = self.result.as_array().map_or(0, Vec::len);
+        let tetraplet = populate_tetraplet_with_lambda(self.tetraplet.as_ref().clone(), lambda, source_len);
 
         Ok((selected_value, tetraplet))
     }
@@ -63,7 +63,8 @@ impl JValuable for StreamJvaluableIngredients<'_> {
             }
             None => SecurityTetraplet::new(exec_ctx.run_parameters.current_peer_id.to_string(), "", "", ""),
         };
-        let tetraplet = populate_tetraplet_with_lambda(tetraplet, lambda);
+        let source_len = self.iter()?.count();
+        let tetraplet = populate_tetraplet_with_lambda(tetraplet, lambda, source_len);
 
         Ok((select_result.result, tetraplet))
     }
@@ -14,18 +14,45 @@
  * limitations under the License.
  */
 
+use air_lambda_ast::Functor;
 use air_lambda_ast::LambdaAST;
+use air_lambda_ast::ValueAccessor;
 use polyplets::SecurityTetraplet;
 
+/// Appends a lambda to the tetraplet json path, `source_len` is a length of an array
+/// or a stream the lambda is applied to. It's used to record `.last` as the index
+/// it's resolved to, since the last element changes as a stream grows.
 pub(crate) fn populate_tetraplet_with_lambda(
     mut tetraplet: SecurityTetraplet,
     lambda: &LambdaAST<'_>,
+    source_len: usize,
 ) -> SecurityTetraplet {
     match lambda {
-        LambdaAST::ValuePath(_) => {
-            tetraplet.add_lambda(&lambda.to_string());
+        // these lambdas select a part of a value, so the source tetraplet is kept
+        LambdaAST::ValuePath(value_path) => {
+            tetraplet.add_lambda(&to_json_path(value_path));
+            tetraplet
+        }
+        LambdaAST::Functor(Functor::Last) => {
+            let last_idx = ValueAccessor::ArrayAccess {
+                idx: source_len.saturating_sub(1) as u32,
+            };
+            tetraplet.add_lambda(&to_json_path(&[last_idx]));
             tetraplet
         }
         LambdaAST::Functor(_) => SecurityTetraplet::new("", "", "", lambda.to_string()),
     }
 }
+
+/// Lambda syntax differs from json path in slices only, they're written as `[start:end]`.
+fn to_json_path(value_path: &[ValueAccessor<'_>]) -> String {
+    let accessors = value_path
+        .iter()
+        .map(|accessor| match accessor {
+            ValueAccessor::ArraySlice { start, end } => format!("[{start}:{end}]"),
+            accessor => accessor.to_string(),
+        })
+        .collect::<Vec<_>>();
+
+    format!(".$.{}", accessors.join("."))
+}
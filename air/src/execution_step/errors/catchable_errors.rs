@@ -93,6 +93,14 @@ pub enum CatchableError {
         variable_name: String,
         actual_value: JValue,
     },
+
+    /// A stream map key is resolved to a value that is neither a string nor an integer.
+    #[error("stream map key could be only a string or an integer, but it's resolved to '{0}'")]
+    StreamMapKeyError(JValue),
+
+    /// Several entries of a canonicalized stream map have keys with the same string form.
+    #[error("several entries of the stream map have key '{0}'")]
+    StreamMapKeyCollision(String),
 }
 
 impl From<LambdaError> for Rc<CatchableError> {
@@ -20,6 +20,7 @@ use super::LastErrorAffectable;
 use super::UncatchableError;
 use crate::ToErrorCode;
 
+use air_interpreter_data::MergeConflict;
 use strum_macros::EnumDiscriminants;
 use strum_macros::EnumIter;
 use thiserror::Error as ThisError;
@@ -53,6 +54,16 @@ impl ExecutionError {
             _ => false,
         }
     }
+
+    /// Returns the place in traces where merging data failed, if it's the error reason.
+    pub fn merge_conflict(&self) -> Option<&MergeConflict> {
+        match self {
+            ExecutionError::Uncatchable(UncatchableError::TraceError { trace_error, .. }) => {
+                trace_error.merge_conflict()
+            }
+            _ => None,
+        }
+    }
 }
 
 impl From<CatchableError> for ExecutionError {
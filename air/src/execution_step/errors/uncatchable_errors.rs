@@ -20,6 +20,7 @@ use crate::ToErrorCode;
 
 use air_interpreter_cid::CidCalculationError;
 use air_interpreter_data::ValueRef;
+use air_interpreter_signatures::SignatureVerificationError;
 use air_trace_handler::GenerationCompatificationError;
 use air_trace_handler::TraceHandlerError;
 
@@ -92,6 +93,28 @@ pub enum UncatchableError {
 
     #[error("failed to deserialize to CallServiceFailed: {0}")]
     MalformedCallServiceFailed(serde_json::Error),
+
+    /// The interpreter executed more instructions than allowed by a host for one run.
+    #[error("instruction limit {limit} is exceeded")]
+    InstructionLimitExceeded { limit: u64 },
+
+    /// A call tries to emit a new call request after the particle deadline,
+    /// it's uncatchable, since a dead particle mustn't proceed to fallback branches.
+    #[error("particle is expired: its deadline is {deadline}, but current time is {current_time}")]
+    ParticleExpired { deadline: u64, current_time: u64 },
+
+    /// Supplied data contains a forged or missing signature of peer contributions,
+    /// such data can't be trusted at all, so it mustn't be handled by a xor instruction.
+    #[error("supplied data failed signature verification, {0}")]
+    SignatureVerificationFailed(SignatureVerificationError),
+
+    /// A call result from data is attributed to a peer other than the one the call was made on,
+    /// it means that a sender tries to sign a result on behalf of another peer.
+    #[error("call result is attributed to peer '{result_peer_id}', but the call was made on peer '{call_peer_id}'")]
+    CallResultAuthorMismatch {
+        call_peer_id: String,
+        result_peer_id: String,
+    },
 }
 
 impl ToErrorCode for UncatchableError {
@@ -20,6 +20,7 @@ use crate::JValue;
 use crate::UncatchableError;
 
 use air_interpreter_cid::CidCalculationError;
+use air_interpreter_cid::CidFormat;
 use air_interpreter_cid::CID;
 use air_interpreter_data::CanonCidAggregate;
 use air_interpreter_data::CidInfo;
@@ -61,14 +62,20 @@ impl ExecutionCidState {
         self.service_result_agg_tracker.record_value(service_result_agg)
     }
 
-    pub(crate) fn from_cid_info(prev_cid_info: CidInfo, current_cid_info: CidInfo) -> Self {
-        let value_tracker = CidTracker::from_cid_stores(prev_cid_info.value_store, current_cid_info.value_store);
-        let tetraplet_tracker =
-            CidTracker::from_cid_stores(prev_cid_info.tetraplet_store, current_cid_info.tetraplet_store);
-        let canon_tracker = CidTracker::from_cid_stores(prev_cid_info.canon_store, current_cid_info.canon_store);
+    pub(crate) fn from_cid_info(prev_cid_info: CidInfo, current_cid_info: CidInfo, cid_format: CidFormat) -> Self {
+        let value_tracker =
+            CidTracker::from_cid_stores(prev_cid_info.value_store, current_cid_info.value_store, cid_format);
+        let tetraplet_tracker = CidTracker::from_cid_stores(
+            prev_cid_info.tetraplet_store,
+            current_cid_info.tetraplet_store,
+            cid_format,
+        );
+        let canon_tracker =
+            CidTracker::from_cid_stores(prev_cid_info.canon_store, current_cid_info.canon_store, cid_format);
         let service_result_agg_tracker = CidTracker::from_cid_stores(
             prev_cid_info.service_result_store,
             current_cid_info.service_result_store,
+            cid_format,
         );
 
         Self {
@@ -19,12 +19,19 @@ use super::LastError;
 use super::LastErrorDescriptor;
 use super::Scalars;
 use super::Streams;
+use crate::execution_step::ExecutionObserver;
 
 use air_execution_info_collector::InstructionTracker;
+use air_interpreter_data::cid_format_for_version;
 use air_interpreter_data::CidInfo;
+use air_interpreter_data::DataFormat;
 use air_interpreter_data::GlobalStreamGens;
 use air_interpreter_data::RestrictedStreamGens;
 use air_interpreter_interface::*;
+use air_interpreter_signatures::SignatureStore;
+use air_interpreter_signatures::Signer;
+use air_parser::ast::Span;
+use air_parser::SourcePosition;
 
 use std::rc::Rc;
 
@@ -69,6 +76,24 @@ pub(crate) struct ExecutionCtx<'i> {
 
     /// CID-to-something trackers.
     pub(crate) cid_state: ExecutionCidState,
+
+    /// Format that will be used to serialize the result data.
+    pub(crate) data_format: DataFormat,
+
+    /// Signatures of peer contributions merged from prev and current data.
+    pub(crate) signature_store: SignatureStore,
+
+    /// Executed script, it's used to convert spans of failed instructions to lines and columns.
+    air_script: &'i str,
+
+    /// Span of the innermost instruction that raised an error which is being bubbled up.
+    error_span: Option<Span>,
+
+    /// Receives control before every instruction, set only by debuggers.
+    pub(crate) observer: Option<Box<dyn ExecutionObserver>>,
+
+    /// Signs call results of a current peer on behalf of a host, a secret key stays on its side.
+    pub(crate) signer: Option<Box<dyn Signer>>,
 }
 
 impl<'i> ExecutionCtx<'i> {
@@ -77,6 +102,8 @@ impl<'i> ExecutionCtx<'i> {
         current_ingredients: ExecCtxIngredients,
         call_results: CallResults,
         run_parameters: RunParameters,
+        data_format: DataFormat,
+        air_script: &'i str,
     ) -> Self {
         let run_parameters = RcRunParameters::from_run_parameters(run_parameters);
         let streams = Streams::from_data(
@@ -86,7 +113,12 @@ impl<'i> ExecutionCtx<'i> {
             current_ingredients.restricted_streams,
         );
 
-        let cid_state = ExecutionCidState::from_cid_info(prev_ingredients.cid_info, current_ingredients.cid_info);
+        // a particle keeps the CID format of its oldest data, so peers with older interpreters could still verify it
+        let data_version = std::cmp::min(&prev_ingredients.data_version, &current_ingredients.data_version);
+        let cid_format = cid_format_for_version(data_version);
+        let cid_state =
+            ExecutionCidState::from_cid_info(prev_ingredients.cid_info, current_ingredients.cid_info, cid_format);
+        let signature_store = SignatureStore::from_stores(prev_ingredients.signatures, current_ingredients.signatures);
 
         Self {
             run_parameters,
@@ -95,6 +127,9 @@ impl<'i> ExecutionCtx<'i> {
             call_results,
             streams,
             cid_state,
+            data_format,
+            signature_store,
+            air_script,
             ..<_>::default()
         }
     }
@@ -125,6 +160,28 @@ impl ExecutionCtx<'_> {
     pub(crate) fn flush_subgraph_completeness(&mut self) {
         self.subgraph_completeness = true;
     }
+
+    /// Remembers the span of a failed instruction, only the innermost one is kept
+    /// while an error is bubbling up.
+    pub(crate) fn meet_error(&mut self, span: Option<Span>) {
+        if self.error_span.is_none() {
+            self.error_span = span;
+        }
+    }
+
+    /// Forgets the span of an error caught by xor or par.
+    pub(crate) fn meet_caught_error(&mut self) {
+        self.error_span = None;
+    }
+
+    /// Position of the instruction that raised the error being bubbled up, if any.
+    pub(crate) fn error_position(&self) -> Option<SourcePosition> {
+        self.error_span.map(|span| self.source_position(span))
+    }
+
+    pub(crate) fn source_position(&self, span: Span) -> SourcePosition {
+        SourcePosition::from_air_pos(self.air_script, span.left)
+    }
 }
 
 /// Helper struct for ExecCtx construction.
@@ -134,6 +191,8 @@ pub(crate) struct ExecCtxIngredients {
     pub(crate) last_call_request_id: u32,
     pub(crate) restricted_streams: RestrictedStreamGens,
     pub(crate) cid_info: CidInfo,
+    pub(crate) signatures: SignatureStore,
+    pub(crate) data_version: semver::Version,
 }
 
 use serde::Deserialize;
@@ -148,6 +207,9 @@ pub(crate) struct RcRunParameters {
     pub(crate) current_peer_id: Rc<String>,
     pub(crate) timestamp: u64,
     pub(crate) ttl: u32,
+    pub(crate) instruction_limit: u64,
+    pub(crate) current_time: u64,
+    pub(crate) collect_stats: bool,
 }
 
 impl RcRunParameters {
@@ -157,8 +219,21 @@ impl RcRunParameters {
             current_peer_id: Rc::new(run_parameters.current_peer_id),
             timestamp: run_parameters.timestamp,
             ttl: run_parameters.ttl,
+            instruction_limit: run_parameters.instruction_limit,
+            current_time: run_parameters.current_time,
+            collect_stats: run_parameters.collect_stats,
         }
     }
+
+    /// Unix timestamp in milliseconds after which a particle is considered as expired.
+    pub(crate) fn particle_deadline(&self) -> u64 {
+        self.timestamp.saturating_add(self.ttl as u64)
+    }
+
+    /// A particle couldn't be considered as expired if a host didn't supply the current time.
+    pub(crate) fn is_particle_expired(&self) -> bool {
+        self.current_time != 0 && self.current_time > self.particle_deadline()
+    }
 }
 
 impl<'i> Display for ExecutionCtx<'i> {
@@ -18,6 +18,7 @@ use super::LastErrorObjectError;
 use crate::execution_step::RcSecurityTetraplet;
 use crate::JValue;
 
+use air_parser::SourcePosition;
 use serde::Deserialize;
 use serde::Serialize;
 
@@ -27,6 +28,7 @@ pub const ERROR_CODE_FIELD_NAME: &str = "error_code";
 pub const MESSAGE_FIELD_NAME: &str = "message";
 pub const INSTRUCTION_FIELD_NAME: &str = "instruction";
 pub const PEER_ID_FIELD_NAME: &str = "peer_id";
+pub const POSITION_FIELD_NAME: &str = "position";
 
 /// This struct is intended to track the last arisen error.
 /// LastError is essentially a scalar value with support of lambda expressions.
@@ -43,13 +45,26 @@ pub struct LastError {
     pub tetraplet: Option<RcSecurityTetraplet>,
 }
 
-pub(crate) fn error_from_raw_fields(error_code: i64, error_message: &str, instruction: &str, peer_id: &str) -> JValue {
-    serde_json::json!({
+/// Position is an object with line and column fields, it's omitted if the instruction position is unknown.
+pub(crate) fn error_from_raw_fields(
+    error_code: i64,
+    error_message: &str,
+    instruction: &str,
+    peer_id: &str,
+    position: Option<SourcePosition>,
+) -> JValue {
+    let mut error_object = serde_json::json!({
         ERROR_CODE_FIELD_NAME: error_code,
         MESSAGE_FIELD_NAME: error_message,
         INSTRUCTION_FIELD_NAME: instruction,
         PEER_ID_FIELD_NAME: peer_id,
-    })
+    });
+
+    if let Some(position) = position {
+        error_object[POSITION_FIELD_NAME] = serde_json::json!(position);
+    }
+
+    error_object
 }
 
 /// Checks that a scalar is a value of an object types that contains at least two fields:
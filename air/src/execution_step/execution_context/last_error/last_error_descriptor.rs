@@ -21,6 +21,8 @@ use crate::execution_step::RcSecurityTetraplet;
 use crate::JValue;
 use crate::ToErrorCode;
 
+use air_parser::SourcePosition;
+
 use std::rc::Rc;
 
 pub(crate) struct LastErrorDescriptor {
@@ -39,6 +41,7 @@ impl LastErrorDescriptor {
         instruction: &str,
         peer_id: &str,
         tetraplet: Option<RcSecurityTetraplet>,
+        position: Option<SourcePosition>,
     ) -> bool {
         // this check is optimization to prevent creation of an error object in case if error
         // couldn't be set
@@ -52,6 +55,7 @@ impl LastErrorDescriptor {
             instruction,
             peer_id,
             tetraplet,
+            position,
         )
     }
 
@@ -62,8 +66,9 @@ impl LastErrorDescriptor {
         instruction: &str,
         peer_id: &str,
         tetraplet: Option<RcSecurityTetraplet>,
+        position: Option<SourcePosition>,
     ) -> bool {
-        let error_object = error_from_raw_fields(error_code, error_message, instruction, peer_id);
+        let error_object = error_from_raw_fields(error_code, error_message, instruction, peer_id, position);
         self.set_from_error_object(Rc::new(error_object), tetraplet);
         true
     }
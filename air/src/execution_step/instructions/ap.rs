@@ -23,6 +23,9 @@ use super::TraceHandler;
 use crate::execution_step::boxed_value::Variable;
 use crate::execution_step::instructions::ValueAggregate;
 use crate::execution_step::resolver::apply_lambda;
+use crate::execution_step::CatchableError;
+use crate::execution_step::STREAM_MAP_KEY;
+use crate::execution_step::STREAM_MAP_VALUE;
 use crate::log_instruction;
 use crate::trace_to_exec_err;
 use crate::JValue;
@@ -30,8 +33,10 @@ use crate::SecurityTetraplet;
 use apply_to_arguments::*;
 use utils::*;
 
+use air_interpreter_data::StreamKind;
 use air_parser::ast;
 use air_parser::ast::Ap;
+use air_parser::ast::ApMap;
 use air_trace_handler::merger::MergerApResult;
 
 use std::rc::Rc;
@@ -40,6 +45,7 @@ impl<'i> super::ExecutableInstruction<'i> for Ap<'i> {
     #[tracing::instrument(level = "debug", skip(exec_ctx, trace_ctx))]
     fn execute(&self, exec_ctx: &mut ExecutionCtx<'i>, trace_ctx: &mut TraceHandler) -> ExecutionResult<()> {
         log_instruction!(call, exec_ctx, trace_ctx);
+        exec_ctx.tracker.meet_ap();
         let should_touch_trace = should_touch_trace(self);
         // this applying should be at the very beginning of this function,
         // because it's necessary to check argument lambda, for more details see
@@ -47,13 +53,41 @@ impl<'i> super::ExecutableInstruction<'i> for Ap<'i> {
         let result = apply_to_arg(&self.argument, exec_ctx, trace_ctx, should_touch_trace)?;
 
         let merger_ap_result = to_merger_ap_result(self, trace_ctx)?;
+        track_executed_ap(&merger_ap_result, exec_ctx);
         let maybe_generation = populate_context(&self.result, &merger_ap_result, result, exec_ctx)?;
-        maybe_update_trace(maybe_generation, trace_ctx);
+        maybe_update_trace(maybe_generation, StreamKind::Stream, trace_ctx);
 
         Ok(())
     }
 }
 
+impl<'i> super::ExecutableInstruction<'i> for ApMap<'i> {
+    #[tracing::instrument(level = "debug", skip(exec_ctx, trace_ctx))]
+    fn execute(&self, exec_ctx: &mut ExecutionCtx<'i>, trace_ctx: &mut TraceHandler) -> ExecutionResult<()> {
+        log_instruction!(ap, exec_ctx, trace_ctx);
+        exec_ctx.tracker.meet_ap();
+        // the same as for ap, key and value should be resolved before touching the trace
+        let key = resolve_stream_map_key(&self.key, exec_ctx)?;
+        let value = apply_to_arg(&self.value, exec_ctx, trace_ctx, true)?;
+        let entry = to_stream_map_entry(key, value);
+
+        let merger_ap_result = trace_to_exec_err!(trace_ctx.meet_ap_start(StreamKind::StreamMap), self)?;
+        track_executed_ap(&merger_ap_result, exec_ctx);
+        let value_descriptor = generate_value_descriptor(entry, self.map.name, self.map.position, &merger_ap_result);
+        let generation = exec_ctx.streams.add_stream_value(value_descriptor)?;
+        maybe_update_trace(Some(generation), StreamKind::StreamMap, trace_ctx);
+
+        Ok(())
+    }
+}
+
+/// An ap without a state in incoming traces is executed by the current peer.
+fn track_executed_ap(merger_ap_result: &MergerApResult, exec_ctx: &mut ExecutionCtx<'_>) {
+    if matches!(merger_ap_result, MergerApResult::NotMet) {
+        exec_ctx.tracker.meet_executed_ap();
+    }
+}
+
 /// This function is intended to check whether a Ap instruction should produce
 /// a new state in data.
 fn should_touch_trace(ap: &Ap<'_>) -> bool {
@@ -64,7 +98,7 @@ fn to_merger_ap_result(instr: &Ap<'_>, trace_ctx: &mut TraceHandler) -> Executio
     match instr.result {
         ast::ApResult::Scalar(_) => Ok(MergerApResult::NotMet),
         ast::ApResult::Stream(_) => {
-            let merger_ap_result = trace_to_exec_err!(trace_ctx.meet_ap_start(), instr)?;
+            let merger_ap_result = trace_to_exec_err!(trace_ctx.meet_ap_start(StreamKind::Stream), instr)?;
             Ok(merger_ap_result)
         }
     }
@@ -79,17 +113,51 @@ fn populate_context<'ctx>(
     match ap_result {
         ast::ApResult::Scalar(scalar) => exec_ctx.scalars.set_scalar_value(scalar.name, result).map(|_| None),
         ast::ApResult::Stream(stream) => {
-            let value_descriptor = generate_value_descriptor(result, stream, merger_ap_result);
+            let value_descriptor = generate_value_descriptor(result, stream.name, stream.position, merger_ap_result);
             exec_ctx.streams.add_stream_value(value_descriptor).map(Some)
         }
     }
 }
 
-fn maybe_update_trace(maybe_generation: Option<u32>, trace_ctx: &mut TraceHandler) {
+fn maybe_update_trace(maybe_generation: Option<u32>, kind: StreamKind, trace_ctx: &mut TraceHandler) {
     use air_interpreter_data::ApResult;
 
     if let Some(generation) = maybe_generation {
-        let final_ap_result = ApResult::new(generation);
+        let final_ap_result = ApResult::with_kind(generation, kind);
         trace_ctx.meet_ap_end(final_ap_result);
     }
 }
+
+/// Only strings and integers are allowed to be keys, so that a canonicalized stream map
+/// could be represented as a JSON object.
+fn resolve_stream_map_key<'i>(
+    key: &ast::StreamMapKeyClause<'i>,
+    exec_ctx: &ExecutionCtx<'i>,
+) -> ExecutionResult<JValue> {
+    use crate::execution_step::resolver;
+    use ast::StreamMapKeyClause::*;
+
+    let (key, _) = match key {
+        Literal(value) => return Ok(JValue::from(value.as_ref())),
+        Int(value) => return Ok(JValue::from(*value)),
+        Scalar(scalar) => resolver::resolve_ast_scalar(scalar, exec_ctx)?,
+        ScalarWithLambda(scalar) => resolver::resolve_ast_scalar_wl(scalar, exec_ctx)?,
+        CanonStreamWithLambda(canon_stream) => resolver::resolve_ast_canon_wl(canon_stream, exec_ctx)?,
+    };
+
+    match &key {
+        JValue::String(_) => Ok(key),
+        JValue::Number(number) if number.is_i64() || number.is_u64() => Ok(key),
+        _ => Err(CatchableError::StreamMapKeyError(key).into()),
+    }
+}
+
+/// A stream map is stored as an ordinary stream of key-value entries, that allows to reuse
+/// the stream machinery for merging and iterating over it.
+fn to_stream_map_entry(key: JValue, value: ValueAggregate) -> ValueAggregate {
+    let mut entry = serde_json::Map::new();
+    entry.insert(STREAM_MAP_KEY.to_string(), key);
+    entry.insert(STREAM_MAP_VALUE.to_string(), value.result.as_ref().clone());
+
+    ValueAggregate::new(Rc::new(JValue::Object(entry)), value.tetraplet, value.trace_pos)
+}
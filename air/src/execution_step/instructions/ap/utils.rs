@@ -18,12 +18,13 @@ use crate::execution_step::execution_context::StreamValueDescriptor;
 use crate::execution_step::Generation;
 use crate::execution_step::ValueAggregate;
 
-use air_parser::ast;
+use air_parser::AirPos;
 use air_trace_handler::merger::MergerApResult;
 
 pub(super) fn generate_value_descriptor<'stream>(
     value: ValueAggregate,
-    stream: &'stream ast::Stream<'_>,
+    stream_name: &'stream str,
+    stream_position: AirPos,
     ap_result: &MergerApResult,
 ) -> StreamValueDescriptor<'stream> {
     use air_trace_handler::merger::ValueSource;
@@ -31,17 +32,17 @@ pub(super) fn generate_value_descriptor<'stream>(
     match ap_result {
         MergerApResult::NotMet => StreamValueDescriptor::new(
             value,
-            stream.name,
+            stream_name,
             ValueSource::PreviousData,
             Generation::Last,
-            stream.position,
+            stream_position,
         ),
         MergerApResult::Met(met_result) => StreamValueDescriptor::new(
             value,
-            stream.name,
+            stream_name,
             met_result.value_source,
             Generation::Nth(met_result.generation),
-            stream.position,
+            stream_position,
         ),
     }
 }
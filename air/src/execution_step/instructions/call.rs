@@ -55,6 +55,8 @@ fn set_last_error<'i>(
     execution_error: ExecutionError,
     tetraplet: Option<RcSecurityTetraplet>,
 ) -> ExecutionError {
+    exec_ctx.meet_error(Some(call.span));
+
     let catchable_error = match execution_error {
         ExecutionError::Catchable(catchable) => catchable,
         ExecutionError::Uncatchable(_) => return execution_error,
@@ -74,11 +76,13 @@ fn set_last_error<'i>(
         current_peer_id
     );
 
+    let position = exec_ctx.error_position();
     let _ = exec_ctx.last_error_descriptor.try_to_set_from_error(
         catchable_error.as_ref(),
         &call.to_string(),
         &current_peer_id,
         tetraplet,
+        position,
     );
     ExecutionError::Catchable(catchable_error)
 }
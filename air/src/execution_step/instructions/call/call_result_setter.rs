@@ -20,7 +20,7 @@ use crate::execution_step::Generation;
 use crate::execution_step::ValueAggregate;
 use crate::UncatchableError;
 
-use air_interpreter_cid::value_to_json_cid;
+use air_interpreter_cid::SerializationFormat;
 use air_interpreter_data::CallResult;
 use air_interpreter_data::TracePos;
 use air_interpreter_data::ValueRef;
@@ -62,7 +62,11 @@ pub(crate) fn populate_context_from_peer_service_result<'i>(
             Ok(CallResult::executed_stream(service_result_agg_cid, generation))
         }
         CallOutputValue::None => {
-            let value_cid = value_to_json_cid(&*executed_result.result)
+            let value_cid = exec_ctx
+                .cid_state
+                .value_tracker
+                .format()
+                .value_to_cid(&*executed_result.result)
                 .map_err(UncatchableError::from)?
                 .into();
 
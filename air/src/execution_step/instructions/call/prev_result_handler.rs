@@ -20,9 +20,12 @@ use crate::execution_step::CatchableError;
 use crate::execution_step::RcSecurityTetraplet;
 use crate::UncatchableError;
 
+use air_interpreter_cid::CID;
 use air_interpreter_data::CallResult;
 use air_interpreter_data::CallServiceFailed;
 use air_interpreter_data::Sender;
+use air_interpreter_data::ServiceResultAggregate;
+use air_interpreter_data::ValueRef;
 use air_interpreter_interface::CallServiceResult;
 use air_parser::ast::CallOutputValue;
 use air_trace_handler::merger::MetCallResult;
@@ -57,6 +60,7 @@ pub(super) fn handle_prev_state<'i>(
                 .cid_state
                 .resolve_service_value(failed_cid)
                 .map_err(UncatchableError::from)?;
+            check_result_author(failed_cid, tetraplet, exec_ctx)?;
             let call_service_failed: CallServiceFailed =
                 serde_json::from_value((*err_value).clone()).map_err(UncatchableError::MalformedCallServiceFailed)?;
 
@@ -80,6 +84,7 @@ pub(super) fn handle_prev_state<'i>(
                         exec_ctx,
                         trace_ctx,
                     )?;
+                    exec_ctx.tracker.meet_executed_call();
                     Ok(StateDescriptor::executed())
                 }
                 // result hasn't been prepared yet
@@ -109,6 +114,9 @@ pub(super) fn handle_prev_state<'i>(
                 output,
                 exec_ctx,
             )?;
+            if let ValueRef::Scalar(cid) | ValueRef::Stream { cid, .. } = &resulted_value {
+                check_result_author(cid, tetraplet, exec_ctx)?;
+            }
             let call_result = CallResult::Executed(resulted_value);
             trace_ctx.meet_call_end(call_result);
 
@@ -117,6 +125,27 @@ pub(super) fn handle_prev_state<'i>(
     }
 }
 
+/// Signatures are verified against peers from stored tetraplets, so a stored tetraplet must
+/// name the same peer as the call, otherwise a result could be signed on behalf of another peer.
+fn check_result_author(
+    cid: &CID<ServiceResultAggregate>,
+    tetraplet: &RcSecurityTetraplet,
+    exec_ctx: &ExecutionCtx<'_>,
+) -> ExecutionResult<()> {
+    let service_result = exec_ctx.cid_state.get_service_result_agg_by_cid(cid)?;
+    let result_tetraplet = exec_ctx.cid_state.get_tetraplet_by_cid(&service_result.tetraplet_cid)?;
+
+    if result_tetraplet.peer_pk != tetraplet.peer_pk {
+        return Err(UncatchableError::CallResultAuthorMismatch {
+            call_peer_id: tetraplet.peer_pk.clone(),
+            result_peer_id: result_tetraplet.peer_pk.clone(),
+        }
+        .into());
+    }
+
+    Ok(())
+}
+
 use super::call_result_setter::*;
 use crate::execution_step::ValueAggregate;
 use crate::JValue;
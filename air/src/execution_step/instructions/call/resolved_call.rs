@@ -27,7 +27,7 @@ use crate::trace_to_exec_err;
 use crate::JValue;
 use crate::SecurityTetraplet;
 
-use air_interpreter_cid::value_to_json_cid;
+use air_interpreter_cid::SerializationFormat;
 use air_interpreter_data::CallResult;
 use air_interpreter_interface::CallRequestParams;
 use air_parser::ast;
@@ -101,9 +101,11 @@ impl<'i> ResolvedCall<'i> {
             CheckArgsResult::Ok(args) => Some(args),
             CheckArgsResult::Joinable(_) => None,
         };
+        let cid_format = exec_ctx.cid_state.value_tracker.format();
         let argument_hash: Option<Rc<str>> = checked_args.map(|args| {
-            value_to_json_cid(&args)
-                .expect("JSON serializer shouldn't fail")
+            cid_format
+                .value_to_cid(&args)
+                .expect("default serializer shouldn't fail")
                 .into_inner()
                 .into()
         });
@@ -136,6 +138,16 @@ impl<'i> ResolvedCall<'i> {
             }
         };
 
+        // services shouldn't be called on behalf of a dead particle
+        let run_parameters = &exec_ctx.run_parameters;
+        if run_parameters.is_particle_expired() {
+            return Err(UncatchableError::ParticleExpired {
+                deadline: run_parameters.particle_deadline(),
+                current_time: run_parameters.current_time,
+            }
+            .into());
+        }
+
         let call_id = exec_ctx.next_call_request_id();
 
         exec_ctx.call_requests.insert(call_id, request_params);
@@ -18,15 +18,22 @@ use super::ExecutionCtx;
 use super::ExecutionResult;
 use super::TraceHandler;
 use crate::execution_step::boxed_value::CanonStream;
+use crate::execution_step::CatchableError;
 use crate::execution_step::Stream;
+use crate::execution_step::ValueAggregate;
+use crate::execution_step::STREAM_MAP_KEY;
+use crate::execution_step::STREAM_MAP_VALUE;
 use crate::log_instruction;
 use crate::trace_to_exec_err;
+use crate::JValue;
 use crate::UncatchableError;
 
 use air_interpreter_cid::CID;
 use air_interpreter_data::CanonCidAggregate;
 use air_interpreter_data::CanonResult;
+use air_interpreter_data::StreamKind;
 use air_parser::ast;
+use air_parser::AirPos;
 use air_trace_handler::merger::MergerCanonResult;
 use polyplets::SecurityTetraplet;
 
@@ -37,24 +44,58 @@ impl<'i> super::ExecutableInstruction<'i> for ast::Canon<'i> {
     #[tracing::instrument(level = "debug", skip(exec_ctx, trace_ctx))]
     fn execute(&self, exec_ctx: &mut ExecutionCtx<'i>, trace_ctx: &mut TraceHandler) -> ExecutionResult<()> {
         log_instruction!(call, exec_ctx, trace_ctx);
-        let canon_result = trace_to_exec_err!(trace_ctx.meet_canon_start(), self)?;
+        let canon_result = trace_to_exec_err!(trace_ctx.meet_canon_start(StreamKind::Stream), self)?;
 
-        match canon_result {
-            MergerCanonResult::CanonResult { tetraplet, values } => {
-                handle_seen_canon(self, tetraplet, values, exec_ctx, trace_ctx)
-            }
-            MergerCanonResult::Empty => handle_unseen_canon(self, exec_ctx, trace_ctx),
+        let stream = &self.stream;
+        match canonicalize(canon_result, &self.peer_id, stream.name, stream.position, exec_ctx)? {
+            Some(stream_with_se) => epilog(self.canon_stream.name, stream_with_se, exec_ctx, trace_ctx),
+            None => Ok(()),
         }
     }
 }
 
+impl<'i> super::ExecutableInstruction<'i> for ast::CanonMap<'i> {
+    #[tracing::instrument(level = "debug", skip(exec_ctx, trace_ctx))]
+    fn execute(&self, exec_ctx: &mut ExecutionCtx<'i>, trace_ctx: &mut TraceHandler) -> ExecutionResult<()> {
+        log_instruction!(canon, exec_ctx, trace_ctx);
+        let canon_result = trace_to_exec_err!(trace_ctx.meet_canon_start(StreamKind::StreamMap), self)?;
+
+        // stream map is kept as a stream of entries, so it's canonicalized the same way
+        let stream_map = &self.stream_map;
+        match canonicalize(
+            canon_result,
+            &self.peer_id,
+            stream_map.name,
+            stream_map.position,
+            exec_ctx,
+        )? {
+            Some(stream_with_se) => map_epilog(self.scalar.name, stream_with_se, exec_ctx, trace_ctx),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Returns None if canonicalization should be performed on another peer.
+fn canonicalize(
+    canon_result: MergerCanonResult,
+    peer_id: &ast::ResolvableToPeerIdVariable<'_>,
+    stream_name: &str,
+    stream_position: AirPos,
+    exec_ctx: &mut ExecutionCtx<'_>,
+) -> ExecutionResult<Option<StreamWithSerializedView>> {
+    match canon_result {
+        MergerCanonResult::CanonResult { tetraplet, values } => {
+            handle_seen_canon(tetraplet, values, exec_ctx).map(Some)
+        }
+        MergerCanonResult::Empty => handle_unseen_canon(peer_id, stream_name, stream_position, exec_ctx),
+    }
+}
+
 fn handle_seen_canon(
-    ast_canon: &ast::Canon<'_>,
     tetraplet_cid: Rc<CID<SecurityTetraplet>>,
     value_cids: Vec<Rc<CID<CanonCidAggregate>>>,
     exec_ctx: &mut ExecutionCtx<'_>,
-    trace_ctx: &mut TraceHandler,
-) -> ExecutionResult<()> {
+) -> ExecutionResult<StreamWithSerializedView> {
     let tetraplet = exec_ctx.cid_state.get_tetraplet_by_cid(&tetraplet_cid)?;
     let values = value_cids
         .iter()
@@ -69,15 +110,16 @@ fn handle_seen_canon(
         value_cids,
     };
 
-    epilog(ast_canon.canon_stream.name, canon_stream_with_se, exec_ctx, trace_ctx)
+    Ok(canon_stream_with_se)
 }
 
 fn handle_unseen_canon(
-    ast_canon: &ast::Canon<'_>,
+    peer_id: &ast::ResolvableToPeerIdVariable<'_>,
+    stream_name: &str,
+    stream_position: AirPos,
     exec_ctx: &mut ExecutionCtx<'_>,
-    trace_ctx: &mut TraceHandler,
-) -> ExecutionResult<()> {
-    let peer_id = crate::execution_step::instructions::resolve_peer_id_to_string(&ast_canon.peer_id, exec_ctx)?;
+) -> ExecutionResult<Option<StreamWithSerializedView>> {
+    let peer_id = crate::execution_step::instructions::resolve_peer_id_to_string(peer_id, exec_ctx)?;
 
     if exec_ctx.run_parameters.current_peer_id.as_str() != peer_id {
         exec_ctx.make_subgraph_incomplete();
@@ -88,11 +130,11 @@ fn handle_unseen_canon(
         //
         // the former means that there wasn't canon associated state in data, the latter that it
         // can't be obtained on this peer, so it's intended not to call meet_canon_end here.
-        return Ok(());
+        return Ok(None);
     }
 
-    let stream_with_positions = create_canon_stream_from_name(ast_canon, peer_id, exec_ctx)?;
-    epilog(ast_canon.canon_stream.name, stream_with_positions, exec_ctx, trace_ctx)
+    let stream_with_positions = create_canon_stream_from_name(stream_name, stream_position, peer_id, exec_ctx)?;
+    Ok(Some(stream_with_positions))
 }
 
 fn epilog(
@@ -115,6 +157,49 @@ fn epilog(
     Ok(())
 }
 
+/// Canonicalized stream map is set as a scalar JSON object. Keys of an object are strings,
+/// so entries whose keys have the same string form, e.g. `1` and `"1"`, collide,
+/// a collision is an error instead of silently dropping one of the values.
+fn map_epilog(
+    scalar_name: &str,
+    stream_with_positions: StreamWithSerializedView,
+    exec_ctx: &mut ExecutionCtx<'_>,
+    trace_ctx: &mut TraceHandler,
+) -> ExecutionResult<()> {
+    let StreamWithSerializedView {
+        canon_stream,
+        tetraplet_cid,
+        value_cids,
+    } = stream_with_positions;
+
+    // canonicalization itself succeeded, so the state is kept even if keys collide,
+    // then every peer gets the same error applying this state
+    let trace_pos = trace_ctx.trace_pos();
+    trace_ctx.meet_canon_end(CanonResult::with_kind(tetraplet_cid, value_cids, StreamKind::StreamMap));
+
+    let mut object = serde_json::Map::new();
+    for entry in canon_stream.iter() {
+        let (key, value) = match (entry.result.get(STREAM_MAP_KEY), entry.result.get(STREAM_MAP_VALUE)) {
+            (Some(JValue::String(key)), Some(value)) => (key.clone(), value),
+            (Some(JValue::Number(key)), Some(value)) => (key.to_string(), value),
+            // entries are produced only by ap to a stream map, so other values shouldn't be here
+            _ => continue,
+        };
+        if object.contains_key(&key) {
+            return Err(CatchableError::StreamMapKeyCollision(key).into());
+        }
+        object.insert(key, value.clone());
+    }
+
+    let value = ValueAggregate::new(
+        Rc::new(JValue::Object(object)),
+        canon_stream.tetraplet().clone(),
+        trace_pos,
+    );
+    exec_ctx.scalars.set_scalar_value(scalar_name, value).map(|_| ())?;
+    Ok(())
+}
+
 struct StreamWithSerializedView {
     canon_stream: CanonStream,
     tetraplet_cid: Rc<CID<SecurityTetraplet>>,
@@ -122,11 +207,12 @@ struct StreamWithSerializedView {
 }
 
 fn create_canon_stream_from_name(
-    ast_canon: &ast::Canon<'_>,
+    stream_name: &str,
+    stream_position: AirPos,
     peer_id: String,
     exec_ctx: &mut ExecutionCtx<'_>,
 ) -> ExecutionResult<StreamWithSerializedView> {
-    let stream = get_stream_or_default(ast_canon, exec_ctx);
+    let stream = get_stream_or_default(stream_name, stream_position, exec_ctx);
 
     let canon_stream = CanonStream::from_stream(stream.as_ref(), peer_id);
 
@@ -161,7 +247,11 @@ fn create_canon_stream_from_name(
 /// This function gets a stream from context or return a default empty stream,
 /// it's crucial for deterministic behaviour, for more info see
 /// github.com/fluencelabs/aquavm/issues/346
-fn get_stream_or_default<'ctx>(ast_canon: &ast::Canon<'_>, exec_ctx: &'ctx ExecutionCtx<'_>) -> Cow<'ctx, Stream> {
-    let maybe_stream = exec_ctx.streams.get(ast_canon.stream.name, ast_canon.stream.position);
+fn get_stream_or_default<'ctx>(
+    stream_name: &str,
+    stream_position: AirPos,
+    exec_ctx: &'ctx ExecutionCtx<'_>,
+) -> Cow<'ctx, Stream> {
+    let maybe_stream = exec_ctx.streams.get(stream_name, stream_position);
     maybe_stream.map(Cow::Borrowed).unwrap_or_default()
 }
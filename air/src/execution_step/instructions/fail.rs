@@ -29,6 +29,7 @@ use crate::JValue;
 
 use air_parser::ast;
 use air_parser::ast::Fail;
+use air_parser::ast::FailArgument;
 use polyplets::SecurityTetraplet;
 
 use std::rc::Rc;
@@ -37,16 +38,16 @@ impl<'i> super::ExecutableInstruction<'i> for Fail<'i> {
     fn execute(&self, exec_ctx: &mut ExecutionCtx<'i>, trace_ctx: &mut TraceHandler) -> ExecutionResult<()> {
         log_instruction!(fail, exec_ctx, trace_ctx);
 
-        match self {
-            Fail::Scalar(scalar) => fail_with_scalar(scalar, exec_ctx),
-            Fail::ScalarWithLambda(scalar) => fail_with_scalar_wl(scalar, exec_ctx),
-            &Fail::Literal {
+        match &self.argument {
+            FailArgument::Scalar(scalar) => fail_with_scalar(scalar, exec_ctx),
+            FailArgument::ScalarWithLambda(scalar) => fail_with_scalar_wl(scalar, exec_ctx),
+            FailArgument::Literal {
                 ret_code,
                 error_message,
-            } => fail_with_literals(ret_code, error_message, self, exec_ctx),
-            Fail::CanonStreamWithLambda(canon_stream) => fail_with_canon_stream(canon_stream, exec_ctx),
+            } => fail_with_literals(*ret_code, error_message, self, exec_ctx),
+            FailArgument::CanonStreamWithLambda(canon_stream) => fail_with_canon_stream(canon_stream, exec_ctx),
             // bubble last error up
-            Fail::LastError => fail_with_last_error(exec_ctx),
+            FailArgument::LastError => fail_with_last_error(exec_ctx),
         }
     }
 }
@@ -80,6 +81,7 @@ fn fail_with_literals(
         error_message,
         &fail.to_string(),
         exec_ctx.run_parameters.init_peer_id.as_ref(),
+        Some(exec_ctx.source_position(fail.span)),
     );
 
     let literal_tetraplet = SecurityTetraplet::literal_tetraplet(exec_ctx.run_parameters.init_peer_id.as_ref());
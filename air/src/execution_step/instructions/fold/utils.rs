@@ -65,14 +65,16 @@ pub(crate) fn create_scalar_wl_iterable<'ctx>(
         ScalarRef::Value(variable) => {
             let jvalues = select_by_lambda_from_scalar(&variable.result, lambda, exec_ctx)?;
             let tetraplet = variable.tetraplet.deref().clone();
-            from_jvalue(jvalues, tetraplet, lambda)
+            let source_len = variable.result.as_array().map_or(0, Vec::len);
+            from_jvalue(jvalues, tetraplet, lambda, source_len)
         }
         ScalarRef::IterableValue(fold_state) => {
             let iterable_value = fold_state.iterable.peek().unwrap();
             let jvalue = iterable_value.apply_lambda(lambda, exec_ctx)?;
             let tetraplet = to_tetraplet(&iterable_value);
+            let source_len = iterable_value.as_jvalue().as_array().map_or(0, Vec::len);
 
-            from_jvalue(jvalue, tetraplet, lambda)
+            from_jvalue(jvalue, tetraplet, lambda, source_len)
         }
     }
 }
@@ -142,8 +144,9 @@ fn from_jvalue(
     jvalue: Cow<'_, JValue>,
     tetraplet: SecurityTetraplet,
     lambda: &LambdaAST<'_>,
+    source_len: usize,
 ) -> ExecutionResult<FoldIterableScalar> {
-    let tetraplet = populate_tetraplet_with_lambda(tetraplet, lambda);
+    let tetraplet = populate_tetraplet_with_lambda(tetraplet, lambda, source_len);
     let tetraplet = Rc::new(tetraplet);
 
     let iterable = match jvalue.as_ref() {
@@ -29,49 +29,91 @@ use crate::trace_to_exec_err;
 use completeness_updater::FoldGenerationObserver;
 use stream_cursor::StreamCursor;
 
-use air_parser::ast;
 use air_parser::ast::FoldStream;
+use air_parser::ast::FoldStreamMap;
+use air_parser::ast::Instruction;
+use air_parser::AirPos;
+
+use std::fmt;
+use std::rc::Rc;
 
 impl<'i> ExecutableInstruction<'i> for FoldStream<'i> {
     fn execute(&self, exec_ctx: &mut ExecutionCtx<'i>, trace_ctx: &mut TraceHandler) -> ExecutionResult<()> {
         log_instruction!(fold, exec_ctx, trace_ctx);
-        exec_ctx.tracker.meet_fold_stream();
-
-        let iterable = &self.iterable;
-        let stream = match exec_ctx.streams.get(iterable.name, iterable.position) {
-            Some(stream) => stream,
-            None => {
-                // having empty streams means that it haven't been met yet, and it's needed to wait
-                exec_ctx.make_subgraph_incomplete();
-                return Ok(());
-            }
-        };
+        execute_fold_stream(self, exec_ctx, trace_ctx)
+    }
+}
 
-        let fold_id = exec_ctx.tracker.fold.seen_stream_count;
-        trace_to_exec_err!(trace_ctx.meet_fold_start(fold_id), self)?;
+impl<'i> ExecutableInstruction<'i> for FoldStreamMap<'i> {
+    fn execute(&self, exec_ctx: &mut ExecutionCtx<'i>, trace_ctx: &mut TraceHandler) -> ExecutionResult<()> {
+        log_instruction!(fold, exec_ctx, trace_ctx);
+        execute_fold_stream(self, exec_ctx, trace_ctx)
+    }
+}
+
+/// Fold over a stream or a stream map, the latter is kept in context as a stream of key-value
+/// entries, so both of them are iterated in the same way.
+trait StreamFold<'i>: fmt::Display {
+    fn iterable_name(&self) -> &'i str;
 
-        let mut stream_cursor = StreamCursor::new();
-        let mut stream_iterable = stream_cursor.construct_iterables(stream);
-        let mut observer = FoldGenerationObserver::new();
+    fn iterable_position(&self) -> AirPos;
 
-        // this cycle manages recursive streams
-        while !stream_iterable.is_empty() {
-            // add a new generation to made all consequence "new" (meaning that they are just executed on this peer)
-            // write operation to this stream to write to this new generation
-            add_new_generation_if_non_empty(&self.iterable, exec_ctx);
-            execute_iterations(stream_iterable, self, fold_id, &mut observer, exec_ctx, trace_ctx)?;
+    fn iterator_name(&self) -> &'i str;
 
-            // it's needed to get stream again, because RefCell allows only one mutable borrowing at time,
-            // and likely that stream could be mutably borrowed in execute_iterations
-            let stream = remove_new_generation_if_non_empty(&self.iterable, exec_ctx);
+    fn instruction(&self) -> Rc<Instruction<'i>>;
+
+    fn last_instruction(&self) -> Option<Rc<Instruction<'i>>>;
+}
 
-            stream_iterable = stream_cursor.construct_iterables(stream)
+fn execute_fold_stream<'i>(
+    fold_stream: &impl StreamFold<'i>,
+    exec_ctx: &mut ExecutionCtx<'i>,
+    trace_ctx: &mut TraceHandler,
+) -> ExecutionResult<()> {
+    exec_ctx.tracker.meet_fold_stream();
+
+    let iterable_name = fold_stream.iterable_name();
+    let iterable_position = fold_stream.iterable_position();
+    let stream = match exec_ctx.streams.get(iterable_name, iterable_position) {
+        Some(stream) => stream,
+        None => {
+            // having empty streams means that it haven't been met yet, and it's needed to wait
+            exec_ctx.make_subgraph_incomplete();
+            return Ok(());
         }
+    };
+
+    let fold_id = exec_ctx.tracker.fold.seen_stream_count;
+    trace_to_exec_err!(trace_ctx.meet_fold_start(fold_id), fold_stream)?;
+
+    let mut stream_cursor = StreamCursor::new();
+    let mut stream_iterable = stream_cursor.construct_iterables(stream);
+    let mut observer = FoldGenerationObserver::new();
+
+    // this cycle manages recursive streams
+    while !stream_iterable.is_empty() {
+        // add a new generation to made all consequence "new" (meaning that they are just executed on this peer)
+        // write operation to this stream to write to this new generation
+        add_new_generation_if_non_empty(iterable_name, iterable_position, exec_ctx);
+        execute_iterations(
+            stream_iterable,
+            fold_stream,
+            fold_id,
+            &mut observer,
+            exec_ctx,
+            trace_ctx,
+        )?;
 
-        observer.update_completeness(exec_ctx);
-        trace_to_exec_err!(trace_ctx.meet_fold_end(fold_id), self)?;
-        Ok(())
+        // it's needed to get stream again, because RefCell allows only one mutable borrowing at time,
+        // and likely that stream could be mutably borrowed in execute_iterations
+        let stream = remove_new_generation_if_non_empty(iterable_name, iterable_position, exec_ctx);
+
+        stream_iterable = stream_cursor.construct_iterables(stream)
     }
+
+    observer.update_completeness(exec_ctx);
+    trace_to_exec_err!(trace_ctx.meet_fold_end(fold_id), fold_stream)?;
+    Ok(())
 }
 
 /// Executes fold iteration over all generation that stream had at the moment of call.
@@ -81,7 +123,7 @@ impl<'i> ExecutableInstruction<'i> for FoldStream<'i> {
 /// must be met.
 fn execute_iterations<'i>(
     iterables: Vec<IterableValue>,
-    fold_stream: &FoldStream<'i>,
+    fold_stream: &impl StreamFold<'i>,
     fold_id: u32,
     generation_observer: &mut FoldGenerationObserver,
     exec_ctx: &mut ExecutionCtx<'i>,
@@ -100,13 +142,13 @@ fn execute_iterations<'i>(
         let result = fold(
             iterable,
             IterableType::Stream(fold_id),
-            fold_stream.iterator.name,
-            fold_stream.instruction.clone(),
-            fold_stream.last_instruction.clone(),
+            fold_stream.iterator_name(),
+            fold_stream.instruction(),
+            fold_stream.last_instruction(),
             exec_ctx,
             trace_ctx,
         );
-        throw_error_if_not_catchable(result)?;
+        throw_error_if_not_catchable(result, exec_ctx)?;
         trace_to_exec_err!(trace_ctx.meet_generation_end(fold_id), fold_stream)?;
 
         generation_observer.observe_completeness(exec_ctx.is_subgraph_complete());
@@ -116,27 +158,75 @@ fn execute_iterations<'i>(
 }
 
 /// Safety: this function should be called iff stream is present in context
-fn add_new_generation_if_non_empty(stream: &ast::Stream<'_>, exec_ctx: &mut ExecutionCtx<'_>) {
-    let stream = exec_ctx.streams.get_mut(stream.name, stream.position).unwrap();
+fn add_new_generation_if_non_empty(name: &str, position: AirPos, exec_ctx: &mut ExecutionCtx<'_>) {
+    let stream = exec_ctx.streams.get_mut(name, position).unwrap();
     stream.add_new_generation_if_non_empty();
 }
 
 /// Safety: this function should be called iff stream is present in context
 fn remove_new_generation_if_non_empty<'ctx>(
-    stream: &ast::Stream<'_>,
+    name: &str,
+    position: AirPos,
     exec_ctx: &'ctx mut ExecutionCtx<'_>,
 ) -> &'ctx Stream {
-    let stream = exec_ctx.streams.get_mut(stream.name, stream.position).unwrap();
+    let stream = exec_ctx.streams.get_mut(name, position).unwrap();
     stream.remove_last_generation_if_empty();
     stream
 }
 
 /// Fold over streams doesn't throw an error if it's a catchable one, because otherwise it would be
 /// not deterministic.
-fn throw_error_if_not_catchable(result: ExecutionResult<()>) -> ExecutionResult<()> {
+fn throw_error_if_not_catchable(result: ExecutionResult<()>, exec_ctx: &mut ExecutionCtx<'_>) -> ExecutionResult<()> {
     match result {
         Ok(_) => Ok(()),
-        Err(error) if error.is_catchable() => Ok(()),
+        Err(error) if error.is_catchable() => {
+            exec_ctx.meet_caught_error();
+            Ok(())
+        }
         error @ Err(_) => error,
     }
 }
+
+impl<'i> StreamFold<'i> for FoldStream<'i> {
+    fn iterable_name(&self) -> &'i str {
+        self.iterable.name
+    }
+
+    fn iterable_position(&self) -> AirPos {
+        self.iterable.position
+    }
+
+    fn iterator_name(&self) -> &'i str {
+        self.iterator.name
+    }
+
+    fn instruction(&self) -> Rc<Instruction<'i>> {
+        self.instruction.clone()
+    }
+
+    fn last_instruction(&self) -> Option<Rc<Instruction<'i>>> {
+        self.last_instruction.clone()
+    }
+}
+
+impl<'i> StreamFold<'i> for FoldStreamMap<'i> {
+    fn iterable_name(&self) -> &'i str {
+        self.iterable.name
+    }
+
+    fn iterable_position(&self) -> AirPos {
+        self.iterable.position
+    }
+
+    fn iterator_name(&self) -> &'i str {
+        self.iterator.name
+    }
+
+    fn instruction(&self) -> Rc<Instruction<'i>> {
+        self.instruction.clone()
+    }
+
+    fn last_instruction(&self) -> Option<Rc<Instruction<'i>>> {
+        self.last_instruction.clone()
+    }
+}
@@ -40,6 +40,7 @@ use super::boxed_value::ValueAggregate;
 use super::ExecutionCtx;
 use super::ExecutionError;
 use super::ExecutionResult;
+use super::ExecutionStep;
 use crate::execution_step::TraceHandler;
 
 use air_parser::ast::Instruction;
@@ -51,12 +52,15 @@ macro_rules! execute {
     ($self:expr, $instr:expr, $exec_ctx:ident, $trace_ctx:ident) => {{
         match $instr.execute($exec_ctx, $trace_ctx) {
             Err(e) => {
+                $exec_ctx.meet_error($self.span());
+                let position = $exec_ctx.error_position();
                 $exec_ctx.last_error_descriptor.try_to_set_from_error(
                     &e,
                     // TODO: avoid excess copying here
                     &$instr.to_string(),
                     $exec_ctx.run_parameters.current_peer_id.as_ref(),
                     None,
+                    position,
                 );
                 Err(e)
             }
@@ -71,16 +75,30 @@ pub(crate) trait ExecutableInstruction<'i> {
 
 impl<'i> ExecutableInstruction<'i> for Instruction<'i> {
     fn execute(&self, exec_ctx: &mut ExecutionCtx<'i>, trace_ctx: &mut TraceHandler) -> ExecutionResult<()> {
+        // comments aren't instructions, so they are neither counted nor logged
+        if let Instruction::Commented(commented) = self {
+            return commented.instruction.execute(exec_ctx, trace_ctx);
+        }
+
+        notify_observer(self, exec_ctx, trace_ctx);
+        check_instruction_limit(exec_ctx).map_err(|e| {
+            exec_ctx.meet_error(self.span());
+            e
+        })?;
+
         match self {
             // call isn't wrapped by the execute macro because
             // it internally sets last_error with resolved triplet
             Instruction::Call(call) => call.execute(exec_ctx, trace_ctx),
 
             Instruction::Ap(ap) => execute!(self, ap, exec_ctx, trace_ctx),
+            Instruction::ApMap(ap_map) => execute!(self, ap_map, exec_ctx, trace_ctx),
             Instruction::Canon(canon) => execute!(self, canon, exec_ctx, trace_ctx),
+            Instruction::CanonMap(canon_map) => execute!(self, canon_map, exec_ctx, trace_ctx),
             Instruction::Fail(fail) => execute!(self, fail, exec_ctx, trace_ctx),
             Instruction::FoldScalar(fold) => execute!(self, fold, exec_ctx, trace_ctx),
             Instruction::FoldStream(fold) => execute!(self, fold, exec_ctx, trace_ctx),
+            Instruction::FoldStreamMap(fold) => execute!(self, fold, exec_ctx, trace_ctx),
             Instruction::Never(never) => execute!(self, never, exec_ctx, trace_ctx),
             Instruction::New(new) => execute!(self, new, exec_ctx, trace_ctx),
             Instruction::Next(next) => execute!(self, next, exec_ctx, trace_ctx),
@@ -91,11 +109,35 @@ impl<'i> ExecutableInstruction<'i> for Instruction<'i> {
             Instruction::Match(match_) => execute!(self, match_, exec_ctx, trace_ctx),
             Instruction::MisMatch(mismatch) => execute!(self, mismatch, exec_ctx, trace_ctx),
 
+            Instruction::Commented(_) => unreachable!("comments are skipped above. QED."),
             Instruction::Error => unreachable!("should not execute if parsing succeeded. QED."),
         }
     }
 }
 
+/// Passes the current state to an observer if it's set, it's taken out of the context
+/// for the time of the call, so that the context could be borrowed.
+fn notify_observer<'i>(instruction: &Instruction<'i>, exec_ctx: &mut ExecutionCtx<'i>, trace_ctx: &TraceHandler) {
+    if let Some(mut observer) = exec_ctx.observer.take() {
+        observer.before_instruction(&ExecutionStep::new(instruction, exec_ctx, trace_ctx));
+        exec_ctx.observer = Some(observer);
+    }
+}
+
+/// Counts an instruction that is going to be executed and checks that it fits into the limit
+/// set by a host. The error is uncatchable, so a runaway script can't continue inside a xor.
+fn check_instruction_limit(exec_ctx: &mut ExecutionCtx<'_>) -> ExecutionResult<()> {
+    use super::UncatchableError;
+
+    let instructions_count = exec_ctx.tracker.meet_instruction();
+    let limit = exec_ctx.run_parameters.instruction_limit;
+    if limit != 0 && instructions_count > limit {
+        return Err(UncatchableError::InstructionLimitExceeded { limit }.into());
+    }
+
+    Ok(())
+}
+
 #[macro_export]
 macro_rules! log_instruction {
     ($instr_name:expr, $exec_ctx:expr, $trace_ctx:expr) => {
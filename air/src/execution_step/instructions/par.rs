@@ -33,6 +33,7 @@ use air_trace_handler::SubgraphType;
 impl<'i> ExecutableInstruction<'i> for Par<'i> {
     fn execute(&self, exec_ctx: &mut ExecutionCtx<'i>, trace_ctx: &mut TraceHandler) -> ExecutionResult<()> {
         log_instruction!(par, exec_ctx, trace_ctx);
+        exec_ctx.tracker.meet_par();
 
         let mut completeness_updater = ParCompletenessUpdater::new();
         trace_to_exec_err!(trace_ctx.meet_par_start(), self)?;
@@ -70,6 +71,10 @@ fn execute_subgraph<'i>(
         }
         Err(e) if e.is_catchable() => {
             exec_ctx.make_subgraph_incomplete();
+            // if both subgraphs fail, par bubbles up the error of the right one
+            if matches!(subgraph_type, SubgraphType::Left) {
+                exec_ctx.meet_caught_error();
+            }
             trace_to_exec_err!(trace_ctx.meet_par_subgraph_end(subgraph_type), par)?;
             SubgraphResult::Failed(e)
         }
@@ -96,6 +101,7 @@ fn prepare_par_result(
     match (left_result, right_result) {
         (SubgraphResult::Succeeded, _) | (_, SubgraphResult::Succeeded) => {
             exec_ctx.last_error_descriptor.meet_par_successed_end();
+            exec_ctx.meet_caught_error();
             Ok(())
         }
         (SubgraphResult::Failed(_), SubgraphResult::Failed(err)) => Err(err),
@@ -111,5 +117,5 @@ fn determine_subgraph_complete(next_instruction: &Instruction<'_>) -> bool {
     //    )
     // )
     // par will be completed after the last next that wouldn't change subgraph_complete
-    !matches!(next_instruction, Instruction::Next(_))
+    !matches!(next_instruction.without_comments(), Instruction::Next(_))
 }
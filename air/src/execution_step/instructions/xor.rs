@@ -33,6 +33,7 @@ impl<'i> super::ExecutableInstruction<'i> for Xor<'i> {
 
                 exec_ctx.flush_subgraph_completeness();
                 exec_ctx.last_error_descriptor.meet_xor_right_branch();
+                exec_ctx.meet_caught_error();
                 self.1.execute(exec_ctx, trace_ctx)
             }
             res => res,
@@ -43,7 +43,7 @@ pub(crate) fn select_by_lambda_from_stream<'value>(
 ) -> ExecutionResult<StreamSelectResult<'value>> {
     match lambda {
         LambdaAST::ValuePath(value_path) => select_by_path_from_stream(stream, value_path, exec_ctx),
-        LambdaAST::Functor(functor) => Ok(select_by_functor_from_stream(stream, functor)),
+        LambdaAST::Functor(functor) => select_by_functor_from_stream(stream, functor),
     }
 }
 
@@ -53,7 +53,7 @@ pub(crate) fn select_by_lambda_from_scalar<'value>(
     exec_ctx: &ExecutionCtx<'_>,
 ) -> ExecutionResult<Cow<'value, JValue>> {
     match lambda {
-        LambdaAST::ValuePath(value_path) => select_by_path_from_scalar(value, value_path.iter(), exec_ctx),
+        LambdaAST::ValuePath(value_path) => select_by_path_from_scalar(value, value_path, exec_ctx),
         LambdaAST::Functor(functor) => select_by_functor_from_scalar(value, functor).map(Cow::Owned),
     }
 }
@@ -66,6 +66,15 @@ fn select_by_path_from_stream<'value>(
     let (prefix, body) = lambda.split_first();
     let idx = match prefix {
         ValueAccessor::ArrayAccess { idx } => *idx,
+        ValueAccessor::ArraySlice { start, end } => {
+            let values = stream.collect::<Vec<_>>();
+            let sliced = lambda_to_execution_error!(try_slice(&values, *start, *end))?;
+            let sliced = JValue::Array(sliced.iter().map(|&value| value.clone()).collect());
+
+            // a slice is a new value, so it doesn't correspond to any particular stream element
+            let result = select_by_path_from_scalar(&sliced, body, exec_ctx)?.into_owned();
+            return Ok(StreamSelectResult::from_value(result));
+        }
         ValueAccessor::FieldAccessByName { field_name } => {
             return lambda_to_execution_error!(Err(LambdaError::FieldAccessorAppliedToStream {
                 field_name: field_name.to_string(),
@@ -84,33 +93,57 @@ fn select_by_path_from_stream<'value>(
         .nth(idx as usize)
         .ok_or(LambdaError::CanonStreamNotHaveEnoughValues { stream_size, idx }))?;
 
-    let result = select_by_path_from_scalar(value, body.iter(), exec_ctx)?;
+    let result = select_by_path_from_scalar(value, body, exec_ctx)?;
     let select_result = StreamSelectResult::from_cow(result, idx);
     Ok(select_result)
 }
 
 fn select_by_functor_from_stream<'value>(
     stream: impl ExactSizeIterator<Item = &'value JValue> + 'value,
-    functor: &Functor,
-) -> StreamSelectResult<'value> {
+    functor: &Functor<'_>,
+) -> ExecutionResult<StreamSelectResult<'value>> {
+    let stream_size = stream.len();
+
     match functor {
         Functor::Length => {
-            let result = serde_json::json!(stream.len());
-            StreamSelectResult::from_value(result)
+            let result = serde_json::json!(stream_size);
+            Ok(StreamSelectResult::from_value(result))
+        }
+        Functor::Keys => {
+            let result = serde_json::json!((0..stream_size).collect::<Vec<_>>());
+            Ok(StreamSelectResult::from_value(result))
+        }
+        Functor::Last => {
+            let idx = lambda_to_execution_error!(stream_size.checked_sub(1).ok_or(LambdaError::EmptyStream))?;
+            let value = stream
+                .last()
+                .expect("stream size has been checked to be non-zero. QED.");
+            Ok(StreamSelectResult::from_cow(Cow::Borrowed(value), idx as u32))
+        }
+        Functor::ContainsKey { key } => {
+            let result = serde_json::json!(contains_idx(stream_size, key));
+            Ok(StreamSelectResult::from_value(result))
         }
     }
 }
 
-fn select_by_path_from_scalar<'value, 'accessor>(
+fn select_by_path_from_scalar<'value>(
     mut value: &'value JValue,
-    lambda: impl Iterator<Item = &'accessor ValueAccessor<'accessor>>,
+    lambda: &[ValueAccessor<'_>],
     exec_ctx: &ExecutionCtx<'_>,
 ) -> ExecutionResult<Cow<'value, JValue>> {
-    for accessor in lambda {
+    for (position, accessor) in lambda.iter().enumerate() {
         match accessor {
             ValueAccessor::ArrayAccess { idx } => {
                 value = lambda_to_execution_error!(try_jvalue_with_idx(value, *idx))?;
             }
+            ValueAccessor::ArraySlice { start, end } => {
+                let sliced = lambda_to_execution_error!(try_jvalue_with_slice(value, *start, *end))?;
+
+                // the rest of a lambda is applied to a new value, so the result can't be borrowed
+                let result = select_by_path_from_scalar(&sliced, &lambda[position + 1..], exec_ctx)?;
+                return Ok(Cow::Owned(result.into_owned()));
+            }
             ValueAccessor::FieldAccessByName { field_name } => {
                 value = lambda_to_execution_error!(try_jvalue_with_field_name(value, field_name))?;
             }
@@ -125,7 +158,7 @@ fn select_by_path_from_scalar<'value, 'accessor>(
     Ok(Cow::Borrowed(value))
 }
 
-fn select_by_functor_from_scalar(value: &JValue, functor: &Functor) -> ExecutionResult<JValue> {
+fn select_by_functor_from_scalar(value: &JValue, functor: &Functor<'_>) -> ExecutionResult<JValue> {
     match functor {
         Functor::Length => {
             let length = value
@@ -136,6 +169,34 @@ fn select_by_functor_from_scalar(value: &JValue, functor: &Functor) -> Execution
                 .len();
             Ok(serde_json::json!(length))
         }
+        Functor::Keys => match value {
+            JValue::Object(map) => Ok(serde_json::json!(map.keys().collect::<Vec<_>>())),
+            JValue::Array(values) => Ok(serde_json::json!((0..values.len()).collect::<Vec<_>>())),
+            _ => lambda_to_execution_error!(Err(LambdaError::FunctorNotMatchValue {
+                value: value.clone(),
+                functor: functor.to_string(),
+            })),
+        },
+        Functor::Last => match value {
+            JValue::Array(values) => {
+                lambda_to_execution_error!(values
+                    .last()
+                    .cloned()
+                    .ok_or(LambdaError::LastFunctorAppliedToEmptyArray))
+            }
+            _ => lambda_to_execution_error!(Err(LambdaError::FunctorNotMatchValue {
+                value: value.clone(),
+                functor: functor.to_string(),
+            })),
+        },
+        Functor::ContainsKey { key } => match value {
+            JValue::Object(map) => Ok(serde_json::json!(map.contains_key(*key))),
+            JValue::Array(values) => Ok(serde_json::json!(contains_idx(values.len(), key))),
+            _ => lambda_to_execution_error!(Err(LambdaError::FunctorNotMatchValue {
+                value: value.clone(),
+                functor: functor.to_string(),
+            })),
+        },
     }
 }
 
@@ -51,4 +51,16 @@ pub enum LambdaError {
 
     #[error("stream accessor `{scalar_accessor}` should has number (u32) type")]
     StreamAccessorHasInvalidType { scalar_accessor: JValue },
+
+    #[error("value '{value}' is not an array-type to match array slice [{start}..{end}]")]
+    ArraySliceNotMatchValue { value: JValue, start: u32, end: u32 },
+
+    #[error("array slice [{start}..{end}] can't be applied to a value with '{size}' elements")]
+    InvalidArraySlice { size: usize, start: u32, end: u32 },
+
+    #[error("functor '.{functor}' can't be applied to value '{value}'")]
+    FunctorNotMatchValue { value: JValue, functor: String },
+
+    #[error("functor '.last' is applied to an empty array")]
+    LastFunctorAppliedToEmptyArray,
 }
@@ -35,6 +35,35 @@ pub(super) fn try_jvalue_with_idx(jvalue: &JValue, idx: u32) -> LambdaResult<&JV
     }
 }
 
+pub(super) fn try_jvalue_with_slice(jvalue: &JValue, start: u32, end: u32) -> LambdaResult<JValue> {
+    match jvalue {
+        JValue::Array(values) => {
+            let sliced = try_slice(values, start, end)?;
+            Ok(JValue::Array(sliced.to_vec()))
+        }
+        _ => Err(LambdaError::ArraySliceNotMatchValue {
+            value: jvalue.clone(),
+            start,
+            end,
+        }),
+    }
+}
+
+pub(super) fn try_slice<T>(values: &[T], start: u32, end: u32) -> LambdaResult<&[T]> {
+    values
+        .get(start as usize..end as usize)
+        .ok_or(LambdaError::InvalidArraySlice {
+            size: values.len(),
+            start,
+            end,
+        })
+}
+
+/// Keys of array-like values are their indices.
+pub(super) fn contains_idx(size: usize, key: &str) -> bool {
+    key.parse::<u32>().map_or(false, |idx| (idx as usize) < size)
+}
+
 pub(super) fn try_jvalue_with_field_name<'value>(
     jvalue: &'value JValue,
     field_name: &str,
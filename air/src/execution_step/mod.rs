@@ -19,6 +19,7 @@ mod errors;
 pub(crate) mod execution_context;
 mod instructions;
 mod lambda_applier;
+mod observer;
 mod resolver;
 
 const PEEK_ALLOWED_ON_NON_EMPTY: &str = "peek always return elements inside fold,\
@@ -26,11 +27,17 @@ const PEEK_ALLOWED_ON_NON_EMPTY: &str = "peek always return elements inside fold
 
 const TETRAPLET_IDX_CORRECT: &str = "selects always return a correct index inside stream";
 
+/// A stream map is kept as a stream of entries with these fields.
+const STREAM_MAP_KEY: &str = "key";
+const STREAM_MAP_VALUE: &str = "value";
+
 pub use errors::CatchableError;
 pub use errors::ExecutionError;
 pub use errors::UncatchableError;
 pub use execution_context::LastErrorObjectError;
 pub use lambda_applier::LambdaError;
+pub use observer::ExecutionObserver;
+pub use observer::ExecutionStep;
 
 pub mod errors_prelude {
     pub use super::CatchableError;
@@ -0,0 +1,73 @@
+/*
+ * Copyright 2023 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use super::ExecutionCtx;
+use super::TraceHandler;
+
+use air_interpreter_data::TracePos;
+use air_parser::ast::Instruction;
+use air_parser::SourcePosition;
+
+/// Receives control before every instruction is executed, it's intended for debuggers
+/// and is available only for the native interpreter.
+pub trait ExecutionObserver {
+    fn before_instruction(&mut self, step: &ExecutionStep<'_, '_>);
+}
+
+/// State of the execution right before an instruction.
+pub struct ExecutionStep<'step, 'i> {
+    instruction: &'step Instruction<'i>,
+    exec_ctx: &'step ExecutionCtx<'i>,
+    trace_ctx: &'step TraceHandler,
+}
+
+impl<'step, 'i> ExecutionStep<'step, 'i> {
+    pub(crate) fn new(
+        instruction: &'step Instruction<'i>,
+        exec_ctx: &'step ExecutionCtx<'i>,
+        trace_ctx: &'step TraceHandler,
+    ) -> Self {
+        Self {
+            instruction,
+            exec_ctx,
+            trace_ctx,
+        }
+    }
+
+    pub fn instruction(&self) -> &'step Instruction<'i> {
+        self.instruction
+    }
+
+    /// Position of the instruction in the script, it's known for every successfully parsed instruction.
+    pub fn position(&self) -> Option<SourcePosition> {
+        self.instruction.span().map(|span| self.exec_ctx.source_position(span))
+    }
+
+    /// Scalars, streams, subgraph completeness and other parts of the execution context.
+    pub fn context(&self) -> String {
+        self.exec_ctx.to_string()
+    }
+
+    /// Positions of the next states that will be read from the previous and current traces.
+    pub fn trace_positions(&self) -> (TracePos, TracePos) {
+        self.trace_ctx.subgraph_positions()
+    }
+
+    /// Position of the next state in the resulted trace.
+    pub fn result_trace_pos(&self) -> TracePos {
+        self.trace_ctx.trace_pos()
+    }
+}
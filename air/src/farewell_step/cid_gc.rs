@@ -0,0 +1,107 @@
+/*
+ * Copyright 2023 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use crate::JValue;
+
+use air_interpreter_cid::CID;
+use air_interpreter_data::CallResult;
+use air_interpreter_data::CanonCidAggregate;
+use air_interpreter_data::CidInfo;
+use air_interpreter_data::ExecutedState;
+use air_interpreter_data::ExecutionTrace;
+use air_interpreter_data::ServiceResultAggregate;
+use air_interpreter_data::ValueRef;
+use polyplets::SecurityTetraplet;
+
+use std::collections::HashSet;
+use std::rc::Rc;
+
+/// Drops all values from CID stores that aren't reachable from the result trace,
+/// otherwise stores would grow with every merge of prev and current data.
+#[tracing::instrument(skip_all)]
+pub fn retain_reachable_cids(trace: &ExecutionTrace, mut cid_info: CidInfo) -> CidInfo {
+    let reachable = ReachableCids::mark(trace, &cid_info);
+
+    cid_info.value_store.retain(|cid| reachable.values.contains(cid));
+    cid_info
+        .tetraplet_store
+        .retain(|cid| reachable.tetraplets.contains(cid));
+    cid_info.canon_store.retain(|cid| reachable.canons.contains(cid));
+    cid_info
+        .service_result_store
+        .retain(|cid| reachable.service_results.contains(cid));
+
+    cid_info
+}
+
+#[derive(Default)]
+struct ReachableCids {
+    values: HashSet<Rc<CID<JValue>>>,
+    tetraplets: HashSet<Rc<CID<SecurityTetraplet>>>,
+    canons: HashSet<Rc<CID<CanonCidAggregate>>>,
+    service_results: HashSet<Rc<CID<ServiceResultAggregate>>>,
+}
+
+impl ReachableCids {
+    fn mark(trace: &ExecutionTrace, cid_info: &CidInfo) -> Self {
+        let mut reachable = Self::default();
+
+        for state in trace.iter() {
+            match state {
+                ExecutedState::Call(CallResult::Executed(ValueRef::Scalar(cid)))
+                | ExecutedState::Call(CallResult::Executed(ValueRef::Stream { cid, .. }))
+                | ExecutedState::Call(CallResult::Failed(cid)) => reachable.mark_service_result(cid, cid_info),
+                ExecutedState::Canon(canon_result) => {
+                    reachable.tetraplets.insert(canon_result.tetraplet.clone());
+                    for canon_cid in &canon_result.values {
+                        reachable.mark_canon(canon_cid, cid_info);
+                    }
+                }
+                // unused values are never put into the value store
+                ExecutedState::Call(CallResult::Executed(ValueRef::Unused(_)))
+                | ExecutedState::Call(CallResult::RequestSentBy(_))
+                | ExecutedState::Par(_)
+                | ExecutedState::Fold(_)
+                | ExecutedState::Ap(_) => {}
+            }
+        }
+
+        reachable
+    }
+
+    fn mark_service_result(&mut self, cid: &Rc<CID<ServiceResultAggregate>>, cid_info: &CidInfo) {
+        if !self.service_results.insert(cid.clone()) {
+            return;
+        }
+
+        // a dangling CID will be reported by the next peer while resolving it
+        if let Some(service_result) = cid_info.service_result_store.get(cid) {
+            self.values.insert(service_result.value_cid.clone());
+            self.tetraplets.insert(service_result.tetraplet_cid.clone());
+        }
+    }
+
+    fn mark_canon(&mut self, cid: &Rc<CID<CanonCidAggregate>>, cid_info: &CidInfo) {
+        if !self.canons.insert(cid.clone()) {
+            return;
+        }
+
+        if let Some(canon_aggregate) = cid_info.canon_store.get(cid) {
+            self.values.insert(canon_aggregate.value.clone());
+            self.tetraplets.insert(canon_aggregate.tetraplet.clone());
+        }
+    }
+}
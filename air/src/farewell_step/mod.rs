@@ -14,9 +14,11 @@
  * limitations under the License.
  */
 
+mod cid_gc;
 mod errors;
 mod outcome;
 
+pub use cid_gc::retain_reachable_cids;
 pub use errors::FarewellError;
 
 pub(crate) use outcome::from_execution_error;
@@ -22,8 +22,18 @@ use crate::InterpreterOutcome;
 use crate::ToErrorCode;
 use crate::INTERPRETER_SUCCESS;
 
+use air_execution_info_collector::InstructionTracker;
+use air_interpreter_cid::CID;
+use air_interpreter_data::CidInfo;
 use air_interpreter_data::InterpreterData;
+use air_interpreter_data::MergeConflict;
 use air_interpreter_interface::CallRequests;
+use air_interpreter_interface::CidStoreStats;
+use air_interpreter_interface::ErrorPosition;
+use air_interpreter_interface::ExecutionStats;
+use air_interpreter_interface::InstructionStats;
+use air_interpreter_interface::TraceStats;
+use air_parser::SourcePosition;
 use air_utils::measure;
 
 use std::fmt::Debug;
@@ -44,33 +54,66 @@ pub(crate) fn from_success_result(
         (farewell_error.to_error_code(), farewell_error.to_string())
     };
 
-    let outcome = populate_outcome_from_contexts(exec_ctx, trace_handler, ret_code, error_message);
+    let outcome = populate_outcome_from_contexts(exec_ctx, trace_handler, ret_code, error_message, None);
     Ok(outcome)
 }
 
 /// Create InterpreterOutcome from supplied data and error,
-/// set ret_code based on the error.
+/// set ret_code based on the error and pass the error position if it's known,
+/// a merge conflict is passed if the error occurred on merging data.
 #[tracing::instrument]
 pub(crate) fn from_uncatchable_error(
     data: impl Into<Vec<u8>> + Debug,
     error: impl ToErrorCode + ToString + Debug,
+    position: Option<SourcePosition>,
+    merge_conflict: Option<&MergeConflict>,
 ) -> InterpreterOutcome {
     let ret_code = error.to_error_code();
     let data = data.into();
     let call_requests = serde_json::to_vec(&CallRequests::new()).expect("default serializer shouldn't fail");
+    let merge_conflict = match merge_conflict {
+        Some(conflict) => serde_json::to_vec(conflict).expect("default serializer shouldn't fail"),
+        None => vec![],
+    };
 
-    InterpreterOutcome::new(ret_code, error.to_string(), data, vec![], call_requests)
+    InterpreterOutcome {
+        ret_code,
+        error_message: error.to_string(),
+        data,
+        next_peer_pks: vec![],
+        call_requests,
+        stats: vec![],
+        merge_conflict,
+        error_position: serialize_error_position(position),
+    }
 }
 
 /// Create InterpreterOutcome from supplied execution context, trace handler, and error,
-/// set ret_code based on the error.
+/// set ret_code based on the error and pass the failed instruction position.
 #[tracing::instrument(skip(exec_ctx, trace_handler))]
 pub(crate) fn from_execution_error(
     exec_ctx: ExecutionCtx<'_>,
     trace_handler: TraceHandler,
     error: impl ToErrorCode + ToString + Debug,
 ) -> InterpreterOutcome {
-    populate_outcome_from_contexts(exec_ctx, trace_handler, error.to_error_code(), error.to_string())
+    let error_position = exec_ctx.error_position();
+    populate_outcome_from_contexts(
+        exec_ctx,
+        trace_handler,
+        error.to_error_code(),
+        error.to_string(),
+        error_position,
+    )
+}
+
+fn serialize_error_position(position: Option<SourcePosition>) -> Vec<u8> {
+    match position {
+        Some(position) => {
+            let position = ErrorPosition::new(position.line, position.column);
+            serde_json::to_vec(&position).expect("default serializer shouldn't fail")
+        }
+        None => vec![],
+    }
 }
 
 #[tracing::instrument(skip(exec_ctx, trace_handler), level = "info")]
@@ -79,6 +122,7 @@ fn populate_outcome_from_contexts(
     mut trace_handler: TraceHandler,
     ret_code: i64,
     error_message: String,
+    error_position: Option<SourcePosition>,
 ) -> InterpreterOutcome {
     let maybe_gens = exec_ctx
         .streams
@@ -89,19 +133,53 @@ fn populate_outcome_from_contexts(
         Err(outcome) => return outcome,
     };
 
-    let data = InterpreterData::from_execution_result(
-        trace_handler.into_result_trace(),
+    let (prev_trace_len, current_trace_len) = trace_handler.input_trace_lens();
+    let trace = trace_handler.into_result_trace();
+    let result_trace_len = trace.len();
+    let cid_info = super::cid_gc::retain_reachable_cids(&trace, exec_ctx.cid_state.into());
+    let mut stats = if exec_ctx.run_parameters.collect_stats {
+        Some(ExecutionStats {
+            instructions: instruction_stats(&exec_ctx.tracker),
+            trace: TraceStats {
+                prev_len: prev_trace_len as u64,
+                current_len: current_trace_len as u64,
+                result_len: result_trace_len as u64,
+            },
+            cid_stores: cid_store_stats(&cid_info),
+            data_size: 0,
+        })
+    } else {
+        None
+    };
+
+    let mut data = InterpreterData::from_execution_result(
+        trace,
         global_streams,
         restricted_streams,
-        exec_ctx.cid_state.into(),
+        cid_info,
         exec_ctx.last_call_request_id,
         semver::Version::parse(env!("CARGO_PKG_VERSION")).expect("cargo version is valid"),
     );
+    data.signatures = exec_ctx.signature_store;
+    let service_result_store = &data.cid_info.service_result_store;
+    data.signatures
+        .retain_reachable(|cid| service_result_store.get(&CID::new(cid)).is_some());
+    if let Some(signer) = &exec_ctx.signer {
+        data.sign_own_contributions(signer.as_ref());
+    }
     let data = measure!(
-        serde_json::to_vec(&data).expect("default serializer shouldn't fail"),
+        data.serialize_with_format(exec_ctx.data_format)
+            .expect("default serializer shouldn't fail"),
         tracing::Level::TRACE,
-        "serde_json::to_vec(data)"
+        "InterpreterData::serialize_with_format(data)"
     );
+    let stats = match stats.as_mut() {
+        Some(stats) => {
+            stats.data_size = data.len() as u64;
+            serde_json::to_vec(stats).expect("default serializer shouldn't fail")
+        }
+        None => vec![],
+    };
     let next_peer_pks = dedup(exec_ctx.next_peer_pks);
     let call_requests = measure!(
         serde_json::to_vec(&exec_ctx.call_requests).expect("default serializer shouldn't fail"),
@@ -109,13 +187,52 @@ fn populate_outcome_from_contexts(
         "serde_json::to_vec(call_results)",
     );
 
-    InterpreterOutcome::new(ret_code, error_message, data, next_peer_pks, call_requests)
+    InterpreterOutcome {
+        ret_code,
+        error_message,
+        data,
+        next_peer_pks,
+        call_requests,
+        stats,
+        merge_conflict: vec![],
+        error_position: serialize_error_position(error_position),
+    }
+}
+
+fn instruction_stats(tracker: &InstructionTracker) -> InstructionStats {
+    InstructionStats {
+        total_count: tracker.instructions_count,
+        call_seen_count: tracker.call.seen_count,
+        call_executed_count: tracker.call.executed_count,
+        ap_seen_count: tracker.ap.seen_count,
+        ap_executed_count: tracker.ap.executed_count,
+        par_seen_count: tracker.par.seen_count,
+        fold_scalar_count: tracker.fold.seen_scalar_count,
+        fold_stream_count: tracker.fold.seen_stream_count,
+    }
+}
+
+fn cid_store_stats(cid_info: &CidInfo) -> CidStoreStats {
+    CidStoreStats {
+        value_count: cid_info.value_store.len() as u64,
+        tetraplet_count: cid_info.tetraplet_store.len() as u64,
+        canon_count: cid_info.canon_store.len() as u64,
+        service_result_count: cid_info.service_result_store.len() as u64,
+    }
 }
 
 // this method is called only if there is an internal error in the interpreter and
 // new execution trace was corrupted
 fn execution_error_into_outcome(error: ExecutionError) -> InterpreterOutcome {
-    InterpreterOutcome::new(error.to_error_code(), error.to_string(), vec![], vec![], vec![])
+    InterpreterOutcome::new(
+        error.to_error_code(),
+        error.to_string(),
+        vec![],
+        vec![],
+        vec![],
+        vec![],
+        vec![],
+    )
 }
 
 /// Deduplicate values in a supplied vector.
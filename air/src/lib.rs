@@ -35,13 +35,18 @@ mod utils;
 pub use air_interpreter_interface::InterpreterOutcome;
 pub use air_interpreter_interface::RunParameters;
 pub use air_interpreter_interface::INTERPRETER_SUCCESS;
+pub use air_trace_handler::ActiveFsm;
+pub use air_trace_handler::MergeConflict;
 pub use execution_step::execution_context::ExecutionCidState;
 pub use execution_step::execution_context::LastError;
 pub use execution_step::CatchableError;
 pub use execution_step::ExecutionError;
+pub use execution_step::ExecutionObserver;
+pub use execution_step::ExecutionStep;
 pub use execution_step::LambdaError;
 pub use execution_step::LastErrorObjectError;
 pub use execution_step::UncatchableError;
+pub use farewell_step::retain_reachable_cids;
 pub use farewell_step::FarewellError;
 pub use polyplets::ResolvedTriplet;
 pub use polyplets::SecurityTetraplet;
@@ -49,11 +54,17 @@ pub use preparation_step::PreparationError;
 pub use utils::ToErrorCode;
 
 pub use crate::runner::execute_air;
+pub use crate::runner::execute_air_with_observer;
+pub use crate::runner::execute_air_with_signer;
 
 pub mod interpreter_data {
     pub use air_interpreter_data::*;
 }
 
+pub mod interpreter_signatures {
+    pub use air_interpreter_signatures::*;
+}
+
 pub mod parser {
     pub use air_parser::ast::Instruction;
 
@@ -61,6 +72,11 @@ pub mod parser {
     pub fn parse(script: &str) -> Result<Box<Instruction<'_>>, String> {
         air_parser::parse(script)
     }
+
+    /// Print AST back to an AIR script.
+    pub fn print(instruction: &Instruction<'_>) -> String {
+        air_parser::ast::print(instruction)
+    }
 }
 
 pub(crate) type JValue = serde_json::Value;
@@ -15,7 +15,10 @@
  */
 
 use crate::ToErrorCode;
+use crate::UncatchableError;
 use air_interpreter_data::data_version;
+use air_interpreter_data::CidInfoVerificationError;
+use air_interpreter_data::DataDeserializationError;
 use air_interpreter_data::Versions;
 
 use serde_json::Error as SerdeJsonError;
@@ -41,7 +44,10 @@ pub enum PreparationError {
         super::interpreter_version(),
         data_version()
     )]
-    DataDeFailed { data: Vec<u8>, error: SerdeJsonError },
+    DataDeFailed {
+        data: Vec<u8>,
+        error: DataDeserializationError,
+    },
 
     /// Errors occurred on executed trace deserialization
     /// when it was possible to recover versions.
@@ -57,7 +63,7 @@ pub enum PreparationError {
     )]
     DataDeFailedWithVersions {
         data: Vec<u8>,
-        error: SerdeJsonError,
+        error: DataDeserializationError,
         versions: Versions,
     },
 
@@ -77,6 +83,14 @@ pub enum PreparationError {
         actual_version: semver::Version,
         required_version: semver::Version,
     },
+
+    /// Error occurred when supplied data contains a CID store value that doesn't correspond to its CID.
+    #[error("supplied data contains a corrupted CID store, {0}")]
+    CidStoreVerificationFailed(CidInfoVerificationError),
+
+    /// Error occurred when a particle came after its deadline and there are no call results to apply.
+    #[error("particle is expired: its deadline is {deadline}, but current time is {current_time}")]
+    ParticleExpired { deadline: u64, current_time: u64 },
 }
 
 impl ToErrorCode for PreparationError {
@@ -87,11 +101,11 @@ impl ToErrorCode for PreparationError {
 }
 
 impl PreparationError {
-    pub fn data_de_failed(data: Vec<u8>, error: SerdeJsonError) -> Self {
+    pub fn data_de_failed(data: Vec<u8>, error: DataDeserializationError) -> Self {
         Self::DataDeFailed { data, error }
     }
 
-    pub fn data_de_failed_with_versions(data: Vec<u8>, error: SerdeJsonError, versions: Versions) -> Self {
+    pub fn data_de_failed_with_versions(data: Vec<u8>, error: DataDeserializationError, versions: Versions) -> Self {
         Self::DataDeFailedWithVersions { data, error, versions }
     }
 
@@ -105,4 +119,32 @@ impl PreparationError {
             required_version,
         }
     }
+
+    pub fn cid_store_verification_failed(error: CidInfoVerificationError) -> Self {
+        Self::CidStoreVerificationFailed(error)
+    }
+
+    pub fn particle_expired(deadline: u64, current_time: u64) -> Self {
+        Self::ParticleExpired { deadline, current_time }
+    }
+}
+
+/// Besides preparation errors, supplied data could be refused with an uncatchable error,
+/// f.e. if it fails signature verification.
+#[derive(Debug, ThisError)]
+pub(crate) enum PreparationFailure {
+    #[error(transparent)]
+    Preparation(#[from] PreparationError),
+
+    #[error(transparent)]
+    Uncatchable(#[from] UncatchableError),
+}
+
+impl ToErrorCode for PreparationFailure {
+    fn to_error_code(&self) -> i64 {
+        match self {
+            Self::Preparation(error) => error.to_error_code(),
+            Self::Uncatchable(error) => error.to_error_code(),
+        }
+    }
 }
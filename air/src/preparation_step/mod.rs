@@ -20,6 +20,8 @@ mod preparation;
 
 pub use errors::PreparationError;
 
+pub(crate) use errors::PreparationFailure;
+
 pub(crate) use preparation::prepare;
 pub(crate) use preparation::PreparationDescriptor;
 
@@ -15,12 +15,17 @@
  */
 
 use super::PreparationError;
+use super::PreparationFailure;
 use crate::execution_step::execution_context::ExecCtxIngredients;
 use crate::execution_step::ExecutionCtx;
 use crate::execution_step::TraceHandler;
+use crate::UncatchableError;
 
+use air_interpreter_data::DataDeserializationError;
+use air_interpreter_data::DataFormat;
 use air_interpreter_data::InterpreterData;
 use air_interpreter_interface::RunParameters;
+use air_interpreter_signatures::PeerIdVerifier;
 use air_parser::ast::Instruction;
 
 type PreparationResult<T> = Result<T, PreparationError>;
@@ -33,6 +38,7 @@ pub(crate) struct PreparationDescriptor<'ctx, 'i> {
 }
 
 /// Parse and prepare supplied data and AIR script.
+#[allow(clippy::result_large_err)]
 #[tracing::instrument(skip_all)]
 pub(crate) fn prepare<'i>(
     prev_data: &[u8],
@@ -40,11 +46,16 @@ pub(crate) fn prepare<'i>(
     raw_air: &'i str,
     call_results: &[u8],
     run_parameters: RunParameters,
-) -> PreparationResult<PreparationDescriptor<'static, 'i>> {
+) -> Result<PreparationDescriptor<'i, 'i>, PreparationFailure> {
+    let data_format = result_data_format(prev_data, current_data);
     let prev_data = try_to_data(prev_data)?;
     let current_data = try_to_data(current_data)?;
 
     check_version_compatibility(&current_data)?;
+    #[cfg(feature = "check_cid_stores")]
+    check_cid_stores(&current_data)?;
+
+    check_signatures(&current_data, run_parameters.require_signatures)?;
 
     let air: Instruction<'i> = *air_parser::parse(raw_air).map_err(PreparationError::AIRParseError)?;
 
@@ -53,6 +64,8 @@ pub(crate) fn prepare<'i>(
         last_call_request_id: prev_data.last_call_request_id,
         restricted_streams: prev_data.restricted_streams,
         cid_info: prev_data.cid_info,
+        signatures: prev_data.signatures,
+        data_version: prev_data.versions.data_version,
     };
 
     let current_ingredients = ExecCtxIngredients {
@@ -60,9 +73,20 @@ pub(crate) fn prepare<'i>(
         last_call_request_id: current_data.last_call_request_id,
         restricted_streams: current_data.restricted_streams,
         cid_info: current_data.cid_info,
+        signatures: current_data.signatures,
+        data_version: current_data.versions.data_version,
     };
 
-    let exec_ctx = make_exec_ctx(prev_ingredients, current_ingredients, call_results, run_parameters)?;
+    let exec_ctx = make_exec_ctx(
+        prev_ingredients,
+        current_ingredients,
+        call_results,
+        run_parameters,
+        data_format,
+        raw_air,
+    )?;
+    check_particle_ttl(&exec_ctx)?;
+
     let trace_handler = TraceHandler::from_trace(prev_data.trace, current_data.trace);
 
     let result = PreparationDescriptor {
@@ -84,7 +108,7 @@ fn try_to_data(raw_data: &[u8]) -> PreparationResult<InterpreterData> {
     InterpreterData::try_from_slice(raw_data).map_err(|de_error| to_date_de_error(raw_data.to_vec(), de_error))
 }
 
-fn to_date_de_error(raw_data: Vec<u8>, de_error: serde_json::Error) -> PreparationError {
+fn to_date_de_error(raw_data: Vec<u8>, de_error: DataDeserializationError) -> PreparationError {
     match InterpreterData::try_get_versions(&raw_data) {
         Ok(versions) => PreparationError::data_de_failed_with_versions(raw_data, de_error, versions),
         Err(_) => PreparationError::data_de_failed(raw_data, de_error),
@@ -92,19 +116,38 @@ fn to_date_de_error(raw_data: Vec<u8>, de_error: serde_json::Error) -> Preparati
 }
 
 #[tracing::instrument(skip_all)]
-fn make_exec_ctx(
+fn make_exec_ctx<'i>(
     prev_ingredients: ExecCtxIngredients,
     current_ingredients: ExecCtxIngredients,
     call_results: &[u8],
     run_parameters: RunParameters,
-) -> PreparationResult<ExecutionCtx<'static>> {
+    data_format: DataFormat,
+    air_script: &'i str,
+) -> PreparationResult<ExecutionCtx<'i>> {
     let call_results = serde_json::from_slice(call_results)
         .map_err(|e| PreparationError::call_results_de_failed(call_results.to_vec(), e))?;
 
-    let ctx = ExecutionCtx::new(prev_ingredients, current_ingredients, call_results, run_parameters);
+    let ctx = ExecutionCtx::new(
+        prev_ingredients,
+        current_ingredients,
+        call_results,
+        run_parameters,
+        data_format,
+        air_script,
+    );
     Ok(ctx)
 }
 
+/// The result data is serialized in the same format as the incoming data,
+/// so a peer keeps the format chosen by a particle sender.
+fn result_data_format(prev_data: &[u8], current_data: &[u8]) -> DataFormat {
+    [current_data, prev_data]
+        .iter()
+        .filter(|data| !data.is_empty())
+        .find_map(|data| DataFormat::detect(data).ok())
+        .unwrap_or_default()
+}
+
 fn check_version_compatibility(data: &InterpreterData) -> PreparationResult<()> {
     if &data.versions.interpreter_version < super::min_supported_version() {
         return Err(PreparationError::UnsupportedInterpreterVersion {
@@ -115,3 +158,38 @@ fn check_version_compatibility(data: &InterpreterData) -> PreparationResult<()>
 
     Ok(())
 }
+
+/// An expired particle is refused, unless a host brings results of services called before
+/// the deadline, they are applied to the trace, but new services won't be called.
+fn check_particle_ttl(exec_ctx: &ExecutionCtx<'_>) -> PreparationResult<()> {
+    let run_parameters = &exec_ctx.run_parameters;
+    if exec_ctx.call_results.is_empty() && run_parameters.is_particle_expired() {
+        return Err(PreparationError::particle_expired(
+            run_parameters.particle_deadline(),
+            run_parameters.current_time,
+        ));
+    }
+
+    Ok(())
+}
+
+/// Checks that call results from current data are signed by peers that produced them,
+/// public keys are extracted from peer ids. A host that signs results of a current peer
+/// requires all incoming results to be signed.
+#[allow(clippy::result_large_err)]
+#[tracing::instrument(skip_all)]
+fn check_signatures(data: &InterpreterData, require_signatures: bool) -> Result<(), UncatchableError> {
+    data.signatures
+        .verify(&data.peer_contributions(), &PeerIdVerifier, require_signatures)
+        .map_err(UncatchableError::SignatureVerificationFailed)
+}
+
+/// Current data comes from other peers, so it's checked that its CID stores weren't forged,
+/// prev data is produced by this peer and treated as trusted.
+#[cfg(feature = "check_cid_stores")]
+#[tracing::instrument(skip_all)]
+fn check_cid_stores(data: &InterpreterData) -> PreparationResult<()> {
+    data.cid_info
+        .verify(&data.versions.data_version)
+        .map_err(PreparationError::cid_store_verification_failed)
+}
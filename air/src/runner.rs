@@ -15,12 +15,14 @@
  */
 
 use crate::execution_step::ExecutableInstruction;
+use crate::execution_step::ExecutionObserver;
 use crate::farewell_step as farewell;
 use crate::preparation_step::prepare;
 use crate::preparation_step::PreparationDescriptor;
 
 use air_interpreter_interface::InterpreterOutcome;
 use air_interpreter_interface::RunParameters;
+use air_interpreter_signatures::Signer;
 use air_log_targets::RUN_PARAMS;
 use air_utils::measure;
 
@@ -44,7 +46,38 @@ pub fn execute_air(
         params.current_peer_id,
     );
 
-    execute_air_impl(air, prev_data, data, params, call_results).unwrap_or_else(identity)
+    execute_air_impl(air, prev_data, data, params, call_results, None, None).unwrap_or_else(identity)
+}
+
+/// Same as `execute_air`, but the observer receives control before every instruction.
+#[tracing::instrument(skip_all)]
+pub fn execute_air_with_observer(
+    air: String,
+    prev_data: Vec<u8>,
+    data: Vec<u8>,
+    params: RunParameters,
+    call_results: Vec<u8>,
+    observer: Box<dyn ExecutionObserver>,
+) -> InterpreterOutcome {
+    use std::convert::identity;
+
+    execute_air_impl(air, prev_data, data, params, call_results, Some(observer), None).unwrap_or_else(identity)
+}
+
+/// Same as `execute_air`, but call results of a current peer are signed with the supplied signer
+/// provided by a host, so its secret key never enters the interpreter.
+#[tracing::instrument(skip_all)]
+pub fn execute_air_with_signer(
+    air: String,
+    prev_data: Vec<u8>,
+    data: Vec<u8>,
+    params: RunParameters,
+    call_results: Vec<u8>,
+    signer: Box<dyn Signer>,
+) -> InterpreterOutcome {
+    use std::convert::identity;
+
+    execute_air_impl(air, prev_data, data, params, call_results, None, Some(signer)).unwrap_or_else(identity)
 }
 
 #[allow(clippy::result_large_err)]
@@ -54,6 +87,8 @@ fn execute_air_impl(
     data: Vec<u8>,
     params: RunParameters,
     call_results: Vec<u8>,
+    observer: Option<Box<dyn ExecutionObserver>>,
+    signer: Option<Box<dyn Signer>>,
 ) -> Result<InterpreterOutcome, InterpreterOutcome> {
     let PreparationDescriptor {
         mut exec_ctx,
@@ -62,8 +97,10 @@ fn execute_air_impl(
     } = match prepare(&prev_data, &data, air.as_str(), &call_results, params) {
         Ok(descriptor) => descriptor,
         // return the prev data in case of errors
-        Err(error) => return Err(farewell::from_uncatchable_error(prev_data, error)),
+        Err(error) => return Err(farewell::from_uncatchable_error(prev_data, error, None, None)),
     };
+    exec_ctx.observer = observer;
+    exec_ctx.signer = signer;
 
     // match here is used instead of map_err, because the compiler can't determine that
     // they are exclusive and would treat exec_ctx and trace_handler as moved
@@ -77,6 +114,15 @@ fn execute_air_impl(
         // return new collected trace in case of errors
         Err(error) if error.is_catchable() => Err(farewell::from_execution_error(exec_ctx, trace_handler, error)),
         // return the prev data in case of any trace errors
-        Err(error) => Err(farewell::from_uncatchable_error(prev_data, error)),
+        Err(error) => {
+            let position = exec_ctx.error_position();
+            let merge_conflict = error.merge_conflict().cloned();
+            Err(farewell::from_uncatchable_error(
+                prev_data,
+                error,
+                position,
+                merge_conflict.as_ref(),
+            ))
+        }
     }
 }
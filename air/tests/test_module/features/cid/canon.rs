@@ -304,6 +304,10 @@ fn test_canon_tetraplet_not_found() {
     fake_tetraplet_tracker
         .record_value(SecurityTetraplet::literal_tetraplet(init_peer_id))
         .unwrap();
+    // the call result tetraplet is kept, it's checked before the canon one
+    fake_tetraplet_tracker
+        .record_value(SecurityTetraplet::new("peer_1", "serv..0", "func", ""))
+        .unwrap();
 
     cid_state.tetraplet_tracker = fake_tetraplet_tracker;
     let cur_data = raw_data_from_trace_with_canon(trace, cid_state);
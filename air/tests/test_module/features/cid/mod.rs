@@ -200,3 +200,70 @@ fn test_unused_cid() {
     assert!(data.cid_info.tetraplet_store.is_empty());
     assert!(data.cid_info.service_result_store.is_empty());
 }
+
+#[test]
+fn test_forged_cid_store() {
+    use air::PreparationError;
+
+    let peer_id = "peer_id";
+    let mut vm = create_avm(echo_call_service(), peer_id);
+
+    let air_script = r#"(call "peer_id" ("service" "call1") [] x)"#;
+    let mut cid_state = ExecutionCidState::new();
+    let trace = vec![scalar_tracked!(
+        42,
+        cid_state,
+        peer = peer_id,
+        service = "service",
+        function = "call1"
+    )];
+    let cur_data = raw_data_from_trace(trace, cid_state);
+
+    let mut forged_data: JValue = serde_json::from_slice(&cur_data).unwrap();
+    for (_, value) in forged_data["cid_info"]["value_store"].as_object_mut().unwrap() {
+        *value = json!(43);
+    }
+    let forged_data = serde_json::to_vec(&forged_data).unwrap();
+
+    let result = call_vm!(vm, <_>::default(), air_script, vec![], forged_data.clone());
+    let forged_interpreter_data = InterpreterData::try_from_slice(&forged_data).unwrap();
+    let verification_error = forged_interpreter_data
+        .cid_info
+        .verify(&forged_interpreter_data.versions.data_version)
+        .unwrap_err();
+    assert_eq!(verification_error.store_name, "value");
+
+    let expected_error = PreparationError::CidStoreVerificationFailed(verification_error);
+    assert!(check_error(&result, expected_error), "{:?}", result);
+}
+
+#[test]
+fn test_unreferenced_cids_are_dropped() {
+    let peer_id = "peer_id";
+    let mut vm = create_avm(set_variable_call_service(json!(1)), peer_id);
+
+    let air_script = r#"
+       (seq
+          (call "peer_id" ("service" "call1") [] x)
+          (call "peer_id" ("service" "call2") [] y))"#;
+    let mut cid_state = ExecutionCidState::new();
+    let trace = vec![scalar_tracked!(
+        42,
+        cid_state,
+        peer = peer_id,
+        service = "service",
+        function = "call1"
+    )];
+    let orphaned_value_cid = cid_state.value_tracker.record_value(json!("orphaned")).unwrap();
+    let orphaned_agg_cid = simple_value_aggregate_cid("orphaned", &mut cid_state);
+
+    let cur_data = raw_data_from_trace(trace, cid_state);
+    let result = checked_call_vm!(vm, <_>::default(), air_script, vec![], cur_data);
+    let data = data_from_result(&result);
+
+    assert_eq!(data.trace.len(), 2);
+    assert_eq!(data.cid_info.value_store.len(), 2);
+    assert_eq!(data.cid_info.service_result_store.len(), 2);
+    assert!(data.cid_info.value_store.get(&orphaned_value_cid).is_none());
+    assert!(data.cid_info.service_result_store.get(&orphaned_agg_cid).is_none());
+}
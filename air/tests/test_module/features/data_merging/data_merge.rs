@@ -388,6 +388,7 @@ fn test_merge_scalar_mismatch() {
 
     let result = avm.call(air, data1, data2, <_>::default()).unwrap();
     assert_eq!(result.ret_code, 20000);
+    assert!(result.merge_conflict.is_some(), "{}", result.error_message);
     assert_eq!(
         result.error_message,
         concat!(
@@ -439,6 +440,7 @@ fn test_merge_stream_mismatch() {
 
     let result = avm.call(air, data1, data2, <_>::default()).unwrap();
     assert_eq!(result.ret_code, 20000);
+    assert!(result.merge_conflict.is_some(), "{}", result.error_message);
     assert_eq!(
         result.error_message,
         concat!(
@@ -474,6 +476,7 @@ fn test_merge_unused_mismatch() {
 
     let result = avm.call(air, data1, data2, <_>::default()).unwrap();
     assert_eq!(result.ret_code, 20000);
+    assert!(result.merge_conflict.is_some(), "{}", result.error_message);
     assert_eq!(
         result.error_message,
         concat!(
@@ -484,3 +487,35 @@ fn test_merge_unused_mismatch() {
         )
     );
 }
+
+#[test]
+fn binary_data_format_is_preserved() {
+    use air_interpreter_data::DataFormat;
+
+    let vm_1_peer_id = "vm_1_peer_id";
+    let vm_2_peer_id = "vm_2_peer_id";
+    let mut vm1 = create_avm(echo_call_service(), vm_1_peer_id);
+    let mut vm2 = create_avm(echo_call_service(), vm_2_peer_id);
+    let mut vm2_json = create_avm(echo_call_service(), vm_2_peer_id);
+
+    let script = f!(r#"
+        (seq
+            (call "{vm_1_peer_id}" ("" "") ["value_1"] $stream)
+            (call "{vm_2_peer_id}" ("" "") ["value_2"] $stream)
+        )
+        "#);
+
+    let result_1 = checked_call_vm!(vm1, <_>::default(), &script, "", "");
+    let json_data = InterpreterData::try_from_slice(&result_1.data).expect("data should be well-formed");
+    let binary_data = json_data
+        .serialize_with_format(DataFormat::MsgPack)
+        .expect("data should be serializable");
+
+    let result_2 = checked_call_vm!(vm2, <_>::default(), &script, "", binary_data);
+    assert_eq!(DataFormat::detect(&result_2.data).unwrap(), DataFormat::MsgPack);
+
+    let binary_trace = trace_from_result(&result_2);
+    let json_result_2 = checked_call_vm!(vm2_json, <_>::default(), &script, "", result_1.data);
+    assert_eq!(DataFormat::detect(&json_result_2.data).unwrap(), DataFormat::Json);
+    assert_eq!(binary_trace, trace_from_result(&json_result_2));
+}
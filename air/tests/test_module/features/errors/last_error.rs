@@ -259,6 +259,7 @@ fn access_last_error_by_not_exists_field() {
                 "instruction": r#"call "fallible_peer_id" ("fallible_call_service" "") [""] "#,
                 "message": r#"Local service error, ret_code is 1, error message is '"failed result from fallible_call_service"'"#,
                 "peer_id": "fallible_peer_id",
+                "position": {"line": 3, "column": 13},
             }),
             field_name: non_exists_field_name.to_string()
         }
@@ -294,7 +295,8 @@ fn last_error_with_par_one_subgraph_failed() {
         "error_code": 10000i64,
         "instruction": r#"call "fallible_peer_id" ("fallible_call_service" "") [""] "#,
         "message": r#"Local service error, ret_code is 1, error message is '"failed result from fallible_call_service"'"#,
-        "peer_id": fallible_peer_id
+        "peer_id": fallible_peer_id,
+        "position": {"line": 4, "column": 17},
     });
     assert_eq!(actual_value, expected_value);
 }
@@ -322,6 +324,7 @@ fn fail_with_scalar_rebubble_error() {
             "instruction": r#"call "fallible_peer_id" ("fallible_call_service" "") [""] "#,
             "message": r#"Local service error, ret_code is 1, error message is '"failed result from fallible_call_service"'"#,
             "peer_id": "fallible_peer_id",
+            "position": {"line": 3, "column": 13},
         })),
     };
     assert!(check_error(&result, expected_error));
@@ -464,3 +467,45 @@ fn last_error_with_match() {
     let trace = trace_from_result(&result);
     assert_eq!(trace.len(), 2); // if match works there will be 2 calls in a resulted trace
 }
+
+#[test]
+fn last_error_contains_position() {
+    let local_peer_id = "local_peer_id";
+    let args = Rc::new(RefCell::new(None));
+    let tetraplets = Rc::new(RefCell::new(None));
+    let mut vm = create_avm(create_check_service_closure(args.clone(), tetraplets), local_peer_id);
+
+    let script = f!(r#"
+        (xor
+            (seq
+                (null)
+                (fail 1337 "error message"))
+            (call "{local_peer_id}" ("" "") [%last_error%.$.position]))
+    "#);
+
+    let _ = checked_call_vm!(vm, <_>::default(), &script, "", "");
+
+    let actual_value = (*args.borrow()).as_ref().unwrap().clone();
+    assert_eq!(actual_value, json!({"line": 5, "column": 17}));
+}
+
+#[test]
+fn error_position_is_returned_separately() {
+    let vm_peer_id = "vm_peer_id";
+    let mut vm = create_avm(echo_call_service(), vm_peer_id);
+
+    let script = r#"
+        (seq
+            (null)
+            (fail 1337 "error message"))
+    "#;
+
+    let result = call_vm!(vm, <_>::default(), script, "", "");
+
+    assert!(
+        result.error_message.starts_with("fail with"),
+        "{}",
+        result.error_message
+    );
+    assert_eq!(result.error_position, Some(ErrorPosition::new(4, 13)));
+}
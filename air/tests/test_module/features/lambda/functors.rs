@@ -15,6 +15,7 @@
  */
 
 use air::CatchableError;
+use air::LambdaError;
 use air_test_framework::AirScriptExecutor;
 use air_test_utils::prelude::*;
 
@@ -239,3 +240,183 @@ fn functor_dont_influence_tetraplet() {
     ];
     assert_eq!(actual_trace, expected_trace);
 }
+
+#[test]
+fn keys_and_contains_key_functors_for_object_scalar() {
+    let set_variable_peer_id = "set_variable_peer_id";
+    let object = json!({"a": 1, "b": 2});
+    let mut set_variable_vm = create_avm(set_variable_call_service(object.clone()), set_variable_peer_id);
+
+    let peer_id = "peer_id";
+    let mut vm = create_avm(echo_call_service(), peer_id);
+
+    let script = f!(r#"
+        (seq
+            (call "{set_variable_peer_id}" ("" "") [] object)
+            (seq
+                (call "{peer_id}" ("" "") [object.keys])
+                (seq
+                    (call "{peer_id}" ("" "") [object.contains_key(a)])
+                    (call "{peer_id}" ("" "") [object.contains_key(c)])
+                )
+            )
+        )
+        "#);
+
+    let result = checked_call_vm!(set_variable_vm, <_>::default(), &script, "", "");
+    let result = checked_call_vm!(vm, <_>::default(), &script, "", result.data);
+    let actual_trace = trace_from_result(&result);
+
+    let expected_trace = vec![
+        scalar!(object, peer = set_variable_peer_id),
+        unused!(json!(["a", "b"]), peer = peer_id, args = vec![json!(["a", "b"])]),
+        unused!(true, peer = peer_id, args = vec![true]),
+        unused!(false, peer = peer_id, args = vec![false]),
+    ];
+    assert_eq!(actual_trace, expected_trace);
+}
+
+#[test]
+fn last_functor_and_slice_for_canon_stream() {
+    let peer_id = "peer_id";
+    let mut vm = create_avm(echo_call_service(), peer_id);
+
+    let script = f!(r#"
+        (seq
+            (seq
+                (seq
+                    (ap 1 $stream)
+                    (ap 2 $stream))
+                (seq
+                    (ap 3 $stream)
+                    (canon "{peer_id}" $stream #canon_stream)))
+            (seq
+                (seq
+                    (call "{peer_id}" ("" "") [#canon_stream.last])
+                    (call "{peer_id}" ("" "") [#canon_stream.$.[0..2]]))
+                (seq
+                    (call "{peer_id}" ("" "") [#canon_stream.keys])
+                    (call "{peer_id}" ("" "") [#canon_stream.contains_key(2)])))
+        )
+        "#);
+
+    let result = checked_call_vm!(vm, <_>::default(), &script, "", "");
+    let actual_trace = trace_from_result(&result);
+
+    assert_eq!(actual_trace[4.into()], unused!(3, peer = peer_id, args = vec![3]));
+    assert_eq!(
+        actual_trace[5.into()],
+        unused!(json!([1, 2]), peer = peer_id, args = vec![json!([1, 2])])
+    );
+    assert_eq!(
+        actual_trace[6.into()],
+        unused!(json!([0, 1, 2]), peer = peer_id, args = vec![json!([0, 1, 2])])
+    );
+    assert_eq!(actual_trace[7.into()], unused!(true, peer = peer_id, args = vec![true]));
+}
+
+#[test]
+fn array_slice_out_of_bounds() {
+    let peer_id = "peer_id";
+    let mut vm = create_avm(set_variable_call_service(json!([1, 2, 3])), peer_id);
+
+    let script = f!(r#"
+        (seq
+            (call "{peer_id}" ("" "") [] array)
+            (call "{peer_id}" ("" "") [array.$.[1..5]])
+        )
+        "#);
+
+    let result = call_vm!(vm, <_>::default(), &script, "", "");
+    let expected_error = CatchableError::LambdaApplierError(LambdaError::InvalidArraySlice {
+        size: 3,
+        start: 1,
+        end: 5,
+    });
+    assert!(check_error(&result, expected_error));
+}
+
+#[test]
+fn last_functor_records_resolved_index_in_tetraplet() {
+    let set_variable_peer_id = "set_variable_peer_id";
+    let set_variable_peer_result = json!({"field": [1,2,3]});
+    let mut set_variable_vm = create_avm(
+        set_variable_call_service(set_variable_peer_result.clone()),
+        set_variable_peer_id,
+    );
+
+    let tetraplet_catcher_peer_id = "tetraplet_catcher_peer_id";
+    let (call_service, actual_tetraplet) = tetraplet_host_function(echo_call_service());
+    let mut tetraplet_catcher_vm = create_avm(call_service, tetraplet_catcher_peer_id);
+
+    let script = f!(r#"
+        (seq
+            (call "{set_variable_peer_id}" ("" "") [] scalar)
+            (seq
+                (ap scalar.$.field field)
+                (call "{tetraplet_catcher_peer_id}" ("" "") [field.last])
+            )
+        )
+        "#);
+
+    let result = checked_call_vm!(set_variable_vm, <_>::default(), &script, "", "");
+    let result = checked_call_vm!(tetraplet_catcher_vm, <_>::default(), &script, "", result.data);
+    let actual_trace = trace_from_result(&result);
+
+    let expected_tetraplet = RefCell::new(vec![vec![SecurityTetraplet::new(
+        set_variable_peer_id,
+        "",
+        "",
+        ".$.field.$.[2]",
+    )]]);
+    assert_eq!(actual_tetraplet.as_ref(), &expected_tetraplet);
+
+    let expected_trace = vec![
+        scalar!(set_variable_peer_result, peer = set_variable_peer_id),
+        unused!(3, peer = tetraplet_catcher_peer_id, args = vec![3]),
+    ];
+    assert_eq!(actual_trace, expected_trace);
+}
+
+#[test]
+fn array_slice_is_recorded_in_tetraplet() {
+    let set_variable_peer_id = "set_variable_peer_id";
+    let set_variable_peer_result = json!([1, 2, 3]);
+    let mut set_variable_vm = create_avm(
+        set_variable_call_service(set_variable_peer_result.clone()),
+        set_variable_peer_id,
+    );
+
+    let tetraplet_catcher_peer_id = "tetraplet_catcher_peer_id";
+    let (call_service, actual_tetraplet) = tetraplet_host_function(echo_call_service());
+    let mut tetraplet_catcher_vm = create_avm(call_service, tetraplet_catcher_peer_id);
+
+    let script = f!(r#"
+        (seq
+            (call "{set_variable_peer_id}" ("" "") [] array)
+            (call "{tetraplet_catcher_peer_id}" ("" "") [array.$.[1..3]])
+        )
+        "#);
+
+    let result = checked_call_vm!(set_variable_vm, <_>::default(), &script, "", "");
+    let result = checked_call_vm!(tetraplet_catcher_vm, <_>::default(), &script, "", result.data);
+    let actual_trace = trace_from_result(&result);
+
+    let expected_tetraplet = RefCell::new(vec![vec![SecurityTetraplet::new(
+        set_variable_peer_id,
+        "",
+        "",
+        ".$.[1:3]",
+    )]]);
+    assert_eq!(actual_tetraplet.as_ref(), &expected_tetraplet);
+
+    let expected_trace = vec![
+        scalar!(set_variable_peer_result, peer = set_variable_peer_id),
+        unused!(
+            json!([2, 3]),
+            peer = tetraplet_catcher_peer_id,
+            args = vec![json!([2, 3])]
+        ),
+    ];
+    assert_eq!(actual_trace, expected_trace);
+}
@@ -0,0 +1,98 @@
+/*
+ * Copyright 2023 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use air::parser::parse;
+use air::parser::print;
+use air::parser::Instruction;
+use serde_json::Value;
+
+use std::path::Path;
+use std::path::PathBuf;
+
+/// Scripts kept for ignored tests that aren't valid AIR anymore, relative to the tests directory.
+const INVALID_SCRIPTS: &[&str] = &["test_module/integration/scripts/network_explore.air"];
+
+fn collect_scripts(dir: &Path, scripts: &mut Vec<PathBuf>) {
+    for entry in std::fs::read_dir(dir).unwrap() {
+        let path = entry.unwrap().path();
+        if path.is_dir() {
+            collect_scripts(&path, scripts);
+        } else if path.extension().map_or(false, |extension| extension == "air") {
+            scripts.push(path);
+        }
+    }
+}
+
+/// AST serialized to JSON without node positions, they differ for a printed script.
+fn without_positions(ast: &Instruction<'_>) -> Value {
+    fn strip_positions(value: &mut Value) {
+        match value {
+            // spans of tuple-like instructions aren't named
+            Value::Object(fields)
+                if fields.len() == 2 && fields.contains_key("left") && fields.contains_key("right") =>
+            {
+                *value = Value::Null
+            }
+            Value::Object(fields) => {
+                fields.remove("position");
+                fields.remove("span");
+                fields.values_mut().for_each(strip_positions);
+            }
+            Value::Array(items) => items.iter_mut().for_each(strip_positions),
+            _ => {}
+        }
+    }
+
+    let mut value = serde_json::to_value(ast).unwrap();
+    strip_positions(&mut value);
+    value
+}
+
+#[test]
+fn test_scripts_survive_ast_round_trip() {
+    let tests_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests");
+    let mut scripts = vec![];
+    collect_scripts(&tests_dir, &mut scripts);
+    assert!(!scripts.is_empty());
+
+    for path in scripts {
+        let script = std::fs::read_to_string(&path).unwrap();
+        let is_invalid = INVALID_SCRIPTS.iter().any(|invalid| path.ends_with(invalid));
+        let ast = match parse(&script) {
+            Ok(ast) => ast,
+            Err(_) if is_invalid => continue,
+            Err(error) => panic!("{}: {}", path.display(), error),
+        };
+        assert!(
+            !is_invalid,
+            "{} is valid, remove it from INVALID_SCRIPTS",
+            path.display()
+        );
+
+        let json = serde_json::to_string(&ast).unwrap();
+        let deserialized: Box<Instruction<'_>> = serde_json::from_str(&json).unwrap();
+        assert_eq!(deserialized, ast, "{}", path.display());
+
+        let printed = print(&ast);
+        let reparsed = parse(&printed).unwrap_or_else(|error| panic!("{}: {}", path.display(), error));
+        assert_eq!(
+            without_positions(&reparsed),
+            without_positions(&ast),
+            "{}",
+            path.display()
+        );
+    }
+}
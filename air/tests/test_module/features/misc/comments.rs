@@ -0,0 +1,57 @@
+/*
+ * Copyright 2023 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use air_test_utils::prelude::*;
+
+#[test]
+fn comments_dont_affect_execution() {
+    let vm_peer_id = "vm_peer_id";
+
+    let commented_script = f!(r#"
+        ;; generated from main.aqua
+        (seq
+            (call "{vm_peer_id}" ("" "") [] iterable) ; source
+            (fold iterable i
+                (par
+                    (call i ("" "") []) ;; remote
+                    ;; next is commented, but still makes par incomplete
+                    (next i)
+                )
+            )
+        ) ;; end
+        "#);
+    let plain_script = f!(r#"
+        (seq
+            (call "{vm_peer_id}" ("" "") [] iterable)
+            (fold iterable i
+                (par
+                    (call i ("" "") [])
+                    (next i)
+                )
+            )
+        )
+        "#);
+
+    let mut commented_vm = create_avm(set_variable_call_service(json!(["peer_a", "peer_b"])), vm_peer_id);
+    let commented_result = checked_call_vm!(commented_vm, <_>::default(), commented_script, "", "");
+
+    let mut plain_vm = create_avm(set_variable_call_service(json!(["peer_a", "peer_b"])), vm_peer_id);
+    let plain_result = checked_call_vm!(plain_vm, <_>::default(), plain_script, "", "");
+
+    assert_eq!(trace_from_result(&commented_result), trace_from_result(&plain_result));
+    assert_next_pks!(&commented_result.next_peer_pks, &["peer_a", "peer_b"]);
+    assert_next_pks!(&plain_result.next_peer_pks, &["peer_a", "peer_b"]);
+}
@@ -0,0 +1,83 @@
+/*
+ * Copyright 2023 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use air_interpreter_interface::RunParameters;
+use air_test_utils::prelude::*;
+
+fn run_with_stats(script: &str, prev_data: Vec<u8>, call_results: CallResults) -> RawAVMOutcome {
+    let vm_peer_id = "vm_peer_id";
+    let run_parameters = RunParameters {
+        collect_stats: true,
+        ..RunParameters::new(vm_peer_id.to_string(), vm_peer_id.to_string(), 0, 0)
+    };
+    let call_results = serde_json::to_vec(&into_raw_result(call_results)).unwrap();
+
+    let outcome = air::execute_air(script.to_string(), prev_data, vec![], run_parameters, call_results);
+    RawAVMOutcome::from_interpreter_outcome(outcome).unwrap()
+}
+
+#[test]
+fn stats_are_collected_on_request() {
+    let script = r#"
+        (seq
+            (par
+                (ap 1 $stream)
+                (call "other_peer_id" ("service" "f") []))
+            (call "vm_peer_id" ("service" "g") [] result))
+    "#;
+
+    let result = run_with_stats(script, vec![], CallResults::new());
+    assert_eq!(result.ret_code, 0, "{}", result.error_message);
+    let stats = result.stats.as_ref().expect("stats were requested");
+
+    assert_eq!(stats.instructions.ap_seen_count, 1);
+    assert_eq!(stats.instructions.ap_executed_count, 1);
+    assert_eq!(stats.instructions.par_seen_count, 1);
+    assert_eq!(stats.instructions.call_seen_count, 2);
+    assert_eq!(stats.instructions.call_executed_count, 0);
+    assert_eq!(
+        stats.trace,
+        TraceStats {
+            prev_len: 0,
+            current_len: 0,
+            result_len: 4,
+        }
+    );
+    assert_eq!(stats.data_size, result.data.len() as u64);
+
+    let call_results = maplit::hashmap! {
+        1 => CallServiceResult::ok(json!("result")),
+    };
+    let result = run_with_stats(script, result.data, call_results);
+    assert_eq!(result.ret_code, 0, "{}", result.error_message);
+    let stats = result.stats.expect("stats were requested");
+
+    assert_eq!(stats.instructions.ap_executed_count, 0);
+    assert_eq!(stats.instructions.call_executed_count, 1);
+    assert_eq!(stats.trace.prev_len, 4);
+    assert_eq!(stats.trace.result_len, 4);
+    assert_eq!(stats.cid_stores.service_result_count, 1);
+    assert_eq!(stats.data_size, result.data.len() as u64);
+}
+
+#[test]
+fn stats_are_absent_by_default() {
+    let vm_peer_id = "vm_peer_id";
+    let mut vm = create_avm(echo_call_service(), vm_peer_id);
+
+    let result = checked_call_vm!(vm, <_>::default(), "(null)", "", "");
+    assert!(result.stats.is_none());
+}
@@ -14,5 +14,9 @@
  * limitations under the License.
  */
 
+mod ast_round_trip;
+mod comments;
 mod empty_array;
+mod execution_stats;
+mod observer;
 mod version_check;
@@ -0,0 +1,91 @@
+/*
+ * Copyright 2023 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use air::ExecutionObserver;
+use air::ExecutionStep;
+use air_interpreter_interface::CallResults;
+use air_interpreter_interface::RunParameters;
+use air_parser::SourcePosition;
+use air_test_utils::prelude::*;
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+struct Step {
+    position: SourcePosition,
+    result_trace_pos: usize,
+    context: String,
+}
+
+struct StepsCollector(Rc<RefCell<Vec<Step>>>);
+
+impl ExecutionObserver for StepsCollector {
+    fn before_instruction(&mut self, step: &ExecutionStep<'_, '_>) {
+        assert_eq!(step.trace_positions(), (0.into(), 0.into()));
+
+        self.0.borrow_mut().push(Step {
+            position: step.position().unwrap(),
+            result_trace_pos: step.result_trace_pos().into(),
+            context: step.context(),
+        });
+    }
+}
+
+#[test]
+fn observer_is_called_before_every_instruction() {
+    let vm_peer_id = "vm_peer_id";
+    let script = f!(r#"
+        (seq
+            (ap 1 $stream)
+            (seq
+                (ap 1 scalar)
+                ;; comments aren't instructions
+                (call "{vm_peer_id}" ("service" "function") [scalar])))
+        "#);
+
+    let steps = Rc::new(RefCell::new(vec![]));
+    let run_parameters = RunParameters::new(vm_peer_id.to_string(), vm_peer_id.to_string(), 0, 0);
+    let call_results = serde_json::to_vec(&CallResults::new()).unwrap();
+
+    let outcome = air::execute_air_with_observer(
+        script,
+        vec![],
+        vec![],
+        run_parameters,
+        call_results,
+        Box::new(StepsCollector(steps.clone())),
+    );
+    assert_eq!(outcome.ret_code, 0, "{:?}", outcome.error_message);
+
+    let steps = steps.borrow();
+    let positions = steps
+        .iter()
+        .map(|step| (step.position, step.result_trace_pos))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        positions,
+        vec![
+            (SourcePosition::new(2, 9), 0),
+            (SourcePosition::new(3, 13), 0),
+            (SourcePosition::new(4, 13), 1),
+            (SourcePosition::new(5, 17), 1),
+            (SourcePosition::new(7, 17), 1),
+        ]
+    );
+
+    assert!(!steps[3].context.contains("scalar: "), "{}", steps[3].context);
+    assert!(steps[4].context.contains("scalar: "), "{}", steps[4].context);
+}
@@ -0,0 +1,193 @@
+/*
+ * Copyright 2023 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use air::interpreter_signatures::KeyError;
+use air::interpreter_signatures::SignatureVerificationError;
+use air::ExecutionCidState;
+use air::UncatchableError;
+use air_interpreter_data::CallResult;
+use air_interpreter_data::ExecutedState;
+use air_interpreter_data::ValueRef;
+use air_test_utils::prelude::*;
+
+fn make_key_pair(seed: u8) -> KeyPair {
+    KeyPair::from_secret_key_bytes(&[seed; 32]).expect("any 32 bytes are a valid secret key")
+}
+
+#[test]
+fn signed_results_verified_on_next_peer() {
+    let key_pair_1 = make_key_pair(1);
+    let key_pair_2 = make_key_pair(2);
+    let peer_id_1 = key_pair_1.peer_id();
+    let peer_id_2 = key_pair_2.peer_id();
+    let mut vm_1 = create_avm_with_key_pair(echo_call_service(), key_pair_1);
+    let mut vm_2 = create_avm_with_key_pair(echo_call_service(), key_pair_2);
+
+    let air_script = f!(r#"
+        (seq
+            (call "{peer_id_1}" ("service" "func") [1] x)
+            (call "{peer_id_2}" ("service" "func") [x] y))"#);
+
+    let result_1 = checked_call_vm!(vm_1, <_>::default(), &air_script, "", "");
+    let data_1 = data_from_result(&result_1);
+    assert_eq!(data_1.signatures.get(&peer_id_1).map(<[_]>::len), Some(1));
+
+    let result_2 = checked_call_vm!(vm_2, <_>::default(), &air_script, "", result_1.data);
+    let data_2 = data_from_result(&result_2);
+    assert_eq!(data_2.signatures.get(&peer_id_1), data_1.signatures.get(&peer_id_1));
+    assert_eq!(data_2.signatures.get(&peer_id_2).map(<[_]>::len), Some(1));
+}
+
+#[test]
+fn forged_call_result_rejected() {
+    let peer_id_1 = make_key_pair(1).peer_id();
+    let key_pair_2 = make_key_pair(2);
+    let peer_id_2 = key_pair_2.peer_id();
+    let mut vm_2 = create_avm_with_key_pair(echo_call_service(), key_pair_2);
+
+    let air_script = f!(r#"
+        (seq
+            (call "{peer_id_1}" ("service" "func") [1] x)
+            (call "{peer_id_2}" ("service" "func") [x]))"#);
+
+    // a result attributed to the first peer, but not signed by it
+    let mut cid_state = ExecutionCidState::new();
+    let trace = vec![scalar_tracked!(
+        "forged",
+        cid_state,
+        peer = &peer_id_1,
+        service = "service",
+        function = "func",
+        args = vec![1]
+    )];
+    let forged_cid = match &trace[0] {
+        ExecutedState::Call(CallResult::Executed(ValueRef::Scalar(cid))) => cid.as_str().to_string(),
+        state => panic!("unexpected state {:?}", state),
+    };
+    let forged_data = raw_data_from_trace(trace, cid_state);
+
+    let result = call_vm!(vm_2, <_>::default(), &air_script, "", forged_data);
+    let expected_error =
+        UncatchableError::SignatureVerificationFailed(SignatureVerificationError::UnsignedContribution {
+            peer_id: peer_id_1,
+            cid: forged_cid,
+        });
+    assert!(check_error(&result, expected_error), "{:?}", result);
+}
+
+#[test]
+fn result_attributed_to_another_peer_rejected() {
+    let key_pair_1 = make_key_pair(1);
+    let peer_id_1 = key_pair_1.peer_id();
+    let peer_id_2 = make_key_pair(2).peer_id();
+    let key_pair_3 = make_key_pair(3);
+    let peer_id_3 = key_pair_3.peer_id();
+    let mut vm_3 = create_avm_with_key_pair(echo_call_service(), key_pair_3);
+
+    let air_script = f!(r#"
+        (seq
+            (call "{peer_id_2}" ("service" "func") [1] x)
+            (call "{peer_id_3}" ("service" "func") [x]))"#);
+
+    // the first peer puts a result of the call on the second peer attributed to itself,
+    // so its own valid signature covers it
+    let mut cid_state = ExecutionCidState::new();
+    let trace = vec![scalar_tracked!(
+        "forged",
+        cid_state,
+        peer = &peer_id_1,
+        service = "service",
+        function = "func",
+        args = vec![1]
+    )];
+    let forged_data = raw_data_from_trace(trace, cid_state);
+    let mut forged_data = InterpreterData::try_from_slice(&forged_data).unwrap();
+    forged_data.sign_own_contributions(&key_pair_1);
+    let forged_data = forged_data.serialize_with_format(<_>::default()).unwrap();
+
+    let result = call_vm!(vm_3, <_>::default(), &air_script, "", forged_data);
+    let expected_error = UncatchableError::CallResultAuthorMismatch {
+        call_peer_id: peer_id_2,
+        result_peer_id: peer_id_1,
+    };
+    assert!(check_error(&result, expected_error), "{:?}", result);
+}
+
+#[test]
+fn signature_of_keyless_peer_id_rejected() {
+    // peer ids of RSA keys are hashes, so their signatures can't be verified
+    let peer_id_1 = "QmYyQSo1c1Ym7orWxLYvCrM2EmxFTANf8wXmmE7DWjhx5N";
+    let key_pair_2 = make_key_pair(2);
+    let peer_id_2 = key_pair_2.peer_id();
+    let mut vm_2 = create_avm_with_key_pair(echo_call_service(), key_pair_2);
+
+    let air_script = f!(r#"
+        (seq
+            (call "{peer_id_1}" ("service" "func") [1] x)
+            (call "{peer_id_2}" ("service" "func") [x]))"#);
+
+    let mut cid_state = ExecutionCidState::new();
+    let trace = vec![scalar_tracked!(
+        "result",
+        cid_state,
+        peer = peer_id_1,
+        service = "service",
+        function = "func",
+        args = vec![1]
+    )];
+    let data = raw_data_from_trace(trace, cid_state);
+    let mut data = InterpreterData::try_from_slice(&data).unwrap();
+    let cids = data.peer_contributions().remove(peer_id_1).unwrap();
+    data.signatures.sign(cids, &FakeSigner(peer_id_1));
+    let data = data.serialize_with_format(<_>::default()).unwrap();
+
+    let result = call_vm!(vm_2, <_>::default(), &air_script, "", data);
+    let expected_error =
+        UncatchableError::SignatureVerificationFailed(SignatureVerificationError::UnverifiableSignature {
+            peer_id: peer_id_1.to_string(),
+            error: KeyError::KeylessPeerId(peer_id_1.to_string()),
+        });
+    assert!(check_error(&result, expected_error), "{:?}", result);
+}
+
+struct FakeSigner(&'static str);
+
+impl Signer for FakeSigner {
+    fn peer_id(&self) -> String {
+        self.0.to_string()
+    }
+
+    fn sign(&self, _message: &[u8]) -> Vec<u8> {
+        vec![0; 64]
+    }
+}
+
+#[test]
+fn unsigned_data_accepted_without_key_pair() {
+    let peer_id_1 = "peer_id_1";
+    let peer_id_2 = "peer_id_2";
+    let mut vm_1 = create_avm(echo_call_service(), peer_id_1);
+    let mut vm_2 = create_avm(echo_call_service(), peer_id_2);
+
+    let air_script = f!(r#"
+        (seq
+            (call "{peer_id_1}" ("service" "func") [1] x)
+            (call "{peer_id_2}" ("service" "func") [x]))"#);
+
+    let result_1 = checked_call_vm!(vm_1, <_>::default(), &air_script, "", "");
+    let result_2 = checked_call_vm!(vm_2, <_>::default(), &air_script, "", result_1.data);
+    assert!(data_from_result(&result_2).signatures.is_empty());
+}
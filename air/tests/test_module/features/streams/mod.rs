@@ -17,5 +17,6 @@
 mod ap_with_fold;
 mod merging;
 mod recursive_streams;
+mod stream_map;
 mod streams;
 mod streams_early_exit;
@@ -0,0 +1,196 @@
+/*
+ * Copyright 2023 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use air::CatchableError;
+use air::UncatchableError;
+use air_test_utils::prelude::*;
+use air_trace_handler::merger::ApResultError;
+use air_trace_handler::TraceHandlerError::MergeError;
+
+#[test]
+fn canon_stream_map() {
+    let peer_id = "peer_id";
+    let mut vm = create_avm(echo_call_service(), peer_id);
+
+    let script = f!(r#"
+        (seq
+            (seq
+                (ap ("a" 1) %map)
+                (ap ("b" "value") %map))
+            (seq
+                (canon "{peer_id}" %map obj)
+                (call "{peer_id}" ("" "") [obj] result)))
+        "#);
+
+    let result = checked_call_vm!(vm, <_>::default(), &script, "", "");
+    let actual_trace = trace_from_result(&result);
+
+    let expected_object = json!({"a": 1, "b": "value"});
+    let expected_state = scalar!(expected_object.clone(), peer = peer_id, args = [expected_object]);
+    assert_eq!(actual_trace[3.into()], expected_state);
+}
+
+#[test]
+fn stream_map_states_are_marked_in_trace() {
+    let peer_id = "peer_id";
+    let mut vm = create_avm(echo_call_service(), peer_id);
+
+    let script = f!(r#"
+        (seq
+            (ap ("a" 1) %map)
+            (canon "{peer_id}" %map obj))
+        "#);
+
+    let result = checked_call_vm!(vm, <_>::default(), &script, "", "");
+    let actual_trace = trace_from_result(&result);
+
+    assert_eq!(
+        actual_trace[0.into()],
+        ExecutedState::Ap(ApResult::with_kind(0, StreamKind::StreamMap))
+    );
+    match &actual_trace[1.into()] {
+        ExecutedState::Canon(CanonResult { kind, .. }) => assert_eq!(*kind, StreamKind::StreamMap),
+        state => panic!("expected a canon state, got {state:?}"),
+    }
+}
+
+#[test]
+fn canon_stream_map_key_collision() {
+    let peer_id = "peer_id";
+    let mut vm = create_avm(echo_call_service(), peer_id);
+
+    let script = f!(r#"
+        (seq
+            (seq
+                (ap ("a" 1) %map)
+                (seq
+                    (ap ("b" "value") %map)
+                    (ap ("a" 2) %map)))
+            (seq
+                (canon "{peer_id}" %map obj)
+                (call "{peer_id}" ("" "") [obj] result)))
+        "#);
+
+    let result = call_vm!(vm, <_>::default(), &script, "", "");
+
+    let expected_error = CatchableError::StreamMapKeyCollision("a".to_string());
+    assert!(check_error(&result, expected_error));
+}
+
+#[test]
+fn canon_stream_map_number_and_string_keys_collide() {
+    let peer_id = "peer_id";
+    let mut vm = create_avm(echo_call_service(), peer_id);
+
+    let script = f!(r#"
+        (seq
+            (seq
+                (ap (1 "number") %map)
+                (ap ("1" "string") %map))
+            (canon "{peer_id}" %map obj))
+        "#);
+
+    let result = call_vm!(vm, <_>::default(), &script, "", "");
+
+    let expected_error = CatchableError::StreamMapKeyCollision("1".to_string());
+    assert!(check_error(&result, expected_error));
+}
+
+#[test]
+fn stream_ap_state_is_rejected_by_map_ap() {
+    let peer_id = "peer_id";
+    let mut vm = create_avm(echo_call_service(), peer_id);
+
+    let script = r#"(ap ("a" 1) %map)"#;
+    let current_data = raw_data_from_trace(vec![ap(0)], <_>::default());
+
+    let result = call_vm!(vm, <_>::default(), script, "", current_data);
+
+    let expected_error = UncatchableError::TraceError {
+        trace_error: MergeError(air_trace_handler::MergeError::IncorrectApResult(
+            ApResultError::StreamKindMismatch {
+                ap_result: ApResult::new(0),
+                expected_kind: StreamKind::StreamMap,
+            },
+        )),
+        instruction: String::from(r#"ap ("a" 1) %map"#),
+    };
+    assert!(check_error(&result, expected_error));
+}
+
+#[test]
+fn fold_over_stream_map() {
+    let peer_id = "peer_id";
+    let mut vm = create_avm(echo_call_service(), peer_id);
+
+    let script = f!(r#"
+        (seq
+            (seq
+                (ap (1 "one") %map)
+                (ap ("two" 2) %map))
+            (seq
+                (fold %map kv
+                    (seq
+                        (call "{peer_id}" ("" "") [kv.$.key] $keys)
+                        (next kv))
+                    (null))
+                (seq
+                    (canon "{peer_id}" $keys #keys)
+                    (call "{peer_id}" ("" "") [#keys] result))))
+        "#);
+
+    let result = checked_call_vm!(vm, <_>::default(), &script, "", "");
+    let actual_trace = trace_from_result(&result);
+
+    let expected_keys = json!([1, "two"]);
+    let expected_state = scalar!(expected_keys.clone(), peer = peer_id, args = [expected_keys]);
+    assert_eq!(actual_trace[6.into()], expected_state);
+}
+
+#[test]
+fn canon_stream_map_is_the_same_on_all_peers() {
+    let peer_id_1 = "peer_id_1";
+    let mut vm_1 = create_avm(echo_call_service(), peer_id_1);
+    let peer_id_2 = "peer_id_2";
+    let mut vm_2 = create_avm(echo_call_service(), peer_id_2);
+    let peer_id_3 = "peer_id_3";
+    let mut vm_3 = create_avm(echo_call_service(), peer_id_3);
+
+    let script = f!(r#"
+        (seq
+            (par
+                (seq
+                    (call "{peer_id_1}" ("" "") ["value_1"] value_1)
+                    (ap ("key_1" value_1) %map))
+                (seq
+                    (call "{peer_id_2}" ("" "") ["value_2"] value_2)
+                    (ap ("key_2" value_2) %map)))
+            (seq
+                (canon "{peer_id_3}" %map obj)
+                (call "{peer_id_1}" ("" "") [obj] result)))
+        "#);
+
+    let vm_1_result_1 = checked_call_vm!(vm_1, <_>::default(), &script, "", "");
+    let vm_2_result = checked_call_vm!(vm_2, <_>::default(), &script, "", vm_1_result_1.data.clone());
+    let vm_3_result = checked_call_vm!(vm_3, <_>::default(), &script, "", vm_2_result.data);
+    let vm_1_result_2 = checked_call_vm!(vm_1, <_>::default(), &script, vm_1_result_1.data, vm_3_result.data);
+    let actual_trace = trace_from_result(&vm_1_result_2);
+
+    // peer_id_1 doesn't canonicalize the map by itself, but takes the object fixed by peer_id_3
+    let expected_object = json!({"key_1": "value_1", "key_2": "value_2"});
+    let expected_state = scalar!(expected_object.clone(), peer = peer_id_1, args = [expected_object]);
+    assert_eq!(actual_trace[6.into()], expected_state);
+}
@@ -282,7 +282,8 @@ fn fold_early_exit() {
             "error_code": 10000i64,
             "instruction" : r#"call "error_trigger_id" ("error" "") [] "#,
             "message": r#"Local service error, ret_code is 1, error message is '"failed result from fallible_call_service"'"#,
-            "peer_id": "error_trigger_id"
+            "peer_id": "error_trigger_id",
+            "position": {"line": 42, "column": 41},
     });
     let expected_state = unused!(error_value.clone(), peer = last_peer_checker_id, args = [error_value]);
 
@@ -37,6 +37,7 @@ fn fail_with_last_error() {
             "instruction": r#"call "local_peer_id" ("service_id_1" "local_fn_name") [] result_1"#,
             "message": r#"Local service error, ret_code is 1, error message is '"failed result from fallible_call_service"'"#,
             "peer_id": "local_peer_id",
+            "position": {"line": 3, "column": 17},
         })),
     };
     assert!(check_error(&result, expected_error));
@@ -62,6 +63,7 @@ fn fail_with_literals() {
         "instruction": r#"fail 1337 "error message""#,
         "message": "error message",
         "peer_id": test_params.init_peer_id,
+        "position": {"line": 3, "column": 17},
         })),
     };
     assert!(check_error(&result, expected_error));
@@ -16,6 +16,7 @@
 
 use air::CatchableError;
 use air::LambdaError;
+use air::UncatchableError;
 use air_test_utils::prelude::*;
 
 #[test]
@@ -542,3 +543,97 @@ fn canon_stream_not_have_enough_values_call_arg() {
         CatchableError::LambdaApplierError(LambdaError::CanonStreamNotHaveEnoughValues { stream_size: 0, idx: 0 });
     assert!(check_error(&result, expected_error));
 }
+
+#[test]
+fn call_after_particle_deadline() {
+    let peer_id = "peer_id";
+    let script = f!(r#"
+        (seq
+            (call "{peer_id}" ("service" "f") [] x)
+            (call "{peer_id}" ("service" "g") [x]))
+    "#);
+
+    let result = call_with_current_time(&script, vec![], 1050, CallResults::new());
+    assert_eq!(result.call_requests.len(), 1, "{:?}", result);
+    let prev_data = result.data;
+
+    // the service was called before the deadline, but a dead particle can't emit new requests
+    let call_results = maplit::hashmap! {
+        1 => CallServiceResult::ok(json!("result")),
+    };
+    let result = call_with_current_time(&script, prev_data.clone(), 2000, call_results);
+
+    let expected_error = UncatchableError::ParticleExpired {
+        deadline: 1100,
+        current_time: 2000,
+    };
+    assert!(check_error(&result, expected_error), "{:?}", result);
+    assert!(result.call_requests.is_empty());
+    assert_eq!(result.data, prev_data);
+}
+
+#[test]
+fn call_after_particle_deadline_isnt_caught_by_xor() {
+    let peer_id = "peer_id";
+    let script = f!(r#"
+        (seq
+            (call "{peer_id}" ("service" "f") [] x)
+            (xor
+                (call "{peer_id}" ("service" "g") [x])
+                (call "{peer_id}" ("fallback" "h") [%last_error%])))
+    "#);
+
+    let result = call_with_current_time(&script, vec![], 1050, CallResults::new());
+    assert_eq!(result.call_requests.len(), 1, "{:?}", result);
+
+    let call_results = maplit::hashmap! {
+        1 => CallServiceResult::ok(json!("result")),
+    };
+    let result = call_with_current_time(&script, result.data, 2000, call_results);
+
+    let expected_error = UncatchableError::ParticleExpired {
+        deadline: 1100,
+        current_time: 2000,
+    };
+    assert!(check_error(&result, expected_error), "{:?}", result);
+    assert!(
+        result.call_requests.is_empty(),
+        "the fallback branch mustn't be executed"
+    );
+}
+
+/// Executes the script on behalf of a particle with timestamp 1000 and ttl 100.
+fn call_with_current_time(
+    script: &str,
+    prev_data: Vec<u8>,
+    current_time: u64,
+    call_results: CallResults,
+) -> RawAVMOutcome {
+    use air_interpreter_interface::RunParameters;
+
+    let peer_id = "peer_id";
+    let run_parameters = RunParameters {
+        current_time,
+        ..RunParameters::new(peer_id.to_string(), peer_id.to_string(), 1000, 100)
+    };
+    let raw_call_results = serde_json::to_vec(&into_raw_result(call_results)).unwrap();
+
+    let result = air::execute_air(script.to_string(), prev_data, vec![], run_parameters, raw_call_results);
+    RawAVMOutcome::from_interpreter_outcome(result).unwrap()
+}
+
+#[test]
+fn ap_to_stream_map_with_invalid_key() {
+    let peer_id = "peer_id";
+    let script = f!(r#"
+        (seq
+            (call "{peer_id}" ("" "") [] key)
+            (ap (key "value") %map))
+    "#);
+
+    let mut vm = create_avm(set_variable_call_service(json!({"field": 1})), peer_id);
+    let result = vm.call(&script, "", "", <_>::default()).unwrap();
+
+    let expected_error = CatchableError::StreamMapKeyError(json!({"field": 1}));
+    assert!(check_error(&result, expected_error));
+}
@@ -37,7 +37,7 @@ fn invalid_data_without_versions() {
 
     let result = call_vm!(vm, <_>::default(), script, "", invalid_data.clone());
 
-    let expected_serde_error = serde_json::from_slice::<InterpreterData>(&invalid_data).err().unwrap();
+    let expected_serde_error = InterpreterData::try_from_slice(&invalid_data).err().unwrap();
     let expected_error = PreparationError::DataDeFailed {
         data: invalid_data,
         error: expected_serde_error,
@@ -67,7 +67,7 @@ fn invalid_data_with_versions() {
 
     let result = call_vm!(vm, <_>::default(), script, "", invalid_data.clone());
 
-    let expected_serde_error = serde_json::from_slice::<InterpreterData>(&invalid_data).err().unwrap();
+    let expected_serde_error = InterpreterData::try_from_slice(&invalid_data).err().unwrap();
     let expected_error = PreparationError::DataDeFailedWithVersions {
         data: invalid_data,
         error: expected_serde_error,
@@ -99,3 +99,23 @@ fn invalid_callresults() {
 
     assert!(check_error(&result, expected_error));
 }
+
+#[test]
+fn expired_particle() {
+    let air = r#"(null)"#.to_string();
+    let client_peer_id = "some_peer_id".to_string();
+    let call_results = serde_json::to_vec(&CallResults::new()).unwrap();
+    let run_parameters = RunParameters {
+        current_time: 2000,
+        ..RunParameters::new(client_peer_id.clone(), client_peer_id, 1000, 100)
+    };
+    let result = air::execute_air(air, vec![], vec![], run_parameters, call_results);
+    let result = RawAVMOutcome::from_interpreter_outcome(result).unwrap();
+
+    let expected_error = PreparationError::ParticleExpired {
+        deadline: 1100,
+        current_time: 2000,
+    };
+    assert!(check_error(&result, expected_error), "{:?}", result);
+    assert!(result.data.is_empty());
+}
@@ -14,7 +14,9 @@
  * limitations under the License.
  */
 
+use air::ActiveFsm;
 use air::ExecutionCidState;
+use air::MergeConflict;
 use air::UncatchableError;
 use air_interpreter_cid::CID;
 use air_interpreter_data::FoldSubTraceLore;
@@ -79,7 +81,7 @@ fn set_subtrace_len_and_pos_failed() {
     let mut cid_state = ExecutionCidState::new();
     let trace = vec![
         executed_state::par(1, 2),
-        stream_tracked!(json!([42, 43]), 0, cid_state),
+        stream_tracked!(json!([42, 43]), 0, cid_state, peer = "vm_peer_id_1"),
         executed_state::fold(vec![executed_state::subtrace_lore(
             1,
             subtrace_desc(5, 1),
@@ -118,7 +120,7 @@ fn no_element_at_position() {
     let mut cid_state = ExecutionCidState::new();
     let trace = vec![
         executed_state::par(1, 2),
-        stream_tracked!(json!([42, 43]), 0, cid_state),
+        stream_tracked!(json!([42, 43]), 0, cid_state, peer = "vm_peer_id_1"),
         executed_state::fold(vec![executed_state::subtrace_lore(
             42,
             subtrace_desc(3, 1),
@@ -158,7 +160,7 @@ fn no_stream_state() {
     let wrong_state = request_sent_by("vm_peer_id_1");
     let trace = vec![
         executed_state::par(1, 2),
-        stream_tracked!(json!([42, 43]), 0, &mut tracker),
+        stream_tracked!(json!([42, 43]), 0, &mut tracker, peer = "vm_peer_id_1"),
         executed_state::fold(vec![executed_state::subtrace_lore(
             3,
             subtrace_desc(3, 1), // try to change the number of elems to 3
@@ -270,6 +272,7 @@ fn invalid_dst_generations() {
         trace_error: MergeError(air_trace_handler::MergeError::IncorrectApResult(
             ApResultError::InvalidDstGenerations(ApResult {
                 res_generations: vec![42, 42],
+                kind: air_interpreter_data::StreamKind::Stream,
             }),
         )),
         instruction: String::from(r#"ap "a" $s"#),
@@ -359,10 +362,12 @@ fn canon_result_error() {
                 prev_canon_result: air_interpreter_data::CanonResult {
                     tetraplet: CID::new("bagaaierasjgckcjojtq3m3aylofqmzqitnostogfwnj2fzyaqsmxnlquf2la").into(),
                     values: vec![CID::new("bagaaieraxlk56ks2zevx5umgur2xxhf2cuopflnpopps3m6sgldmzquxumvq").into()],
+                    kind: air_interpreter_data::StreamKind::Stream,
                 },
                 current_canon_result: air_interpreter_data::CanonResult {
                     tetraplet: CID::new("bagaaierasjgckcjojtq3m3aylofqmzqitnostogfwnj2fzyaqsmxnlquf2la").into(),
                     values: vec![CID::new("bagaaierawwlvodborlxavs7j42eea23dmitophllfxg4kvozwe5ru4ywitea").into()],
+                    kind: air_interpreter_data::StreamKind::Stream,
                 },
             },
         )),
@@ -390,7 +395,7 @@ fn several_records_with_same_pos() {
     let value_pos = 1;
     let trace = vec![
         executed_state::par(1, 2),
-        stream_tracked!(json!([42, 43]), 0, cid_state),
+        stream_tracked!(json!([42, 43]), 0, cid_state, peer = "vm_peer_id_1"),
         fold(vec![
             subtrace_lore(value_pos, subtrace_desc(3, 1), subtrace_desc(4, 0)),
             subtrace_lore(value_pos, subtrace_desc(3, 1), subtrace_desc(4, 0)),
@@ -467,3 +472,43 @@ fn values_not_equal() {
     };
     assert!(check_error(&result, expected_error));
 }
+
+#[test]
+fn merge_conflict_is_serialized_into_error_message() {
+    let vm_peer_id = "vm_peer_id";
+    let mut peer_vm = create_avm(echo_call_service(), vm_peer_id);
+    let script = format!(
+        r#"
+        (par
+            (call "{vm_peer_id}" ("" "") [] scalar)
+            (ap 1 $stream)
+        )
+    "#
+    );
+    let mut cid_tracker = ExecutionCidState::new();
+    let prev_call = scalar_tracked!("", cid_tracker, peer = vm_peer_id);
+    let prev_trace = vec![executed_state::par(1, 1), prev_call.clone(), executed_state::ap(0)];
+    let current_trace = vec![executed_state::par(1, 1), executed_state::ap(0), executed_state::ap(0)];
+    let prev_data = raw_data_from_trace(prev_trace, cid_tracker.into());
+    let current_data = raw_data_from_trace(current_trace, <_>::default());
+    let result = call_vm!(peer_vm, <_>::default(), &script, prev_data, current_data);
+
+    let expected_conflict = MergeConflict {
+        prev_position: 1.into(),
+        current_position: 1.into(),
+        prev_state: Some(serde_json::to_value(prev_call).unwrap()),
+        current_state: Some(serde_json::to_value(executed_state::ap(0)).unwrap()),
+        prev_subtrace_len: 1,
+        current_subtrace_len: 1,
+        active_fsms: vec![ActiveFsm::Par {
+            prev_par: (1, 1),
+            current_par: (1, 1),
+        }],
+    };
+    assert_eq!(
+        result.merge_conflict,
+        Some(expected_conflict),
+        "{}",
+        result.error_message
+    );
+}
@@ -17,6 +17,8 @@
 use air::UncatchableError::*;
 use air_interpreter_cid::CID;
 use air_interpreter_data::ValueRef;
+use air_interpreter_interface::CallResults;
+use air_interpreter_interface::RunParameters;
 use air_test_utils::prelude::*;
 
 #[test]
@@ -117,3 +119,50 @@ fn value_for_cid_not_found() {
     let expected_error = ValueForCidNotFound("service result aggregate", missing_cid);
     assert!(check_error(&result, expected_error));
 }
+
+#[test]
+fn instruction_limit_exceeded() {
+    let vm_peer_id = "vm_peer_id";
+    let run_parameters = |instruction_limit| RunParameters {
+        instruction_limit,
+        ..RunParameters::new(vm_peer_id.to_string(), vm_peer_id.to_string(), 0, 0)
+    };
+    let call_results = serde_json::to_vec(&CallResults::new()).unwrap();
+
+    let prev_outcome = air::execute_air(
+        String::from("(null)"),
+        vec![],
+        vec![],
+        run_parameters(0),
+        call_results.clone(),
+    );
+    assert_eq!(prev_outcome.ret_code, 0, "{:?}", prev_outcome.error_message);
+
+    // xor, seq and two nulls, the limit can't be caught by xor
+    let script = r#"
+        (xor
+            (seq (null) (null))
+            (null))
+    "#;
+
+    let outcome = air::execute_air(
+        script.to_string(),
+        prev_outcome.data.clone(),
+        vec![],
+        run_parameters(4),
+        call_results.clone(),
+    );
+    assert_eq!(outcome.ret_code, 0, "{:?}", outcome.error_message);
+
+    let outcome = air::execute_air(
+        script.to_string(),
+        prev_outcome.data.clone(),
+        vec![],
+        run_parameters(3),
+        call_results,
+    );
+    let result = RawAVMOutcome::from_interpreter_outcome(outcome).unwrap();
+    let expected_error = InstructionLimitExceeded { limit: 3 };
+    assert!(check_error(&result, expected_error), "{:?}", result);
+    assert_eq!(result.data, prev_outcome.data);
+}
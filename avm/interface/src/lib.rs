@@ -69,6 +69,31 @@ pub enum CallSeDeErrors {
         call_params: air_interpreter_interface::CallRequestParams,
         de_error: SerdeError,
     },
+
+    /// This error is encountered when deserialization of execution stats failed for some reason.
+    #[error("'{raw_stats:?}' can't be deserialized to execution stats with error '{error}'")]
+    ExecutionStatsDeError {
+        raw_stats: Vec<u8>,
+        error: SerdeError,
+    },
+
+    /// This error is encountered when deserialization of a merge conflict failed for some reason.
+    #[error(
+        "'{raw_merge_conflict:?}' can't be deserialized to a merge conflict with error '{error}'"
+    )]
+    MergeConflictDeError {
+        raw_merge_conflict: Vec<u8>,
+        error: SerdeError,
+    },
+
+    /// This error is encountered when deserialization of an error position failed for some reason.
+    #[error(
+        "'{raw_error_position:?}' can't be deserialized to an error position with error '{error}'"
+    )]
+    ErrorPositionDeError {
+        raw_error_position: Vec<u8>,
+        error: SerdeError,
+    },
 }
 type JValue = serde_json::Value;
 
@@ -76,3 +101,11 @@ pub use call_request_parameters::*;
 pub use call_service_result::*;
 pub use outcome::*;
 pub use particle_parameters::*;
+
+pub use air_interpreter_data::ActiveFsm;
+pub use air_interpreter_data::MergeConflict;
+pub use air_interpreter_interface::CidStoreStats;
+pub use air_interpreter_interface::ErrorPosition;
+pub use air_interpreter_interface::ExecutionStats;
+pub use air_interpreter_interface::InstructionStats;
+pub use air_interpreter_interface::TraceStats;
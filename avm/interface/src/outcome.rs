@@ -17,6 +17,10 @@
 use super::CallRequests;
 use crate::raw_outcome::RawAVMOutcome;
 
+use air_interpreter_data::MergeConflict;
+use air_interpreter_interface::ErrorPosition;
+use air_interpreter_interface::ExecutionStats;
+
 use serde::Deserialize;
 use serde::Serialize;
 
@@ -40,6 +44,9 @@ pub struct AVMOutcome {
     /// Time of a particle execution
     /// (it counts only execution time without operations with DataStore and so on)
     pub execution_time: Duration,
+
+    /// Statistics collected by the interpreter, present only if they were requested.
+    pub stats: Option<ExecutionStats>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -47,6 +54,12 @@ pub struct ErrorAVMOutcome {
     pub error_code: i64,
     pub error_message: String,
     pub outcome: AVMOutcome,
+
+    /// Place in traces where merging data failed, present only if it's the error reason.
+    pub merge_conflict: Option<MergeConflict>,
+
+    /// Position of the failed instruction in the script, present only if it's known.
+    pub error_position: Option<ErrorPosition>,
 }
 
 impl AVMOutcome {
@@ -56,6 +69,7 @@ impl AVMOutcome {
         next_peer_pks: Vec<String>,
         memory_delta: usize,
         execution_time: Duration,
+        stats: Option<ExecutionStats>,
     ) -> Self {
         Self {
             data,
@@ -63,6 +77,7 @@ impl AVMOutcome {
             next_peer_pks,
             memory_delta,
             execution_time,
+            stats,
         }
     }
 
@@ -80,6 +95,9 @@ impl AVMOutcome {
             data,
             call_requests,
             next_peer_pks,
+            stats,
+            merge_conflict,
+            error_position,
         } = raw_outcome;
 
         let avm_outcome = AVMOutcome::new(
@@ -88,22 +106,37 @@ impl AVMOutcome {
             next_peer_pks,
             memory_delta,
             execution_time,
+            stats,
         );
 
         if ret_code == INTERPRETER_SUCCESS {
             Ok(avm_outcome)
         } else {
-            Err(ErrorAVMOutcome::new(ret_code, error_message, avm_outcome))
+            Err(ErrorAVMOutcome::new(
+                ret_code,
+                error_message,
+                avm_outcome,
+                merge_conflict,
+                error_position,
+            ))
         }
     }
 }
 
 impl ErrorAVMOutcome {
-    pub(self) fn new(error_code: i64, error_message: String, outcome: AVMOutcome) -> Self {
+    pub(self) fn new(
+        error_code: i64,
+        error_message: String,
+        outcome: AVMOutcome,
+        merge_conflict: Option<MergeConflict>,
+        error_position: Option<ErrorPosition>,
+    ) -> Self {
         Self {
             error_code,
             error_message,
             outcome,
+            merge_conflict,
+            error_position,
         }
     }
 }
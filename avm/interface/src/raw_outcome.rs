@@ -18,6 +18,9 @@ use crate::CallSeDeErrors;
 
 use super::CallRequests;
 
+use air_interpreter_data::MergeConflict;
+use air_interpreter_interface::ErrorPosition;
+use air_interpreter_interface::ExecutionStats;
 use air_interpreter_interface::InterpreterOutcome;
 
 use serde::Deserialize;
@@ -31,6 +34,9 @@ pub struct RawAVMOutcome {
     pub data: Vec<u8>,
     pub call_requests: CallRequests,
     pub next_peer_pks: Vec<String>,
+    pub stats: Option<ExecutionStats>,
+    pub merge_conflict: Option<MergeConflict>,
+    pub error_position: Option<ErrorPosition>,
 }
 
 impl RawAVMOutcome {
@@ -41,9 +47,15 @@ impl RawAVMOutcome {
             data,
             call_requests,
             next_peer_pks,
+            stats,
+            merge_conflict,
+            error_position,
         } = outcome;
 
         let call_requests = crate::from_raw_call_requests(call_requests)?;
+        let stats = from_raw_stats(stats)?;
+        let merge_conflict = from_raw_merge_conflict(merge_conflict)?;
+        let error_position = from_raw_error_position(error_position)?;
 
         let raw_avm_outcome = Self {
             ret_code,
@@ -51,8 +63,54 @@ impl RawAVMOutcome {
             data,
             call_requests,
             next_peer_pks,
+            stats,
+            merge_conflict,
+            error_position,
         };
 
         Ok(raw_avm_outcome)
     }
 }
+
+/// Empty stats mean that they weren't requested.
+fn from_raw_stats(raw_stats: Vec<u8>) -> Result<Option<ExecutionStats>, CallSeDeErrors> {
+    if raw_stats.is_empty() {
+        return Ok(None);
+    }
+
+    serde_json::from_slice(&raw_stats)
+        .map(Some)
+        .map_err(|error| CallSeDeErrors::ExecutionStatsDeError { raw_stats, error })
+}
+
+/// Empty merge conflict means that the execution didn't fail on merging data.
+fn from_raw_merge_conflict(
+    raw_merge_conflict: Vec<u8>,
+) -> Result<Option<MergeConflict>, CallSeDeErrors> {
+    if raw_merge_conflict.is_empty() {
+        return Ok(None);
+    }
+
+    serde_json::from_slice(&raw_merge_conflict)
+        .map(Some)
+        .map_err(|error| CallSeDeErrors::MergeConflictDeError {
+            raw_merge_conflict,
+            error,
+        })
+}
+
+/// Empty error position means that the execution succeeded or the failed instruction is unknown.
+fn from_raw_error_position(
+    raw_error_position: Vec<u8>,
+) -> Result<Option<ErrorPosition>, CallSeDeErrors> {
+    if raw_error_position.is_empty() {
+        return Ok(None);
+    }
+
+    serde_json::from_slice(&raw_error_position)
+        .map(Some)
+        .map_err(|error| CallSeDeErrors::ErrorPositionDeError {
+            raw_error_position,
+            error,
+        })
+}
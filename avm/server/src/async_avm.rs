@@ -0,0 +1,546 @@
+/*
+ * Copyright 2023 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use crate::avm::current_unix_time_ms;
+use crate::interpreter_registry::InterpreterSelector;
+use crate::runner_pool::RunnerPool;
+use crate::runner_pool::RunnerRequest;
+use crate::runner_pool::RunnerResponse;
+use crate::AVMError;
+use crate::AVMResult;
+use crate::InterpreterRegistryConfig;
+
+use air_interpreter_signatures::KeyPair;
+use avm_data_store::AnomalyData;
+use avm_data_store::AsyncDataStore;
+use avm_data_store::ExecutionSummary;
+use avm_interface::raw_outcome::RawAVMOutcome;
+use avm_interface::AVMOutcome;
+use avm_interface::CallResults;
+use avm_interface::ParticleParameters;
+
+use parking_lot::Mutex;
+use tokio::sync::Mutex as AsyncMutex;
+use tokio::sync::OwnedMutexGuard;
+
+use std::collections::HashMap;
+use std::num::NonZeroUsize;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::Duration;
+
+pub type AsyncAVMDataStore<E> = Box<dyn AsyncDataStore<Error = E> + Send + Sync + 'static>;
+
+/// Describes behaviour of the async AVM.
+pub struct AsyncAVMConfig<E> {
+    /// Path to a AIR interpreter Wasm file.
+    pub air_wasm_path: PathBuf,
+
+    /// Maximum heap size in bytes available for each interpreter in the pool. Every worker holds
+    /// an interpreter of each registered version, so the pool could take up to
+    /// `pool_size` × count of versions × `max_heap_size` bytes.
+    pub max_heap_size: Option<u64>,
+
+    /// Mask used to filter logs, for details see `log_utf8_string` in fluence-faas.
+    pub logging_mask: i32,
+
+    /// Maximum number of instructions the interpreter could execute in one call,
+    /// a particle exceeding it fails with an uncatchable error and prev data is kept.
+    pub instruction_limit: Option<u64>,
+
+    /// If set, the interpreter collects execution stats and returns them in outcomes.
+    pub collect_stats: bool,
+
+    /// Interpreters of other versions and a version policy, if set, particles are dispatched
+    /// between them and the interpreter from `air_wasm_path` by versions of their data.
+    pub interpreter_registry: Option<InterpreterRegistryConfig>,
+
+    /// Count of interpreters in the pool, each of them runs on its own thread.
+    pub pool_size: NonZeroUsize,
+
+    pub data_store: AsyncAVMDataStore<E>,
+}
+
+/// Async facade over a pool of interpreters, particles are queued until one of interpreters is free.
+///
+/// It's cheap to clone and could be shared between tasks.
+pub struct AsyncAVM<E> {
+    pool: Arc<RunnerPool>,
+    selector: Arc<InterpreterSelector>,
+    data_store: Arc<AsyncAVMDataStore<E>>,
+    particle_locks: Arc<ParticleLocks>,
+}
+
+impl<E> Clone for AsyncAVM<E> {
+    fn clone(&self) -> Self {
+        Self {
+            pool: self.pool.clone(),
+            selector: self.selector.clone(),
+            data_store: self.data_store.clone(),
+            particle_locks: self.particle_locks.clone(),
+        }
+    }
+}
+
+impl<E> AsyncAVM<E> {
+    /// Create AVM with provided config, it waits until all interpreters in the pool are created.
+    #[allow(clippy::result_large_err)]
+    pub async fn new(config: AsyncAVMConfig<E>) -> AVMResult<Self, E> {
+        let AsyncAVMConfig {
+            air_wasm_path,
+            max_heap_size,
+            logging_mask,
+            instruction_limit,
+            collect_stats,
+            interpreter_registry,
+            pool_size,
+            mut data_store,
+        } = config;
+
+        data_store.initialize().await?;
+
+        let (selector, interpreters) =
+            InterpreterSelector::from_config(air_wasm_path, interpreter_registry)
+                .map_err(AVMError::RunnerError)?;
+        let pool = RunnerPool::new(
+            interpreters,
+            max_heap_size,
+            logging_mask,
+            instruction_limit,
+            collect_stats,
+            pool_size,
+        )
+        .await
+        .map_err(AVMError::RunnerError)?;
+
+        Ok(Self::from_parts(pool, selector, data_store))
+    }
+
+    fn from_parts(
+        pool: RunnerPool,
+        selector: InterpreterSelector,
+        data_store: AsyncAVMDataStore<E>,
+    ) -> Self {
+        Self {
+            pool: Arc::new(pool),
+            selector: Arc::new(selector),
+            data_store: Arc::new(data_store),
+            particle_locks: <_>::default(),
+        }
+    }
+
+    /// Executes AIR, call results produced by the current peer are signed with the supplied key pair,
+    /// without it they are left unsigned and unsigned results of other peers are accepted.
+    /// Calls of the same particle on the same peer are executed one by one, each of them
+    /// starts from data stored by the previous one, other calls are executed concurrently.
+    #[allow(clippy::result_large_err)]
+    pub async fn call(
+        &self,
+        air: impl Into<String>,
+        data: impl Into<Vec<u8>>,
+        particle_parameters: ParticleParameters<'_>,
+        call_results: CallResults,
+        key_pair: Option<Arc<KeyPair>>,
+    ) -> AVMResult<AVMOutcome, E> {
+        let air = air.into();
+        // held until resulted data is stored
+        let _particle_guard = self
+            .particle_locks
+            .lock(
+                &particle_parameters.particle_id,
+                &particle_parameters.current_peer_id,
+            )
+            .await;
+        let prev_data = self
+            .data_store
+            .read_data(
+                &particle_parameters.particle_id,
+                &particle_parameters.current_peer_id,
+            )
+            .await?;
+        let prev_data_len = prev_data.len();
+        let current_data = data.into();
+        let interpreter = self
+            .selector
+            .select(&prev_data, &current_data)
+            .map_err(AVMError::RunnerError)?;
+
+        let request = RunnerRequest {
+            interpreter,
+            air: air.clone(),
+            prev_data,
+            current_data: current_data.clone(),
+            init_peer_id: particle_parameters.init_peer_id.clone().into_owned(),
+            timestamp: particle_parameters.timestamp,
+            ttl: particle_parameters.ttl,
+            current_time: current_unix_time_ms(),
+            current_peer_id: particle_parameters.current_peer_id.clone().into_owned(),
+            key_pair,
+            call_results: call_results.clone(),
+        };
+        let RunnerResponse {
+            outcome,
+            execution_time,
+            memory_delta,
+        } = self
+            .pool
+            .call(request)
+            .await
+            .map_err(AVMError::RunnerError)?;
+
+        let execution = ExecutionSummary {
+            particle_id: &particle_parameters.particle_id,
+            current_peer_id: &particle_parameters.current_peer_id,
+            execution_time,
+            memory_delta,
+            prev_data_len,
+            outcome: &outcome,
+        };
+        if self.data_store.detect_anomaly(&execution) {
+            self.save_anomaly_data(
+                &air,
+                &current_data,
+                &call_results,
+                &particle_parameters,
+                &outcome,
+                execution_time,
+                memory_delta,
+            )
+            .await?;
+        }
+
+        // persist resulted data
+        self.data_store
+            .store_data(
+                &outcome.data,
+                &particle_parameters.particle_id,
+                &particle_parameters.current_peer_id,
+            )
+            .await?;
+        let outcome = AVMOutcome::from_raw_outcome(outcome, memory_delta, execution_time)
+            .map_err(AVMError::InterpreterFailed)?;
+
+        Ok(outcome)
+    }
+
+    /// Version of the interpreter executing new particles, it's known only if the interpreter registry is set.
+    pub fn interpreter_version(&self) -> Option<semver::Version> {
+        self.selector.active_version()
+    }
+
+    /// Switches new particles to the newest registered interpreter, it should be called once
+    /// all participants support it. Particles already started keep running on their interpreters.
+    /// It affects all clones of this AVM. Returns the version of the active interpreter,
+    /// if the interpreter registry is set.
+    pub fn upgrade_interpreter(&self) -> Option<semver::Version> {
+        self.selector.upgrade()
+    }
+
+    /// Cleanup data that become obsolete.
+    #[allow(clippy::result_large_err)]
+    pub async fn cleanup_data(&self, particle_id: &str, current_peer_id: &str) -> AVMResult<(), E> {
+        self.data_store
+            .cleanup_data(particle_id, current_peer_id)
+            .await?;
+        Ok(())
+    }
+
+    #[allow(clippy::result_large_err, clippy::too_many_arguments)]
+    async fn save_anomaly_data(
+        &self,
+        air_script: &str,
+        current_data: &[u8],
+        call_result: &CallResults,
+        particle_parameters: &ParticleParameters<'_>,
+        avm_outcome: &RawAVMOutcome,
+        execution_time: Duration,
+        memory_delta: usize,
+    ) -> AVMResult<(), E> {
+        // prev data is moved into the interpreter, so it's read again only for rare anomalies,
+        // the store isn't updated until the anomaly is saved
+        let prev_data = self
+            .data_store
+            .read_data(
+                &particle_parameters.particle_id,
+                &particle_parameters.current_peer_id,
+            )
+            .await?;
+        let call_results = serde_json::to_vec(call_result).map_err(AVMError::AnomalyDataSeError)?;
+        let ser_particle =
+            serde_json::to_vec(particle_parameters).map_err(AVMError::AnomalyDataSeError)?;
+        let ser_avm_outcome =
+            serde_json::to_vec(avm_outcome).map_err(AVMError::AnomalyDataSeError)?;
+
+        let anomaly_data = AnomalyData::new(
+            air_script,
+            &ser_particle,
+            &prev_data,
+            current_data,
+            &call_results,
+            &ser_avm_outcome,
+            execution_time,
+            memory_delta,
+        );
+
+        self.data_store
+            .collect_anomaly_data(
+                &particle_parameters.particle_id,
+                &particle_parameters.current_peer_id,
+                anomaly_data,
+            )
+            .await
+            .map_err(Into::into)
+    }
+}
+
+/// Locks of particles being executed, they're keyed by a particle id and a current peer id.
+/// Without them two concurrent calls of one particle would read the same prev data,
+/// and data stored by one of them would be overwritten by the other.
+#[derive(Default)]
+struct ParticleLocks {
+    locks: Mutex<HashMap<(String, String), Arc<AsyncMutex<()>>>>,
+}
+
+impl ParticleLocks {
+    async fn lock(self: &Arc<Self>, particle_id: &str, current_peer_id: &str) -> ParticleGuard {
+        let key = (particle_id.to_string(), current_peer_id.to_string());
+        let lock = self.locks.lock().entry(key.clone()).or_default().clone();
+        let guard = lock.lock_owned().await;
+
+        ParticleGuard {
+            locks: self.clone(),
+            key,
+            _guard: guard,
+        }
+    }
+}
+
+struct ParticleGuard {
+    locks: Arc<ParticleLocks>,
+    key: (String, String),
+    _guard: OwnedMutexGuard<()>,
+}
+
+impl Drop for ParticleGuard {
+    fn drop(&mut self) {
+        let mut locks = self.locks.locks.lock();
+        // the lock is referenced only by the map and this guard, so nobody waits for it,
+        // and nobody could take it while the map is locked
+        let is_unused = locks
+            .get(&self.key)
+            .map(|lock| Arc::strong_count(lock) == 2)
+            .unwrap_or(false);
+        if is_unused {
+            locks.remove(&self.key);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::runner_pool::Worker;
+    use crate::RunnerError;
+    use crate::RunnerResult;
+
+    use std::io::Error as IOError;
+    use std::time::Instant;
+
+    const EXECUTION_TIME: Duration = Duration::from_millis(100);
+
+    /// Appends a byte to prev data, so lost updates are seen in stored data.
+    struct AppendingWorker;
+
+    impl Worker for AppendingWorker {
+        fn execute(&mut self, request: RunnerRequest) -> RunnerResult<RunnerResponse> {
+            std::thread::sleep(EXECUTION_TIME);
+            if request.air == "fail" {
+                return Err(RunnerError::InterpreterResultDeError("fail".to_string()));
+            }
+
+            let mut data = request.prev_data;
+            data.push(b'x');
+            let outcome = RawAVMOutcome {
+                ret_code: 0,
+                error_message: String::new(),
+                data,
+                call_requests: <_>::default(),
+                next_peer_pks: vec![],
+                stats: None,
+                merge_conflict: None,
+                error_position: None,
+            };
+
+            Ok(RunnerResponse {
+                outcome,
+                execution_time: EXECUTION_TIME,
+                memory_delta: 0,
+            })
+        }
+    }
+
+    #[derive(Default)]
+    struct MemoryDataStore {
+        data: Mutex<HashMap<(String, String), Vec<u8>>>,
+    }
+
+    #[async_trait::async_trait]
+    impl AsyncDataStore for MemoryDataStore {
+        type Error = IOError;
+
+        async fn initialize(&mut self) -> Result<(), Self::Error> {
+            Ok(())
+        }
+
+        async fn store_data(
+            &self,
+            data: &[u8],
+            particle_id: &str,
+            current_peer_id: &str,
+        ) -> Result<(), Self::Error> {
+            let key = (particle_id.to_string(), current_peer_id.to_string());
+            self.data.lock().insert(key, data.to_vec());
+            Ok(())
+        }
+
+        async fn read_data(
+            &self,
+            particle_id: &str,
+            current_peer_id: &str,
+        ) -> Result<Vec<u8>, Self::Error> {
+            if particle_id == "unreadable" {
+                return Err(IOError::new(std::io::ErrorKind::Other, "unreadable"));
+            }
+
+            let key = (particle_id.to_string(), current_peer_id.to_string());
+            Ok(self.data.lock().get(&key).cloned().unwrap_or_default())
+        }
+
+        async fn cleanup_data(
+            &self,
+            particle_id: &str,
+            current_peer_id: &str,
+        ) -> Result<(), Self::Error> {
+            let key = (particle_id.to_string(), current_peer_id.to_string());
+            self.data.lock().remove(&key);
+            Ok(())
+        }
+
+        fn detect_anomaly(&self, _execution: &ExecutionSummary<'_>) -> bool {
+            false
+        }
+
+        async fn collect_anomaly_data(
+            &self,
+            _particle_id: &str,
+            _current_peer_id: &str,
+            _anomaly_data: AnomalyData<'_>,
+        ) -> Result<(), Self::Error> {
+            Ok(())
+        }
+    }
+
+    async fn fake_avm(pool_size: usize) -> AsyncAVM<IOError> {
+        let pool = RunnerPool::with_workers(
+            || Ok(AppendingWorker),
+            NonZeroUsize::new(pool_size).unwrap(),
+        )
+        .await
+        .unwrap();
+        let (selector, _) = InterpreterSelector::from_config(PathBuf::new(), None).unwrap();
+
+        AsyncAVM::from_parts(pool, selector, Box::<MemoryDataStore>::default())
+    }
+
+    async fn call(
+        avm: &AsyncAVM<IOError>,
+        air: &str,
+        particle_id: &str,
+        current_peer_id: &str,
+    ) -> AVMResult<AVMOutcome, IOError> {
+        let particle_parameters = ParticleParameters::new(
+            "init_peer_id".into(),
+            particle_id.into(),
+            0,
+            0,
+            current_peer_id.into(),
+        );
+        avm.call(air, vec![], particle_parameters, <_>::default(), None)
+            .await
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn same_particle_calls_are_serialized() {
+        let avm = fake_avm(2).await;
+
+        let start = Instant::now();
+        let (first, second) = tokio::join!(
+            call(&avm, "", "particle", "peer"),
+            call(&avm, "", "particle", "peer"),
+        );
+
+        // the second call starts from data stored by the first one
+        let mut outcomes = vec![first.unwrap().data, second.unwrap().data];
+        outcomes.sort();
+        assert_eq!(outcomes, vec![b"x".to_vec(), b"xx".to_vec()]);
+        assert!(start.elapsed() >= 2 * EXECUTION_TIME);
+        assert!(avm.particle_locks.locks.lock().is_empty());
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn different_particles_are_executed_concurrently() {
+        let avm = fake_avm(3).await;
+
+        let start = Instant::now();
+        let (first, second, third) = tokio::join!(
+            call(&avm, "", "particle_1", "peer"),
+            call(&avm, "", "particle_2", "peer"),
+            call(&avm, "", "particle_1", "other_peer"),
+        );
+
+        assert_eq!(first.unwrap().data, b"x");
+        assert_eq!(second.unwrap().data, b"x");
+        assert_eq!(third.unwrap().data, b"x");
+        assert!(start.elapsed() < 2 * EXECUTION_TIME);
+        assert!(avm.particle_locks.locks.lock().is_empty());
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn runner_error_is_propagated() {
+        let avm = fake_avm(1).await;
+
+        let result = call(&avm, "fail", "particle", "peer").await;
+        assert!(matches!(
+            result,
+            Err(AVMError::RunnerError(RunnerError::InterpreterResultDeError(message))) if message == "fail"
+        ));
+
+        // nothing was stored for the failed call, and the particle isn't left locked
+        let outcome = call(&avm, "", "particle", "peer").await.unwrap();
+        assert_eq!(outcome.data, b"x");
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn data_store_error_is_propagated() {
+        let avm = fake_avm(1).await;
+
+        let result = call(&avm, "", "unreadable", "peer").await;
+        assert!(
+            matches!(result, Err(AVMError::DataStoreError(error)) if error.to_string() == "unreadable")
+        );
+        assert!(avm.particle_locks.locks.lock().is_empty());
+    }
+}
@@ -19,9 +19,12 @@ use super::AVMDataStore;
 use super::AVMError;
 use super::AVMMemoryStats;
 use crate::config::AVMConfig;
+use crate::interpreter_registry::InterpreterSelector;
 use crate::AVMResult;
 
+use air_interpreter_signatures::KeyPair;
 use avm_data_store::AnomalyData;
+use avm_data_store::ExecutionSummary;
 use avm_interface::raw_outcome::RawAVMOutcome;
 use avm_interface::AVMOutcome;
 use avm_interface::CallResults;
@@ -31,6 +34,8 @@ use std::ops::Deref;
 use std::ops::DerefMut;
 use std::time::Duration;
 use std::time::Instant;
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
 
 /// A newtype needed to mark it as `unsafe impl Send`
 struct SendSafeRunner(AVMRunner);
@@ -52,7 +57,9 @@ impl DerefMut for SendSafeRunner {
 }
 
 pub struct AVM<E> {
-    runner: SendSafeRunner,
+    /// Interpreters indexed as the selector identifies them.
+    runners: Vec<SendSafeRunner>,
+    selector: InterpreterSelector,
     data_store: AVMDataStore<E>,
 }
 
@@ -64,19 +71,43 @@ impl<E> AVM<E> {
             air_wasm_path,
             max_heap_size,
             logging_mask,
+            instruction_limit,
+            collect_stats,
+            interpreter_registry,
             mut data_store,
         } = config;
 
         data_store.initialize()?;
 
-        let runner = AVMRunner::new(air_wasm_path, max_heap_size, logging_mask)
+        let (selector, interpreters) =
+            InterpreterSelector::from_config(air_wasm_path, interpreter_registry)
+                .map_err(AVMError::RunnerError)?;
+        let runners = interpreters
+            .into_iter()
+            .map(|(air_wasm_path, abi)| {
+                AVMRunner::with_abi(
+                    air_wasm_path,
+                    abi,
+                    max_heap_size,
+                    logging_mask,
+                    instruction_limit,
+                    collect_stats,
+                )
+                .map(SendSafeRunner)
+            })
+            .collect::<Result<Vec<_>, _>>()
             .map_err(AVMError::RunnerError)?;
-        let runner = SendSafeRunner(runner);
-        let avm = Self { runner, data_store };
+        let avm = Self {
+            runners,
+            selector,
+            data_store,
+        };
 
         Ok(avm)
     }
 
+    /// Executes AIR, call results produced by the current peer are signed with the supplied key pair,
+    /// without it they are left unsigned and unsigned results of other peers are accepted.
     #[allow(clippy::result_large_err)]
     pub fn call(
         &mut self,
@@ -84,18 +115,25 @@ impl<E> AVM<E> {
         data: impl Into<Vec<u8>>,
         particle_parameters: ParticleParameters<'_>,
         call_results: CallResults,
+        key_pair: Option<&KeyPair>,
     ) -> AVMResult<AVMOutcome, E> {
         let air = air.into();
         let prev_data = self.data_store.read_data(
             &particle_parameters.particle_id,
             &particle_parameters.current_peer_id,
         )?;
+        let prev_data_len = prev_data.len();
         let current_data = data.into();
 
+        let interpreter = self
+            .selector
+            .select(&prev_data, &current_data)
+            .map_err(AVMError::RunnerError)?;
+        let runner = &mut self.runners[interpreter];
+
         let execution_start_time = Instant::now();
-        let memory_size_before = self.memory_stats().memory_size;
-        let outcome = self
-            .runner
+        let memory_size_before = runner.memory_stats().memory_size;
+        let outcome = runner
             .call(
                 air.clone(),
                 prev_data,
@@ -103,17 +141,24 @@ impl<E> AVM<E> {
                 particle_parameters.init_peer_id.clone().into_owned(),
                 particle_parameters.timestamp,
                 particle_parameters.ttl,
+                current_unix_time_ms(),
                 particle_parameters.current_peer_id.clone(),
+                key_pair,
                 call_results.clone(),
             )
             .map_err(AVMError::RunnerError)?;
 
         let execution_time = execution_start_time.elapsed();
-        let memory_delta = self.memory_stats().memory_size - memory_size_before;
-        if self
-            .data_store
-            .detect_anomaly(execution_time, memory_delta, &outcome)
-        {
+        let memory_delta = runner.memory_stats().memory_size - memory_size_before;
+        let execution = ExecutionSummary {
+            particle_id: &particle_parameters.particle_id,
+            current_peer_id: &particle_parameters.current_peer_id,
+            execution_time,
+            memory_delta,
+            prev_data_len,
+            outcome: &outcome,
+        };
+        if self.data_store.detect_anomaly(&execution) {
             self.save_anomaly_data(
                 &air,
                 &current_data,
@@ -144,9 +189,21 @@ impl<E> AVM<E> {
         Ok(())
     }
 
-    /// Return memory stat of an interpreter heap.
+    /// Return memory stat of the active interpreter heap.
     pub fn memory_stats(&self) -> AVMMemoryStats {
-        self.runner.memory_stats()
+        self.runners[self.selector.active_index()].memory_stats()
+    }
+
+    /// Version of the interpreter executing new particles, it's known only if the interpreter registry is set.
+    pub fn interpreter_version(&self) -> Option<semver::Version> {
+        self.selector.active_version()
+    }
+
+    /// Switches new particles to the newest registered interpreter, it should be called once
+    /// all participants support it. Particles already started keep running on their interpreters.
+    /// Returns the version of the active interpreter, if the interpreter registry is set.
+    pub fn upgrade_interpreter(&mut self) -> Option<semver::Version> {
+        self.selector.upgrade()
     }
 
     #[allow(clippy::result_large_err, clippy::too_many_arguments)]
@@ -160,6 +217,8 @@ impl<E> AVM<E> {
         execution_time: Duration,
         memory_delta: usize,
     ) -> AVMResult<(), E> {
+        // prev data is moved into the interpreter, so it's read again only for rare anomalies,
+        // the store isn't updated until the anomaly is saved
         let prev_data = self.data_store.read_data(
             &particle_parameters.particle_id,
             &particle_parameters.current_peer_id,
@@ -190,3 +249,11 @@ impl<E> AVM<E> {
             .map_err(Into::into)
     }
 }
+
+/// Current time used to check particles TTL, the interpreter treats 0 as unknown time.
+pub(crate) fn current_unix_time_ms() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_millis() as u64)
+        .unwrap_or_default()
+}
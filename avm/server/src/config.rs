@@ -15,6 +15,8 @@
  */
 
 use super::AVMDataStore;
+use crate::InterpreterRegistryConfig;
+
 use std::path::PathBuf;
 
 /// Describes behaviour of the AVM.
@@ -28,5 +30,17 @@ pub struct AVMConfig<E> {
     /// Mask used to filter logs, for details see `log_utf8_string` in fluence-faas.
     pub logging_mask: i32,
 
+    /// Maximum number of instructions the interpreter could execute in one call,
+    /// a particle exceeding it fails with an uncatchable error and prev data is kept.
+    pub instruction_limit: Option<u64>,
+
+    /// If set, the interpreter collects execution stats and returns them in outcomes.
+    pub collect_stats: bool,
+
+    /// Interpreters of other versions and a version policy, if set, particles are dispatched
+    /// between them and the interpreter from `air_wasm_path` by versions of their data.
+    /// Each interpreter has its own heap, so memory grows with the count of versions.
+    pub interpreter_registry: Option<InterpreterRegistryConfig>,
+
     pub data_store: AVMDataStore<E>,
 }
@@ -72,4 +72,40 @@ pub enum RunnerError {
     /// This errors are encountered from an call results/params se/de.
     #[error(transparent)]
     CallSeDeErrors(#[from] CallSeDeErrors),
+
+    /// A worker thread of the runner pool can't be spawned.
+    #[error("failed to spawn an AVM runner pool worker: {0}")]
+    WorkerSpawnFailed(IOError),
+
+    /// The runner pool doesn't accept requests anymore, it happens if all its workers stopped.
+    #[error("AVM runner pool is closed")]
+    RunnerPoolClosed,
+
+    /// Interpreter registry contains several interpreters of the same version.
+    #[error("interpreter registry contains several interpreters of version {0}")]
+    DuplicatedInterpreterVersion(semver::Version),
+
+    /// Particle data was produced by an interpreter refused by the version policy.
+    #[error("data produced by interpreter {version} isn't supported, supported versions are {supported_versions}")]
+    UnsupportedInterpreterVersion {
+        version: semver::Version,
+        supported_versions: semver::VersionReq,
+    },
+
+    /// Particle data was produced by an interpreter newer than all registered ones.
+    #[error("there is no interpreter able to handle data produced by interpreter {0}")]
+    NoSuitableInterpreter(semver::Version),
+
+    /// A supplied key pair doesn't belong to a current peer.
+    #[error(
+        "supplied key pair belongs to `{key_peer_id}`, but current peer id is `{current_peer_id}`"
+    )]
+    KeyPairMismatch {
+        key_peer_id: String,
+        current_peer_id: String,
+    },
+
+    /// A runner pool worker panicked while executing a request, it's replaced with a new one.
+    #[error("AVM runner pool worker panicked: {0}")]
+    WorkerPanicked(String),
 }
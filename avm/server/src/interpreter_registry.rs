@@ -0,0 +1,299 @@
+/*
+ * Copyright 2023 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use crate::runner::InterpreterAbi;
+use crate::RunnerError;
+use crate::RunnerResult;
+
+use air_interpreter_data::InterpreterData;
+use semver::Version;
+use semver::VersionReq;
+
+use std::path::PathBuf;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+
+/// An AIR interpreter Wasm file built from the specified interpreter version.
+#[derive(Debug, Clone)]
+pub struct InterpreterModule {
+    pub version: Version,
+    pub air_wasm_path: PathBuf,
+}
+
+/// Describes several interpreters held by the AVM, each particle is dispatched to one of them
+/// by versions of interpreters produced its prev and current data.
+#[derive(Debug, Clone)]
+pub struct InterpreterRegistryConfig {
+    /// Version of the interpreter from `air_wasm_path`, new particles are executed by it
+    /// until the AVM is upgraded.
+    pub main_version: Version,
+
+    /// Interpreters of other versions, e.g. a newer one that could be switched to during an upgrade.
+    pub interpreters: Vec<InterpreterModule>,
+
+    /// Particles with data produced by interpreters out of this range are refused.
+    pub supported_versions: VersionReq,
+}
+
+/// Chooses an interpreter for every particle, interpreters are identified by their indices
+/// in the list of Wasm paths returned on creation.
+#[derive(Debug)]
+pub(crate) struct InterpreterSelector {
+    /// Versions of registered interpreters sorted in ascending order,
+    /// it's empty if the AVM holds only one interpreter without a registry.
+    versions: Vec<Version>,
+    supported_versions: VersionReq,
+    /// Index of an interpreter executing particles without data.
+    active: AtomicUsize,
+}
+
+impl InterpreterSelector {
+    /// Returns a selector and paths to Wasm files of interpreters with their ABIs
+    /// in the order of their indices.
+    pub(crate) fn from_config(
+        air_wasm_path: PathBuf,
+        registry: Option<InterpreterRegistryConfig>,
+    ) -> RunnerResult<(Self, Vec<(PathBuf, InterpreterAbi)>)> {
+        let InterpreterRegistryConfig {
+            main_version,
+            interpreters,
+            supported_versions,
+        } = match registry {
+            Some(registry) => registry,
+            None => {
+                let interpreters = vec![(air_wasm_path, InterpreterAbi::for_version(None))];
+                return Ok((Self::single(), interpreters));
+            }
+        };
+
+        let main = InterpreterModule {
+            version: main_version.clone(),
+            air_wasm_path,
+        };
+        let mut modules = interpreters;
+        modules.push(main);
+        modules.sort_by(|lhs, rhs| lhs.version.cmp(&rhs.version));
+        if let Some(pair) = modules
+            .windows(2)
+            .find(|pair| pair[0].version == pair[1].version)
+        {
+            return Err(RunnerError::DuplicatedInterpreterVersion(
+                pair[0].version.clone(),
+            ));
+        }
+
+        let active = modules
+            .iter()
+            .position(|module| module.version == main_version)
+            .expect("the main interpreter was just added");
+        let (versions, interpreters) = modules
+            .into_iter()
+            .map(|module| {
+                let abi = InterpreterAbi::for_version(Some(&module.version));
+                (module.version, (module.air_wasm_path, abi))
+            })
+            .unzip();
+
+        let selector = Self {
+            versions,
+            supported_versions,
+            active: AtomicUsize::new(active),
+        };
+        Ok((selector, interpreters))
+    }
+
+    fn single() -> Self {
+        Self {
+            versions: vec![],
+            supported_versions: VersionReq::STAR,
+            active: AtomicUsize::new(0),
+        }
+    }
+
+    /// Particles without data go to the active interpreter. Otherwise, a particle goes to
+    /// the oldest interpreter able to read its data, so in-flight particles keep running
+    /// on interpreters they were started on when the AVM is upgraded.
+    pub(crate) fn select(&self, prev_data: &[u8], current_data: &[u8]) -> RunnerResult<usize> {
+        if self.versions.is_empty() {
+            return Ok(0);
+        }
+
+        // malformed data is left to the interpreter, it returns a proper error for it
+        let data_version = [prev_data, current_data]
+            .iter()
+            .filter(|data| !data.is_empty())
+            .filter_map(|data| InterpreterData::try_get_versions(data).ok())
+            .map(|versions| versions.interpreter_version)
+            .max();
+        let data_version = match data_version {
+            Some(version) => version,
+            None => return Ok(self.active.load(Ordering::Acquire)),
+        };
+
+        if !self.supported_versions.matches(&data_version) {
+            return Err(RunnerError::UnsupportedInterpreterVersion {
+                version: data_version,
+                supported_versions: self.supported_versions.clone(),
+            });
+        }
+
+        self.versions
+            .iter()
+            .position(|version| version >= &data_version)
+            .ok_or(RunnerError::NoSuitableInterpreter(data_version))
+    }
+
+    /// Makes the newest interpreter active, it should be called once all participants
+    /// of new particles support it. Returns the version of the active interpreter.
+    pub(crate) fn upgrade(&self) -> Option<Version> {
+        let newest = self.versions.len().checked_sub(1)?;
+        self.active.store(newest, Ordering::Release);
+        self.versions.last().cloned()
+    }
+
+    pub(crate) fn active_version(&self) -> Option<Version> {
+        self.versions
+            .get(self.active.load(Ordering::Acquire))
+            .cloned()
+    }
+
+    pub(crate) fn active_index(&self) -> usize {
+        self.active.load(Ordering::Acquire)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn data(interpreter_version: &str) -> Vec<u8> {
+        let interpreter_version = Version::parse(interpreter_version).unwrap();
+        InterpreterData::new(interpreter_version)
+            .serialize_with_format(<_>::default())
+            .unwrap()
+    }
+
+    fn module(version: &str) -> InterpreterModule {
+        InterpreterModule {
+            version: Version::parse(version).unwrap(),
+            air_wasm_path: PathBuf::from(format!("air_interpreter_{version}.wasm")),
+        }
+    }
+
+    fn registry(main_version: &str, interpreters: &[&str]) -> InterpreterRegistryConfig {
+        InterpreterRegistryConfig {
+            main_version: Version::parse(main_version).unwrap(),
+            interpreters: interpreters.iter().map(|version| module(version)).collect(),
+            supported_versions: VersionReq::parse(">=0.39.0").unwrap(),
+        }
+    }
+
+    fn selector() -> (InterpreterSelector, Vec<(PathBuf, InterpreterAbi)>) {
+        let registry = registry("0.40.0", &["0.41.0", "0.39.0"]);
+        InterpreterSelector::from_config(module("0.40.0").air_wasm_path, Some(registry)).unwrap()
+    }
+
+    #[test]
+    fn interpreters_are_ordered_by_version() {
+        let (_, interpreters) = selector();
+
+        let expected = vec![
+            (module("0.39.0").air_wasm_path, InterpreterAbi::Legacy),
+            (module("0.40.0").air_wasm_path, InterpreterAbi::Current),
+            (module("0.41.0").air_wasm_path, InterpreterAbi::Current),
+        ];
+        assert_eq!(interpreters, expected);
+    }
+
+    #[test]
+    fn particle_without_data_goes_to_main_interpreter() {
+        let (selector, _) = selector();
+
+        assert_eq!(selector.select(&[], &[]).unwrap(), 1);
+        assert_eq!(selector.active_version(), Some(Version::new(0, 40, 0)));
+    }
+
+    #[test]
+    fn oldest_compatible_interpreter_is_chosen() {
+        let (selector, _) = selector();
+
+        assert_eq!(selector.select(&data("0.39.0"), &[]).unwrap(), 0);
+        assert_eq!(selector.select(&[], &data("0.40.0")).unwrap(), 1);
+        assert_eq!(selector.select(&data("0.40.1"), &[]).unwrap(), 2);
+        // the newest version of prev and current data is taken
+        assert_eq!(
+            selector.select(&data("0.39.0"), &data("0.41.0")).unwrap(),
+            2
+        );
+    }
+
+    #[test]
+    fn upgrade_switches_only_new_particles() {
+        let (selector, _) = selector();
+
+        assert_eq!(selector.upgrade(), Some(Version::new(0, 41, 0)));
+        assert_eq!(selector.active_version(), Some(Version::new(0, 41, 0)));
+        assert_eq!(selector.select(&[], &[]).unwrap(), 2);
+        assert_eq!(selector.select(&data("0.39.0"), &[]).unwrap(), 0);
+    }
+
+    #[test]
+    fn duplicated_versions_are_refused() {
+        let registry = registry("0.40.0", &["0.39.0", "0.40.0"]);
+
+        let result = InterpreterSelector::from_config(PathBuf::new(), Some(registry));
+        assert!(matches!(
+            result,
+            Err(RunnerError::DuplicatedInterpreterVersion(version)) if version == Version::new(0, 40, 0)
+        ));
+    }
+
+    #[test]
+    fn unsupported_data_version_is_refused() {
+        let (selector, _) = selector();
+
+        let result = selector.select(&data("0.38.0"), &[]);
+        assert!(matches!(
+            result,
+            Err(RunnerError::UnsupportedInterpreterVersion { version, .. }) if version == Version::new(0, 38, 0)
+        ));
+    }
+
+    #[test]
+    fn data_newer_than_all_interpreters_is_refused() {
+        let (selector, _) = selector();
+
+        let result = selector.select(&data("0.42.0"), &[]);
+        assert!(matches!(
+            result,
+            Err(RunnerError::NoSuitableInterpreter(version)) if version == Version::new(0, 42, 0)
+        ));
+    }
+
+    #[test]
+    fn single_interpreter_takes_all_particles() {
+        let (selector, interpreters) =
+            InterpreterSelector::from_config(PathBuf::from("air.wasm"), None).unwrap();
+
+        assert_eq!(
+            interpreters,
+            vec![(PathBuf::from("air.wasm"), InterpreterAbi::Current)]
+        );
+        assert_eq!(selector.select(&data("0.38.0"), &[]).unwrap(), 0);
+        assert_eq!(selector.upgrade(), None);
+        assert_eq!(selector.active_version(), None);
+    }
+}
@@ -24,14 +24,28 @@
     unreachable_patterns
 )]
 
+#[cfg(feature = "async")]
+mod async_avm;
 mod avm;
 mod config;
 mod errors;
+mod interpreter_registry;
 mod runner;
+#[cfg(feature = "async")]
+mod runner_pool;
 
+#[cfg(feature = "async")]
+pub use async_avm::AsyncAVM;
+#[cfg(feature = "async")]
+pub use async_avm::AsyncAVMConfig;
+#[cfg(feature = "async")]
+pub use async_avm::AsyncAVMDataStore;
 pub use avm::AVM;
 pub use config::AVMConfig;
 pub use errors::AVMError;
+pub use errors::RunnerError;
+pub use interpreter_registry::InterpreterModule;
+pub use interpreter_registry::InterpreterRegistryConfig;
 pub use runner::AVMMemoryStats;
 
 pub use avm_interface::*;
@@ -49,14 +63,22 @@ pub use marine::HostImportError;
 pub use marine::IType;
 pub use marine::IValue;
 
+pub use air_interpreter_signatures::KeyPair;
+pub use air_interpreter_signatures::Signer;
 pub use polyplets::SecurityTetraplet;
 
 pub use avm_data_store::AnomalyData;
+pub use avm_data_store::AnomalyPolicy;
+pub use avm_data_store::AnomalyPolicyConfig;
+#[cfg(feature = "async")]
+pub use avm_data_store::AsyncDataStore;
 pub use avm_data_store::DataStore;
+pub use avm_data_store::ExecutionSummary;
+pub use avm_data_store::FsDataStore;
+pub use avm_data_store::FsDataStoreConfig;
 
 pub type AVMDataStore<E> = Box<dyn DataStore<Error = E> + Send + Sync + 'static>;
 
 pub type AVMResult<T, E> = std::result::Result<T, AVMError<E>>;
 
-pub(crate) use errors::RunnerError;
 pub(crate) type RunnerResult<T> = std::result::Result<T, RunnerError>;
@@ -18,20 +18,61 @@ use crate::RunnerError;
 use crate::RunnerResult;
 
 use air_interpreter_interface::InterpreterOutcome;
+use air_interpreter_signatures::KeyPair;
+use air_interpreter_signatures::Signer;
 use air_utils::measure;
 use avm_interface::raw_outcome::RawAVMOutcome;
 use avm_interface::CallResults;
+use marine::HostExportedFunc;
+use marine::HostImportDescriptor;
+use marine::IType;
 use marine::IValue;
 use marine::Marine;
 use marine::MarineConfig;
 use marine::ModuleDescriptor;
+use semver::Version;
 
 use std::path::PathBuf;
+use std::sync::Arc;
+use std::sync::Mutex;
+
+/// Key pair of a current peer available to the `sign` import of the interpreter,
+/// it's set only for the duration of a call.
+type SigningKey = Arc<Mutex<Option<KeyPair>>>;
+
+/// Layout of run parameters an interpreter reads, it was extended over versions
+/// and older interpreters refuse records with unknown fields.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum InterpreterAbi {
+    /// Interpreters before 0.40.0 read only particle parameters.
+    Legacy,
+    Current,
+}
+
+impl InterpreterAbi {
+    const FIRST_CURRENT_VERSION: Version = Version::new(0, 40, 0);
+
+    /// An interpreter of an unknown version is considered to be built along with this crate.
+    pub(crate) fn for_version(version: Option<&Version>) -> Self {
+        match version {
+            Some(version) if version < &Self::FIRST_CURRENT_VERSION => Self::Legacy,
+            _ => Self::Current,
+        }
+    }
+}
 
 pub struct AVMRunner {
     marine: Marine,
     /// file name of the AIR interpreter .wasm
     wasm_filename: String,
+    /// layout of run parameters the interpreter reads
+    abi: InterpreterAbi,
+    /// maximum number of instructions executed by the interpreter in one call
+    instruction_limit: Option<u64>,
+    /// whether the interpreter should collect execution stats
+    collect_stats: bool,
+    /// key pair used by the interpreter to sign results of a current peer
+    signing_key: SigningKey,
 }
 
 /// Return statistic of AVM server Wasm module heap footprint.
@@ -49,21 +90,53 @@ impl AVMRunner {
         air_wasm_path: PathBuf,
         max_heap_size: Option<u64>,
         logging_mask: i32,
+        instruction_limit: Option<u64>,
+        collect_stats: bool,
+    ) -> RunnerResult<Self> {
+        Self::with_abi(
+            air_wasm_path,
+            InterpreterAbi::Current,
+            max_heap_size,
+            logging_mask,
+            instruction_limit,
+            collect_stats,
+        )
+    }
+
+    pub(crate) fn with_abi(
+        air_wasm_path: PathBuf,
+        abi: InterpreterAbi,
+        max_heap_size: Option<u64>,
+        logging_mask: i32,
+        instruction_limit: Option<u64>,
+        collect_stats: bool,
     ) -> RunnerResult<Self> {
         let (wasm_dir, wasm_filename) = split_dirname(air_wasm_path)?;
 
-        let marine_config =
-            make_marine_config(wasm_dir, &wasm_filename, max_heap_size, logging_mask);
+        let signing_key = SigningKey::default();
+        let marine_config = make_marine_config(
+            wasm_dir,
+            &wasm_filename,
+            max_heap_size,
+            logging_mask,
+            signing_key.clone(),
+        );
         let marine = Marine::with_raw_config(marine_config)?;
 
         let avm = Self {
             marine,
             wasm_filename,
+            abi,
+            instruction_limit,
+            collect_stats,
+            signing_key,
         };
 
         Ok(avm)
     }
 
+    /// Executes AIR, `current_time` is a unix timestamp in milliseconds used to check
+    /// the particle TTL, 0 means that it's unknown and the check is skipped.
     #[allow(clippy::too_many_arguments)]
     #[tracing::instrument(skip_all)]
     pub fn call(
@@ -74,23 +147,32 @@ impl AVMRunner {
         init_peer_id: impl Into<String>,
         timestamp: u64,
         ttl: u32,
+        current_time: u64,
         current_peer_id: impl Into<String>,
+        key_pair: Option<&KeyPair>,
         call_results: CallResults,
     ) -> RunnerResult<RawAVMOutcome> {
+        let current_peer_id = current_peer_id.into();
+        check_key_pair(key_pair, &current_peer_id)?;
+
         let args = prepare_args(
+            self.abi,
             air,
             prev_data,
             data,
-            current_peer_id.into(),
+            current_peer_id,
             init_peer_id.into(),
             timestamp,
             ttl,
+            current_time,
+            key_pair.is_some(),
+            self.instruction_limit,
+            self.collect_stats,
             call_results,
         );
 
         let result = measure!(
-            self.marine
-                .call_with_ivalues(&self.wasm_filename, "invoke", &args, <_>::default())?,
+            self.call_interpreter("invoke", &args, key_pair)?,
             tracing::Level::INFO,
             "marine.call_with_ivalues",
             method = "invoke",
@@ -114,31 +196,36 @@ impl AVMRunner {
         init_peer_id: impl Into<String>,
         timestamp: u64,
         ttl: u32,
+        current_time: u64,
         current_peer_id: impl Into<String>,
+        key_pair: Option<&KeyPair>,
         call_results: CallResults,
         tracing_params: String,
         tracing_output_mode: u8,
     ) -> RunnerResult<RawAVMOutcome> {
+        let current_peer_id = current_peer_id.into();
+        check_key_pair(key_pair, &current_peer_id)?;
+
         let mut args = prepare_args(
+            self.abi,
             air,
             prev_data,
             data,
-            current_peer_id.into(),
+            current_peer_id,
             init_peer_id.into(),
             timestamp,
             ttl,
+            current_time,
+            key_pair.is_some(),
+            self.instruction_limit,
+            self.collect_stats,
             call_results,
         );
         args.push(IValue::String(tracing_params));
         args.push(IValue::U8(tracing_output_mode));
 
         let result = measure!(
-            self.marine.call_with_ivalues(
-                &self.wasm_filename,
-                "invoke_tracing",
-                &args,
-                <_>::default(),
-            )?,
+            self.call_interpreter("invoke_tracing", &args, key_pair)?,
             tracing::Level::INFO,
             "marine.call_with_ivalues",
             method = "invoke_tracing",
@@ -152,6 +239,23 @@ impl AVMRunner {
         Ok(outcome)
     }
 
+    /// The key pair is exposed to the `sign` import only while the interpreter runs,
+    /// so it's never shared between calls of different peers.
+    fn call_interpreter(
+        &mut self,
+        function_name: &str,
+        args: &[IValue],
+        key_pair: Option<&KeyPair>,
+    ) -> RunnerResult<Vec<IValue>> {
+        *lock_signing_key(&self.signing_key) = key_pair.cloned();
+        let result =
+            self.marine
+                .call_with_ivalues(&self.wasm_filename, function_name, args, <_>::default());
+        *lock_signing_key(&self.signing_key) = None;
+
+        Ok(result?)
+    }
+
     pub fn memory_stats(&self) -> AVMMemoryStats {
         let stats = self.marine.module_memory_stats();
 
@@ -168,6 +272,7 @@ impl AVMRunner {
 #[allow(clippy::too_many_arguments)]
 #[tracing::instrument(skip(air, prev_data, data, call_results))]
 fn prepare_args(
+    abi: InterpreterAbi,
     air: impl Into<String>,
     prev_data: impl Into<Vec<u8>>,
     data: impl Into<Vec<u8>>,
@@ -175,15 +280,27 @@ fn prepare_args(
     init_peer_id: String,
     timestamp: u64,
     ttl: u32,
+    current_time: u64,
+    require_signatures: bool,
+    instruction_limit: Option<u64>,
+    collect_stats: bool,
     call_results: CallResults,
 ) -> Vec<IValue> {
-    let run_parameters = air_interpreter_interface::RunParameters::new(
+    let run_parameters = air_interpreter_interface::RunParameters {
         init_peer_id,
         current_peer_id,
         timestamp,
         ttl,
-    )
-    .into_ivalue();
+        require_signatures,
+        // the interpreter treats 0 as no limit
+        instruction_limit: instruction_limit.unwrap_or_default(),
+        current_time,
+        collect_stats,
+    };
+    let run_parameters = match abi {
+        InterpreterAbi::Legacy => run_parameters.into_legacy_ivalue(),
+        InterpreterAbi::Current => run_parameters.into_ivalue(),
+    };
 
     let call_results = avm_interface::into_raw_result(call_results);
     let call_results = measure!(
@@ -201,6 +318,47 @@ fn prepare_args(
     ]
 }
 
+/// The interpreter signs results of a current peer with this import, so a secret key
+/// never leaves a host. A call fails if it's used without a key pair.
+fn make_sign_import(signing_key: SigningKey) -> HostImportDescriptor {
+    let host_exported_func: HostExportedFunc = Box::new(move |_, args: Vec<IValue>| {
+        let key_pair = lock_signing_key(&signing_key);
+        match (key_pair.as_ref(), args.as_slice()) {
+            (Some(key_pair), [IValue::ByteArray(message)]) => {
+                Some(IValue::ByteArray(key_pair.sign(message)))
+            }
+            _ => None,
+        }
+    });
+
+    HostImportDescriptor {
+        host_exported_func,
+        argument_types: vec![IType::ByteArray],
+        output_type: Some(IType::ByteArray),
+        error_handler: None,
+    }
+}
+
+fn lock_signing_key(signing_key: &SigningKey) -> std::sync::MutexGuard<'_, Option<KeyPair>> {
+    // the lock is held only while the key pair is replaced or used, that can't panic
+    signing_key
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+}
+
+/// A key pair is used to sign results produced by a current peer, so it must belong to it.
+fn check_key_pair(key_pair: Option<&KeyPair>, current_peer_id: &str) -> RunnerResult<()> {
+    match key_pair {
+        Some(key_pair) if key_pair.peer_id() != current_peer_id => {
+            Err(RunnerError::KeyPairMismatch {
+                key_peer_id: key_pair.peer_id(),
+                current_peer_id: current_peer_id.to_string(),
+            })
+        }
+        _ => Ok(()),
+    }
+}
+
 /// Splits given path into its directory and file name
 ///
 /// # Example
@@ -239,12 +397,16 @@ fn make_marine_config(
     air_wasm_file: &str,
     max_heap_size: Option<u64>,
     logging_mask: i32,
+    signing_key: SigningKey,
 ) -> MarineConfig {
+    let host_imports = maplit::hashmap! {
+        String::from("sign") => make_sign_import(signing_key),
+    };
     let air_module_config = marine::MarineModuleConfig {
         mem_pages_count: None,
         max_heap_size,
         logger_enabled: true,
-        host_imports: <_>::default(),
+        host_imports,
         wasi: None,
         logging_mask,
     };
@@ -270,3 +432,73 @@ fn try_as_one_value_vec(mut ivalues: Vec<IValue>) -> RunnerResult<IValue> {
 
     Ok(ivalues.remove(0))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::interpreter_registry::InterpreterSelector;
+    use crate::InterpreterModule;
+    use crate::InterpreterRegistryConfig;
+
+    use air_interpreter_data::InterpreterData;
+    use semver::VersionReq;
+
+    fn run_parameters_fields_count(abi: InterpreterAbi) -> usize {
+        let args = prepare_args(
+            abi,
+            "(null)",
+            vec![],
+            vec![],
+            "current_peer_id".to_string(),
+            "init_peer_id".to_string(),
+            0,
+            0,
+            0,
+            true,
+            None,
+            false,
+            <_>::default(),
+        );
+
+        match &args[3] {
+            IValue::Record(fields) => fields.len(),
+            run_parameters => panic!("expected a record, got {:?}", run_parameters),
+        }
+    }
+
+    #[test]
+    fn older_interpreter_gets_legacy_run_parameters() {
+        let registry = InterpreterRegistryConfig {
+            main_version: Version::new(0, 40, 0),
+            interpreters: vec![InterpreterModule {
+                version: Version::new(0, 39, 0),
+                air_wasm_path: PathBuf::from("air_interpreter_0.39.0.wasm"),
+            }],
+            supported_versions: VersionReq::STAR,
+        };
+        let (selector, interpreters) =
+            InterpreterSelector::from_config(PathBuf::from("air_interpreter.wasm"), Some(registry))
+                .unwrap();
+
+        let old_data = InterpreterData::new(Version::new(0, 39, 0))
+            .serialize_with_format(<_>::default())
+            .unwrap();
+        let (_, abi) = &interpreters[selector.select(&old_data, &[]).unwrap()];
+        assert_eq!(run_parameters_fields_count(*abi), 4);
+
+        let (_, abi) = &interpreters[selector.select(&[], &[]).unwrap()];
+        assert_eq!(run_parameters_fields_count(*abi), 8);
+    }
+
+    #[test]
+    fn key_pair_must_belong_to_current_peer() {
+        let key_pair = KeyPair::from_secret_key_bytes(&[42; 32]).unwrap();
+
+        assert!(check_key_pair(None, "current_peer_id").is_ok());
+        assert!(check_key_pair(Some(&key_pair), &key_pair.peer_id()).is_ok());
+        assert!(matches!(
+            check_key_pair(Some(&key_pair), "current_peer_id"),
+            Err(RunnerError::KeyPairMismatch { .. })
+        ));
+    }
+}
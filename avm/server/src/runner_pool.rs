@@ -0,0 +1,435 @@
+/*
+ * Copyright 2023 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use crate::avm_runner::AVMRunner;
+use crate::runner::InterpreterAbi;
+use crate::RunnerError;
+use crate::RunnerResult;
+
+use air_interpreter_signatures::KeyPair;
+use avm_interface::raw_outcome::RawAVMOutcome;
+use avm_interface::CallResults;
+
+use parking_lot::Mutex;
+use tokio::sync::mpsc;
+use tokio::sync::oneshot;
+
+use std::any::Any;
+use std::num::NonZeroUsize;
+use std::panic::AssertUnwindSafe;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::Duration;
+use std::time::Instant;
+
+type Job = (RunnerRequest, oneshot::Sender<RunnerResult<RunnerResponse>>);
+
+/// Arguments of one interpreter invocation sent to a pool worker.
+pub(crate) struct RunnerRequest {
+    /// Index of an interpreter chosen by the selector.
+    pub(crate) interpreter: usize,
+    pub(crate) air: String,
+    pub(crate) prev_data: Vec<u8>,
+    pub(crate) current_data: Vec<u8>,
+    pub(crate) init_peer_id: String,
+    pub(crate) timestamp: u64,
+    pub(crate) ttl: u32,
+    pub(crate) current_time: u64,
+    pub(crate) current_peer_id: String,
+    pub(crate) key_pair: Option<Arc<KeyPair>>,
+    pub(crate) call_results: CallResults,
+}
+
+pub(crate) struct RunnerResponse {
+    pub(crate) outcome: RawAVMOutcome,
+    pub(crate) execution_time: Duration,
+    pub(crate) memory_delta: usize,
+}
+
+/// A pool of interpreters, each of them is created and used only by its own worker thread,
+/// so runners never cross thread boundaries. Workers take jobs from a shared queue.
+/// Every worker holds interpreters of all registered versions.
+pub(crate) struct RunnerPool {
+    job_sender: mpsc::UnboundedSender<Job>,
+}
+
+impl RunnerPool {
+    pub(crate) async fn new(
+        interpreters: Vec<(PathBuf, InterpreterAbi)>,
+        max_heap_size: Option<u64>,
+        logging_mask: i32,
+        instruction_limit: Option<u64>,
+        collect_stats: bool,
+        pool_size: NonZeroUsize,
+    ) -> RunnerResult<Self> {
+        let make_runners = move || {
+            interpreters
+                .iter()
+                .map(|(air_wasm_path, abi)| {
+                    AVMRunner::with_abi(
+                        air_wasm_path.clone(),
+                        *abi,
+                        max_heap_size,
+                        logging_mask,
+                        instruction_limit,
+                        collect_stats,
+                    )
+                })
+                .collect::<Result<Vec<_>, _>>()
+        };
+
+        Self::with_workers(make_runners, pool_size).await
+    }
+
+    /// Spawns workers created by `make_worker` on their threads, it waits until all of them are created.
+    pub(crate) async fn with_workers<W, F>(
+        make_worker: F,
+        pool_size: NonZeroUsize,
+    ) -> RunnerResult<Self>
+    where
+        W: Worker,
+        F: Fn() -> RunnerResult<W> + Send + Sync + 'static,
+    {
+        let (job_sender, job_receiver) = mpsc::unbounded_channel();
+        let job_receiver = Arc::new(Mutex::new(job_receiver));
+        let make_worker = Arc::new(make_worker);
+
+        let mut init_receivers = Vec::with_capacity(pool_size.get());
+        for worker_id in 0..pool_size.get() {
+            let (init_sender, init_receiver) = oneshot::channel();
+            let make_worker = make_worker.clone();
+            let job_receiver = job_receiver.clone();
+
+            std::thread::Builder::new()
+                .name(format!("avm-runner-{worker_id}"))
+                .spawn(move || {
+                    let worker = match make_worker() {
+                        Ok(worker) => worker,
+                        Err(error) => {
+                            let _ = init_sender.send(Err(error));
+                            return;
+                        }
+                    };
+                    let _ = init_sender.send(Ok(()));
+
+                    run_worker(worker, make_worker.as_ref(), &job_receiver);
+                })
+                .map_err(RunnerError::WorkerSpawnFailed)?;
+
+            init_receivers.push(init_receiver);
+        }
+
+        // already started workers stop as soon as the pool is dropped on an error
+        for init_receiver in init_receivers {
+            init_receiver
+                .await
+                .map_err(|_| RunnerError::RunnerPoolClosed)??;
+        }
+
+        Ok(Self { job_sender })
+    }
+
+    /// Queues a request and waits until one of the workers executes it.
+    pub(crate) async fn call(&self, request: RunnerRequest) -> RunnerResult<RunnerResponse> {
+        let (response_sender, response_receiver) = oneshot::channel();
+        self.job_sender
+            .send((request, response_sender))
+            .map_err(|_| RunnerError::RunnerPoolClosed)?;
+
+        // a response sender is dropped without a response only if a worker thread stopped abruptly
+        response_receiver
+            .await
+            .map_err(|_| RunnerError::RunnerPoolClosed)?
+    }
+}
+
+/// Executes requests taken from the pool queue, it's created on its own thread and never leaves it.
+pub(crate) trait Worker {
+    fn execute(&mut self, request: RunnerRequest) -> RunnerResult<RunnerResponse>;
+}
+
+impl Worker for Vec<AVMRunner> {
+    fn execute(&mut self, request: RunnerRequest) -> RunnerResult<RunnerResponse> {
+        execute_request(self, request)
+    }
+}
+
+/// Executes jobs until the pool is dropped, a worker that panicked is replaced with a new one
+/// created by `make_worker` before the next job, so the pool never shrinks.
+fn run_worker<W: Worker>(
+    worker: W,
+    make_worker: &impl Fn() -> RunnerResult<W>,
+    job_receiver: &Mutex<mpsc::UnboundedReceiver<Job>>,
+) {
+    let mut worker = Some(worker);
+    loop {
+        // the lock is held only while waiting for a job, so idle workers queue up on it
+        let job = job_receiver.lock().blocking_recv();
+        let (request, response_sender) = match job {
+            Some(job) => job,
+            // the pool was dropped
+            None => return,
+        };
+
+        let response = match worker.as_mut() {
+            Some(current_worker) => execute_job(current_worker, request),
+            None => make_worker()
+                .and_then(|new_worker| execute_job(worker.get_or_insert(new_worker), request)),
+        };
+        // a worker state is unknown after a panic, so it's dropped
+        if matches!(response, Err(RunnerError::WorkerPanicked(_))) {
+            worker = None;
+        }
+
+        // a caller could have been cancelled, then the response is just dropped
+        let _ = response_sender.send(response);
+    }
+}
+
+fn execute_job(worker: &mut impl Worker, request: RunnerRequest) -> RunnerResult<RunnerResponse> {
+    std::panic::catch_unwind(AssertUnwindSafe(|| worker.execute(request)))
+        .unwrap_or_else(|payload| Err(RunnerError::WorkerPanicked(panic_message(payload))))
+}
+
+fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    match payload.downcast::<String>() {
+        Ok(message) => *message,
+        Err(payload) => payload
+            .downcast_ref::<&str>()
+            .map(ToString::to_string)
+            .unwrap_or_else(|| "unknown panic payload".to_string()),
+    }
+}
+
+fn execute_request(
+    runners: &mut [AVMRunner],
+    request: RunnerRequest,
+) -> RunnerResult<RunnerResponse> {
+    let RunnerRequest {
+        interpreter,
+        air,
+        prev_data,
+        current_data,
+        init_peer_id,
+        timestamp,
+        ttl,
+        current_time,
+        current_peer_id,
+        key_pair,
+        call_results,
+    } = request;
+    let runner = &mut runners[interpreter];
+
+    let execution_start_time = Instant::now();
+    let memory_size_before = runner.memory_stats().memory_size;
+    let outcome = runner.call(
+        air,
+        prev_data,
+        current_data,
+        init_peer_id,
+        timestamp,
+        ttl,
+        current_time,
+        current_peer_id,
+        key_pair.as_deref(),
+        call_results,
+    )?;
+
+    let execution_time = execution_start_time.elapsed();
+    let memory_delta = runner.memory_stats().memory_size - memory_size_before;
+
+    Ok(RunnerResponse {
+        outcome,
+        execution_time,
+        memory_delta,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::sync::atomic::AtomicUsize;
+    use std::sync::atomic::Ordering;
+
+    const EXECUTION_TIME: Duration = Duration::from_millis(100);
+
+    /// Behaves as the AIR passed to it says, and tracks how many requests are executed at once.
+    struct FakeWorker {
+        running: Arc<AtomicUsize>,
+        max_running: Arc<AtomicUsize>,
+    }
+
+    impl Worker for FakeWorker {
+        fn execute(&mut self, request: RunnerRequest) -> RunnerResult<RunnerResponse> {
+            let running = self.running.fetch_add(1, Ordering::SeqCst) + 1;
+            self.max_running.fetch_max(running, Ordering::SeqCst);
+            std::thread::sleep(EXECUTION_TIME);
+            self.running.fetch_sub(1, Ordering::SeqCst);
+
+            match request.air.as_str() {
+                "fail" => Err(RunnerError::InterpreterResultDeError("fail".to_string())),
+                "panic" => panic!("the worker panicked"),
+                _ => Ok(response(request.current_data)),
+            }
+        }
+    }
+
+    fn response(data: Vec<u8>) -> RunnerResponse {
+        let outcome = RawAVMOutcome {
+            ret_code: 0,
+            error_message: String::new(),
+            data,
+            call_requests: <_>::default(),
+            next_peer_pks: vec![],
+            stats: None,
+            merge_conflict: None,
+            error_position: None,
+        };
+
+        RunnerResponse {
+            outcome,
+            execution_time: EXECUTION_TIME,
+            memory_delta: 0,
+        }
+    }
+
+    fn request(air: &str, data: &[u8]) -> RunnerRequest {
+        RunnerRequest {
+            interpreter: 0,
+            air: air.to_string(),
+            prev_data: vec![],
+            current_data: data.to_vec(),
+            init_peer_id: "init_peer_id".to_string(),
+            timestamp: 0,
+            ttl: 0,
+            current_time: 0,
+            current_peer_id: "current_peer_id".to_string(),
+            key_pair: None,
+            call_results: <_>::default(),
+        }
+    }
+
+    async fn fake_pool(pool_size: usize) -> (RunnerPool, Arc<AtomicUsize>) {
+        let running = Arc::new(AtomicUsize::new(0));
+        let max_running = Arc::new(AtomicUsize::new(0));
+        let make_worker = {
+            let max_running = max_running.clone();
+            move || {
+                Ok(FakeWorker {
+                    running: running.clone(),
+                    max_running: max_running.clone(),
+                })
+            }
+        };
+
+        let pool = RunnerPool::with_workers(make_worker, NonZeroUsize::new(pool_size).unwrap())
+            .await
+            .unwrap();
+        (pool, max_running)
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn requests_are_executed_concurrently() {
+        let (pool, max_running) = fake_pool(3).await;
+
+        let start = Instant::now();
+        let (first, second, third) = tokio::join!(
+            pool.call(request("", b"1")),
+            pool.call(request("", b"2")),
+            pool.call(request("", b"3")),
+        );
+
+        assert_eq!(first.unwrap().outcome.data, b"1");
+        assert_eq!(second.unwrap().outcome.data, b"2");
+        assert_eq!(third.unwrap().outcome.data, b"3");
+        assert_eq!(max_running.load(Ordering::SeqCst), 3);
+        assert!(start.elapsed() < 3 * EXECUTION_TIME);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn requests_wait_for_busy_workers() {
+        let (pool, max_running) = fake_pool(1).await;
+
+        let start = Instant::now();
+        let (first, second, third) = tokio::join!(
+            pool.call(request("", b"1")),
+            pool.call(request("", b"2")),
+            pool.call(request("", b"3")),
+        );
+
+        // requests are queued rather than refused when all workers are busy
+        assert_eq!(first.unwrap().outcome.data, b"1");
+        assert_eq!(second.unwrap().outcome.data, b"2");
+        assert_eq!(third.unwrap().outcome.data, b"3");
+        assert_eq!(max_running.load(Ordering::SeqCst), 1);
+        assert!(start.elapsed() >= 3 * EXECUTION_TIME);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn runner_error_is_returned_to_caller() {
+        let (pool, _) = fake_pool(1).await;
+
+        let result = pool.call(request("fail", b"")).await;
+        assert!(matches!(
+            result,
+            Err(RunnerError::InterpreterResultDeError(message)) if message == "fail"
+        ));
+
+        // the worker keeps serving requests after an error
+        let response = pool.call(request("", b"data")).await.unwrap();
+        assert_eq!(response.outcome.data, b"data");
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn panicked_worker_is_replaced() {
+        let (pool, _) = fake_pool(2).await;
+
+        let result = pool.call(request("panic", b"")).await;
+        assert!(matches!(
+            result,
+            Err(RunnerError::WorkerPanicked(message)) if message == "the worker panicked"
+        ));
+
+        // both workers are still available after a panic
+        let (first, second) = tokio::join!(
+            pool.call(request("panic", b"")),
+            pool.call(request("", b"2")),
+        );
+        assert!(matches!(first, Err(RunnerError::WorkerPanicked(_))));
+        assert_eq!(second.unwrap().outcome.data, b"2");
+
+        let start = Instant::now();
+        let (first, second) =
+            tokio::join!(pool.call(request("", b"1")), pool.call(request("", b"2")),);
+        assert_eq!(first.unwrap().outcome.data, b"1");
+        assert_eq!(second.unwrap().outcome.data, b"2");
+        assert!(start.elapsed() < 2 * EXECUTION_TIME);
+    }
+
+    #[tokio::test]
+    async fn worker_creation_error_is_returned() {
+        let make_worker = || -> RunnerResult<FakeWorker> {
+            Err(RunnerError::InterpreterResultDeError("init".to_string()))
+        };
+
+        let result = RunnerPool::with_workers(make_worker, NonZeroUsize::new(2).unwrap()).await;
+        assert!(matches!(
+            result,
+            Err(RunnerError::InterpreterResultDeError(message)) if message == "init"
+        ));
+    }
+}
@@ -25,6 +25,7 @@ use criterion::Criterion;
 
 use air_parser::AIRLexer;
 use air_parser::AIRParser;
+use air_parser::CommentsCollector;
 use air_parser::VariableValidator;
 
 const SOURCE_CODE_BAD: &str = r#"(seq
@@ -84,11 +85,13 @@ fn parse(c: &mut Criterion) {
             let parser = parser.clone();
             b.iter(move || {
                 let mut validator = VariableValidator::new();
+                let mut comments = CommentsCollector::new();
                 let lexer = AIRLexer::new(SOURCE_CODE_GOOD);
+                let tokens = CommentsCollector::skip(lexer);
 
                 parser
                     .clone()
-                    .parse("", &mut Vec::new(), &mut validator, lexer)
+                    .parse("", &mut Vec::new(), &mut validator, &mut comments, tokens)
                     .expect("success")
             })
         },
@@ -103,11 +106,13 @@ fn parse_to_fail(c: &mut Criterion) {
             let parser = parser.clone();
             b.iter(move || {
                 let mut validator = VariableValidator::new();
+                let mut comments = CommentsCollector::new();
                 let lexer = AIRLexer::new(SOURCE_CODE_BAD);
+                let tokens = CommentsCollector::skip(lexer);
 
                 parser
                     .clone()
-                    .parse("", &mut Vec::new(), &mut validator, lexer)
+                    .parse("", &mut Vec::new(), &mut validator, &mut comments, tokens)
             })
         },
     );
@@ -129,11 +134,13 @@ fn parse_deep(c: &mut Criterion) {
             let code = &source_code[i];
             b.iter(move || {
                 let mut validator = VariableValidator::new();
+                let mut comments = CommentsCollector::new();
                 let lexer = AIRLexer::new(code);
+                let tokens = CommentsCollector::skip(lexer);
 
                 parser
                     .clone()
-                    .parse("", &mut Vec::new(), &mut validator, lexer)
+                    .parse("", &mut Vec::new(), &mut validator, &mut comments, tokens)
                     .expect("success")
             });
         });
@@ -151,11 +158,13 @@ fn parse_dashboard_script(c: &mut Criterion) {
             let parser = parser.clone();
             b.iter(move || {
                 let mut validator = VariableValidator::new();
+                let mut comments = CommentsCollector::new();
                 let lexer = AIRLexer::new(DASHBOARD_SCRIPT);
+                let tokens = CommentsCollector::skip(lexer);
 
                 parser
                     .clone()
-                    .parse("", &mut Vec::new(), &mut validator, lexer)
+                    .parse("", &mut Vec::new(), &mut validator, &mut comments, tokens)
                     .expect("success")
             })
         },
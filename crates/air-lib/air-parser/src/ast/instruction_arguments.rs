@@ -24,17 +24,21 @@ use super::ImmutableVariableWithLambda;
 use super::Scalar;
 use super::ScalarWithLambda;
 use super::Stream;
+use super::StreamMap;
 
 use air_lambda_ast::LambdaAST;
 
 use serde::Deserialize;
 use serde::Serialize;
 
+use std::borrow::Cow;
+
 /// Contains all variable variants that could be resolved to a peer id.
 #[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
 pub enum ResolvableToPeerIdVariable<'i> {
     InitPeerId,
-    Literal(&'i str),
+    #[serde(borrow)]
+    Literal(Cow<'i, str>),
     Scalar(Scalar<'i>),
     ScalarWithLambda(ScalarWithLambda<'i>),
     // canon without lambda can't be resolved to a string, since it represents an array of values
@@ -44,7 +48,8 @@ pub enum ResolvableToPeerIdVariable<'i> {
 /// Contains all variable variants that could be resolved to a string type.
 #[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
 pub enum ResolvableToStringVariable<'i> {
-    Literal(&'i str),
+    #[serde(borrow)]
+    Literal(Cow<'i, str>),
     Scalar(Scalar<'i>),
     ScalarWithLambda(ScalarWithLambda<'i>),
     // canon without lambda can't be resolved to a string, since it represents an array of values
@@ -70,7 +75,8 @@ pub enum ImmutableValue<'i> {
     LastError(Option<LambdaAST<'i>>),
     Timestamp,
     TTL,
-    Literal(&'i str),
+    #[serde(borrow)]
+    Literal(Cow<'i, str>),
     Number(Number),
     Boolean(bool),
     EmptyArray, // only empty arrays are allowed now
@@ -78,7 +84,7 @@ pub enum ImmutableValue<'i> {
     VariableWithLambda(ImmutableVariableWithLambda<'i>),
 }
 
-#[derive(Serialize, Debug, PartialEq, Eq, Clone)]
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
 pub enum CallOutputValue<'i> {
     #[serde(borrow)]
     Scalar(Scalar<'i>),
@@ -87,13 +93,30 @@ pub enum CallOutputValue<'i> {
     None,
 }
 
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+pub enum FailArgument<'i> {
+    #[serde(borrow)]
+    Scalar(Scalar<'i>),
+    #[serde(borrow)]
+    ScalarWithLambda(ScalarWithLambda<'i>),
+    Literal {
+        ret_code: i64,
+        #[serde(borrow)]
+        error_message: Cow<'i, str>,
+    },
+    #[serde(borrow)]
+    CanonStreamWithLambda(CanonStreamWithLambda<'i>),
+    LastError,
+}
+
 #[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
 pub enum ApArgument<'i> {
     InitPeerId,
     Timestamp,
     TTL,
     LastError(Option<LambdaAST<'i>>),
-    Literal(&'i str),
+    #[serde(borrow)]
+    Literal(Cow<'i, str>),
     Number(Number),
     Boolean(bool),
     EmptyArray,
@@ -111,6 +134,18 @@ pub enum ApResult<'i> {
     Stream(Stream<'i>),
 }
 
+/// Contains all values that could be used as a key of a stream map,
+/// a key is resolved either to a string or to an integer.
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+pub enum StreamMapKeyClause<'i> {
+    #[serde(borrow)]
+    Literal(Cow<'i, str>),
+    Int(i64),
+    Scalar(Scalar<'i>),
+    ScalarWithLambda(ScalarWithLambda<'i>),
+    CanonStreamWithLambda(CanonStreamWithLambda<'i>),
+}
+
 #[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
 pub enum Number {
     Int(i64),
@@ -137,4 +172,6 @@ pub enum NewArgument<'i> {
     Stream(Stream<'i>),
     #[serde(borrow)]
     CanonStream(CanonStream<'i>),
+    #[serde(borrow)]
+    StreamMap(StreamMap<'i>),
 }
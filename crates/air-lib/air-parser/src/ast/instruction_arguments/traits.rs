@@ -108,6 +108,37 @@ impl fmt::Display for ApArgument<'_> {
     }
 }
 
+impl fmt::Display for FailArgument<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use FailArgument::*;
+
+        match self {
+            Scalar(scalar) => write!(f, "{scalar}"),
+            ScalarWithLambda(scalar) => write!(f, "{scalar}"),
+            Literal {
+                ret_code,
+                error_message,
+            } => write!(f, r#"{ret_code} "{error_message}""#),
+            CanonStreamWithLambda(canon_stream) => write!(f, "{canon_stream}"),
+            LastError => write!(f, "%last_error%"),
+        }
+    }
+}
+
+impl fmt::Display for StreamMapKeyClause<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use StreamMapKeyClause::*;
+
+        match self {
+            Literal(literal) => write!(f, r#""{literal}""#),
+            Int(number) => write!(f, "{number}"),
+            Scalar(scalar) => write!(f, "{scalar}"),
+            ScalarWithLambda(scalar) => write!(f, "{scalar}"),
+            CanonStreamWithLambda(canon_stream) => write!(f, "{canon_stream}"),
+        }
+    }
+}
+
 impl fmt::Display for Triplet<'_> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(
@@ -124,6 +155,7 @@ impl fmt::Display for NewArgument<'_> {
             Self::Scalar(scalar) => write!(f, "{scalar}"),
             Self::Stream(stream) => write!(f, "{stream}"),
             Self::CanonStream(canon_stream) => write!(f, "{canon_stream}"),
+            Self::StreamMap(stream_map) => write!(f, "{stream_map}"),
         }
     }
 }
@@ -134,6 +166,8 @@ impl fmt::Display for Number {
 
         match self {
             Int(number) => write!(f, "{number}"),
+            // keep the dot, otherwise a whole float would be parsed back as an integer
+            Float(number) if number.fract() == 0.0 => write!(f, "{number:.1}"),
             Float(number) => write!(f, "{number}"),
         }
     }
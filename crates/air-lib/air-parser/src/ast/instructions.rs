@@ -19,100 +19,160 @@ mod traits;
 
 use super::*;
 
+use serde::Deserialize;
 use serde::Serialize;
 
+use std::borrow::Cow;
 use std::rc::Rc;
 
 // TODO: sort instruction in alphanumeric order
 #[allow(clippy::large_enum_variant)] // for Null and Error variants
-#[derive(Serialize, Debug, PartialEq)]
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
 pub enum Instruction<'i> {
+    #[serde(borrow)]
     Call(Call<'i>),
+    #[serde(borrow)]
     Ap(Ap<'i>),
+    #[serde(borrow)]
+    ApMap(ApMap<'i>),
+    #[serde(borrow)]
     Canon(Canon<'i>),
+    #[serde(borrow)]
+    CanonMap(CanonMap<'i>),
+    #[serde(borrow)]
     Seq(Seq<'i>),
+    #[serde(borrow)]
     Par(Par<'i>),
+    #[serde(borrow)]
     Xor(Xor<'i>),
+    #[serde(borrow)]
     Match(Match<'i>),
+    #[serde(borrow)]
     MisMatch(MisMatch<'i>),
+    #[serde(borrow)]
     Fail(Fail<'i>),
+    #[serde(borrow)]
     FoldScalar(FoldScalar<'i>),
+    #[serde(borrow)]
     FoldStream(FoldStream<'i>),
+    #[serde(borrow)]
+    FoldStreamMap(FoldStreamMap<'i>),
     Never(Never),
+    #[serde(borrow)]
     New(New<'i>),
+    #[serde(borrow)]
     Next(Next<'i>),
     Null(Null),
+    #[serde(borrow)]
+    Commented(Commented<'i>),
     Error,
 }
 
 /// (call (peer part of a triplet: PeerPart) (function part of a triplet: FunctionPart) [arguments] output)
-#[derive(Serialize, Debug, PartialEq)]
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
 pub struct Call<'i> {
+    #[serde(borrow)]
     pub triplet: Triplet<'i>,
     pub args: Rc<Vec<ImmutableValue<'i>>>,
     pub output: CallOutputValue<'i>,
+    pub span: Span,
 }
 
 /// (ap argument result)
-#[derive(Serialize, Debug, PartialEq)]
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
 pub struct Ap<'i> {
+    #[serde(borrow)]
     pub argument: ApArgument<'i>,
     pub result: ApResult<'i>,
+    pub span: Span,
+}
+
+/// (ap (key value) %map)
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+pub struct ApMap<'i> {
+    #[serde(borrow)]
+    pub key: StreamMapKeyClause<'i>,
+    pub value: ApArgument<'i>,
+    pub map: StreamMap<'i>,
+    pub span: Span,
 }
 
 /// (canon peer_id $stream #canon_stream)
-#[derive(Serialize, Debug, PartialEq, Eq)]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
 pub struct Canon<'i> {
+    #[serde(borrow)]
     pub peer_id: ResolvableToPeerIdVariable<'i>,
     pub stream: Stream<'i>,
     pub canon_stream: CanonStream<'i>,
+    pub span: Span,
+}
+
+/// (canon peer_id %stream_map scalar)
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+pub struct CanonMap<'i> {
+    #[serde(borrow)]
+    pub peer_id: ResolvableToPeerIdVariable<'i>,
+    pub stream_map: StreamMap<'i>,
+    pub scalar: Scalar<'i>,
+    pub span: Span,
 }
 
 /// (seq instruction instruction)
-#[derive(Serialize, Debug, PartialEq)]
-pub struct Seq<'i>(pub Box<Instruction<'i>>, pub Box<Instruction<'i>>);
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+pub struct Seq<'i>(
+    #[serde(borrow)] pub Box<Instruction<'i>>,
+    #[serde(borrow)] pub Box<Instruction<'i>>,
+    pub Span,
+);
 
 /// (par instruction instruction)
-#[derive(Serialize, Debug, PartialEq)]
-pub struct Par<'i>(pub Box<Instruction<'i>>, pub Box<Instruction<'i>>);
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+pub struct Par<'i>(
+    #[serde(borrow)] pub Box<Instruction<'i>>,
+    #[serde(borrow)] pub Box<Instruction<'i>>,
+    pub Span,
+);
 
 /// (xor instruction instruction)
-#[derive(Serialize, Debug, PartialEq)]
-pub struct Xor<'i>(pub Box<Instruction<'i>>, pub Box<Instruction<'i>>);
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+pub struct Xor<'i>(
+    #[serde(borrow)] pub Box<Instruction<'i>>,
+    #[serde(borrow)] pub Box<Instruction<'i>>,
+    pub Span,
+);
 
 /// (match left_value right_value instruction)
-#[derive(Serialize, Debug, PartialEq)]
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
 pub struct Match<'i> {
+    #[serde(borrow)]
     pub left_value: ImmutableValue<'i>,
     pub right_value: ImmutableValue<'i>,
     pub instruction: Box<Instruction<'i>>,
+    pub span: Span,
 }
 
 /// (mismatch left_value right_value instruction)
-#[derive(Serialize, Debug, PartialEq)]
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
 pub struct MisMatch<'i> {
+    #[serde(borrow)]
     pub left_value: ImmutableValue<'i>,
     pub right_value: ImmutableValue<'i>,
     pub instruction: Box<Instruction<'i>>,
+    pub span: Span,
 }
 
 /// (fail 1337 "error message")
 /// (fail %last_error%)
 /// (fail value)
-#[derive(Serialize, Debug, PartialEq, Eq)]
-pub enum Fail<'i> {
-    Scalar(Scalar<'i>),
-    ScalarWithLambda(ScalarWithLambda<'i>),
-    Literal {
-        ret_code: i64,
-        error_message: &'i str,
-    },
-    CanonStreamWithLambda(CanonStreamWithLambda<'i>),
-    LastError,
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+pub struct Fail<'i> {
+    #[serde(borrow)]
+    pub argument: FailArgument<'i>,
+    pub span: Span,
 }
 
 /// (fold scalar_iterable iterator instruction)
-#[derive(Serialize, Debug, PartialEq)]
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
 pub struct FoldScalar<'i> {
     #[serde(borrow)]
     pub iterable: FoldScalarIterable<'i>,
@@ -125,8 +185,9 @@ pub struct FoldScalar<'i> {
 }
 
 /// (fold stream_iterable iterator instruction)
-#[derive(Serialize, Debug, PartialEq)]
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
 pub struct FoldStream<'i> {
+    #[serde(borrow)]
     pub iterable: Stream<'i>,
     #[serde(borrow)]
     pub iterator: Scalar<'i>,
@@ -136,24 +197,63 @@ pub struct FoldStream<'i> {
     pub span: Span,
 }
 
+/// (fold stream_map_iterable iterator instruction)
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+pub struct FoldStreamMap<'i> {
+    #[serde(borrow)]
+    pub iterable: StreamMap<'i>,
+    #[serde(borrow)]
+    pub iterator: Scalar<'i>,
+    pub instruction: Rc<Instruction<'i>>,
+    // option is needed to provide a graceful period of adoption
+    pub last_instruction: Option<Rc<Instruction<'i>>>,
+    pub span: Span,
+}
+
 /// (fold stream_iterable iterator instruction)
-#[derive(Serialize, Debug, PartialEq, Eq)]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
 pub struct Next<'i> {
+    #[serde(borrow)]
     pub iterator: Scalar<'i>,
+    pub span: Span,
 }
 
 /// (never)
-#[derive(Serialize, Debug, PartialEq, Eq)]
-pub struct Never;
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+pub struct Never {
+    pub span: Span,
+}
 
 /// (new variable instruction)
-#[derive(Serialize, Debug, PartialEq)]
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
 pub struct New<'i> {
+    #[serde(borrow)]
     pub argument: NewArgument<'i>,
     pub instruction: Box<Instruction<'i>>,
     pub span: Span,
 }
 
 /// (null)
-#[derive(Serialize, Debug, PartialEq, Eq)]
-pub struct Null;
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+pub struct Null {
+    pub span: Span,
+}
+
+/// ;; comment
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
+pub struct Comment<'i> {
+    #[serde(borrow)]
+    pub text: Cow<'i, str>,
+    pub span: Span,
+}
+
+/// An instruction together with comments attached to it, comments don't affect execution.
+///
+/// Leading comments precede the instruction, the trailing ones follow its closing bracket.
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+pub struct Commented<'i> {
+    #[serde(borrow)]
+    pub leading: Vec<Comment<'i>>,
+    pub instruction: Box<Instruction<'i>>,
+    pub trailing: Vec<Comment<'i>>,
+}
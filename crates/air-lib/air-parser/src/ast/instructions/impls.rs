@@ -16,9 +16,69 @@
 
 use super::*;
 
+impl<'i> Instruction<'i> {
+    /// Returns the instruction itself skipping comments attached to it.
+    pub fn without_comments(&self) -> &Instruction<'i> {
+        let mut instruction = self;
+        while let Instruction::Commented(commented) = instruction {
+            instruction = &commented.instruction;
+        }
+
+        instruction
+    }
+
+    /// Returns the span of the instruction, `Error` is the only instruction without it.
+    pub fn span(&self) -> Option<Span> {
+        use Instruction::*;
+
+        match self {
+            Call(call) => Some(call.span),
+            Ap(ap) => Some(ap.span),
+            ApMap(ap_map) => Some(ap_map.span),
+            Canon(canon) => Some(canon.span),
+            CanonMap(canon_map) => Some(canon_map.span),
+            Seq(seq) => Some(seq.2),
+            Par(par) => Some(par.2),
+            Xor(xor) => Some(xor.2),
+            Match(match_) => Some(match_.span),
+            MisMatch(mismatch) => Some(mismatch.span),
+            Fail(fail) => Some(fail.span),
+            FoldScalar(fold) => Some(fold.span),
+            FoldStream(fold) => Some(fold.span),
+            FoldStreamMap(fold) => Some(fold.span),
+            Never(never) => Some(never.span),
+            New(new) => Some(new.span),
+            Next(next) => Some(next.span),
+            Null(null) => Some(null.span),
+            Commented(commented) => commented.instruction.span(),
+            Error => None,
+        }
+    }
+}
+
 impl<'i> Ap<'i> {
-    pub fn new(argument: ApArgument<'i>, result: ApResult<'i>) -> Self {
-        Self { argument, result }
+    pub fn new(argument: ApArgument<'i>, result: ApResult<'i>, span: Span) -> Self {
+        Self {
+            argument,
+            result,
+            span,
+        }
+    }
+}
+
+impl<'i> ApMap<'i> {
+    pub fn new(
+        key: StreamMapKeyClause<'i>,
+        value: ApArgument<'i>,
+        map: StreamMap<'i>,
+        span: Span,
+    ) -> Self {
+        Self {
+            key,
+            value,
+            map,
+            span,
+        }
     }
 }
 
@@ -27,11 +87,13 @@ impl<'i> Call<'i> {
         triplet: Triplet<'i>,
         args: Rc<Vec<ImmutableValue<'i>>>,
         output: CallOutputValue<'i>,
+        span: Span,
     ) -> Self {
         Self {
             triplet,
             args,
             output,
+            span,
         }
     }
 }
@@ -41,11 +103,29 @@ impl<'i> Canon<'i> {
         peer_id: ResolvableToPeerIdVariable<'i>,
         stream: Stream<'i>,
         canon_stream: CanonStream<'i>,
+        span: Span,
     ) -> Self {
         Self {
             peer_id,
             stream,
             canon_stream,
+            span,
+        }
+    }
+}
+
+impl<'i> CanonMap<'i> {
+    pub fn new(
+        peer_id: ResolvableToPeerIdVariable<'i>,
+        stream_map: StreamMap<'i>,
+        scalar: Scalar<'i>,
+        span: Span,
+    ) -> Self {
+        Self {
+            peer_id,
+            stream_map,
+            scalar,
+            span,
         }
     }
 }
@@ -54,8 +134,9 @@ impl<'i> Seq<'i> {
     pub fn new(
         left_instruction: Box<Instruction<'i>>,
         right_instruction: Box<Instruction<'i>>,
+        span: Span,
     ) -> Self {
-        Self(left_instruction, right_instruction)
+        Self(left_instruction, right_instruction, span)
     }
 }
 
@@ -63,8 +144,9 @@ impl<'i> Par<'i> {
     pub fn new(
         left_instruction: Box<Instruction<'i>>,
         right_instruction: Box<Instruction<'i>>,
+        span: Span,
     ) -> Self {
-        Self(left_instruction, right_instruction)
+        Self(left_instruction, right_instruction, span)
     }
 }
 
@@ -72,8 +154,9 @@ impl<'i> Xor<'i> {
     pub fn new(
         left_instruction: Box<Instruction<'i>>,
         right_instruction: Box<Instruction<'i>>,
+        span: Span,
     ) -> Self {
-        Self(left_instruction, right_instruction)
+        Self(left_instruction, right_instruction, span)
     }
 }
 
@@ -82,11 +165,13 @@ impl<'i> Match<'i> {
         left_value: ImmutableValue<'i>,
         right_value: ImmutableValue<'i>,
         instruction: Box<Instruction<'i>>,
+        span: Span,
     ) -> Self {
         Self {
             left_value,
             right_value,
             instruction,
+            span,
         }
     }
 }
@@ -96,11 +181,13 @@ impl<'i> MisMatch<'i> {
         left_value: ImmutableValue<'i>,
         right_value: ImmutableValue<'i>,
         instruction: Box<Instruction<'i>>,
+        span: Span,
     ) -> Self {
         Self {
             left_value,
             right_value,
             instruction,
+            span,
         }
     }
 }
@@ -141,9 +228,45 @@ impl<'i> FoldStream<'i> {
     }
 }
 
+impl<'i> FoldStreamMap<'i> {
+    pub fn new(
+        iterable: StreamMap<'i>,
+        iterator: Scalar<'i>,
+        instruction: Instruction<'i>,
+        last_instruction: Option<Instruction<'i>>,
+        span: Span,
+    ) -> Self {
+        Self {
+            iterable,
+            iterator,
+            instruction: Rc::new(instruction),
+            last_instruction: last_instruction.map(Rc::new),
+            span,
+        }
+    }
+}
+
 impl<'i> Next<'i> {
-    pub fn new(iterator: Scalar<'i>) -> Self {
-        Self { iterator }
+    pub fn new(iterator: Scalar<'i>, span: Span) -> Self {
+        Self { iterator, span }
+    }
+}
+
+impl Never {
+    pub fn new(span: Span) -> Self {
+        Self { span }
+    }
+}
+
+impl Null {
+    pub fn new(span: Span) -> Self {
+        Self { span }
+    }
+}
+
+impl<'i> Fail<'i> {
+    pub fn new(argument: FailArgument<'i>, span: Span) -> Self {
+        Self { argument, span }
     }
 }
 
@@ -157,3 +280,26 @@ impl<'i> New<'i> {
         }
     }
 }
+
+impl<'i> Comment<'i> {
+    pub fn new(text: &'i str, span: Span) -> Self {
+        Self {
+            text: text.into(),
+            span,
+        }
+    }
+}
+
+impl<'i> Commented<'i> {
+    pub fn new(
+        leading: Vec<Comment<'i>>,
+        instruction: Box<Instruction<'i>>,
+        trailing: Vec<Comment<'i>>,
+    ) -> Self {
+        Self {
+            leading,
+            instruction,
+            trailing,
+        }
+    }
+}
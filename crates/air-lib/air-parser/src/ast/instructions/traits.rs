@@ -25,7 +25,9 @@ impl fmt::Display for Instruction<'_> {
         match self {
             Call(call) => write!(f, "{call}"),
             Canon(canon) => write!(f, "{canon}"),
+            CanonMap(canon_map) => write!(f, "{canon_map}"),
             Ap(ap) => write!(f, "{ap}"),
+            ApMap(ap_map) => write!(f, "{ap_map}"),
             Seq(seq) => write!(f, "{seq}"),
             Par(par) => write!(f, "{par}"),
             Xor(xor) => write!(f, "{xor}"),
@@ -34,10 +36,12 @@ impl fmt::Display for Instruction<'_> {
             Fail(fail) => write!(f, "{fail}"),
             FoldScalar(fold) => write!(f, "{fold}"),
             FoldStream(fold) => write!(f, "{fold}"),
+            FoldStreamMap(fold) => write!(f, "{fold}"),
             Never(never) => write!(f, "{never}"),
             Next(next) => write!(f, "{next}"),
             New(new) => write!(f, "{new}"),
             Null(null) => write!(f, "{null}"),
+            Commented(commented) => write!(f, "{commented}"),
             Error => write!(f, "error"),
         }
     }
@@ -62,26 +66,31 @@ impl fmt::Display for Canon<'_> {
     }
 }
 
+impl fmt::Display for CanonMap<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "canon {} {} {}",
+            self.peer_id, self.stream_map, self.scalar
+        )
+    }
+}
+
 impl fmt::Display for Ap<'_> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "ap {} {}", self.argument, self.result)
     }
 }
 
+impl fmt::Display for ApMap<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "ap ({} {}) {}", self.key, self.value, self.map)
+    }
+}
+
 impl fmt::Display for Fail<'_> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        match self {
-            Fail::Scalar(scalar) => write!(f, "fail {scalar}"),
-            Fail::ScalarWithLambda(scalar) => write!(f, "fail {scalar}"),
-            Fail::Literal {
-                ret_code,
-                error_message,
-            } => write!(f, r#"fail {ret_code} "{error_message}""#),
-            Fail::CanonStreamWithLambda(stream) => {
-                write!(f, "fail {stream}")
-            }
-            Fail::LastError => write!(f, "fail %last_error%"),
-        }
+        write!(f, "fail {}", self.argument)
     }
 }
 
@@ -97,6 +106,12 @@ impl fmt::Display for FoldStream<'_> {
     }
 }
 
+impl fmt::Display for FoldStreamMap<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "fold {} {}", self.iterable, self.iterator)
+    }
+}
+
 impl fmt::Display for Seq<'_> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "seq")
@@ -150,3 +165,16 @@ impl fmt::Display for New<'_> {
         write!(f, "new {}", self.argument)
     }
 }
+
+impl fmt::Display for Comment<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.text)
+    }
+}
+
+impl fmt::Display for Commented<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // comments are trivia, so they aren't a part of instruction representation
+        write!(f, "{}", self.instruction)
+    }
+}
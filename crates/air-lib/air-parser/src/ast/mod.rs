@@ -16,6 +16,7 @@
 
 mod instruction_arguments;
 mod instructions;
+mod printer;
 mod values;
 
 #[cfg(test)]
@@ -23,6 +24,8 @@ pub mod tests;
 
 pub use instruction_arguments::*;
 pub use instructions::*;
+pub use printer::print;
+pub use printer::print_indented;
 pub use values::*;
 
 pub use crate::parser::Span;
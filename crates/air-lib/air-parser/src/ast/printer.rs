@@ -0,0 +1,162 @@
+/*
+ * Copyright 2023 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use super::*;
+
+use std::fmt::Display;
+use std::fmt::Write;
+
+/// Print AST back to AIR, the result is parsed to the same AST up to positions of its nodes.
+///
+/// Instructions are printed on one line, every comment is followed by a line break,
+/// since a comment lasts up to the end of a line. The `Error` node doesn't correspond
+/// to any valid AIR, it's met only in AST of invalid scripts and printed as `(error)`.
+pub fn print(instruction: &Instruction<'_>) -> String {
+    Printer::new(None).print(instruction)
+}
+
+/// Print AST back to AIR the same way as `print`, but every instruction with nested instructions
+/// is put on its own line and its children are indented by `indent_step` spaces.
+///
+/// The first trailing comment is kept on the line of the instruction it trails, the rest
+/// are put on separate lines after it.
+pub fn print_indented(instruction: &Instruction<'_>, indent_step: usize) -> String {
+    Printer::new(Some(indent_step)).print(instruction)
+}
+
+struct Printer {
+    air: String,
+    // instructions are printed on one line if there is no indentation step
+    indent_step: Option<usize>,
+    // a comment lasts up to the end of a line, so the next token must start a new line
+    after_comment: bool,
+}
+
+impl Printer {
+    fn new(indent_step: Option<usize>) -> Self {
+        Self {
+            air: String::new(),
+            indent_step,
+            after_comment: false,
+        }
+    }
+
+    fn print(mut self, instruction: &Instruction<'_>) -> String {
+        self.print_instruction(instruction, 0)
+            .expect("writing to a string never fails. QED.");
+        self.air
+    }
+
+    // an instruction is printed without the final line break, so a trailing comment can follow it
+    fn print_instruction(
+        &mut self,
+        instruction: &Instruction<'_>,
+        indent: usize,
+    ) -> std::fmt::Result {
+        use std::iter::once;
+
+        match instruction {
+            Instruction::Seq(seq) => self.print_compound(seq, [&*seq.0, &*seq.1], indent),
+            Instruction::Par(par) => self.print_compound(par, [&*par.0, &*par.1], indent),
+            Instruction::Xor(xor) => self.print_compound(xor, [&*xor.0, &*xor.1], indent),
+            Instruction::Match(match_) => {
+                self.print_compound(match_, [&*match_.instruction], indent)
+            }
+            Instruction::MisMatch(mismatch) => {
+                self.print_compound(mismatch, [&*mismatch.instruction], indent)
+            }
+            Instruction::FoldScalar(fold) => {
+                let children = once(&*fold.instruction).chain(fold.last_instruction.as_deref());
+                self.print_compound(fold, children, indent)
+            }
+            Instruction::FoldStream(fold) => {
+                let children = once(&*fold.instruction).chain(fold.last_instruction.as_deref());
+                self.print_compound(fold, children, indent)
+            }
+            Instruction::FoldStreamMap(fold) => {
+                let children = once(&*fold.instruction).chain(fold.last_instruction.as_deref());
+                self.print_compound(fold, children, indent)
+            }
+            Instruction::New(new) => self.print_compound(new, [&*new.instruction], indent),
+            Instruction::Commented(commented) => self.print_commented(commented, indent),
+            instruction => {
+                self.print_indent(indent);
+                // a call without output is displayed with a trailing space
+                write!(self.air, "({})", instruction.to_string().trim_end())
+            }
+        }
+    }
+
+    fn print_compound<'node, 'i: 'node>(
+        &mut self,
+        instruction: impl Display,
+        children: impl IntoIterator<Item = &'node Instruction<'i>>,
+        indent: usize,
+    ) -> std::fmt::Result {
+        self.print_indent(indent);
+        write!(self.air, "({instruction}")?;
+
+        let child_indent = indent + self.indent_step.unwrap_or_default();
+        for child in children {
+            self.print_separator(" ");
+            self.print_instruction(child, child_indent)?;
+        }
+
+        self.print_separator("");
+        self.print_indent(indent);
+        self.air.push(')');
+        Ok(())
+    }
+
+    fn print_commented(&mut self, commented: &Commented<'_>, indent: usize) -> std::fmt::Result {
+        for comment in &commented.leading {
+            self.print_indent(indent);
+            writeln!(self.air, "{comment}")?;
+        }
+
+        self.print_instruction(&commented.instruction, indent)?;
+
+        // the rest of trailing comments still trail the instruction when parsed
+        let mut trailing = commented.trailing.iter();
+        if let Some(comment) = trailing.next() {
+            write!(self.air, " {comment}")?;
+            self.after_comment = true;
+        }
+        for comment in trailing {
+            self.air.push('\n');
+            self.print_indent(indent);
+            write!(self.air, "{comment}")?;
+        }
+
+        Ok(())
+    }
+
+    /// Separates tokens with a line break if instructions are indented or a comment precedes them.
+    fn print_separator(&mut self, one_line_separator: &str) {
+        let after_comment = std::mem::take(&mut self.after_comment);
+        if self.indent_step.is_some() || after_comment {
+            self.air.push('\n');
+        } else {
+            self.air.push_str(one_line_separator);
+        }
+    }
+
+    fn print_indent(&mut self, indent: usize) {
+        if self.indent_step.is_some() {
+            self.air.push_str(&" ".repeat(indent));
+        }
+    }
+}
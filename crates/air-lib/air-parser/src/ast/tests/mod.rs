@@ -16,3 +16,4 @@
 
 pub mod instruction_arguments;
 pub mod instructions;
+pub mod printer;
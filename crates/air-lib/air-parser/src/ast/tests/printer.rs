@@ -0,0 +1,143 @@
+/*
+ * Copyright 2023 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use crate::ast::print;
+use crate::ast::print_indented;
+use crate::ast::Instruction;
+
+use serde_json::Value;
+
+const SCRIPT: &str = r#"
+    ;; leading
+    (seq
+        (new $stream
+            (par
+                (call %init_peer_id% ("service" "function") [1 2.0 "literal" []] $stream)
+                (ap ("key" 1) %map))) ;; trailing
+        (xor
+            (match %last_error%.$.message "error"
+                (fold $stream iterator
+                    (seq
+                        (canon "peer" $stream #canon)
+                        (next iterator))
+                    (never)))
+            (seq
+                (canon "peer" %map scalar)
+                (fail 1 "message"))))
+    "#;
+
+/// AST serialized to JSON without node positions, printed AIR is laid out differently
+/// from the original script, so only this view of their ASTs is expected to be the same.
+fn without_positions(ast: &Instruction<'_>) -> Value {
+    fn strip_positions(value: &mut Value) {
+        match value {
+            // spans of tuple-like instructions aren't named
+            Value::Object(fields)
+                if fields.len() == 2
+                    && fields.contains_key("left")
+                    && fields.contains_key("right") =>
+            {
+                *value = Value::Null
+            }
+            Value::Object(fields) => {
+                fields.remove("position");
+                fields.remove("span");
+                fields.values_mut().for_each(strip_positions);
+            }
+            Value::Array(items) => items.iter_mut().for_each(strip_positions),
+            _ => {}
+        }
+    }
+
+    let mut value = serde_json::to_value(ast).unwrap();
+    strip_positions(&mut value);
+    value
+}
+
+#[test]
+fn printed_ast_is_parsed_to_the_same_ast() {
+    let ast = crate::parse_with_comments(SCRIPT).unwrap();
+
+    let printed = print(&ast);
+    let reparsed = crate::parse_with_comments(&printed).unwrap();
+    assert_eq!(without_positions(&reparsed), without_positions(&ast));
+
+    let printed = print_indented(&ast, 4);
+    let reparsed = crate::parse_with_comments(&printed).unwrap();
+    assert_eq!(without_positions(&reparsed), without_positions(&ast));
+}
+
+#[test]
+fn print_compound_instructions() {
+    let ast = crate::parse_with_comments(
+        r#"
+        (seq
+            (call "peer" ("service" "function") [])
+            (xor (null) (ap 1 $stream)))
+        "#,
+    )
+    .unwrap();
+
+    assert_eq!(
+        print(&ast),
+        r#"(seq (call "peer" ("service" "function") []) (xor (null) (ap 1 $stream)))"#
+    );
+}
+
+#[test]
+fn print_comments() {
+    let ast = crate::parse_with_comments(
+        r#"
+        ;; first
+        (seq
+            (null) ;; second
+            (never))
+        "#,
+    )
+    .unwrap();
+
+    assert_eq!(print(&ast), ";; first\n(seq (null) ;; second\n(never))");
+    assert_eq!(
+        print_indented(&ast, 2),
+        ";; first\n(seq\n  (null) ;; second\n  (never)\n)"
+    );
+}
+
+#[test]
+fn ast_is_deserialized_from_json() {
+    let ast = crate::parse_with_comments(SCRIPT).unwrap();
+
+    let json = serde_json::to_string(&ast).unwrap();
+    let deserialized: Box<Instruction<'_>> = serde_json::from_str(&json).unwrap();
+    assert_eq!(deserialized, ast);
+}
+
+#[test]
+fn escaped_strings_survive_json_round_trip() {
+    // backslashes and quotes are escaped in JSON, so these strings can't be borrowed from it
+    let script = r#"
+        ;; "quoted" comment
+        (seq
+            (call "peer" ("service" "function") ["back\\slash"])
+            (fail 1 "C:\\path"))
+    "#;
+    let ast = crate::parse_with_comments(script).unwrap();
+
+    let json = serde_json::to_string(&ast).unwrap();
+    let deserialized: Box<Instruction<'_>> = serde_json::from_str(&json).unwrap();
+    assert_eq!(deserialized, ast);
+    assert_eq!(print(&deserialized), print(&ast));
+}
@@ -46,6 +46,13 @@ pub struct Stream<'i> {
     pub position: AirPos,
 }
 
+/// A stream map without a lambda.
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+pub struct StreamMap<'i> {
+    pub name: &'i str,
+    pub position: AirPos,
+}
+
 /// A canonicalized stream without a lambda.
 #[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
 pub struct CanonStream<'i> {
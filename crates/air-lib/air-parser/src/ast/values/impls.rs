@@ -69,6 +69,12 @@ impl<'i> Stream<'i> {
     }
 }
 
+impl<'i> StreamMap<'i> {
+    pub fn new(name: &'i str, position: AirPos) -> Self {
+        Self { name, position }
+    }
+}
+
 impl<'i> ImmutableVariable<'i> {
     pub fn scalar(name: &'i str, position: AirPos) -> Self {
         Self::Scalar(Scalar::new(name, position))
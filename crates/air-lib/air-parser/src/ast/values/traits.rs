@@ -35,6 +35,12 @@ impl fmt::Display for Stream<'_> {
     }
 }
 
+impl fmt::Display for StreamMap<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.name)
+    }
+}
+
 impl fmt::Display for CanonStream<'_> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.name)
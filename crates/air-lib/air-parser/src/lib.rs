@@ -26,12 +26,19 @@
 )]
 
 pub mod ast;
+pub mod lint;
 mod parser;
 
 pub use parser::lexer::AirPos;
+pub use parser::lexer::Token;
 pub use parser::parse;
+pub use parser::parse_with_comments;
+pub use parser::parse_with_diagnostics;
 pub use parser::AIRLexer;
 pub use parser::AIRParser;
+pub use parser::CommentsCollector;
+pub use parser::ParserDiagnostic;
+pub use parser::SourcePosition;
 pub use parser::VariableValidator;
 
 #[cfg(test)]
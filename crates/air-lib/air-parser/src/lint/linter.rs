@@ -0,0 +1,378 @@
+/*
+ * Copyright 2023 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use super::LintWarning;
+use crate::ast::*;
+use crate::AirPos;
+
+use air_lambda_ast::LambdaAST;
+use air_lambda_ast::ValueAccessor;
+
+use std::collections::HashSet;
+
+/// Walks AST in the depth-first order and collects warnings.
+pub(super) struct Linter<'i> {
+    /// Count of xor instructions the current one is in the left branch of.
+    enclosing_xors: usize,
+
+    /// Names of all variables read so far, in the order they were met.
+    reads: Vec<&'i str>,
+
+    /// Names of iterators met in next instructions so far.
+    nexts: Vec<&'i str>,
+
+    /// Names and positions of scalars defined by call, ap and canon.
+    scalar_definitions: Vec<(&'i str, AirPos)>,
+    warnings: Vec<LintWarning>,
+}
+
+impl<'i> Linter<'i> {
+    pub(super) fn new() -> Self {
+        Self {
+            enclosing_xors: 0,
+            reads: vec![],
+            nexts: vec![],
+            scalar_definitions: vec![],
+            warnings: vec![],
+        }
+    }
+
+    pub(super) fn lint(mut self, instruction: &Instruction<'i>) -> Vec<LintWarning> {
+        self.visit(instruction);
+
+        let reads = self.reads.iter().copied().collect::<HashSet<_>>();
+        for (scalar_name, position) in self.scalar_definitions {
+            if !reads.contains(scalar_name) {
+                self.warnings.push(LintWarning::UnusedScalar {
+                    span: name_span(scalar_name, position),
+                    scalar_name: scalar_name.to_string(),
+                });
+            }
+        }
+
+        self.warnings
+    }
+
+    fn visit(&mut self, instruction: &Instruction<'i>) {
+        if let Instruction::Commented(commented) = instruction {
+            return self.visit(&commented.instruction);
+        }
+
+        // only error instructions have no span, they aren't met in a successfully parsed AST
+        let span = match instruction.span() {
+            Some(span) => span,
+            None => return,
+        };
+
+        match instruction {
+            Instruction::Call(call) => self.visit_call(call),
+            Instruction::Ap(ap) => {
+                self.met_ap_argument(&ap.argument);
+                if let ApResult::Scalar(scalar) = &ap.result {
+                    self.met_scalar_definition(scalar);
+                }
+            }
+            Instruction::ApMap(ap_map) => {
+                self.met_stream_map_key(&ap_map.key);
+                self.met_ap_argument(&ap_map.value);
+            }
+            Instruction::Canon(canon) => {
+                self.met_peer_id(&canon.peer_id);
+                self.reads.push(canon.stream.name);
+            }
+            Instruction::CanonMap(canon_map) => {
+                self.met_peer_id(&canon_map.peer_id);
+                self.reads.push(canon_map.stream_map.name);
+                self.met_scalar_definition(&canon_map.scalar);
+            }
+            Instruction::Seq(Seq(left, right, _)) | Instruction::Par(Par(left, right, _)) => {
+                self.visit(left);
+                self.visit(right);
+            }
+            Instruction::Xor(Xor(left, right, _)) => {
+                if !can_fail(left) {
+                    self.warnings
+                        .push(LintWarning::XorLeftBranchNeverFails(span));
+                }
+
+                // only failures of the left branch are caught by xor
+                self.enclosing_xors += 1;
+                self.visit(left);
+                self.enclosing_xors -= 1;
+                self.visit(right);
+            }
+            Instruction::Match(match_) => {
+                if self.enclosing_xors == 0 {
+                    self.warnings.push(LintWarning::MatchWithoutXor(span));
+                }
+                self.met_value(&match_.left_value);
+                self.met_value(&match_.right_value);
+                self.visit(&match_.instruction);
+            }
+            Instruction::MisMatch(mismatch) => {
+                if self.enclosing_xors == 0 {
+                    self.warnings.push(LintWarning::MismatchWithoutXor(span));
+                }
+                self.met_value(&mismatch.left_value);
+                self.met_value(&mismatch.right_value);
+                self.visit(&mismatch.instruction);
+            }
+            Instruction::Fail(fail) => self.visit_fail(fail),
+            Instruction::FoldScalar(fold) => {
+                match &fold.iterable {
+                    FoldScalarIterable::Scalar(scalar) => self.reads.push(scalar.name),
+                    FoldScalarIterable::ScalarWithLambda(scalar) => self.met_scalar_wl(scalar),
+                    FoldScalarIterable::CanonStream(canon_stream) => {
+                        self.reads.push(canon_stream.name)
+                    }
+                    FoldScalarIterable::EmptyArray => {}
+                }
+                self.visit_fold(
+                    &fold.iterator,
+                    &fold.instruction,
+                    &fold.last_instruction,
+                    span,
+                );
+            }
+            Instruction::FoldStream(fold) => {
+                self.reads.push(fold.iterable.name);
+                self.visit_fold(
+                    &fold.iterator,
+                    &fold.instruction,
+                    &fold.last_instruction,
+                    span,
+                );
+            }
+            Instruction::FoldStreamMap(fold) => {
+                self.reads.push(fold.iterable.name);
+                self.visit_fold(
+                    &fold.iterator,
+                    &fold.instruction,
+                    &fold.last_instruction,
+                    span,
+                );
+            }
+            Instruction::New(new) => self.visit_new(new),
+            Instruction::Next(next) => {
+                self.reads.push(next.iterator.name);
+                self.nexts.push(next.iterator.name);
+            }
+            Instruction::Never(_)
+            | Instruction::Null(_)
+            | Instruction::Commented(_)
+            | Instruction::Error => {}
+        }
+    }
+
+    fn visit_call(&mut self, call: &Call<'i>) {
+        self.met_peer_id(&call.triplet.peer_id);
+        self.met_string_resolvable(&call.triplet.service_id);
+        self.met_string_resolvable(&call.triplet.function_name);
+        for arg in call.args.iter() {
+            self.met_value(arg);
+        }
+
+        if let CallOutputValue::Scalar(scalar) = &call.output {
+            self.met_scalar_definition(scalar);
+        }
+    }
+
+    fn visit_fail(&mut self, fail: &Fail<'i>) {
+        match &fail.argument {
+            FailArgument::Scalar(scalar) => self.reads.push(scalar.name),
+            FailArgument::ScalarWithLambda(scalar) => self.met_scalar_wl(scalar),
+            FailArgument::CanonStreamWithLambda(canon_stream) => {
+                self.met_canon_stream_wl(canon_stream)
+            }
+            FailArgument::Literal { .. } | FailArgument::LastError => {}
+        }
+    }
+
+    fn visit_fold(
+        &mut self,
+        iterator: &Scalar<'i>,
+        instruction: &Instruction<'i>,
+        last_instruction: &Option<std::rc::Rc<Instruction<'i>>>,
+        span: Span,
+    ) {
+        let nexts_before = self.nexts.len();
+        self.visit(instruction);
+        if !self.nexts[nexts_before..].contains(&iterator.name) {
+            self.warnings.push(LintWarning::FoldWithoutNext {
+                span,
+                iterator_name: iterator.name.to_string(),
+            });
+        }
+
+        if let Some(last_instruction) = last_instruction {
+            self.visit(last_instruction);
+        }
+    }
+
+    fn visit_new(&mut self, new: &New<'i>) {
+        let (name, position) = match &new.argument {
+            NewArgument::Scalar(scalar) => (scalar.name, scalar.position),
+            NewArgument::Stream(stream) => (stream.name, stream.position),
+            NewArgument::CanonStream(canon_stream) => (canon_stream.name, canon_stream.position),
+            NewArgument::StreamMap(stream_map) => (stream_map.name, stream_map.position),
+        };
+
+        let reads_before = self.reads.len();
+        self.visit(&new.instruction);
+        if !self.reads[reads_before..].contains(&name) {
+            self.warnings.push(LintWarning::UnreadNewVariable {
+                span: name_span(name, position),
+                variable_name: name.to_string(),
+            });
+        }
+    }
+
+    fn met_scalar_definition(&mut self, scalar: &Scalar<'i>) {
+        self.scalar_definitions.push((scalar.name, scalar.position));
+    }
+
+    fn met_value(&mut self, value: &ImmutableValue<'i>) {
+        match value {
+            ImmutableValue::Variable(ImmutableVariable::Scalar(scalar)) => {
+                self.reads.push(scalar.name)
+            }
+            ImmutableValue::Variable(ImmutableVariable::CanonStream(canon_stream)) => {
+                self.reads.push(canon_stream.name)
+            }
+            ImmutableValue::VariableWithLambda(ImmutableVariableWithLambda::Scalar(scalar)) => {
+                self.met_scalar_wl(scalar)
+            }
+            ImmutableValue::VariableWithLambda(ImmutableVariableWithLambda::CanonStream(
+                canon_stream,
+            )) => self.met_canon_stream_wl(canon_stream),
+            ImmutableValue::LastError(Some(lambda)) => self.met_lambda(lambda),
+            ImmutableValue::InitPeerId
+            | ImmutableValue::LastError(None)
+            | ImmutableValue::Timestamp
+            | ImmutableValue::TTL
+            | ImmutableValue::Literal(_)
+            | ImmutableValue::Number(_)
+            | ImmutableValue::Boolean(_)
+            | ImmutableValue::EmptyArray => {}
+        }
+    }
+
+    fn met_ap_argument(&mut self, argument: &ApArgument<'i>) {
+        match argument {
+            ApArgument::Scalar(scalar) => self.reads.push(scalar.name),
+            ApArgument::ScalarWithLambda(scalar) => self.met_scalar_wl(scalar),
+            ApArgument::CanonStream(canon_stream) => self.reads.push(canon_stream.name),
+            ApArgument::CanonStreamWithLambda(canon_stream) => {
+                self.met_canon_stream_wl(canon_stream)
+            }
+            ApArgument::LastError(Some(lambda)) => self.met_lambda(lambda),
+            ApArgument::InitPeerId
+            | ApArgument::Timestamp
+            | ApArgument::TTL
+            | ApArgument::LastError(None)
+            | ApArgument::Literal(_)
+            | ApArgument::Number(_)
+            | ApArgument::Boolean(_)
+            | ApArgument::EmptyArray => {}
+        }
+    }
+
+    fn met_stream_map_key(&mut self, key: &StreamMapKeyClause<'i>) {
+        match key {
+            StreamMapKeyClause::Scalar(scalar) => self.reads.push(scalar.name),
+            StreamMapKeyClause::ScalarWithLambda(scalar) => self.met_scalar_wl(scalar),
+            StreamMapKeyClause::CanonStreamWithLambda(canon_stream) => {
+                self.met_canon_stream_wl(canon_stream)
+            }
+            StreamMapKeyClause::Literal(_) | StreamMapKeyClause::Int(_) => {}
+        }
+    }
+
+    fn met_peer_id(&mut self, peer_id: &ResolvableToPeerIdVariable<'i>) {
+        match peer_id {
+            ResolvableToPeerIdVariable::Scalar(scalar) => self.reads.push(scalar.name),
+            ResolvableToPeerIdVariable::ScalarWithLambda(scalar) => self.met_scalar_wl(scalar),
+            ResolvableToPeerIdVariable::CanonStreamWithLambda(canon_stream) => {
+                self.met_canon_stream_wl(canon_stream)
+            }
+            ResolvableToPeerIdVariable::InitPeerId | ResolvableToPeerIdVariable::Literal(_) => {}
+        }
+    }
+
+    fn met_string_resolvable(&mut self, value: &ResolvableToStringVariable<'i>) {
+        match value {
+            ResolvableToStringVariable::Scalar(scalar) => self.reads.push(scalar.name),
+            ResolvableToStringVariable::ScalarWithLambda(scalar) => self.met_scalar_wl(scalar),
+            ResolvableToStringVariable::CanonStreamWithLambda(canon_stream) => {
+                self.met_canon_stream_wl(canon_stream)
+            }
+            ResolvableToStringVariable::Literal(_) => {}
+        }
+    }
+
+    fn met_scalar_wl(&mut self, scalar: &ScalarWithLambda<'i>) {
+        self.reads.push(scalar.name);
+        self.met_lambda(&scalar.lambda);
+    }
+
+    fn met_canon_stream_wl(&mut self, canon_stream: &CanonStreamWithLambda<'i>) {
+        self.reads.push(canon_stream.name);
+        self.met_lambda(&canon_stream.lambda);
+    }
+
+    fn met_lambda(&mut self, lambda: &LambdaAST<'i>) {
+        let accessors = match lambda {
+            LambdaAST::ValuePath(accessors) => accessors,
+            LambdaAST::Functor(_) => return,
+        };
+
+        for accessor in accessors.iter() {
+            if let &ValueAccessor::FieldAccessByScalar { scalar_name } = accessor {
+                self.reads.push(scalar_name);
+            }
+        }
+    }
+}
+
+/// Returns false only for instructions that are known to never fail.
+fn can_fail(instruction: &Instruction<'_>) -> bool {
+    match instruction {
+        Instruction::Null(_) | Instruction::Never(_) => false,
+        // ap of a constant to a stream doesn't depend on anything
+        Instruction::Ap(ap) => {
+            let is_constant = matches!(
+                ap.argument,
+                ApArgument::InitPeerId
+                    | ApArgument::Timestamp
+                    | ApArgument::TTL
+                    | ApArgument::Literal(_)
+                    | ApArgument::Number(_)
+                    | ApArgument::Boolean(_)
+                    | ApArgument::EmptyArray
+            );
+            !(is_constant && matches!(ap.result, ApResult::Stream(_)))
+        }
+        Instruction::Seq(Seq(left, right, _)) => can_fail(left) || can_fail(right),
+        Instruction::Par(Par(left, right, _)) => can_fail(left) && can_fail(right),
+        Instruction::New(new) => can_fail(&new.instruction),
+        Instruction::Commented(commented) => can_fail(&commented.instruction),
+        _ => true,
+    }
+}
+
+fn name_span(name: &str, position: AirPos) -> Span {
+    Span::new(position, position + name.len())
+}
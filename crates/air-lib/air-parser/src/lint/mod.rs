@@ -0,0 +1,41 @@
+/*
+ * Copyright 2023 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! Static checks that find scripts which are valid, but most likely behave not as intended.
+//!
+//! Unlike errors reported by the parser, warnings don't prevent a script from execution.
+
+mod linter;
+mod warnings;
+
+#[cfg(test)]
+mod tests;
+
+pub use warnings::LintWarning;
+
+use linter::Linter;
+
+/// Parse AIR `air_script` and check it for suspicious constructions.
+///
+/// Returns the same error as `parse` if the script isn't valid,
+/// otherwise warnings sorted by their positions in the script.
+pub fn lint(air_script: &str) -> Result<Vec<LintWarning>, String> {
+    let instruction = crate::parse(air_script)?;
+    let mut warnings = Linter::new().lint(&instruction);
+    warnings.sort_by_key(|warning| warning.span().left);
+
+    Ok(warnings)
+}
@@ -0,0 +1,195 @@
+/*
+ * Copyright 2023 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use super::lint;
+use super::LintWarning;
+use crate::ast::Span;
+
+/// Span of the instruction that starts with `prefix`.
+fn instruction_span(script: &str, prefix: &str) -> Span {
+    let start = script.find(prefix).unwrap();
+    let mut depth = 0;
+    for (offset, ch) in script[start..].char_indices() {
+        match ch {
+            '(' => depth += 1,
+            ')' if depth == 1 => return Span::new(start.into(), (start + offset + 1).into()),
+            ')' => depth -= 1,
+            _ => {}
+        }
+    }
+    unreachable!("test scripts are valid")
+}
+
+fn name_span(script: &str, name: &str) -> Span {
+    let start = script.find(name).unwrap();
+    Span::new(start.into(), (start + name.len()).into())
+}
+
+#[test]
+fn valid_script_has_no_warnings() {
+    let script = r#"
+        (seq
+            (call "peer" ("service" "function") [] result)
+            (xor
+                (match result 1
+                    (new $stream
+                        (fold $stream i
+                            (seq
+                                (call i ("" "") [])
+                                (next i)))))
+                (null)))
+        "#;
+
+    let warnings = lint(script).unwrap();
+    assert_eq!(warnings, vec![]);
+}
+
+#[test]
+fn match_and_mismatch_without_xor() {
+    let script = r#"
+        (seq
+            (match 1 2 (null))
+            (xor
+                (seq
+                    (null)
+                    (mismatch 1 1 (null)))
+                (mismatch 1 2 (null))))
+        "#;
+
+    let warnings = lint(script).unwrap();
+    let expected = vec![
+        LintWarning::MatchWithoutXor(instruction_span(script, "(match")),
+        LintWarning::MismatchWithoutXor(instruction_span(script, "(mismatch 1 2")),
+    ];
+    assert_eq!(warnings, expected);
+}
+
+#[test]
+fn xor_left_branch_never_fails() {
+    let script = r#"
+        (seq
+            (xor
+                (seq (null) (ap "constant" $stream))
+                (call "peer" ("" "") []))
+            (xor
+                (par (null) (call "peer" ("" "") []))
+                (null)))
+        "#;
+
+    let warnings = lint(script).unwrap();
+    let second_xor = &script[script.rfind("(xor").unwrap()..];
+    let expected = vec![
+        LintWarning::XorLeftBranchNeverFails(instruction_span(script, "(xor")),
+        LintWarning::XorLeftBranchNeverFails(instruction_span(script, second_xor)),
+    ];
+    assert_eq!(warnings, expected);
+}
+
+#[test]
+fn unused_scalar() {
+    let script = r#"
+        (seq
+            (call "peer" ("" "") [] used)
+            (seq
+                (ap used.$.field unused)
+                (canon "peer" %map scalar)))
+        "#;
+
+    let warnings = lint(script).unwrap();
+    let expected = vec![
+        LintWarning::UnusedScalar {
+            span: name_span(script, "unused"),
+            scalar_name: "unused".to_string(),
+        },
+        LintWarning::UnusedScalar {
+            span: name_span(script, "scalar"),
+            scalar_name: "scalar".to_string(),
+        },
+    ];
+    assert_eq!(warnings, expected);
+}
+
+#[test]
+fn scalar_used_in_lambda_is_used() {
+    let script = r#"
+        (seq
+            (call "peer" ("" "") [] key)
+            (seq
+                (call "peer" ("" "") [] object)
+                (call "peer" ("" "") [object.$.[key]])))
+        "#;
+
+    let warnings = lint(script).unwrap();
+    assert_eq!(warnings, vec![]);
+}
+
+#[test]
+fn fold_without_next() {
+    let script = r#"
+        (new $stream
+            (fold $stream i
+                (seq
+                    (call i ("" "") [])
+                    (fold $stream j
+                        (next i)))))
+        "#;
+
+    let warnings = lint(script).unwrap();
+    let expected = vec![LintWarning::FoldWithoutNext {
+        span: instruction_span(script, "(fold $stream j"),
+        iterator_name: "j".to_string(),
+    }];
+    assert_eq!(warnings, expected);
+}
+
+#[test]
+fn unread_new_variable() {
+    let script = r#"
+        (new $unread
+            (new $read
+                (seq
+                    (call "peer" ("" "") [] $unread)
+                    (canon "peer" $read #canon))))
+        "#;
+
+    let warnings = lint(script).unwrap();
+    let expected = vec![LintWarning::UnreadNewVariable {
+        span: name_span(script, "$unread"),
+        variable_name: "$unread".to_string(),
+    }];
+    assert_eq!(warnings, expected);
+}
+
+#[test]
+fn commented_instructions_are_linted() {
+    let script = r#"
+        (seq
+            ;; never fails
+            (match 1 2 (null)) ;; but here it does
+            (null))
+        "#;
+
+    let warnings = lint(script).unwrap();
+    let expected = vec![LintWarning::MatchWithoutXor(instruction_span(
+        script, "(match",
+    ))];
+    assert_eq!(warnings, expected);
+}
+
+#[test]
+fn invalid_script_is_error() {
+    assert!(lint("(seq (null)").is_err());
+}
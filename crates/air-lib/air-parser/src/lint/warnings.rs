@@ -0,0 +1,57 @@
+/*
+ * Copyright 2023 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use crate::ast::Span;
+
+use thiserror::Error as ThisError;
+
+#[derive(ThisError, Debug, Clone, PartialEq, Eq)]
+pub enum LintWarning {
+    #[error("match isn't in the left branch of xor, so it fails the whole particle if values don't match")]
+    MatchWithoutXor(Span),
+
+    #[error(
+        "mismatch isn't in the left branch of xor, so it fails the whole particle if values match"
+    )]
+    MismatchWithoutXor(Span),
+
+    #[error("the left branch of xor can never fail, so the right one is never executed")]
+    XorLeftBranchNeverFails(Span),
+
+    #[error("scalar '{scalar_name}' is defined, but never used")]
+    UnusedScalar { span: Span, scalar_name: String },
+
+    #[error(
+        "fold over iterator '{iterator_name}' has no next, so only the first iteration is executed"
+    )]
+    FoldWithoutNext { span: Span, iterator_name: String },
+
+    #[error("'{variable_name}' restricted by new is never read inside it")]
+    UnreadNewVariable { span: Span, variable_name: String },
+}
+
+impl LintWarning {
+    pub fn span(&self) -> Span {
+        match self {
+            Self::MatchWithoutXor(span) => *span,
+            Self::MismatchWithoutXor(span) => *span,
+            Self::XorLeftBranchNeverFails(span) => *span,
+            Self::UnusedScalar { span, .. } => *span,
+            Self::FoldWithoutNext { span, .. } => *span,
+            Self::UnreadNewVariable { span, .. } => *span,
+        }
+    }
+}
@@ -1,6 +1,7 @@
 // auto-generated: "lalrpop 0.19.8"
-// sha3: 3ad9b22b9e4c2674604f2810bb1b7b297eecd7ba9091a4c8694fb34d4c670b6e
+// sha3: 3ac7d2dd1a0bb93d733b6cf5803de0a707ffcc993638d40c35956b3c467a261b
 use crate::ast::*;
+use crate::parser::CommentsCollector;
 use crate::parser::ParserError;
 use crate::parser::VariableValidator;
 use crate::parser::Span;
@@ -20,6 +21,7 @@ mod __parse__AIR {
     #![allow(non_snake_case, non_camel_case_types, unused_mut, unused_variables, unused_imports, unused_parens, clippy::all)]
 
     use crate::ast::*;
+    use crate::parser::CommentsCollector;
     use crate::parser::ParserError;
     use crate::parser::VariableValidator;
     use crate::parser::Span;
@@ -56,7 +58,7 @@ mod __parse__AIR {
         Variant16(CallOutputValue<'input>),
         Variant17(core::option::Option<CallOutputValue<'input>>),
         Variant18(CanonStream<'input>),
-        Variant19(Fail<'input>),
+        Variant19(FailArgument<'input>),
         Variant20(FoldScalarIterable<'input>),
         Variant21(ResolvableToStringVariable<'input>),
         Variant22(core::option::Option<Box<Instruction<'input>>>),
@@ -64,298 +66,346 @@ mod __parse__AIR {
         Variant24(Number),
         Variant25(ResolvableToPeerIdVariable<'input>),
         Variant26(Stream<'input>),
-        Variant27(Triplet<'input>),
+        Variant27(StreamMap<'input>),
+        Variant28(StreamMapKeyClause<'input>),
+        Variant29(Triplet<'input>),
     }
     const __ACTION: &[i16] = &[
         // State 0
-        36, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 37,
+        42, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 43,
         // State 1
-        0, 0, 42, 0, 43, 44, 45, 46, 47, 48, 49, 50, 51, 52, 53, 0, 54, 55, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        14, 0, 48, 0, 49, 50, 51, 52, 53, 54, 55, 56, 57, 58, 59, 0, 0, 60, 61, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 2
-        0, 0, 0, 0, 0, 0, 58, 0, 0, 59, 0, 0, 60, 61, 62, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 64, 0, 0, 65, 0, 0, 66, 67, 68, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 3
-        0, 0, 0, 0, 0, 0, 58, 0, 0, 59, 0, 0, 60, 61, 62, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 64, 0, 0, 65, 0, 0, 66, 67, 68, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 4
-        0, 0, 0, 0, 0, 0, 64, 0, 65, 0, 66, 0, 0, 67, 68, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 70, 0, 71, 0, 72, 0, 0, 73, 74, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 5
-        0, 0, 70, 0, 0, 71, 0, 0, 0, 0, 0, 0, 0, 72, 73, 74, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 77, 0, 0, 78, 0, 0, 0, 0, 0, 0, 0, 79, 80, 81, 82, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 6
-        0, 0, 76, 0, 77, 78, 79, 46, 47, 80, 81, 82, 83, 84, 85, 0, 86, 87, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 84, 0, 85, 86, 87, 52, 53, 88, 89, 90, 91, 92, 93, 0, 0, 94, 95, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 7
-        0, 0, 76, 0, 77, 78, 79, 46, 47, 80, 81, 82, 83, 84, 85, 0, 86, 87, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 84, 0, 85, 86, 87, 52, 53, 88, 89, 90, 91, 92, 93, 0, 0, 94, 95, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 8
-        0, 0, 0, 0, 0, 89, 0, 0, 0, 0, 0, 0, 0, 90, 0, 91, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 97, 0, 0, 0, 0, 0, 0, 0, 98, 0, 99, 100, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 9
-        36, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 37,
+        42, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 43,
         // State 10
-        36, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 37,
+        42, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 43,
         // State 11
-        36, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 37,
+        42, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 43,
         // State 12
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 95, 0, 96, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 104, 0, 105, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 13
-        0, 0, 24, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 106, 0, 107, 0, 0, 0, 108, 109, 110, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 14
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 98, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 26, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 15
-        0, 0, 76, 0, 77, 78, 79, 46, 47, 80, 81, 82, 83, 84, 85, 0, 86, 87, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 113, 82, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 16
-        0, 0, 76, 0, 77, 78, 79, 46, 47, 80, 81, 82, 83, 84, 85, 0, 86, 87, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 84, 0, 85, 86, 87, 52, 53, 88, 89, 90, 91, 92, 93, 0, 0, 94, 95, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 17
-        36, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 37,
+        0, 0, 84, 0, 85, 86, 87, 52, 53, 88, 89, 90, 91, 92, 93, 0, 0, 94, 95, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 18
-        36, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 37,
+        42, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 43,
         // State 19
-        36, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 37,
+        42, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 43,
         // State 20
-        36, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 37,
+        42, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 43,
         // State 21
-        0, 0, 0, 0, 0, 0, 110, 0, 0, 0, 0, 0, 111, 112, 113, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        42, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 43,
         // State 22
-        0, 115, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 116, 0, 117, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 48, 0, 49, 50, 51, 52, 53, 54, 55, 56, 57, 58, 59, 0, 0, 60, 61, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 23
-        0, 0, 76, 120, 77, 78, 79, 46, 47, 80, 81, 82, 83, 84, 85, 0, 86, 87, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 126, 0, 0, 0, 0, 0, 127, 128, 129, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 24
-        0, 0, 0, 0, 0, 122, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 131, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 132, 0, 133, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 25
-        36, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 37,
+        0, 0, 84, 136, 85, 86, 87, 52, 53, 88, 89, 90, 91, 92, 93, 0, 0, 94, 95, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 26
-        36, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 37,
+        0, 0, 0, 0, 0, 138, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 27
-        36, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 37,
+        42, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 43,
         // State 28
-        36, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 37,
+        42, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 43,
         // State 29
-        0, 0, 0, 0, 0, 0, 110, 0, 0, 0, 0, 0, 111, 112, 113, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        42, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 43,
         // State 30
-        0, 0, 76, 133, 77, 78, 79, 46, 47, 80, 81, 82, 83, 84, 85, 0, 86, 87, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        42, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 43,
         // State 31
-        36, 136, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 37,
+        42, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 43,
         // State 32
-        36, 138, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 37,
+        0, 0, 0, 0, 0, 0, 126, 0, 0, 0, 0, 0, 127, 128, 129, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 33
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 84, 150, 85, 86, 87, 52, 53, 88, 89, 90, 91, 92, 93, 0, 0, 94, 95, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 34
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        42, 154, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 43,
         // State 35
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 3, 4, 5, 6, 7, 8, 38, 9, 39, 40, 10, 11, 12, 0,
+        42, 156, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 43,
         // State 36
-        -60, -60, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -60,
+        42, 158, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 43,
         // State 37
-        0, 88, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 82, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 38
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 92, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 39
-        0, 93, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -65, -65, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -65,
         // State 40
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -15, 0, -15, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 41
-        0, 0, 0, 97, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 3, 4, 5, 6, 7, 8, 44, 9, 45, 46, 10, 11, 12, 0,
         // State 42
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -16, 0, -16, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -49, -49, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -49,
         // State 43
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -20, 0, -20, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 96, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 44
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -21, 0, -21, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 101, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 45
-        -67, 0, -67, -67, -67, -67, -67, -67, -67, -67, -67, -67, -67, -67, -67, -67, -67, -67, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -67,
+        0, 102, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 46
-        -66, 0, -66, -66, -66, -66, -66, -66, -66, -66, -66, -66, -66, -66, -66, -66, -66, -66, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -66,
+        0, -15, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -15, 0, -15, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 47
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -9, 0, -9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 111, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 48
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -10, 0, -10, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -16, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -16, 0, -16, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 49
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -11, 0, -11, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -20, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -20, 0, -20, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 50
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -14, 0, -14, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -21, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -21, 0, -21, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 51
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -18, 0, -18, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -73, -73, -73, -73, -73, -73, -73, -73, -73, -73, -73, -73, -73, -73, -73, -73, 0, -73, -73, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -73,
         // State 52
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -19, 0, -19, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -72, -72, -72, -72, -72, -72, -72, -72, -72, -72, -72, -72, -72, -72, -72, -72, 0, -72, -72, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -72,
         // State 53
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -13, 0, -13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -9, 0, -9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 54
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -12, 0, -12, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -10, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -10, 0, -10, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 55
-        22, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -11, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -11, 0, -11, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 56
-        -68, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -14, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -14, 0, -14, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 57
-        -73, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -73, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -18, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -18, 0, -18, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 58
-        -69, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -69, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -19, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -19, 0, -19, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 59
-        -70, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -70, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -13, 0, -13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 60
-        -71, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -71, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -12, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -12, 0, -12, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 61
-        -72, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -72, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        24, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 62
-        0, 99, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -74, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 63
-        0, -35, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -79, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -79, -79, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 64
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 100, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -75, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -75, -75, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 65
-        0, -36, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -76, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -76, -76, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 66
-        0, -32, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -77, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -77, -77, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 67
-        0, -33, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -78, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -78, -78, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 68
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 26, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 114, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 69
-        0, 0, 0, 101, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -58, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 70
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -39, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 115, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 71
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -37, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -59, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 72
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -38, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -55, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 73
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 27, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -56, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 74
-        -87, 0, -87, -87, -87, -87, -87, -87, -87, -87, -87, -87, -87, -87, -87, 0, -87, -87, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -87,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 28, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 75
-        0, 0, 0, 102, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 29, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 76
-        -88, 0, -88, -88, -88, -88, -88, -88, -88, -88, -88, -88, -88, -88, -88, 0, -88, -88, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -88,
+        0, 0, 0, 116, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 77
-        -92, 0, -92, -92, -92, -92, -92, -92, -92, -92, -92, -92, -92, -92, -92, 0, -92, -92, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -92,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -62, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 78
-        -93, 0, -93, -93, -93, -93, -93, -93, -93, -93, -93, -93, -93, -93, -93, 0, -93, -93, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -93,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -60, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 79
-        -81, 0, -81, -81, -81, -81, -81, -81, -81, -81, -81, -81, -81, -81, -81, 0, -81, -81, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -81,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -61, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 80
-        -82, 0, -82, -82, -82, -82, -82, -82, -82, -82, -82, -82, -82, -82, -82, 0, -82, -82, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -82,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 30, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 81
-        -83, 0, -83, -83, -83, -83, -83, -83, -83, -83, -83, -83, -83, -83, -83, 0, -83, -83, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -83,
+        0, -86, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -86, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 82
-        -84, 0, -84, -84, -84, -84, -84, -84, -84, -84, -84, -84, -84, -84, -84, 0, -84, -84, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -84,
+        -99, 0, -99, -99, -99, -99, -99, -99, -99, -99, -99, -99, -99, -99, -99, 0, 0, -99, -99, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -99,
         // State 83
-        -90, 0, -90, -90, -90, -90, -90, -90, -90, -90, -90, -90, -90, -90, -90, 0, -90, -90, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -90,
+        0, 0, 0, 117, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 84
-        -91, 0, -91, -91, -91, -91, -91, -91, -91, -91, -91, -91, -91, -91, -91, 0, -91, -91, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -91,
+        -100, 0, -100, -100, -100, -100, -100, -100, -100, -100, -100, -100, -100, -100, -100, 0, 0, -100, -100, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -100,
         // State 85
-        -86, 0, -86, -86, -86, -86, -86, -86, -86, -86, -86, -86, -86, -86, -86, 0, -86, -86, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -86,
+        -104, 0, -104, -104, -104, -104, -104, -104, -104, -104, -104, -104, -104, -104, -104, 0, 0, -104, -104, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -104,
         // State 86
-        -85, 0, -85, -85, -85, -85, -85, -85, -85, -85, -85, -85, -85, -85, -85, 0, -85, -85, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -85,
+        -105, 0, -105, -105, -105, -105, -105, -105, -105, -105, -105, -105, -105, -105, -105, 0, 0, -105, -105, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -105,
         // State 87
-        -48, -48, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -48,
+        -93, 0, -93, -93, -93, -93, -93, -93, -93, -93, -93, -93, -93, -93, -93, 0, 0, -93, -93, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -93,
         // State 88
-        -65, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -65,
+        -94, 0, -94, -94, -94, -94, -94, -94, -94, -94, -94, -94, -94, -94, -94, 0, 0, -94, -94, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -94,
         // State 89
-        -63, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -63,
+        -95, 0, -95, -95, -95, -95, -95, -95, -95, -95, -95, -95, -95, -95, -95, 0, 0, -95, -95, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -95,
         // State 90
-        -64, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -64,
+        -96, 0, -96, -96, -96, -96, -96, -96, -96, -96, -96, -96, -96, -96, -96, 0, 0, -96, -96, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -96,
         // State 91
-        0, 104, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -102, 0, -102, -102, -102, -102, -102, -102, -102, -102, -102, -102, -102, -102, -102, 0, 0, -102, -102, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -102,
         // State 92
-        -49, -49, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -49,
+        -103, 0, -103, -103, -103, -103, -103, -103, -103, -103, -103, -103, -103, -103, -103, 0, 0, -103, -103, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -103,
         // State 93
-        0, 108, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -98, 0, -98, -98, -98, -98, -98, -98, -98, -98, -98, -98, -98, -98, -98, 0, 0, -98, -98, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -98,
         // State 94
-        0, -22, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -97, 0, -97, -97, -97, -97, -97, -97, -97, -97, -97, -97, -97, -97, -97, 0, 0, -97, -97, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -97,
         // State 95
-        0, -23, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -35, -35, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -35,
         // State 96
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -17, 0, -17, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -70, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -70,
         // State 97
-        0, 0, 0, 0, 0, -79, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -68, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -68,
         // State 98
-        -51, -51, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -51,
+        -69, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -69,
         // State 99
-        0, -34, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -71, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -71,
         // State 100
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -40, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 119, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 101
-        -89, 0, -89, -89, -89, -89, -89, -89, -89, -89, -89, -89, -89, -89, -89, 0, -89, -89, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -89,
+        -36, -36, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -36,
         // State 102
-        0, 125, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 123, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 103
-        -56, -56, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -56,
+        0, -22, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 104
-        0, 126, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -23, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 105
-        0, 127, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, -91, 0, -91, -91, -91, -91, -91, -91, -91, -91, -91, -91, -91, 0, 0, -91, -91, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 106
-        0, 128, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, -88, 0, -88, -88, -88, -88, -88, -88, -88, -88, -88, -88, -88, 0, 0, -88, -88, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 107
-        -45, -45, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -45,
+        0, 0, -87, 0, -87, -87, -87, -87, -87, -87, -87, -87, -87, -87, -87, 0, 0, -87, -87, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 108
-        0, 0, 0, 0, 0, 0, -78, 0, 0, 0, 0, 0, -78, -78, -78, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, -89, 0, -89, -89, -89, -89, -89, -89, -89, -89, -89, -89, -89, 0, 0, -89, -89, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 109
-        0, -77, 0, 0, 0, 0, -77, 0, 0, 0, 0, 0, -77, -77, -77, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, -90, 0, -90, -90, -90, -90, -90, -90, -90, -90, -90, -90, -90, 0, 0, -90, -90, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 110
-        0, -74, 0, 0, 0, 0, -74, 0, 0, 0, 0, 0, -74, -74, -74, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -17, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -17, 0, -17, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 111
-        0, -75, 0, 0, 0, 0, -75, 0, 0, 0, 0, 0, -75, -75, -75, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 139, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 112
-        0, -76, 0, 0, 0, 0, -76, 0, 0, 0, 0, 0, -76, -76, -76, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, -85, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 113
-        0, 131, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -38, -38, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -38,
         // State 114
-        -43, -43, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -43,
+        0, -57, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 115
-        0, -27, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -63, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 116
-        0, -28, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -101, 0, -101, -101, -101, -101, -101, -101, -101, -101, -101, -101, -101, -101, -101, 0, 0, -101, -101, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -101,
         // State 117
-        0, 0, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, 0, -4, -4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 142, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 118
-        0, 0, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, 0, -24, -24, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -45, -45, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -45,
         // State 119
-        0, -25, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -25, 0, -25, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 143, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 120
-        0, 134, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 144, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 121
-        0, -31, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 145, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 122
-        0, 139, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -31, -31, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -31,
         // State 123
-        0, 140, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 38, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 124
-        -50, -50, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -50,
+        0, 0, 0, 0, 0, 0, -84, 0, 0, 0, 0, 0, -84, -84, -84, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 125
-        -47, -47, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -47,
+        0, -83, 0, 0, 0, 0, -83, 0, 0, 0, 0, 0, -83, -83, -83, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 126
-        -46, -46, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -46,
+        0, -80, 0, 0, 0, 0, -80, 0, 0, 0, 0, 0, -80, -80, -80, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 127
-        -57, -57, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -57,
+        0, -81, 0, 0, 0, 0, -81, 0, 0, 0, 0, 0, -81, -81, -81, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 128
-        0, 141, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -82, 0, 0, 0, 0, -82, 0, 0, 0, 0, 0, -82, -82, -82, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 129
-        0, -41, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 148, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 130
-        -42, -42, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -42,
+        -28, -28, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -28,
         // State 131
-        0, 0, -5, -5, -5, -5, -5, -5, -5, -5, -5, -5, -5, -5, -5, 0, -5, -5, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -50, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 132
-        0, -26, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -26, 0, -26, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -51, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 133
-        -44, -44, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -44,
+        0, 0, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, 0, 0, -4, -4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 134
-        0, 142, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, 0, 0, -24, -24, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 135
-        -53, -53, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -53,
+        0, -25, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -25, 0, -25, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 136
-        0, 143, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 151, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 137
-        -55, -55, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -55,
+        0, -54, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 138
-        -58, -58, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -58,
+        0, 152, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 139
-        -59, -59, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -59,
+        0, 159, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 140
-        0, 0, -80, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 160, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 141
-        -52, -52, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -52,
+        -37, -37, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -37,
         // State 142
-        -54, -54, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -54,
+        -34, -34, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -34,
+        // State 143
+        -33, -33, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -33,
+        // State 144
+        -46, -46, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -46,
+        // State 145
+        0, 162, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 146
+        0, -64, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 147
+        -27, -27, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -27,
+        // State 148
+        0, 0, -5, -5, -5, -5, -5, -5, -5, -5, -5, -5, -5, -5, -5, 0, 0, -5, -5, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 149
+        0, -26, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -26, 0, -26, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 150
+        -29, -29, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -29,
+        // State 151
+        -30, -30, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -30,
+        // State 152
+        0, 163, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 153
+        -40, -40, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -40,
+        // State 154
+        0, 164, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 155
+        -44, -44, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -44,
+        // State 156
+        0, 165, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 157
+        -42, -42, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -42,
+        // State 158
+        -47, -47, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -47,
+        // State 159
+        -48, -48, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -48,
+        // State 160
+        0, 166, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 161
+        0, 0, -92, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 162
+        -39, -39, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -39,
+        // State 163
+        -43, -43, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -43,
+        // State 164
+        -41, -41, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -41,
+        // State 165
+        -32, -32, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -32,
     ];
     fn __action(state: i16, integer: usize) -> i16 {
-        __ACTION[(state as usize) * 33 + integer]
+        __ACTION[(state as usize) * 34 + integer]
     }
     const __EOF_ACTION: &[i16] = &[
         // State 0
@@ -425,25 +475,25 @@ mod __parse__AIR {
         // State 32
         0,
         // State 33
-        -94,
+        0,
         // State 34
-        -8,
+        0,
         // State 35
         0,
         // State 36
-        -60,
+        0,
         // State 37
         0,
         // State 38
-        0,
+        -106,
         // State 39
-        0,
+        -65,
         // State 40
-        0,
+        -8,
         // State 41
         0,
         // State 42
-        0,
+        -49,
         // State 43
         0,
         // State 44
@@ -533,7 +583,7 @@ mod __parse__AIR {
         // State 86
         0,
         // State 87
-        -48,
+        0,
         // State 88
         0,
         // State 89
@@ -543,29 +593,29 @@ mod __parse__AIR {
         // State 91
         0,
         // State 92
-        -49,
+        0,
         // State 93
         0,
         // State 94
         0,
         // State 95
-        0,
+        -35,
         // State 96
         0,
         // State 97
         0,
         // State 98
-        -51,
+        0,
         // State 99
         0,
         // State 100
         0,
         // State 101
-        0,
+        -36,
         // State 102
         0,
         // State 103
-        -56,
+        0,
         // State 104
         0,
         // State 105
@@ -573,7 +623,7 @@ mod __parse__AIR {
         // State 106
         0,
         // State 107
-        -45,
+        0,
         // State 108
         0,
         // State 109
@@ -585,9 +635,9 @@ mod __parse__AIR {
         // State 112
         0,
         // State 113
-        0,
+        -38,
         // State 114
-        -43,
+        0,
         // State 115
         0,
         // State 116
@@ -595,7 +645,7 @@ mod __parse__AIR {
         // State 117
         0,
         // State 118
-        0,
+        -45,
         // State 119
         0,
         // State 120
@@ -603,103 +653,161 @@ mod __parse__AIR {
         // State 121
         0,
         // State 122
-        0,
+        -31,
         // State 123
         0,
         // State 124
-        -50,
+        0,
         // State 125
-        -47,
+        0,
         // State 126
-        -46,
+        0,
         // State 127
-        -57,
+        0,
         // State 128
         0,
         // State 129
         0,
         // State 130
-        -42,
+        -28,
         // State 131
         0,
         // State 132
         0,
         // State 133
-        -44,
+        0,
         // State 134
         0,
         // State 135
-        -53,
+        0,
         // State 136
         0,
         // State 137
-        -55,
+        0,
         // State 138
-        -58,
+        0,
         // State 139
-        -59,
+        0,
         // State 140
         0,
         // State 141
-        -52,
+        -37,
         // State 142
-        -54,
+        -34,
+        // State 143
+        -33,
+        // State 144
+        -46,
+        // State 145
+        0,
+        // State 146
+        0,
+        // State 147
+        -27,
+        // State 148
+        0,
+        // State 149
+        0,
+        // State 150
+        -29,
+        // State 151
+        -30,
+        // State 152
+        0,
+        // State 153
+        -40,
+        // State 154
+        0,
+        // State 155
+        -44,
+        // State 156
+        0,
+        // State 157
+        -42,
+        // State 158
+        -47,
+        // State 159
+        -48,
+        // State 160
+        0,
+        // State 161
+        0,
+        // State 162
+        -39,
+        // State 163
+        -43,
+        // State 164
+        -41,
+        // State 165
+        -32,
     ];
     fn __goto(state: i16, nt: usize) -> i16 {
         match nt {
-            2 => 30,
-            5 => 33,
-            6 => 12,
-            7 => 93,
+            2 => 33,
+            5 => 38,
+            6 => match state {
+                22 => 123,
+                _ => 12,
+            },
+            7 => 102,
             8 => match state {
-                30 => 131,
-                _ => 117,
+                33 => 148,
+                _ => 133,
             },
-            9 => 22,
-            10 => 113,
-            12 => 120,
-            13 => 62,
+            9 => 24,
+            10 => 39,
+            11 => 129,
+            13 => 136,
             14 => 68,
-            15 => 128,
-            16 => match state {
-                10 => 19,
-                11 => 20,
-                25 => 31,
-                26 => 32,
-                0 => 34,
-                17 => 102,
-                18 => 104,
-                19 => 105,
-                20 => 106,
-                27 => 122,
-                28 => 123,
-                31 => 134,
-                32 => 136,
-                _ => 18,
-            },
-            18 => 17,
-            19 => match state {
-                1 => 40,
-                _ => 74,
+            15 => 74,
+            16 => 145,
+            17 => match state {
+                10 => 20,
+                11 => 21,
+                27 => 34,
+                28 => 35,
+                29 => 36,
+                0 => 40,
+                18 => 117,
+                19 => 119,
+                20 => 120,
+                21 => 121,
+                30 => 139,
+                31 => 140,
+                34 => 152,
+                35 => 154,
+                36 => 156,
+                _ => 19,
             },
-            20 => 55,
-            21 => match state {
-                2 => 56,
-                _ => 14,
+            19 => 18,
+            20 => match state {
+                1 | 22 => 46,
+                _ => 82,
             },
+            21 => 61,
             22 => match state {
-                29 => 129,
-                _ => 108,
+                2 => 62,
+                _ => 15,
             },
-            23 => 29,
-            24 => 24,
-            25 => 13,
+            23 => match state {
+                32 => 146,
+                _ => 124,
+            },
+            24 => 32,
+            25 => 26,
             26 => match state {
-                6 => 15,
-                7 => 16,
-                15 => 27,
-                16 => 28,
-                _ => 118,
+                15 => 111,
+                37 => 160,
+                _ => 75,
+            },
+            27 => 22,
+            28 => 14,
+            29 => match state {
+                6 => 16,
+                7 => 17,
+                16 => 30,
+                17 => 31,
+                _ => 134,
             },
             _ => 0,
         }
@@ -722,6 +830,7 @@ mod __parse__AIR {
             r###"Scalar"###,
             r###"ScalarWithLambda"###,
             r###"Stream"###,
+            r###"StreamMap"###,
             r###"TTL"###,
             r###"Timestamp"###,
             r###"ap"###,
@@ -748,16 +857,17 @@ mod __parse__AIR {
             }
         }).collect()
     }
-    pub(crate) struct __StateMachine<'err, 'input, 'v>
-    where 'input: 'err, 'input: 'v
+    pub(crate) struct __StateMachine<'err, 'input, 'v, 'c>
+    where 'input: 'err, 'input: 'v, 'input: 'c
     {
         input: &'input str,
         errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
         validator: &'v mut VariableValidator<'input>,
-        __phantom: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
+        comments: &'c mut CommentsCollector<'input>,
+        __phantom: core::marker::PhantomData<(&'err (), &'input (), &'v (), &'c ())>,
     }
-    impl<'err, 'input, 'v> __state_machine::ParserDefinition for __StateMachine<'err, 'input, 'v>
-    where 'input: 'err, 'input: 'v
+    impl<'err, 'input, 'v, 'c> __state_machine::ParserDefinition for __StateMachine<'err, 'input, 'v, 'c>
+    where 'input: 'err, 'input: 'v, 'input: 'c
     {
         type Location = AirPos;
         type Error = ParserError;
@@ -782,7 +892,7 @@ mod __parse__AIR {
 
         #[inline]
         fn token_to_index(&self, token: &Self::Token) -> Option<usize> {
-            __token_to_integer(token, core::marker::PhantomData::<(&(), &(), &())>)
+            __token_to_integer(token, core::marker::PhantomData::<(&(), &(), &(), &())>)
         }
 
         #[inline]
@@ -792,7 +902,7 @@ mod __parse__AIR {
 
         #[inline]
         fn error_action(&self, state: i16) -> i16 {
-            __action(state, 33 - 1)
+            __action(state, 34 - 1)
         }
 
         #[inline]
@@ -806,7 +916,7 @@ mod __parse__AIR {
         }
 
         fn token_to_symbol(&self, token_index: usize, token: Self::Token) -> Self::Symbol {
-            __token_to_symbol(token_index, token, core::marker::PhantomData::<(&(), &(), &())>)
+            __token_to_symbol(token_index, token, core::marker::PhantomData::<(&(), &(), &(), &())>)
         }
 
         fn expected_tokens(&self, state: i16) -> alloc::vec::Vec<alloc::string::String> {
@@ -837,25 +947,27 @@ mod __parse__AIR {
                 self.input,
                 self.errors,
                 self.validator,
+                self.comments,
                 action,
                 start_location,
                 states,
                 symbols,
-                core::marker::PhantomData::<(&(), &(), &())>,
+                core::marker::PhantomData::<(&(), &(), &(), &())>,
             )
         }
 
         fn simulate_reduce(&self, action: i16) -> __state_machine::SimulatedReduce<Self> {
-            __simulate_reduce(action, core::marker::PhantomData::<(&(), &(), &())>)
+            __simulate_reduce(action, core::marker::PhantomData::<(&(), &(), &(), &())>)
         }
     }
     fn __token_to_integer<
         'err,
         'input,
         'v,
+        'c,
     >(
         __token: &Token<'input>,
-        _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
+        _: core::marker::PhantomData<(&'err (), &'input (), &'v (), &'c ())>,
     ) -> Option<usize>
     {
         match *__token {
@@ -875,22 +987,23 @@ mod __parse__AIR {
             Token::Scalar { name: _, position: _ } if true => Some(13),
             Token::ScalarWithLambda { name: _, lambda: _, position: _ } if true => Some(14),
             Token::Stream { name: _, position: _ } if true => Some(15),
-            Token::TTL if true => Some(16),
-            Token::Timestamp if true => Some(17),
-            Token::Ap if true => Some(18),
-            Token::Call if true => Some(19),
-            Token::Canon if true => Some(20),
-            Token::Fail if true => Some(21),
-            Token::Fold if true => Some(22),
-            Token::Match if true => Some(23),
-            Token::MisMatch if true => Some(24),
-            Token::Never if true => Some(25),
-            Token::New if true => Some(26),
-            Token::Next if true => Some(27),
-            Token::Null if true => Some(28),
-            Token::Par if true => Some(29),
-            Token::Seq if true => Some(30),
-            Token::Xor if true => Some(31),
+            Token::StreamMap { name: _, position: _ } if true => Some(16),
+            Token::TTL if true => Some(17),
+            Token::Timestamp if true => Some(18),
+            Token::Ap if true => Some(19),
+            Token::Call if true => Some(20),
+            Token::Canon if true => Some(21),
+            Token::Fail if true => Some(22),
+            Token::Fold if true => Some(23),
+            Token::Match if true => Some(24),
+            Token::MisMatch if true => Some(25),
+            Token::Never if true => Some(26),
+            Token::New if true => Some(27),
+            Token::Next if true => Some(28),
+            Token::Null if true => Some(29),
+            Token::Par if true => Some(30),
+            Token::Seq if true => Some(31),
+            Token::Xor if true => Some(32),
             _ => None,
         }
     }
@@ -898,20 +1011,21 @@ mod __parse__AIR {
         'err,
         'input,
         'v,
+        'c,
     >(
         __token_index: usize,
         __token: Token<'input>,
-        _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
+        _: core::marker::PhantomData<(&'err (), &'input (), &'v (), &'c ())>,
     ) -> __Symbol<'input>
     {
         match __token_index {
-            0 | 1 | 2 | 3 | 9 | 10 | 16 | 17 | 18 | 19 | 20 | 21 | 22 | 23 | 24 | 25 | 26 | 27 | 28 | 29 | 30 | 31 => __Symbol::Variant0(__token),
+            0 | 1 | 2 | 3 | 9 | 10 | 17 | 18 | 19 | 20 | 21 | 22 | 23 | 24 | 25 | 26 | 27 | 28 | 29 | 30 | 31 | 32 => __Symbol::Variant0(__token),
             4 => match __token {
                 Token::Boolean(__tok0) if true => __Symbol::Variant1(__tok0),
                 _ => unreachable!(),
             },
-            5 | 13 | 15 => match __token {
-                Token::CanonStream { name: __tok0, position: __tok1 } | Token::Scalar { name: __tok0, position: __tok1 } | Token::Stream { name: __tok0, position: __tok1 } if true => __Symbol::Variant2((__tok0, __tok1)),
+            5 | 13 | 15 | 16 => match __token {
+                Token::CanonStream { name: __tok0, position: __tok1 } | Token::Scalar { name: __tok0, position: __tok1 } | Token::Stream { name: __tok0, position: __tok1 } | Token::StreamMap { name: __tok0, position: __tok1 } if true => __Symbol::Variant2((__tok0, __tok1)),
                 _ => unreachable!(),
             },
             6 | 14 => match __token {
@@ -941,13 +1055,15 @@ mod __parse__AIR {
         'err,
         'input,
         'v,
+        'c,
     >(
         __reduce_index: i16,
-        _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
-    ) -> __state_machine::SimulatedReduce<__StateMachine<'err, 'input, 'v>>
+        _: core::marker::PhantomData<(&'err (), &'input (), &'v (), &'c ())>,
+    ) -> __state_machine::SimulatedReduce<__StateMachine<'err, 'input, 'v, 'c>>
     where
         'input: 'err,
         'input: 'v,
+        'input: 'c,
     {
         match __reduce_index {
             0 => {
@@ -1108,290 +1224,290 @@ mod __parse__AIR {
             }
             26 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
+                    states_to_pop: 6,
                     nonterminal_produced: 10,
                 }
             }
             27 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
+                    states_to_pop: 5,
                     nonterminal_produced: 10,
                 }
             }
             28 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 11,
+                    states_to_pop: 6,
+                    nonterminal_produced: 10,
                 }
             }
             29 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 0,
-                    nonterminal_produced: 11,
+                    states_to_pop: 6,
+                    nonterminal_produced: 10,
                 }
             }
             30 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 12,
+                    states_to_pop: 5,
+                    nonterminal_produced: 10,
                 }
             }
             31 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 13,
+                    states_to_pop: 8,
+                    nonterminal_produced: 10,
                 }
             }
             32 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 13,
+                    states_to_pop: 5,
+                    nonterminal_produced: 10,
                 }
             }
             33 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 2,
-                    nonterminal_produced: 13,
+                    states_to_pop: 5,
+                    nonterminal_produced: 10,
                 }
             }
             34 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 13,
+                    states_to_pop: 3,
+                    nonterminal_produced: 10,
                 }
             }
             35 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 13,
+                    states_to_pop: 3,
+                    nonterminal_produced: 10,
                 }
             }
             36 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 14,
+                    states_to_pop: 5,
+                    nonterminal_produced: 10,
                 }
             }
             37 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 14,
+                    states_to_pop: 4,
+                    nonterminal_produced: 10,
                 }
             }
             38 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 14,
+                    states_to_pop: 7,
+                    nonterminal_produced: 10,
                 }
             }
             39 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 2,
-                    nonterminal_produced: 14,
+                    states_to_pop: 6,
+                    nonterminal_produced: 10,
                 }
             }
             40 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 15,
+                    states_to_pop: 7,
+                    nonterminal_produced: 10,
                 }
             }
             41 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 6,
-                    nonterminal_produced: 16,
+                    nonterminal_produced: 10,
                 }
             }
             42 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 5,
-                    nonterminal_produced: 16,
+                    states_to_pop: 7,
+                    nonterminal_produced: 10,
                 }
             }
             43 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 6,
-                    nonterminal_produced: 16,
+                    nonterminal_produced: 10,
                 }
             }
             44 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 5,
-                    nonterminal_produced: 16,
+                    states_to_pop: 4,
+                    nonterminal_produced: 10,
                 }
             }
             45 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 5,
-                    nonterminal_produced: 16,
+                    nonterminal_produced: 10,
                 }
             }
             46 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 5,
-                    nonterminal_produced: 16,
+                    states_to_pop: 6,
+                    nonterminal_produced: 10,
                 }
             }
             47 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 3,
-                    nonterminal_produced: 16,
+                    states_to_pop: 6,
+                    nonterminal_produced: 10,
                 }
             }
             48 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 3,
-                    nonterminal_produced: 16,
+                    states_to_pop: 1,
+                    nonterminal_produced: 10,
                 }
             }
             49 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 5,
-                    nonterminal_produced: 16,
+                    states_to_pop: 1,
+                    nonterminal_produced: 11,
                 }
             }
             50 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 4,
-                    nonterminal_produced: 16,
+                    states_to_pop: 1,
+                    nonterminal_produced: 11,
                 }
             }
             51 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 7,
-                    nonterminal_produced: 16,
+                    states_to_pop: 1,
+                    nonterminal_produced: 12,
                 }
             }
             52 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 6,
-                    nonterminal_produced: 16,
+                    states_to_pop: 0,
+                    nonterminal_produced: 12,
                 }
             }
             53 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 7,
-                    nonterminal_produced: 16,
+                    states_to_pop: 1,
+                    nonterminal_produced: 13,
                 }
             }
             54 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 6,
-                    nonterminal_produced: 16,
+                    states_to_pop: 1,
+                    nonterminal_produced: 14,
                 }
             }
             55 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 4,
-                    nonterminal_produced: 16,
+                    states_to_pop: 1,
+                    nonterminal_produced: 14,
                 }
             }
             56 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 5,
-                    nonterminal_produced: 16,
+                    states_to_pop: 2,
+                    nonterminal_produced: 14,
                 }
             }
             57 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 6,
-                    nonterminal_produced: 16,
+                    states_to_pop: 1,
+                    nonterminal_produced: 14,
                 }
             }
             58 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 6,
-                    nonterminal_produced: 16,
+                    states_to_pop: 1,
+                    nonterminal_produced: 14,
                 }
             }
             59 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 16,
+                    nonterminal_produced: 15,
                 }
             }
             60 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 17,
+                    nonterminal_produced: 15,
                 }
             }
             61 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 0,
-                    nonterminal_produced: 17,
+                    states_to_pop: 1,
+                    nonterminal_produced: 15,
                 }
             }
             62 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 18,
+                    states_to_pop: 2,
+                    nonterminal_produced: 15,
                 }
             }
             63 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 18,
+                    nonterminal_produced: 16,
                 }
             }
             64 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 18,
+                    nonterminal_produced: 17,
                 }
             }
             65 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 19,
+                    nonterminal_produced: 18,
                 }
             }
             66 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 19,
+                    states_to_pop: 0,
+                    nonterminal_produced: 18,
                 }
             }
             67 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 20,
+                    nonterminal_produced: 19,
                 }
             }
             68 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 21,
+                    nonterminal_produced: 19,
                 }
             }
             69 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 21,
+                    nonterminal_produced: 19,
                 }
             }
             70 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 21,
+                    nonterminal_produced: 19,
                 }
             }
             71 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 21,
+                    nonterminal_produced: 20,
                 }
             }
             72 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 21,
+                    nonterminal_produced: 20,
                 }
             }
             73 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 22,
+                    nonterminal_produced: 21,
                 }
             }
             74 => {
@@ -1415,49 +1531,49 @@ mod __parse__AIR {
             77 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 23,
+                    nonterminal_produced: 22,
                 }
             }
             78 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 24,
+                    nonterminal_produced: 22,
                 }
             }
             79 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 5,
-                    nonterminal_produced: 25,
+                    states_to_pop: 1,
+                    nonterminal_produced: 23,
                 }
             }
             80 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 26,
+                    nonterminal_produced: 23,
                 }
             }
             81 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 26,
+                    nonterminal_produced: 23,
                 }
             }
             82 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 26,
+                    nonterminal_produced: 23,
                 }
             }
             83 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 26,
+                    nonterminal_produced: 24,
                 }
             }
             84 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 26,
+                    nonterminal_produced: 25,
                 }
             }
             85 => {
@@ -1469,58 +1585,130 @@ mod __parse__AIR {
             86 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 26,
+                    nonterminal_produced: 27,
                 }
             }
             87 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 26,
+                    nonterminal_produced: 27,
                 }
             }
             88 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 2,
-                    nonterminal_produced: 26,
+                    states_to_pop: 1,
+                    nonterminal_produced: 27,
                 }
             }
             89 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 26,
+                    nonterminal_produced: 27,
                 }
             }
             90 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 26,
+                    nonterminal_produced: 27,
                 }
             }
             91 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 26,
+                    states_to_pop: 5,
+                    nonterminal_produced: 28,
                 }
             }
             92 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 26,
+                    nonterminal_produced: 29,
                 }
             }
-            93 => __state_machine::SimulatedReduce::Accept,
-            _ => panic!("invalid reduction index {}", __reduce_index)
-        }
-    }
-    pub struct AIRParser {
-        _priv: (),
-    }
-
-    impl AIRParser {
-        pub fn new() -> AIRParser {
-            AIRParser {
-                _priv: (),
-            }
+            93 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 29,
+                }
+            }
+            94 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 29,
+                }
+            }
+            95 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 29,
+                }
+            }
+            96 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 29,
+                }
+            }
+            97 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 29,
+                }
+            }
+            98 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 29,
+                }
+            }
+            99 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 29,
+                }
+            }
+            100 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 29,
+                }
+            }
+            101 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 29,
+                }
+            }
+            102 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 29,
+                }
+            }
+            103 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 29,
+                }
+            }
+            104 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 29,
+                }
+            }
+            105 => __state_machine::SimulatedReduce::Accept,
+            _ => panic!("invalid reduction index {}", __reduce_index)
+        }
+    }
+    pub struct AIRParser {
+        _priv: (),
+    }
+
+    impl AIRParser {
+        pub fn new() -> AIRParser {
+            AIRParser {
+                _priv: (),
+            }
         }
 
         #[allow(dead_code)]
@@ -1528,13 +1716,15 @@ mod __parse__AIR {
             'err,
             'input,
             'v,
-            __TOKEN: __ToTriple<'err, 'input, 'v, >,
+            'c,
+            __TOKEN: __ToTriple<'err, 'input, 'v, 'c, >,
             __TOKENS: IntoIterator<Item=__TOKEN>,
         >(
             &self,
             input: &'input str,
             errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
             validator: &'v mut VariableValidator<'input>,
+            comments: &'c mut CommentsCollector<'input>,
             __tokens0: __TOKENS,
         ) -> Result<Box<Instruction<'input>>, __lalrpop_util::ParseError<AirPos, Token<'input>, ParserError>>
         {
@@ -1545,7 +1735,8 @@ mod __parse__AIR {
                     input,
                     errors,
                     validator,
-                    __phantom: core::marker::PhantomData::<(&(), &(), &())>,
+                    comments,
+                    __phantom: core::marker::PhantomData::<(&(), &(), &(), &())>,
                 },
                 __tokens,
             )
@@ -1555,14 +1746,16 @@ mod __parse__AIR {
         'err,
         'input,
         'v,
+        'c,
     >(
         input: &'input str,
         errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
         validator: &'v mut VariableValidator<'input>,
+        comments: &'c mut CommentsCollector<'input>,
         __error_state: i16,
         __states: & [i16],
         __opt_integer: Option<usize>,
-        _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
+        _: core::marker::PhantomData<(&'err (), &'input (), &'v (), &'c ())>,
     ) -> bool
     {
         let mut __states = __states.to_vec();
@@ -1576,7 +1769,7 @@ mod __parse__AIR {
             };
             if __action == 0 { return false; }
             if __action > 0 { return true; }
-            let (__to_pop, __nt) = match __simulate_reduce(-(__action + 1), core::marker::PhantomData::<(&(), &(), &())>) {
+            let (__to_pop, __nt) = match __simulate_reduce(-(__action + 1), core::marker::PhantomData::<(&(), &(), &(), &())>) {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop, nonterminal_produced
                 } => (states_to_pop, nonterminal_produced),
@@ -1593,303 +1786,341 @@ mod __parse__AIR {
         'err,
         'input,
         'v,
+        'c,
     >(
         input: &'input str,
         errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
         validator: &'v mut VariableValidator<'input>,
+        comments: &'c mut CommentsCollector<'input>,
         __action: i16,
         __lookahead_start: Option<&AirPos>,
         __states: &mut alloc::vec::Vec<i16>,
         __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>,
-        _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
+        _: core::marker::PhantomData<(&'err (), &'input (), &'v (), &'c ())>,
     ) -> Option<Result<Box<Instruction<'input>>,__lalrpop_util::ParseError<AirPos, Token<'input>, ParserError>>>
     {
         let (__pop_states, __nonterminal) = match __action {
             0 => {
-                __reduce0(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+                __reduce0(input, errors, validator, comments, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &(), &())>)
             }
             1 => {
-                __reduce1(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+                __reduce1(input, errors, validator, comments, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &(), &())>)
             }
             2 => {
-                __reduce2(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+                __reduce2(input, errors, validator, comments, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &(), &())>)
             }
             3 => {
-                __reduce3(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+                __reduce3(input, errors, validator, comments, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &(), &())>)
             }
             4 => {
-                __reduce4(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+                __reduce4(input, errors, validator, comments, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &(), &())>)
             }
             5 => {
-                __reduce5(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+                __reduce5(input, errors, validator, comments, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &(), &())>)
             }
             6 => {
-                __reduce6(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+                __reduce6(input, errors, validator, comments, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &(), &())>)
             }
             7 => {
-                __reduce7(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+                __reduce7(input, errors, validator, comments, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &(), &())>)
             }
             8 => {
-                __reduce8(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+                __reduce8(input, errors, validator, comments, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &(), &())>)
             }
             9 => {
-                __reduce9(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+                __reduce9(input, errors, validator, comments, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &(), &())>)
             }
             10 => {
-                __reduce10(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+                __reduce10(input, errors, validator, comments, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &(), &())>)
             }
             11 => {
-                __reduce11(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+                __reduce11(input, errors, validator, comments, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &(), &())>)
             }
             12 => {
-                __reduce12(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+                __reduce12(input, errors, validator, comments, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &(), &())>)
             }
             13 => {
-                __reduce13(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+                __reduce13(input, errors, validator, comments, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &(), &())>)
             }
             14 => {
-                __reduce14(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+                __reduce14(input, errors, validator, comments, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &(), &())>)
             }
             15 => {
-                __reduce15(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+                __reduce15(input, errors, validator, comments, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &(), &())>)
             }
             16 => {
-                __reduce16(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+                __reduce16(input, errors, validator, comments, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &(), &())>)
             }
             17 => {
-                __reduce17(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+                __reduce17(input, errors, validator, comments, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &(), &())>)
             }
             18 => {
-                __reduce18(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+                __reduce18(input, errors, validator, comments, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &(), &())>)
             }
             19 => {
-                __reduce19(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+                __reduce19(input, errors, validator, comments, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &(), &())>)
             }
             20 => {
-                __reduce20(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+                __reduce20(input, errors, validator, comments, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &(), &())>)
             }
             21 => {
-                __reduce21(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+                __reduce21(input, errors, validator, comments, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &(), &())>)
             }
             22 => {
-                __reduce22(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+                __reduce22(input, errors, validator, comments, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &(), &())>)
             }
             23 => {
-                __reduce23(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+                __reduce23(input, errors, validator, comments, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &(), &())>)
             }
             24 => {
-                __reduce24(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+                __reduce24(input, errors, validator, comments, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &(), &())>)
             }
             25 => {
-                __reduce25(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+                __reduce25(input, errors, validator, comments, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &(), &())>)
             }
             26 => {
-                __reduce26(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+                __reduce26(input, errors, validator, comments, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &(), &())>)
             }
             27 => {
-                __reduce27(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+                __reduce27(input, errors, validator, comments, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &(), &())>)
             }
             28 => {
-                __reduce28(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+                __reduce28(input, errors, validator, comments, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &(), &())>)
             }
             29 => {
-                __reduce29(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+                __reduce29(input, errors, validator, comments, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &(), &())>)
             }
             30 => {
-                __reduce30(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+                __reduce30(input, errors, validator, comments, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &(), &())>)
             }
             31 => {
-                __reduce31(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+                __reduce31(input, errors, validator, comments, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &(), &())>)
             }
             32 => {
-                __reduce32(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+                __reduce32(input, errors, validator, comments, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &(), &())>)
             }
             33 => {
-                __reduce33(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+                __reduce33(input, errors, validator, comments, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &(), &())>)
             }
             34 => {
-                __reduce34(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+                __reduce34(input, errors, validator, comments, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &(), &())>)
             }
             35 => {
-                __reduce35(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+                __reduce35(input, errors, validator, comments, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &(), &())>)
             }
             36 => {
-                __reduce36(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+                __reduce36(input, errors, validator, comments, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &(), &())>)
             }
             37 => {
-                __reduce37(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+                __reduce37(input, errors, validator, comments, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &(), &())>)
             }
             38 => {
-                __reduce38(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+                __reduce38(input, errors, validator, comments, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &(), &())>)
             }
             39 => {
-                __reduce39(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+                __reduce39(input, errors, validator, comments, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &(), &())>)
             }
             40 => {
-                __reduce40(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+                __reduce40(input, errors, validator, comments, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &(), &())>)
             }
             41 => {
-                __reduce41(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+                __reduce41(input, errors, validator, comments, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &(), &())>)
             }
             42 => {
-                __reduce42(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+                __reduce42(input, errors, validator, comments, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &(), &())>)
             }
             43 => {
-                __reduce43(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+                __reduce43(input, errors, validator, comments, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &(), &())>)
             }
             44 => {
-                __reduce44(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+                __reduce44(input, errors, validator, comments, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &(), &())>)
             }
             45 => {
-                __reduce45(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+                __reduce45(input, errors, validator, comments, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &(), &())>)
             }
             46 => {
-                __reduce46(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+                __reduce46(input, errors, validator, comments, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &(), &())>)
             }
             47 => {
-                __reduce47(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+                __reduce47(input, errors, validator, comments, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &(), &())>)
             }
             48 => {
-                __reduce48(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+                __reduce48(input, errors, validator, comments, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &(), &())>)
             }
             49 => {
-                __reduce49(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+                __reduce49(input, errors, validator, comments, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &(), &())>)
             }
             50 => {
-                __reduce50(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+                __reduce50(input, errors, validator, comments, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &(), &())>)
             }
             51 => {
-                __reduce51(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+                __reduce51(input, errors, validator, comments, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &(), &())>)
             }
             52 => {
-                __reduce52(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+                __reduce52(input, errors, validator, comments, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &(), &())>)
             }
             53 => {
-                __reduce53(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+                __reduce53(input, errors, validator, comments, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &(), &())>)
             }
             54 => {
-                __reduce54(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+                __reduce54(input, errors, validator, comments, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &(), &())>)
             }
             55 => {
-                __reduce55(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+                __reduce55(input, errors, validator, comments, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &(), &())>)
             }
             56 => {
-                __reduce56(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+                __reduce56(input, errors, validator, comments, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &(), &())>)
             }
             57 => {
-                __reduce57(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+                __reduce57(input, errors, validator, comments, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &(), &())>)
             }
             58 => {
-                __reduce58(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+                __reduce58(input, errors, validator, comments, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &(), &())>)
             }
             59 => {
-                __reduce59(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+                __reduce59(input, errors, validator, comments, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &(), &())>)
             }
             60 => {
-                __reduce60(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+                __reduce60(input, errors, validator, comments, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &(), &())>)
             }
             61 => {
-                __reduce61(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+                __reduce61(input, errors, validator, comments, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &(), &())>)
             }
             62 => {
-                __reduce62(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+                __reduce62(input, errors, validator, comments, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &(), &())>)
             }
             63 => {
-                __reduce63(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+                __reduce63(input, errors, validator, comments, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &(), &())>)
             }
             64 => {
-                __reduce64(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+                __reduce64(input, errors, validator, comments, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &(), &())>)
             }
             65 => {
-                __reduce65(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+                __reduce65(input, errors, validator, comments, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &(), &())>)
             }
             66 => {
-                __reduce66(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+                __reduce66(input, errors, validator, comments, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &(), &())>)
             }
             67 => {
-                __reduce67(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+                __reduce67(input, errors, validator, comments, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &(), &())>)
             }
             68 => {
-                __reduce68(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+                __reduce68(input, errors, validator, comments, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &(), &())>)
             }
             69 => {
-                __reduce69(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+                __reduce69(input, errors, validator, comments, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &(), &())>)
             }
             70 => {
-                __reduce70(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+                __reduce70(input, errors, validator, comments, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &(), &())>)
             }
             71 => {
-                __reduce71(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+                __reduce71(input, errors, validator, comments, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &(), &())>)
             }
             72 => {
-                __reduce72(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+                __reduce72(input, errors, validator, comments, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &(), &())>)
             }
             73 => {
-                __reduce73(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+                __reduce73(input, errors, validator, comments, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &(), &())>)
             }
             74 => {
-                __reduce74(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+                __reduce74(input, errors, validator, comments, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &(), &())>)
             }
             75 => {
-                __reduce75(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+                __reduce75(input, errors, validator, comments, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &(), &())>)
             }
             76 => {
-                __reduce76(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+                __reduce76(input, errors, validator, comments, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &(), &())>)
             }
             77 => {
-                __reduce77(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+                __reduce77(input, errors, validator, comments, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &(), &())>)
             }
             78 => {
-                __reduce78(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+                __reduce78(input, errors, validator, comments, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &(), &())>)
             }
             79 => {
-                __reduce79(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+                __reduce79(input, errors, validator, comments, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &(), &())>)
             }
             80 => {
-                __reduce80(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+                __reduce80(input, errors, validator, comments, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &(), &())>)
             }
             81 => {
-                __reduce81(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+                __reduce81(input, errors, validator, comments, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &(), &())>)
             }
             82 => {
-                __reduce82(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+                __reduce82(input, errors, validator, comments, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &(), &())>)
             }
             83 => {
-                __reduce83(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+                __reduce83(input, errors, validator, comments, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &(), &())>)
             }
             84 => {
-                __reduce84(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+                __reduce84(input, errors, validator, comments, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &(), &())>)
             }
             85 => {
-                __reduce85(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+                __reduce85(input, errors, validator, comments, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &(), &())>)
             }
             86 => {
-                __reduce86(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+                __reduce86(input, errors, validator, comments, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &(), &())>)
             }
             87 => {
-                __reduce87(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+                __reduce87(input, errors, validator, comments, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &(), &())>)
             }
             88 => {
-                __reduce88(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+                __reduce88(input, errors, validator, comments, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &(), &())>)
             }
             89 => {
-                __reduce89(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+                __reduce89(input, errors, validator, comments, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &(), &())>)
             }
             90 => {
-                __reduce90(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+                __reduce90(input, errors, validator, comments, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &(), &())>)
             }
             91 => {
-                __reduce91(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+                __reduce91(input, errors, validator, comments, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &(), &())>)
             }
             92 => {
-                __reduce92(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+                __reduce92(input, errors, validator, comments, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &(), &())>)
             }
             93 => {
+                __reduce93(input, errors, validator, comments, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &(), &())>)
+            }
+            94 => {
+                __reduce94(input, errors, validator, comments, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &(), &())>)
+            }
+            95 => {
+                __reduce95(input, errors, validator, comments, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &(), &())>)
+            }
+            96 => {
+                __reduce96(input, errors, validator, comments, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &(), &())>)
+            }
+            97 => {
+                __reduce97(input, errors, validator, comments, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &(), &())>)
+            }
+            98 => {
+                __reduce98(input, errors, validator, comments, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &(), &())>)
+            }
+            99 => {
+                __reduce99(input, errors, validator, comments, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &(), &())>)
+            }
+            100 => {
+                __reduce100(input, errors, validator, comments, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &(), &())>)
+            }
+            101 => {
+                __reduce101(input, errors, validator, comments, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &(), &())>)
+            }
+            102 => {
+                __reduce102(input, errors, validator, comments, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &(), &())>)
+            }
+            103 => {
+                __reduce103(input, errors, validator, comments, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &(), &())>)
+            }
+            104 => {
+                __reduce104(input, errors, validator, comments, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &(), &())>)
+            }
+            105 => {
                 // __AIR = AIR => ActionFn(0);
                 let __sym0 = __pop_Variant12(__symbols);
                 let __start = __sym0.0.clone();
                 let __end = __sym0.2.clone();
-                let __nt = super::__action0::<>(input, errors, validator, __sym0);
+                let __nt = super::__action0::<>(input, errors, validator, comments, __sym0);
                 return Some(Ok(__nt));
             }
             _ => panic!("invalid action code {}", __action)
@@ -1997,7 +2228,7 @@ mod __parse__AIR {
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>
-    ) -> (AirPos, Fail<'input>, AirPos)
+    ) -> (AirPos, FailArgument<'input>, AirPos)
      {
         match __symbols.pop() {
             Some((__l, __Symbol::Variant19(__v), __r)) => (__l, __v, __r),
@@ -2092,6 +2323,28 @@ mod __parse__AIR {
             _ => __symbol_type_mismatch()
         }
     }
+    fn __pop_Variant27<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>
+    ) -> (AirPos, StreamMap<'input>, AirPos)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant27(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant28<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>
+    ) -> (AirPos, StreamMapKeyClause<'input>, AirPos)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant28(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
     fn __pop_Variant0<
       'input,
     >(
@@ -2103,14 +2356,14 @@ mod __parse__AIR {
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant27<
+    fn __pop_Variant29<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>
     ) -> (AirPos, Triplet<'input>, AirPos)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant27(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant29(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
@@ -2217,20 +2470,22 @@ mod __parse__AIR {
         'err,
         'input,
         'v,
+        'c,
     >(
         input: &'input str,
         errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
         validator: &'v mut VariableValidator<'input>,
+        comments: &'c mut CommentsCollector<'input>,
         __lookahead_start: Option<&AirPos>,
         __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>,
-        _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
+        _: core::marker::PhantomData<(&'err (), &'input (), &'v (), &'c ())>,
     ) -> (usize, usize)
     {
-        // (<Arg>) = Arg => ActionFn(81);
+        // (<Arg>) = Arg => ActionFn(92);
         let __sym0 = __pop_Variant9(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action81::<>(input, errors, validator, __sym0);
+        let __nt = super::__action92::<>(input, errors, validator, comments, __sym0);
         __symbols.push((__start, __Symbol::Variant9(__nt), __end));
         (1, 0)
     }
@@ -2238,19 +2493,21 @@ mod __parse__AIR {
         'err,
         'input,
         'v,
+        'c,
     >(
         input: &'input str,
         errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
         validator: &'v mut VariableValidator<'input>,
+        comments: &'c mut CommentsCollector<'input>,
         __lookahead_start: Option<&AirPos>,
         __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>,
-        _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
+        _: core::marker::PhantomData<(&'err (), &'input (), &'v (), &'c ())>,
     ) -> (usize, usize)
     {
-        // (<Arg>)* =  => ActionFn(79);
+        // (<Arg>)* =  => ActionFn(90);
         let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
         let __end = __start.clone();
-        let __nt = super::__action79::<>(input, errors, validator, &__start, &__end);
+        let __nt = super::__action90::<>(input, errors, validator, comments, &__start, &__end);
         __symbols.push((__start, __Symbol::Variant10(__nt), __end));
         (0, 1)
     }
@@ -2258,20 +2515,22 @@ mod __parse__AIR {
         'err,
         'input,
         'v,
+        'c,
     >(
         input: &'input str,
         errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
         validator: &'v mut VariableValidator<'input>,
+        comments: &'c mut CommentsCollector<'input>,
         __lookahead_start: Option<&AirPos>,
         __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>,
-        _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
+        _: core::marker::PhantomData<(&'err (), &'input (), &'v (), &'c ())>,
     ) -> (usize, usize)
     {
-        // (<Arg>)* = (<Arg>)+ => ActionFn(80);
+        // (<Arg>)* = (<Arg>)+ => ActionFn(91);
         let __sym0 = __pop_Variant10(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action80::<>(input, errors, validator, __sym0);
+        let __nt = super::__action91::<>(input, errors, validator, comments, __sym0);
         __symbols.push((__start, __Symbol::Variant10(__nt), __end));
         (1, 1)
     }
@@ -2279,20 +2538,22 @@ mod __parse__AIR {
         'err,
         'input,
         'v,
+        'c,
     >(
         input: &'input str,
         errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
         validator: &'v mut VariableValidator<'input>,
+        comments: &'c mut CommentsCollector<'input>,
         __lookahead_start: Option<&AirPos>,
         __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>,
-        _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
+        _: core::marker::PhantomData<(&'err (), &'input (), &'v (), &'c ())>,
     ) -> (usize, usize)
     {
-        // (<Arg>)+ = Arg => ActionFn(90);
+        // (<Arg>)+ = Arg => ActionFn(101);
         let __sym0 = __pop_Variant9(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action90::<>(input, errors, validator, __sym0);
+        let __nt = super::__action101::<>(input, errors, validator, comments, __sym0);
         __symbols.push((__start, __Symbol::Variant10(__nt), __end));
         (1, 2)
     }
@@ -2300,22 +2561,24 @@ mod __parse__AIR {
         'err,
         'input,
         'v,
+        'c,
     >(
         input: &'input str,
         errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
         validator: &'v mut VariableValidator<'input>,
+        comments: &'c mut CommentsCollector<'input>,
         __lookahead_start: Option<&AirPos>,
         __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>,
-        _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
+        _: core::marker::PhantomData<(&'err (), &'input (), &'v (), &'c ())>,
     ) -> (usize, usize)
     {
-        // (<Arg>)+ = (<Arg>)+, Arg => ActionFn(91);
+        // (<Arg>)+ = (<Arg>)+, Arg => ActionFn(102);
         assert!(__symbols.len() >= 2);
         let __sym1 = __pop_Variant9(__symbols);
         let __sym0 = __pop_Variant10(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym1.2.clone();
-        let __nt = super::__action91::<>(input, errors, validator, __sym0, __sym1);
+        let __nt = super::__action102::<>(input, errors, validator, comments, __sym0, __sym1);
         __symbols.push((__start, __Symbol::Variant10(__nt), __end));
         (2, 2)
     }
@@ -2323,19 +2586,21 @@ mod __parse__AIR {
         'err,
         'input,
         'v,
+        'c,
     >(
         input: &'input str,
         errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
         validator: &'v mut VariableValidator<'input>,
+        comments: &'c mut CommentsCollector<'input>,
         __lookahead_start: Option<&AirPos>,
         __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>,
-        _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
+        _: core::marker::PhantomData<(&'err (), &'input (), &'v (), &'c ())>,
     ) -> (usize, usize)
     {
-        // @L =  => ActionFn(87);
+        // @L =  => ActionFn(98);
         let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
         let __end = __start.clone();
-        let __nt = super::__action87::<>(input, errors, validator, &__start, &__end);
+        let __nt = super::__action98::<>(input, errors, validator, comments, &__start, &__end);
         __symbols.push((__start, __Symbol::Variant11(__nt), __end));
         (0, 3)
     }
@@ -2343,19 +2608,21 @@ mod __parse__AIR {
         'err,
         'input,
         'v,
+        'c,
     >(
         input: &'input str,
         errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
         validator: &'v mut VariableValidator<'input>,
+        comments: &'c mut CommentsCollector<'input>,
         __lookahead_start: Option<&AirPos>,
         __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>,
-        _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
+        _: core::marker::PhantomData<(&'err (), &'input (), &'v (), &'c ())>,
     ) -> (usize, usize)
     {
-        // @R =  => ActionFn(84);
+        // @R =  => ActionFn(97);
         let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
         let __end = __start.clone();
-        let __nt = super::__action84::<>(input, errors, validator, &__start, &__end);
+        let __nt = super::__action97::<>(input, errors, validator, comments, &__start, &__end);
         __symbols.push((__start, __Symbol::Variant11(__nt), __end));
         (0, 4)
     }
@@ -2363,20 +2630,22 @@ mod __parse__AIR {
         'err,
         'input,
         'v,
+        'c,
     >(
         input: &'input str,
         errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
         validator: &'v mut VariableValidator<'input>,
+        comments: &'c mut CommentsCollector<'input>,
         __lookahead_start: Option<&AirPos>,
         __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>,
-        _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
+        _: core::marker::PhantomData<(&'err (), &'input (), &'v (), &'c ())>,
     ) -> (usize, usize)
     {
         // AIR = Instr => ActionFn(1);
         let __sym0 = __pop_Variant12(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action1::<>(input, errors, validator, __sym0);
+        let __nt = super::__action1::<>(input, errors, validator, comments, __sym0);
         __symbols.push((__start, __Symbol::Variant12(__nt), __end));
         (1, 5)
     }
@@ -2384,20 +2653,22 @@ mod __parse__AIR {
         'err,
         'input,
         'v,
+        'c,
     >(
         input: &'input str,
         errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
         validator: &'v mut VariableValidator<'input>,
+        comments: &'c mut CommentsCollector<'input>,
         __lookahead_start: Option<&AirPos>,
         __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>,
-        _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
+        _: core::marker::PhantomData<(&'err (), &'input (), &'v (), &'c ())>,
     ) -> (usize, usize)
     {
-        // ApArgument = InitPeerId => ActionFn(64);
+        // ApArgument = InitPeerId => ActionFn(74);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action64::<>(input, errors, validator, __sym0);
+        let __nt = super::__action74::<>(input, errors, validator, comments, __sym0);
         __symbols.push((__start, __Symbol::Variant13(__nt), __end));
         (1, 6)
     }
@@ -2405,20 +2676,22 @@ mod __parse__AIR {
         'err,
         'input,
         'v,
+        'c,
     >(
         input: &'input str,
         errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
         validator: &'v mut VariableValidator<'input>,
+        comments: &'c mut CommentsCollector<'input>,
         __lookahead_start: Option<&AirPos>,
         __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>,
-        _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
+        _: core::marker::PhantomData<(&'err (), &'input (), &'v (), &'c ())>,
     ) -> (usize, usize)
     {
-        // ApArgument = LastError => ActionFn(65);
+        // ApArgument = LastError => ActionFn(75);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action65::<>(input, errors, validator, __sym0);
+        let __nt = super::__action75::<>(input, errors, validator, comments, __sym0);
         __symbols.push((__start, __Symbol::Variant13(__nt), __end));
         (1, 6)
     }
@@ -2426,20 +2699,22 @@ mod __parse__AIR {
         'err,
         'input,
         'v,
+        'c,
     >(
         input: &'input str,
         errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
         validator: &'v mut VariableValidator<'input>,
+        comments: &'c mut CommentsCollector<'input>,
         __lookahead_start: Option<&AirPos>,
         __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>,
-        _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
+        _: core::marker::PhantomData<(&'err (), &'input (), &'v (), &'c ())>,
     ) -> (usize, usize)
     {
-        // ApArgument = LastErrorWithLambda => ActionFn(66);
+        // ApArgument = LastErrorWithLambda => ActionFn(76);
         let __sym0 = __pop_Variant6(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action66::<>(input, errors, validator, __sym0);
+        let __nt = super::__action76::<>(input, errors, validator, comments, __sym0);
         __symbols.push((__start, __Symbol::Variant13(__nt), __end));
         (1, 6)
     }
@@ -2447,20 +2722,22 @@ mod __parse__AIR {
         'err,
         'input,
         'v,
+        'c,
     >(
         input: &'input str,
         errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
         validator: &'v mut VariableValidator<'input>,
+        comments: &'c mut CommentsCollector<'input>,
         __lookahead_start: Option<&AirPos>,
         __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>,
-        _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
+        _: core::marker::PhantomData<(&'err (), &'input (), &'v (), &'c ())>,
     ) -> (usize, usize)
     {
-        // ApArgument = Timestamp => ActionFn(67);
+        // ApArgument = Timestamp => ActionFn(77);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action67::<>(input, errors, validator, __sym0);
+        let __nt = super::__action77::<>(input, errors, validator, comments, __sym0);
         __symbols.push((__start, __Symbol::Variant13(__nt), __end));
         (1, 6)
     }
@@ -2468,20 +2745,22 @@ mod __parse__AIR {
         'err,
         'input,
         'v,
+        'c,
     >(
         input: &'input str,
         errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
         validator: &'v mut VariableValidator<'input>,
+        comments: &'c mut CommentsCollector<'input>,
         __lookahead_start: Option<&AirPos>,
         __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>,
-        _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
+        _: core::marker::PhantomData<(&'err (), &'input (), &'v (), &'c ())>,
     ) -> (usize, usize)
     {
-        // ApArgument = TTL => ActionFn(68);
+        // ApArgument = TTL => ActionFn(78);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action68::<>(input, errors, validator, __sym0);
+        let __nt = super::__action78::<>(input, errors, validator, comments, __sym0);
         __symbols.push((__start, __Symbol::Variant13(__nt), __end));
         (1, 6)
     }
@@ -2489,20 +2768,22 @@ mod __parse__AIR {
         'err,
         'input,
         'v,
+        'c,
     >(
         input: &'input str,
         errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
         validator: &'v mut VariableValidator<'input>,
+        comments: &'c mut CommentsCollector<'input>,
         __lookahead_start: Option<&AirPos>,
         __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>,
-        _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
+        _: core::marker::PhantomData<(&'err (), &'input (), &'v (), &'c ())>,
     ) -> (usize, usize)
     {
-        // ApArgument = Literal => ActionFn(69);
+        // ApArgument = Literal => ActionFn(79);
         let __sym0 = __pop_Variant7(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action69::<>(input, errors, validator, __sym0);
+        let __nt = super::__action79::<>(input, errors, validator, comments, __sym0);
         __symbols.push((__start, __Symbol::Variant13(__nt), __end));
         (1, 6)
     }
@@ -2510,20 +2791,22 @@ mod __parse__AIR {
         'err,
         'input,
         'v,
+        'c,
     >(
         input: &'input str,
         errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
         validator: &'v mut VariableValidator<'input>,
+        comments: &'c mut CommentsCollector<'input>,
         __lookahead_start: Option<&AirPos>,
         __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>,
-        _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
+        _: core::marker::PhantomData<(&'err (), &'input (), &'v (), &'c ())>,
     ) -> (usize, usize)
     {
-        // ApArgument = Number => ActionFn(70);
+        // ApArgument = Number => ActionFn(80);
         let __sym0 = __pop_Variant24(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action70::<>(input, errors, validator, __sym0);
+        let __nt = super::__action80::<>(input, errors, validator, comments, __sym0);
         __symbols.push((__start, __Symbol::Variant13(__nt), __end));
         (1, 6)
     }
@@ -2531,20 +2814,22 @@ mod __parse__AIR {
         'err,
         'input,
         'v,
+        'c,
     >(
         input: &'input str,
         errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
         validator: &'v mut VariableValidator<'input>,
+        comments: &'c mut CommentsCollector<'input>,
         __lookahead_start: Option<&AirPos>,
         __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>,
-        _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
+        _: core::marker::PhantomData<(&'err (), &'input (), &'v (), &'c ())>,
     ) -> (usize, usize)
     {
-        // ApArgument = Boolean => ActionFn(71);
+        // ApArgument = Boolean => ActionFn(81);
         let __sym0 = __pop_Variant1(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action71::<>(input, errors, validator, __sym0);
+        let __nt = super::__action81::<>(input, errors, validator, comments, __sym0);
         __symbols.push((__start, __Symbol::Variant13(__nt), __end));
         (1, 6)
     }
@@ -2552,22 +2837,24 @@ mod __parse__AIR {
         'err,
         'input,
         'v,
+        'c,
     >(
         input: &'input str,
         errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
         validator: &'v mut VariableValidator<'input>,
+        comments: &'c mut CommentsCollector<'input>,
         __lookahead_start: Option<&AirPos>,
         __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>,
-        _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
+        _: core::marker::PhantomData<(&'err (), &'input (), &'v (), &'c ())>,
     ) -> (usize, usize)
     {
-        // ApArgument = "[", "]" => ActionFn(72);
+        // ApArgument = "[", "]" => ActionFn(82);
         assert!(__symbols.len() >= 2);
         let __sym1 = __pop_Variant0(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym1.2.clone();
-        let __nt = super::__action72::<>(input, errors, validator, __sym0, __sym1);
+        let __nt = super::__action82::<>(input, errors, validator, comments, __sym0, __sym1);
         __symbols.push((__start, __Symbol::Variant13(__nt), __end));
         (2, 6)
     }
@@ -2575,20 +2862,22 @@ mod __parse__AIR {
         'err,
         'input,
         'v,
+        'c,
     >(
         input: &'input str,
         errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
         validator: &'v mut VariableValidator<'input>,
+        comments: &'c mut CommentsCollector<'input>,
         __lookahead_start: Option<&AirPos>,
         __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>,
-        _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
+        _: core::marker::PhantomData<(&'err (), &'input (), &'v (), &'c ())>,
     ) -> (usize, usize)
     {
-        // ApArgument = Scalar => ActionFn(73);
+        // ApArgument = Scalar => ActionFn(83);
         let __sym0 = __pop_Variant2(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action73::<>(input, errors, validator, __sym0);
+        let __nt = super::__action83::<>(input, errors, validator, comments, __sym0);
         __symbols.push((__start, __Symbol::Variant13(__nt), __end));
         (1, 6)
     }
@@ -2596,20 +2885,22 @@ mod __parse__AIR {
         'err,
         'input,
         'v,
+        'c,
     >(
         input: &'input str,
         errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
         validator: &'v mut VariableValidator<'input>,
+        comments: &'c mut CommentsCollector<'input>,
         __lookahead_start: Option<&AirPos>,
         __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>,
-        _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
+        _: core::marker::PhantomData<(&'err (), &'input (), &'v (), &'c ())>,
     ) -> (usize, usize)
     {
-        // ApArgument = ScalarWithLambda => ActionFn(74);
+        // ApArgument = ScalarWithLambda => ActionFn(84);
         let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action74::<>(input, errors, validator, __sym0);
+        let __nt = super::__action84::<>(input, errors, validator, comments, __sym0);
         __symbols.push((__start, __Symbol::Variant13(__nt), __end));
         (1, 6)
     }
@@ -2617,20 +2908,22 @@ mod __parse__AIR {
         'err,
         'input,
         'v,
+        'c,
     >(
         input: &'input str,
         errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
         validator: &'v mut VariableValidator<'input>,
+        comments: &'c mut CommentsCollector<'input>,
         __lookahead_start: Option<&AirPos>,
         __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>,
-        _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
+        _: core::marker::PhantomData<(&'err (), &'input (), &'v (), &'c ())>,
     ) -> (usize, usize)
     {
-        // ApArgument = CanonStream => ActionFn(75);
+        // ApArgument = CanonStream => ActionFn(85);
         let __sym0 = __pop_Variant2(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action75::<>(input, errors, validator, __sym0);
+        let __nt = super::__action85::<>(input, errors, validator, comments, __sym0);
         __symbols.push((__start, __Symbol::Variant13(__nt), __end));
         (1, 6)
     }
@@ -2638,20 +2931,22 @@ mod __parse__AIR {
         'err,
         'input,
         'v,
+        'c,
     >(
         input: &'input str,
         errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
         validator: &'v mut VariableValidator<'input>,
+        comments: &'c mut CommentsCollector<'input>,
         __lookahead_start: Option<&AirPos>,
         __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>,
-        _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
+        _: core::marker::PhantomData<(&'err (), &'input (), &'v (), &'c ())>,
     ) -> (usize, usize)
     {
-        // ApArgument = CanonStreamWithLambda => ActionFn(76);
+        // ApArgument = CanonStreamWithLambda => ActionFn(86);
         let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action76::<>(input, errors, validator, __sym0);
+        let __nt = super::__action86::<>(input, errors, validator, comments, __sym0);
         __symbols.push((__start, __Symbol::Variant13(__nt), __end));
         (1, 6)
     }
@@ -2659,20 +2954,22 @@ mod __parse__AIR {
         'err,
         'input,
         'v,
+        'c,
     >(
         input: &'input str,
         errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
         validator: &'v mut VariableValidator<'input>,
+        comments: &'c mut CommentsCollector<'input>,
         __lookahead_start: Option<&AirPos>,
         __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>,
-        _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
+        _: core::marker::PhantomData<(&'err (), &'input (), &'v (), &'c ())>,
     ) -> (usize, usize)
     {
-        // ApResult = Scalar => ActionFn(20);
+        // ApResult = Scalar => ActionFn(24);
         let __sym0 = __pop_Variant2(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action20::<>(input, errors, validator, __sym0);
+        let __nt = super::__action24::<>(input, errors, validator, comments, __sym0);
         __symbols.push((__start, __Symbol::Variant14(__nt), __end));
         (1, 7)
     }
@@ -2680,20 +2977,22 @@ mod __parse__AIR {
         'err,
         'input,
         'v,
+        'c,
     >(
         input: &'input str,
         errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
         validator: &'v mut VariableValidator<'input>,
+        comments: &'c mut CommentsCollector<'input>,
         __lookahead_start: Option<&AirPos>,
         __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>,
-        _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
+        _: core::marker::PhantomData<(&'err (), &'input (), &'v (), &'c ())>,
     ) -> (usize, usize)
     {
-        // ApResult = Stream => ActionFn(21);
+        // ApResult = Stream => ActionFn(25);
         let __sym0 = __pop_Variant2(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action21::<>(input, errors, validator, __sym0);
+        let __nt = super::__action25::<>(input, errors, validator, comments, __sym0);
         __symbols.push((__start, __Symbol::Variant14(__nt), __end));
         (1, 7)
     }
@@ -2701,20 +3000,22 @@ mod __parse__AIR {
         'err,
         'input,
         'v,
+        'c,
     >(
         input: &'input str,
         errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
         validator: &'v mut VariableValidator<'input>,
+        comments: &'c mut CommentsCollector<'input>,
         __lookahead_start: Option<&AirPos>,
         __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>,
-        _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
+        _: core::marker::PhantomData<(&'err (), &'input (), &'v (), &'c ())>,
     ) -> (usize, usize)
     {
-        // Arg = Value => ActionFn(50);
+        // Arg = Value => ActionFn(60);
         let __sym0 = __pop_Variant9(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action50::<>(input, errors, validator, __sym0);
+        let __nt = super::__action60::<>(input, errors, validator, comments, __sym0);
         __symbols.push((__start, __Symbol::Variant9(__nt), __end));
         (1, 8)
     }
@@ -2722,22 +3023,24 @@ mod __parse__AIR {
         'err,
         'input,
         'v,
+        'c,
     >(
         input: &'input str,
         errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
         validator: &'v mut VariableValidator<'input>,
+        comments: &'c mut CommentsCollector<'input>,
         __lookahead_start: Option<&AirPos>,
         __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>,
-        _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
+        _: core::marker::PhantomData<(&'err (), &'input (), &'v (), &'c ())>,
     ) -> (usize, usize)
     {
-        // Args = "[", "]" => ActionFn(92);
+        // Args = "[", "]" => ActionFn(103);
         assert!(__symbols.len() >= 2);
         let __sym1 = __pop_Variant0(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym1.2.clone();
-        let __nt = super::__action92::<>(input, errors, validator, __sym0, __sym1);
+        let __nt = super::__action103::<>(input, errors, validator, comments, __sym0, __sym1);
         __symbols.push((__start, __Symbol::Variant15(__nt), __end));
         (2, 9)
     }
@@ -2745,23 +3048,25 @@ mod __parse__AIR {
         'err,
         'input,
         'v,
+        'c,
     >(
         input: &'input str,
         errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
         validator: &'v mut VariableValidator<'input>,
+        comments: &'c mut CommentsCollector<'input>,
         __lookahead_start: Option<&AirPos>,
         __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>,
-        _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
+        _: core::marker::PhantomData<(&'err (), &'input (), &'v (), &'c ())>,
     ) -> (usize, usize)
     {
-        // Args = "[", (<Arg>)+, "]" => ActionFn(93);
+        // Args = "[", (<Arg>)+, "]" => ActionFn(104);
         assert!(__symbols.len() >= 3);
         let __sym2 = __pop_Variant0(__symbols);
         let __sym1 = __pop_Variant10(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym2.2.clone();
-        let __nt = super::__action93::<>(input, errors, validator, __sym0, __sym1, __sym2);
+        let __nt = super::__action104::<>(input, errors, validator, comments, __sym0, __sym1, __sym2);
         __symbols.push((__start, __Symbol::Variant15(__nt), __end));
         (3, 9)
     }
@@ -2769,440 +3074,559 @@ mod __parse__AIR {
         'err,
         'input,
         'v,
+        'c,
     >(
         input: &'input str,
         errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
         validator: &'v mut VariableValidator<'input>,
+        comments: &'c mut CommentsCollector<'input>,
         __lookahead_start: Option<&AirPos>,
         __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>,
-        _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
+        _: core::marker::PhantomData<(&'err (), &'input (), &'v (), &'c ())>,
     ) -> (usize, usize)
     {
-        // CallOutput = Scalar => ActionFn(22);
-        let __sym0 = __pop_Variant2(__symbols);
+        // BareInstr = "(", call, Triplet, Args, CallOutput, ")" => ActionFn(145);
+        assert!(__symbols.len() >= 6);
+        let __sym5 = __pop_Variant0(__symbols);
+        let __sym4 = __pop_Variant16(__symbols);
+        let __sym3 = __pop_Variant15(__symbols);
+        let __sym2 = __pop_Variant29(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action22::<>(input, errors, validator, __sym0);
-        __symbols.push((__start, __Symbol::Variant16(__nt), __end));
-        (1, 10)
+        let __end = __sym5.2.clone();
+        let __nt = super::__action145::<>(input, errors, validator, comments, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
+        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
+        (6, 10)
     }
     pub(crate) fn __reduce27<
         'err,
         'input,
         'v,
+        'c,
     >(
         input: &'input str,
         errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
         validator: &'v mut VariableValidator<'input>,
+        comments: &'c mut CommentsCollector<'input>,
         __lookahead_start: Option<&AirPos>,
         __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>,
-        _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
+        _: core::marker::PhantomData<(&'err (), &'input (), &'v (), &'c ())>,
     ) -> (usize, usize)
     {
-        // CallOutput = Stream => ActionFn(23);
-        let __sym0 = __pop_Variant2(__symbols);
+        // BareInstr = "(", call, Triplet, Args, ")" => ActionFn(146);
+        assert!(__symbols.len() >= 5);
+        let __sym4 = __pop_Variant0(__symbols);
+        let __sym3 = __pop_Variant15(__symbols);
+        let __sym2 = __pop_Variant29(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action23::<>(input, errors, validator, __sym0);
-        __symbols.push((__start, __Symbol::Variant16(__nt), __end));
-        (1, 10)
+        let __end = __sym4.2.clone();
+        let __nt = super::__action146::<>(input, errors, validator, comments, __sym0, __sym1, __sym2, __sym3, __sym4);
+        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
+        (5, 10)
     }
     pub(crate) fn __reduce28<
         'err,
         'input,
         'v,
+        'c,
     >(
         input: &'input str,
         errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
         validator: &'v mut VariableValidator<'input>,
+        comments: &'c mut CommentsCollector<'input>,
         __lookahead_start: Option<&AirPos>,
         __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>,
-        _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
+        _: core::marker::PhantomData<(&'err (), &'input (), &'v (), &'c ())>,
     ) -> (usize, usize)
     {
-        // CallOutput? = CallOutput => ActionFn(85);
-        let __sym0 = __pop_Variant16(__symbols);
+        // BareInstr = "(", canon, ResolvableToPeerIdVariable, StreamArgument, CanonStreamArgument, ")" => ActionFn(126);
+        assert!(__symbols.len() >= 6);
+        let __sym5 = __pop_Variant0(__symbols);
+        let __sym4 = __pop_Variant18(__symbols);
+        let __sym3 = __pop_Variant26(__symbols);
+        let __sym2 = __pop_Variant25(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action85::<>(input, errors, validator, __sym0);
-        __symbols.push((__start, __Symbol::Variant17(__nt), __end));
-        (1, 11)
+        let __end = __sym5.2.clone();
+        let __nt = super::__action126::<>(input, errors, validator, comments, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
+        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
+        (6, 10)
     }
     pub(crate) fn __reduce29<
         'err,
         'input,
         'v,
+        'c,
     >(
         input: &'input str,
         errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
         validator: &'v mut VariableValidator<'input>,
+        comments: &'c mut CommentsCollector<'input>,
         __lookahead_start: Option<&AirPos>,
         __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>,
-        _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
+        _: core::marker::PhantomData<(&'err (), &'input (), &'v (), &'c ())>,
     ) -> (usize, usize)
     {
-        // CallOutput? =  => ActionFn(86);
-        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
-        let __end = __start.clone();
-        let __nt = super::__action86::<>(input, errors, validator, &__start, &__end);
-        __symbols.push((__start, __Symbol::Variant17(__nt), __end));
-        (0, 11)
+        // BareInstr = "(", canon, ResolvableToPeerIdVariable, StreamMapArgument, Scalar, ")" => ActionFn(127);
+        assert!(__symbols.len() >= 6);
+        let __sym5 = __pop_Variant0(__symbols);
+        let __sym4 = __pop_Variant2(__symbols);
+        let __sym3 = __pop_Variant27(__symbols);
+        let __sym2 = __pop_Variant25(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym5.2.clone();
+        let __nt = super::__action127::<>(input, errors, validator, comments, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
+        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
+        (6, 10)
     }
     pub(crate) fn __reduce30<
         'err,
         'input,
         'v,
+        'c,
     >(
         input: &'input str,
         errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
         validator: &'v mut VariableValidator<'input>,
+        comments: &'c mut CommentsCollector<'input>,
         __lookahead_start: Option<&AirPos>,
         __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>,
-        _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
+        _: core::marker::PhantomData<(&'err (), &'input (), &'v (), &'c ())>,
     ) -> (usize, usize)
     {
-        // CanonStreamArgument = CanonStream => ActionFn(78);
-        let __sym0 = __pop_Variant2(__symbols);
+        // BareInstr = "(", ap, ApArgument, ApResult, ")" => ActionFn(128);
+        assert!(__symbols.len() >= 5);
+        let __sym4 = __pop_Variant0(__symbols);
+        let __sym3 = __pop_Variant14(__symbols);
+        let __sym2 = __pop_Variant13(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action78::<>(input, errors, validator, __sym0);
-        __symbols.push((__start, __Symbol::Variant18(__nt), __end));
-        (1, 12)
+        let __end = __sym4.2.clone();
+        let __nt = super::__action128::<>(input, errors, validator, comments, __sym0, __sym1, __sym2, __sym3, __sym4);
+        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
+        (5, 10)
     }
     pub(crate) fn __reduce31<
         'err,
         'input,
         'v,
+        'c,
     >(
         input: &'input str,
         errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
         validator: &'v mut VariableValidator<'input>,
+        comments: &'c mut CommentsCollector<'input>,
         __lookahead_start: Option<&AirPos>,
         __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>,
-        _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
+        _: core::marker::PhantomData<(&'err (), &'input (), &'v (), &'c ())>,
     ) -> (usize, usize)
     {
-        // FailBody = Scalar => ActionFn(24);
-        let __sym0 = __pop_Variant2(__symbols);
+        // BareInstr = "(", ap, "(", StreamMapKeyClause, ApArgument, ")", StreamMapArgument, ")" => ActionFn(129);
+        assert!(__symbols.len() >= 8);
+        let __sym7 = __pop_Variant0(__symbols);
+        let __sym6 = __pop_Variant27(__symbols);
+        let __sym5 = __pop_Variant0(__symbols);
+        let __sym4 = __pop_Variant13(__symbols);
+        let __sym3 = __pop_Variant28(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action24::<>(input, errors, validator, __sym0);
-        __symbols.push((__start, __Symbol::Variant19(__nt), __end));
-        (1, 13)
+        let __end = __sym7.2.clone();
+        let __nt = super::__action129::<>(input, errors, validator, comments, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6, __sym7);
+        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
+        (8, 10)
     }
     pub(crate) fn __reduce32<
         'err,
         'input,
         'v,
+        'c,
     >(
         input: &'input str,
         errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
         validator: &'v mut VariableValidator<'input>,
+        comments: &'c mut CommentsCollector<'input>,
         __lookahead_start: Option<&AirPos>,
         __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>,
-        _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
+        _: core::marker::PhantomData<(&'err (), &'input (), &'v (), &'c ())>,
     ) -> (usize, usize)
     {
-        // FailBody = ScalarWithLambda => ActionFn(25);
-        let __sym0 = __pop_Variant3(__symbols);
+        // BareInstr = "(", seq, Instr, Instr, ")" => ActionFn(130);
+        assert!(__symbols.len() >= 5);
+        let __sym4 = __pop_Variant0(__symbols);
+        let __sym3 = __pop_Variant12(__symbols);
+        let __sym2 = __pop_Variant12(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action25::<>(input, errors, validator, __sym0);
-        __symbols.push((__start, __Symbol::Variant19(__nt), __end));
-        (1, 13)
+        let __end = __sym4.2.clone();
+        let __nt = super::__action130::<>(input, errors, validator, comments, __sym0, __sym1, __sym2, __sym3, __sym4);
+        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
+        (5, 10)
     }
     pub(crate) fn __reduce33<
         'err,
         'input,
         'v,
+        'c,
     >(
         input: &'input str,
         errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
         validator: &'v mut VariableValidator<'input>,
+        comments: &'c mut CommentsCollector<'input>,
         __lookahead_start: Option<&AirPos>,
         __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>,
-        _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
+        _: core::marker::PhantomData<(&'err (), &'input (), &'v (), &'c ())>,
     ) -> (usize, usize)
     {
-        // FailBody = I64, Literal => ActionFn(26);
-        assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant7(__symbols);
-        let __sym0 = __pop_Variant5(__symbols);
+        // BareInstr = "(", par, Instr, Instr, ")" => ActionFn(131);
+        assert!(__symbols.len() >= 5);
+        let __sym4 = __pop_Variant0(__symbols);
+        let __sym3 = __pop_Variant12(__symbols);
+        let __sym2 = __pop_Variant12(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym1.2.clone();
-        let __nt = super::__action26::<>(input, errors, validator, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant19(__nt), __end));
-        (2, 13)
+        let __end = __sym4.2.clone();
+        let __nt = super::__action131::<>(input, errors, validator, comments, __sym0, __sym1, __sym2, __sym3, __sym4);
+        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
+        (5, 10)
     }
     pub(crate) fn __reduce34<
         'err,
         'input,
         'v,
+        'c,
     >(
         input: &'input str,
         errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
         validator: &'v mut VariableValidator<'input>,
+        comments: &'c mut CommentsCollector<'input>,
         __lookahead_start: Option<&AirPos>,
         __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>,
-        _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
+        _: core::marker::PhantomData<(&'err (), &'input (), &'v (), &'c ())>,
     ) -> (usize, usize)
     {
-        // FailBody = CanonStreamWithLambda => ActionFn(27);
-        let __sym0 = __pop_Variant3(__symbols);
+        // BareInstr = "(", never, ")" => ActionFn(132);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action27::<>(input, errors, validator, __sym0);
-        __symbols.push((__start, __Symbol::Variant19(__nt), __end));
-        (1, 13)
+        let __end = __sym2.2.clone();
+        let __nt = super::__action132::<>(input, errors, validator, comments, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
+        (3, 10)
     }
     pub(crate) fn __reduce35<
         'err,
         'input,
         'v,
+        'c,
     >(
         input: &'input str,
         errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
         validator: &'v mut VariableValidator<'input>,
+        comments: &'c mut CommentsCollector<'input>,
         __lookahead_start: Option<&AirPos>,
         __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>,
-        _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
+        _: core::marker::PhantomData<(&'err (), &'input (), &'v (), &'c ())>,
     ) -> (usize, usize)
     {
-        // FailBody = LastError => ActionFn(104);
+        // BareInstr = "(", null, ")" => ActionFn(133);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action104::<>(input, errors, validator, __sym0);
-        __symbols.push((__start, __Symbol::Variant19(__nt), __end));
-        (1, 13)
+        let __end = __sym2.2.clone();
+        let __nt = super::__action133::<>(input, errors, validator, comments, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
+        (3, 10)
     }
     pub(crate) fn __reduce36<
         'err,
         'input,
         'v,
+        'c,
     >(
         input: &'input str,
         errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
         validator: &'v mut VariableValidator<'input>,
+        comments: &'c mut CommentsCollector<'input>,
         __lookahead_start: Option<&AirPos>,
         __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>,
-        _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
+        _: core::marker::PhantomData<(&'err (), &'input (), &'v (), &'c ())>,
     ) -> (usize, usize)
     {
-        // FoldScalarIterable = Scalar => ActionFn(29);
-        let __sym0 = __pop_Variant2(__symbols);
+        // BareInstr = "(", new, NewArgument, Instr, ")" => ActionFn(134);
+        assert!(__symbols.len() >= 5);
+        let __sym4 = __pop_Variant0(__symbols);
+        let __sym3 = __pop_Variant12(__symbols);
+        let __sym2 = __pop_Variant23(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action29::<>(input, errors, validator, __sym0);
-        __symbols.push((__start, __Symbol::Variant20(__nt), __end));
-        (1, 14)
+        let __end = __sym4.2.clone();
+        let __nt = super::__action134::<>(input, errors, validator, comments, __sym0, __sym1, __sym2, __sym3, __sym4);
+        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
+        (5, 10)
     }
     pub(crate) fn __reduce37<
         'err,
         'input,
         'v,
+        'c,
     >(
         input: &'input str,
         errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
         validator: &'v mut VariableValidator<'input>,
+        comments: &'c mut CommentsCollector<'input>,
         __lookahead_start: Option<&AirPos>,
         __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>,
-        _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
+        _: core::marker::PhantomData<(&'err (), &'input (), &'v (), &'c ())>,
     ) -> (usize, usize)
     {
-        // FoldScalarIterable = ScalarWithLambda => ActionFn(30);
-        let __sym0 = __pop_Variant3(__symbols);
+        // BareInstr = "(", fail, FailBody, ")" => ActionFn(135);
+        assert!(__symbols.len() >= 4);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant19(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action30::<>(input, errors, validator, __sym0);
-        __symbols.push((__start, __Symbol::Variant20(__nt), __end));
-        (1, 14)
+        let __end = __sym3.2.clone();
+        let __nt = super::__action135::<>(input, errors, validator, comments, __sym0, __sym1, __sym2, __sym3);
+        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
+        (4, 10)
     }
     pub(crate) fn __reduce38<
         'err,
         'input,
         'v,
+        'c,
     >(
         input: &'input str,
         errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
         validator: &'v mut VariableValidator<'input>,
+        comments: &'c mut CommentsCollector<'input>,
         __lookahead_start: Option<&AirPos>,
         __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>,
-        _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
+        _: core::marker::PhantomData<(&'err (), &'input (), &'v (), &'c ())>,
     ) -> (usize, usize)
     {
-        // FoldScalarIterable = CanonStream => ActionFn(31);
-        let __sym0 = __pop_Variant2(__symbols);
+        // BareInstr = "(", fold, FoldScalarIterable, Scalar, Instr, Instr, ")" => ActionFn(147);
+        assert!(__symbols.len() >= 7);
+        let __sym6 = __pop_Variant0(__symbols);
+        let __sym5 = __pop_Variant12(__symbols);
+        let __sym4 = __pop_Variant12(__symbols);
+        let __sym3 = __pop_Variant2(__symbols);
+        let __sym2 = __pop_Variant20(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action31::<>(input, errors, validator, __sym0);
-        __symbols.push((__start, __Symbol::Variant20(__nt), __end));
-        (1, 14)
+        let __end = __sym6.2.clone();
+        let __nt = super::__action147::<>(input, errors, validator, comments, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6);
+        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
+        (7, 10)
     }
     pub(crate) fn __reduce39<
         'err,
         'input,
         'v,
+        'c,
     >(
         input: &'input str,
         errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
         validator: &'v mut VariableValidator<'input>,
+        comments: &'c mut CommentsCollector<'input>,
         __lookahead_start: Option<&AirPos>,
         __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>,
-        _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
+        _: core::marker::PhantomData<(&'err (), &'input (), &'v (), &'c ())>,
     ) -> (usize, usize)
     {
-        // FoldScalarIterable = "[", "]" => ActionFn(32);
-        assert!(__symbols.len() >= 2);
+        // BareInstr = "(", fold, FoldScalarIterable, Scalar, Instr, ")" => ActionFn(148);
+        assert!(__symbols.len() >= 6);
+        let __sym5 = __pop_Variant0(__symbols);
+        let __sym4 = __pop_Variant12(__symbols);
+        let __sym3 = __pop_Variant2(__symbols);
+        let __sym2 = __pop_Variant20(__symbols);
         let __sym1 = __pop_Variant0(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym1.2.clone();
-        let __nt = super::__action32::<>(input, errors, validator, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant20(__nt), __end));
-        (2, 14)
+        let __end = __sym5.2.clone();
+        let __nt = super::__action148::<>(input, errors, validator, comments, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
+        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
+        (6, 10)
     }
     pub(crate) fn __reduce40<
         'err,
         'input,
         'v,
+        'c,
     >(
         input: &'input str,
         errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
         validator: &'v mut VariableValidator<'input>,
+        comments: &'c mut CommentsCollector<'input>,
         __lookahead_start: Option<&AirPos>,
         __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>,
-        _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
+        _: core::marker::PhantomData<(&'err (), &'input (), &'v (), &'c ())>,
     ) -> (usize, usize)
     {
-        // Function = ResolvableToStringVariable => ActionFn(34);
-        let __sym0 = __pop_Variant21(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action34::<>(input, errors, validator, __sym0);
-        __symbols.push((__start, __Symbol::Variant21(__nt), __end));
-        (1, 15)
+        // BareInstr = "(", fold, Stream, Scalar, Instr, Instr, ")" => ActionFn(149);
+        assert!(__symbols.len() >= 7);
+        let __sym6 = __pop_Variant0(__symbols);
+        let __sym5 = __pop_Variant12(__symbols);
+        let __sym4 = __pop_Variant12(__symbols);
+        let __sym3 = __pop_Variant2(__symbols);
+        let __sym2 = __pop_Variant2(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym6.2.clone();
+        let __nt = super::__action149::<>(input, errors, validator, comments, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6);
+        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
+        (7, 10)
     }
     pub(crate) fn __reduce41<
         'err,
         'input,
         'v,
+        'c,
     >(
         input: &'input str,
         errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
         validator: &'v mut VariableValidator<'input>,
+        comments: &'c mut CommentsCollector<'input>,
         __lookahead_start: Option<&AirPos>,
         __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>,
-        _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
+        _: core::marker::PhantomData<(&'err (), &'input (), &'v (), &'c ())>,
     ) -> (usize, usize)
     {
-        // Instr = "(", call, Triplet, Args, CallOutput, ")" => ActionFn(114);
+        // BareInstr = "(", fold, Stream, Scalar, Instr, ")" => ActionFn(150);
         assert!(__symbols.len() >= 6);
         let __sym5 = __pop_Variant0(__symbols);
-        let __sym4 = __pop_Variant16(__symbols);
-        let __sym3 = __pop_Variant15(__symbols);
-        let __sym2 = __pop_Variant27(__symbols);
+        let __sym4 = __pop_Variant12(__symbols);
+        let __sym3 = __pop_Variant2(__symbols);
+        let __sym2 = __pop_Variant2(__symbols);
         let __sym1 = __pop_Variant0(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym5.2.clone();
-        let __nt = super::__action114::<>(input, errors, validator, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
+        let __nt = super::__action150::<>(input, errors, validator, comments, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
         __symbols.push((__start, __Symbol::Variant12(__nt), __end));
-        (6, 16)
+        (6, 10)
     }
     pub(crate) fn __reduce42<
         'err,
         'input,
         'v,
+        'c,
     >(
         input: &'input str,
         errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
         validator: &'v mut VariableValidator<'input>,
+        comments: &'c mut CommentsCollector<'input>,
         __lookahead_start: Option<&AirPos>,
         __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>,
-        _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
+        _: core::marker::PhantomData<(&'err (), &'input (), &'v (), &'c ())>,
     ) -> (usize, usize)
     {
-        // Instr = "(", call, Triplet, Args, ")" => ActionFn(115);
-        assert!(__symbols.len() >= 5);
-        let __sym4 = __pop_Variant0(__symbols);
-        let __sym3 = __pop_Variant15(__symbols);
+        // BareInstr = "(", fold, StreamMapArgument, Scalar, Instr, Instr, ")" => ActionFn(151);
+        assert!(__symbols.len() >= 7);
+        let __sym6 = __pop_Variant0(__symbols);
+        let __sym5 = __pop_Variant12(__symbols);
+        let __sym4 = __pop_Variant12(__symbols);
+        let __sym3 = __pop_Variant2(__symbols);
         let __sym2 = __pop_Variant27(__symbols);
         let __sym1 = __pop_Variant0(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym4.2.clone();
-        let __nt = super::__action115::<>(input, errors, validator, __sym0, __sym1, __sym2, __sym3, __sym4);
+        let __end = __sym6.2.clone();
+        let __nt = super::__action151::<>(input, errors, validator, comments, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6);
         __symbols.push((__start, __Symbol::Variant12(__nt), __end));
-        (5, 16)
+        (7, 10)
     }
     pub(crate) fn __reduce43<
         'err,
         'input,
         'v,
+        'c,
     >(
         input: &'input str,
         errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
         validator: &'v mut VariableValidator<'input>,
+        comments: &'c mut CommentsCollector<'input>,
         __lookahead_start: Option<&AirPos>,
         __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>,
-        _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
+        _: core::marker::PhantomData<(&'err (), &'input (), &'v (), &'c ())>,
     ) -> (usize, usize)
     {
-        // Instr = "(", canon, ResolvableToPeerIdVariable, StreamArgument, CanonStreamArgument, ")" => ActionFn(106);
+        // BareInstr = "(", fold, StreamMapArgument, Scalar, Instr, ")" => ActionFn(152);
         assert!(__symbols.len() >= 6);
         let __sym5 = __pop_Variant0(__symbols);
-        let __sym4 = __pop_Variant18(__symbols);
-        let __sym3 = __pop_Variant26(__symbols);
-        let __sym2 = __pop_Variant25(__symbols);
+        let __sym4 = __pop_Variant12(__symbols);
+        let __sym3 = __pop_Variant2(__symbols);
+        let __sym2 = __pop_Variant27(__symbols);
         let __sym1 = __pop_Variant0(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym5.2.clone();
-        let __nt = super::__action106::<>(input, errors, validator, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
+        let __nt = super::__action152::<>(input, errors, validator, comments, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
         __symbols.push((__start, __Symbol::Variant12(__nt), __end));
-        (6, 16)
+        (6, 10)
     }
     pub(crate) fn __reduce44<
         'err,
         'input,
         'v,
+        'c,
     >(
         input: &'input str,
         errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
         validator: &'v mut VariableValidator<'input>,
+        comments: &'c mut CommentsCollector<'input>,
         __lookahead_start: Option<&AirPos>,
         __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>,
-        _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
+        _: core::marker::PhantomData<(&'err (), &'input (), &'v (), &'c ())>,
     ) -> (usize, usize)
     {
-        // Instr = "(", ap, ApArgument, ApResult, ")" => ActionFn(107);
-        assert!(__symbols.len() >= 5);
-        let __sym4 = __pop_Variant0(__symbols);
-        let __sym3 = __pop_Variant14(__symbols);
-        let __sym2 = __pop_Variant13(__symbols);
+        // BareInstr = "(", next, Scalar, ")" => ActionFn(139);
+        assert!(__symbols.len() >= 4);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant2(__symbols);
         let __sym1 = __pop_Variant0(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym4.2.clone();
-        let __nt = super::__action107::<>(input, errors, validator, __sym0, __sym1, __sym2, __sym3, __sym4);
+        let __end = __sym3.2.clone();
+        let __nt = super::__action139::<>(input, errors, validator, comments, __sym0, __sym1, __sym2, __sym3);
         __symbols.push((__start, __Symbol::Variant12(__nt), __end));
-        (5, 16)
+        (4, 10)
     }
     pub(crate) fn __reduce45<
         'err,
         'input,
         'v,
+        'c,
     >(
         input: &'input str,
         errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
         validator: &'v mut VariableValidator<'input>,
+        comments: &'c mut CommentsCollector<'input>,
         __lookahead_start: Option<&AirPos>,
         __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>,
-        _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
+        _: core::marker::PhantomData<(&'err (), &'input (), &'v (), &'c ())>,
     ) -> (usize, usize)
     {
-        // Instr = "(", seq, Instr, Instr, ")" => ActionFn(5);
+        // BareInstr = "(", xor, Instr, Instr, ")" => ActionFn(140);
         assert!(__symbols.len() >= 5);
         let __sym4 = __pop_Variant0(__symbols);
         let __sym3 = __pop_Variant12(__symbols);
@@ -3211,1324 +3635,1776 @@ mod __parse__AIR {
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym4.2.clone();
-        let __nt = super::__action5::<>(input, errors, validator, __sym0, __sym1, __sym2, __sym3, __sym4);
+        let __nt = super::__action140::<>(input, errors, validator, comments, __sym0, __sym1, __sym2, __sym3, __sym4);
         __symbols.push((__start, __Symbol::Variant12(__nt), __end));
-        (5, 16)
+        (5, 10)
     }
     pub(crate) fn __reduce46<
         'err,
         'input,
         'v,
+        'c,
     >(
         input: &'input str,
         errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
         validator: &'v mut VariableValidator<'input>,
+        comments: &'c mut CommentsCollector<'input>,
         __lookahead_start: Option<&AirPos>,
         __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>,
-        _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
+        _: core::marker::PhantomData<(&'err (), &'input (), &'v (), &'c ())>,
     ) -> (usize, usize)
     {
-        // Instr = "(", par, Instr, Instr, ")" => ActionFn(6);
-        assert!(__symbols.len() >= 5);
-        let __sym4 = __pop_Variant0(__symbols);
-        let __sym3 = __pop_Variant12(__symbols);
-        let __sym2 = __pop_Variant12(__symbols);
+        // BareInstr = "(", match_, Value, Value, Instr, ")" => ActionFn(141);
+        assert!(__symbols.len() >= 6);
+        let __sym5 = __pop_Variant0(__symbols);
+        let __sym4 = __pop_Variant12(__symbols);
+        let __sym3 = __pop_Variant9(__symbols);
+        let __sym2 = __pop_Variant9(__symbols);
         let __sym1 = __pop_Variant0(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym4.2.clone();
-        let __nt = super::__action6::<>(input, errors, validator, __sym0, __sym1, __sym2, __sym3, __sym4);
+        let __end = __sym5.2.clone();
+        let __nt = super::__action141::<>(input, errors, validator, comments, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
         __symbols.push((__start, __Symbol::Variant12(__nt), __end));
-        (5, 16)
+        (6, 10)
     }
     pub(crate) fn __reduce47<
         'err,
         'input,
         'v,
+        'c,
     >(
         input: &'input str,
         errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
         validator: &'v mut VariableValidator<'input>,
+        comments: &'c mut CommentsCollector<'input>,
         __lookahead_start: Option<&AirPos>,
         __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>,
-        _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
+        _: core::marker::PhantomData<(&'err (), &'input (), &'v (), &'c ())>,
     ) -> (usize, usize)
     {
-        // Instr = "(", never, ")" => ActionFn(7);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant0(__symbols);
+        // BareInstr = "(", mismatch, Value, Value, Instr, ")" => ActionFn(142);
+        assert!(__symbols.len() >= 6);
+        let __sym5 = __pop_Variant0(__symbols);
+        let __sym4 = __pop_Variant12(__symbols);
+        let __sym3 = __pop_Variant9(__symbols);
+        let __sym2 = __pop_Variant9(__symbols);
         let __sym1 = __pop_Variant0(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym2.2.clone();
-        let __nt = super::__action7::<>(input, errors, validator, __sym0, __sym1, __sym2);
+        let __end = __sym5.2.clone();
+        let __nt = super::__action142::<>(input, errors, validator, comments, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
         __symbols.push((__start, __Symbol::Variant12(__nt), __end));
-        (3, 16)
+        (6, 10)
     }
     pub(crate) fn __reduce48<
         'err,
         'input,
         'v,
+        'c,
     >(
         input: &'input str,
         errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
         validator: &'v mut VariableValidator<'input>,
+        comments: &'c mut CommentsCollector<'input>,
         __lookahead_start: Option<&AirPos>,
         __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>,
-        _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
+        _: core::marker::PhantomData<(&'err (), &'input (), &'v (), &'c ())>,
     ) -> (usize, usize)
     {
-        // Instr = "(", null, ")" => ActionFn(8);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
+        // BareInstr = error => ActionFn(21);
+        let __sym0 = __pop_Variant8(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym2.2.clone();
-        let __nt = super::__action8::<>(input, errors, validator, __sym0, __sym1, __sym2);
+        let __end = __sym0.2.clone();
+        let __nt = super::__action21::<>(input, errors, validator, comments, __sym0);
         __symbols.push((__start, __Symbol::Variant12(__nt), __end));
-        (3, 16)
+        (1, 10)
     }
     pub(crate) fn __reduce49<
         'err,
         'input,
         'v,
+        'c,
     >(
         input: &'input str,
         errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
         validator: &'v mut VariableValidator<'input>,
+        comments: &'c mut CommentsCollector<'input>,
         __lookahead_start: Option<&AirPos>,
         __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>,
-        _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
+        _: core::marker::PhantomData<(&'err (), &'input (), &'v (), &'c ())>,
     ) -> (usize, usize)
     {
-        // Instr = "(", new, NewArgument, Instr, ")" => ActionFn(108);
-        assert!(__symbols.len() >= 5);
-        let __sym4 = __pop_Variant0(__symbols);
-        let __sym3 = __pop_Variant12(__symbols);
-        let __sym2 = __pop_Variant23(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
+        // CallOutput = Scalar => ActionFn(26);
+        let __sym0 = __pop_Variant2(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym4.2.clone();
-        let __nt = super::__action108::<>(input, errors, validator, __sym0, __sym1, __sym2, __sym3, __sym4);
-        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
-        (5, 16)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action26::<>(input, errors, validator, comments, __sym0);
+        __symbols.push((__start, __Symbol::Variant16(__nt), __end));
+        (1, 11)
     }
     pub(crate) fn __reduce50<
         'err,
         'input,
         'v,
+        'c,
     >(
         input: &'input str,
         errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
         validator: &'v mut VariableValidator<'input>,
+        comments: &'c mut CommentsCollector<'input>,
         __lookahead_start: Option<&AirPos>,
         __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>,
-        _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
+        _: core::marker::PhantomData<(&'err (), &'input (), &'v (), &'c ())>,
     ) -> (usize, usize)
     {
-        // Instr = "(", fail, FailBody, ")" => ActionFn(10);
-        assert!(__symbols.len() >= 4);
-        let __sym3 = __pop_Variant0(__symbols);
-        let __sym2 = __pop_Variant19(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
+        // CallOutput = Stream => ActionFn(27);
+        let __sym0 = __pop_Variant2(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym3.2.clone();
-        let __nt = super::__action10::<>(input, errors, validator, __sym0, __sym1, __sym2, __sym3);
-        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
-        (4, 16)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action27::<>(input, errors, validator, comments, __sym0);
+        __symbols.push((__start, __Symbol::Variant16(__nt), __end));
+        (1, 11)
     }
     pub(crate) fn __reduce51<
         'err,
         'input,
         'v,
+        'c,
     >(
         input: &'input str,
         errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
         validator: &'v mut VariableValidator<'input>,
+        comments: &'c mut CommentsCollector<'input>,
         __lookahead_start: Option<&AirPos>,
         __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>,
-        _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
+        _: core::marker::PhantomData<(&'err (), &'input (), &'v (), &'c ())>,
     ) -> (usize, usize)
     {
-        // Instr = "(", fold, FoldScalarIterable, Scalar, Instr, Instr, ")" => ActionFn(116);
-        assert!(__symbols.len() >= 7);
-        let __sym6 = __pop_Variant0(__symbols);
-        let __sym5 = __pop_Variant12(__symbols);
-        let __sym4 = __pop_Variant12(__symbols);
-        let __sym3 = __pop_Variant2(__symbols);
-        let __sym2 = __pop_Variant20(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
+        // CallOutput? = CallOutput => ActionFn(95);
+        let __sym0 = __pop_Variant16(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym6.2.clone();
-        let __nt = super::__action116::<>(input, errors, validator, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6);
-        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
-        (7, 16)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action95::<>(input, errors, validator, comments, __sym0);
+        __symbols.push((__start, __Symbol::Variant17(__nt), __end));
+        (1, 12)
     }
     pub(crate) fn __reduce52<
         'err,
         'input,
         'v,
+        'c,
     >(
         input: &'input str,
         errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
         validator: &'v mut VariableValidator<'input>,
+        comments: &'c mut CommentsCollector<'input>,
         __lookahead_start: Option<&AirPos>,
         __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>,
-        _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
+        _: core::marker::PhantomData<(&'err (), &'input (), &'v (), &'c ())>,
     ) -> (usize, usize)
     {
-        // Instr = "(", fold, FoldScalarIterable, Scalar, Instr, ")" => ActionFn(117);
-        assert!(__symbols.len() >= 6);
-        let __sym5 = __pop_Variant0(__symbols);
-        let __sym4 = __pop_Variant12(__symbols);
-        let __sym3 = __pop_Variant2(__symbols);
-        let __sym2 = __pop_Variant20(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym5.2.clone();
-        let __nt = super::__action117::<>(input, errors, validator, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
-        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
-        (6, 16)
+        // CallOutput? =  => ActionFn(96);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
+        let __end = __start.clone();
+        let __nt = super::__action96::<>(input, errors, validator, comments, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant17(__nt), __end));
+        (0, 12)
     }
     pub(crate) fn __reduce53<
         'err,
         'input,
         'v,
+        'c,
     >(
         input: &'input str,
         errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
         validator: &'v mut VariableValidator<'input>,
+        comments: &'c mut CommentsCollector<'input>,
         __lookahead_start: Option<&AirPos>,
         __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>,
-        _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
+        _: core::marker::PhantomData<(&'err (), &'input (), &'v (), &'c ())>,
     ) -> (usize, usize)
     {
-        // Instr = "(", fold, Stream, Scalar, Instr, Instr, ")" => ActionFn(118);
-        assert!(__symbols.len() >= 7);
-        let __sym6 = __pop_Variant0(__symbols);
-        let __sym5 = __pop_Variant12(__symbols);
-        let __sym4 = __pop_Variant12(__symbols);
-        let __sym3 = __pop_Variant2(__symbols);
-        let __sym2 = __pop_Variant2(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
+        // CanonStreamArgument = CanonStream => ActionFn(89);
+        let __sym0 = __pop_Variant2(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym6.2.clone();
-        let __nt = super::__action118::<>(input, errors, validator, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6);
-        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
-        (7, 16)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action89::<>(input, errors, validator, comments, __sym0);
+        __symbols.push((__start, __Symbol::Variant18(__nt), __end));
+        (1, 13)
     }
     pub(crate) fn __reduce54<
         'err,
         'input,
         'v,
+        'c,
     >(
         input: &'input str,
         errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
         validator: &'v mut VariableValidator<'input>,
+        comments: &'c mut CommentsCollector<'input>,
         __lookahead_start: Option<&AirPos>,
         __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>,
-        _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
+        _: core::marker::PhantomData<(&'err (), &'input (), &'v (), &'c ())>,
     ) -> (usize, usize)
     {
-        // Instr = "(", fold, Stream, Scalar, Instr, ")" => ActionFn(119);
-        assert!(__symbols.len() >= 6);
-        let __sym5 = __pop_Variant0(__symbols);
-        let __sym4 = __pop_Variant12(__symbols);
-        let __sym3 = __pop_Variant2(__symbols);
-        let __sym2 = __pop_Variant2(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
+        // FailBody = Scalar => ActionFn(28);
+        let __sym0 = __pop_Variant2(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym5.2.clone();
-        let __nt = super::__action119::<>(input, errors, validator, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
-        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
-        (6, 16)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action28::<>(input, errors, validator, comments, __sym0);
+        __symbols.push((__start, __Symbol::Variant19(__nt), __end));
+        (1, 14)
     }
     pub(crate) fn __reduce55<
         'err,
         'input,
         'v,
+        'c,
     >(
         input: &'input str,
         errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
         validator: &'v mut VariableValidator<'input>,
+        comments: &'c mut CommentsCollector<'input>,
         __lookahead_start: Option<&AirPos>,
         __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>,
-        _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
+        _: core::marker::PhantomData<(&'err (), &'input (), &'v (), &'c ())>,
     ) -> (usize, usize)
     {
-        // Instr = "(", next, Scalar, ")" => ActionFn(111);
-        assert!(__symbols.len() >= 4);
-        let __sym3 = __pop_Variant0(__symbols);
-        let __sym2 = __pop_Variant2(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
+        // FailBody = ScalarWithLambda => ActionFn(29);
+        let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym3.2.clone();
-        let __nt = super::__action111::<>(input, errors, validator, __sym0, __sym1, __sym2, __sym3);
-        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
-        (4, 16)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action29::<>(input, errors, validator, comments, __sym0);
+        __symbols.push((__start, __Symbol::Variant19(__nt), __end));
+        (1, 14)
     }
     pub(crate) fn __reduce56<
         'err,
         'input,
         'v,
+        'c,
     >(
         input: &'input str,
         errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
         validator: &'v mut VariableValidator<'input>,
+        comments: &'c mut CommentsCollector<'input>,
         __lookahead_start: Option<&AirPos>,
         __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>,
-        _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
+        _: core::marker::PhantomData<(&'err (), &'input (), &'v (), &'c ())>,
     ) -> (usize, usize)
     {
-        // Instr = "(", xor, Instr, Instr, ")" => ActionFn(14);
-        assert!(__symbols.len() >= 5);
-        let __sym4 = __pop_Variant0(__symbols);
-        let __sym3 = __pop_Variant12(__symbols);
-        let __sym2 = __pop_Variant12(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
+        // FailBody = I64, Literal => ActionFn(30);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant7(__symbols);
+        let __sym0 = __pop_Variant5(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym4.2.clone();
-        let __nt = super::__action14::<>(input, errors, validator, __sym0, __sym1, __sym2, __sym3, __sym4);
-        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
-        (5, 16)
+        let __end = __sym1.2.clone();
+        let __nt = super::__action30::<>(input, errors, validator, comments, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant19(__nt), __end));
+        (2, 14)
     }
     pub(crate) fn __reduce57<
         'err,
         'input,
         'v,
+        'c,
     >(
         input: &'input str,
         errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
         validator: &'v mut VariableValidator<'input>,
+        comments: &'c mut CommentsCollector<'input>,
         __lookahead_start: Option<&AirPos>,
         __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>,
-        _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
+        _: core::marker::PhantomData<(&'err (), &'input (), &'v (), &'c ())>,
     ) -> (usize, usize)
     {
-        // Instr = "(", match_, Value, Value, Instr, ")" => ActionFn(112);
-        assert!(__symbols.len() >= 6);
-        let __sym5 = __pop_Variant0(__symbols);
-        let __sym4 = __pop_Variant12(__symbols);
-        let __sym3 = __pop_Variant9(__symbols);
-        let __sym2 = __pop_Variant9(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
+        // FailBody = CanonStreamWithLambda => ActionFn(31);
+        let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym5.2.clone();
-        let __nt = super::__action112::<>(input, errors, validator, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
-        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
-        (6, 16)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action31::<>(input, errors, validator, comments, __sym0);
+        __symbols.push((__start, __Symbol::Variant19(__nt), __end));
+        (1, 14)
     }
     pub(crate) fn __reduce58<
         'err,
         'input,
         'v,
+        'c,
     >(
         input: &'input str,
         errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
         validator: &'v mut VariableValidator<'input>,
+        comments: &'c mut CommentsCollector<'input>,
         __lookahead_start: Option<&AirPos>,
         __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>,
-        _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
+        _: core::marker::PhantomData<(&'err (), &'input (), &'v (), &'c ())>,
     ) -> (usize, usize)
     {
-        // Instr = "(", mismatch, Value, Value, Instr, ")" => ActionFn(113);
-        assert!(__symbols.len() >= 6);
-        let __sym5 = __pop_Variant0(__symbols);
-        let __sym4 = __pop_Variant12(__symbols);
-        let __sym3 = __pop_Variant9(__symbols);
-        let __sym2 = __pop_Variant9(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
+        // FailBody = LastError => ActionFn(143);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym5.2.clone();
-        let __nt = super::__action113::<>(input, errors, validator, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
-        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
-        (6, 16)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action143::<>(input, errors, validator, comments, __sym0);
+        __symbols.push((__start, __Symbol::Variant19(__nt), __end));
+        (1, 14)
     }
     pub(crate) fn __reduce59<
         'err,
         'input,
         'v,
+        'c,
     >(
         input: &'input str,
         errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
         validator: &'v mut VariableValidator<'input>,
+        comments: &'c mut CommentsCollector<'input>,
         __lookahead_start: Option<&AirPos>,
         __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>,
-        _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
+        _: core::marker::PhantomData<(&'err (), &'input (), &'v (), &'c ())>,
     ) -> (usize, usize)
     {
-        // Instr = error => ActionFn(17);
-        let __sym0 = __pop_Variant8(__symbols);
+        // FoldScalarIterable = Scalar => ActionFn(33);
+        let __sym0 = __pop_Variant2(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action17::<>(input, errors, validator, __sym0);
-        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
-        (1, 16)
+        let __nt = super::__action33::<>(input, errors, validator, comments, __sym0);
+        __symbols.push((__start, __Symbol::Variant20(__nt), __end));
+        (1, 15)
     }
     pub(crate) fn __reduce60<
         'err,
         'input,
         'v,
+        'c,
     >(
         input: &'input str,
         errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
         validator: &'v mut VariableValidator<'input>,
+        comments: &'c mut CommentsCollector<'input>,
         __lookahead_start: Option<&AirPos>,
         __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>,
-        _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
+        _: core::marker::PhantomData<(&'err (), &'input (), &'v (), &'c ())>,
     ) -> (usize, usize)
     {
-        // Instr? = Instr => ActionFn(82);
-        let __sym0 = __pop_Variant12(__symbols);
+        // FoldScalarIterable = ScalarWithLambda => ActionFn(34);
+        let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action82::<>(input, errors, validator, __sym0);
-        __symbols.push((__start, __Symbol::Variant22(__nt), __end));
-        (1, 17)
+        let __nt = super::__action34::<>(input, errors, validator, comments, __sym0);
+        __symbols.push((__start, __Symbol::Variant20(__nt), __end));
+        (1, 15)
     }
     pub(crate) fn __reduce61<
         'err,
         'input,
         'v,
+        'c,
     >(
         input: &'input str,
         errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
         validator: &'v mut VariableValidator<'input>,
+        comments: &'c mut CommentsCollector<'input>,
         __lookahead_start: Option<&AirPos>,
         __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>,
-        _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
+        _: core::marker::PhantomData<(&'err (), &'input (), &'v (), &'c ())>,
     ) -> (usize, usize)
     {
-        // Instr? =  => ActionFn(83);
-        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
-        let __end = __start.clone();
-        let __nt = super::__action83::<>(input, errors, validator, &__start, &__end);
-        __symbols.push((__start, __Symbol::Variant22(__nt), __end));
-        (0, 17)
+        // FoldScalarIterable = CanonStream => ActionFn(35);
+        let __sym0 = __pop_Variant2(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action35::<>(input, errors, validator, comments, __sym0);
+        __symbols.push((__start, __Symbol::Variant20(__nt), __end));
+        (1, 15)
     }
     pub(crate) fn __reduce62<
         'err,
         'input,
         'v,
+        'c,
     >(
         input: &'input str,
         errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
         validator: &'v mut VariableValidator<'input>,
+        comments: &'c mut CommentsCollector<'input>,
         __lookahead_start: Option<&AirPos>,
         __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>,
-        _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
+        _: core::marker::PhantomData<(&'err (), &'input (), &'v (), &'c ())>,
     ) -> (usize, usize)
     {
-        // NewArgument = Scalar => ActionFn(45);
-        let __sym0 = __pop_Variant2(__symbols);
+        // FoldScalarIterable = "[", "]" => ActionFn(36);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action45::<>(input, errors, validator, __sym0);
-        __symbols.push((__start, __Symbol::Variant23(__nt), __end));
-        (1, 18)
+        let __end = __sym1.2.clone();
+        let __nt = super::__action36::<>(input, errors, validator, comments, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant20(__nt), __end));
+        (2, 15)
     }
     pub(crate) fn __reduce63<
         'err,
         'input,
         'v,
+        'c,
     >(
         input: &'input str,
         errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
         validator: &'v mut VariableValidator<'input>,
+        comments: &'c mut CommentsCollector<'input>,
         __lookahead_start: Option<&AirPos>,
         __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>,
-        _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
+        _: core::marker::PhantomData<(&'err (), &'input (), &'v (), &'c ())>,
     ) -> (usize, usize)
     {
-        // NewArgument = Stream => ActionFn(46);
-        let __sym0 = __pop_Variant2(__symbols);
+        // Function = ResolvableToStringVariable => ActionFn(38);
+        let __sym0 = __pop_Variant21(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action46::<>(input, errors, validator, __sym0);
-        __symbols.push((__start, __Symbol::Variant23(__nt), __end));
-        (1, 18)
+        let __nt = super::__action38::<>(input, errors, validator, comments, __sym0);
+        __symbols.push((__start, __Symbol::Variant21(__nt), __end));
+        (1, 16)
     }
     pub(crate) fn __reduce64<
         'err,
         'input,
         'v,
+        'c,
     >(
         input: &'input str,
         errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
         validator: &'v mut VariableValidator<'input>,
+        comments: &'c mut CommentsCollector<'input>,
         __lookahead_start: Option<&AirPos>,
         __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>,
-        _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
+        _: core::marker::PhantomData<(&'err (), &'input (), &'v (), &'c ())>,
     ) -> (usize, usize)
     {
-        // NewArgument = CanonStream => ActionFn(47);
-        let __sym0 = __pop_Variant2(__symbols);
+        // Instr = BareInstr => ActionFn(144);
+        let __sym0 = __pop_Variant12(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action47::<>(input, errors, validator, __sym0);
-        __symbols.push((__start, __Symbol::Variant23(__nt), __end));
-        (1, 18)
+        let __nt = super::__action144::<>(input, errors, validator, comments, __sym0);
+        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
+        (1, 17)
     }
     pub(crate) fn __reduce65<
         'err,
         'input,
         'v,
+        'c,
     >(
         input: &'input str,
         errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
         validator: &'v mut VariableValidator<'input>,
+        comments: &'c mut CommentsCollector<'input>,
         __lookahead_start: Option<&AirPos>,
         __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>,
-        _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
+        _: core::marker::PhantomData<(&'err (), &'input (), &'v (), &'c ())>,
     ) -> (usize, usize)
     {
-        // Number = I64 => ActionFn(48);
-        let __sym0 = __pop_Variant5(__symbols);
+        // Instr? = Instr => ActionFn(93);
+        let __sym0 = __pop_Variant12(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action48::<>(input, errors, validator, __sym0);
-        __symbols.push((__start, __Symbol::Variant24(__nt), __end));
-        (1, 19)
+        let __nt = super::__action93::<>(input, errors, validator, comments, __sym0);
+        __symbols.push((__start, __Symbol::Variant22(__nt), __end));
+        (1, 18)
     }
     pub(crate) fn __reduce66<
         'err,
         'input,
         'v,
+        'c,
     >(
         input: &'input str,
         errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
         validator: &'v mut VariableValidator<'input>,
+        comments: &'c mut CommentsCollector<'input>,
         __lookahead_start: Option<&AirPos>,
         __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>,
-        _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
+        _: core::marker::PhantomData<(&'err (), &'input (), &'v (), &'c ())>,
     ) -> (usize, usize)
     {
-        // Number = F64 => ActionFn(49);
-        let __sym0 = __pop_Variant4(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action49::<>(input, errors, validator, __sym0);
-        __symbols.push((__start, __Symbol::Variant24(__nt), __end));
-        (1, 19)
+        // Instr? =  => ActionFn(94);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
+        let __end = __start.clone();
+        let __nt = super::__action94::<>(input, errors, validator, comments, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant22(__nt), __end));
+        (0, 18)
     }
     pub(crate) fn __reduce67<
         'err,
         'input,
         'v,
+        'c,
     >(
         input: &'input str,
         errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
         validator: &'v mut VariableValidator<'input>,
+        comments: &'c mut CommentsCollector<'input>,
         __lookahead_start: Option<&AirPos>,
         __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>,
-        _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
+        _: core::marker::PhantomData<(&'err (), &'input (), &'v (), &'c ())>,
     ) -> (usize, usize)
     {
-        // PeerId = ResolvableToPeerIdVariable => ActionFn(33);
-        let __sym0 = __pop_Variant25(__symbols);
+        // NewArgument = Scalar => ActionFn(49);
+        let __sym0 = __pop_Variant2(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action33::<>(input, errors, validator, __sym0);
-        __symbols.push((__start, __Symbol::Variant25(__nt), __end));
-        (1, 20)
+        let __nt = super::__action49::<>(input, errors, validator, comments, __sym0);
+        __symbols.push((__start, __Symbol::Variant23(__nt), __end));
+        (1, 19)
     }
     pub(crate) fn __reduce68<
         'err,
         'input,
         'v,
+        'c,
     >(
         input: &'input str,
         errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
         validator: &'v mut VariableValidator<'input>,
+        comments: &'c mut CommentsCollector<'input>,
         __lookahead_start: Option<&AirPos>,
         __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>,
-        _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
+        _: core::marker::PhantomData<(&'err (), &'input (), &'v (), &'c ())>,
     ) -> (usize, usize)
     {
-        // ResolvableToPeerIdVariable = InitPeerId => ActionFn(36);
-        let __sym0 = __pop_Variant0(__symbols);
+        // NewArgument = Stream => ActionFn(50);
+        let __sym0 = __pop_Variant2(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action36::<>(input, errors, validator, __sym0);
-        __symbols.push((__start, __Symbol::Variant25(__nt), __end));
-        (1, 21)
+        let __nt = super::__action50::<>(input, errors, validator, comments, __sym0);
+        __symbols.push((__start, __Symbol::Variant23(__nt), __end));
+        (1, 19)
     }
     pub(crate) fn __reduce69<
         'err,
         'input,
         'v,
+        'c,
     >(
         input: &'input str,
         errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
         validator: &'v mut VariableValidator<'input>,
+        comments: &'c mut CommentsCollector<'input>,
         __lookahead_start: Option<&AirPos>,
         __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>,
-        _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
+        _: core::marker::PhantomData<(&'err (), &'input (), &'v (), &'c ())>,
     ) -> (usize, usize)
     {
-        // ResolvableToPeerIdVariable = Literal => ActionFn(37);
-        let __sym0 = __pop_Variant7(__symbols);
+        // NewArgument = CanonStream => ActionFn(51);
+        let __sym0 = __pop_Variant2(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action37::<>(input, errors, validator, __sym0);
-        __symbols.push((__start, __Symbol::Variant25(__nt), __end));
-        (1, 21)
+        let __nt = super::__action51::<>(input, errors, validator, comments, __sym0);
+        __symbols.push((__start, __Symbol::Variant23(__nt), __end));
+        (1, 19)
     }
     pub(crate) fn __reduce70<
         'err,
         'input,
         'v,
+        'c,
     >(
         input: &'input str,
         errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
         validator: &'v mut VariableValidator<'input>,
+        comments: &'c mut CommentsCollector<'input>,
         __lookahead_start: Option<&AirPos>,
         __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>,
-        _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
+        _: core::marker::PhantomData<(&'err (), &'input (), &'v (), &'c ())>,
     ) -> (usize, usize)
     {
-        // ResolvableToPeerIdVariable = Scalar => ActionFn(38);
+        // NewArgument = StreamMap => ActionFn(52);
         let __sym0 = __pop_Variant2(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action38::<>(input, errors, validator, __sym0);
-        __symbols.push((__start, __Symbol::Variant25(__nt), __end));
-        (1, 21)
+        let __nt = super::__action52::<>(input, errors, validator, comments, __sym0);
+        __symbols.push((__start, __Symbol::Variant23(__nt), __end));
+        (1, 19)
     }
     pub(crate) fn __reduce71<
         'err,
         'input,
         'v,
+        'c,
     >(
         input: &'input str,
         errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
         validator: &'v mut VariableValidator<'input>,
+        comments: &'c mut CommentsCollector<'input>,
         __lookahead_start: Option<&AirPos>,
         __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>,
-        _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
+        _: core::marker::PhantomData<(&'err (), &'input (), &'v (), &'c ())>,
     ) -> (usize, usize)
     {
-        // ResolvableToPeerIdVariable = ScalarWithLambda => ActionFn(39);
-        let __sym0 = __pop_Variant3(__symbols);
+        // Number = I64 => ActionFn(58);
+        let __sym0 = __pop_Variant5(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action39::<>(input, errors, validator, __sym0);
-        __symbols.push((__start, __Symbol::Variant25(__nt), __end));
-        (1, 21)
+        let __nt = super::__action58::<>(input, errors, validator, comments, __sym0);
+        __symbols.push((__start, __Symbol::Variant24(__nt), __end));
+        (1, 20)
     }
     pub(crate) fn __reduce72<
         'err,
         'input,
         'v,
+        'c,
     >(
         input: &'input str,
         errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
         validator: &'v mut VariableValidator<'input>,
+        comments: &'c mut CommentsCollector<'input>,
         __lookahead_start: Option<&AirPos>,
         __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>,
-        _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
+        _: core::marker::PhantomData<(&'err (), &'input (), &'v (), &'c ())>,
     ) -> (usize, usize)
     {
-        // ResolvableToPeerIdVariable = CanonStreamWithLambda => ActionFn(40);
-        let __sym0 = __pop_Variant3(__symbols);
+        // Number = F64 => ActionFn(59);
+        let __sym0 = __pop_Variant4(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action40::<>(input, errors, validator, __sym0);
-        __symbols.push((__start, __Symbol::Variant25(__nt), __end));
-        (1, 21)
+        let __nt = super::__action59::<>(input, errors, validator, comments, __sym0);
+        __symbols.push((__start, __Symbol::Variant24(__nt), __end));
+        (1, 20)
     }
     pub(crate) fn __reduce73<
         'err,
         'input,
         'v,
+        'c,
     >(
         input: &'input str,
         errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
         validator: &'v mut VariableValidator<'input>,
+        comments: &'c mut CommentsCollector<'input>,
         __lookahead_start: Option<&AirPos>,
         __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>,
-        _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
+        _: core::marker::PhantomData<(&'err (), &'input (), &'v (), &'c ())>,
     ) -> (usize, usize)
     {
-        // ResolvableToStringVariable = Literal => ActionFn(41);
-        let __sym0 = __pop_Variant7(__symbols);
+        // PeerId = ResolvableToPeerIdVariable => ActionFn(37);
+        let __sym0 = __pop_Variant25(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action41::<>(input, errors, validator, __sym0);
-        __symbols.push((__start, __Symbol::Variant21(__nt), __end));
-        (1, 22)
+        let __nt = super::__action37::<>(input, errors, validator, comments, __sym0);
+        __symbols.push((__start, __Symbol::Variant25(__nt), __end));
+        (1, 21)
     }
     pub(crate) fn __reduce74<
         'err,
         'input,
         'v,
+        'c,
     >(
         input: &'input str,
         errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
         validator: &'v mut VariableValidator<'input>,
+        comments: &'c mut CommentsCollector<'input>,
         __lookahead_start: Option<&AirPos>,
         __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>,
-        _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
+        _: core::marker::PhantomData<(&'err (), &'input (), &'v (), &'c ())>,
     ) -> (usize, usize)
     {
-        // ResolvableToStringVariable = Scalar => ActionFn(42);
-        let __sym0 = __pop_Variant2(__symbols);
+        // ResolvableToPeerIdVariable = InitPeerId => ActionFn(40);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action42::<>(input, errors, validator, __sym0);
-        __symbols.push((__start, __Symbol::Variant21(__nt), __end));
+        let __nt = super::__action40::<>(input, errors, validator, comments, __sym0);
+        __symbols.push((__start, __Symbol::Variant25(__nt), __end));
         (1, 22)
     }
     pub(crate) fn __reduce75<
         'err,
         'input,
         'v,
+        'c,
     >(
         input: &'input str,
         errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
         validator: &'v mut VariableValidator<'input>,
+        comments: &'c mut CommentsCollector<'input>,
         __lookahead_start: Option<&AirPos>,
         __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>,
-        _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
+        _: core::marker::PhantomData<(&'err (), &'input (), &'v (), &'c ())>,
     ) -> (usize, usize)
     {
-        // ResolvableToStringVariable = ScalarWithLambda => ActionFn(43);
-        let __sym0 = __pop_Variant3(__symbols);
+        // ResolvableToPeerIdVariable = Literal => ActionFn(41);
+        let __sym0 = __pop_Variant7(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action43::<>(input, errors, validator, __sym0);
-        __symbols.push((__start, __Symbol::Variant21(__nt), __end));
+        let __nt = super::__action41::<>(input, errors, validator, comments, __sym0);
+        __symbols.push((__start, __Symbol::Variant25(__nt), __end));
         (1, 22)
     }
     pub(crate) fn __reduce76<
         'err,
         'input,
         'v,
+        'c,
     >(
         input: &'input str,
         errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
         validator: &'v mut VariableValidator<'input>,
+        comments: &'c mut CommentsCollector<'input>,
         __lookahead_start: Option<&AirPos>,
         __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>,
-        _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
+        _: core::marker::PhantomData<(&'err (), &'input (), &'v (), &'c ())>,
     ) -> (usize, usize)
     {
-        // ResolvableToStringVariable = CanonStreamWithLambda => ActionFn(44);
-        let __sym0 = __pop_Variant3(__symbols);
+        // ResolvableToPeerIdVariable = Scalar => ActionFn(42);
+        let __sym0 = __pop_Variant2(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action44::<>(input, errors, validator, __sym0);
-        __symbols.push((__start, __Symbol::Variant21(__nt), __end));
+        let __nt = super::__action42::<>(input, errors, validator, comments, __sym0);
+        __symbols.push((__start, __Symbol::Variant25(__nt), __end));
         (1, 22)
     }
     pub(crate) fn __reduce77<
         'err,
         'input,
         'v,
+        'c,
     >(
         input: &'input str,
         errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
         validator: &'v mut VariableValidator<'input>,
+        comments: &'c mut CommentsCollector<'input>,
         __lookahead_start: Option<&AirPos>,
         __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>,
-        _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
+        _: core::marker::PhantomData<(&'err (), &'input (), &'v (), &'c ())>,
     ) -> (usize, usize)
     {
-        // ServiceId = ResolvableToStringVariable => ActionFn(35);
-        let __sym0 = __pop_Variant21(__symbols);
+        // ResolvableToPeerIdVariable = ScalarWithLambda => ActionFn(43);
+        let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action35::<>(input, errors, validator, __sym0);
-        __symbols.push((__start, __Symbol::Variant21(__nt), __end));
-        (1, 23)
+        let __nt = super::__action43::<>(input, errors, validator, comments, __sym0);
+        __symbols.push((__start, __Symbol::Variant25(__nt), __end));
+        (1, 22)
     }
     pub(crate) fn __reduce78<
         'err,
         'input,
         'v,
+        'c,
     >(
         input: &'input str,
         errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
         validator: &'v mut VariableValidator<'input>,
+        comments: &'c mut CommentsCollector<'input>,
         __lookahead_start: Option<&AirPos>,
         __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>,
-        _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
+        _: core::marker::PhantomData<(&'err (), &'input (), &'v (), &'c ())>,
     ) -> (usize, usize)
     {
-        // StreamArgument = Stream => ActionFn(77);
-        let __sym0 = __pop_Variant2(__symbols);
+        // ResolvableToPeerIdVariable = CanonStreamWithLambda => ActionFn(44);
+        let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action77::<>(input, errors, validator, __sym0);
-        __symbols.push((__start, __Symbol::Variant26(__nt), __end));
-        (1, 24)
+        let __nt = super::__action44::<>(input, errors, validator, comments, __sym0);
+        __symbols.push((__start, __Symbol::Variant25(__nt), __end));
+        (1, 22)
     }
     pub(crate) fn __reduce79<
         'err,
         'input,
         'v,
+        'c,
     >(
         input: &'input str,
         errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
         validator: &'v mut VariableValidator<'input>,
+        comments: &'c mut CommentsCollector<'input>,
         __lookahead_start: Option<&AirPos>,
         __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>,
-        _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
+        _: core::marker::PhantomData<(&'err (), &'input (), &'v (), &'c ())>,
     ) -> (usize, usize)
     {
-        // Triplet = PeerId, "(", ServiceId, Function, ")" => ActionFn(19);
-        assert!(__symbols.len() >= 5);
-        let __sym4 = __pop_Variant0(__symbols);
-        let __sym3 = __pop_Variant21(__symbols);
-        let __sym2 = __pop_Variant21(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant25(__symbols);
+        // ResolvableToStringVariable = Literal => ActionFn(45);
+        let __sym0 = __pop_Variant7(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym4.2.clone();
-        let __nt = super::__action19::<>(input, errors, validator, __sym0, __sym1, __sym2, __sym3, __sym4);
-        __symbols.push((__start, __Symbol::Variant27(__nt), __end));
-        (5, 25)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action45::<>(input, errors, validator, comments, __sym0);
+        __symbols.push((__start, __Symbol::Variant21(__nt), __end));
+        (1, 23)
     }
     pub(crate) fn __reduce80<
         'err,
         'input,
         'v,
+        'c,
     >(
         input: &'input str,
         errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
         validator: &'v mut VariableValidator<'input>,
+        comments: &'c mut CommentsCollector<'input>,
         __lookahead_start: Option<&AirPos>,
         __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>,
-        _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
+        _: core::marker::PhantomData<(&'err (), &'input (), &'v (), &'c ())>,
     ) -> (usize, usize)
     {
-        // Value = InitPeerId => ActionFn(51);
-        let __sym0 = __pop_Variant0(__symbols);
+        // ResolvableToStringVariable = Scalar => ActionFn(46);
+        let __sym0 = __pop_Variant2(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action51::<>(input, errors, validator, __sym0);
-        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
-        (1, 26)
+        let __nt = super::__action46::<>(input, errors, validator, comments, __sym0);
+        __symbols.push((__start, __Symbol::Variant21(__nt), __end));
+        (1, 23)
     }
     pub(crate) fn __reduce81<
         'err,
         'input,
         'v,
+        'c,
     >(
         input: &'input str,
         errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
         validator: &'v mut VariableValidator<'input>,
+        comments: &'c mut CommentsCollector<'input>,
         __lookahead_start: Option<&AirPos>,
         __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>,
-        _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
+        _: core::marker::PhantomData<(&'err (), &'input (), &'v (), &'c ())>,
     ) -> (usize, usize)
     {
-        // Value = LastError => ActionFn(52);
-        let __sym0 = __pop_Variant0(__symbols);
+        // ResolvableToStringVariable = ScalarWithLambda => ActionFn(47);
+        let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action52::<>(input, errors, validator, __sym0);
-        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
-        (1, 26)
+        let __nt = super::__action47::<>(input, errors, validator, comments, __sym0);
+        __symbols.push((__start, __Symbol::Variant21(__nt), __end));
+        (1, 23)
     }
     pub(crate) fn __reduce82<
         'err,
         'input,
         'v,
+        'c,
     >(
         input: &'input str,
         errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
         validator: &'v mut VariableValidator<'input>,
+        comments: &'c mut CommentsCollector<'input>,
         __lookahead_start: Option<&AirPos>,
         __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>,
-        _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
+        _: core::marker::PhantomData<(&'err (), &'input (), &'v (), &'c ())>,
     ) -> (usize, usize)
     {
-        // Value = LastErrorWithLambda => ActionFn(53);
-        let __sym0 = __pop_Variant6(__symbols);
+        // ResolvableToStringVariable = CanonStreamWithLambda => ActionFn(48);
+        let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action53::<>(input, errors, validator, __sym0);
-        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
-        (1, 26)
+        let __nt = super::__action48::<>(input, errors, validator, comments, __sym0);
+        __symbols.push((__start, __Symbol::Variant21(__nt), __end));
+        (1, 23)
     }
     pub(crate) fn __reduce83<
         'err,
         'input,
         'v,
+        'c,
     >(
         input: &'input str,
         errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
         validator: &'v mut VariableValidator<'input>,
+        comments: &'c mut CommentsCollector<'input>,
         __lookahead_start: Option<&AirPos>,
         __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>,
-        _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
+        _: core::marker::PhantomData<(&'err (), &'input (), &'v (), &'c ())>,
     ) -> (usize, usize)
     {
-        // Value = Literal => ActionFn(54);
-        let __sym0 = __pop_Variant7(__symbols);
+        // ServiceId = ResolvableToStringVariable => ActionFn(39);
+        let __sym0 = __pop_Variant21(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action54::<>(input, errors, validator, __sym0);
-        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
-        (1, 26)
+        let __nt = super::__action39::<>(input, errors, validator, comments, __sym0);
+        __symbols.push((__start, __Symbol::Variant21(__nt), __end));
+        (1, 24)
     }
     pub(crate) fn __reduce84<
         'err,
         'input,
         'v,
+        'c,
     >(
         input: &'input str,
         errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
         validator: &'v mut VariableValidator<'input>,
+        comments: &'c mut CommentsCollector<'input>,
         __lookahead_start: Option<&AirPos>,
         __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>,
-        _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
+        _: core::marker::PhantomData<(&'err (), &'input (), &'v (), &'c ())>,
     ) -> (usize, usize)
     {
-        // Value = Timestamp => ActionFn(55);
-        let __sym0 = __pop_Variant0(__symbols);
+        // StreamArgument = Stream => ActionFn(87);
+        let __sym0 = __pop_Variant2(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action55::<>(input, errors, validator, __sym0);
-        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
-        (1, 26)
+        let __nt = super::__action87::<>(input, errors, validator, comments, __sym0);
+        __symbols.push((__start, __Symbol::Variant26(__nt), __end));
+        (1, 25)
     }
     pub(crate) fn __reduce85<
         'err,
         'input,
         'v,
+        'c,
     >(
         input: &'input str,
         errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
         validator: &'v mut VariableValidator<'input>,
+        comments: &'c mut CommentsCollector<'input>,
         __lookahead_start: Option<&AirPos>,
         __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>,
-        _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
+        _: core::marker::PhantomData<(&'err (), &'input (), &'v (), &'c ())>,
     ) -> (usize, usize)
     {
-        // Value = TTL => ActionFn(56);
-        let __sym0 = __pop_Variant0(__symbols);
+        // StreamMapArgument = StreamMap => ActionFn(88);
+        let __sym0 = __pop_Variant2(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action56::<>(input, errors, validator, __sym0);
-        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
+        let __nt = super::__action88::<>(input, errors, validator, comments, __sym0);
+        __symbols.push((__start, __Symbol::Variant27(__nt), __end));
         (1, 26)
     }
     pub(crate) fn __reduce86<
         'err,
         'input,
         'v,
+        'c,
     >(
         input: &'input str,
         errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
         validator: &'v mut VariableValidator<'input>,
+        comments: &'c mut CommentsCollector<'input>,
         __lookahead_start: Option<&AirPos>,
         __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>,
-        _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
+        _: core::marker::PhantomData<(&'err (), &'input (), &'v (), &'c ())>,
     ) -> (usize, usize)
     {
-        // Value = Number => ActionFn(57);
-        let __sym0 = __pop_Variant24(__symbols);
+        // StreamMapKeyClause = Literal => ActionFn(53);
+        let __sym0 = __pop_Variant7(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action57::<>(input, errors, validator, __sym0);
-        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
-        (1, 26)
+        let __nt = super::__action53::<>(input, errors, validator, comments, __sym0);
+        __symbols.push((__start, __Symbol::Variant28(__nt), __end));
+        (1, 27)
     }
     pub(crate) fn __reduce87<
         'err,
         'input,
         'v,
+        'c,
     >(
         input: &'input str,
         errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
         validator: &'v mut VariableValidator<'input>,
+        comments: &'c mut CommentsCollector<'input>,
         __lookahead_start: Option<&AirPos>,
         __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>,
-        _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
+        _: core::marker::PhantomData<(&'err (), &'input (), &'v (), &'c ())>,
     ) -> (usize, usize)
     {
-        // Value = Boolean => ActionFn(58);
-        let __sym0 = __pop_Variant1(__symbols);
+        // StreamMapKeyClause = I64 => ActionFn(54);
+        let __sym0 = __pop_Variant5(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action58::<>(input, errors, validator, __sym0);
-        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
-        (1, 26)
+        let __nt = super::__action54::<>(input, errors, validator, comments, __sym0);
+        __symbols.push((__start, __Symbol::Variant28(__nt), __end));
+        (1, 27)
     }
     pub(crate) fn __reduce88<
         'err,
         'input,
         'v,
+        'c,
     >(
         input: &'input str,
         errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
         validator: &'v mut VariableValidator<'input>,
+        comments: &'c mut CommentsCollector<'input>,
         __lookahead_start: Option<&AirPos>,
         __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>,
-        _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
+        _: core::marker::PhantomData<(&'err (), &'input (), &'v (), &'c ())>,
     ) -> (usize, usize)
     {
-        // Value = "[", "]" => ActionFn(59);
-        assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
+        // StreamMapKeyClause = Scalar => ActionFn(55);
+        let __sym0 = __pop_Variant2(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym1.2.clone();
-        let __nt = super::__action59::<>(input, errors, validator, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
-        (2, 26)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action55::<>(input, errors, validator, comments, __sym0);
+        __symbols.push((__start, __Symbol::Variant28(__nt), __end));
+        (1, 27)
     }
     pub(crate) fn __reduce89<
         'err,
         'input,
         'v,
+        'c,
     >(
         input: &'input str,
         errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
         validator: &'v mut VariableValidator<'input>,
+        comments: &'c mut CommentsCollector<'input>,
         __lookahead_start: Option<&AirPos>,
         __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>,
-        _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
+        _: core::marker::PhantomData<(&'err (), &'input (), &'v (), &'c ())>,
     ) -> (usize, usize)
     {
-        // Value = Scalar => ActionFn(60);
-        let __sym0 = __pop_Variant2(__symbols);
+        // StreamMapKeyClause = ScalarWithLambda => ActionFn(56);
+        let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action60::<>(input, errors, validator, __sym0);
-        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
-        (1, 26)
+        let __nt = super::__action56::<>(input, errors, validator, comments, __sym0);
+        __symbols.push((__start, __Symbol::Variant28(__nt), __end));
+        (1, 27)
     }
     pub(crate) fn __reduce90<
         'err,
         'input,
         'v,
+        'c,
     >(
         input: &'input str,
         errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
         validator: &'v mut VariableValidator<'input>,
+        comments: &'c mut CommentsCollector<'input>,
         __lookahead_start: Option<&AirPos>,
         __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>,
-        _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
+        _: core::marker::PhantomData<(&'err (), &'input (), &'v (), &'c ())>,
     ) -> (usize, usize)
     {
-        // Value = ScalarWithLambda => ActionFn(61);
+        // StreamMapKeyClause = CanonStreamWithLambda => ActionFn(57);
         let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action61::<>(input, errors, validator, __sym0);
-        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
-        (1, 26)
+        let __nt = super::__action57::<>(input, errors, validator, comments, __sym0);
+        __symbols.push((__start, __Symbol::Variant28(__nt), __end));
+        (1, 27)
     }
     pub(crate) fn __reduce91<
         'err,
         'input,
         'v,
+        'c,
     >(
         input: &'input str,
         errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
         validator: &'v mut VariableValidator<'input>,
+        comments: &'c mut CommentsCollector<'input>,
         __lookahead_start: Option<&AirPos>,
         __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>,
-        _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
+        _: core::marker::PhantomData<(&'err (), &'input (), &'v (), &'c ())>,
     ) -> (usize, usize)
     {
-        // Value = CanonStream => ActionFn(62);
-        let __sym0 = __pop_Variant2(__symbols);
+        // Triplet = PeerId, "(", ServiceId, Function, ")" => ActionFn(23);
+        assert!(__symbols.len() >= 5);
+        let __sym4 = __pop_Variant0(__symbols);
+        let __sym3 = __pop_Variant21(__symbols);
+        let __sym2 = __pop_Variant21(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant25(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action62::<>(input, errors, validator, __sym0);
-        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
-        (1, 26)
+        let __end = __sym4.2.clone();
+        let __nt = super::__action23::<>(input, errors, validator, comments, __sym0, __sym1, __sym2, __sym3, __sym4);
+        __symbols.push((__start, __Symbol::Variant29(__nt), __end));
+        (5, 28)
     }
     pub(crate) fn __reduce92<
         'err,
         'input,
         'v,
+        'c,
     >(
         input: &'input str,
         errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
         validator: &'v mut VariableValidator<'input>,
+        comments: &'c mut CommentsCollector<'input>,
         __lookahead_start: Option<&AirPos>,
         __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>,
-        _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
+        _: core::marker::PhantomData<(&'err (), &'input (), &'v (), &'c ())>,
     ) -> (usize, usize)
     {
-        // Value = CanonStreamWithLambda => ActionFn(63);
-        let __sym0 = __pop_Variant3(__symbols);
+        // Value = InitPeerId => ActionFn(61);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action63::<>(input, errors, validator, __sym0);
+        let __nt = super::__action61::<>(input, errors, validator, comments, __sym0);
         __symbols.push((__start, __Symbol::Variant9(__nt), __end));
-        (1, 26)
+        (1, 29)
     }
-}
-pub use self::__parse__AIR::AIRParser;
-
-#[allow(unused_variables)]
-fn __action0<
-    'err,
-    'input,
-    'v,
->(
-    input: &'input str,
-    errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
-    validator: &'v mut VariableValidator<'input>,
-    (_, __0, _): (AirPos, Box<Instruction<'input>>, AirPos),
-) -> Box<Instruction<'input>>
-{
-    __0
-}
-
-#[allow(unused_variables)]
-fn __action1<
-    'err,
-    'input,
-    'v,
->(
-    input: &'input str,
-    errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
-    validator: &'v mut VariableValidator<'input>,
-    (_, __0, _): (AirPos, Box<Instruction<'input>>, AirPos),
-) -> Box<Instruction<'input>>
-{
-    __0
-}
-
-#[allow(unused_variables)]
-fn __action2<
-    'err,
-    'input,
-    'v,
->(
-    input: &'input str,
-    errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
-    validator: &'v mut VariableValidator<'input>,
-    (_, left, _): (AirPos, AirPos, AirPos),
-    (_, _, _): (AirPos, Token<'input>, AirPos),
-    (_, _, _): (AirPos, Token<'input>, AirPos),
-    (_, triplet, _): (AirPos, Triplet<'input>, AirPos),
-    (_, args, _): (AirPos, Vec<ImmutableValue<'input>>, AirPos),
-    (_, output, _): (AirPos, core::option::Option<CallOutputValue<'input>>, AirPos),
-    (_, _, _): (AirPos, Token<'input>, AirPos),
-    (_, right, _): (AirPos, AirPos, AirPos),
-) -> Box<Instruction<'input>>
-{
+    pub(crate) fn __reduce93<
+        'err,
+        'input,
+        'v,
+        'c,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+        validator: &'v mut VariableValidator<'input>,
+        comments: &'c mut CommentsCollector<'input>,
+        __lookahead_start: Option<&AirPos>,
+        __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>,
+        _: core::marker::PhantomData<(&'err (), &'input (), &'v (), &'c ())>,
+    ) -> (usize, usize)
     {
-        let args = Rc::new(args);
-        let output = output.unwrap_or(CallOutputValue::None);
-        let call = Call::new(triplet, args, output);
-        let span = Span::new(left, right);
-
-        validator.met_call(&call, span);
-
-        Box::new(Instruction::Call(call))
+        // Value = LastError => ActionFn(62);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action62::<>(input, errors, validator, comments, __sym0);
+        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
+        (1, 29)
     }
-}
-
-#[allow(unused_variables)]
-fn __action3<
-    'err,
-    'input,
-    'v,
->(
-    input: &'input str,
-    errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
-    validator: &'v mut VariableValidator<'input>,
-    (_, left, _): (AirPos, AirPos, AirPos),
-    (_, _, _): (AirPos, Token<'input>, AirPos),
-    (_, _, _): (AirPos, Token<'input>, AirPos),
-    (_, peer_id, _): (AirPos, ResolvableToPeerIdVariable<'input>, AirPos),
-    (_, stream, _): (AirPos, Stream<'input>, AirPos),
-    (_, canon_stream, _): (AirPos, CanonStream<'input>, AirPos),
-    (_, _, _): (AirPos, Token<'input>, AirPos),
-    (_, right, _): (AirPos, AirPos, AirPos),
-) -> Box<Instruction<'input>>
-{
+    pub(crate) fn __reduce94<
+        'err,
+        'input,
+        'v,
+        'c,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+        validator: &'v mut VariableValidator<'input>,
+        comments: &'c mut CommentsCollector<'input>,
+        __lookahead_start: Option<&AirPos>,
+        __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>,
+        _: core::marker::PhantomData<(&'err (), &'input (), &'v (), &'c ())>,
+    ) -> (usize, usize)
     {
-        let canon = Canon::new(peer_id, stream, canon_stream);
-
-        let span = Span::new(left, right);
-        validator.met_canon(&canon, span);
-
-        Box::new(Instruction::Canon(canon))
+        // Value = LastErrorWithLambda => ActionFn(63);
+        let __sym0 = __pop_Variant6(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action63::<>(input, errors, validator, comments, __sym0);
+        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
+        (1, 29)
     }
-}
-
-#[allow(unused_variables)]
-fn __action4<
-    'err,
-    'input,
-    'v,
->(
-    input: &'input str,
-    errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
-    validator: &'v mut VariableValidator<'input>,
-    (_, left, _): (AirPos, AirPos, AirPos),
-    (_, _, _): (AirPos, Token<'input>, AirPos),
-    (_, _, _): (AirPos, Token<'input>, AirPos),
-    (_, arg, _): (AirPos, ApArgument<'input>, AirPos),
-    (_, result, _): (AirPos, ApResult<'input>, AirPos),
-    (_, _, _): (AirPos, Token<'input>, AirPos),
-    (_, right, _): (AirPos, AirPos, AirPos),
-) -> Box<Instruction<'input>>
-{
+    pub(crate) fn __reduce95<
+        'err,
+        'input,
+        'v,
+        'c,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+        validator: &'v mut VariableValidator<'input>,
+        comments: &'c mut CommentsCollector<'input>,
+        __lookahead_start: Option<&AirPos>,
+        __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>,
+        _: core::marker::PhantomData<(&'err (), &'input (), &'v (), &'c ())>,
+    ) -> (usize, usize)
     {
-        let apply = Ap::new(arg, result);
-
-        let span = Span::new(left, right);
-        validator.met_ap(&apply, span);
-
-        Box::new(Instruction::Ap(apply))
+        // Value = Literal => ActionFn(64);
+        let __sym0 = __pop_Variant7(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action64::<>(input, errors, validator, comments, __sym0);
+        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
+        (1, 29)
+    }
+    pub(crate) fn __reduce96<
+        'err,
+        'input,
+        'v,
+        'c,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+        validator: &'v mut VariableValidator<'input>,
+        comments: &'c mut CommentsCollector<'input>,
+        __lookahead_start: Option<&AirPos>,
+        __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>,
+        _: core::marker::PhantomData<(&'err (), &'input (), &'v (), &'c ())>,
+    ) -> (usize, usize)
+    {
+        // Value = Timestamp => ActionFn(65);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action65::<>(input, errors, validator, comments, __sym0);
+        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
+        (1, 29)
+    }
+    pub(crate) fn __reduce97<
+        'err,
+        'input,
+        'v,
+        'c,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+        validator: &'v mut VariableValidator<'input>,
+        comments: &'c mut CommentsCollector<'input>,
+        __lookahead_start: Option<&AirPos>,
+        __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>,
+        _: core::marker::PhantomData<(&'err (), &'input (), &'v (), &'c ())>,
+    ) -> (usize, usize)
+    {
+        // Value = TTL => ActionFn(66);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action66::<>(input, errors, validator, comments, __sym0);
+        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
+        (1, 29)
+    }
+    pub(crate) fn __reduce98<
+        'err,
+        'input,
+        'v,
+        'c,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+        validator: &'v mut VariableValidator<'input>,
+        comments: &'c mut CommentsCollector<'input>,
+        __lookahead_start: Option<&AirPos>,
+        __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>,
+        _: core::marker::PhantomData<(&'err (), &'input (), &'v (), &'c ())>,
+    ) -> (usize, usize)
+    {
+        // Value = Number => ActionFn(67);
+        let __sym0 = __pop_Variant24(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action67::<>(input, errors, validator, comments, __sym0);
+        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
+        (1, 29)
+    }
+    pub(crate) fn __reduce99<
+        'err,
+        'input,
+        'v,
+        'c,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+        validator: &'v mut VariableValidator<'input>,
+        comments: &'c mut CommentsCollector<'input>,
+        __lookahead_start: Option<&AirPos>,
+        __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>,
+        _: core::marker::PhantomData<(&'err (), &'input (), &'v (), &'c ())>,
+    ) -> (usize, usize)
+    {
+        // Value = Boolean => ActionFn(68);
+        let __sym0 = __pop_Variant1(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action68::<>(input, errors, validator, comments, __sym0);
+        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
+        (1, 29)
+    }
+    pub(crate) fn __reduce100<
+        'err,
+        'input,
+        'v,
+        'c,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+        validator: &'v mut VariableValidator<'input>,
+        comments: &'c mut CommentsCollector<'input>,
+        __lookahead_start: Option<&AirPos>,
+        __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>,
+        _: core::marker::PhantomData<(&'err (), &'input (), &'v (), &'c ())>,
+    ) -> (usize, usize)
+    {
+        // Value = "[", "]" => ActionFn(69);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action69::<>(input, errors, validator, comments, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
+        (2, 29)
+    }
+    pub(crate) fn __reduce101<
+        'err,
+        'input,
+        'v,
+        'c,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+        validator: &'v mut VariableValidator<'input>,
+        comments: &'c mut CommentsCollector<'input>,
+        __lookahead_start: Option<&AirPos>,
+        __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>,
+        _: core::marker::PhantomData<(&'err (), &'input (), &'v (), &'c ())>,
+    ) -> (usize, usize)
+    {
+        // Value = Scalar => ActionFn(70);
+        let __sym0 = __pop_Variant2(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action70::<>(input, errors, validator, comments, __sym0);
+        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
+        (1, 29)
+    }
+    pub(crate) fn __reduce102<
+        'err,
+        'input,
+        'v,
+        'c,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+        validator: &'v mut VariableValidator<'input>,
+        comments: &'c mut CommentsCollector<'input>,
+        __lookahead_start: Option<&AirPos>,
+        __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>,
+        _: core::marker::PhantomData<(&'err (), &'input (), &'v (), &'c ())>,
+    ) -> (usize, usize)
+    {
+        // Value = ScalarWithLambda => ActionFn(71);
+        let __sym0 = __pop_Variant3(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action71::<>(input, errors, validator, comments, __sym0);
+        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
+        (1, 29)
+    }
+    pub(crate) fn __reduce103<
+        'err,
+        'input,
+        'v,
+        'c,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+        validator: &'v mut VariableValidator<'input>,
+        comments: &'c mut CommentsCollector<'input>,
+        __lookahead_start: Option<&AirPos>,
+        __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>,
+        _: core::marker::PhantomData<(&'err (), &'input (), &'v (), &'c ())>,
+    ) -> (usize, usize)
+    {
+        // Value = CanonStream => ActionFn(72);
+        let __sym0 = __pop_Variant2(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action72::<>(input, errors, validator, comments, __sym0);
+        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
+        (1, 29)
+    }
+    pub(crate) fn __reduce104<
+        'err,
+        'input,
+        'v,
+        'c,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+        validator: &'v mut VariableValidator<'input>,
+        comments: &'c mut CommentsCollector<'input>,
+        __lookahead_start: Option<&AirPos>,
+        __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>,
+        _: core::marker::PhantomData<(&'err (), &'input (), &'v (), &'c ())>,
+    ) -> (usize, usize)
+    {
+        // Value = CanonStreamWithLambda => ActionFn(73);
+        let __sym0 = __pop_Variant3(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action73::<>(input, errors, validator, comments, __sym0);
+        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
+        (1, 29)
     }
 }
+pub use self::__parse__AIR::AIRParser;
 
 #[allow(unused_variables)]
-fn __action5<
+fn __action0<
     'err,
     'input,
     'v,
+    'c,
 >(
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
-    (_, _, _): (AirPos, Token<'input>, AirPos),
-    (_, _, _): (AirPos, Token<'input>, AirPos),
-    (_, l, _): (AirPos, Box<Instruction<'input>>, AirPos),
-    (_, r, _): (AirPos, Box<Instruction<'input>>, AirPos),
-    (_, _, _): (AirPos, Token<'input>, AirPos),
+    comments: &'c mut CommentsCollector<'input>,
+    (_, __0, _): (AirPos, Box<Instruction<'input>>, AirPos),
 ) -> Box<Instruction<'input>>
 {
-    Box::new(Instruction::Seq(Seq::new(l, r)))
+    __0
 }
 
 #[allow(unused_variables)]
-fn __action6<
+fn __action1<
     'err,
     'input,
     'v,
+    'c,
 >(
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
-    (_, _, _): (AirPos, Token<'input>, AirPos),
-    (_, _, _): (AirPos, Token<'input>, AirPos),
-    (_, l, _): (AirPos, Box<Instruction<'input>>, AirPos),
-    (_, r, _): (AirPos, Box<Instruction<'input>>, AirPos),
-    (_, _, _): (AirPos, Token<'input>, AirPos),
+    comments: &'c mut CommentsCollector<'input>,
+    (_, __0, _): (AirPos, Box<Instruction<'input>>, AirPos),
 ) -> Box<Instruction<'input>>
 {
-    Box::new(Instruction::Par(Par::new(l, r)))
+    __0
 }
 
 #[allow(unused_variables)]
-fn __action7<
+fn __action2<
     'err,
     'input,
     'v,
+    'c,
 >(
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
-    (_, __0, _): (AirPos, Token<'input>, AirPos),
-    (_, __1, _): (AirPos, Token<'input>, AirPos),
-    (_, __2, _): (AirPos, Token<'input>, AirPos),
+    comments: &'c mut CommentsCollector<'input>,
+    (_, left, _): (AirPos, AirPos, AirPos),
+    (_, instruction, _): (AirPos, Box<Instruction<'input>>, AirPos),
+    (_, right, _): (AirPos, AirPos, AirPos),
 ) -> Box<Instruction<'input>>
 {
-    Box::new(Instruction::Never(Never))
+    {
+        let span = Span::new(left, right);
+        comments.attach(instruction, span)
+    }
 }
 
 #[allow(unused_variables)]
-fn __action8<
+fn __action3<
     'err,
     'input,
     'v,
+    'c,
 >(
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
-    (_, __0, _): (AirPos, Token<'input>, AirPos),
-    (_, __1, _): (AirPos, Token<'input>, AirPos),
-    (_, __2, _): (AirPos, Token<'input>, AirPos),
+    comments: &'c mut CommentsCollector<'input>,
+    (_, left, _): (AirPos, AirPos, AirPos),
+    (_, _, _): (AirPos, Token<'input>, AirPos),
+    (_, _, _): (AirPos, Token<'input>, AirPos),
+    (_, triplet, _): (AirPos, Triplet<'input>, AirPos),
+    (_, args, _): (AirPos, Vec<ImmutableValue<'input>>, AirPos),
+    (_, output, _): (AirPos, core::option::Option<CallOutputValue<'input>>, AirPos),
+    (_, _, _): (AirPos, Token<'input>, AirPos),
+    (_, right, _): (AirPos, AirPos, AirPos),
 ) -> Box<Instruction<'input>>
 {
-    Box::new(Instruction::Null(Null))
+    {
+        let args = Rc::new(args);
+        let output = output.unwrap_or(CallOutputValue::None);
+        let span = Span::new(left, right);
+        let call = Call::new(triplet, args, output, span);
+
+        validator.met_call(&call, span);
+
+        Box::new(Instruction::Call(call))
+    }
 }
 
 #[allow(unused_variables)]
-fn __action9<
+fn __action4<
     'err,
     'input,
     'v,
+    'c,
 >(
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
+    comments: &'c mut CommentsCollector<'input>,
     (_, left, _): (AirPos, AirPos, AirPos),
     (_, _, _): (AirPos, Token<'input>, AirPos),
     (_, _, _): (AirPos, Token<'input>, AirPos),
-    (_, argument, _): (AirPos, NewArgument<'input>, AirPos),
-    (_, instruction, _): (AirPos, Box<Instruction<'input>>, AirPos),
+    (_, peer_id, _): (AirPos, ResolvableToPeerIdVariable<'input>, AirPos),
+    (_, stream, _): (AirPos, Stream<'input>, AirPos),
+    (_, canon_stream, _): (AirPos, CanonStream<'input>, AirPos),
     (_, _, _): (AirPos, Token<'input>, AirPos),
     (_, right, _): (AirPos, AirPos, AirPos),
 ) -> Box<Instruction<'input>>
 {
     {
         let span = Span::new(left, right);
-        let new = New::new(argument, instruction, span);
+        let canon = Canon::new(peer_id, stream, canon_stream, span);
 
-        validator.met_new(&new, span);
+        validator.met_canon(&canon, span);
 
-        Box::new(Instruction::New(new))
+        Box::new(Instruction::Canon(canon))
     }
 }
 
 #[allow(unused_variables)]
-fn __action10<
+fn __action5<
     'err,
     'input,
     'v,
+    'c,
 >(
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
+    comments: &'c mut CommentsCollector<'input>,
+    (_, left, _): (AirPos, AirPos, AirPos),
     (_, _, _): (AirPos, Token<'input>, AirPos),
     (_, _, _): (AirPos, Token<'input>, AirPos),
-    (_, fail_body, _): (AirPos, Fail<'input>, AirPos),
+    (_, peer_id, _): (AirPos, ResolvableToPeerIdVariable<'input>, AirPos),
+    (_, stream_map, _): (AirPos, StreamMap<'input>, AirPos),
+    (_, scalar, _): (AirPos, (&'input str, AirPos), AirPos),
     (_, _, _): (AirPos, Token<'input>, AirPos),
+    (_, right, _): (AirPos, AirPos, AirPos),
 ) -> Box<Instruction<'input>>
 {
     {
-        Box::new(Instruction::Fail(fail_body))
+        let scalar = Scalar::new(scalar.0, scalar.1);
+        let span = Span::new(left, right);
+        let canon_map = CanonMap::new(peer_id, stream_map, scalar, span);
+
+        validator.met_canon_map(&canon_map, span);
+
+        Box::new(Instruction::CanonMap(canon_map))
     }
 }
 
 #[allow(unused_variables)]
-fn __action11<
+fn __action6<
     'err,
     'input,
     'v,
+    'c,
 >(
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
+    comments: &'c mut CommentsCollector<'input>,
     (_, left, _): (AirPos, AirPos, AirPos),
     (_, _, _): (AirPos, Token<'input>, AirPos),
     (_, _, _): (AirPos, Token<'input>, AirPos),
-    (_, iterable, _): (AirPos, FoldScalarIterable<'input>, AirPos),
+    (_, arg, _): (AirPos, ApArgument<'input>, AirPos),
+    (_, result, _): (AirPos, ApResult<'input>, AirPos),
+    (_, _, _): (AirPos, Token<'input>, AirPos),
+    (_, right, _): (AirPos, AirPos, AirPos),
+) -> Box<Instruction<'input>>
+{
+    {
+        let span = Span::new(left, right);
+        let apply = Ap::new(arg, result, span);
+
+        validator.met_ap(&apply, span);
+
+        Box::new(Instruction::Ap(apply))
+    }
+}
+
+#[allow(unused_variables)]
+fn __action7<
+    'err,
+    'input,
+    'v,
+    'c,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+    validator: &'v mut VariableValidator<'input>,
+    comments: &'c mut CommentsCollector<'input>,
+    (_, left, _): (AirPos, AirPos, AirPos),
+    (_, _, _): (AirPos, Token<'input>, AirPos),
+    (_, _, _): (AirPos, Token<'input>, AirPos),
+    (_, _, _): (AirPos, Token<'input>, AirPos),
+    (_, key, _): (AirPos, StreamMapKeyClause<'input>, AirPos),
+    (_, value, _): (AirPos, ApArgument<'input>, AirPos),
+    (_, _, _): (AirPos, Token<'input>, AirPos),
+    (_, map, _): (AirPos, StreamMap<'input>, AirPos),
+    (_, _, _): (AirPos, Token<'input>, AirPos),
+    (_, right, _): (AirPos, AirPos, AirPos),
+) -> Box<Instruction<'input>>
+{
+    {
+        let span = Span::new(left, right);
+        let ap_map = ApMap::new(key, value, map, span);
+
+        validator.met_ap_map(&ap_map, span);
+
+        Box::new(Instruction::ApMap(ap_map))
+    }
+}
+
+#[allow(unused_variables)]
+fn __action8<
+    'err,
+    'input,
+    'v,
+    'c,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+    validator: &'v mut VariableValidator<'input>,
+    comments: &'c mut CommentsCollector<'input>,
+    (_, left, _): (AirPos, AirPos, AirPos),
+    (_, _, _): (AirPos, Token<'input>, AirPos),
+    (_, _, _): (AirPos, Token<'input>, AirPos),
+    (_, l, _): (AirPos, Box<Instruction<'input>>, AirPos),
+    (_, r, _): (AirPos, Box<Instruction<'input>>, AirPos),
+    (_, _, _): (AirPos, Token<'input>, AirPos),
+    (_, right, _): (AirPos, AirPos, AirPos),
+) -> Box<Instruction<'input>>
+{
+    {
+        let span = Span::new(left, right);
+        Box::new(Instruction::Seq(Seq::new(l, r, span)))
+    }
+}
+
+#[allow(unused_variables)]
+fn __action9<
+    'err,
+    'input,
+    'v,
+    'c,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+    validator: &'v mut VariableValidator<'input>,
+    comments: &'c mut CommentsCollector<'input>,
+    (_, left, _): (AirPos, AirPos, AirPos),
+    (_, _, _): (AirPos, Token<'input>, AirPos),
+    (_, _, _): (AirPos, Token<'input>, AirPos),
+    (_, l, _): (AirPos, Box<Instruction<'input>>, AirPos),
+    (_, r, _): (AirPos, Box<Instruction<'input>>, AirPos),
+    (_, _, _): (AirPos, Token<'input>, AirPos),
+    (_, right, _): (AirPos, AirPos, AirPos),
+) -> Box<Instruction<'input>>
+{
+    {
+        let span = Span::new(left, right);
+        Box::new(Instruction::Par(Par::new(l, r, span)))
+    }
+}
+
+#[allow(unused_variables)]
+fn __action10<
+    'err,
+    'input,
+    'v,
+    'c,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+    validator: &'v mut VariableValidator<'input>,
+    comments: &'c mut CommentsCollector<'input>,
+    (_, left, _): (AirPos, AirPos, AirPos),
+    (_, _, _): (AirPos, Token<'input>, AirPos),
+    (_, _, _): (AirPos, Token<'input>, AirPos),
+    (_, _, _): (AirPos, Token<'input>, AirPos),
+    (_, right, _): (AirPos, AirPos, AirPos),
+) -> Box<Instruction<'input>>
+{
+    {
+        let span = Span::new(left, right);
+        Box::new(Instruction::Never(Never::new(span)))
+    }
+}
+
+#[allow(unused_variables)]
+fn __action11<
+    'err,
+    'input,
+    'v,
+    'c,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+    validator: &'v mut VariableValidator<'input>,
+    comments: &'c mut CommentsCollector<'input>,
+    (_, left, _): (AirPos, AirPos, AirPos),
+    (_, _, _): (AirPos, Token<'input>, AirPos),
+    (_, _, _): (AirPos, Token<'input>, AirPos),
+    (_, _, _): (AirPos, Token<'input>, AirPos),
+    (_, right, _): (AirPos, AirPos, AirPos),
+) -> Box<Instruction<'input>>
+{
+    {
+        let span = Span::new(left, right);
+        Box::new(Instruction::Null(Null::new(span)))
+    }
+}
+
+#[allow(unused_variables)]
+fn __action12<
+    'err,
+    'input,
+    'v,
+    'c,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+    validator: &'v mut VariableValidator<'input>,
+    comments: &'c mut CommentsCollector<'input>,
+    (_, left, _): (AirPos, AirPos, AirPos),
+    (_, _, _): (AirPos, Token<'input>, AirPos),
+    (_, _, _): (AirPos, Token<'input>, AirPos),
+    (_, argument, _): (AirPos, NewArgument<'input>, AirPos),
+    (_, instruction, _): (AirPos, Box<Instruction<'input>>, AirPos),
+    (_, _, _): (AirPos, Token<'input>, AirPos),
+    (_, right, _): (AirPos, AirPos, AirPos),
+) -> Box<Instruction<'input>>
+{
+    {
+        let span = Span::new(left, right);
+        let new = New::new(argument, instruction, span);
+
+        validator.met_new(&new, span);
+
+        Box::new(Instruction::New(new))
+    }
+}
+
+#[allow(unused_variables)]
+fn __action13<
+    'err,
+    'input,
+    'v,
+    'c,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+    validator: &'v mut VariableValidator<'input>,
+    comments: &'c mut CommentsCollector<'input>,
+    (_, left, _): (AirPos, AirPos, AirPos),
+    (_, _, _): (AirPos, Token<'input>, AirPos),
+    (_, _, _): (AirPos, Token<'input>, AirPos),
+    (_, fail_body, _): (AirPos, FailArgument<'input>, AirPos),
+    (_, _, _): (AirPos, Token<'input>, AirPos),
+    (_, right, _): (AirPos, AirPos, AirPos),
+) -> Box<Instruction<'input>>
+{
+    {
+        let span = Span::new(left, right);
+        Box::new(Instruction::Fail(Fail::new(fail_body, span)))
+    }
+}
+
+#[allow(unused_variables)]
+fn __action14<
+    'err,
+    'input,
+    'v,
+    'c,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+    validator: &'v mut VariableValidator<'input>,
+    comments: &'c mut CommentsCollector<'input>,
+    (_, left, _): (AirPos, AirPos, AirPos),
+    (_, _, _): (AirPos, Token<'input>, AirPos),
+    (_, _, _): (AirPos, Token<'input>, AirPos),
+    (_, iterable, _): (AirPos, FoldScalarIterable<'input>, AirPos),
     (_, iterator, _): (AirPos, (&'input str, AirPos), AirPos),
     (_, instruction, _): (AirPos, Box<Instruction<'input>>, AirPos),
     (_, last_instruction, _): (AirPos, core::option::Option<Box<Instruction<'input>>>, AirPos),
@@ -4548,14 +5424,16 @@ fn __action11<
 }
 
 #[allow(unused_variables)]
-fn __action12<
+fn __action15<
     'err,
     'input,
     'v,
+    'c,
 >(
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
+    comments: &'c mut CommentsCollector<'input>,
     (_, left, _): (AirPos, AirPos, AirPos),
     (_, _, _): (AirPos, Token<'input>, AirPos),
     (_, _, _): (AirPos, Token<'input>, AirPos),
@@ -4580,14 +5458,49 @@ fn __action12<
 }
 
 #[allow(unused_variables)]
-fn __action13<
+fn __action16<
+    'err,
+    'input,
+    'v,
+    'c,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+    validator: &'v mut VariableValidator<'input>,
+    comments: &'c mut CommentsCollector<'input>,
+    (_, left, _): (AirPos, AirPos, AirPos),
+    (_, _, _): (AirPos, Token<'input>, AirPos),
+    (_, _, _): (AirPos, Token<'input>, AirPos),
+    (_, stream_map, _): (AirPos, StreamMap<'input>, AirPos),
+    (_, iterator, _): (AirPos, (&'input str, AirPos), AirPos),
+    (_, instruction, _): (AirPos, Box<Instruction<'input>>, AirPos),
+    (_, last_instruction, _): (AirPos, core::option::Option<Box<Instruction<'input>>>, AirPos),
+    (_, _, _): (AirPos, Token<'input>, AirPos),
+    (_, right, _): (AirPos, AirPos, AirPos),
+) -> Box<Instruction<'input>>
+{
+    {
+        let iterator = Scalar::new(iterator.0, iterator.1);
+        let span = Span::new(left, right);
+        let fold = FoldStreamMap::new(stream_map, iterator, *instruction, last_instruction.map(|v| *v), span);
+
+        validator.meet_fold_stream_map(&fold, span);
+
+        Box::new(Instruction::FoldStreamMap(fold))
+    }
+}
+
+#[allow(unused_variables)]
+fn __action17<
     'err,
     'input,
     'v,
+    'c,
 >(
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
+    comments: &'c mut CommentsCollector<'input>,
     (_, left, _): (AirPos, AirPos, AirPos),
     (_, _, _): (AirPos, Token<'input>, AirPos),
     (_, _, _): (AirPos, Token<'input>, AirPos),
@@ -4598,8 +5511,8 @@ fn __action13<
 {
     {
         let iterator = Scalar::new(iterator.0, iterator.1);
-        let next = Next::new(iterator);
         let span = Span::new(left, right);
+        let next = Next::new(iterator, span);
         validator.met_next(&next, span);
 
         Box::new(Instruction::Next(next))
@@ -4607,33 +5520,42 @@ fn __action13<
 }
 
 #[allow(unused_variables)]
-fn __action14<
+fn __action18<
     'err,
     'input,
     'v,
+    'c,
 >(
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
+    comments: &'c mut CommentsCollector<'input>,
+    (_, left, _): (AirPos, AirPos, AirPos),
     (_, _, _): (AirPos, Token<'input>, AirPos),
     (_, _, _): (AirPos, Token<'input>, AirPos),
     (_, l, _): (AirPos, Box<Instruction<'input>>, AirPos),
     (_, r, _): (AirPos, Box<Instruction<'input>>, AirPos),
     (_, _, _): (AirPos, Token<'input>, AirPos),
+    (_, right, _): (AirPos, AirPos, AirPos),
 ) -> Box<Instruction<'input>>
 {
-    Box::new(Instruction::Xor(Xor(l, r)))
+    {
+        let span = Span::new(left, right);
+        Box::new(Instruction::Xor(Xor::new(l, r, span)))
+    }
 }
 
 #[allow(unused_variables)]
-fn __action15<
+fn __action19<
     'err,
     'input,
     'v,
+    'c,
 >(
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
+    comments: &'c mut CommentsCollector<'input>,
     (_, left, _): (AirPos, AirPos, AirPos),
     (_, _, _): (AirPos, Token<'input>, AirPos),
     (_, _, _): (AirPos, Token<'input>, AirPos),
@@ -4645,8 +5567,8 @@ fn __action15<
 ) -> Box<Instruction<'input>>
 {
     {
-        let match_ = Match::new(l, r, i);
         let span = Span::new(left, right);
+        let match_ = Match::new(l, r, i, span);
         validator.met_match(&match_, span);
 
         Box::new(Instruction::Match(match_))
@@ -4654,14 +5576,16 @@ fn __action15<
 }
 
 #[allow(unused_variables)]
-fn __action16<
+fn __action20<
     'err,
     'input,
     'v,
+    'c,
 >(
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
+    comments: &'c mut CommentsCollector<'input>,
     (_, left, _): (AirPos, AirPos, AirPos),
     (_, _, _): (AirPos, Token<'input>, AirPos),
     (_, _, _): (AirPos, Token<'input>, AirPos),
@@ -4673,8 +5597,8 @@ fn __action16<
 ) -> Box<Instruction<'input>>
 {
     {
-        let mismatch = MisMatch::new(l, r, i);
         let span = Span::new(left, right);
+        let mismatch = MisMatch::new(l, r, i, span);
         validator.met_mismatch(&mismatch, span);
 
         Box::new(Instruction::MisMatch(mismatch))
@@ -4682,14 +5606,16 @@ fn __action16<
 }
 
 #[allow(unused_variables)]
-fn __action17<
+fn __action21<
     'err,
     'input,
     'v,
+    'c,
 >(
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
+    comments: &'c mut CommentsCollector<'input>,
     (_, __0, _): (AirPos, __lalrpop_util::ErrorRecovery<AirPos, Token<'input>, ParserError>, AirPos),
 ) -> Box<Instruction<'input>>
 {
@@ -4697,14 +5623,16 @@ fn __action17<
 }
 
 #[allow(unused_variables)]
-fn __action18<
+fn __action22<
     'err,
     'input,
     'v,
+    'c,
 >(
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
+    comments: &'c mut CommentsCollector<'input>,
     (_, _, _): (AirPos, Token<'input>, AirPos),
     (_, args, _): (AirPos, alloc::vec::Vec<ImmutableValue<'input>>, AirPos),
     (_, _, _): (AirPos, Token<'input>, AirPos),
@@ -4714,14 +5642,16 @@ fn __action18<
 }
 
 #[allow(unused_variables)]
-fn __action19<
+fn __action23<
     'err,
     'input,
     'v,
+    'c,
 >(
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
+    comments: &'c mut CommentsCollector<'input>,
     (_, peer_id, _): (AirPos, ResolvableToPeerIdVariable<'input>, AirPos),
     (_, _, _): (AirPos, Token<'input>, AirPos),
     (_, service_id, _): (AirPos, ResolvableToStringVariable<'input>, AirPos),
@@ -4737,14 +5667,16 @@ fn __action19<
 }
 
 #[allow(unused_variables)]
-fn __action20<
+fn __action24<
     'err,
     'input,
     'v,
+    'c,
 >(
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
+    comments: &'c mut CommentsCollector<'input>,
     (_, scalar, _): (AirPos, (&'input str, AirPos), AirPos),
 ) -> ApResult<'input>
 {
@@ -4752,14 +5684,16 @@ fn __action20<
 }
 
 #[allow(unused_variables)]
-fn __action21<
+fn __action25<
     'err,
     'input,
     'v,
+    'c,
 >(
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
+    comments: &'c mut CommentsCollector<'input>,
     (_, stream, _): (AirPos, (&'input str, AirPos), AirPos),
 ) -> ApResult<'input>
 {
@@ -4767,14 +5701,16 @@ fn __action21<
 }
 
 #[allow(unused_variables)]
-fn __action22<
+fn __action26<
     'err,
     'input,
     'v,
+    'c,
 >(
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
+    comments: &'c mut CommentsCollector<'input>,
     (_, scalar, _): (AirPos, (&'input str, AirPos), AirPos),
 ) -> CallOutputValue<'input>
 {
@@ -4782,14 +5718,16 @@ fn __action22<
 }
 
 #[allow(unused_variables)]
-fn __action23<
+fn __action27<
     'err,
     'input,
     'v,
+    'c,
 >(
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
+    comments: &'c mut CommentsCollector<'input>,
     (_, stream, _): (AirPos, (&'input str, AirPos), AirPos),
 ) -> CallOutputValue<'input>
 {
@@ -4797,97 +5735,109 @@ fn __action23<
 }
 
 #[allow(unused_variables)]
-fn __action24<
+fn __action28<
     'err,
     'input,
     'v,
+    'c,
 >(
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
+    comments: &'c mut CommentsCollector<'input>,
     (_, scalar, _): (AirPos, (&'input str, AirPos), AirPos),
-) -> Fail<'input>
+) -> FailArgument<'input>
 {
-    Fail::Scalar(Scalar::new(scalar.0, scalar.1))
+    FailArgument::Scalar(Scalar::new(scalar.0, scalar.1))
 }
 
 #[allow(unused_variables)]
-fn __action25<
+fn __action29<
     'err,
     'input,
     'v,
+    'c,
 >(
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
+    comments: &'c mut CommentsCollector<'input>,
     (_, scalar, _): (AirPos, (&'input str, LambdaAST<'input>, AirPos), AirPos),
-) -> Fail<'input>
+) -> FailArgument<'input>
 {
-    Fail::ScalarWithLambda(ScalarWithLambda::new(scalar.0, scalar.1, scalar.2))
+    FailArgument::ScalarWithLambda(ScalarWithLambda::new(scalar.0, scalar.1, scalar.2))
 }
 
 #[allow(unused_variables)]
-fn __action26<
+fn __action30<
     'err,
     'input,
     'v,
+    'c,
 >(
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
+    comments: &'c mut CommentsCollector<'input>,
     (_, ret_code, _): (AirPos, i64, AirPos),
     (_, error_message, _): (AirPos, &'input str, AirPos),
-) -> Fail<'input>
+) -> FailArgument<'input>
 {
-    Fail::Literal {
+    FailArgument::Literal {
         ret_code,
-        error_message,
+        error_message: error_message.into(),
     }
 }
 
 #[allow(unused_variables)]
-fn __action27<
+fn __action31<
     'err,
     'input,
     'v,
+    'c,
 >(
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
+    comments: &'c mut CommentsCollector<'input>,
     (_, canon_stream, _): (AirPos, (&'input str, LambdaAST<'input>, AirPos), AirPos),
-) -> Fail<'input>
+) -> FailArgument<'input>
 {
-    Fail::CanonStreamWithLambda(CanonStreamWithLambda::new(canon_stream.0, canon_stream.1, canon_stream.2))
+    FailArgument::CanonStreamWithLambda(CanonStreamWithLambda::new(canon_stream.0, canon_stream.1, canon_stream.2))
 }
 
 #[allow(unused_variables)]
-fn __action28<
+fn __action32<
     'err,
     'input,
     'v,
+    'c,
 >(
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
+    comments: &'c mut CommentsCollector<'input>,
     (_, left, _): (AirPos, AirPos, AirPos),
     (_, l, _): (AirPos, Token<'input>, AirPos),
     (_, right, _): (AirPos, AirPos, AirPos),
-) -> Fail<'input>
+) -> FailArgument<'input>
 {
     {
-        Fail::LastError
+        FailArgument::LastError
     }
 }
 
 #[allow(unused_variables)]
-fn __action29<
+fn __action33<
     'err,
     'input,
     'v,
+    'c,
 >(
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
+    comments: &'c mut CommentsCollector<'input>,
     (_, scalar, _): (AirPos, (&'input str, AirPos), AirPos),
 ) -> FoldScalarIterable<'input>
 {
@@ -4895,14 +5845,16 @@ fn __action29<
 }
 
 #[allow(unused_variables)]
-fn __action30<
+fn __action34<
     'err,
     'input,
     'v,
+    'c,
 >(
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
+    comments: &'c mut CommentsCollector<'input>,
     (_, scalar, _): (AirPos, (&'input str, LambdaAST<'input>, AirPos), AirPos),
 ) -> FoldScalarIterable<'input>
 {
@@ -4910,14 +5862,16 @@ fn __action30<
 }
 
 #[allow(unused_variables)]
-fn __action31<
+fn __action35<
     'err,
     'input,
     'v,
+    'c,
 >(
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
+    comments: &'c mut CommentsCollector<'input>,
     (_, canon_stream, _): (AirPos, (&'input str, AirPos), AirPos),
 ) -> FoldScalarIterable<'input>
 {
@@ -4925,14 +5879,16 @@ fn __action31<
 }
 
 #[allow(unused_variables)]
-fn __action32<
+fn __action36<
     'err,
     'input,
     'v,
+    'c,
 >(
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
+    comments: &'c mut CommentsCollector<'input>,
     (_, __0, _): (AirPos, Token<'input>, AirPos),
     (_, __1, _): (AirPos, Token<'input>, AirPos),
 ) -> FoldScalarIterable<'input>
@@ -4941,14 +5897,16 @@ fn __action32<
 }
 
 #[allow(unused_variables)]
-fn __action33<
+fn __action37<
     'err,
     'input,
     'v,
+    'c,
 >(
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
+    comments: &'c mut CommentsCollector<'input>,
     (_, __0, _): (AirPos, ResolvableToPeerIdVariable<'input>, AirPos),
 ) -> ResolvableToPeerIdVariable<'input>
 {
@@ -4956,14 +5914,16 @@ fn __action33<
 }
 
 #[allow(unused_variables)]
-fn __action34<
+fn __action38<
     'err,
     'input,
     'v,
+    'c,
 >(
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
+    comments: &'c mut CommentsCollector<'input>,
     (_, __0, _): (AirPos, ResolvableToStringVariable<'input>, AirPos),
 ) -> ResolvableToStringVariable<'input>
 {
@@ -4971,14 +5931,16 @@ fn __action34<
 }
 
 #[allow(unused_variables)]
-fn __action35<
+fn __action39<
     'err,
     'input,
     'v,
+    'c,
 >(
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
+    comments: &'c mut CommentsCollector<'input>,
     (_, __0, _): (AirPos, ResolvableToStringVariable<'input>, AirPos),
 ) -> ResolvableToStringVariable<'input>
 {
@@ -4986,14 +5948,16 @@ fn __action35<
 }
 
 #[allow(unused_variables)]
-fn __action36<
+fn __action40<
     'err,
     'input,
     'v,
+    'c,
 >(
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
+    comments: &'c mut CommentsCollector<'input>,
     (_, __0, _): (AirPos, Token<'input>, AirPos),
 ) -> ResolvableToPeerIdVariable<'input>
 {
@@ -5001,29 +5965,33 @@ fn __action36<
 }
 
 #[allow(unused_variables)]
-fn __action37<
+fn __action41<
     'err,
     'input,
     'v,
+    'c,
 >(
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
+    comments: &'c mut CommentsCollector<'input>,
     (_, literal, _): (AirPos, &'input str, AirPos),
 ) -> ResolvableToPeerIdVariable<'input>
 {
-    ResolvableToPeerIdVariable::Literal(literal)
+    ResolvableToPeerIdVariable::Literal(literal.into())
 }
 
 #[allow(unused_variables)]
-fn __action38<
+fn __action42<
     'err,
     'input,
     'v,
+    'c,
 >(
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
+    comments: &'c mut CommentsCollector<'input>,
     (_, scalar, _): (AirPos, (&'input str, AirPos), AirPos),
 ) -> ResolvableToPeerIdVariable<'input>
 {
@@ -5031,14 +5999,16 @@ fn __action38<
 }
 
 #[allow(unused_variables)]
-fn __action39<
+fn __action43<
     'err,
     'input,
     'v,
+    'c,
 >(
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
+    comments: &'c mut CommentsCollector<'input>,
     (_, scalar, _): (AirPos, (&'input str, LambdaAST<'input>, AirPos), AirPos),
 ) -> ResolvableToPeerIdVariable<'input>
 {
@@ -5046,14 +6016,16 @@ fn __action39<
 }
 
 #[allow(unused_variables)]
-fn __action40<
+fn __action44<
     'err,
     'input,
     'v,
+    'c,
 >(
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
+    comments: &'c mut CommentsCollector<'input>,
     (_, canon_stream, _): (AirPos, (&'input str, LambdaAST<'input>, AirPos), AirPos),
 ) -> ResolvableToPeerIdVariable<'input>
 {
@@ -5061,29 +6033,33 @@ fn __action40<
 }
 
 #[allow(unused_variables)]
-fn __action41<
+fn __action45<
     'err,
     'input,
     'v,
+    'c,
 >(
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
+    comments: &'c mut CommentsCollector<'input>,
     (_, literal, _): (AirPos, &'input str, AirPos),
 ) -> ResolvableToStringVariable<'input>
 {
-    ResolvableToStringVariable::Literal(literal)
+    ResolvableToStringVariable::Literal(literal.into())
 }
 
 #[allow(unused_variables)]
-fn __action42<
+fn __action46<
     'err,
     'input,
     'v,
+    'c,
 >(
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
+    comments: &'c mut CommentsCollector<'input>,
     (_, scalar, _): (AirPos, (&'input str, AirPos), AirPos),
 ) -> ResolvableToStringVariable<'input>
 {
@@ -5091,14 +6067,16 @@ fn __action42<
 }
 
 #[allow(unused_variables)]
-fn __action43<
+fn __action47<
     'err,
     'input,
     'v,
+    'c,
 >(
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
+    comments: &'c mut CommentsCollector<'input>,
     (_, scalar, _): (AirPos, (&'input str, LambdaAST<'input>, AirPos), AirPos),
 ) -> ResolvableToStringVariable<'input>
 {
@@ -5106,14 +6084,16 @@ fn __action43<
 }
 
 #[allow(unused_variables)]
-fn __action44<
+fn __action48<
     'err,
     'input,
     'v,
+    'c,
 >(
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
+    comments: &'c mut CommentsCollector<'input>,
     (_, canon_stream, _): (AirPos, (&'input str, LambdaAST<'input>, AirPos), AirPos),
 ) -> ResolvableToStringVariable<'input>
 {
@@ -5121,14 +6101,16 @@ fn __action44<
 }
 
 #[allow(unused_variables)]
-fn __action45<
+fn __action49<
     'err,
     'input,
     'v,
+    'c,
 >(
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
+    comments: &'c mut CommentsCollector<'input>,
     (_, scalar, _): (AirPos, (&'input str, AirPos), AirPos),
 ) -> NewArgument<'input>
 {
@@ -5136,14 +6118,16 @@ fn __action45<
 }
 
 #[allow(unused_variables)]
-fn __action46<
+fn __action50<
     'err,
     'input,
     'v,
+    'c,
 >(
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
+    comments: &'c mut CommentsCollector<'input>,
     (_, stream, _): (AirPos, (&'input str, AirPos), AirPos),
 ) -> NewArgument<'input>
 {
@@ -5151,14 +6135,16 @@ fn __action46<
 }
 
 #[allow(unused_variables)]
-fn __action47<
+fn __action51<
     'err,
     'input,
     'v,
+    'c,
 >(
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
+    comments: &'c mut CommentsCollector<'input>,
     (_, canon_stream, _): (AirPos, (&'input str, AirPos), AirPos),
 ) -> NewArgument<'input>
 {
@@ -5166,14 +6152,118 @@ fn __action47<
 }
 
 #[allow(unused_variables)]
-fn __action48<
+fn __action52<
+    'err,
+    'input,
+    'v,
+    'c,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+    validator: &'v mut VariableValidator<'input>,
+    comments: &'c mut CommentsCollector<'input>,
+    (_, stream_map, _): (AirPos, (&'input str, AirPos), AirPos),
+) -> NewArgument<'input>
+{
+    NewArgument::StreamMap(StreamMap::new(stream_map.0, stream_map.1))
+}
+
+#[allow(unused_variables)]
+fn __action53<
+    'err,
+    'input,
+    'v,
+    'c,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+    validator: &'v mut VariableValidator<'input>,
+    comments: &'c mut CommentsCollector<'input>,
+    (_, literal, _): (AirPos, &'input str, AirPos),
+) -> StreamMapKeyClause<'input>
+{
+    StreamMapKeyClause::Literal(literal.into())
+}
+
+#[allow(unused_variables)]
+fn __action54<
+    'err,
+    'input,
+    'v,
+    'c,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+    validator: &'v mut VariableValidator<'input>,
+    comments: &'c mut CommentsCollector<'input>,
+    (_, integer, _): (AirPos, i64, AirPos),
+) -> StreamMapKeyClause<'input>
+{
+    StreamMapKeyClause::Int(integer)
+}
+
+#[allow(unused_variables)]
+fn __action55<
+    'err,
+    'input,
+    'v,
+    'c,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+    validator: &'v mut VariableValidator<'input>,
+    comments: &'c mut CommentsCollector<'input>,
+    (_, scalar, _): (AirPos, (&'input str, AirPos), AirPos),
+) -> StreamMapKeyClause<'input>
+{
+    StreamMapKeyClause::Scalar(Scalar::new(scalar.0, scalar.1))
+}
+
+#[allow(unused_variables)]
+fn __action56<
+    'err,
+    'input,
+    'v,
+    'c,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+    validator: &'v mut VariableValidator<'input>,
+    comments: &'c mut CommentsCollector<'input>,
+    (_, scalar, _): (AirPos, (&'input str, LambdaAST<'input>, AirPos), AirPos),
+) -> StreamMapKeyClause<'input>
+{
+    StreamMapKeyClause::ScalarWithLambda(ScalarWithLambda::new(scalar.0, scalar.1, scalar.2))
+}
+
+#[allow(unused_variables)]
+fn __action57<
+    'err,
+    'input,
+    'v,
+    'c,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+    validator: &'v mut VariableValidator<'input>,
+    comments: &'c mut CommentsCollector<'input>,
+    (_, canon_stream, _): (AirPos, (&'input str, LambdaAST<'input>, AirPos), AirPos),
+) -> StreamMapKeyClause<'input>
+{
+    StreamMapKeyClause::CanonStreamWithLambda(CanonStreamWithLambda::new(canon_stream.0, canon_stream.1, canon_stream.2))
+}
+
+#[allow(unused_variables)]
+fn __action58<
     'err,
     'input,
     'v,
+    'c,
 >(
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
+    comments: &'c mut CommentsCollector<'input>,
     (_, integer, _): (AirPos, i64, AirPos),
 ) -> Number
 {
@@ -5181,14 +6271,16 @@ fn __action48<
 }
 
 #[allow(unused_variables)]
-fn __action49<
+fn __action59<
     'err,
     'input,
     'v,
+    'c,
 >(
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
+    comments: &'c mut CommentsCollector<'input>,
     (_, float, _): (AirPos, f64, AirPos),
 ) -> Number
 {
@@ -5196,14 +6288,16 @@ fn __action49<
 }
 
 #[allow(unused_variables)]
-fn __action50<
+fn __action60<
     'err,
     'input,
     'v,
+    'c,
 >(
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
+    comments: &'c mut CommentsCollector<'input>,
     (_, __0, _): (AirPos, ImmutableValue<'input>, AirPos),
 ) -> ImmutableValue<'input>
 {
@@ -5211,14 +6305,16 @@ fn __action50<
 }
 
 #[allow(unused_variables)]
-fn __action51<
+fn __action61<
     'err,
     'input,
     'v,
+    'c,
 >(
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
+    comments: &'c mut CommentsCollector<'input>,
     (_, __0, _): (AirPos, Token<'input>, AirPos),
 ) -> ImmutableValue<'input>
 {
@@ -5226,14 +6322,16 @@ fn __action51<
 }
 
 #[allow(unused_variables)]
-fn __action52<
+fn __action62<
     'err,
     'input,
     'v,
+    'c,
 >(
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
+    comments: &'c mut CommentsCollector<'input>,
     (_, __0, _): (AirPos, Token<'input>, AirPos),
 ) -> ImmutableValue<'input>
 {
@@ -5241,14 +6339,16 @@ fn __action52<
 }
 
 #[allow(unused_variables)]
-fn __action53<
+fn __action63<
     'err,
     'input,
     'v,
+    'c,
 >(
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
+    comments: &'c mut CommentsCollector<'input>,
     (_, le, _): (AirPos, LambdaAST<'input>, AirPos),
 ) -> ImmutableValue<'input>
 {
@@ -5256,29 +6356,33 @@ fn __action53<
 }
 
 #[allow(unused_variables)]
-fn __action54<
+fn __action64<
     'err,
     'input,
     'v,
+    'c,
 >(
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
+    comments: &'c mut CommentsCollector<'input>,
     (_, l, _): (AirPos, &'input str, AirPos),
 ) -> ImmutableValue<'input>
 {
-    ImmutableValue::Literal(l)
+    ImmutableValue::Literal(l.into())
 }
 
 #[allow(unused_variables)]
-fn __action55<
+fn __action65<
     'err,
     'input,
     'v,
+    'c,
 >(
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
+    comments: &'c mut CommentsCollector<'input>,
     (_, __0, _): (AirPos, Token<'input>, AirPos),
 ) -> ImmutableValue<'input>
 {
@@ -5286,14 +6390,16 @@ fn __action55<
 }
 
 #[allow(unused_variables)]
-fn __action56<
+fn __action66<
     'err,
     'input,
     'v,
+    'c,
 >(
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
+    comments: &'c mut CommentsCollector<'input>,
     (_, __0, _): (AirPos, Token<'input>, AirPos),
 ) -> ImmutableValue<'input>
 {
@@ -5301,14 +6407,16 @@ fn __action56<
 }
 
 #[allow(unused_variables)]
-fn __action57<
+fn __action67<
     'err,
     'input,
     'v,
+    'c,
 >(
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
+    comments: &'c mut CommentsCollector<'input>,
     (_, n, _): (AirPos, Number, AirPos),
 ) -> ImmutableValue<'input>
 {
@@ -5316,14 +6424,16 @@ fn __action57<
 }
 
 #[allow(unused_variables)]
-fn __action58<
+fn __action68<
     'err,
     'input,
     'v,
+    'c,
 >(
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
+    comments: &'c mut CommentsCollector<'input>,
     (_, b, _): (AirPos, bool, AirPos),
 ) -> ImmutableValue<'input>
 {
@@ -5331,14 +6441,16 @@ fn __action58<
 }
 
 #[allow(unused_variables)]
-fn __action59<
+fn __action69<
     'err,
     'input,
     'v,
+    'c,
 >(
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
+    comments: &'c mut CommentsCollector<'input>,
     (_, __0, _): (AirPos, Token<'input>, AirPos),
     (_, __1, _): (AirPos, Token<'input>, AirPos),
 ) -> ImmutableValue<'input>
@@ -5347,14 +6459,16 @@ fn __action59<
 }
 
 #[allow(unused_variables)]
-fn __action60<
+fn __action70<
     'err,
     'input,
     'v,
+    'c,
 >(
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
+    comments: &'c mut CommentsCollector<'input>,
     (_, scalar, _): (AirPos, (&'input str, AirPos), AirPos),
 ) -> ImmutableValue<'input>
 {
@@ -5362,14 +6476,16 @@ fn __action60<
 }
 
 #[allow(unused_variables)]
-fn __action61<
+fn __action71<
     'err,
     'input,
     'v,
+    'c,
 >(
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
+    comments: &'c mut CommentsCollector<'input>,
     (_, scalar, _): (AirPos, (&'input str, LambdaAST<'input>, AirPos), AirPos),
 ) -> ImmutableValue<'input>
 {
@@ -5377,14 +6493,16 @@ fn __action61<
 }
 
 #[allow(unused_variables)]
-fn __action62<
+fn __action72<
     'err,
     'input,
     'v,
+    'c,
 >(
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
+    comments: &'c mut CommentsCollector<'input>,
     (_, canon_stream, _): (AirPos, (&'input str, AirPos), AirPos),
 ) -> ImmutableValue<'input>
 {
@@ -5392,14 +6510,16 @@ fn __action62<
 }
 
 #[allow(unused_variables)]
-fn __action63<
+fn __action73<
     'err,
     'input,
     'v,
+    'c,
 >(
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
+    comments: &'c mut CommentsCollector<'input>,
     (_, canon_stream, _): (AirPos, (&'input str, LambdaAST<'input>, AirPos), AirPos),
 ) -> ImmutableValue<'input>
 {
@@ -5407,14 +6527,16 @@ fn __action63<
 }
 
 #[allow(unused_variables)]
-fn __action64<
+fn __action74<
     'err,
     'input,
     'v,
+    'c,
 >(
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
+    comments: &'c mut CommentsCollector<'input>,
     (_, __0, _): (AirPos, Token<'input>, AirPos),
 ) -> ApArgument<'input>
 {
@@ -5422,14 +6544,16 @@ fn __action64<
 }
 
 #[allow(unused_variables)]
-fn __action65<
+fn __action75<
     'err,
     'input,
     'v,
+    'c,
 >(
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
+    comments: &'c mut CommentsCollector<'input>,
     (_, __0, _): (AirPos, Token<'input>, AirPos),
 ) -> ApArgument<'input>
 {
@@ -5437,14 +6561,16 @@ fn __action65<
 }
 
 #[allow(unused_variables)]
-fn __action66<
+fn __action76<
     'err,
     'input,
     'v,
+    'c,
 >(
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
+    comments: &'c mut CommentsCollector<'input>,
     (_, le, _): (AirPos, LambdaAST<'input>, AirPos),
 ) -> ApArgument<'input>
 {
@@ -5452,14 +6578,16 @@ fn __action66<
 }
 
 #[allow(unused_variables)]
-fn __action67<
+fn __action77<
     'err,
     'input,
     'v,
+    'c,
 >(
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
+    comments: &'c mut CommentsCollector<'input>,
     (_, __0, _): (AirPos, Token<'input>, AirPos),
 ) -> ApArgument<'input>
 {
@@ -5467,14 +6595,16 @@ fn __action67<
 }
 
 #[allow(unused_variables)]
-fn __action68<
+fn __action78<
     'err,
     'input,
     'v,
+    'c,
 >(
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
+    comments: &'c mut CommentsCollector<'input>,
     (_, __0, _): (AirPos, Token<'input>, AirPos),
 ) -> ApArgument<'input>
 {
@@ -5482,29 +6612,33 @@ fn __action68<
 }
 
 #[allow(unused_variables)]
-fn __action69<
+fn __action79<
     'err,
     'input,
     'v,
+    'c,
 >(
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
+    comments: &'c mut CommentsCollector<'input>,
     (_, l, _): (AirPos, &'input str, AirPos),
 ) -> ApArgument<'input>
 {
-    ApArgument::Literal(l)
+    ApArgument::Literal(l.into())
 }
 
 #[allow(unused_variables)]
-fn __action70<
+fn __action80<
     'err,
     'input,
     'v,
+    'c,
 >(
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
+    comments: &'c mut CommentsCollector<'input>,
     (_, n, _): (AirPos, Number, AirPos),
 ) -> ApArgument<'input>
 {
@@ -5512,14 +6646,16 @@ fn __action70<
 }
 
 #[allow(unused_variables)]
-fn __action71<
+fn __action81<
     'err,
     'input,
     'v,
+    'c,
 >(
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
+    comments: &'c mut CommentsCollector<'input>,
     (_, b, _): (AirPos, bool, AirPos),
 ) -> ApArgument<'input>
 {
@@ -5527,14 +6663,16 @@ fn __action71<
 }
 
 #[allow(unused_variables)]
-fn __action72<
+fn __action82<
     'err,
     'input,
     'v,
+    'c,
 >(
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
+    comments: &'c mut CommentsCollector<'input>,
     (_, __0, _): (AirPos, Token<'input>, AirPos),
     (_, __1, _): (AirPos, Token<'input>, AirPos),
 ) -> ApArgument<'input>
@@ -5543,14 +6681,16 @@ fn __action72<
 }
 
 #[allow(unused_variables)]
-fn __action73<
+fn __action83<
     'err,
     'input,
     'v,
+    'c,
 >(
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
+    comments: &'c mut CommentsCollector<'input>,
     (_, scalar, _): (AirPos, (&'input str, AirPos), AirPos),
 ) -> ApArgument<'input>
 {
@@ -5558,14 +6698,16 @@ fn __action73<
 }
 
 #[allow(unused_variables)]
-fn __action74<
+fn __action84<
     'err,
     'input,
     'v,
+    'c,
 >(
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
+    comments: &'c mut CommentsCollector<'input>,
     (_, scalar, _): (AirPos, (&'input str, LambdaAST<'input>, AirPos), AirPos),
 ) -> ApArgument<'input>
 {
@@ -5573,14 +6715,16 @@ fn __action74<
 }
 
 #[allow(unused_variables)]
-fn __action75<
+fn __action85<
     'err,
     'input,
     'v,
+    'c,
 >(
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
+    comments: &'c mut CommentsCollector<'input>,
     (_, canon_stream, _): (AirPos, (&'input str, AirPos), AirPos),
 ) -> ApArgument<'input>
 {
@@ -5588,14 +6732,16 @@ fn __action75<
 }
 
 #[allow(unused_variables)]
-fn __action76<
+fn __action86<
     'err,
     'input,
     'v,
+    'c,
 >(
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
+    comments: &'c mut CommentsCollector<'input>,
     (_, canon_stream, _): (AirPos, (&'input str, LambdaAST<'input>, AirPos), AirPos),
 ) -> ApArgument<'input>
 {
@@ -5603,14 +6749,16 @@ fn __action76<
 }
 
 #[allow(unused_variables)]
-fn __action77<
+fn __action87<
     'err,
     'input,
     'v,
+    'c,
 >(
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
+    comments: &'c mut CommentsCollector<'input>,
     (_, stream, _): (AirPos, (&'input str, AirPos), AirPos),
 ) -> Stream<'input>
 {
@@ -5618,14 +6766,33 @@ fn __action77<
 }
 
 #[allow(unused_variables)]
-fn __action78<
+fn __action88<
+    'err,
+    'input,
+    'v,
+    'c,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+    validator: &'v mut VariableValidator<'input>,
+    comments: &'c mut CommentsCollector<'input>,
+    (_, stream_map, _): (AirPos, (&'input str, AirPos), AirPos),
+) -> StreamMap<'input>
+{
+    StreamMap::new(stream_map.0, stream_map.1)
+}
+
+#[allow(unused_variables)]
+fn __action89<
     'err,
     'input,
     'v,
+    'c,
 >(
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
+    comments: &'c mut CommentsCollector<'input>,
     (_, canon_stream, _): (AirPos, (&'input str, AirPos), AirPos),
 ) -> CanonStream<'input>
 {
@@ -5633,14 +6800,16 @@ fn __action78<
 }
 
 #[allow(unused_variables)]
-fn __action79<
+fn __action90<
     'err,
     'input,
     'v,
+    'c,
 >(
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
+    comments: &'c mut CommentsCollector<'input>,
     __lookbehind: &AirPos,
     __lookahead: &AirPos,
 ) -> alloc::vec::Vec<ImmutableValue<'input>>
@@ -5649,14 +6818,16 @@ fn __action79<
 }
 
 #[allow(unused_variables)]
-fn __action80<
+fn __action91<
     'err,
     'input,
     'v,
+    'c,
 >(
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
+    comments: &'c mut CommentsCollector<'input>,
     (_, v, _): (AirPos, alloc::vec::Vec<ImmutableValue<'input>>, AirPos),
 ) -> alloc::vec::Vec<ImmutableValue<'input>>
 {
@@ -5664,14 +6835,16 @@ fn __action80<
 }
 
 #[allow(unused_variables)]
-fn __action81<
+fn __action92<
     'err,
     'input,
     'v,
+    'c,
 >(
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
+    comments: &'c mut CommentsCollector<'input>,
     (_, __0, _): (AirPos, ImmutableValue<'input>, AirPos),
 ) -> ImmutableValue<'input>
 {
@@ -5679,14 +6852,16 @@ fn __action81<
 }
 
 #[allow(unused_variables)]
-fn __action82<
+fn __action93<
     'err,
     'input,
     'v,
+    'c,
 >(
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
+    comments: &'c mut CommentsCollector<'input>,
     (_, __0, _): (AirPos, Box<Instruction<'input>>, AirPos),
 ) -> core::option::Option<Box<Instruction<'input>>>
 {
@@ -5694,14 +6869,16 @@ fn __action82<
 }
 
 #[allow(unused_variables)]
-fn __action83<
+fn __action94<
     'err,
     'input,
     'v,
+    'c,
 >(
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
+    comments: &'c mut CommentsCollector<'input>,
     __lookbehind: &AirPos,
     __lookahead: &AirPos,
 ) -> core::option::Option<Box<Instruction<'input>>>
@@ -5710,334 +6887,1368 @@ fn __action83<
 }
 
 #[allow(unused_variables)]
-fn __action84<
+fn __action95<
     'err,
     'input,
     'v,
+    'c,
 >(
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
-    __lookbehind: &AirPos,
-    __lookahead: &AirPos,
-) -> AirPos
+    comments: &'c mut CommentsCollector<'input>,
+    (_, __0, _): (AirPos, CallOutputValue<'input>, AirPos),
+) -> core::option::Option<CallOutputValue<'input>>
 {
-    __lookbehind.clone()
+    Some(__0)
 }
 
 #[allow(unused_variables)]
-fn __action85<
+fn __action96<
     'err,
     'input,
     'v,
+    'c,
 >(
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
-    (_, __0, _): (AirPos, CallOutputValue<'input>, AirPos),
+    comments: &'c mut CommentsCollector<'input>,
+    __lookbehind: &AirPos,
+    __lookahead: &AirPos,
 ) -> core::option::Option<CallOutputValue<'input>>
 {
-    Some(__0)
+    None
 }
 
 #[allow(unused_variables)]
-fn __action86<
+fn __action97<
     'err,
     'input,
     'v,
+    'c,
 >(
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
+    comments: &'c mut CommentsCollector<'input>,
     __lookbehind: &AirPos,
     __lookahead: &AirPos,
-) -> core::option::Option<CallOutputValue<'input>>
+) -> AirPos
 {
-    None
+    __lookbehind.clone()
 }
 
 #[allow(unused_variables)]
-fn __action87<
+fn __action98<
     'err,
     'input,
     'v,
+    'c,
 >(
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
+    comments: &'c mut CommentsCollector<'input>,
     __lookbehind: &AirPos,
     __lookahead: &AirPos,
 ) -> AirPos
 {
-    __lookahead.clone()
+    __lookahead.clone()
+}
+
+#[allow(unused_variables)]
+fn __action99<
+    'err,
+    'input,
+    'v,
+    'c,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+    validator: &'v mut VariableValidator<'input>,
+    comments: &'c mut CommentsCollector<'input>,
+    (_, __0, _): (AirPos, ImmutableValue<'input>, AirPos),
+) -> alloc::vec::Vec<ImmutableValue<'input>>
+{
+    alloc::vec![__0]
+}
+
+#[allow(unused_variables)]
+fn __action100<
+    'err,
+    'input,
+    'v,
+    'c,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+    validator: &'v mut VariableValidator<'input>,
+    comments: &'c mut CommentsCollector<'input>,
+    (_, v, _): (AirPos, alloc::vec::Vec<ImmutableValue<'input>>, AirPos),
+    (_, e, _): (AirPos, ImmutableValue<'input>, AirPos),
+) -> alloc::vec::Vec<ImmutableValue<'input>>
+{
+    { let mut v = v; v.push(e); v }
+}
+
+#[allow(unused_variables)]
+fn __action101<
+    'err,
+    'input,
+    'v,
+    'c,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+    validator: &'v mut VariableValidator<'input>,
+    comments: &'c mut CommentsCollector<'input>,
+    __0: (AirPos, ImmutableValue<'input>, AirPos),
+) -> alloc::vec::Vec<ImmutableValue<'input>>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action92(
+        input,
+        errors,
+        validator,
+        comments,
+        __0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action99(
+        input,
+        errors,
+        validator,
+        comments,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action102<
+    'err,
+    'input,
+    'v,
+    'c,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+    validator: &'v mut VariableValidator<'input>,
+    comments: &'c mut CommentsCollector<'input>,
+    __0: (AirPos, alloc::vec::Vec<ImmutableValue<'input>>, AirPos),
+    __1: (AirPos, ImmutableValue<'input>, AirPos),
+) -> alloc::vec::Vec<ImmutableValue<'input>>
+{
+    let __start0 = __1.0.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action92(
+        input,
+        errors,
+        validator,
+        comments,
+        __1,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action100(
+        input,
+        errors,
+        validator,
+        comments,
+        __0,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action103<
+    'err,
+    'input,
+    'v,
+    'c,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+    validator: &'v mut VariableValidator<'input>,
+    comments: &'c mut CommentsCollector<'input>,
+    __0: (AirPos, Token<'input>, AirPos),
+    __1: (AirPos, Token<'input>, AirPos),
+) -> Vec<ImmutableValue<'input>>
+{
+    let __start0 = __0.2.clone();
+    let __end0 = __1.0.clone();
+    let __temp0 = __action90(
+        input,
+        errors,
+        validator,
+        comments,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action22(
+        input,
+        errors,
+        validator,
+        comments,
+        __0,
+        __temp0,
+        __1,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action104<
+    'err,
+    'input,
+    'v,
+    'c,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+    validator: &'v mut VariableValidator<'input>,
+    comments: &'c mut CommentsCollector<'input>,
+    __0: (AirPos, Token<'input>, AirPos),
+    __1: (AirPos, alloc::vec::Vec<ImmutableValue<'input>>, AirPos),
+    __2: (AirPos, Token<'input>, AirPos),
+) -> Vec<ImmutableValue<'input>>
+{
+    let __start0 = __1.0.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action91(
+        input,
+        errors,
+        validator,
+        comments,
+        __1,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action22(
+        input,
+        errors,
+        validator,
+        comments,
+        __0,
+        __temp0,
+        __2,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action105<
+    'err,
+    'input,
+    'v,
+    'c,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+    validator: &'v mut VariableValidator<'input>,
+    comments: &'c mut CommentsCollector<'input>,
+    __0: (AirPos, Token<'input>, AirPos),
+    __1: (AirPos, Token<'input>, AirPos),
+    __2: (AirPos, Triplet<'input>, AirPos),
+    __3: (AirPos, Vec<ImmutableValue<'input>>, AirPos),
+    __4: (AirPos, core::option::Option<CallOutputValue<'input>>, AirPos),
+    __5: (AirPos, Token<'input>, AirPos),
+    __6: (AirPos, AirPos, AirPos),
+) -> Box<Instruction<'input>>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action98(
+        input,
+        errors,
+        validator,
+        comments,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action3(
+        input,
+        errors,
+        validator,
+        comments,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __6,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action106<
+    'err,
+    'input,
+    'v,
+    'c,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+    validator: &'v mut VariableValidator<'input>,
+    comments: &'c mut CommentsCollector<'input>,
+    __0: (AirPos, Token<'input>, AirPos),
+    __1: (AirPos, Token<'input>, AirPos),
+    __2: (AirPos, ResolvableToPeerIdVariable<'input>, AirPos),
+    __3: (AirPos, Stream<'input>, AirPos),
+    __4: (AirPos, CanonStream<'input>, AirPos),
+    __5: (AirPos, Token<'input>, AirPos),
+    __6: (AirPos, AirPos, AirPos),
+) -> Box<Instruction<'input>>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action98(
+        input,
+        errors,
+        validator,
+        comments,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action4(
+        input,
+        errors,
+        validator,
+        comments,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __6,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action107<
+    'err,
+    'input,
+    'v,
+    'c,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+    validator: &'v mut VariableValidator<'input>,
+    comments: &'c mut CommentsCollector<'input>,
+    __0: (AirPos, Token<'input>, AirPos),
+    __1: (AirPos, Token<'input>, AirPos),
+    __2: (AirPos, ResolvableToPeerIdVariable<'input>, AirPos),
+    __3: (AirPos, StreamMap<'input>, AirPos),
+    __4: (AirPos, (&'input str, AirPos), AirPos),
+    __5: (AirPos, Token<'input>, AirPos),
+    __6: (AirPos, AirPos, AirPos),
+) -> Box<Instruction<'input>>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action98(
+        input,
+        errors,
+        validator,
+        comments,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action5(
+        input,
+        errors,
+        validator,
+        comments,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __6,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action108<
+    'err,
+    'input,
+    'v,
+    'c,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+    validator: &'v mut VariableValidator<'input>,
+    comments: &'c mut CommentsCollector<'input>,
+    __0: (AirPos, Token<'input>, AirPos),
+    __1: (AirPos, Token<'input>, AirPos),
+    __2: (AirPos, ApArgument<'input>, AirPos),
+    __3: (AirPos, ApResult<'input>, AirPos),
+    __4: (AirPos, Token<'input>, AirPos),
+    __5: (AirPos, AirPos, AirPos),
+) -> Box<Instruction<'input>>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action98(
+        input,
+        errors,
+        validator,
+        comments,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action6(
+        input,
+        errors,
+        validator,
+        comments,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action109<
+    'err,
+    'input,
+    'v,
+    'c,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+    validator: &'v mut VariableValidator<'input>,
+    comments: &'c mut CommentsCollector<'input>,
+    __0: (AirPos, Token<'input>, AirPos),
+    __1: (AirPos, Token<'input>, AirPos),
+    __2: (AirPos, Token<'input>, AirPos),
+    __3: (AirPos, StreamMapKeyClause<'input>, AirPos),
+    __4: (AirPos, ApArgument<'input>, AirPos),
+    __5: (AirPos, Token<'input>, AirPos),
+    __6: (AirPos, StreamMap<'input>, AirPos),
+    __7: (AirPos, Token<'input>, AirPos),
+    __8: (AirPos, AirPos, AirPos),
+) -> Box<Instruction<'input>>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action98(
+        input,
+        errors,
+        validator,
+        comments,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action7(
+        input,
+        errors,
+        validator,
+        comments,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __6,
+        __7,
+        __8,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action110<
+    'err,
+    'input,
+    'v,
+    'c,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+    validator: &'v mut VariableValidator<'input>,
+    comments: &'c mut CommentsCollector<'input>,
+    __0: (AirPos, Token<'input>, AirPos),
+    __1: (AirPos, Token<'input>, AirPos),
+    __2: (AirPos, Box<Instruction<'input>>, AirPos),
+    __3: (AirPos, Box<Instruction<'input>>, AirPos),
+    __4: (AirPos, Token<'input>, AirPos),
+    __5: (AirPos, AirPos, AirPos),
+) -> Box<Instruction<'input>>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action98(
+        input,
+        errors,
+        validator,
+        comments,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action8(
+        input,
+        errors,
+        validator,
+        comments,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action111<
+    'err,
+    'input,
+    'v,
+    'c,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+    validator: &'v mut VariableValidator<'input>,
+    comments: &'c mut CommentsCollector<'input>,
+    __0: (AirPos, Token<'input>, AirPos),
+    __1: (AirPos, Token<'input>, AirPos),
+    __2: (AirPos, Box<Instruction<'input>>, AirPos),
+    __3: (AirPos, Box<Instruction<'input>>, AirPos),
+    __4: (AirPos, Token<'input>, AirPos),
+    __5: (AirPos, AirPos, AirPos),
+) -> Box<Instruction<'input>>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action98(
+        input,
+        errors,
+        validator,
+        comments,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action9(
+        input,
+        errors,
+        validator,
+        comments,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action112<
+    'err,
+    'input,
+    'v,
+    'c,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+    validator: &'v mut VariableValidator<'input>,
+    comments: &'c mut CommentsCollector<'input>,
+    __0: (AirPos, Token<'input>, AirPos),
+    __1: (AirPos, Token<'input>, AirPos),
+    __2: (AirPos, Token<'input>, AirPos),
+    __3: (AirPos, AirPos, AirPos),
+) -> Box<Instruction<'input>>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action98(
+        input,
+        errors,
+        validator,
+        comments,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action10(
+        input,
+        errors,
+        validator,
+        comments,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action113<
+    'err,
+    'input,
+    'v,
+    'c,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+    validator: &'v mut VariableValidator<'input>,
+    comments: &'c mut CommentsCollector<'input>,
+    __0: (AirPos, Token<'input>, AirPos),
+    __1: (AirPos, Token<'input>, AirPos),
+    __2: (AirPos, Token<'input>, AirPos),
+    __3: (AirPos, AirPos, AirPos),
+) -> Box<Instruction<'input>>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action98(
+        input,
+        errors,
+        validator,
+        comments,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action11(
+        input,
+        errors,
+        validator,
+        comments,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action114<
+    'err,
+    'input,
+    'v,
+    'c,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+    validator: &'v mut VariableValidator<'input>,
+    comments: &'c mut CommentsCollector<'input>,
+    __0: (AirPos, Token<'input>, AirPos),
+    __1: (AirPos, Token<'input>, AirPos),
+    __2: (AirPos, NewArgument<'input>, AirPos),
+    __3: (AirPos, Box<Instruction<'input>>, AirPos),
+    __4: (AirPos, Token<'input>, AirPos),
+    __5: (AirPos, AirPos, AirPos),
+) -> Box<Instruction<'input>>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action98(
+        input,
+        errors,
+        validator,
+        comments,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action12(
+        input,
+        errors,
+        validator,
+        comments,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action115<
+    'err,
+    'input,
+    'v,
+    'c,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+    validator: &'v mut VariableValidator<'input>,
+    comments: &'c mut CommentsCollector<'input>,
+    __0: (AirPos, Token<'input>, AirPos),
+    __1: (AirPos, Token<'input>, AirPos),
+    __2: (AirPos, FailArgument<'input>, AirPos),
+    __3: (AirPos, Token<'input>, AirPos),
+    __4: (AirPos, AirPos, AirPos),
+) -> Box<Instruction<'input>>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action98(
+        input,
+        errors,
+        validator,
+        comments,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action13(
+        input,
+        errors,
+        validator,
+        comments,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action116<
+    'err,
+    'input,
+    'v,
+    'c,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+    validator: &'v mut VariableValidator<'input>,
+    comments: &'c mut CommentsCollector<'input>,
+    __0: (AirPos, Token<'input>, AirPos),
+    __1: (AirPos, Token<'input>, AirPos),
+    __2: (AirPos, FoldScalarIterable<'input>, AirPos),
+    __3: (AirPos, (&'input str, AirPos), AirPos),
+    __4: (AirPos, Box<Instruction<'input>>, AirPos),
+    __5: (AirPos, core::option::Option<Box<Instruction<'input>>>, AirPos),
+    __6: (AirPos, Token<'input>, AirPos),
+    __7: (AirPos, AirPos, AirPos),
+) -> Box<Instruction<'input>>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action98(
+        input,
+        errors,
+        validator,
+        comments,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action14(
+        input,
+        errors,
+        validator,
+        comments,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __6,
+        __7,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action117<
+    'err,
+    'input,
+    'v,
+    'c,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+    validator: &'v mut VariableValidator<'input>,
+    comments: &'c mut CommentsCollector<'input>,
+    __0: (AirPos, Token<'input>, AirPos),
+    __1: (AirPos, Token<'input>, AirPos),
+    __2: (AirPos, (&'input str, AirPos), AirPos),
+    __3: (AirPos, (&'input str, AirPos), AirPos),
+    __4: (AirPos, Box<Instruction<'input>>, AirPos),
+    __5: (AirPos, core::option::Option<Box<Instruction<'input>>>, AirPos),
+    __6: (AirPos, Token<'input>, AirPos),
+    __7: (AirPos, AirPos, AirPos),
+) -> Box<Instruction<'input>>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action98(
+        input,
+        errors,
+        validator,
+        comments,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action15(
+        input,
+        errors,
+        validator,
+        comments,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __6,
+        __7,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action118<
+    'err,
+    'input,
+    'v,
+    'c,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+    validator: &'v mut VariableValidator<'input>,
+    comments: &'c mut CommentsCollector<'input>,
+    __0: (AirPos, Token<'input>, AirPos),
+    __1: (AirPos, Token<'input>, AirPos),
+    __2: (AirPos, StreamMap<'input>, AirPos),
+    __3: (AirPos, (&'input str, AirPos), AirPos),
+    __4: (AirPos, Box<Instruction<'input>>, AirPos),
+    __5: (AirPos, core::option::Option<Box<Instruction<'input>>>, AirPos),
+    __6: (AirPos, Token<'input>, AirPos),
+    __7: (AirPos, AirPos, AirPos),
+) -> Box<Instruction<'input>>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action98(
+        input,
+        errors,
+        validator,
+        comments,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action16(
+        input,
+        errors,
+        validator,
+        comments,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __6,
+        __7,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action119<
+    'err,
+    'input,
+    'v,
+    'c,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+    validator: &'v mut VariableValidator<'input>,
+    comments: &'c mut CommentsCollector<'input>,
+    __0: (AirPos, Token<'input>, AirPos),
+    __1: (AirPos, Token<'input>, AirPos),
+    __2: (AirPos, (&'input str, AirPos), AirPos),
+    __3: (AirPos, Token<'input>, AirPos),
+    __4: (AirPos, AirPos, AirPos),
+) -> Box<Instruction<'input>>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action98(
+        input,
+        errors,
+        validator,
+        comments,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action17(
+        input,
+        errors,
+        validator,
+        comments,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action120<
+    'err,
+    'input,
+    'v,
+    'c,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+    validator: &'v mut VariableValidator<'input>,
+    comments: &'c mut CommentsCollector<'input>,
+    __0: (AirPos, Token<'input>, AirPos),
+    __1: (AirPos, Token<'input>, AirPos),
+    __2: (AirPos, Box<Instruction<'input>>, AirPos),
+    __3: (AirPos, Box<Instruction<'input>>, AirPos),
+    __4: (AirPos, Token<'input>, AirPos),
+    __5: (AirPos, AirPos, AirPos),
+) -> Box<Instruction<'input>>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action98(
+        input,
+        errors,
+        validator,
+        comments,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action18(
+        input,
+        errors,
+        validator,
+        comments,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action88<
+fn __action121<
     'err,
     'input,
     'v,
+    'c,
 >(
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
-    (_, __0, _): (AirPos, ImmutableValue<'input>, AirPos),
-) -> alloc::vec::Vec<ImmutableValue<'input>>
+    comments: &'c mut CommentsCollector<'input>,
+    __0: (AirPos, Token<'input>, AirPos),
+    __1: (AirPos, Token<'input>, AirPos),
+    __2: (AirPos, ImmutableValue<'input>, AirPos),
+    __3: (AirPos, ImmutableValue<'input>, AirPos),
+    __4: (AirPos, Box<Instruction<'input>>, AirPos),
+    __5: (AirPos, Token<'input>, AirPos),
+    __6: (AirPos, AirPos, AirPos),
+) -> Box<Instruction<'input>>
 {
-    alloc::vec![__0]
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action98(
+        input,
+        errors,
+        validator,
+        comments,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action19(
+        input,
+        errors,
+        validator,
+        comments,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __6,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action89<
+fn __action122<
     'err,
     'input,
     'v,
+    'c,
 >(
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
-    (_, v, _): (AirPos, alloc::vec::Vec<ImmutableValue<'input>>, AirPos),
-    (_, e, _): (AirPos, ImmutableValue<'input>, AirPos),
-) -> alloc::vec::Vec<ImmutableValue<'input>>
+    comments: &'c mut CommentsCollector<'input>,
+    __0: (AirPos, Token<'input>, AirPos),
+    __1: (AirPos, Token<'input>, AirPos),
+    __2: (AirPos, ImmutableValue<'input>, AirPos),
+    __3: (AirPos, ImmutableValue<'input>, AirPos),
+    __4: (AirPos, Box<Instruction<'input>>, AirPos),
+    __5: (AirPos, Token<'input>, AirPos),
+    __6: (AirPos, AirPos, AirPos),
+) -> Box<Instruction<'input>>
 {
-    { let mut v = v; v.push(e); v }
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action98(
+        input,
+        errors,
+        validator,
+        comments,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action20(
+        input,
+        errors,
+        validator,
+        comments,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __6,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action90<
+fn __action123<
     'err,
     'input,
     'v,
+    'c,
 >(
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
-    __0: (AirPos, ImmutableValue<'input>, AirPos),
-) -> alloc::vec::Vec<ImmutableValue<'input>>
+    comments: &'c mut CommentsCollector<'input>,
+    __0: (AirPos, Token<'input>, AirPos),
+    __1: (AirPos, AirPos, AirPos),
+) -> FailArgument<'input>
 {
     let __start0 = __0.0.clone();
-    let __end0 = __0.2.clone();
-    let __temp0 = __action81(
+    let __end0 = __0.0.clone();
+    let __temp0 = __action98(
         input,
         errors,
         validator,
-        __0,
+        comments,
+        &__start0,
+        &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action88(
+    __action32(
         input,
         errors,
         validator,
+        comments,
         __temp0,
+        __0,
+        __1,
     )
 }
 
 #[allow(unused_variables)]
-fn __action91<
+fn __action124<
     'err,
     'input,
     'v,
+    'c,
 >(
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
-    __0: (AirPos, alloc::vec::Vec<ImmutableValue<'input>>, AirPos),
-    __1: (AirPos, ImmutableValue<'input>, AirPos),
-) -> alloc::vec::Vec<ImmutableValue<'input>>
+    comments: &'c mut CommentsCollector<'input>,
+    __0: (AirPos, Box<Instruction<'input>>, AirPos),
+    __1: (AirPos, AirPos, AirPos),
+) -> Box<Instruction<'input>>
 {
-    let __start0 = __1.0.clone();
-    let __end0 = __1.2.clone();
-    let __temp0 = __action81(
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action98(
         input,
         errors,
         validator,
-        __1,
+        comments,
+        &__start0,
+        &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action89(
+    __action2(
         input,
         errors,
         validator,
-        __0,
+        comments,
         __temp0,
+        __0,
+        __1,
     )
 }
 
 #[allow(unused_variables)]
-fn __action92<
+fn __action125<
     'err,
     'input,
     'v,
+    'c,
 >(
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
+    comments: &'c mut CommentsCollector<'input>,
     __0: (AirPos, Token<'input>, AirPos),
     __1: (AirPos, Token<'input>, AirPos),
-) -> Vec<ImmutableValue<'input>>
+    __2: (AirPos, Triplet<'input>, AirPos),
+    __3: (AirPos, Vec<ImmutableValue<'input>>, AirPos),
+    __4: (AirPos, core::option::Option<CallOutputValue<'input>>, AirPos),
+    __5: (AirPos, Token<'input>, AirPos),
+) -> Box<Instruction<'input>>
 {
-    let __start0 = __0.2.clone();
-    let __end0 = __1.0.clone();
-    let __temp0 = __action79(
+    let __start0 = __5.2.clone();
+    let __end0 = __5.2.clone();
+    let __temp0 = __action97(
         input,
         errors,
         validator,
+        comments,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action18(
+    __action105(
         input,
         errors,
         validator,
+        comments,
         __0,
-        __temp0,
         __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __temp0,
     )
 }
 
 #[allow(unused_variables)]
-fn __action93<
+fn __action126<
     'err,
     'input,
     'v,
+    'c,
 >(
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
+    comments: &'c mut CommentsCollector<'input>,
     __0: (AirPos, Token<'input>, AirPos),
-    __1: (AirPos, alloc::vec::Vec<ImmutableValue<'input>>, AirPos),
-    __2: (AirPos, Token<'input>, AirPos),
-) -> Vec<ImmutableValue<'input>>
+    __1: (AirPos, Token<'input>, AirPos),
+    __2: (AirPos, ResolvableToPeerIdVariable<'input>, AirPos),
+    __3: (AirPos, Stream<'input>, AirPos),
+    __4: (AirPos, CanonStream<'input>, AirPos),
+    __5: (AirPos, Token<'input>, AirPos),
+) -> Box<Instruction<'input>>
 {
-    let __start0 = __1.0.clone();
-    let __end0 = __1.2.clone();
-    let __temp0 = __action80(
+    let __start0 = __5.2.clone();
+    let __end0 = __5.2.clone();
+    let __temp0 = __action97(
         input,
         errors,
         validator,
-        __1,
+        comments,
+        &__start0,
+        &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action18(
+    __action106(
         input,
         errors,
         validator,
+        comments,
         __0,
-        __temp0,
+        __1,
         __2,
+        __3,
+        __4,
+        __5,
+        __temp0,
     )
 }
 
 #[allow(unused_variables)]
-fn __action94<
+fn __action127<
     'err,
     'input,
     'v,
+    'c,
 >(
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
+    comments: &'c mut CommentsCollector<'input>,
     __0: (AirPos, Token<'input>, AirPos),
-    __1: (AirPos, AirPos, AirPos),
-) -> Fail<'input>
+    __1: (AirPos, Token<'input>, AirPos),
+    __2: (AirPos, ResolvableToPeerIdVariable<'input>, AirPos),
+    __3: (AirPos, StreamMap<'input>, AirPos),
+    __4: (AirPos, (&'input str, AirPos), AirPos),
+    __5: (AirPos, Token<'input>, AirPos),
+) -> Box<Instruction<'input>>
 {
-    let __start0 = __0.0.clone();
-    let __end0 = __0.0.clone();
-    let __temp0 = __action87(
+    let __start0 = __5.2.clone();
+    let __end0 = __5.2.clone();
+    let __temp0 = __action97(
         input,
         errors,
         validator,
+        comments,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action28(
+    __action107(
         input,
         errors,
         validator,
-        __temp0,
+        comments,
         __0,
         __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __temp0,
     )
 }
 
 #[allow(unused_variables)]
-fn __action95<
+fn __action128<
     'err,
     'input,
     'v,
+    'c,
 >(
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
+    comments: &'c mut CommentsCollector<'input>,
     __0: (AirPos, Token<'input>, AirPos),
     __1: (AirPos, Token<'input>, AirPos),
-    __2: (AirPos, Triplet<'input>, AirPos),
-    __3: (AirPos, Vec<ImmutableValue<'input>>, AirPos),
-    __4: (AirPos, core::option::Option<CallOutputValue<'input>>, AirPos),
-    __5: (AirPos, Token<'input>, AirPos),
-    __6: (AirPos, AirPos, AirPos),
+    __2: (AirPos, ApArgument<'input>, AirPos),
+    __3: (AirPos, ApResult<'input>, AirPos),
+    __4: (AirPos, Token<'input>, AirPos),
 ) -> Box<Instruction<'input>>
 {
-    let __start0 = __0.0.clone();
-    let __end0 = __0.0.clone();
-    let __temp0 = __action87(
+    let __start0 = __4.2.clone();
+    let __end0 = __4.2.clone();
+    let __temp0 = __action97(
         input,
         errors,
         validator,
+        comments,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action2(
+    __action108(
         input,
         errors,
         validator,
-        __temp0,
+        comments,
         __0,
         __1,
         __2,
         __3,
         __4,
-        __5,
-        __6,
+        __temp0,
     )
 }
 
 #[allow(unused_variables)]
-fn __action96<
+fn __action129<
     'err,
     'input,
     'v,
+    'c,
 >(
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
+    comments: &'c mut CommentsCollector<'input>,
     __0: (AirPos, Token<'input>, AirPos),
     __1: (AirPos, Token<'input>, AirPos),
-    __2: (AirPos, ResolvableToPeerIdVariable<'input>, AirPos),
-    __3: (AirPos, Stream<'input>, AirPos),
-    __4: (AirPos, CanonStream<'input>, AirPos),
+    __2: (AirPos, Token<'input>, AirPos),
+    __3: (AirPos, StreamMapKeyClause<'input>, AirPos),
+    __4: (AirPos, ApArgument<'input>, AirPos),
     __5: (AirPos, Token<'input>, AirPos),
-    __6: (AirPos, AirPos, AirPos),
+    __6: (AirPos, StreamMap<'input>, AirPos),
+    __7: (AirPos, Token<'input>, AirPos),
 ) -> Box<Instruction<'input>>
 {
-    let __start0 = __0.0.clone();
-    let __end0 = __0.0.clone();
-    let __temp0 = __action87(
+    let __start0 = __7.2.clone();
+    let __end0 = __7.2.clone();
+    let __temp0 = __action97(
         input,
         errors,
         validator,
+        comments,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action3(
+    __action109(
         input,
         errors,
         validator,
-        __temp0,
+        comments,
         __0,
         __1,
         __2,
@@ -6045,296 +8256,295 @@ fn __action96<
         __4,
         __5,
         __6,
+        __7,
+        __temp0,
     )
 }
 
 #[allow(unused_variables)]
-fn __action97<
+fn __action130<
     'err,
     'input,
     'v,
+    'c,
 >(
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
+    comments: &'c mut CommentsCollector<'input>,
     __0: (AirPos, Token<'input>, AirPos),
     __1: (AirPos, Token<'input>, AirPos),
-    __2: (AirPos, ApArgument<'input>, AirPos),
-    __3: (AirPos, ApResult<'input>, AirPos),
+    __2: (AirPos, Box<Instruction<'input>>, AirPos),
+    __3: (AirPos, Box<Instruction<'input>>, AirPos),
     __4: (AirPos, Token<'input>, AirPos),
-    __5: (AirPos, AirPos, AirPos),
 ) -> Box<Instruction<'input>>
 {
-    let __start0 = __0.0.clone();
-    let __end0 = __0.0.clone();
-    let __temp0 = __action87(
+    let __start0 = __4.2.clone();
+    let __end0 = __4.2.clone();
+    let __temp0 = __action97(
         input,
         errors,
         validator,
+        comments,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action4(
+    __action110(
         input,
         errors,
         validator,
-        __temp0,
+        comments,
         __0,
         __1,
         __2,
         __3,
         __4,
-        __5,
+        __temp0,
     )
 }
 
 #[allow(unused_variables)]
-fn __action98<
+fn __action131<
     'err,
     'input,
     'v,
+    'c,
 >(
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
+    comments: &'c mut CommentsCollector<'input>,
     __0: (AirPos, Token<'input>, AirPos),
     __1: (AirPos, Token<'input>, AirPos),
-    __2: (AirPos, NewArgument<'input>, AirPos),
+    __2: (AirPos, Box<Instruction<'input>>, AirPos),
     __3: (AirPos, Box<Instruction<'input>>, AirPos),
     __4: (AirPos, Token<'input>, AirPos),
-    __5: (AirPos, AirPos, AirPos),
 ) -> Box<Instruction<'input>>
 {
-    let __start0 = __0.0.clone();
-    let __end0 = __0.0.clone();
-    let __temp0 = __action87(
+    let __start0 = __4.2.clone();
+    let __end0 = __4.2.clone();
+    let __temp0 = __action97(
         input,
         errors,
         validator,
+        comments,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action9(
+    __action111(
         input,
         errors,
         validator,
-        __temp0,
+        comments,
         __0,
         __1,
         __2,
         __3,
         __4,
-        __5,
+        __temp0,
     )
 }
 
 #[allow(unused_variables)]
-fn __action99<
+fn __action132<
     'err,
     'input,
     'v,
+    'c,
 >(
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
+    comments: &'c mut CommentsCollector<'input>,
     __0: (AirPos, Token<'input>, AirPos),
     __1: (AirPos, Token<'input>, AirPos),
-    __2: (AirPos, FoldScalarIterable<'input>, AirPos),
-    __3: (AirPos, (&'input str, AirPos), AirPos),
-    __4: (AirPos, Box<Instruction<'input>>, AirPos),
-    __5: (AirPos, core::option::Option<Box<Instruction<'input>>>, AirPos),
-    __6: (AirPos, Token<'input>, AirPos),
-    __7: (AirPos, AirPos, AirPos),
+    __2: (AirPos, Token<'input>, AirPos),
 ) -> Box<Instruction<'input>>
 {
-    let __start0 = __0.0.clone();
-    let __end0 = __0.0.clone();
-    let __temp0 = __action87(
+    let __start0 = __2.2.clone();
+    let __end0 = __2.2.clone();
+    let __temp0 = __action97(
         input,
         errors,
         validator,
+        comments,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action11(
+    __action112(
         input,
         errors,
         validator,
-        __temp0,
+        comments,
         __0,
         __1,
-        __2,
-        __3,
-        __4,
-        __5,
-        __6,
-        __7,
+        __2,
+        __temp0,
     )
 }
 
 #[allow(unused_variables)]
-fn __action100<
+fn __action133<
     'err,
     'input,
     'v,
+    'c,
 >(
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
+    comments: &'c mut CommentsCollector<'input>,
     __0: (AirPos, Token<'input>, AirPos),
     __1: (AirPos, Token<'input>, AirPos),
-    __2: (AirPos, (&'input str, AirPos), AirPos),
-    __3: (AirPos, (&'input str, AirPos), AirPos),
-    __4: (AirPos, Box<Instruction<'input>>, AirPos),
-    __5: (AirPos, core::option::Option<Box<Instruction<'input>>>, AirPos),
-    __6: (AirPos, Token<'input>, AirPos),
-    __7: (AirPos, AirPos, AirPos),
+    __2: (AirPos, Token<'input>, AirPos),
 ) -> Box<Instruction<'input>>
 {
-    let __start0 = __0.0.clone();
-    let __end0 = __0.0.clone();
-    let __temp0 = __action87(
+    let __start0 = __2.2.clone();
+    let __end0 = __2.2.clone();
+    let __temp0 = __action97(
         input,
         errors,
         validator,
+        comments,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action12(
+    __action113(
         input,
         errors,
         validator,
-        __temp0,
+        comments,
         __0,
         __1,
         __2,
-        __3,
-        __4,
-        __5,
-        __6,
-        __7,
+        __temp0,
     )
 }
 
 #[allow(unused_variables)]
-fn __action101<
+fn __action134<
     'err,
     'input,
     'v,
+    'c,
 >(
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
+    comments: &'c mut CommentsCollector<'input>,
     __0: (AirPos, Token<'input>, AirPos),
     __1: (AirPos, Token<'input>, AirPos),
-    __2: (AirPos, (&'input str, AirPos), AirPos),
-    __3: (AirPos, Token<'input>, AirPos),
-    __4: (AirPos, AirPos, AirPos),
+    __2: (AirPos, NewArgument<'input>, AirPos),
+    __3: (AirPos, Box<Instruction<'input>>, AirPos),
+    __4: (AirPos, Token<'input>, AirPos),
 ) -> Box<Instruction<'input>>
 {
-    let __start0 = __0.0.clone();
-    let __end0 = __0.0.clone();
-    let __temp0 = __action87(
+    let __start0 = __4.2.clone();
+    let __end0 = __4.2.clone();
+    let __temp0 = __action97(
         input,
         errors,
         validator,
+        comments,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action13(
+    __action114(
         input,
         errors,
         validator,
-        __temp0,
+        comments,
         __0,
         __1,
         __2,
         __3,
         __4,
+        __temp0,
     )
 }
 
 #[allow(unused_variables)]
-fn __action102<
+fn __action135<
     'err,
     'input,
     'v,
+    'c,
 >(
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
+    comments: &'c mut CommentsCollector<'input>,
     __0: (AirPos, Token<'input>, AirPos),
     __1: (AirPos, Token<'input>, AirPos),
-    __2: (AirPos, ImmutableValue<'input>, AirPos),
-    __3: (AirPos, ImmutableValue<'input>, AirPos),
-    __4: (AirPos, Box<Instruction<'input>>, AirPos),
-    __5: (AirPos, Token<'input>, AirPos),
-    __6: (AirPos, AirPos, AirPos),
+    __2: (AirPos, FailArgument<'input>, AirPos),
+    __3: (AirPos, Token<'input>, AirPos),
 ) -> Box<Instruction<'input>>
 {
-    let __start0 = __0.0.clone();
-    let __end0 = __0.0.clone();
-    let __temp0 = __action87(
+    let __start0 = __3.2.clone();
+    let __end0 = __3.2.clone();
+    let __temp0 = __action97(
         input,
         errors,
         validator,
+        comments,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action15(
+    __action115(
         input,
         errors,
         validator,
-        __temp0,
+        comments,
         __0,
         __1,
         __2,
         __3,
-        __4,
-        __5,
-        __6,
+        __temp0,
     )
 }
 
 #[allow(unused_variables)]
-fn __action103<
+fn __action136<
     'err,
     'input,
     'v,
+    'c,
 >(
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
+    comments: &'c mut CommentsCollector<'input>,
     __0: (AirPos, Token<'input>, AirPos),
     __1: (AirPos, Token<'input>, AirPos),
-    __2: (AirPos, ImmutableValue<'input>, AirPos),
-    __3: (AirPos, ImmutableValue<'input>, AirPos),
+    __2: (AirPos, FoldScalarIterable<'input>, AirPos),
+    __3: (AirPos, (&'input str, AirPos), AirPos),
     __4: (AirPos, Box<Instruction<'input>>, AirPos),
-    __5: (AirPos, Token<'input>, AirPos),
-    __6: (AirPos, AirPos, AirPos),
+    __5: (AirPos, core::option::Option<Box<Instruction<'input>>>, AirPos),
+    __6: (AirPos, Token<'input>, AirPos),
 ) -> Box<Instruction<'input>>
 {
-    let __start0 = __0.0.clone();
-    let __end0 = __0.0.clone();
-    let __temp0 = __action87(
+    let __start0 = __6.2.clone();
+    let __end0 = __6.2.clone();
+    let __temp0 = __action97(
         input,
         errors,
         validator,
+        comments,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action16(
+    __action116(
         input,
         errors,
         validator,
-        __temp0,
+        comments,
         __0,
         __1,
         __2,
@@ -6342,152 +8552,179 @@ fn __action103<
         __4,
         __5,
         __6,
+        __temp0,
     )
 }
 
 #[allow(unused_variables)]
-fn __action104<
+fn __action137<
     'err,
     'input,
     'v,
+    'c,
 >(
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
+    comments: &'c mut CommentsCollector<'input>,
     __0: (AirPos, Token<'input>, AirPos),
-) -> Fail<'input>
+    __1: (AirPos, Token<'input>, AirPos),
+    __2: (AirPos, (&'input str, AirPos), AirPos),
+    __3: (AirPos, (&'input str, AirPos), AirPos),
+    __4: (AirPos, Box<Instruction<'input>>, AirPos),
+    __5: (AirPos, core::option::Option<Box<Instruction<'input>>>, AirPos),
+    __6: (AirPos, Token<'input>, AirPos),
+) -> Box<Instruction<'input>>
 {
-    let __start0 = __0.2.clone();
-    let __end0 = __0.2.clone();
-    let __temp0 = __action84(
+    let __start0 = __6.2.clone();
+    let __end0 = __6.2.clone();
+    let __temp0 = __action97(
         input,
         errors,
         validator,
+        comments,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action94(
+    __action117(
         input,
         errors,
         validator,
+        comments,
         __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __6,
         __temp0,
     )
 }
 
 #[allow(unused_variables)]
-fn __action105<
+fn __action138<
     'err,
     'input,
     'v,
+    'c,
 >(
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
+    comments: &'c mut CommentsCollector<'input>,
     __0: (AirPos, Token<'input>, AirPos),
     __1: (AirPos, Token<'input>, AirPos),
-    __2: (AirPos, Triplet<'input>, AirPos),
-    __3: (AirPos, Vec<ImmutableValue<'input>>, AirPos),
-    __4: (AirPos, core::option::Option<CallOutputValue<'input>>, AirPos),
-    __5: (AirPos, Token<'input>, AirPos),
+    __2: (AirPos, StreamMap<'input>, AirPos),
+    __3: (AirPos, (&'input str, AirPos), AirPos),
+    __4: (AirPos, Box<Instruction<'input>>, AirPos),
+    __5: (AirPos, core::option::Option<Box<Instruction<'input>>>, AirPos),
+    __6: (AirPos, Token<'input>, AirPos),
 ) -> Box<Instruction<'input>>
 {
-    let __start0 = __5.2.clone();
-    let __end0 = __5.2.clone();
-    let __temp0 = __action84(
+    let __start0 = __6.2.clone();
+    let __end0 = __6.2.clone();
+    let __temp0 = __action97(
         input,
         errors,
         validator,
+        comments,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action95(
+    __action118(
         input,
         errors,
         validator,
+        comments,
         __0,
         __1,
         __2,
         __3,
         __4,
         __5,
+        __6,
         __temp0,
     )
 }
 
 #[allow(unused_variables)]
-fn __action106<
+fn __action139<
     'err,
     'input,
     'v,
+    'c,
 >(
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
+    comments: &'c mut CommentsCollector<'input>,
     __0: (AirPos, Token<'input>, AirPos),
     __1: (AirPos, Token<'input>, AirPos),
-    __2: (AirPos, ResolvableToPeerIdVariable<'input>, AirPos),
-    __3: (AirPos, Stream<'input>, AirPos),
-    __4: (AirPos, CanonStream<'input>, AirPos),
-    __5: (AirPos, Token<'input>, AirPos),
+    __2: (AirPos, (&'input str, AirPos), AirPos),
+    __3: (AirPos, Token<'input>, AirPos),
 ) -> Box<Instruction<'input>>
 {
-    let __start0 = __5.2.clone();
-    let __end0 = __5.2.clone();
-    let __temp0 = __action84(
+    let __start0 = __3.2.clone();
+    let __end0 = __3.2.clone();
+    let __temp0 = __action97(
         input,
         errors,
         validator,
+        comments,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action96(
+    __action119(
         input,
         errors,
         validator,
+        comments,
         __0,
         __1,
         __2,
         __3,
-        __4,
-        __5,
         __temp0,
     )
 }
 
 #[allow(unused_variables)]
-fn __action107<
+fn __action140<
     'err,
     'input,
     'v,
+    'c,
 >(
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
+    comments: &'c mut CommentsCollector<'input>,
     __0: (AirPos, Token<'input>, AirPos),
     __1: (AirPos, Token<'input>, AirPos),
-    __2: (AirPos, ApArgument<'input>, AirPos),
-    __3: (AirPos, ApResult<'input>, AirPos),
+    __2: (AirPos, Box<Instruction<'input>>, AirPos),
+    __3: (AirPos, Box<Instruction<'input>>, AirPos),
     __4: (AirPos, Token<'input>, AirPos),
 ) -> Box<Instruction<'input>>
 {
     let __start0 = __4.2.clone();
     let __end0 = __4.2.clone();
-    let __temp0 = __action84(
+    let __temp0 = __action97(
         input,
         errors,
         validator,
+        comments,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action97(
+    __action120(
         input,
         errors,
         validator,
+        comments,
         __0,
         __1,
         __2,
@@ -6498,339 +8735,355 @@ fn __action107<
 }
 
 #[allow(unused_variables)]
-fn __action108<
+fn __action141<
     'err,
     'input,
     'v,
+    'c,
 >(
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
+    comments: &'c mut CommentsCollector<'input>,
     __0: (AirPos, Token<'input>, AirPos),
     __1: (AirPos, Token<'input>, AirPos),
-    __2: (AirPos, NewArgument<'input>, AirPos),
-    __3: (AirPos, Box<Instruction<'input>>, AirPos),
-    __4: (AirPos, Token<'input>, AirPos),
+    __2: (AirPos, ImmutableValue<'input>, AirPos),
+    __3: (AirPos, ImmutableValue<'input>, AirPos),
+    __4: (AirPos, Box<Instruction<'input>>, AirPos),
+    __5: (AirPos, Token<'input>, AirPos),
 ) -> Box<Instruction<'input>>
 {
-    let __start0 = __4.2.clone();
-    let __end0 = __4.2.clone();
-    let __temp0 = __action84(
+    let __start0 = __5.2.clone();
+    let __end0 = __5.2.clone();
+    let __temp0 = __action97(
         input,
         errors,
         validator,
+        comments,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action98(
+    __action121(
         input,
         errors,
         validator,
+        comments,
         __0,
         __1,
         __2,
         __3,
         __4,
+        __5,
         __temp0,
     )
 }
 
 #[allow(unused_variables)]
-fn __action109<
+fn __action142<
     'err,
     'input,
     'v,
+    'c,
 >(
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
+    comments: &'c mut CommentsCollector<'input>,
     __0: (AirPos, Token<'input>, AirPos),
     __1: (AirPos, Token<'input>, AirPos),
-    __2: (AirPos, FoldScalarIterable<'input>, AirPos),
-    __3: (AirPos, (&'input str, AirPos), AirPos),
+    __2: (AirPos, ImmutableValue<'input>, AirPos),
+    __3: (AirPos, ImmutableValue<'input>, AirPos),
     __4: (AirPos, Box<Instruction<'input>>, AirPos),
-    __5: (AirPos, core::option::Option<Box<Instruction<'input>>>, AirPos),
-    __6: (AirPos, Token<'input>, AirPos),
+    __5: (AirPos, Token<'input>, AirPos),
 ) -> Box<Instruction<'input>>
 {
-    let __start0 = __6.2.clone();
-    let __end0 = __6.2.clone();
-    let __temp0 = __action84(
+    let __start0 = __5.2.clone();
+    let __end0 = __5.2.clone();
+    let __temp0 = __action97(
         input,
         errors,
         validator,
+        comments,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action99(
+    __action122(
         input,
         errors,
         validator,
+        comments,
         __0,
         __1,
         __2,
         __3,
         __4,
         __5,
-        __6,
         __temp0,
     )
 }
 
 #[allow(unused_variables)]
-fn __action110<
+fn __action143<
     'err,
     'input,
     'v,
+    'c,
 >(
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
+    comments: &'c mut CommentsCollector<'input>,
     __0: (AirPos, Token<'input>, AirPos),
-    __1: (AirPos, Token<'input>, AirPos),
-    __2: (AirPos, (&'input str, AirPos), AirPos),
-    __3: (AirPos, (&'input str, AirPos), AirPos),
-    __4: (AirPos, Box<Instruction<'input>>, AirPos),
-    __5: (AirPos, core::option::Option<Box<Instruction<'input>>>, AirPos),
-    __6: (AirPos, Token<'input>, AirPos),
-) -> Box<Instruction<'input>>
+) -> FailArgument<'input>
 {
-    let __start0 = __6.2.clone();
-    let __end0 = __6.2.clone();
-    let __temp0 = __action84(
+    let __start0 = __0.2.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action97(
         input,
         errors,
         validator,
+        comments,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action100(
+    __action123(
         input,
         errors,
         validator,
+        comments,
         __0,
-        __1,
-        __2,
-        __3,
-        __4,
-        __5,
-        __6,
         __temp0,
     )
 }
 
 #[allow(unused_variables)]
-fn __action111<
+fn __action144<
     'err,
     'input,
     'v,
+    'c,
 >(
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
-    __0: (AirPos, Token<'input>, AirPos),
-    __1: (AirPos, Token<'input>, AirPos),
-    __2: (AirPos, (&'input str, AirPos), AirPos),
-    __3: (AirPos, Token<'input>, AirPos),
+    comments: &'c mut CommentsCollector<'input>,
+    __0: (AirPos, Box<Instruction<'input>>, AirPos),
 ) -> Box<Instruction<'input>>
 {
-    let __start0 = __3.2.clone();
-    let __end0 = __3.2.clone();
-    let __temp0 = __action84(
+    let __start0 = __0.2.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action97(
         input,
         errors,
         validator,
+        comments,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action101(
+    __action124(
         input,
         errors,
         validator,
+        comments,
         __0,
-        __1,
-        __2,
-        __3,
         __temp0,
     )
 }
 
 #[allow(unused_variables)]
-fn __action112<
+fn __action145<
     'err,
     'input,
     'v,
+    'c,
 >(
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
+    comments: &'c mut CommentsCollector<'input>,
     __0: (AirPos, Token<'input>, AirPos),
     __1: (AirPos, Token<'input>, AirPos),
-    __2: (AirPos, ImmutableValue<'input>, AirPos),
-    __3: (AirPos, ImmutableValue<'input>, AirPos),
-    __4: (AirPos, Box<Instruction<'input>>, AirPos),
+    __2: (AirPos, Triplet<'input>, AirPos),
+    __3: (AirPos, Vec<ImmutableValue<'input>>, AirPos),
+    __4: (AirPos, CallOutputValue<'input>, AirPos),
     __5: (AirPos, Token<'input>, AirPos),
 ) -> Box<Instruction<'input>>
 {
-    let __start0 = __5.2.clone();
-    let __end0 = __5.2.clone();
-    let __temp0 = __action84(
+    let __start0 = __4.0.clone();
+    let __end0 = __4.2.clone();
+    let __temp0 = __action95(
         input,
         errors,
         validator,
-        &__start0,
-        &__end0,
+        comments,
+        __4,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action102(
+    __action125(
         input,
         errors,
         validator,
+        comments,
         __0,
         __1,
         __2,
         __3,
-        __4,
-        __5,
         __temp0,
+        __5,
     )
 }
 
 #[allow(unused_variables)]
-fn __action113<
+fn __action146<
     'err,
     'input,
     'v,
+    'c,
 >(
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
+    comments: &'c mut CommentsCollector<'input>,
     __0: (AirPos, Token<'input>, AirPos),
     __1: (AirPos, Token<'input>, AirPos),
-    __2: (AirPos, ImmutableValue<'input>, AirPos),
-    __3: (AirPos, ImmutableValue<'input>, AirPos),
-    __4: (AirPos, Box<Instruction<'input>>, AirPos),
-    __5: (AirPos, Token<'input>, AirPos),
+    __2: (AirPos, Triplet<'input>, AirPos),
+    __3: (AirPos, Vec<ImmutableValue<'input>>, AirPos),
+    __4: (AirPos, Token<'input>, AirPos),
 ) -> Box<Instruction<'input>>
 {
-    let __start0 = __5.2.clone();
-    let __end0 = __5.2.clone();
-    let __temp0 = __action84(
+    let __start0 = __3.2.clone();
+    let __end0 = __4.0.clone();
+    let __temp0 = __action96(
         input,
         errors,
         validator,
+        comments,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action103(
+    __action125(
         input,
         errors,
         validator,
+        comments,
         __0,
         __1,
         __2,
         __3,
-        __4,
-        __5,
         __temp0,
+        __4,
     )
 }
 
 #[allow(unused_variables)]
-fn __action114<
+fn __action147<
     'err,
     'input,
     'v,
+    'c,
 >(
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
+    comments: &'c mut CommentsCollector<'input>,
     __0: (AirPos, Token<'input>, AirPos),
     __1: (AirPos, Token<'input>, AirPos),
-    __2: (AirPos, Triplet<'input>, AirPos),
-    __3: (AirPos, Vec<ImmutableValue<'input>>, AirPos),
-    __4: (AirPos, CallOutputValue<'input>, AirPos),
-    __5: (AirPos, Token<'input>, AirPos),
+    __2: (AirPos, FoldScalarIterable<'input>, AirPos),
+    __3: (AirPos, (&'input str, AirPos), AirPos),
+    __4: (AirPos, Box<Instruction<'input>>, AirPos),
+    __5: (AirPos, Box<Instruction<'input>>, AirPos),
+    __6: (AirPos, Token<'input>, AirPos),
 ) -> Box<Instruction<'input>>
 {
-    let __start0 = __4.0.clone();
-    let __end0 = __4.2.clone();
-    let __temp0 = __action85(
+    let __start0 = __5.0.clone();
+    let __end0 = __5.2.clone();
+    let __temp0 = __action93(
         input,
         errors,
         validator,
-        __4,
+        comments,
+        __5,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action105(
+    __action136(
         input,
         errors,
         validator,
+        comments,
         __0,
         __1,
         __2,
         __3,
+        __4,
         __temp0,
-        __5,
+        __6,
     )
 }
 
 #[allow(unused_variables)]
-fn __action115<
+fn __action148<
     'err,
     'input,
     'v,
+    'c,
 >(
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
+    comments: &'c mut CommentsCollector<'input>,
     __0: (AirPos, Token<'input>, AirPos),
     __1: (AirPos, Token<'input>, AirPos),
-    __2: (AirPos, Triplet<'input>, AirPos),
-    __3: (AirPos, Vec<ImmutableValue<'input>>, AirPos),
-    __4: (AirPos, Token<'input>, AirPos),
+    __2: (AirPos, FoldScalarIterable<'input>, AirPos),
+    __3: (AirPos, (&'input str, AirPos), AirPos),
+    __4: (AirPos, Box<Instruction<'input>>, AirPos),
+    __5: (AirPos, Token<'input>, AirPos),
 ) -> Box<Instruction<'input>>
 {
-    let __start0 = __3.2.clone();
-    let __end0 = __4.0.clone();
-    let __temp0 = __action86(
+    let __start0 = __4.2.clone();
+    let __end0 = __5.0.clone();
+    let __temp0 = __action94(
         input,
         errors,
         validator,
+        comments,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action105(
+    __action136(
         input,
         errors,
         validator,
+        comments,
         __0,
         __1,
         __2,
         __3,
-        __temp0,
         __4,
+        __temp0,
+        __5,
     )
 }
 
 #[allow(unused_variables)]
-fn __action116<
+fn __action149<
     'err,
     'input,
     'v,
+    'c,
 >(
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
+    comments: &'c mut CommentsCollector<'input>,
     __0: (AirPos, Token<'input>, AirPos),
     __1: (AirPos, Token<'input>, AirPos),
-    __2: (AirPos, FoldScalarIterable<'input>, AirPos),
+    __2: (AirPos, (&'input str, AirPos), AirPos),
     __3: (AirPos, (&'input str, AirPos), AirPos),
     __4: (AirPos, Box<Instruction<'input>>, AirPos),
     __5: (AirPos, Box<Instruction<'input>>, AirPos),
@@ -6839,17 +9092,19 @@ fn __action116<
 {
     let __start0 = __5.0.clone();
     let __end0 = __5.2.clone();
-    let __temp0 = __action82(
+    let __temp0 = __action93(
         input,
         errors,
         validator,
+        comments,
         __5,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action109(
+    __action137(
         input,
         errors,
         validator,
+        comments,
         __0,
         __1,
         __2,
@@ -6861,17 +9116,19 @@ fn __action116<
 }
 
 #[allow(unused_variables)]
-fn __action117<
+fn __action150<
     'err,
     'input,
     'v,
+    'c,
 >(
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
+    comments: &'c mut CommentsCollector<'input>,
     __0: (AirPos, Token<'input>, AirPos),
     __1: (AirPos, Token<'input>, AirPos),
-    __2: (AirPos, FoldScalarIterable<'input>, AirPos),
+    __2: (AirPos, (&'input str, AirPos), AirPos),
     __3: (AirPos, (&'input str, AirPos), AirPos),
     __4: (AirPos, Box<Instruction<'input>>, AirPos),
     __5: (AirPos, Token<'input>, AirPos),
@@ -6879,18 +9136,20 @@ fn __action117<
 {
     let __start0 = __4.2.clone();
     let __end0 = __5.0.clone();
-    let __temp0 = __action83(
+    let __temp0 = __action94(
         input,
         errors,
         validator,
+        comments,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action109(
+    __action137(
         input,
         errors,
         validator,
+        comments,
         __0,
         __1,
         __2,
@@ -6902,17 +9161,19 @@ fn __action117<
 }
 
 #[allow(unused_variables)]
-fn __action118<
+fn __action151<
     'err,
     'input,
     'v,
+    'c,
 >(
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
+    comments: &'c mut CommentsCollector<'input>,
     __0: (AirPos, Token<'input>, AirPos),
     __1: (AirPos, Token<'input>, AirPos),
-    __2: (AirPos, (&'input str, AirPos), AirPos),
+    __2: (AirPos, StreamMap<'input>, AirPos),
     __3: (AirPos, (&'input str, AirPos), AirPos),
     __4: (AirPos, Box<Instruction<'input>>, AirPos),
     __5: (AirPos, Box<Instruction<'input>>, AirPos),
@@ -6921,17 +9182,19 @@ fn __action118<
 {
     let __start0 = __5.0.clone();
     let __end0 = __5.2.clone();
-    let __temp0 = __action82(
+    let __temp0 = __action93(
         input,
         errors,
         validator,
+        comments,
         __5,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action110(
+    __action138(
         input,
         errors,
         validator,
+        comments,
         __0,
         __1,
         __2,
@@ -6943,17 +9206,19 @@ fn __action118<
 }
 
 #[allow(unused_variables)]
-fn __action119<
+fn __action152<
     'err,
     'input,
     'v,
+    'c,
 >(
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
+    comments: &'c mut CommentsCollector<'input>,
     __0: (AirPos, Token<'input>, AirPos),
     __1: (AirPos, Token<'input>, AirPos),
-    __2: (AirPos, (&'input str, AirPos), AirPos),
+    __2: (AirPos, StreamMap<'input>, AirPos),
     __3: (AirPos, (&'input str, AirPos), AirPos),
     __4: (AirPos, Box<Instruction<'input>>, AirPos),
     __5: (AirPos, Token<'input>, AirPos),
@@ -6961,18 +9226,20 @@ fn __action119<
 {
     let __start0 = __4.2.clone();
     let __end0 = __5.0.clone();
-    let __temp0 = __action83(
+    let __temp0 = __action94(
         input,
         errors,
         validator,
+        comments,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action110(
+    __action138(
         input,
         errors,
         validator,
+        comments,
         __0,
         __1,
         __2,
@@ -6983,18 +9250,18 @@ fn __action119<
     )
 }
 
-pub trait __ToTriple<'err, 'input, 'v, >
+pub trait __ToTriple<'err, 'input, 'v, 'c, >
 {
     fn to_triple(value: Self) -> Result<(AirPos,Token<'input>,AirPos), __lalrpop_util::ParseError<AirPos, Token<'input>, ParserError>>;
 }
 
-impl<'err, 'input, 'v, > __ToTriple<'err, 'input, 'v, > for (AirPos, Token<'input>, AirPos)
+impl<'err, 'input, 'v, 'c, > __ToTriple<'err, 'input, 'v, 'c, > for (AirPos, Token<'input>, AirPos)
 {
     fn to_triple(value: Self) -> Result<(AirPos,Token<'input>,AirPos), __lalrpop_util::ParseError<AirPos, Token<'input>, ParserError>> {
         Ok(value)
     }
 }
-impl<'err, 'input, 'v, > __ToTriple<'err, 'input, 'v, > for Result<(AirPos, Token<'input>, AirPos), ParserError>
+impl<'err, 'input, 'v, 'c, > __ToTriple<'err, 'input, 'v, 'c, > for Result<(AirPos, Token<'input>, AirPos), ParserError>
 {
     fn to_triple(value: Self) -> Result<(AirPos,Token<'input>,AirPos), __lalrpop_util::ParseError<AirPos, Token<'input>, ParserError>> {
         match value {
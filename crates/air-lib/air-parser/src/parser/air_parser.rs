@@ -20,6 +20,8 @@ use super::lexer::AirPos;
 use super::lexer::Token;
 use super::ParserError;
 use crate::ast::Instruction;
+use crate::ast::Span;
+use crate::parser::CommentsCollector;
 use crate::parser::VariableValidator;
 use air::AIRParser;
 
@@ -32,32 +34,89 @@ use lalrpop_util::{ErrorRecovery, ParseError};
 // caching parser to improve instantiation time
 thread_local!(static PARSER: AIRParser = AIRParser::new());
 
-/// Parse AIR `source_code` to `Box<Instruction>`
+type RecoveredErrors<'input> = Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>;
+
+/// Error found in an AIR script, intended for tools that report errors on their own.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParserDiagnostic {
+    pub span: Span,
+    pub message: String,
+}
+
+/// Parse AIR `source_code` to `Box<Instruction>`, comments are skipped.
 #[tracing::instrument(skip_all)]
 pub fn parse(air_script: &str) -> Result<Box<Instruction<'_>>, String> {
+    parse_and_report(air_script, false)
+}
+
+/// Same as `parse`, but keeps comments in the AST, intended for tools that print scripts back.
+#[tracing::instrument(skip_all)]
+pub fn parse_with_comments(air_script: &str) -> Result<Box<Instruction<'_>>, String> {
+    parse_and_report(air_script, true)
+}
+
+fn parse_and_report(air_script: &str, keep_comments: bool) -> Result<Box<Instruction<'_>>, String> {
     let mut files = SimpleFiles::new();
     let file_id = files.add("script.air", air_script);
 
+    match parse_with_errors(air_script, keep_comments) {
+        (Some(instruction), errors) if errors.is_empty() => Ok(instruction),
+        (_, errors) => Err(report_errors(file_id, files, errors)),
+    }
+}
+
+/// Parse AIR `source_code` and return all found errors with their spans.
+///
+/// Unlike `parse`, it returns AST even if the parser recovered from some errors,
+/// malformed instructions are represented by `Instruction::Error` in this case.
+pub fn parse_with_diagnostics(
+    air_script: &str,
+) -> (Option<Box<Instruction<'_>>>, Vec<ParserDiagnostic>) {
+    let (instruction, errors) = parse_with_errors(air_script, false);
+    (instruction, errors_to_diagnostics(errors))
+}
+
+fn parse_with_errors(
+    air_script: &str,
+    keep_comments: bool,
+) -> (Option<Box<Instruction<'_>>>, RecoveredErrors<'_>) {
     PARSER.with(|parser| {
-        let mut errors: Vec<ErrorRecovery<AirPos, Token<'_>, ParserError>> = Vec::new();
-        let lexer = AIRLexer::new(air_script);
+        let mut errors: RecoveredErrors<'_> = Vec::new();
         let mut validator = VariableValidator::new();
-        let result = parser.parse(air_script, &mut errors, &mut validator, lexer);
+        let mut comments = CommentsCollector::new();
+        let lexer = AIRLexer::new(air_script);
+        // comments are collected only on demand to keep the interpreter parsing path lean
+        let result = if keep_comments {
+            let tokens = comments.collect(air_script, lexer);
+            parser.parse(
+                air_script,
+                &mut errors,
+                &mut validator,
+                &mut comments,
+                tokens,
+            )
+        } else {
+            parser.parse(
+                air_script,
+                &mut errors,
+                &mut validator,
+                &mut comments,
+                CommentsCollector::skip(lexer),
+            )
+        };
 
         let validator_errors = validator.finalize();
         errors.extend(validator_errors);
 
         match result {
-            Ok(r) if errors.is_empty() => Ok(r),
-            Ok(_) => Err(report_errors(file_id, files, errors)),
-            Err(error) => Err(report_errors(
-                file_id,
-                files,
+            Ok(instruction) => (Some(instruction), errors),
+            Err(error) => (
+                None,
                 vec![ErrorRecovery {
                     error,
                     dropped_tokens: vec![],
                 }],
-            )),
+            ),
         }
     })
 }
@@ -65,7 +124,7 @@ pub fn parse(air_script: &str) -> Result<Box<Instruction<'_>>, String> {
 fn report_errors(
     file_id: usize,
     files: SimpleFiles<&str, &str>,
-    errors: Vec<ErrorRecovery<AirPos, Token<'_>, ParserError>>,
+    errors: RecoveredErrors<'_>,
 ) -> String {
     let labels = errors_to_labels(file_id, errors);
     let diagnostic = Diagnostic::error().with_labels(labels);
@@ -83,30 +142,39 @@ fn report_errors(
         .to_string()
 }
 
-fn errors_to_labels(
-    file_id: usize,
-    errors: Vec<ErrorRecovery<AirPos, Token<'_>, ParserError>>,
-) -> Vec<Label<usize>> {
+fn errors_to_labels(file_id: usize, errors: RecoveredErrors<'_>) -> Vec<Label<usize>> {
+    errors_to_diagnostics(errors)
+        .into_iter()
+        .map(|diagnostic| {
+            let span = diagnostic.span;
+            Label::primary(file_id, span.left.into()..span.right.into())
+                .with_message(diagnostic.message)
+        })
+        .collect()
+}
+
+fn errors_to_diagnostics(errors: RecoveredErrors<'_>) -> Vec<ParserDiagnostic> {
     errors
         .into_iter()
         .map(|err| match err.error {
             ParseError::UnrecognizedToken {
                 token: (start, _, end),
                 expected,
-            } => Label::primary(file_id, start.into()..end.into())
-                .with_message(format!("expected {}", pretty_expected(expected))),
+            } => ParserDiagnostic::new(
+                start..end,
+                format!("expected {}", pretty_expected(expected)),
+            ),
             ParseError::InvalidToken { location } => {
-                Label::primary(file_id, location.into()..(location + 1).into())
-                    .with_message("unexpected token")
+                ParserDiagnostic::new(location..location + 1, "unexpected token")
             }
             ParseError::ExtraToken {
                 token: (start, _, end),
-            } => Label::primary(file_id, start.into()..end.into()).with_message("extra token"),
-            ParseError::UnrecognizedEOF { location, expected } => {
-                Label::primary(file_id, location.into()..(location + 1).into())
-                    .with_message(format!("expected {}", pretty_expected(expected)))
-            }
-            ParseError::User { error } => parser_error_to_label(file_id, error),
+            } => ParserDiagnostic::new(start..end, "extra token"),
+            ParseError::UnrecognizedEOF { location, expected } => ParserDiagnostic::new(
+                location..location + 1,
+                format!("expected {}", pretty_expected(expected)),
+            ),
+            ParseError::User { error } => ParserDiagnostic::new(error.span(), error.to_string()),
         })
         .collect()
 }
@@ -119,7 +187,11 @@ fn pretty_expected(expected: Vec<String>) -> String {
     }
 }
 
-fn parser_error_to_label(file_id: usize, error: ParserError) -> Label<usize> {
-    let span = error.span();
-    Label::primary(file_id, span.left.into()..span.right.into()).with_message(error.to_string())
+impl ParserDiagnostic {
+    fn new(span: impl Into<Span>, message: impl Into<String>) -> Self {
+        Self {
+            span: span.into(),
+            message: message.into(),
+        }
+    }
 }
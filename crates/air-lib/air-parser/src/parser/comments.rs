@@ -0,0 +1,163 @@
+/*
+ * Copyright 2023 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+use super::lexer::AirPos;
+use super::lexer::LexerError;
+use super::lexer::Token;
+use crate::ast::Comment;
+use crate::ast::Commented;
+use crate::ast::Instruction;
+use crate::parser::Span;
+
+pub type SpannedToken<'i> = Result<(AirPos, Token<'i>, AirPos), LexerError>;
+
+/// Collects comments met by the lexer and attaches them to the nearest instructions.
+///
+/// A comment trails an instruction if it follows the instruction closing bracket on the same line
+/// or if it isn't followed by another instruction. All other comments lead the instruction
+/// that follows them, comments placed among instruction arguments lead the instruction itself.
+#[derive(Debug, Default, Clone)]
+pub struct CommentsCollector<'i> {
+    /// Contains comments in the order they were met in a script.
+    comments: Vec<MetComment<'i>>,
+}
+
+#[derive(Debug, Clone)]
+struct MetComment<'i> {
+    comment: Comment<'i>,
+
+    /// End of the closest token before the comment, None at the beginning of a script.
+    previous_token_end: Option<AirPos>,
+
+    /// Start of the closest token after the comment, None at the end of a script.
+    next_token_start: Option<AirPos>,
+
+    /// True if there is no line break between the previous token and the comment.
+    on_previous_token_line: bool,
+
+    /// True if the next token is an opening round bracket.
+    followed_by_round_bracket: bool,
+
+    attached: bool,
+}
+
+impl<'i> CommentsCollector<'i> {
+    pub fn new() -> Self {
+        <_>::default()
+    }
+
+    /// Drops comments from the lexer output, it's used when comments aren't needed in the AST.
+    pub fn skip(
+        tokens: impl IntoIterator<Item = SpannedToken<'i>>,
+    ) -> impl Iterator<Item = SpannedToken<'i>> {
+        tokens
+            .into_iter()
+            .filter(|token| !matches!(token, Ok((_, Token::Comment(_), _))))
+    }
+
+    /// Removes comments from the lexer output, they are kept to be attached by the parser later.
+    pub fn collect(
+        &mut self,
+        air_script: &'i str,
+        tokens: impl IntoIterator<Item = SpannedToken<'i>>,
+    ) -> Vec<SpannedToken<'i>> {
+        let mut previous_token_end: Option<AirPos> = None;
+        let mut first_unfollowed = self.comments.len();
+        let mut remaining_tokens = Vec::new();
+
+        for token in tokens {
+            match token {
+                Ok((start, Token::Comment(text), end)) => {
+                    let on_previous_token_line = previous_token_end
+                        .map(|previous_end| {
+                            !air_script[previous_end.into()..start.into()].contains('\n')
+                        })
+                        .unwrap_or(false);
+
+                    self.comments.push(MetComment {
+                        comment: Comment::new(text, Span::new(start, end)),
+                        previous_token_end,
+                        next_token_start: None,
+                        on_previous_token_line,
+                        followed_by_round_bracket: false,
+                        attached: false,
+                    });
+                }
+                Ok((start, token, end)) => {
+                    let followed_by_round_bracket = matches!(token, Token::OpenRoundBracket);
+                    for comment in &mut self.comments[first_unfollowed..] {
+                        comment.next_token_start = Some(start);
+                        comment.followed_by_round_bracket = followed_by_round_bracket;
+                    }
+                    first_unfollowed = self.comments.len();
+                    previous_token_end = Some(end);
+
+                    remaining_tokens.push(Ok((start, token, end)));
+                }
+                Err(error) => remaining_tokens.push(Err(error)),
+            }
+        }
+
+        remaining_tokens
+    }
+
+    /// Wraps the instruction with comments that belong to it, if there are any.
+    ///
+    /// Instructions are reduced from the innermost ones, so nested instructions
+    /// have already taken their comments by the time the enclosing one is met.
+    pub(super) fn attach(
+        &mut self,
+        instruction: Box<Instruction<'i>>,
+        span: Span,
+    ) -> Box<Instruction<'i>> {
+        // both positions grow monotonically with comments, so candidates form a continuous range
+        let candidates_start = self.comments.partition_point(|met| {
+            met.next_token_start
+                .map(|next_start| next_start < span.left)
+                .unwrap_or(false)
+        });
+        let candidates_end = self.comments.partition_point(|met| {
+            met.previous_token_end
+                .map(|previous_end| previous_end <= span.right)
+                .unwrap_or(true)
+        });
+
+        let mut leading = vec![];
+        let mut trailing = vec![];
+        let candidates_end = candidates_end.max(candidates_start);
+        for met in &mut self.comments[candidates_start..candidates_end] {
+            if met.attached {
+                continue;
+            }
+
+            if met.previous_token_end != Some(span.right) {
+                leading.push(met.comment.clone());
+            } else if met.on_previous_token_line || !met.followed_by_round_bracket {
+                trailing.push(met.comment.clone());
+            } else {
+                // this comment leads the next instruction
+                continue;
+            }
+            met.attached = true;
+        }
+
+        if leading.is_empty() && trailing.is_empty() {
+            return instruction;
+        }
+
+        let commented = Commented::new(leading, instruction, trailing);
+        Box::new(Instruction::Commented(commented))
+    }
+}
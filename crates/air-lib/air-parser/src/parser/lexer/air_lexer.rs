@@ -54,7 +54,7 @@ impl<'input> AIRLexer<'input> {
                 '[' => return Some(Ok((start_pos, Token::OpenSquareBracket, start_pos + 1))),
                 ']' => return Some(Ok((start_pos, Token::CloseSquareBracket, start_pos + 1))),
 
-                ';' => self.skip_comment(),
+                ';' => return self.tokenize_comment(start_pos),
 
                 ch if ch.is_whitespace() => {}
 
@@ -67,14 +67,28 @@ impl<'input> AIRLexer<'input> {
         None
     }
 
-    fn skip_comment(&mut self) {
-        const NEW_LINE: char = '\n'; // TODO: consider '\n\r'
+    #[allow(clippy::unnecessary_wraps)]
+    fn tokenize_comment(
+        &mut self,
+        start_pos: AirPos,
+    ) -> Option<Spanned<Token<'input>, AirPos, LexerError>> {
+        const NEW_LINE: char = '\n';
 
-        for (_, ch) in &mut self.chars {
+        let mut end_pos = AirPos::from(self.input.len());
+        for (pos, ch) in &mut self.chars {
             if ch == NEW_LINE {
+                end_pos = pos.into();
                 break;
             }
         }
+
+        // a comment doesn't include the line ending, '\r' of '\r\n' as well
+        let comment = self.input[start_pos.into()..end_pos.into()].trim_end();
+        Some(Ok((
+            start_pos,
+            Token::Comment(comment),
+            start_pos + comment.len(),
+        )))
     }
 
     #[allow(clippy::unnecessary_wraps)]
@@ -35,6 +35,7 @@ enum MetTag {
     None,
     Stream,
     CanonStream,
+    StreamMap,
 }
 
 #[derive(Debug)]
@@ -291,11 +292,19 @@ impl<'input> CallVariableParser<'input> {
                 name,
                 position: self.start_pos,
             },
+            MetTag::StreamMap => Token::StreamMap {
+                name,
+                position: self.start_pos,
+            },
         }
     }
 
-    fn to_variable_token_with_lambda<'v>(&self, name: &'v str, lambda: LambdaAST<'v>) -> Token<'v> {
-        match self.state.met_tag {
+    fn to_variable_token_with_lambda<'v>(
+        &self,
+        name: &'v str,
+        lambda: LambdaAST<'v>,
+    ) -> LexerResult<Token<'v>> {
+        let token = match self.state.met_tag {
             MetTag::None => Token::ScalarWithLambda {
                 name,
                 lambda,
@@ -311,7 +320,14 @@ impl<'input> CallVariableParser<'input> {
                 lambda,
                 position: self.start_pos,
             },
-        }
+            MetTag::StreamMap => {
+                return Err(LexerError::stream_map_with_lambda(
+                    self.start_pos..self.start_pos + self.string_to_parse.len(),
+                ))
+            }
+        };
+
+        Ok(token)
     }
 
     fn try_to_variable_and_lambda(&self, lambda_start_offset: usize) -> LexerResult<Token<'input>> {
@@ -324,9 +340,7 @@ impl<'input> CallVariableParser<'input> {
                 )
             })?;
 
-        let token = self
-            .to_variable_token_with_lambda(&self.string_to_parse[0..lambda_start_offset], lambda);
-        Ok(token)
+        self.to_variable_token_with_lambda(&self.string_to_parse[0..lambda_start_offset], lambda)
     }
 
     fn try_to_i64(&self) -> LexerResult<Token<'input>> {
@@ -378,6 +392,7 @@ impl MetTag {
         match tag {
             '$' => Self::Stream,
             '#' => Self::CanonStream,
+            '%' => Self::StreamMap,
             _ => Self::None,
         }
     }
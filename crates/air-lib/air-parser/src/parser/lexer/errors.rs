@@ -67,6 +67,9 @@ pub enum LexerError {
 
     #[error("leading dot without any symbols before - please write 0 if it's float or variable name if it's a lambda")]
     LeadingDot(Span),
+
+    #[error("stream maps can't be used with lambdas, canonicalize a stream map into a scalar to access its values")]
+    StreamMapWithLambda(Span),
 }
 
 impl LexerError {
@@ -85,6 +88,7 @@ impl LexerError {
             Self::LastErrorPathError { span, .. } => span,
             Self::TooBigFloat(span) => span,
             Self::LeadingDot(span) => span,
+            Self::StreamMapWithLambda(span) => span,
         };
 
         *span
@@ -150,13 +154,17 @@ impl LexerError {
     pub fn leading_dot(range: Range<AirPos>) -> Self {
         Self::LeadingDot(range.into())
     }
+
+    pub fn stream_map_with_lambda(range: Range<AirPos>) -> Self {
+        Self::StreamMapWithLambda(range.into())
+    }
 }
 
 use super::Token;
 use crate::parser::air::__ToTriple;
 use crate::parser::ParserError;
 
-impl<'err, 'input, 'i> __ToTriple<'err, 'input, 'i>
+impl<'err, 'input, 'i, 'c> __ToTriple<'err, 'input, 'i, 'c>
     for Result<(AirPos, Token<'input>, AirPos), LexerError>
 {
     #[allow(clippy::wrong_self_convention)]
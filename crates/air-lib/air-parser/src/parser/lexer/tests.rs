@@ -215,6 +215,35 @@ fn canon_stream() {
     );
 }
 
+#[test]
+fn stream_map() {
+    const STREAM_MAP: &str = "%stream_map____asdasd";
+
+    lexer_test(
+        STREAM_MAP,
+        Single(Ok((
+            0.into(),
+            Token::StreamMap {
+                name: STREAM_MAP,
+                position: 0.into(),
+            },
+            STREAM_MAP.len().into(),
+        ))),
+    );
+}
+
+#[test]
+fn stream_map_with_lambda() {
+    const STREAM_MAP: &str = "%stream_map.$.field";
+
+    lexer_test(
+        STREAM_MAP,
+        Single(Err(LexerError::stream_map_with_lambda(
+            0.into()..STREAM_MAP.len().into(),
+        ))),
+    );
+}
+
 #[test]
 fn canon_stream_with_functor() {
     let canon_stream_name = "#canon_stream";
@@ -613,3 +642,19 @@ fn match_with_empty_array__() {
         ),
     );
 }
+
+#[test]
+fn comments() {
+    const COMMENTED: &str = ";; leading\r\n(null) ; trailing";
+
+    lexer_test(
+        COMMENTED,
+        All(vec![
+            Ok((0.into(), Token::Comment(";; leading"), 10.into())),
+            Ok((12.into(), Token::OpenRoundBracket, 13.into())),
+            Ok((13.into(), Token::Null, 17.into())),
+            Ok((17.into(), Token::CloseRoundBracket, 18.into())),
+            Ok((19.into(), Token::Comment("; trailing"), 29.into())),
+        ]),
+    );
+}
@@ -55,6 +55,10 @@ pub enum Token<'input> {
         lambda: LambdaAST<'input>,
         position: AirPos,
     },
+    StreamMap {
+        name: &'input str,
+        position: AirPos,
+    },
 
     StringLiteral(&'input str),
     I64(i64),
@@ -81,4 +85,6 @@ pub enum Token<'input> {
     Null,
     Match,
     MisMatch,
+
+    Comment(&'input str),
 }
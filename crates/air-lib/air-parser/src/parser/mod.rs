@@ -16,7 +16,9 @@
 
 pub mod air_parser;
 mod air_utils;
+mod comments;
 pub(crate) mod lexer;
+mod source_position;
 mod span;
 
 // air is auto-generated, so exclude it from `cargo fmt -- --check` and `cargo clippy`
@@ -31,8 +33,13 @@ mod validator;
 pub mod tests;
 
 pub use self::air_parser::parse;
+pub use self::air_parser::parse_with_comments;
+pub use self::air_parser::parse_with_diagnostics;
+pub use self::air_parser::ParserDiagnostic;
 pub use air::AIRParser;
+pub use comments::CommentsCollector;
 pub use lexer::AIRLexer;
+pub use source_position::SourcePosition;
 pub use span::Span;
 pub use validator::VariableValidator;
 
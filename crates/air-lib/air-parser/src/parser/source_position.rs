@@ -0,0 +1,54 @@
+/*
+ * Copyright 2023 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use super::lexer::AirPos;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+use std::fmt;
+
+/// Position in a script suitable for humans, both line and column start from 1,
+/// the column is counted in characters.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct SourcePosition {
+    pub line: usize,
+    pub column: usize,
+}
+
+impl SourcePosition {
+    pub fn new(line: usize, column: usize) -> Self {
+        Self { line, column }
+    }
+
+    /// Converts a byte offset in the script, positions outside of it are clamped to its end.
+    pub fn from_air_pos(air_script: &str, position: AirPos) -> Self {
+        let offset = usize::from(position).min(air_script.len());
+        let prefix = &air_script[..offset];
+
+        let line = prefix.matches('\n').count() + 1;
+        let line_start = prefix.rfind('\n').map(|newline| newline + 1).unwrap_or(0);
+        let column = prefix[line_start..].chars().count() + 1;
+
+        Self { line, column }
+    }
+}
+
+impl fmt::Display for SourcePosition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", self.line, self.column)
+    }
+}
@@ -30,7 +30,7 @@ fn ap_with_literal() {
 
     let actual = parse(source_code);
     let expected = ap(
-        ApArgument::Literal("some_string"),
+        ApArgument::Literal("some_string".into()),
         ApResult::Stream(Stream::new("$stream", 27.into())),
     );
 
@@ -184,3 +184,37 @@ fn ap_with_canon_stream_with_lambda() {
 
     assert_eq!(actual, expected);
 }
+
+#[test]
+fn ap_with_literal_key_to_stream_map() {
+    let stream_map = "%stream_map";
+    let source_code = f!(r#"
+        (ap ("key" 1) {stream_map})
+    "#);
+
+    let actual = parse(&source_code);
+    let expected = ap_map(
+        StreamMapKeyClause::Literal("key".into()),
+        ApArgument::Number(Number::Int(1)),
+        StreamMap::new(stream_map, 23.into()),
+    );
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn ap_with_int_key_to_stream_map() {
+    let stream_map = "%stream_map";
+    let source_code = f!(r#"
+        (ap (-1 "value") {stream_map})
+    "#);
+
+    let actual = parse(&source_code);
+    let expected = ap_map(
+        StreamMapKeyClause::Int(-1),
+        ApArgument::Literal("value".into()),
+        StreamMap::new(stream_map, 26.into()),
+    );
+
+    assert_eq!(actual, expected);
+}
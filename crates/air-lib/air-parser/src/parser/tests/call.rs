@@ -39,10 +39,10 @@ fn parse_json_path() {
             vec![ValueAccessor::FieldAccessByName { field_name: "a" }],
             15.into(),
         )),
-        ResolvableToStringVariable::Literal("service_id"),
-        ResolvableToStringVariable::Literal("function_name"),
+        ResolvableToStringVariable::Literal("service_id".into()),
+        ResolvableToStringVariable::Literal("function_name".into()),
         Rc::new(vec![
-            ImmutableValue::Literal("hello"),
+            ImmutableValue::Literal("hello".into()),
             ImmutableValue::Variable(ImmutableVariable::scalar("name", 68.into())),
         ]),
         CallOutputValue::Stream(Stream::new("$void", 74.into())),
@@ -60,9 +60,9 @@ fn parse_empty_array() {
     let expected = call(
         ResolvableToPeerIdVariable::Scalar(Scalar::new("peer_id", 15.into())),
         ResolvableToStringVariable::Scalar(Scalar::new("service_id", 24.into())),
-        ResolvableToStringVariable::Literal("function_name"),
+        ResolvableToStringVariable::Literal("function_name".into()),
         Rc::new(vec![
-            ImmutableValue::Literal(""),
+            ImmutableValue::Literal("".into()),
             ImmutableValue::EmptyArray,
             ImmutableValue::Variable(ImmutableVariable::scalar("arg", 59.into())),
         ]),
@@ -81,8 +81,8 @@ fn parse_empty_array_2() {
     let actual = parse(source_code);
     let expected = call(
         ResolvableToPeerIdVariable::Scalar(Scalar::new("peer_id", 15.into())),
-        ResolvableToStringVariable::Literal("service_id"),
-        ResolvableToStringVariable::Literal("function_name"),
+        ResolvableToStringVariable::Literal("service_id".into()),
+        ResolvableToStringVariable::Literal("function_name".into()),
         Rc::new(vec![
             ImmutableValue::Variable(ImmutableVariable::scalar("k", 55.into())),
             ImmutableValue::EmptyArray,
@@ -105,8 +105,15 @@ fn parse_undefined_variable() {
     let parser = crate::AIRParser::new();
     let mut errors = Vec::new();
     let mut validator = crate::parser::VariableValidator::new();
+    let mut comments = crate::parser::CommentsCollector::new();
     parser
-        .parse(source_code, &mut errors, &mut validator, lexer)
+        .parse(
+            source_code,
+            &mut errors,
+            &mut validator,
+            &mut comments,
+            lexer,
+        )
         .expect("parser shouldn't fail");
 
     let errors = validator.finalize();
@@ -137,8 +144,15 @@ fn parse_undefined_stream_without_json_path() {
     let parser = crate::AIRParser::new();
     let mut errors = Vec::new();
     let mut validator = crate::parser::VariableValidator::new();
+    let mut comments = crate::parser::CommentsCollector::new();
     parser
-        .parse(source_code, &mut errors, &mut validator, lexer)
+        .parse(
+            source_code,
+            &mut errors,
+            &mut validator,
+            &mut comments,
+            lexer,
+        )
         .expect("parser shouldn't fail");
 
     let errors = validator.finalize();
@@ -162,8 +176,8 @@ fn parse_lambda_complex() {
                 vec![ValueAccessor::ArrayAccess { idx: 1 }],
                 32.into(),
             )),
-            ResolvableToStringVariable::Literal("service_id"),
-            ResolvableToStringVariable::Literal("function_name"),
+            ResolvableToStringVariable::Literal("service_id".into()),
+            ResolvableToStringVariable::Literal("function_name".into()),
             Rc::new(vec![]),
             CallOutputValue::Scalar(Scalar::new("void", 75.into())),
         ),
@@ -181,8 +195,8 @@ fn parse_lambda_complex() {
                 ],
                 99.into(),
             )),
-            ResolvableToStringVariable::Literal("service_id"),
-            ResolvableToStringVariable::Literal("function_name"),
+            ResolvableToStringVariable::Literal("service_id".into()),
+            ResolvableToStringVariable::Literal("function_name".into()),
             Rc::new(vec![]),
             CallOutputValue::Scalar(Scalar::new("void", 162.into())),
         ),
@@ -214,8 +228,8 @@ fn parse_lambda_with_scalars_complex() {
                 ],
                 32.into(),
             )),
-            ResolvableToStringVariable::Literal("service_id"),
-            ResolvableToStringVariable::Literal("function_name"),
+            ResolvableToStringVariable::Literal("service_id".into()),
+            ResolvableToStringVariable::Literal("function_name".into()),
             Rc::new(vec![]),
             CallOutputValue::Scalar(Scalar::new("void", 97.into())),
         ),
@@ -239,8 +253,8 @@ fn parse_lambda_with_scalars_complex() {
                 ],
                 121.into(),
             )),
-            ResolvableToStringVariable::Literal("service_id"),
-            ResolvableToStringVariable::Literal("function_name"),
+            ResolvableToStringVariable::Literal("service_id".into()),
+            ResolvableToStringVariable::Literal("function_name".into()),
             Rc::new(vec![]),
             CallOutputValue::Scalar(Scalar::new("void", 205.into())),
         ),
@@ -262,8 +276,8 @@ fn json_path_square_braces() {
             }],
             15.into(),
         )),
-        ResolvableToStringVariable::Literal("return"),
-        ResolvableToStringVariable::Literal(""),
+        ResolvableToStringVariable::Literal("return".into()),
+        ResolvableToStringVariable::Literal("".into()),
         Rc::new(vec![
             ImmutableValue::VariableWithLambda(ImmutableVariableWithLambda::from_raw_value_path(
                 "u",
@@ -302,16 +316,16 @@ fn parse_init_peer_id() {
     let instruction = parse(&source_code);
     let expected = seq(
         call(
-            ResolvableToPeerIdVariable::Literal(peer_id),
-            ResolvableToStringVariable::Literal("local_service_id"),
-            ResolvableToStringVariable::Literal("local_fn_name"),
+            ResolvableToPeerIdVariable::Literal(peer_id.into()),
+            ResolvableToStringVariable::Literal("local_service_id".into()),
+            ResolvableToStringVariable::Literal("local_fn_name".into()),
             Rc::new(vec![]),
             CallOutputValue::None,
         ),
         call(
             ResolvableToPeerIdVariable::InitPeerId,
-            ResolvableToStringVariable::Literal("service_id"),
-            ResolvableToStringVariable::Literal("fn_name"),
+            ResolvableToStringVariable::Literal("service_id".into()),
+            ResolvableToStringVariable::Literal("fn_name".into()),
             Rc::new(vec![]),
             CallOutputValue::None,
         ),
@@ -328,9 +342,9 @@ fn parse_timestamp() {
 
     let instruction = parse(source_code);
     let expected = call(
-        ResolvableToPeerIdVariable::Literal("peer_id"),
-        ResolvableToStringVariable::Literal("service_id"),
-        ResolvableToStringVariable::Literal("fn_name"),
+        ResolvableToPeerIdVariable::Literal("peer_id".into()),
+        ResolvableToStringVariable::Literal("service_id".into()),
+        ResolvableToStringVariable::Literal("fn_name".into()),
         Rc::new(vec![ImmutableValue::Timestamp]),
         CallOutputValue::None,
     );
@@ -346,9 +360,9 @@ fn parse_ttl() {
 
     let instruction = parse(source_code);
     let expected = call(
-        ResolvableToPeerIdVariable::Literal("peer_id"),
-        ResolvableToStringVariable::Literal("service_id"),
-        ResolvableToStringVariable::Literal("fn_name"),
+        ResolvableToPeerIdVariable::Literal("peer_id".into()),
+        ResolvableToStringVariable::Literal("service_id".into()),
+        ResolvableToStringVariable::Literal("fn_name".into()),
         Rc::new(vec![ImmutableValue::TTL]),
         CallOutputValue::None,
     );
@@ -369,8 +383,8 @@ fn parse_last_error() {
     let expected = seq(
         call(
             ResolvableToPeerIdVariable::InitPeerId,
-            ResolvableToStringVariable::Literal("service_id"),
-            ResolvableToStringVariable::Literal("fn_name"),
+            ResolvableToStringVariable::Literal("service_id".into()),
+            ResolvableToStringVariable::Literal("fn_name".into()),
             Rc::new(vec![ImmutableValue::LastError(None)]),
             CallOutputValue::None,
         ),
@@ -392,8 +406,8 @@ fn canon_stream_in_args() {
     let instruction = parse(&source_code);
     let expected = call(
         ResolvableToPeerIdVariable::InitPeerId,
-        ResolvableToStringVariable::Literal(service_id),
-        ResolvableToStringVariable::Literal(function_name),
+        ResolvableToStringVariable::Literal(service_id.into()),
+        ResolvableToStringVariable::Literal(function_name.into()),
         Rc::new(vec![ImmutableValue::Variable(
             ImmutableVariable::canon_stream(canon_stream, 66.into()),
         )]),
@@ -417,8 +431,15 @@ fn canon_stream_in_triplet() {
     let parser = crate::AIRParser::new();
     let mut errors = Vec::new();
     let mut validator = crate::parser::VariableValidator::new();
+    let mut comments = crate::parser::CommentsCollector::new();
     parser
-        .parse(&source_code, &mut errors, &mut validator, lexer)
+        .parse(
+            &source_code,
+            &mut errors,
+            &mut validator,
+            &mut comments,
+            lexer,
+        )
         .expect("parser shouldn't fail");
 
     assert_eq!(errors.len(), 1);
@@ -449,8 +470,8 @@ fn canon_stream_with_lambda_in_triplet() {
             .unwrap(),
             19.into(),
         )),
-        ResolvableToStringVariable::Literal(service_id),
-        ResolvableToStringVariable::Literal(function_name),
+        ResolvableToStringVariable::Literal(service_id.into()),
+        ResolvableToStringVariable::Literal(function_name.into()),
         Rc::new(vec![]),
         CallOutputValue::None,
     );
@@ -474,24 +495,24 @@ fn seq_par_call() {
     let expected = seq(
         par(
             call(
-                ResolvableToPeerIdVariable::Literal(peer_id),
-                ResolvableToStringVariable::Literal("local_service_id"),
-                ResolvableToStringVariable::Literal("local_fn_name"),
+                ResolvableToPeerIdVariable::Literal(peer_id.into()),
+                ResolvableToStringVariable::Literal("local_service_id".into()),
+                ResolvableToStringVariable::Literal("local_fn_name".into()),
                 Rc::new(vec![]),
                 CallOutputValue::Scalar(Scalar::new("result_1", 108.into())),
             ),
             call(
-                ResolvableToPeerIdVariable::Literal(peer_id),
-                ResolvableToStringVariable::Literal("service_id"),
-                ResolvableToStringVariable::Literal("fn_name"),
+                ResolvableToPeerIdVariable::Literal(peer_id.into()),
+                ResolvableToStringVariable::Literal("service_id".into()),
+                ResolvableToStringVariable::Literal("fn_name".into()),
                 Rc::new(vec![]),
                 CallOutputValue::Scalar(Scalar::new("g", 183.into())),
             ),
         ),
         call(
-            ResolvableToPeerIdVariable::Literal(peer_id),
-            ResolvableToStringVariable::Literal("local_service_id"),
-            ResolvableToStringVariable::Literal("local_fn_name"),
+            ResolvableToPeerIdVariable::Literal(peer_id.into()),
+            ResolvableToStringVariable::Literal("local_service_id".into()),
+            ResolvableToStringVariable::Literal("local_fn_name".into()),
             Rc::new(vec![]),
             CallOutputValue::Scalar(Scalar::new("result_2", 273.into())),
         ),
@@ -529,33 +550,33 @@ fn seq_with_empty_and_dash() {
         seq(
             seq(
                 call(
-                    ResolvableToPeerIdVariable::Literal("set_variables"),
-                    ResolvableToStringVariable::Literal(""),
-                    ResolvableToStringVariable::Literal(""),
-                    Rc::new(vec![ImmutableValue::Literal("module-bytes")]),
+                    ResolvableToPeerIdVariable::Literal("set_variables".into()),
+                    ResolvableToStringVariable::Literal("".into()),
+                    ResolvableToStringVariable::Literal("".into()),
+                    Rc::new(vec![ImmutableValue::Literal("module-bytes".into())]),
                     CallOutputValue::Scalar(Scalar::new("module-bytes", 119.into())),
                 ),
                 call(
-                    ResolvableToPeerIdVariable::Literal("set_variables"),
-                    ResolvableToStringVariable::Literal(""),
-                    ResolvableToStringVariable::Literal(""),
-                    Rc::new(vec![ImmutableValue::Literal("module_config")]),
+                    ResolvableToPeerIdVariable::Literal("set_variables".into()),
+                    ResolvableToStringVariable::Literal("".into()),
+                    ResolvableToStringVariable::Literal("".into()),
+                    Rc::new(vec![ImmutableValue::Literal("module_config".into())]),
                     CallOutputValue::Scalar(Scalar::new("module_config", 201.into())),
                 ),
             ),
             call(
-                ResolvableToPeerIdVariable::Literal("set_variables"),
-                ResolvableToStringVariable::Literal(""),
-                ResolvableToStringVariable::Literal(""),
-                Rc::new(vec![ImmutableValue::Literal("blueprint")]),
+                ResolvableToPeerIdVariable::Literal("set_variables".into()),
+                ResolvableToStringVariable::Literal("".into()),
+                ResolvableToStringVariable::Literal("".into()),
+                Rc::new(vec![ImmutableValue::Literal("blueprint".into())]),
                 CallOutputValue::Scalar(Scalar::new("blueprint", 294.into())),
             ),
         ),
         seq(
             call(
-                ResolvableToPeerIdVariable::Literal("A"),
-                ResolvableToStringVariable::Literal("add_module"),
-                ResolvableToStringVariable::Literal(""),
+                ResolvableToPeerIdVariable::Literal("A".into()),
+                ResolvableToStringVariable::Literal("add_module".into()),
+                ResolvableToStringVariable::Literal("".into()),
                 Rc::new(vec![
                     ImmutableValue::Variable(ImmutableVariable::scalar("module-bytes", 381.into())),
                     ImmutableValue::Variable(ImmutableVariable::scalar(
@@ -568,21 +589,22 @@ fn seq_with_empty_and_dash() {
             seq(
                 Instruction::Call(Call {
                     triplet: Triplet {
-                        peer_id: ResolvableToPeerIdVariable::Literal("A"),
-                        service_id: ResolvableToStringVariable::Literal("add_blueprint"),
-                        function_name: ResolvableToStringVariable::Literal(""),
+                        peer_id: ResolvableToPeerIdVariable::Literal("A".into()),
+                        service_id: ResolvableToStringVariable::Literal("add_blueprint".into()),
+                        function_name: ResolvableToStringVariable::Literal("".into()),
                     },
                     args: Rc::new(vec![ImmutableValue::Variable(ImmutableVariable::scalar(
                         "blueprint",
                         490.into(),
                     ))]),
                     output: CallOutputValue::Scalar(Scalar::new("blueprint_id", 501.into())),
+                    span: no_span(),
                 }),
                 seq(
                     call(
-                        ResolvableToPeerIdVariable::Literal("A"),
-                        ResolvableToStringVariable::Literal("create"),
-                        ResolvableToStringVariable::Literal(""),
+                        ResolvableToPeerIdVariable::Literal("A".into()),
+                        ResolvableToStringVariable::Literal("create".into()),
+                        ResolvableToStringVariable::Literal("".into()),
                         Rc::new(vec![ImmutableValue::Variable(ImmutableVariable::scalar(
                             "blueprint_id",
                             589.into(),
@@ -590,9 +612,9 @@ fn seq_with_empty_and_dash() {
                         CallOutputValue::Scalar(Scalar::new("service_id", 603.into())),
                     ),
                     call(
-                        ResolvableToPeerIdVariable::Literal("remote_peer_id"),
-                        ResolvableToStringVariable::Literal(""),
-                        ResolvableToStringVariable::Literal(""),
+                        ResolvableToPeerIdVariable::Literal("remote_peer_id".into()),
+                        ResolvableToStringVariable::Literal("".into()),
+                        ResolvableToStringVariable::Literal("".into()),
                         Rc::new(vec![ImmutableValue::Variable(ImmutableVariable::scalar(
                             "service_id",
                             671.into(),
@@ -639,8 +661,15 @@ fn not_defined_scalar_in_lambda() {
     let parser = crate::AIRParser::new();
     let mut errors = Vec::new();
     let mut validator = crate::parser::VariableValidator::new();
+    let mut comments = crate::parser::CommentsCollector::new();
     parser
-        .parse(source_code, &mut errors, &mut validator, lexer)
+        .parse(
+            source_code,
+            &mut errors,
+            &mut validator,
+            &mut comments,
+            lexer,
+        )
         .expect("parser shouldn't fail");
 
     let errors = validator.finalize();
@@ -657,3 +686,18 @@ fn not_defined_scalar_in_lambda() {
         ParserError::UndefinedVariable { .. }
     ));
 }
+
+#[test]
+fn parse_with_diagnostics_reports_undefined_variable_span() {
+    let source_code = r#"(call "" ("" "") [undefined_name])"#;
+
+    let (instruction, diagnostics) = crate::parse_with_diagnostics(source_code);
+    assert!(instruction.is_some());
+
+    // variables are validated per instruction, so the span covers the whole call
+    let expected = vec![crate::ParserDiagnostic {
+        span: Span::new(0.into(), source_code.len().into()),
+        message: "variable 'undefined_name' wasn't defined".to_string(),
+    }];
+    assert_eq!(diagnostics, expected);
+}
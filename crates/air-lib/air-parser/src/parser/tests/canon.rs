@@ -32,7 +32,7 @@ fn canon_with_literal_peer_id() {
 
     let actual = parse(&source_code);
     let expected = canon(
-        ResolvableToPeerIdVariable::Literal(peer_id),
+        ResolvableToPeerIdVariable::Literal(peer_id.into()),
         Stream::new(stream, 26.into()),
         CanonStream::new(canon_stream, 34.into()),
     );
@@ -58,3 +58,22 @@ fn canon_with_variable_peer_id() {
 
     assert_eq!(actual, expected);
 }
+
+#[test]
+fn canon_stream_map() {
+    let peer_id = "peer_id";
+    let stream_map = "%stream_map";
+    let scalar = "scalar";
+    let source_code = f!(r#"
+        (canon "{peer_id}" {stream_map} {scalar})
+    "#);
+
+    let actual = parse(&source_code);
+    let expected = canon_map(
+        ResolvableToPeerIdVariable::Literal(peer_id.into()),
+        StreamMap::new(stream_map, 26.into()),
+        Scalar::new(scalar, 38.into()),
+    );
+
+    assert_eq!(actual, expected);
+}
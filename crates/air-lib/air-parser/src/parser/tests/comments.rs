@@ -0,0 +1,89 @@
+/*
+ * Copyright 2023 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use super::dsl::*;
+use super::parse;
+use crate::ast::*;
+
+#[test]
+fn comments_are_attached_to_nearest_instructions() {
+    let source_code = r#"
+        ;; script
+        (seq
+            ;; first
+            (null) ;; after first
+            ;; second
+            (par ;; inside par
+                (never)
+                (null)
+                ;; before closing
+            )
+        ) ;; end
+        "#;
+
+    let instruction = parse(source_code);
+    let first = commented(
+        vec![comment(";; first", 44)],
+        null(),
+        vec![comment(";; after first", 72)],
+    );
+    let second = commented(
+        vec![comment(";; second", 99)],
+        par(
+            commented(vec![comment(";; inside par", 126)], never(), vec![]),
+            commented(vec![], null(), vec![comment(";; before closing", 203)]),
+        ),
+        vec![],
+    );
+    let expected = commented(
+        vec![comment(";; script", 9)],
+        seq(first, second),
+        vec![comment(";; end", 245)],
+    );
+    assert_eq!(instruction, expected);
+}
+
+#[test]
+fn comment_among_arguments_leads_instruction() {
+    let source_code = r#"(ap 1 ;; why
+    $stream)"#;
+
+    let instruction = parse(source_code);
+    let ap = Instruction::Ap(Ap::new(
+        ApArgument::Number(Number::Int(1)),
+        ApResult::stream("$stream", 17.into()),
+        no_span(),
+    ));
+    let expected = commented(vec![comment(";; why", 6)], ap, vec![]);
+    assert_eq!(instruction, expected);
+}
+
+#[test]
+fn script_without_comments_is_not_wrapped() {
+    let instruction = parse("(seq (null) (never))");
+    assert_eq!(instruction, seq(null(), never()));
+}
+
+#[test]
+fn comments_are_kept_only_on_demand() {
+    let source_code = ";; leading\n(null)";
+
+    let instruction = crate::parse(source_code).unwrap();
+    assert!(matches!(*instruction, Instruction::Null(_)));
+
+    let instruction = crate::parse_with_comments(source_code).unwrap();
+    assert!(matches!(*instruction, Instruction::Commented(_)));
+}
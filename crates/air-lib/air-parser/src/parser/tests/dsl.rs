@@ -17,6 +17,12 @@
 use crate::ast::*;
 use std::rc::Rc;
 
+/// Span of instructions built by dsl, `parse` resets spans of parsed instructions to it,
+/// except for spans of fold and new.
+pub(super) fn no_span() -> Span {
+    Span::new(0.into(), 0.into())
+}
+
 pub(super) fn call<'i>(
     peer_pk: ResolvableToPeerIdVariable<'i>,
     service_id: ResolvableToStringVariable<'i>,
@@ -34,19 +40,20 @@ pub(super) fn call<'i>(
         triplet,
         args,
         output,
+        span: no_span(),
     })
 }
 
 pub(super) fn seq<'i>(l: Instruction<'i>, r: Instruction<'i>) -> Instruction<'i> {
-    Instruction::Seq(Seq(Box::new(l), Box::new(r)))
+    Instruction::Seq(Seq(Box::new(l), Box::new(r), no_span()))
 }
 
 pub(super) fn par<'i>(l: Instruction<'i>, r: Instruction<'i>) -> Instruction<'i> {
-    Instruction::Par(Par(Box::new(l), Box::new(r)))
+    Instruction::Par(Par(Box::new(l), Box::new(r), no_span()))
 }
 
 pub(super) fn xor<'i>(l: Instruction<'i>, r: Instruction<'i>) -> Instruction<'i> {
-    Instruction::Xor(Xor(Box::new(l), Box::new(r)))
+    Instruction::Xor(Xor(Box::new(l), Box::new(r), no_span()))
 }
 
 pub(super) fn seqnn() -> Instruction<'static> {
@@ -66,30 +73,31 @@ pub(super) fn new<'i>(
 }
 
 pub(super) fn never() -> Instruction<'static> {
-    Instruction::Never(Never)
+    Instruction::Never(Never::new(no_span()))
 }
 
 pub(super) fn null() -> Instruction<'static> {
-    Instruction::Null(Null)
+    Instruction::Null(Null::new(no_span()))
 }
 
 pub(super) fn fail_scalar(scalar: Scalar) -> Instruction<'_> {
-    Instruction::Fail(Fail::Scalar(scalar))
+    Instruction::Fail(Fail::new(FailArgument::Scalar(scalar), no_span()))
 }
 
 pub(super) fn fail_scalar_wl(scalar: ScalarWithLambda) -> Instruction<'_> {
-    Instruction::Fail(Fail::ScalarWithLambda(scalar))
+    Instruction::Fail(Fail::new(FailArgument::ScalarWithLambda(scalar), no_span()))
 }
 
 pub(super) fn fail_literals(ret_code: i64, error_message: &str) -> Instruction<'_> {
-    Instruction::Fail(Fail::Literal {
+    let argument = FailArgument::Literal {
         ret_code,
-        error_message,
-    })
+        error_message: error_message.into(),
+    };
+    Instruction::Fail(Fail::new(argument, no_span()))
 }
 
 pub(super) fn fail_last_error() -> Instruction<'static> {
-    Instruction::Fail(Fail::LastError)
+    Instruction::Fail(Fail::new(FailArgument::LastError, no_span()))
 }
 
 pub(super) fn fold_scalar_variable<'i>(
@@ -171,6 +179,22 @@ pub(super) fn fold_stream<'i>(
     })
 }
 
+pub(super) fn fold_stream_map<'i>(
+    iterable: StreamMap<'i>,
+    iterator: Scalar<'i>,
+    instruction: Instruction<'i>,
+    last_instruction: Option<Instruction<'i>>,
+    span: Span,
+) -> Instruction<'i> {
+    Instruction::FoldStreamMap(FoldStreamMap {
+        iterable,
+        iterator,
+        instruction: Rc::new(instruction),
+        last_instruction: last_instruction.map(Rc::new),
+        span,
+    })
+}
+
 pub(super) fn match_<'i>(
     left_value: ImmutableValue<'i>,
     right_value: ImmutableValue<'i>,
@@ -180,6 +204,7 @@ pub(super) fn match_<'i>(
         left_value,
         right_value,
         instruction: Box::new(instruction),
+        span: no_span(),
     })
 }
 
@@ -192,11 +217,20 @@ pub(super) fn mismatch<'i>(
         left_value,
         right_value,
         instruction: Box::new(instruction),
+        span: no_span(),
     })
 }
 
 pub(super) fn ap<'i>(argument: ApArgument<'i>, result: ApResult<'i>) -> Instruction<'i> {
-    Instruction::Ap(Ap { argument, result })
+    Instruction::Ap(Ap::new(argument, result, no_span()))
+}
+
+pub(super) fn ap_map<'i>(
+    key: StreamMapKeyClause<'i>,
+    value: ApArgument<'i>,
+    map: StreamMap<'i>,
+) -> Instruction<'i> {
+    Instruction::ApMap(ApMap::new(key, value, map, no_span()))
 }
 
 pub(super) fn canon<'i>(
@@ -208,6 +242,20 @@ pub(super) fn canon<'i>(
         peer_id: peer_pk,
         stream,
         canon_stream,
+        span: no_span(),
+    })
+}
+
+pub(super) fn canon_map<'i>(
+    peer_pk: ResolvableToPeerIdVariable<'i>,
+    stream_map: StreamMap<'i>,
+    scalar: Scalar<'i>,
+) -> Instruction<'i> {
+    Instruction::CanonMap(CanonMap {
+        peer_id: peer_pk,
+        stream_map,
+        scalar,
+        span: no_span(),
     })
 }
 
@@ -221,3 +269,18 @@ pub(super) fn binary_instruction<'i, 'b>(
         _ => unreachable!(),
     }
 }
+
+pub(super) fn comment(text: &str, position: usize) -> Comment<'_> {
+    Comment::new(
+        text,
+        Span::new(position.into(), (position + text.len()).into()),
+    )
+}
+
+pub(super) fn commented<'i>(
+    leading: Vec<Comment<'i>>,
+    instruction: Instruction<'i>,
+    trailing: Vec<Comment<'i>>,
+) -> Instruction<'i> {
+    Instruction::Commented(Commented::new(leading, Box::new(instruction), trailing))
+}
@@ -43,8 +43,15 @@ fn fold_with_undefined_iterable() {
     let parser = crate::AIRParser::new();
     let mut errors = Vec::new();
     let mut validator = crate::parser::VariableValidator::new();
+    let mut comments = crate::parser::CommentsCollector::new();
     parser
-        .parse(source_code, &mut errors, &mut validator, lexer)
+        .parse(
+            source_code,
+            &mut errors,
+            &mut validator,
+            &mut comments,
+            lexer,
+        )
         .expect("parser shouldn't fail");
 
     let errors = validator.finalize();
@@ -82,8 +89,15 @@ fn fold_with_undefined_variable() {
     let parser = crate::AIRParser::new();
     let mut errors = Vec::new();
     let mut validator = crate::parser::VariableValidator::new();
+    let mut comments = crate::parser::CommentsCollector::new();
     parser
-        .parse(source_code, &mut errors, &mut validator, lexer)
+        .parse(
+            source_code,
+            &mut errors,
+            &mut validator,
+            &mut comments,
+            lexer,
+        )
         .expect("parser shouldn't fail");
 
     let errors = validator.finalize();
@@ -121,8 +135,15 @@ fn scalar_fold_with_multiple_nexts_inside() {
     let parser = crate::AIRParser::new();
     let mut errors = Vec::new();
     let mut validator = crate::parser::VariableValidator::new();
+    let mut comments = crate::parser::CommentsCollector::new();
     parser
-        .parse(source_code, &mut errors, &mut validator, lexer)
+        .parse(
+            source_code,
+            &mut errors,
+            &mut validator,
+            &mut comments,
+            lexer,
+        )
         .expect("parser shouldn't fail");
 
     let errors = validator.finalize();
@@ -168,8 +189,15 @@ fn multiple_scalar_folds_with_same_iterator() {
     let parser = crate::AIRParser::new();
     let mut errors = Vec::new();
     let mut validator = crate::parser::VariableValidator::new();
+    let mut comments = crate::parser::CommentsCollector::new();
     parser
-        .parse(source_code, &mut errors, &mut validator, lexer)
+        .parse(
+            source_code,
+            &mut errors,
+            &mut validator,
+            &mut comments,
+            lexer,
+        )
         .expect("parser shouldn't fail");
 
     let errors = validator.finalize();
@@ -196,8 +224,15 @@ fn stream_fold_with_multiple_nexts_inside() {
     let parser = crate::AIRParser::new();
     let mut errors = Vec::new();
     let mut validator = crate::parser::VariableValidator::new();
+    let mut comments = crate::parser::CommentsCollector::new();
     parser
-        .parse(source_code, &mut errors, &mut validator, lexer)
+        .parse(
+            source_code,
+            &mut errors,
+            &mut validator,
+            &mut comments,
+            lexer,
+        )
         .expect("parser shouldn't fail");
 
     let errors = validator.finalize();
@@ -243,8 +278,15 @@ fn parse_fold_with_multiple_iterator() {
     let parser = crate::AIRParser::new();
     let mut errors = Vec::new();
     let mut validator = crate::parser::VariableValidator::new();
+    let mut comments = crate::parser::CommentsCollector::new();
     parser
-        .parse(source_code, &mut errors, &mut validator, lexer)
+        .parse(
+            source_code,
+            &mut errors,
+            &mut validator,
+            &mut comments,
+            lexer,
+        )
         .expect("parser shouldn't fail");
 
     let errors = validator.finalize();
@@ -309,7 +351,7 @@ fn fold_json_path() {
     "#;
 
     let instruction = parse(source_code);
-    let expected = fold_scalar_variable_wl(
+    let fold = fold_scalar_variable_wl(
         ScalarWithLambda::from_raw_lambda(
             "members",
             vec![ValueAccessor::ArrayAccess { idx: 123321 }],
@@ -320,6 +362,11 @@ fn fold_json_path() {
         None,
         Span::new(27.into(), 61.into()),
     );
+    let expected = commented(
+        vec![comment("; comment", 9)],
+        fold,
+        vec![comment(";;; comment", 62), comment(";;; comment", 82)],
+    );
     assert_eq!(instruction, expected);
 }
 
@@ -405,7 +452,7 @@ fn comments() {
         ;;; comme;?!.$.  nt[][][][()()()null;$::!
     "#;
     let instruction = parse(source_code);
-    let expected = fold_scalar_variable_wl(
+    let fold = fold_scalar_variable_wl(
         ScalarWithLambda::from_raw_lambda(
             "members",
             vec![
@@ -421,6 +468,14 @@ fn comments() {
         None,
         Span::new(27.into(), 61.into()),
     );
+    let expected = commented(
+        vec![comment("; comment", 9)],
+        fold,
+        vec![
+            comment(";;; comment ;;?()()", 62),
+            comment(";;; comme;?!.$.  nt[][][][()()()null;$::!", 90),
+        ],
+    );
     assert_eq!(instruction, expected);
 }
 
@@ -445,3 +500,20 @@ fn parse_fold_with_xor_par_seq() {
         assert_eq!(instruction, expected);
     }
 }
+
+#[test]
+fn fold_on_stream_map() {
+    let source_code = r#"
+        (fold %stream_map iterator (null))
+    "#;
+
+    let instruction = parse(source_code);
+    let expected = fold_stream_map(
+        StreamMap::new("%stream_map", 15.into()),
+        Scalar::new("iterator", 27.into()),
+        null(),
+        None,
+        Span::new(9.into(), 43.into()),
+    );
+    assert_eq!(instruction, expected);
+}
@@ -17,6 +17,7 @@
 mod ap;
 mod call;
 mod canon;
+mod comments;
 mod dsl;
 mod fail;
 mod fold;
@@ -26,20 +27,93 @@ mod new;
 mod null;
 mod par;
 mod seq;
+mod spans;
 
-use crate::ast::Instruction;
+use crate::ast::*;
 use crate::parser::AIRParser;
 
+use std::rc::Rc;
+
 thread_local!(static TEST_PARSER: AIRParser = AIRParser::new());
 
 fn parse(source_code: &str) -> Instruction {
+    let mut instruction = parse_with_spans(source_code);
+    reset_spans(&mut instruction);
+    instruction
+}
+
+fn parse_with_spans(source_code: &str) -> Instruction {
     *TEST_PARSER.with(|parser| {
         let mut errors = Vec::new();
         let lexer = crate::parser::AIRLexer::new(source_code);
         let mut validator = crate::parser::VariableValidator::new();
+        let mut comments = crate::parser::CommentsCollector::new();
+        let tokens = comments.collect(source_code, lexer);
 
         parser
-            .parse(source_code, &mut errors, &mut validator, lexer)
+            .parse(
+                source_code,
+                &mut errors,
+                &mut validator,
+                &mut comments,
+                tokens,
+            )
             .expect("parsing should be successful")
     })
 }
+
+/// Expected ASTs are built by dsl without spans of most instructions, these spans are checked
+/// in the spans module instead. Spans of fold and new are a part of expected ASTs.
+fn reset_spans(instruction: &mut Instruction<'_>) {
+    let span = dsl::no_span();
+
+    match instruction {
+        Instruction::Call(call) => call.span = span,
+        Instruction::Ap(ap) => ap.span = span,
+        Instruction::ApMap(ap_map) => ap_map.span = span,
+        Instruction::Canon(canon) => canon.span = span,
+        Instruction::CanonMap(canon_map) => canon_map.span = span,
+        Instruction::Seq(Seq(left, right, instruction_span))
+        | Instruction::Par(Par(left, right, instruction_span))
+        | Instruction::Xor(Xor(left, right, instruction_span)) => {
+            *instruction_span = span;
+            reset_spans(left);
+            reset_spans(right);
+        }
+        Instruction::Match(match_) => {
+            match_.span = span;
+            reset_spans(&mut match_.instruction);
+        }
+        Instruction::MisMatch(mismatch) => {
+            mismatch.span = span;
+            reset_spans(&mut mismatch.instruction);
+        }
+        Instruction::Fail(fail) => fail.span = span,
+        Instruction::FoldScalar(fold) => {
+            reset_fold_spans(&mut fold.instruction, &mut fold.last_instruction)
+        }
+        Instruction::FoldStream(fold) => {
+            reset_fold_spans(&mut fold.instruction, &mut fold.last_instruction)
+        }
+        Instruction::FoldStreamMap(fold) => {
+            reset_fold_spans(&mut fold.instruction, &mut fold.last_instruction)
+        }
+        Instruction::Never(never) => never.span = span,
+        Instruction::New(new) => reset_spans(&mut new.instruction),
+        Instruction::Next(next) => next.span = span,
+        Instruction::Null(null) => null.span = span,
+        Instruction::Commented(commented) => reset_spans(&mut commented.instruction),
+        Instruction::Error => {}
+    }
+}
+
+fn reset_fold_spans<'i>(
+    instruction: &mut Rc<Instruction<'i>>,
+    last_instruction: &mut Option<Rc<Instruction<'i>>>,
+) {
+    let instructions = std::iter::once(instruction).chain(last_instruction.as_mut());
+    for instruction in instructions {
+        let instruction = Rc::get_mut(instruction).expect("parsed AST isn't shared");
+        reset_spans(instruction);
+    }
+}
@@ -87,8 +87,15 @@ fn iterators_cant_be_restricted() {
     let parser = crate::AIRParser::new();
     let mut errors = Vec::new();
     let mut validator = crate::parser::VariableValidator::new();
+    let mut comments = crate::parser::CommentsCollector::new();
     parser
-        .parse(source_code, &mut errors, &mut validator, lexer)
+        .parse(
+            source_code,
+            &mut errors,
+            &mut validator,
+            &mut comments,
+            lexer,
+        )
         .expect("parser shouldn't fail");
 
     let errors = validator.finalize();
@@ -39,11 +39,11 @@ fn parse_seq() {
             CallOutputValue::Scalar(Scalar::new("output", 75.into())),
         ),
         call(
-            ResolvableToPeerIdVariable::Literal("peer_id"),
-            ResolvableToStringVariable::Literal("service_id"),
-            ResolvableToStringVariable::Literal("function_name"),
+            ResolvableToPeerIdVariable::Literal("peer_id".into()),
+            ResolvableToStringVariable::Literal("service_id".into()),
+            ResolvableToStringVariable::Literal("function_name".into()),
             Rc::new(vec![
-                ImmutableValue::Literal("hello"),
+                ImmutableValue::Literal("hello".into()),
                 ImmutableValue::EmptyArray,
                 ImmutableValue::Variable(ImmutableVariable::scalar("name", 154.into())),
             ]),
@@ -76,18 +76,18 @@ fn parse_seq_seq() {
             ),
             call(
                 ResolvableToPeerIdVariable::Scalar(Scalar::new("peer_id", 114.into())),
-                ResolvableToStringVariable::Literal("service_B"),
+                ResolvableToStringVariable::Literal("service_B".into()),
                 ResolvableToStringVariable::Scalar(Scalar::new("function_name", 135.into())),
                 Rc::new(vec![]),
                 CallOutputValue::None,
             ),
         ),
         call(
-            ResolvableToPeerIdVariable::Literal("peer_id"),
-            ResolvableToStringVariable::Literal("service_id"),
-            ResolvableToStringVariable::Literal("function_name"),
+            ResolvableToPeerIdVariable::Literal("peer_id".into()),
+            ResolvableToStringVariable::Literal("service_id".into()),
+            ResolvableToStringVariable::Literal("function_name".into()),
             Rc::new(vec![
-                ImmutableValue::Literal("hello"),
+                ImmutableValue::Literal("hello".into()),
                 ImmutableValue::Variable(ImmutableVariable::scalar("name", 236.into())),
             ]),
             CallOutputValue::Stream(Stream::new("$output", 242.into())),
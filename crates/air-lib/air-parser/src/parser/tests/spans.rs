@@ -0,0 +1,174 @@
+/*
+ * Copyright 2023 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use super::parse_with_spans;
+use crate::ast::*;
+
+/// Collects texts of all instructions in the order of a depth-first traversal.
+fn instruction_texts<'s>(source_code: &'s str, instruction: &Instruction<'_>) -> Vec<&'s str> {
+    fn walk<'s>(source_code: &'s str, instruction: &Instruction<'_>, texts: &mut Vec<&'s str>) {
+        if let Instruction::Commented(commented) = instruction {
+            return walk(source_code, &commented.instruction, texts);
+        }
+
+        let span = instruction.span().unwrap();
+        texts.push(&source_code[span.left.into()..span.right.into()]);
+
+        let children: Vec<&Instruction<'_>> = match instruction {
+            Instruction::Seq(Seq(left, right, _))
+            | Instruction::Par(Par(left, right, _))
+            | Instruction::Xor(Xor(left, right, _)) => vec![left, right],
+            Instruction::Match(match_) => vec![&match_.instruction],
+            Instruction::MisMatch(mismatch) => vec![&mismatch.instruction],
+            Instruction::FoldScalar(fold) => std::iter::once(&*fold.instruction)
+                .chain(fold.last_instruction.as_deref())
+                .collect(),
+            Instruction::FoldStream(fold) => vec![&fold.instruction],
+            Instruction::FoldStreamMap(fold) => vec![&fold.instruction],
+            Instruction::New(new) => vec![&new.instruction],
+            _ => vec![],
+        };
+        for child in children {
+            walk(source_code, child, texts);
+        }
+    }
+
+    let mut texts = vec![];
+    walk(source_code, instruction, &mut texts);
+    texts
+}
+
+#[test]
+fn every_instruction_has_span() {
+    let source_code = r#"
+        (seq
+            (par
+                (call "peer" ("service" "function") [] scalar)
+                (seq
+                    (ap scalar $stream)
+                    (ap ("key" scalar) %map)))
+            (xor
+                (seq
+                    (match scalar 1
+                        (fail 1 "error"))
+                    (mismatch scalar 1
+                        (canon "peer" $stream #canon)))
+                (new $other
+                    (seq
+                        (canon "peer" %map canon_map)
+                        (seq
+                            (fold #canon i
+                                (next i)
+                                (never))
+                            (seq
+                                (fold $stream j (null))
+                                (fold %map k (fail %last_error%))))))))
+        "#;
+
+    let instruction = parse_with_spans(source_code);
+    let expected = vec![
+        source_code[source_code.find("(seq").unwrap()..].trim_end(),
+        r#"(par
+                (call "peer" ("service" "function") [] scalar)
+                (seq
+                    (ap scalar $stream)
+                    (ap ("key" scalar) %map)))"#,
+        r#"(call "peer" ("service" "function") [] scalar)"#,
+        r#"(seq
+                    (ap scalar $stream)
+                    (ap ("key" scalar) %map))"#,
+        "(ap scalar $stream)",
+        r#"(ap ("key" scalar) %map)"#,
+        source_code[source_code.find("(xor").unwrap()..]
+            .trim_end()
+            .strip_suffix(')')
+            .unwrap(),
+        r#"(seq
+                    (match scalar 1
+                        (fail 1 "error"))
+                    (mismatch scalar 1
+                        (canon "peer" $stream #canon)))"#,
+        r#"(match scalar 1
+                        (fail 1 "error"))"#,
+        r#"(fail 1 "error")"#,
+        r#"(mismatch scalar 1
+                        (canon "peer" $stream #canon))"#,
+        r#"(canon "peer" $stream #canon)"#,
+        source_code[source_code.find("(new").unwrap()..]
+            .trim_end()
+            .strip_suffix("))")
+            .unwrap(),
+        r#"(seq
+                        (canon "peer" %map canon_map)
+                        (seq
+                            (fold #canon i
+                                (next i)
+                                (never))
+                            (seq
+                                (fold $stream j (null))
+                                (fold %map k (fail %last_error%)))))"#,
+        r#"(canon "peer" %map canon_map)"#,
+        r#"(seq
+                            (fold #canon i
+                                (next i)
+                                (never))
+                            (seq
+                                (fold $stream j (null))
+                                (fold %map k (fail %last_error%))))"#,
+        r#"(fold #canon i
+                                (next i)
+                                (never))"#,
+        "(next i)",
+        "(never)",
+        r#"(seq
+                                (fold $stream j (null))
+                                (fold %map k (fail %last_error%)))"#,
+        "(fold $stream j (null))",
+        "(null)",
+        "(fold %map k (fail %last_error%))",
+        "(fail %last_error%)",
+    ];
+
+    assert_eq!(instruction_texts(source_code, &instruction), expected);
+}
+
+#[test]
+fn commented_instruction_span_excludes_comments() {
+    let source_code = "(seq ;; first\n (null) ;; second\n (never))";
+
+    let instruction = parse_with_spans(source_code);
+    assert_eq!(
+        instruction_texts(source_code, &instruction),
+        vec![source_code, "(null)", "(never)"]
+    );
+}
+
+#[test]
+fn source_position_counts_lines_and_characters() {
+    use crate::SourcePosition;
+
+    let source_code = "(seq\n    (null) ;; ☃\n    (never))";
+
+    let never = source_code.find("(never)").unwrap();
+    assert_eq!(
+        SourcePosition::from_air_pos(source_code, never.into()),
+        SourcePosition::new(3, 5)
+    );
+
+    let after_snowman = source_code.find('☃').unwrap() + '☃'.len_utf8();
+    let position = SourcePosition::from_air_pos(source_code, after_snowman.into());
+    assert_eq!(position.to_string(), "2:16");
+}
@@ -85,6 +85,11 @@ impl<'i> VariableValidator<'i> {
         self.met_variable_name_definition(canon.canon_stream.name, span);
     }
 
+    // the same as for canon, a stream map isn't checked to be defined
+    pub(super) fn met_canon_map(&mut self, canon_map: &CanonMap<'i>, span: Span) {
+        self.met_variable_name_definition(canon_map.scalar.name, span);
+    }
+
     pub(super) fn met_match(&mut self, match_: &Match<'i>, span: Span) {
         self.met_matchable(&match_.left_value, span);
         self.met_matchable(&match_.right_value, span);
@@ -112,6 +117,11 @@ impl<'i> VariableValidator<'i> {
         self.met_iterator_definition(&fold.iterator, span);
     }
 
+    pub(super) fn meet_fold_stream_map(&mut self, fold: &FoldStreamMap<'i>, span: Span) {
+        self.met_variable_name(fold.iterable.name, span);
+        self.met_iterator_definition(&fold.iterator, span);
+    }
+
     pub(super) fn met_new(&mut self, new: &New<'i>, span: Span) {
         self.not_iterators_candidates
             .push((new.argument.name(), span));
@@ -129,7 +139,18 @@ impl<'i> VariableValidator<'i> {
     }
 
     pub(super) fn met_ap(&mut self, ap: &Ap<'i>, span: Span) {
-        match &ap.argument {
+        self.met_ap_argument(&ap.argument, span);
+        self.met_variable_name_definition(ap.result.name(), span);
+    }
+
+    pub(super) fn met_ap_map(&mut self, ap_map: &ApMap<'i>, span: Span) {
+        self.met_stream_map_key(&ap_map.key, span);
+        self.met_ap_argument(&ap_map.value, span);
+        self.met_variable_name_definition(ap_map.map.name, span);
+    }
+
+    fn met_ap_argument(&mut self, argument: &ApArgument<'i>, span: Span) {
+        match argument {
             ApArgument::Number(_)
             | ApArgument::Timestamp
             | ApArgument::TTL
@@ -145,7 +166,6 @@ impl<'i> VariableValidator<'i> {
                 self.met_canon_stream_wl(canon_stream, span)
             }
         }
-        self.met_variable_name_definition(ap.result.name(), span);
     }
 
     pub(super) fn finalize(self) -> Vec<ErrorRecovery<AirPos, Token<'i>, ParserError>> {
@@ -164,6 +184,17 @@ impl<'i> VariableValidator<'i> {
         }
     }
 
+    fn met_stream_map_key(&mut self, key: &StreamMapKeyClause<'i>, span: Span) {
+        use StreamMapKeyClause::*;
+
+        match key {
+            Literal(_) | Int(_) => {}
+            Scalar(scalar) => self.met_scalar(scalar, span),
+            ScalarWithLambda(scalar) => self.met_scalar_wl(scalar, span),
+            CanonStreamWithLambda(stream) => self.met_canon_stream_wl(stream, span),
+        }
+    }
+
     fn met_peer_id_resolvable_value(
         &mut self,
         variable: &ResolvableToPeerIdVariable<'i>,
@@ -250,6 +281,7 @@ impl<'i> VariableValidator<'i> {
                     self.met_variable_name(scalar_name, span)
                 }
                 ValueAccessor::ArrayAccess { .. }
+                | ValueAccessor::ArraySlice { .. }
                 | ValueAccessor::FieldAccessByName { .. }
                 | ValueAccessor::Error => {}
             }
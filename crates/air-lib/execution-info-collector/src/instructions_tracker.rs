@@ -23,6 +23,8 @@ use std::collections::HashMap;
 /// current peer (executed) and overall number (seen) of met instructions of such type.
 #[derive(Default, Debug, PartialEq, Eq)]
 pub struct InstructionTracker {
+    /// Overall number of executed instructions of all types.
+    pub instructions_count: u64,
     pub ap: ApTracker,
     pub call: CallTracker,
     pub fold: FoldTracker,
@@ -69,6 +71,12 @@ pub struct NewTracker {
 }
 
 impl InstructionTracker {
+    /// Counts any executed instruction and returns the overall count.
+    pub fn meet_instruction(&mut self) -> u64 {
+        self.instructions_count += 1;
+        self.instructions_count
+    }
+
     pub fn meet_ap(&mut self) {
         self.ap.seen_count += 1;
     }
@@ -26,6 +26,10 @@
     unreachable_patterns
 )]
 
+mod serialization_format;
+
+pub use serialization_format::*;
+
 use serde::Deserialize;
 use serde::Serialize;
 
@@ -41,6 +45,10 @@ impl<T: ?Sized> CID<T> {
         Self(cid.into(), PhantomData)
     }
 
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
     pub fn into_inner(self) -> String {
         self.0
     }
@@ -73,50 +81,12 @@ impl<T: ?Sized> From<CID<T>> for String {
     }
 }
 
-// TODO we might refactor this to `SerializationFormat` trait
-// that both transform data to binary/text form (be it JSON, CBOR or something else)
-// and produces CID too
+/// Calculate a CID of JSON-serialized data.
 pub fn json_data_cid<Val: ?Sized>(data: &[u8]) -> CID<Val> {
-    use cid::Cid;
-    use multihash::{Code, MultihashDigest};
-
-    // the Sha2_256 is current IPFS default hash
-    let digest = Code::Sha2_256.digest(data);
-    // seems to be better than RAW_CODEC = 0x55
-    const JSON_CODEC: u64 = 0x0200;
-
-    let cid = Cid::new_v1(JSON_CODEC, digest);
-    CID::new(cid.to_string())
-}
-
-pub struct CidCalculationError(serde_json::Error);
-
-impl fmt::Debug for CidCalculationError {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt::Debug::fmt(&self.0, f)
-    }
-}
-
-impl fmt::Display for CidCalculationError {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt::Display::fmt(&self.0, f)
-    }
-}
-
-impl From<serde_json::Error> for CidCalculationError {
-    fn from(source: serde_json::Error) -> Self {
-        Self(source)
-    }
-}
-
-impl std::error::Error for CidCalculationError {
-    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
-        Some(&self.0)
-    }
+    JsonFormat.data_cid(data)
 }
 
 /// Calculate a CID of JSON-serialized value.
 pub fn value_to_json_cid<Val: Serialize>(value: &Val) -> Result<CID<Val>, CidCalculationError> {
-    let data = serde_json::to_vec(value)?;
-    Ok(json_data_cid(&data))
+    JsonFormat.value_to_cid(value)
 }
@@ -0,0 +1,212 @@
+/*
+ * Copyright 2023 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use crate::CID;
+
+use cid::Cid;
+use multihash::Code;
+use multihash::MultihashDigest;
+use serde::Serialize;
+
+use std::collections::TryReserveError;
+use std::convert::TryFrom;
+use std::fmt;
+
+/// Multicodec code of JSON, it seems to be better than RAW_CODEC = 0x55.
+pub const JSON_CODEC: u64 = 0x0200;
+
+/// Multicodec code of DAG-CBOR.
+pub const DAG_CBOR_CODEC: u64 = 0x71;
+
+/// Transforms values to their binary form and produces CIDs of it.
+pub trait SerializationFormat {
+    /// Multicodec code of the serialized data.
+    fn codec(&self) -> u64;
+
+    /// Multihash function used to calculate a digest of the serialized data.
+    fn hash_code(&self) -> Code;
+
+    fn to_vec<Val: Serialize + ?Sized>(&self, value: &Val) -> Result<Vec<u8>, CidCalculationError>;
+
+    /// Calculate a CID of data already serialized with this format.
+    fn data_cid<Val: ?Sized>(&self, data: &[u8]) -> CID<Val> {
+        let digest = self.hash_code().digest(data);
+        let cid = Cid::new_v1(self.codec(), digest);
+        CID::new(cid.to_string())
+    }
+
+    /// Calculate a CID of a value serialized with this format.
+    fn value_to_cid<Val: Serialize + ?Sized>(
+        &self,
+        value: &Val,
+    ) -> Result<CID<Val>, CidCalculationError> {
+        let data = self.to_vec(value)?;
+        Ok(self.data_cid(&data))
+    }
+}
+
+/// JSON hashed with SHA2-256, the only format used by data versions <= 0.7.0.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct JsonFormat;
+
+impl SerializationFormat for JsonFormat {
+    fn codec(&self) -> u64 {
+        JSON_CODEC
+    }
+
+    fn hash_code(&self) -> Code {
+        // the Sha2_256 is current IPFS default hash
+        Code::Sha2_256
+    }
+
+    fn to_vec<Val: Serialize + ?Sized>(&self, value: &Val) -> Result<Vec<u8>, CidCalculationError> {
+        Ok(serde_json::to_vec(value)?)
+    }
+}
+
+/// DAG-CBOR hashed with Blake3, it's more compact and faster to compute than JSON.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct DagCborFormat;
+
+impl SerializationFormat for DagCborFormat {
+    fn codec(&self) -> u64 {
+        DAG_CBOR_CODEC
+    }
+
+    fn hash_code(&self) -> Code {
+        Code::Blake3_256
+    }
+
+    fn to_vec<Val: Serialize + ?Sized>(&self, value: &Val) -> Result<Vec<u8>, CidCalculationError> {
+        Ok(serde_ipld_dagcbor::to_vec(value)?)
+    }
+}
+
+/// All formats supported by the interpreter, it's intended to be chosen in runtime
+/// according to a data version.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum CidFormat {
+    #[default]
+    Json,
+    DagCbor,
+}
+
+impl CidFormat {
+    /// Determines a format that was used to produce the supplied CID,
+    /// returns None if CID is malformed or produced by an unknown format.
+    pub fn from_cid<Val: ?Sized>(cid: &CID<Val>) -> Option<Self> {
+        let cid = Cid::try_from(cid.as_str()).ok()?;
+        let hash_code = Code::try_from(cid.hash().code()).ok()?;
+
+        [Self::Json, Self::DagCbor]
+            .iter()
+            .copied()
+            .find(|format| format.codec() == cid.codec() && format.hash_code() == hash_code)
+    }
+}
+
+impl SerializationFormat for CidFormat {
+    fn codec(&self) -> u64 {
+        match self {
+            Self::Json => JsonFormat.codec(),
+            Self::DagCbor => DagCborFormat.codec(),
+        }
+    }
+
+    fn hash_code(&self) -> Code {
+        match self {
+            Self::Json => JsonFormat.hash_code(),
+            Self::DagCbor => DagCborFormat.hash_code(),
+        }
+    }
+
+    fn to_vec<Val: Serialize + ?Sized>(&self, value: &Val) -> Result<Vec<u8>, CidCalculationError> {
+        match self {
+            Self::Json => JsonFormat.to_vec(value),
+            Self::DagCbor => DagCborFormat.to_vec(value),
+        }
+    }
+}
+
+pub enum CidCalculationError {
+    Json(serde_json::Error),
+    DagCbor(serde_ipld_dagcbor::EncodeError<TryReserveError>),
+}
+
+impl fmt::Debug for CidCalculationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Json(error) => fmt::Debug::fmt(error, f),
+            Self::DagCbor(error) => fmt::Debug::fmt(error, f),
+        }
+    }
+}
+
+impl fmt::Display for CidCalculationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Json(error) => fmt::Display::fmt(error, f),
+            Self::DagCbor(error) => fmt::Display::fmt(error, f),
+        }
+    }
+}
+
+impl From<serde_json::Error> for CidCalculationError {
+    fn from(source: serde_json::Error) -> Self {
+        Self::Json(source)
+    }
+}
+
+impl From<serde_ipld_dagcbor::EncodeError<TryReserveError>> for CidCalculationError {
+    fn from(source: serde_ipld_dagcbor::EncodeError<TryReserveError>) -> Self {
+        Self::DagCbor(source)
+    }
+}
+
+impl std::error::Error for CidCalculationError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Json(error) => Some(error),
+            Self::DagCbor(error) => Some(error),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn json_format_is_compatible() {
+        let cid: CID<serde_json::Value> = JsonFormat.value_to_cid(&json!("test")).unwrap();
+        assert_eq!(
+            cid,
+            CID::new("bagaaierajwlhumardpzj6dv2ahcerm3vyfrjwl7nahg7zq5o3eprwv6v3vpa")
+        );
+        assert_eq!(CidFormat::from_cid(&cid), Some(CidFormat::Json));
+    }
+
+    #[test]
+    fn dag_cbor_format_detected() {
+        let value = json!({"b": 1, "a": [1, 2, 3]});
+        let cid: CID<serde_json::Value> = CidFormat::DagCbor.value_to_cid(&value).unwrap();
+
+        assert_ne!(cid, JsonFormat.value_to_cid(&value).unwrap());
+        assert_eq!(CidFormat::from_cid(&cid), Some(CidFormat::DagCbor));
+        assert_eq!(CidFormat::from_cid(&CID::<()>::new("not a cid")), None);
+    }
+}
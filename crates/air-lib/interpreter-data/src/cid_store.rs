@@ -16,11 +16,13 @@
 
 use crate::JValue;
 
-use air_interpreter_cid::value_to_json_cid;
 use air_interpreter_cid::CidCalculationError;
+use air_interpreter_cid::CidFormat;
+use air_interpreter_cid::SerializationFormat;
 use air_interpreter_cid::CID;
 use serde::Deserialize;
 use serde::Serialize;
+use thiserror::Error as ThisError;
 
 use std::{collections::HashMap, rc::Rc};
 
@@ -45,6 +47,45 @@ impl<Val> CidStore<Val> {
     pub fn len(&self) -> usize {
         self.0.len()
     }
+
+    /// Retains only values whose CIDs satisfy the predicate.
+    pub fn retain(&mut self, mut predicate: impl FnMut(&CID<Val>) -> bool) {
+        self.0.retain(|cid, _| predicate(cid))
+    }
+}
+
+impl<Val: Serialize> CidStore<Val> {
+    /// Checks that every value in the store hashes to its CID,
+    /// a CID is recalculated with the same format it was produced with,
+    /// and this format should be supported by the supplied data version.
+    pub fn verify(&self, data_version: &semver::Version) -> Result<(), CidStoreVerificationError> {
+        for (cid, value) in &self.0 {
+            let format = CidFormat::from_cid(cid).ok_or_else(|| {
+                CidStoreVerificationError::UnknownCidFormat(cid.as_str().to_string())
+            })?;
+            if !crate::is_cid_format_supported(format, data_version) {
+                return Err(CidStoreVerificationError::UnsupportedCidFormat {
+                    cid: cid.as_str().to_string(),
+                    data_version: data_version.clone(),
+                });
+            }
+            let actual_cid = format.value_to_cid(&**value).map_err(|error| {
+                CidStoreVerificationError::CidCalculationFailed {
+                    cid: cid.as_str().to_string(),
+                    error,
+                }
+            })?;
+
+            if actual_cid != **cid {
+                return Err(CidStoreVerificationError::ValueMismatch {
+                    expected_cid: cid.as_str().to_string(),
+                    actual_cid: actual_cid.into_inner(),
+                });
+            }
+        }
+
+        Ok(())
+    }
 }
 
 impl<Val> Default for CidStore<Val> {
@@ -53,9 +94,37 @@ impl<Val> Default for CidStore<Val> {
     }
 }
 
+/// Errors happened during verification of a CID store.
+#[derive(Debug, ThisError)]
+pub enum CidStoreVerificationError {
+    #[error("CID '{0}' is malformed or produced by an unknown format")]
+    UnknownCidFormat(String),
+
+    #[error("failed to calculate CID of a value stored by '{cid}': {error}")]
+    CidCalculationFailed {
+        cid: String,
+        error: CidCalculationError,
+    },
+
+    #[error("value stored by CID '{expected_cid}' has CID '{actual_cid}'")]
+    ValueMismatch {
+        expected_cid: String,
+        actual_cid: String,
+    },
+
+    #[error("CID '{cid}' is produced by a format unsupported by data version {data_version}")]
+    UnsupportedCidFormat {
+        cid: String,
+        data_version: semver::Version,
+    },
+}
+
 #[derive(Clone, Debug)]
 pub struct CidTracker<Val = JValue> {
     cids: HashMap<Rc<CID<Val>>, Rc<Val>>,
+    /// Format used to calculate CIDs of newly recorded values,
+    /// values obtained from stores keep CIDs they were received with.
+    format: CidFormat,
 }
 
 impl<Val> CidTracker<Val> {
@@ -63,18 +132,33 @@ impl<Val> CidTracker<Val> {
         Self::default()
     }
 
-    pub fn from_cid_stores(prev_cid_map: CidStore<Val>, current_cid_map: CidStore<Val>) -> Self {
+    pub fn with_format(format: CidFormat) -> Self {
+        Self {
+            cids: Default::default(),
+            format,
+        }
+    }
+
+    pub fn from_cid_stores(
+        prev_cid_map: CidStore<Val>,
+        current_cid_map: CidStore<Val>,
+        format: CidFormat,
+    ) -> Self {
         let mut cids = prev_cid_map.0;
         for (cid, val) in current_cid_map.0 {
-            // TODO check that values matches?
+            // values from untrusted data could be checked in advance with `CidStore::verify`
             cids.insert(cid, val);
         }
-        Self { cids }
+        Self { cids, format }
     }
 
     pub fn get(&self, cid: &CID<Val>) -> Option<Rc<Val>> {
         self.cids.get(cid).cloned()
     }
+
+    pub fn format(&self) -> CidFormat {
+        self.format
+    }
 }
 
 impl<Val: Serialize> CidTracker<Val> {
@@ -83,7 +167,7 @@ impl<Val: Serialize> CidTracker<Val> {
         value: impl Into<Rc<Val>>,
     ) -> Result<Rc<CID<Val>>, CidCalculationError> {
         let value = value.into();
-        let cid = Rc::new(value_to_json_cid(&*value)?);
+        let cid = Rc::new(self.format.value_to_cid(&*value)?);
         self.cids.insert(cid.clone(), value);
         Ok(cid)
     }
@@ -91,9 +175,7 @@ impl<Val: Serialize> CidTracker<Val> {
 
 impl<Val> Default for CidTracker<Val> {
     fn default() -> Self {
-        Self {
-            cids: Default::default(),
-        }
+        Self::with_format(crate::data_cid_format())
     }
 }
 
@@ -122,7 +204,7 @@ mod tests {
 
     #[test]
     fn test_iter() {
-        let mut tracker = CidTracker::new();
+        let mut tracker = CidTracker::with_format(CidFormat::Json);
         tracker.record_value(json!("test")).unwrap();
         tracker.record_value(json!(1)).unwrap();
         tracker.record_value(json!([1, 2, 3])).unwrap();
@@ -164,7 +246,7 @@ mod tests {
 
     #[test]
     fn test_store() {
-        let mut tracker = CidTracker::new();
+        let mut tracker = CidTracker::with_format(CidFormat::Json);
         tracker.record_value(json!("test")).unwrap();
         tracker.record_value(json!(1)).unwrap();
         tracker.record_value(json!([1, 2, 3])).unwrap();
@@ -210,4 +292,70 @@ mod tests {
 
         assert_eq!(store.get(&CID::new("loremimpsumdolorsitament")), None,);
     }
+
+    #[test]
+    fn test_verify() {
+        let mut tracker = CidTracker::with_format(CidFormat::Json);
+        tracker.record_value(json!("test")).unwrap();
+        let mut dag_cbor_tracker = CidTracker::with_format(CidFormat::DagCbor);
+        dag_cbor_tracker.record_value(json!([1, 2, 3])).unwrap();
+
+        let store = CidStore::from(CidTracker::from_cid_stores(
+            tracker.into(),
+            dag_cbor_tracker.into(),
+            CidFormat::DagCbor,
+        ));
+        let dag_cbor_version = semver::Version::new(0, 8, 0);
+        assert!(store.verify(&dag_cbor_version).is_ok());
+
+        let mut fake_store = CidStore::new();
+        fake_store.0.insert(
+            CID::new("bagaaierajwlhumardpzj6dv2ahcerm3vyfrjwl7nahg7zq5o3eprwv6v3vpa").into(),
+            json!("fake").into(),
+        );
+        assert!(matches!(
+            fake_store.verify(crate::data_version()),
+            Err(CidStoreVerificationError::ValueMismatch { .. })
+        ));
+
+        let mut malformed_store = CidStore::new();
+        malformed_store.0.insert(
+            CID::new("loremimpsumdolorsitament").into(),
+            json!("test").into(),
+        );
+        assert!(matches!(
+            malformed_store.verify(crate::data_version()),
+            Err(CidStoreVerificationError::UnknownCidFormat(_))
+        ));
+    }
+
+    #[test]
+    fn test_verify_by_data_version() {
+        let old_version = semver::Version::new(0, 7, 0);
+        let new_version = semver::Version::new(0, 8, 0);
+
+        let mut tracker = CidTracker::with_format(crate::cid_format_for_version(&old_version));
+        tracker.record_value(json!("test")).unwrap();
+        let old_store = CidStore::from(tracker);
+        assert!(old_store.verify(&old_version).is_ok());
+        assert!(old_store.verify(&new_version).is_ok());
+
+        let mut dag_cbor_tracker = CidTracker::with_format(CidFormat::DagCbor);
+        dag_cbor_tracker.record_value(json!("test")).unwrap();
+        let new_store = CidStore::from(dag_cbor_tracker);
+        assert!(new_store.verify(&new_version).is_ok());
+        assert!(matches!(
+            new_store.verify(&old_version),
+            Err(CidStoreVerificationError::UnsupportedCidFormat { .. })
+        ));
+    }
+
+    #[test]
+    fn test_dag_cbor_tracker() {
+        let mut tracker = CidTracker::with_format(CidFormat::DagCbor);
+        let cid = tracker.record_value(json!({"key": 42})).unwrap();
+
+        assert_eq!(CidFormat::from_cid(&cid), Some(CidFormat::DagCbor));
+        assert_eq!(&*tracker.get(&cid).unwrap(), &json!({"key": 42}));
+    }
 }
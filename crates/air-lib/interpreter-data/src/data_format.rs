@@ -0,0 +1,172 @@
+/*
+ * Copyright 2023 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use thiserror::Error as ThisError;
+
+/// Marks data encoded with a binary format. JSON data always starts with `{` (possibly preceded
+/// by whitespaces), so this prefix can't be confused with the legacy JSON encoding.
+const BINARY_MAGIC: &[u8; 4] = b"\x00AQV";
+
+/// Size of the binary header length field that follows the format marker.
+const HEADER_LEN_SIZE: usize = std::mem::size_of::<u32>();
+
+/// Format of serialized interpreter data.
+///
+/// Binary formats are laid out as
+/// `BINARY_MAGIC | format tag (u8) | header length (u32, LE) | header | payload`,
+/// where the header contains only `Versions`, so it could be read without decoding the whole payload.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DataFormat {
+    /// Legacy text format, data is a plain JSON object without any marker.
+    #[default]
+    Json,
+
+    /// MessagePack with named struct fields.
+    MsgPack,
+}
+
+#[derive(Debug, ThisError)]
+pub enum DataSerializationError {
+    #[error(transparent)]
+    Json(#[from] serde_json::Error),
+
+    #[error(transparent)]
+    MsgPack(#[from] rmp_serde::encode::Error),
+}
+
+#[derive(Debug, ThisError)]
+pub enum DataDeserializationError {
+    #[error(transparent)]
+    Json(#[from] serde_json::Error),
+
+    #[error(transparent)]
+    MsgPack(#[from] rmp_serde::decode::Error),
+
+    #[error("unknown data format tag {0}")]
+    UnknownFormatTag(u8),
+
+    #[error("binary data is truncated: expected at least {expected} bytes, but got {actual}")]
+    TruncatedData { expected: usize, actual: usize },
+}
+
+impl DataFormat {
+    /// Detects format of supplied data by its marker.
+    pub fn detect(data: &[u8]) -> Result<Self, DataDeserializationError> {
+        if !data.starts_with(BINARY_MAGIC) {
+            return Ok(Self::Json);
+        }
+
+        match data.get(BINARY_MAGIC.len()) {
+            Some(&tag) => Self::from_tag(tag),
+            None => Err(DataDeserializationError::TruncatedData {
+                expected: BINARY_MAGIC.len() + 1,
+                actual: data.len(),
+            }),
+        }
+    }
+
+    fn from_tag(tag: u8) -> Result<Self, DataDeserializationError> {
+        match tag {
+            1 => Ok(Self::MsgPack),
+            tag => Err(DataDeserializationError::UnknownFormatTag(tag)),
+        }
+    }
+
+    fn tag(&self) -> u8 {
+        match self {
+            Self::Json => 0,
+            Self::MsgPack => 1,
+        }
+    }
+
+    /// Serializes a value together with its header, header is ignored for JSON,
+    /// since the legacy format keeps everything in one object.
+    pub(crate) fn to_vec<H: Serialize, V: Serialize>(
+        self,
+        header: &H,
+        value: &V,
+    ) -> Result<Vec<u8>, DataSerializationError> {
+        match self {
+            Self::Json => Ok(serde_json::to_vec(value)?),
+            Self::MsgPack => {
+                let header = rmp_serde::to_vec_named(header)?;
+                let payload = rmp_serde::to_vec_named(value)?;
+
+                let mut data = Vec::with_capacity(
+                    BINARY_MAGIC.len() + 1 + HEADER_LEN_SIZE + header.len() + payload.len(),
+                );
+                data.extend_from_slice(BINARY_MAGIC);
+                data.push(self.tag());
+                data.extend_from_slice(&(header.len() as u32).to_le_bytes());
+                data.extend_from_slice(&header);
+                data.extend_from_slice(&payload);
+
+                Ok(data)
+            }
+        }
+    }
+
+    /// Deserializes the whole value, format is detected by the data marker.
+    pub(crate) fn from_slice<V: DeserializeOwned>(
+        data: &[u8],
+    ) -> Result<V, DataDeserializationError> {
+        match Self::detect(data)? {
+            Self::Json => Ok(serde_json::from_slice(data)?),
+            Self::MsgPack => {
+                let (_, payload) = split_binary(data)?;
+                Ok(rmp_serde::from_slice(payload)?)
+            }
+        }
+    }
+
+    /// Deserializes only a header part of data, for JSON it's deserialized from the whole object.
+    pub(crate) fn header_from_slice<H: DeserializeOwned>(
+        data: &[u8],
+    ) -> Result<H, DataDeserializationError> {
+        match Self::detect(data)? {
+            Self::Json => Ok(serde_json::from_slice(data)?),
+            Self::MsgPack => {
+                let (header, _) = split_binary(data)?;
+                Ok(rmp_serde::from_slice(header)?)
+            }
+        }
+    }
+}
+
+/// Splits binary data into header and payload parts.
+fn split_binary(data: &[u8]) -> Result<(&[u8], &[u8]), DataDeserializationError> {
+    let header_start = BINARY_MAGIC.len() + 1 + HEADER_LEN_SIZE;
+    if data.len() < header_start {
+        return Err(DataDeserializationError::TruncatedData {
+            expected: header_start,
+            actual: data.len(),
+        });
+    }
+
+    let mut header_len = [0u8; HEADER_LEN_SIZE];
+    header_len.copy_from_slice(&data[header_start - HEADER_LEN_SIZE..header_start]);
+    let header_end = header_start + u32::from_le_bytes(header_len) as usize;
+    if data.len() < header_end {
+        return Err(DataDeserializationError::TruncatedData {
+            expected: header_end,
+            actual: data.len(),
+        });
+    }
+
+    Ok((&data[header_start..header_end], &data[header_end..]))
+}
@@ -180,6 +180,9 @@ pub struct FoldResult {
 pub struct ApResult {
     #[serde(rename = "gens")]
     pub res_generations: Vec<u32>,
+
+    #[serde(default, skip_serializing_if = "StreamKind::is_stream")]
+    pub kind: StreamKind,
 }
 
 /// Contains ids of element that were on a stream at the moment of an appropriate canon call.
@@ -188,6 +191,20 @@ pub struct ApResult {
 pub struct CanonResult {
     pub tetraplet: Rc<CID<SecurityTetraplet>>,
     pub values: Vec<Rc<CID<CanonCidAggregate>>>,
+
+    #[serde(default, skip_serializing_if = "StreamKind::is_stream")]
+    pub kind: StreamKind,
+}
+
+/// A kind of a stream ap and canon states are produced for. Stream maps are kept as streams
+/// of key-value entries, the kind prevents merging their states with states of ordinary streams.
+/// It's omitted for ordinary streams, so data produced before stream maps keeps its form.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StreamKind {
+    #[default]
+    Stream,
+    StreamMap,
 }
 
 /// The type Canon trace CID refers to.
@@ -90,8 +90,13 @@ impl ExecutedState {
 
 impl ApResult {
     pub fn new(res_generation: u32) -> Self {
+        Self::with_kind(res_generation, StreamKind::Stream)
+    }
+
+    pub fn with_kind(res_generation: u32, kind: StreamKind) -> Self {
         Self {
             res_generations: vec![res_generation],
+            kind,
         }
     }
 }
@@ -101,7 +106,34 @@ impl CanonResult {
         tetraplet: Rc<CID<SecurityTetraplet>>,
         values: Vec<Rc<CID<CanonCidAggregate>>>,
     ) -> Self {
-        Self { tetraplet, values }
+        Self::with_kind(tetraplet, values, StreamKind::Stream)
+    }
+
+    pub fn with_kind(
+        tetraplet: Rc<CID<SecurityTetraplet>>,
+        values: Vec<Rc<CID<CanonCidAggregate>>>,
+        kind: StreamKind,
+    ) -> Self {
+        Self {
+            tetraplet,
+            values,
+            kind,
+        }
+    }
+}
+
+impl StreamKind {
+    pub fn is_stream(&self) -> bool {
+        matches!(self, Self::Stream)
+    }
+}
+
+impl std::fmt::Display for StreamKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Stream => write!(f, "stream"),
+            Self::StreamMap => write!(f, "stream map"),
+        }
     }
 }
 
@@ -137,12 +169,18 @@ impl std::fmt::Display for ExecutedState {
                 }
                 write!(f, "     )")
             }
-            Ap(ap) => {
+            Ap(ap) if ap.kind.is_stream() => {
                 write!(f, "ap: _ -> {:?}", ap.res_generations)
             }
-            Canon(_) => {
+            Ap(ap) => {
+                write!(f, "ap {}: _ -> {:?}", ap.kind, ap.res_generations)
+            }
+            Canon(canon) if canon.kind.is_stream() => {
                 write!(f, "canon [<object>]")
             }
+            Canon(canon) => {
+                write!(f, "canon {} [<object>]", canon.kind)
+            }
         }
     }
 }
@@ -17,16 +17,27 @@
 use super::GlobalStreamGens;
 use super::RestrictedStreamGens;
 use crate::cid_store::CidStore;
+use crate::cid_store::CidStoreVerificationError;
+use crate::CallResult;
 use crate::CanonCidAggregate;
+use crate::DataDeserializationError;
+use crate::DataFormat;
+use crate::DataSerializationError;
+use crate::ExecutedState;
 use crate::ExecutionTrace;
 use crate::JValue;
 use crate::ServiceResultAggregate;
+use crate::ValueRef;
 
+use air_interpreter_signatures::PeerContributions;
+use air_interpreter_signatures::SignatureStore;
+use air_interpreter_signatures::Signer;
 use air_utils::measure;
 use polyplets::SecurityTetraplet;
 
 use serde::Deserialize;
 use serde::Serialize;
+use thiserror::Error as ThisError;
 
 /// The AIR interpreter could be considered as a function
 /// f(prev_data: InterpreterData, current_data: InterpreterData, ... ) -> (result_data: InterpreterData, ...).
@@ -60,6 +71,10 @@ pub struct InterpreterData {
 
     /// CID-to-somethings mappings.
     pub cid_info: CidInfo,
+
+    /// Signatures of peers over CIDs of call results they produced.
+    #[serde(default)]
+    pub signatures: SignatureStore,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -83,6 +98,7 @@ impl InterpreterData {
             last_call_request_id: 0,
             restricted_streams: RestrictedStreamGens::new(),
             cid_info: <_>::default(),
+            signatures: <_>::default(),
         }
     }
 
@@ -104,21 +120,77 @@ impl InterpreterData {
             last_call_request_id,
             restricted_streams,
             cid_info,
+            signatures: <_>::default(),
+        }
+    }
+
+    /// Collects CIDs of call results attributed to peers by their tetraplets.
+    pub fn peer_contributions(&self) -> PeerContributions {
+        let mut contributions = PeerContributions::new();
+
+        for state in self.trace.iter() {
+            let cid = match state {
+                ExecutedState::Call(CallResult::Executed(ValueRef::Scalar(cid)))
+                | ExecutedState::Call(CallResult::Executed(ValueRef::Stream { cid, .. }))
+                | ExecutedState::Call(CallResult::Failed(cid)) => cid,
+                _ => continue,
+            };
+
+            // dangling CIDs are reported while resolving them during execution
+            let tetraplet =
+                self.cid_info
+                    .service_result_store
+                    .get(cid)
+                    .and_then(|service_result| {
+                        self.cid_info
+                            .tetraplet_store
+                            .get(&service_result.tetraplet_cid)
+                    });
+            if let Some(tetraplet) = tetraplet {
+                contributions
+                    .entry(tetraplet.peer_pk.clone())
+                    .or_default()
+                    .insert(cid.as_str().to_string());
+            }
         }
+
+        contributions
     }
 
-    /// Tries to de InterpreterData from slice according to the data version.
-    pub fn try_from_slice(slice: &[u8]) -> Result<Self, serde_json::Error> {
+    /// Signs all call results attributed to a signer, it covers results produced
+    /// both in the last and in previous runs, since they are merged only on other peers.
+    pub fn sign_own_contributions(&mut self, signer: &(impl Signer + ?Sized)) {
+        let mut contributions = self.peer_contributions();
+        if let Some(own_cids) = contributions.remove(&signer.peer_id()) {
+            self.signatures.sign(own_cids, signer);
+        }
+    }
+
+    /// Tries to de InterpreterData from slice according to the data format marker.
+    pub fn try_from_slice(slice: &[u8]) -> Result<Self, DataDeserializationError> {
         measure!(
-            serde_json::from_slice(slice),
+            DataFormat::from_slice(slice),
             tracing::Level::INFO,
-            "serde_json::from_slice"
+            "DataFormat::from_slice"
         )
     }
 
-    /// Tries to de only versions part of interpreter data.
-    pub fn try_get_versions(slice: &[u8]) -> Result<Versions, serde_json::Error> {
-        serde_json::from_slice(slice)
+    /// Tries to de only versions part of interpreter data,
+    /// for binary formats it doesn't touch the rest of data.
+    pub fn try_get_versions(slice: &[u8]) -> Result<Versions, DataDeserializationError> {
+        DataFormat::header_from_slice(slice)
+    }
+
+    /// Serializes InterpreterData into the supplied format.
+    pub fn serialize_with_format(
+        &self,
+        format: DataFormat,
+    ) -> Result<Vec<u8>, DataSerializationError> {
+        measure!(
+            format.to_vec(&self.versions, self),
+            tracing::Level::INFO,
+            "DataFormat::to_vec"
+        )
     }
 }
 
@@ -146,6 +218,37 @@ pub struct CidInfo {
     pub service_result_store: CidStore<ServiceResultAggregate>,
 }
 
+impl CidInfo {
+    /// Checks that all values of all stores correspond to their CIDs
+    /// calculated with formats supported by the data version.
+    pub fn verify(&self, data_version: &semver::Version) -> Result<(), CidInfoVerificationError> {
+        let with_store = |store_name: &'static str| {
+            move |error: CidStoreVerificationError| CidInfoVerificationError { store_name, error }
+        };
+
+        self.value_store
+            .verify(data_version)
+            .map_err(with_store("value"))?;
+        self.tetraplet_store
+            .verify(data_version)
+            .map_err(with_store("tetraplet"))?;
+        self.canon_store
+            .verify(data_version)
+            .map_err(with_store("canon"))?;
+        self.service_result_store
+            .verify(data_version)
+            .map_err(with_store("service result aggregate"))
+    }
+}
+
+/// Describes a store that failed verification.
+#[derive(Debug, ThisError)]
+#[error("{store_name} store: {error}")]
+pub struct CidInfoVerificationError {
+    pub store_name: &'static str,
+    pub error: CidStoreVerificationError,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -191,4 +294,53 @@ mod tests {
         let data_0_6_0 = serde_json::from_slice::<InterpreterData0_6_0>(&data_0_6_1_se);
         assert!(data_0_6_0.is_ok());
     }
+
+    #[test]
+    fn json_data_without_marker() {
+        let data = InterpreterData::new(semver::Version::new(1, 1, 1));
+        let data_se = serde_json::to_vec(&data).unwrap();
+
+        assert_eq!(DataFormat::detect(&data_se).unwrap(), DataFormat::Json);
+        assert_eq!(
+            data.serialize_with_format(DataFormat::Json).unwrap(),
+            data_se
+        );
+
+        let data_de = InterpreterData::try_from_slice(&data_se).unwrap();
+        assert_eq!(
+            data_de.versions.interpreter_version,
+            semver::Version::new(1, 1, 1)
+        );
+    }
+
+    #[test]
+    fn msgpack_round_trip() {
+        let data = InterpreterData::new(semver::Version::new(1, 1, 1));
+        let data_se = data.serialize_with_format(DataFormat::MsgPack).unwrap();
+
+        assert_eq!(DataFormat::detect(&data_se).unwrap(), DataFormat::MsgPack);
+
+        let versions = InterpreterData::try_get_versions(&data_se).unwrap();
+        assert_eq!(versions.interpreter_version, semver::Version::new(1, 1, 1));
+        assert_eq!(&versions.data_version, crate::data_version());
+
+        let data_de = InterpreterData::try_from_slice(&data_se).unwrap();
+        assert_eq!(
+            data_de.versions.interpreter_version,
+            semver::Version::new(1, 1, 1)
+        );
+        assert_eq!(data_de.cid_info, data.cid_info);
+    }
+
+    #[test]
+    fn versions_readable_from_corrupted_msgpack_payload() {
+        let data = InterpreterData::new(semver::Version::new(1, 1, 1));
+        let mut data_se = data.serialize_with_format(DataFormat::MsgPack).unwrap();
+        let new_len = data_se.len() - 1;
+        data_se.truncate(new_len);
+
+        assert!(InterpreterData::try_from_slice(&data_se).is_err());
+        let versions = InterpreterData::try_get_versions(&data_se).unwrap();
+        assert_eq!(versions.interpreter_version, semver::Version::new(1, 1, 1));
+    }
 }
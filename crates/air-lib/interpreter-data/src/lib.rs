@@ -27,19 +27,24 @@
 )]
 
 mod cid_store;
+mod data_format;
 mod executed_state;
 mod interpreter_data;
+mod merge_conflict;
 mod stream_generations;
 mod trace;
 mod trace_pos;
 
 pub use cid_store::*;
+pub use data_format::*;
 pub use executed_state::*;
 pub use interpreter_data::*;
+pub use merge_conflict::*;
 pub use stream_generations::*;
 pub use trace::*;
 pub use trace_pos::*;
 
+use air_interpreter_cid::CidFormat;
 use once_cell::sync::Lazy;
 use serde_json::Value as JValue;
 
@@ -50,6 +55,32 @@ static DATA_FORMAT_VERSION: Lazy<semver::Version> = Lazy::new(|| {
         .expect("invalid data format version specified")
 });
 
+/// The first data version whose CIDs are calculated from DAG-CBOR with Blake3.
+static DAG_CBOR_CID_VERSION: Lazy<semver::Version> = Lazy::new(|| semver::Version::new(0, 8, 0));
+
 pub fn data_version() -> &'static semver::Version {
     Lazy::force(&DATA_FORMAT_VERSION)
 }
+
+/// Returns a format used to calculate CIDs for data of the supplied version.
+pub fn cid_format_for_version(data_version: &semver::Version) -> CidFormat {
+    if data_version >= Lazy::force(&DAG_CBOR_CID_VERSION) {
+        CidFormat::DagCbor
+    } else {
+        CidFormat::Json
+    }
+}
+
+/// Returns a format used to calculate CIDs for data produced by this crate.
+pub fn data_cid_format() -> CidFormat {
+    cid_format_for_version(data_version())
+}
+
+/// Checks that data of the supplied version could contain CIDs of the format,
+/// JSON CIDs are allowed in newer data since particles started with older interpreters keep them.
+pub fn is_cid_format_supported(format: CidFormat, data_version: &semver::Version) -> bool {
+    match format {
+        CidFormat::Json => true,
+        CidFormat::DagCbor => data_version >= Lazy::force(&DAG_CBOR_CID_VERSION),
+    }
+}
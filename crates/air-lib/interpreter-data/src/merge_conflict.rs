@@ -0,0 +1,174 @@
+/*
+ * Copyright 2023 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use crate::ExecutedState;
+use crate::JValue;
+use crate::TracePos;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+use std::fmt;
+
+/// Place in the previous and current traces where a merge error happened. It's returned
+/// in an interpreter outcome along with the error, so hosts could render a conflict report.
+///
+/// States are kept as serialized `ExecutedState`s, since the latter can't be sent between threads
+/// and outcomes are passed from interpreter threads to hosts.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MergeConflict {
+    /// Position of the state read from the previous trace.
+    pub prev_position: TracePos,
+
+    /// Position of the state read from the current trace.
+    pub current_position: TracePos,
+
+    /// The state read from the previous trace, None if its subtrace was exhausted.
+    pub prev_state: Option<JValue>,
+
+    /// The state read from the current trace, None if its subtrace was exhausted.
+    pub current_state: Option<JValue>,
+
+    /// Count of states left in the previous subtrace, including the read one.
+    pub prev_subtrace_len: usize,
+
+    /// Count of states left in the current subtrace, including the read one.
+    pub current_subtrace_len: usize,
+
+    /// Par and fold state machines that were active at the moment of the error.
+    pub active_fsms: Vec<ActiveFsm>,
+}
+
+/// A par or fold state machine in progress.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case", tag = "type")]
+pub enum ActiveFsm {
+    /// Sizes of the left and right subgraphs in both traces.
+    Par {
+        prev_par: (u32, u32),
+        current_par: (u32, u32),
+    },
+
+    /// Count of states occupied by the fold in both traces.
+    Fold {
+        fold_id: u32,
+        prev_states_count: usize,
+        current_states_count: usize,
+    },
+}
+
+/// Renders a human-readable report.
+impl fmt::Display for MergeConflict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_state(
+            f,
+            "previous",
+            self.prev_position,
+            &self.prev_state,
+            self.prev_subtrace_len,
+        )?;
+        write_state(
+            f,
+            "current",
+            self.current_position,
+            &self.current_state,
+            self.current_subtrace_len,
+        )?;
+
+        if self.active_fsms.is_empty() {
+            return writeln!(f, "no active par or fold");
+        }
+        writeln!(f, "active state machines, from the outermost:")?;
+        for fsm in &self.active_fsms {
+            writeln!(f, "  {fsm}")?;
+        }
+
+        Ok(())
+    }
+}
+
+fn write_state(
+    f: &mut fmt::Formatter<'_>,
+    trace_name: &str,
+    position: TracePos,
+    state: &Option<JValue>,
+    subtrace_len: usize,
+) -> fmt::Result {
+    let state = match state {
+        Some(state) => state,
+        None => return writeln!(f, "{trace_name} trace at {position}: subtrace is exhausted"),
+    };
+
+    write!(
+        f,
+        "{trace_name} trace at {position} (subtrace of {subtrace_len} states): "
+    )?;
+    // a state of an unknown format is printed as is
+    match ExecutedState::deserialize(state) {
+        Ok(state) => writeln!(f, "{state}"),
+        Err(_) => writeln!(f, "{state}"),
+    }
+}
+
+impl fmt::Display for ActiveFsm {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ActiveFsm::Par { prev_par, current_par } => write!(
+                f,
+                "par: previous ({}, {}), current ({}, {})",
+                prev_par.0, prev_par.1, current_par.0, current_par.1
+            ),
+            ActiveFsm::Fold {
+                fold_id,
+                prev_states_count,
+                current_states_count,
+            } => write!(
+                f,
+                "fold {fold_id}: previous {prev_states_count} states, current {current_states_count} states"
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ParResult;
+
+    #[test]
+    fn report_renders_serialized_states() {
+        let par = ExecutedState::Par(ParResult {
+            left_size: 1,
+            right_size: 2,
+        });
+        let conflict = MergeConflict {
+            prev_position: 0.into(),
+            current_position: 1.into(),
+            prev_state: Some(serde_json::to_value(par).unwrap()),
+            current_state: Some(serde_json::json!({"unknown": 42})),
+            prev_subtrace_len: 2,
+            current_subtrace_len: 3,
+            active_fsms: vec![],
+        };
+
+        let expected = "\
+previous trace at 0 (subtrace of 2 states): par(1, 2)
+current trace at 1 (subtrace of 3 states): {\"unknown\":42}
+no active par or fold
+";
+        assert_eq!(conflict.to_string(), expected);
+    }
+}
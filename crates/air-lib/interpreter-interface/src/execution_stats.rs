@@ -0,0 +1,68 @@
+/*
+ * Copyright 2023 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use serde::Deserialize;
+use serde::Serialize;
+
+/// Statistics of one interpreter run, they are collected only if requested in run parameters
+/// and intended to be exported by a host as per-particle metrics.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExecutionStats {
+    /// Counts of instructions met during the execution.
+    pub instructions: InstructionStats,
+
+    /// Lengths of traces participating in the execution.
+    pub trace: TraceStats,
+
+    /// Sizes of CID stores of the resulting data.
+    pub cid_stores: CidStoreStats,
+
+    /// Size of the resulting serialized data in bytes.
+    pub data_size: u64,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct InstructionStats {
+    /// Overall number of executed instructions of all types.
+    pub total_count: u64,
+    pub call_seen_count: u32,
+    pub call_executed_count: u32,
+    pub ap_seen_count: u32,
+    pub ap_executed_count: u32,
+    pub par_seen_count: u32,
+    pub fold_scalar_count: u32,
+    pub fold_stream_count: u32,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TraceStats {
+    /// Length of a trace from prev data.
+    pub prev_len: u64,
+
+    /// Length of a trace from current data.
+    pub current_len: u64,
+
+    /// Length of the resulting trace.
+    pub result_len: u64,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CidStoreStats {
+    pub value_count: u64,
+    pub tetraplet_count: u64,
+    pub canon_count: u64,
+    pub service_result_count: u64,
+}
@@ -43,6 +43,15 @@ pub struct InterpreterOutcome {
 
     /// Collected parameters of all met call instructions that could be executed on a current peer.
     pub call_requests: Vec<u8>,
+
+    /// Serialized `ExecutionStats`, empty if they weren't requested in run parameters.
+    pub stats: Vec<u8>,
+
+    /// Serialized `MergeConflict`, empty if the execution didn't fail on merging data.
+    pub merge_conflict: Vec<u8>,
+
+    /// Serialized `ErrorPosition` of the failed instruction, empty if it's unknown.
+    pub error_position: Vec<u8>,
 }
 
 impl InterpreterOutcome {
@@ -52,6 +61,8 @@ impl InterpreterOutcome {
         data: Vec<u8>,
         next_peer_pks: Vec<String>,
         call_requests: Vec<u8>,
+        stats: Vec<u8>,
+        merge_conflict: Vec<u8>,
     ) -> Self {
         Self {
             ret_code,
@@ -59,29 +70,50 @@ impl InterpreterOutcome {
             data,
             next_peer_pks,
             call_requests,
+            stats,
+            merge_conflict,
+            error_position: vec![],
         }
     }
 }
 
 #[cfg(feature = "marine")]
 impl InterpreterOutcome {
+    /// Outcomes of older interpreters lack trailing fields, they are left empty then:
+    /// the 5-field outcome has neither stats nor a merge conflict, the 6-field one has no merge conflict,
+    /// and the 7-field one has no error position.
     pub fn from_ivalue(ivalue: IValue) -> Result<Self, String> {
-        const OUTCOME_FIELDS_COUNT: usize = 5;
+        const MIN_OUTCOME_FIELDS_COUNT: usize = 5;
+        const OUTCOME_FIELDS_COUNT: usize = 8;
 
-        let mut record_values = try_as_record(ivalue)?.into_vec();
-        if record_values.len() != OUTCOME_FIELDS_COUNT {
+        let record_values = try_as_record(ivalue)?.into_vec();
+        if !(MIN_OUTCOME_FIELDS_COUNT..=OUTCOME_FIELDS_COUNT).contains(&record_values.len()) {
             return Err(format!(
-                "expected InterpreterOutcome struct with {OUTCOME_FIELDS_COUNT} fields, got {record_values:?}"
+                "expected InterpreterOutcome struct with {MIN_OUTCOME_FIELDS_COUNT} to {OUTCOME_FIELDS_COUNT} fields, got {record_values:?}"
             ));
         }
 
-        let call_requests = try_as_byte_vec(record_values.pop().unwrap(), "call_requests")?;
-        let next_peer_pks = try_as_string_vec(record_values.pop().unwrap(), "next_peer_pks")?;
-        let data = try_as_byte_vec(record_values.pop().unwrap(), "data")?;
-        let error_message = try_as_string(record_values.pop().unwrap(), "error_message")?;
-        let ret_code = try_as_i64(record_values.pop().unwrap(), "ret_code")?;
+        // the count was checked above, so mandatory fields are present
+        let mut record_values = record_values.into_iter();
+        let ret_code = try_as_i64(record_values.next().unwrap(), "ret_code")?;
+        let error_message = try_as_string(record_values.next().unwrap(), "error_message")?;
+        let data = try_as_byte_vec(record_values.next().unwrap(), "data")?;
+        let next_peer_pks = try_as_string_vec(record_values.next().unwrap(), "next_peer_pks")?;
+        let call_requests = try_as_byte_vec(record_values.next().unwrap(), "call_requests")?;
+        let stats = try_as_optional_byte_vec(record_values.next(), "stats")?;
+        let merge_conflict = try_as_optional_byte_vec(record_values.next(), "merge_conflict")?;
+        let error_position = try_as_optional_byte_vec(record_values.next(), "error_position")?;
 
-        let outcome = Self::new(ret_code, error_message, data, next_peer_pks, call_requests);
+        let outcome = Self {
+            ret_code,
+            error_message,
+            data,
+            next_peer_pks,
+            call_requests,
+            stats,
+            merge_conflict,
+            error_position,
+        };
 
         Ok(outcome)
     }
@@ -134,6 +166,13 @@ fn try_as_byte_vec(ivalue: IValue, field_name: &str) -> Result<Vec<u8>, String>
     Ok(byte_vec)
 }
 
+#[cfg(feature = "marine")]
+fn try_as_optional_byte_vec(ivalue: Option<IValue>, field_name: &str) -> Result<Vec<u8>, String> {
+    ivalue
+        .map(|ivalue| try_as_byte_vec(ivalue, field_name))
+        .unwrap_or_else(|| Ok(vec![]))
+}
+
 #[cfg(feature = "marine")]
 fn try_as_string_vec(ivalue: IValue, field_name: &str) -> Result<Vec<String>, String> {
     match ivalue {
@@ -151,3 +190,75 @@ fn try_as_string_vec(ivalue: IValue, field_name: &str) -> Result<Vec<String>, St
         v => Err(format!("expected an array for {field_name}, got {v:?}")),
     }
 }
+
+#[cfg(all(test, feature = "marine"))]
+mod tests {
+    use super::*;
+
+    fn outcome_ivalue(fields_count: usize) -> IValue {
+        let fields = vec![
+            IValue::S64(INTERPRETER_SUCCESS),
+            IValue::String("error".to_string()),
+            IValue::ByteArray(b"data".to_vec()),
+            IValue::Array(vec![IValue::String("peer".to_string())]),
+            IValue::ByteArray(b"call_requests".to_vec()),
+            IValue::ByteArray(b"stats".to_vec()),
+            IValue::ByteArray(b"merge_conflict".to_vec()),
+            IValue::ByteArray(b"error_position".to_vec()),
+            IValue::ByteArray(b"unknown".to_vec()),
+        ];
+        let fields = fields.into_iter().take(fields_count).collect();
+
+        IValue::Record(NEVec::new(fields).unwrap())
+    }
+
+    fn expected_outcome(
+        stats: &[u8],
+        merge_conflict: &[u8],
+        error_position: &[u8],
+    ) -> InterpreterOutcome {
+        InterpreterOutcome {
+            ret_code: INTERPRETER_SUCCESS,
+            error_message: "error".to_string(),
+            data: b"data".to_vec(),
+            next_peer_pks: vec!["peer".to_string()],
+            call_requests: b"call_requests".to_vec(),
+            stats: stats.to_vec(),
+            merge_conflict: merge_conflict.to_vec(),
+            error_position: error_position.to_vec(),
+        }
+    }
+
+    #[test]
+    fn outcome_without_stats_and_merge_conflict() {
+        let outcome = InterpreterOutcome::from_ivalue(outcome_ivalue(5)).unwrap();
+        assert_eq!(outcome, expected_outcome(b"", b"", b""));
+    }
+
+    #[test]
+    fn outcome_without_merge_conflict() {
+        let outcome = InterpreterOutcome::from_ivalue(outcome_ivalue(6)).unwrap();
+        assert_eq!(outcome, expected_outcome(b"stats", b"", b""));
+    }
+
+    #[test]
+    fn outcome_without_error_position() {
+        let outcome = InterpreterOutcome::from_ivalue(outcome_ivalue(7)).unwrap();
+        assert_eq!(outcome, expected_outcome(b"stats", b"merge_conflict", b""));
+    }
+
+    #[test]
+    fn full_outcome() {
+        let outcome = InterpreterOutcome::from_ivalue(outcome_ivalue(8)).unwrap();
+        assert_eq!(
+            outcome,
+            expected_outcome(b"stats", b"merge_conflict", b"error_position")
+        );
+    }
+
+    #[test]
+    fn outcome_with_unexpected_fields_count() {
+        assert!(InterpreterOutcome::from_ivalue(outcome_ivalue(4)).is_err());
+        assert!(InterpreterOutcome::from_ivalue(outcome_ivalue(9)).is_err());
+    }
+}
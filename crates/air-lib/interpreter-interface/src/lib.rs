@@ -28,10 +28,14 @@
 
 mod call_request_parameters;
 mod call_service_result;
+mod error_position;
+mod execution_stats;
 mod interpreter_outcome;
 mod run_parameters;
 
 pub use call_request_parameters::*;
 pub use call_service_result::*;
+pub use error_position::*;
+pub use execution_stats::*;
 pub use interpreter_outcome::*;
 pub use run_parameters::*;
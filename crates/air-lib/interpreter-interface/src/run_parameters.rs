@@ -25,7 +25,7 @@ use serde::Serialize;
 
 /// Parameters that a host side should pass to an interpreter and that necessary for execution.
 #[cfg_attr(feature = "marine", marine)]
-#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Default, Clone, Eq, PartialEq, Serialize, Deserialize)]
 pub struct RunParameters {
     /// Peer id of a peer that start this particle.
     pub init_peer_id: String,
@@ -39,20 +39,61 @@ pub struct RunParameters {
 
     /// TTL set by init peer id in milliseconds.
     pub ttl: u32,
+
+    /// Set by a host that signs call results of a current peer through the `sign` import
+    /// of the interpreter, then all incoming call results are required to be signed.
+    #[serde(default)]
+    pub require_signatures: bool,
+
+    /// Maximum number of instructions an interpreter could execute in one run,
+    /// 0 means that there is no limit.
+    #[serde(default)]
+    pub instruction_limit: u64,
+
+    /// Current unix timestamp of a host in milliseconds used to check whether a particle
+    /// is expired, 0 means that it's unknown and the check is skipped.
+    #[serde(default)]
+    pub current_time: u64,
+
+    /// If set, the interpreter collects execution statistics and returns them in the outcome.
+    #[serde(default)]
+    pub collect_stats: bool,
 }
 
 impl RunParameters {
+    /// Creates parameters of a particle, the rest of parameters are default: signatures
+    /// aren't required, there is no instruction limit and expiration isn't checked.
     pub fn new(init_peer_id: String, current_peer_id: String, timestamp: u64, ttl: u32) -> Self {
         Self {
             init_peer_id,
             current_peer_id,
             timestamp,
             ttl,
+            ..<_>::default()
         }
     }
 
     #[cfg(feature = "marine")]
     pub fn into_ivalue(self) -> IValue {
+        let run_parameters = vec![
+            IValue::String(self.init_peer_id),
+            IValue::String(self.current_peer_id),
+            IValue::U64(self.timestamp),
+            IValue::U32(self.ttl),
+            IValue::Boolean(self.require_signatures),
+            IValue::U64(self.instruction_limit),
+            IValue::U64(self.current_time),
+            IValue::Boolean(self.collect_stats),
+        ];
+        // unwrap is safe here because run_parameters is non-empty array
+        let run_parameters = NEVec::new(run_parameters).unwrap();
+        IValue::Record(run_parameters)
+    }
+
+    /// Encodes only particle parameters, interpreters before 0.40.0 expect a record of them
+    /// and don't know about the rest of fields, so they neither verify signatures nor apply limits.
+    #[cfg(feature = "marine")]
+    pub fn into_legacy_ivalue(self) -> IValue {
         let run_parameters = vec![
             IValue::String(self.init_peer_id),
             IValue::String(self.current_peer_id),
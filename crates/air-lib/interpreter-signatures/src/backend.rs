@@ -0,0 +1,31 @@
+/*
+ * Copyright 2023 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use crate::KeyError;
+
+/// Produces signatures on behalf of a peer.
+pub trait Signer {
+    /// Id of a peer whose public key verifies produced signatures.
+    fn peer_id(&self) -> String;
+
+    fn sign(&self, message: &[u8]) -> Vec<u8>;
+}
+
+/// Checks signatures against a public key derived from a peer id.
+pub trait SignatureVerifier {
+    /// Returns an error if a public key can't be derived from the peer id.
+    fn verify(&self, peer_id: &str, message: &[u8], signature: &[u8]) -> Result<bool, KeyError>;
+}
@@ -0,0 +1,57 @@
+/*
+ * Copyright 2023 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use libp2p_identity::DecodingError;
+use libp2p_identity::ParseError;
+use thiserror::Error as ThisError;
+
+/// Errors happened while loading a key pair.
+#[derive(Debug, ThisError)]
+pub enum KeyError {
+    #[error("secret key is malformed: {0}")]
+    MalformedSecretKey(DecodingError),
+
+    #[error("peer id '{peer_id}' is malformed: {error}")]
+    MalformedPeerId { peer_id: String, error: ParseError },
+
+    #[error("peer id '{0}' is a hash of a public key, so the key can't be derived from it")]
+    KeylessPeerId(String),
+
+    #[error("public key of peer id '{peer_id}' can't be decoded: {error}")]
+    MalformedPublicKey {
+        peer_id: String,
+        error: DecodingError,
+    },
+}
+
+/// Errors happened while verifying signatures of peer contributions.
+#[derive(Debug, ThisError)]
+pub enum SignatureVerificationError {
+    #[error("signature of peer '{peer_id}' isn't a valid base58 string: {error}")]
+    MalformedSignature {
+        peer_id: String,
+        error: bs58::decode::Error,
+    },
+
+    #[error("signature of peer '{peer_id}' can't be verified: {error}")]
+    UnverifiableSignature { peer_id: String, error: KeyError },
+
+    #[error("signature of peer '{peer_id}' over {cids:?} is invalid")]
+    InvalidSignature { peer_id: String, cids: Vec<String> },
+
+    #[error("state with CID '{cid}' produced by peer '{peer_id}' isn't signed")]
+    UnsignedContribution { peer_id: String, cid: String },
+}
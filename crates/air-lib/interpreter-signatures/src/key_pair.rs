@@ -0,0 +1,122 @@
+/*
+ * Copyright 2023 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use crate::public_key_from_peer_id;
+use crate::KeyError;
+use crate::SignatureVerifier;
+use crate::Signer;
+
+use libp2p_identity::ed25519;
+use libp2p_identity::PublicKey;
+
+/// Ed25519 key pair of a peer, its peer id is a libp2p one embedding the public key.
+#[derive(Debug, Clone)]
+pub struct KeyPair(ed25519::Keypair);
+
+impl KeyPair {
+    pub fn from_secret_key_bytes(secret_key_bytes: &[u8]) -> Result<Self, KeyError> {
+        // the key is zeroized while decoding, so a copy is passed
+        let secret = ed25519::SecretKey::try_from_bytes(secret_key_bytes.to_vec())
+            .map_err(KeyError::MalformedSecretKey)?;
+
+        Ok(Self(ed25519::Keypair::from(secret)))
+    }
+
+    pub fn secret_key_bytes(&self) -> Vec<u8> {
+        self.0.secret().as_ref().to_vec()
+    }
+
+    pub fn public_key(&self) -> PublicKey {
+        PublicKey::from(self.0.public())
+    }
+}
+
+impl Signer for KeyPair {
+    fn peer_id(&self) -> String {
+        self.public_key().to_peer_id().to_base58()
+    }
+
+    fn sign(&self, message: &[u8]) -> Vec<u8> {
+        self.0.sign(message)
+    }
+}
+
+/// Verifies signatures with a public key extracted from a peer id,
+/// an algorithm is chosen by the key type.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct PeerIdVerifier;
+
+impl SignatureVerifier for PeerIdVerifier {
+    fn verify(&self, peer_id: &str, message: &[u8], signature: &[u8]) -> Result<bool, KeyError> {
+        let public_key = public_key_from_peer_id(peer_id)?;
+        Ok(public_key.verify(message, signature))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sign_and_verify() {
+        let key_pair = KeyPair::from_secret_key_bytes(&[42; 32]).unwrap();
+        let peer_id = key_pair.peer_id();
+        let signature = key_pair.sign(b"message");
+
+        assert!(PeerIdVerifier
+            .verify(&peer_id, b"message", &signature)
+            .unwrap());
+        assert!(!PeerIdVerifier
+            .verify(&peer_id, b"other message", &signature)
+            .unwrap());
+
+        let other_peer_id = KeyPair::from_secret_key_bytes(&[43; 32]).unwrap().peer_id();
+        assert!(!PeerIdVerifier
+            .verify(&other_peer_id, b"message", &signature)
+            .unwrap());
+
+        let restored = KeyPair::from_secret_key_bytes(&key_pair.secret_key_bytes()).unwrap();
+        assert_eq!(restored.peer_id(), peer_id);
+    }
+
+    #[test]
+    fn peer_id_is_libp2p_one() {
+        let key_pair = KeyPair::from_secret_key_bytes(&[42; 32]).unwrap();
+        let peer_id = key_pair.peer_id();
+
+        // all libp2p peer ids of Ed25519 keys share this prefix
+        assert!(peer_id.starts_with("12D3KooW"), "{}", peer_id);
+        assert_eq!(
+            public_key_from_peer_id(&peer_id).unwrap(),
+            key_pair.public_key()
+        );
+    }
+
+    #[test]
+    fn keys_are_extracted_only_from_identity_peer_ids() {
+        // an RSA peer id is a SHA-256 multihash of the key
+        let rsa_peer_id = "QmYyQSo1c1Ym7orWxLYvCrM2EmxFTANf8wXmmE7DWjhx5N";
+        assert!(matches!(
+            public_key_from_peer_id(rsa_peer_id),
+            Err(KeyError::KeylessPeerId(_))
+        ));
+
+        assert!(matches!(
+            public_key_from_peer_id("peer_id"),
+            Err(KeyError::MalformedPeerId { .. })
+        ));
+    }
+}
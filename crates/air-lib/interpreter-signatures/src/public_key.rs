@@ -0,0 +1,46 @@
+/*
+ * Copyright 2023 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use crate::KeyError;
+
+use libp2p_identity::PeerId;
+use libp2p_identity::PublicKey;
+
+use std::str::FromStr;
+
+/// Multihash code of the identity hash, peer ids of short public keys embed them with it.
+const IDENTITY_MULTIHASH_CODE: u64 = 0x00;
+
+/// Extracts a public key from a libp2p peer id. Peer ids of long keys, e.g. RSA ones,
+/// are hashes of them, so keys can't be restored from such peer ids.
+pub fn public_key_from_peer_id(peer_id: &str) -> Result<PublicKey, KeyError> {
+    let parsed_peer_id = PeerId::from_str(peer_id).map_err(|error| KeyError::MalformedPeerId {
+        peer_id: peer_id.to_string(),
+        error,
+    })?;
+
+    let multihash = parsed_peer_id.as_ref();
+    if multihash.code() != IDENTITY_MULTIHASH_CODE {
+        return Err(KeyError::KeylessPeerId(peer_id.to_string()));
+    }
+
+    PublicKey::try_decode_protobuf(multihash.digest()).map_err(|error| {
+        KeyError::MalformedPublicKey {
+            peer_id: peer_id.to_string(),
+            error,
+        }
+    })
+}
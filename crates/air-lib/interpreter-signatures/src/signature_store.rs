@@ -0,0 +1,328 @@
+/*
+ * Copyright 2023 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use crate::SignatureVerificationError;
+use crate::SignatureVerifier;
+use crate::Signer;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+
+/// CIDs of states produced by each peer, a state is attributed to a peer by its tetraplet.
+pub type PeerContributions = BTreeMap<String, BTreeSet<String>>;
+
+/// Set of CIDs signed by a peer in one of its runs.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SignedCids {
+    /// Sorted CIDs of states produced by a peer.
+    pub cids: Vec<String>,
+
+    /// Base58-encoded signature of the serialized `cids`.
+    pub signature: String,
+}
+
+/// Maps a peer id to all sets of CIDs signed by this peer.
+///
+/// A peer could produce states in several runs on different par branches, and these states
+/// are merged only on other peers, so it's impossible to keep one signature per peer.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct SignatureStore(BTreeMap<String, Vec<SignedCids>>);
+
+impl SignatureStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Merges signatures from prev and current data.
+    pub fn from_stores(prev_store: Self, current_store: Self) -> Self {
+        let mut store = prev_store;
+        for (peer_id, signed_cids) in current_store.0 {
+            let peer_signatures = store.0.entry(peer_id).or_default();
+            for signed in signed_cids {
+                if !peer_signatures.contains(&signed) {
+                    peer_signatures.push(signed);
+                }
+            }
+        }
+
+        store
+    }
+
+    pub fn get(&self, peer_id: &str) -> Option<&[SignedCids]> {
+        self.0.get(peer_id).map(Vec::as_slice)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Signs all supplied CIDs on behalf of the signer, previous signatures of this peer covering
+    /// only a subset of these CIDs are superseded by the new one.
+    pub fn sign(
+        &mut self,
+        cids: impl IntoIterator<Item = String>,
+        signer: &(impl Signer + ?Sized),
+    ) {
+        let cids = cids.into_iter().collect::<BTreeSet<_>>();
+        if cids.is_empty() {
+            return;
+        }
+
+        let cids = cids.into_iter().collect::<Vec<_>>();
+        let signature = signer.sign(&signed_message(&cids));
+        let signed = SignedCids {
+            cids,
+            signature: bs58::encode(signature).into_string(),
+        };
+
+        let peer_signatures = self.0.entry(signer.peer_id()).or_default();
+        peer_signatures.retain(|previous| !is_subset(&previous.cids, &signed.cids));
+        peer_signatures.push(signed);
+    }
+
+    /// Drops signatures that don't cover any reachable CID anymore.
+    ///
+    /// Signed CID lists are kept intact even if some of their CIDs are unreachable,
+    /// because removing a CID from a list would invalidate its signature.
+    pub fn retain_reachable(&mut self, mut is_reachable: impl FnMut(&str) -> bool) {
+        for peer_signatures in self.0.values_mut() {
+            peer_signatures.retain(|signed| signed.cids.iter().any(|cid| is_reachable(cid)));
+        }
+        self.0
+            .retain(|_, peer_signatures| !peer_signatures.is_empty());
+    }
+
+    /// Checks all stored signatures and that contributions are covered by them.
+    ///
+    /// Contributions of a peer that has at least one signature must be fully signed,
+    /// the same is required from all peers if `require_signatures` is set.
+    pub fn verify(
+        &self,
+        contributions: &PeerContributions,
+        verifier: &impl SignatureVerifier,
+        require_signatures: bool,
+    ) -> Result<(), SignatureVerificationError> {
+        for (peer_id, peer_signatures) in &self.0 {
+            for signed in peer_signatures {
+                verify_signed_cids(peer_id, signed, verifier)?;
+            }
+        }
+
+        for (peer_id, peer_cids) in contributions {
+            let peer_signatures = match self.0.get(peer_id) {
+                Some(peer_signatures) => peer_signatures.as_slice(),
+                None if require_signatures => &[][..],
+                None => continue,
+            };
+
+            let unsigned_cid = peer_cids.iter().find(|cid| {
+                !peer_signatures
+                    .iter()
+                    .any(|signed| signed.cids.binary_search(*cid).is_ok())
+            });
+            if let Some(cid) = unsigned_cid {
+                return Err(SignatureVerificationError::UnsignedContribution {
+                    peer_id: peer_id.clone(),
+                    cid: cid.clone(),
+                });
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn verify_signed_cids(
+    peer_id: &str,
+    signed: &SignedCids,
+    verifier: &impl SignatureVerifier,
+) -> Result<(), SignatureVerificationError> {
+    let signature = bs58::decode(&signed.signature)
+        .into_vec()
+        .map_err(|error| SignatureVerificationError::MalformedSignature {
+            peer_id: peer_id.to_string(),
+            error,
+        })?;
+
+    // CIDs are sorted before signing, unsorted ones are treated as forged
+    let is_sorted = signed.cids.windows(2).all(|pair| pair[0] < pair[1]);
+    let is_valid = verifier
+        .verify(peer_id, &signed_message(&signed.cids), &signature)
+        .map_err(|error| SignatureVerificationError::UnverifiableSignature {
+            peer_id: peer_id.to_string(),
+            error,
+        })?;
+    if !is_sorted || !is_valid {
+        return Err(SignatureVerificationError::InvalidSignature {
+            peer_id: peer_id.to_string(),
+            cids: signed.cids.clone(),
+        });
+    }
+
+    Ok(())
+}
+
+fn signed_message(cids: &[String]) -> Vec<u8> {
+    serde_json::to_vec(cids).expect("default serializer shouldn't fail")
+}
+
+/// Both slices are expected to be sorted.
+fn is_subset(subset: &[String], superset: &[String]) -> bool {
+    subset.iter().all(|cid| superset.binary_search(cid).is_ok())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::KeyError;
+
+    /// Deterministic stand-in for a real signature scheme,
+    /// a signature is a peer id concatenated with a message.
+    struct TestSigner(&'static str);
+
+    impl Signer for TestSigner {
+        fn peer_id(&self) -> String {
+            self.0.to_string()
+        }
+
+        fn sign(&self, message: &[u8]) -> Vec<u8> {
+            [self.0.as_bytes(), message].concat()
+        }
+    }
+
+    struct TestVerifier;
+
+    impl SignatureVerifier for TestVerifier {
+        fn verify(
+            &self,
+            peer_id: &str,
+            message: &[u8],
+            signature: &[u8],
+        ) -> Result<bool, KeyError> {
+            Ok(signature == [peer_id.as_bytes(), message].concat().as_slice())
+        }
+    }
+
+    fn contributions(peer_id: &str, cids: &[&str]) -> PeerContributions {
+        let cids = cids.iter().map(|cid| cid.to_string()).collect();
+        std::iter::once((peer_id.to_string(), cids)).collect()
+    }
+
+    #[test]
+    fn signed_contributions_verified() {
+        let mut store = SignatureStore::new();
+        store.sign(
+            vec!["cid_2".to_string(), "cid_1".to_string()],
+            &TestSigner("peer_1"),
+        );
+
+        let result = store.verify(
+            &contributions("peer_1", &["cid_1", "cid_2"]),
+            &TestVerifier,
+            true,
+        );
+        assert!(result.is_ok(), "{:?}", result);
+    }
+
+    #[test]
+    fn unsigned_contribution_rejected() {
+        let mut store = SignatureStore::new();
+        store.sign(vec!["cid_1".to_string()], &TestSigner("peer_1"));
+
+        let result = store.verify(
+            &contributions("peer_1", &["cid_1", "cid_2"]),
+            &TestVerifier,
+            false,
+        );
+        assert!(matches!(
+            result,
+            Err(SignatureVerificationError::UnsignedContribution { cid, .. }) if cid == "cid_2"
+        ));
+
+        let result = store.verify(&contributions("peer_2", &["cid_3"]), &TestVerifier, false);
+        assert!(result.is_ok(), "{:?}", result);
+
+        let result = store.verify(&contributions("peer_2", &["cid_3"]), &TestVerifier, true);
+        assert!(matches!(
+            result,
+            Err(SignatureVerificationError::UnsignedContribution { peer_id, .. }) if peer_id == "peer_2"
+        ));
+    }
+
+    #[test]
+    fn forged_signature_rejected() {
+        let mut store = SignatureStore::new();
+        store.sign(vec!["cid_1".to_string()], &TestSigner("peer_1"));
+        store.0.get_mut("peer_1").unwrap()[0].cids = vec!["forged_cid".to_string()];
+
+        let result = store.verify(&PeerContributions::new(), &TestVerifier, false);
+        assert!(matches!(
+            result,
+            Err(SignatureVerificationError::InvalidSignature { .. })
+        ));
+    }
+
+    #[test]
+    fn merged_signatures_cover_both_runs() {
+        let mut prev_store = SignatureStore::new();
+        prev_store.sign(vec!["cid_1".to_string()], &TestSigner("peer_1"));
+        let mut current_store = SignatureStore::new();
+        current_store.sign(vec!["cid_2".to_string()], &TestSigner("peer_1"));
+
+        let mut store = SignatureStore::from_stores(prev_store.clone(), current_store);
+        assert_eq!(store.get("peer_1").unwrap().len(), 2);
+        let result = store.verify(
+            &contributions("peer_1", &["cid_1", "cid_2"]),
+            &TestVerifier,
+            true,
+        );
+        assert!(result.is_ok(), "{:?}", result);
+
+        store.sign(
+            vec!["cid_1".to_string(), "cid_2".to_string()],
+            &TestSigner("peer_1"),
+        );
+        assert_eq!(store.get("peer_1").unwrap().len(), 1);
+
+        let store = SignatureStore::from_stores(store, prev_store);
+        assert_eq!(store.get("peer_1").unwrap().len(), 2);
+    }
+
+    #[test]
+    fn unreachable_signatures_pruned() {
+        let mut store = SignatureStore::new();
+        store.sign(vec!["cid_1".to_string()], &TestSigner("peer_1"));
+        store.sign(
+            vec!["cid_2".to_string(), "cid_3".to_string()],
+            &TestSigner("peer_1"),
+        );
+        store.sign(vec!["cid_4".to_string()], &TestSigner("peer_2"));
+
+        store.retain_reachable(|cid| cid == "cid_3");
+
+        let peer_signatures = store.get("peer_1").unwrap();
+        assert_eq!(peer_signatures.len(), 1);
+        assert_eq!(peer_signatures[0].cids, vec!["cid_2", "cid_3"]);
+        assert!(store.get("peer_2").is_none());
+
+        let result = store.verify(&contributions("peer_1", &["cid_3"]), &TestVerifier, true);
+        assert!(result.is_ok(), "{:?}", result);
+    }
+}
@@ -25,7 +25,8 @@ use serde::Serialize;
 #[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
 pub enum LambdaAST<'input> {
     /// Various functors that could applied to a value.
-    Functor(Functor),
+    #[serde(borrow)]
+    Functor(Functor<'input>),
     /// Each value in AIR could be represented as a tree and
     /// this variant acts as a path in such trees.
     #[serde(borrow)]
@@ -37,6 +38,9 @@ pub enum ValueAccessor<'input> {
     // (.)?[$idx]
     ArrayAccess { idx: u32 },
 
+    // (.)?[$start..$end]
+    ArraySlice { start: u32, end: u32 },
+
     // .field
     FieldAccessByName { field_name: &'input str },
 
@@ -49,8 +53,18 @@ pub enum ValueAccessor<'input> {
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy, Serialize, Deserialize)]
-pub enum Functor {
+pub enum Functor<'input> {
     /// Returns a length of a value if this value has array type (json array or canon stream)
     /// or a error if not.
     Length,
+
+    /// Returns keys of an object or indices of an array-like value.
+    Keys,
+
+    /// Returns the last element of an array-like value or a error if it's empty.
+    Last,
+
+    /// Returns true if an object contains a field with such name or if an array-like value
+    /// contains an element with such index.
+    ContainsKey { key: &'input str },
 }
@@ -31,7 +31,7 @@ impl<'input> LambdaAST<'input> {
         Ok(lambda_ast)
     }
 
-    pub fn from_functor(functor: Functor) -> Self {
+    pub fn from_functor(functor: Functor<'input>) -> Self {
         Self::Functor(functor)
     }
 }
@@ -36,6 +36,7 @@ impl fmt::Display for ValueAccessor<'_> {
 
         match self {
             ArrayAccess { idx } => write!(f, "[{idx}]"),
+            ArraySlice { start, end } => write!(f, "[{start}..{end}]"),
             FieldAccessByName { field_name } => write!(f, "{field_name}"),
             FieldAccessByScalar { scalar_name } => write!(f, "[{scalar_name}]"),
             Error => write!(f, "a parser error occurred while parsing lambda expression"),
@@ -43,12 +44,15 @@ impl fmt::Display for ValueAccessor<'_> {
     }
 }
 
-impl fmt::Display for Functor {
+impl fmt::Display for Functor<'_> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         use Functor::*;
 
         match self {
             Length => write!(f, "length"),
+            Keys => write!(f, "keys"),
+            Last => write!(f, "last"),
+            ContainsKey { key } => write!(f, "contains_key({key})"),
         }
     }
 }
@@ -60,7 +60,7 @@ impl<'input> TryFrom<RawLambdaAST<'input>> for LambdaAST<'input> {
 
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub(crate) enum RawLambdaAST<'input> {
-    Functor(Functor),
+    Functor(Functor<'input>),
     ValuePath(Vec<ValueAccessor<'input>>),
     // needed to allow parser catch all errors from a lambda expression without stopping on the very first one.
     Error,
@@ -23,6 +23,10 @@ use std::str::CharIndices;
 
 const ARRAY_IDX_BASE: u32 = 10;
 const LENGTH_FUNCTOR: &str = ".length";
+const KEYS_FUNCTOR: &str = ".keys";
+const LAST_FUNCTOR: &str = ".last";
+const CONTAINS_KEY_FUNCTOR_START: &str = ".contains_key(";
+const CONTAINS_KEY_FUNCTOR_END: &str = ")";
 const VALUE_PATH_STARTER: &str = ".$";
 
 pub type Spanned<Token, Loc, Error> = Result<(Loc, Token, Loc), Error>;
@@ -126,6 +130,12 @@ impl<'input> LambdaASTLexer<'input> {
     fn try_parse_first_token(&mut self) -> Spanned<Token<'input>, usize, LexerError> {
         let (token, token_size) = if self.input == LENGTH_FUNCTOR {
             (Token::LengthFunctor, LENGTH_FUNCTOR.len())
+        } else if self.input == KEYS_FUNCTOR {
+            (Token::KeysFunctor, KEYS_FUNCTOR.len())
+        } else if self.input == LAST_FUNCTOR {
+            (Token::LastFunctor, LAST_FUNCTOR.len())
+        } else if let Some(key) = self.try_parse_contains_key_functor() {
+            (Token::ContainsKeyFunctor(key), self.input.len())
         } else if self.input.starts_with(VALUE_PATH_STARTER) {
             (Token::ValuePathStarter, VALUE_PATH_STARTER.len())
         } else {
@@ -136,6 +146,19 @@ impl<'input> LambdaASTLexer<'input> {
         Ok((0, token, token_size))
     }
 
+    fn try_parse_contains_key_functor(&self) -> Option<&'input str> {
+        let key = self
+            .input
+            .strip_prefix(CONTAINS_KEY_FUNCTOR_START)?
+            .strip_suffix(CONTAINS_KEY_FUNCTOR_END)?;
+
+        if key.is_empty() || !key.chars().all(is_air_alphanumeric) {
+            return None;
+        }
+
+        Some(key)
+    }
+
     fn advance_by(&mut self, advance_size: usize) {
         // advance_by is unstable
         for _ in 0..advance_size {
@@ -52,3 +52,17 @@ fn field_access() {
     ];
     assert_eq!(actual, expected);
 }
+
+#[test]
+fn contains_key_functor() {
+    let key = "some_field_name";
+    let functor = format!(".contains_key({key})");
+
+    let actual = run_lexer(&functor);
+    let expected = vec![Spanned::Ok((
+        0,
+        Token::ContainsKeyFunctor(key),
+        functor.len(),
+    ))];
+    assert_eq!(actual, expected);
+}
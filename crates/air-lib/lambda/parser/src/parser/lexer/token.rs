@@ -20,6 +20,9 @@ use serde::Serialize;
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum Token<'input> {
     LengthFunctor,
+    KeysFunctor,
+    LastFunctor,
+    ContainsKeyFunctor(&'input str),
 
     //.$
     ValuePathStarter,
@@ -39,7 +39,7 @@ fn parse_to_accessors(source_code: &str) -> Vec<ValueAccessor<'_>> {
     }
 }
 
-fn parse_to_functor(source_code: &str) -> Functor {
+fn parse_to_functor(source_code: &str) -> Functor<'_> {
     let lambda_ast = parse(source_code);
     match lambda_ast {
         RawLambdaAST::Functor(functor) => functor,
@@ -266,3 +266,47 @@ fn parse_length_functor_with_following_accessors() {
 
     assert!(matches!(actual, Err(lalrpop_util::ParseError::User { .. })))
 }
+
+#[test]
+fn parse_keys_functor() {
+    let lambda = ".keys";
+
+    let actual = parse_to_functor(lambda);
+    let expected = Functor::Keys;
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn parse_last_functor() {
+    let lambda = ".last";
+
+    let actual = parse_to_functor(lambda);
+    let expected = Functor::Last;
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn parse_contains_key_functor() {
+    let key = "some_field_name";
+    let lambda = format!(".contains_key({key})");
+
+    let actual = parse_to_functor(&lambda);
+    let expected = Functor::ContainsKey { key };
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn array_slice() {
+    let start = 1;
+    let end = 3;
+    let lambda = format!(".$.[{start}..{end}].field_name");
+
+    let actual = parse_to_accessors(&lambda);
+    let expected = vec![
+        ValueAccessor::ArraySlice { start, end },
+        ValueAccessor::FieldAccessByName {
+            field_name: "field_name",
+        },
+    ];
+    assert_eq!(actual, expected);
+}
@@ -1,5 +1,5 @@
 // auto-generated: "lalrpop 0.19.8"
-// sha3: 592663f5597c21f4cc9cb3ad5cb93fa0a7db3635fc4d677c2b6130d33b6e22fd
+// sha3: 653759d9af973f3014d4308e0d370087b2d50ce5e3b3f8365898d56692b6478c
 use crate::ValueAccessor;
 use crate::parser::lambda_parser::RawLambdaAST;
 use crate::Functor;
@@ -34,8 +34,8 @@ mod __parse__RawLambdaAST {
     pub(crate) enum __Symbol<'input>
      {
         Variant0(Token<'input>),
-        Variant1(u32),
-        Variant2(&'input str),
+        Variant1(&'input str),
+        Variant2(u32),
         Variant3(__lalrpop_util::ErrorRecovery<usize, Token<'input>, LexerError>),
         Variant4(core::option::Option<Token<'input>>),
         Variant5(RawLambdaAST<'input>),
@@ -44,60 +44,86 @@ mod __parse__RawLambdaAST {
     }
     const __ACTION: &[i8] = &[
         // State 0
-        0, 0, 2, 0, 0, 5, 0, 0, 6,
+        0, 0, 2, 0, 0, 5, 6, 7, 8, 0, 0, 9,
         // State 1
-        0, 8, 0, 9, 0, 0, 0, 0, 10,
+        0, 11, 0, 12, 0, 0, 0, 0, 0, 0, 0, 13,
         // State 2
-        0, 8, 0, 9, 0, 0, 0, 0, 10,
+        0, 11, 0, 12, 0, 0, 0, 0, 0, 0, 0, 13,
         // State 3
-        0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 4
-        0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 5
-        0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 6
-        0, -22, 0, -22, 0, 0, 0, 0, -22,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 7
-        0, 0, 0, 12, 0, 0, 0, 13, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 8
-        0, 0, 0, 0, 0, 0, 14, 15, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 9
-        0, -19, 0, -19, 0, 0, 0, 0, -19,
+        0, -29, 0, -29, 0, 0, 0, 0, 0, 0, 0, -29,
         // State 10
-        0, -23, 0, -23, 0, 0, 0, 0, -23,
+        0, 0, 0, 15, 0, 0, 0, 0, 0, 0, 16, 0,
         // State 11
-        0, 0, 0, 0, 0, 0, 16, 17, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 17, 18, 0,
         // State 12
-        18, -18, 0, -18, 0, 0, 0, 0, -18,
+        0, -26, 0, -26, 0, 0, 0, 0, 0, 0, 0, -26,
         // State 13
-        0, 0, 0, 0, 19, 0, 0, 0, 0,
+        0, -30, 0, -30, 0, 0, 0, 0, 0, 0, 0, -30,
         // State 14
-        0, 0, 0, 0, 20, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 19, 20, 0,
         // State 15
-        0, 0, 0, 0, 21, 0, 0, 0, 0,
+        21, -25, 0, -25, 0, 0, 0, 0, 0, 0, 0, -25,
         // State 16
-        0, 0, 0, 0, 22, 0, 0, 0, 0,
+        0, 22, 0, 0, 23, 0, 0, 0, 0, 0, 0, 0,
         // State 17
-        0, -17, 0, -17, 0, 0, 0, 0, -17,
+        0, 0, 0, 0, 24, 0, 0, 0, 0, 0, 0, 0,
         // State 18
-        23, -12, 0, -12, 0, 0, 0, 0, -12,
+        0, 25, 0, 0, 26, 0, 0, 0, 0, 0, 0, 0,
         // State 19
-        24, -16, 0, -16, 0, 0, 0, 0, -16,
+        0, 0, 0, 0, 27, 0, 0, 0, 0, 0, 0, 0,
         // State 20
-        25, -11, 0, -11, 0, 0, 0, 0, -11,
+        0, -24, 0, -24, 0, 0, 0, 0, 0, 0, 0, -24,
         // State 21
-        26, -15, 0, -15, 0, 0, 0, 0, -15,
+        0, 28, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 22
-        0, -10, 0, -10, 0, 0, 0, 0, -10,
+        29, -15, 0, -15, 0, 0, 0, 0, 0, 0, 0, -15,
         // State 23
-        0, -14, 0, -14, 0, 0, 0, 0, -14,
+        30, -23, 0, -23, 0, 0, 0, 0, 0, 0, 0, -23,
         // State 24
-        0, -9, 0, -9, 0, 0, 0, 0, -9,
+        0, 31, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 25
-        0, -13, 0, -13, 0, 0, 0, 0, -13,
+        32, -14, 0, -14, 0, 0, 0, 0, 0, 0, 0, -14,
+        // State 26
+        33, -22, 0, -22, 0, 0, 0, 0, 0, 0, 0, -22,
+        // State 27
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 34, 0, 0,
+        // State 28
+        0, -13, 0, -13, 0, 0, 0, 0, 0, 0, 0, -13,
+        // State 29
+        0, -21, 0, -21, 0, 0, 0, 0, 0, 0, 0, -21,
+        // State 30
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 35, 0, 0,
+        // State 31
+        0, -12, 0, -12, 0, 0, 0, 0, 0, 0, 0, -12,
+        // State 32
+        0, -20, 0, -20, 0, 0, 0, 0, 0, 0, 0, -20,
+        // State 33
+        0, 0, 0, 0, 36, 0, 0, 0, 0, 0, 0, 0,
+        // State 34
+        0, 0, 0, 0, 37, 0, 0, 0, 0, 0, 0, 0,
+        // State 35
+        38, -19, 0, -19, 0, 0, 0, 0, 0, 0, 0, -19,
+        // State 36
+        39, -18, 0, -18, 0, 0, 0, 0, 0, 0, 0, -18,
+        // State 37
+        0, -17, 0, -17, 0, 0, 0, 0, 0, 0, 0, -17,
+        // State 38
+        0, -16, 0, -16, 0, 0, 0, 0, 0, 0, 0, -16,
     ];
     fn __action(state: i8, integer: usize) -> i8 {
-        __ACTION[(state as usize) * 9 + integer]
+        __ACTION[(state as usize) * 12 + integer]
     }
     const __EOF_ACTION: &[i8] = &[
         // State 0
@@ -107,58 +133,84 @@ mod __parse__RawLambdaAST {
         // State 2
         -6,
         // State 3
-        -24,
+        -31,
         // State 4
-        -7,
+        -10,
         // State 5
         -8,
         // State 6
-        -22,
+        -9,
         // State 7
-        0,
+        -7,
         // State 8
-        0,
+        -11,
         // State 9
-        -19,
+        -29,
         // State 10
-        -23,
+        0,
         // State 11
         0,
         // State 12
-        -18,
+        -26,
         // State 13
-        0,
+        -30,
         // State 14
         0,
         // State 15
-        0,
+        -25,
         // State 16
         0,
         // State 17
-        -17,
+        0,
         // State 18
-        -12,
+        0,
         // State 19
-        -16,
+        0,
         // State 20
-        -11,
+        -24,
         // State 21
-        -15,
+        0,
         // State 22
-        -10,
+        -15,
         // State 23
-        -14,
+        -23,
         // State 24
-        -9,
+        0,
         // State 25
+        -14,
+        // State 26
+        -22,
+        // State 27
+        0,
+        // State 28
         -13,
+        // State 29
+        -21,
+        // State 30
+        0,
+        // State 31
+        -12,
+        // State 32
+        -20,
+        // State 33
+        0,
+        // State 34
+        0,
+        // State 35
+        -19,
+        // State 36
+        -18,
+        // State 37
+        -17,
+        // State 38
+        -16,
     ];
     fn __goto(state: i8, nt: usize) -> i8 {
         match nt {
             2 => 3,
             3 => match state {
-                2 => 10,
-                _ => 6,
+                2 => 13,
+                _ => 9,
             },
             5 => 2,
             _ => 0,
@@ -171,6 +223,9 @@ mod __parse__RawLambdaAST {
             r###"".$""###,
             r###""[""###,
             r###""]""###,
+            r###"contains_key_functor"###,
+            r###"keys_functor"###,
+            r###"last_functor"###,
             r###"length_functor"###,
             r###"number_accessor"###,
             r###"string_accessor"###,
@@ -227,7 +282,7 @@ mod __parse__RawLambdaAST {
 
         #[inline]
         fn error_action(&self, state: i8) -> i8 {
-            __action(state, 9 - 1)
+            __action(state, 12 - 1)
         }
 
         #[inline]
@@ -297,9 +352,12 @@ mod __parse__RawLambdaAST {
             Token::ValuePathStarter if true => Some(2),
             Token::OpenSquareBracket if true => Some(3),
             Token::CloseSquareBracket if true => Some(4),
-            Token::LengthFunctor if true => Some(5),
-            Token::NumberAccessor(_) if true => Some(6),
-            Token::StringAccessor(_) if true => Some(7),
+            Token::ContainsKeyFunctor(_) if true => Some(5),
+            Token::KeysFunctor if true => Some(6),
+            Token::LastFunctor if true => Some(7),
+            Token::LengthFunctor if true => Some(8),
+            Token::NumberAccessor(_) if true => Some(9),
+            Token::StringAccessor(_) if true => Some(10),
             _ => None,
         }
     }
@@ -313,13 +371,13 @@ mod __parse__RawLambdaAST {
     ) -> __Symbol<'input>
     {
         match __token_index {
-            0 | 1 | 2 | 3 | 4 | 5 => __Symbol::Variant0(__token),
-            6 => match __token {
-                Token::NumberAccessor(__tok0) if true => __Symbol::Variant1(__tok0),
+            0 | 1 | 2 | 3 | 4 | 6 | 7 | 8 => __Symbol::Variant0(__token),
+            5 | 10 => match __token {
+                Token::ContainsKeyFunctor(__tok0) | Token::StringAccessor(__tok0) if true => __Symbol::Variant1(__tok0),
                 _ => unreachable!(),
             },
-            7 => match __token {
-                Token::StringAccessor(__tok0) if true => __Symbol::Variant2(__tok0),
+            9 => match __token {
+                Token::NumberAccessor(__tok0) if true => __Symbol::Variant2(__tok0),
                 _ => unreachable!(),
             },
             _ => unreachable!(),
@@ -385,96 +443,138 @@ mod __parse__RawLambdaAST {
                 }
             }
             8 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 2,
+                }
+            }
+            9 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 2,
+                }
+            }
+            10 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 2,
+                }
+            }
+            11 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 5,
                     nonterminal_produced: 3,
                 }
             }
-            9 => {
+            12 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 4,
                     nonterminal_produced: 3,
                 }
             }
-            10 => {
+            13 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 4,
                     nonterminal_produced: 3,
                 }
             }
-            11 => {
+            14 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 3,
                     nonterminal_produced: 3,
                 }
             }
-            12 => {
+            15 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 8,
+                    nonterminal_produced: 3,
+                }
+            }
+            16 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 7,
+                    nonterminal_produced: 3,
+                }
+            }
+            17 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 7,
+                    nonterminal_produced: 3,
+                }
+            }
+            18 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 6,
+                    nonterminal_produced: 3,
+                }
+            }
+            19 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 5,
                     nonterminal_produced: 3,
                 }
             }
-            13 => {
+            20 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 4,
                     nonterminal_produced: 3,
                 }
             }
-            14 => {
+            21 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 4,
                     nonterminal_produced: 3,
                 }
             }
-            15 => {
+            22 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 3,
                     nonterminal_produced: 3,
                 }
             }
-            16 => {
+            23 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 3,
                     nonterminal_produced: 3,
                 }
             }
-            17 => {
+            24 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 2,
                     nonterminal_produced: 3,
                 }
             }
-            18 => {
+            25 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
                     nonterminal_produced: 3,
                 }
             }
-            19 => {
+            26 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 0,
                     nonterminal_produced: 4,
                 }
             }
-            20 => {
+            27 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
                     nonterminal_produced: 4,
                 }
             }
-            21 => {
+            28 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
                     nonterminal_produced: 5,
                 }
             }
-            22 => {
+            29 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 2,
                     nonterminal_produced: 5,
                 }
             }
-            23 => __state_machine::SimulatedReduce::Accept,
+            30 => __state_machine::SimulatedReduce::Accept,
             _ => panic!("invalid reduction index {}", __reduce_index)
         }
     }
@@ -634,6 +734,27 @@ mod __parse__RawLambdaAST {
                 __reduce22(input, errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             23 => {
+                __reduce23(input, errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            24 => {
+                __reduce24(input, errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            25 => {
+                __reduce25(input, errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            26 => {
+                __reduce26(input, errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            27 => {
+                __reduce27(input, errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            28 => {
+                __reduce28(input, errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            29 => {
+                __reduce29(input, errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            30 => {
                 // __RawLambdaAST = RawLambdaAST => ActionFn(0);
                 let __sym0 = __pop_Variant5(__symbols);
                 let __start = __sym0.0.clone();
@@ -720,25 +841,25 @@ mod __parse__RawLambdaAST {
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant1<
+    fn __pop_Variant2<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
     ) -> (usize, u32, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant1(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant2(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant2<
+    fn __pop_Variant1<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
     ) -> (usize, &'input str, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant2(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant1(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
@@ -753,11 +874,11 @@ mod __parse__RawLambdaAST {
         _: core::marker::PhantomData<(&'err (), &'input ())>,
     ) -> (usize, usize)
     {
-        // "!"? = "!" => ActionFn(8);
+        // "!"? = "!" => ActionFn(12);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action8::<>(input, errors, __sym0);
+        let __nt = super::__action12::<>(input, errors, __sym0);
         __symbols.push((__start, __Symbol::Variant4(__nt), __end));
         (1, 0)
     }
@@ -772,10 +893,10 @@ mod __parse__RawLambdaAST {
         _: core::marker::PhantomData<(&'err (), &'input ())>,
     ) -> (usize, usize)
     {
-        // "!"? =  => ActionFn(9);
+        // "!"? =  => ActionFn(13);
         let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
         let __end = __start.clone();
-        let __nt = super::__action9::<>(input, errors, &__start, &__end);
+        let __nt = super::__action13::<>(input, errors, &__start, &__end);
         __symbols.push((__start, __Symbol::Variant4(__nt), __end));
         (0, 0)
     }
@@ -790,11 +911,11 @@ mod __parse__RawLambdaAST {
         _: core::marker::PhantomData<(&'err (), &'input ())>,
     ) -> (usize, usize)
     {
-        // "."? = "." => ActionFn(10);
+        // "."? = "." => ActionFn(14);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action10::<>(input, errors, __sym0);
+        let __nt = super::__action14::<>(input, errors, __sym0);
         __symbols.push((__start, __Symbol::Variant4(__nt), __end));
         (1, 1)
     }
@@ -809,10 +930,10 @@ mod __parse__RawLambdaAST {
         _: core::marker::PhantomData<(&'err (), &'input ())>,
     ) -> (usize, usize)
     {
-        // "."? =  => ActionFn(11);
+        // "."? =  => ActionFn(15);
         let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
         let __end = __start.clone();
-        let __nt = super::__action11::<>(input, errors, &__start, &__end);
+        let __nt = super::__action15::<>(input, errors, &__start, &__end);
         __symbols.push((__start, __Symbol::Variant4(__nt), __end));
         (0, 1)
     }
@@ -827,11 +948,11 @@ mod __parse__RawLambdaAST {
         _: core::marker::PhantomData<(&'err (), &'input ())>,
     ) -> (usize, usize)
     {
-        // RawLambdaAST = ".$" => ActionFn(30);
+        // RawLambdaAST = ".$" => ActionFn(40);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action30::<>(input, errors, __sym0);
+        let __nt = super::__action40::<>(input, errors, __sym0);
         __symbols.push((__start, __Symbol::Variant5(__nt), __end));
         (1, 2)
     }
@@ -846,13 +967,13 @@ mod __parse__RawLambdaAST {
         _: core::marker::PhantomData<(&'err (), &'input ())>,
     ) -> (usize, usize)
     {
-        // RawLambdaAST = ".$", ValueAccessor+ => ActionFn(31);
+        // RawLambdaAST = ".$", ValueAccessor+ => ActionFn(41);
         assert!(__symbols.len() >= 2);
         let __sym1 = __pop_Variant7(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym1.2.clone();
-        let __nt = super::__action31::<>(input, errors, __sym0, __sym1);
+        let __nt = super::__action41::<>(input, errors, __sym0, __sym1);
         __symbols.push((__start, __Symbol::Variant5(__nt), __end));
         (2, 2)
     }
@@ -886,8 +1007,8 @@ mod __parse__RawLambdaAST {
         _: core::marker::PhantomData<(&'err (), &'input ())>,
     ) -> (usize, usize)
     {
-        // RawLambdaAST = error => ActionFn(3);
-        let __sym0 = __pop_Variant3(__symbols);
+        // RawLambdaAST = keys_functor => ActionFn(3);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
         let __nt = super::__action3::<>(input, errors, __sym0);
@@ -905,18 +1026,13 @@ mod __parse__RawLambdaAST {
         _: core::marker::PhantomData<(&'err (), &'input ())>,
     ) -> (usize, usize)
     {
-        // ValueAccessor = ".", "[", number_accessor, "]", "!" => ActionFn(22);
-        assert!(__symbols.len() >= 5);
-        let __sym4 = __pop_Variant0(__symbols);
-        let __sym3 = __pop_Variant0(__symbols);
-        let __sym2 = __pop_Variant1(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
+        // RawLambdaAST = last_functor => ActionFn(4);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym4.2.clone();
-        let __nt = super::__action22::<>(input, errors, __sym0, __sym1, __sym2, __sym3, __sym4);
-        __symbols.push((__start, __Symbol::Variant6(__nt), __end));
-        (5, 3)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action4::<>(input, errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (1, 2)
     }
     pub(crate) fn __reduce9<
         'err,
@@ -929,17 +1045,13 @@ mod __parse__RawLambdaAST {
         _: core::marker::PhantomData<(&'err (), &'input ())>,
     ) -> (usize, usize)
     {
-        // ValueAccessor = "[", number_accessor, "]", "!" => ActionFn(23);
-        assert!(__symbols.len() >= 4);
-        let __sym3 = __pop_Variant0(__symbols);
-        let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant1(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
+        // RawLambdaAST = contains_key_functor => ActionFn(5);
+        let __sym0 = __pop_Variant1(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym3.2.clone();
-        let __nt = super::__action23::<>(input, errors, __sym0, __sym1, __sym2, __sym3);
-        __symbols.push((__start, __Symbol::Variant6(__nt), __end));
-        (4, 3)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action5::<>(input, errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (1, 2)
     }
     pub(crate) fn __reduce10<
         'err,
@@ -952,17 +1064,13 @@ mod __parse__RawLambdaAST {
         _: core::marker::PhantomData<(&'err (), &'input ())>,
     ) -> (usize, usize)
     {
-        // ValueAccessor = ".", "[", number_accessor, "]" => ActionFn(24);
-        assert!(__symbols.len() >= 4);
-        let __sym3 = __pop_Variant0(__symbols);
-        let __sym2 = __pop_Variant1(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
+        // RawLambdaAST = error => ActionFn(6);
+        let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym3.2.clone();
-        let __nt = super::__action24::<>(input, errors, __sym0, __sym1, __sym2, __sym3);
-        __symbols.push((__start, __Symbol::Variant6(__nt), __end));
-        (4, 3)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action6::<>(input, errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (1, 2)
     }
     pub(crate) fn __reduce11<
         'err,
@@ -975,29 +1083,7 @@ mod __parse__RawLambdaAST {
         _: core::marker::PhantomData<(&'err (), &'input ())>,
     ) -> (usize, usize)
     {
-        // ValueAccessor = "[", number_accessor, "]" => ActionFn(25);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant1(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym2.2.clone();
-        let __nt = super::__action25::<>(input, errors, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant6(__nt), __end));
-        (3, 3)
-    }
-    pub(crate) fn __reduce12<
-        'err,
-        'input,
-    >(
-        input: &'input str,
-        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexerError>>,
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'err (), &'input ())>,
-    ) -> (usize, usize)
-    {
-        // ValueAccessor = ".", "[", string_accessor, "]", "!" => ActionFn(26);
+        // ValueAccessor = ".", "[", number_accessor, "]", "!" => ActionFn(28);
         assert!(__symbols.len() >= 5);
         let __sym4 = __pop_Variant0(__symbols);
         let __sym3 = __pop_Variant0(__symbols);
@@ -1006,11 +1092,11 @@ mod __parse__RawLambdaAST {
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym4.2.clone();
-        let __nt = super::__action26::<>(input, errors, __sym0, __sym1, __sym2, __sym3, __sym4);
+        let __nt = super::__action28::<>(input, errors, __sym0, __sym1, __sym2, __sym3, __sym4);
         __symbols.push((__start, __Symbol::Variant6(__nt), __end));
         (5, 3)
     }
-    pub(crate) fn __reduce13<
+    pub(crate) fn __reduce12<
         'err,
         'input,
     >(
@@ -1021,7 +1107,7 @@ mod __parse__RawLambdaAST {
         _: core::marker::PhantomData<(&'err (), &'input ())>,
     ) -> (usize, usize)
     {
-        // ValueAccessor = "[", string_accessor, "]", "!" => ActionFn(27);
+        // ValueAccessor = "[", number_accessor, "]", "!" => ActionFn(29);
         assert!(__symbols.len() >= 4);
         let __sym3 = __pop_Variant0(__symbols);
         let __sym2 = __pop_Variant0(__symbols);
@@ -1029,11 +1115,11 @@ mod __parse__RawLambdaAST {
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym3.2.clone();
-        let __nt = super::__action27::<>(input, errors, __sym0, __sym1, __sym2, __sym3);
+        let __nt = super::__action29::<>(input, errors, __sym0, __sym1, __sym2, __sym3);
         __symbols.push((__start, __Symbol::Variant6(__nt), __end));
         (4, 3)
     }
-    pub(crate) fn __reduce14<
+    pub(crate) fn __reduce13<
         'err,
         'input,
     >(
@@ -1044,7 +1130,7 @@ mod __parse__RawLambdaAST {
         _: core::marker::PhantomData<(&'err (), &'input ())>,
     ) -> (usize, usize)
     {
-        // ValueAccessor = ".", "[", string_accessor, "]" => ActionFn(28);
+        // ValueAccessor = ".", "[", number_accessor, "]" => ActionFn(30);
         assert!(__symbols.len() >= 4);
         let __sym3 = __pop_Variant0(__symbols);
         let __sym2 = __pop_Variant2(__symbols);
@@ -1052,11 +1138,11 @@ mod __parse__RawLambdaAST {
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym3.2.clone();
-        let __nt = super::__action28::<>(input, errors, __sym0, __sym1, __sym2, __sym3);
+        let __nt = super::__action30::<>(input, errors, __sym0, __sym1, __sym2, __sym3);
         __symbols.push((__start, __Symbol::Variant6(__nt), __end));
         (4, 3)
     }
-    pub(crate) fn __reduce15<
+    pub(crate) fn __reduce14<
         'err,
         'input,
     >(
@@ -1067,18 +1153,18 @@ mod __parse__RawLambdaAST {
         _: core::marker::PhantomData<(&'err (), &'input ())>,
     ) -> (usize, usize)
     {
-        // ValueAccessor = "[", string_accessor, "]" => ActionFn(29);
+        // ValueAccessor = "[", number_accessor, "]" => ActionFn(31);
         assert!(__symbols.len() >= 3);
         let __sym2 = __pop_Variant0(__symbols);
         let __sym1 = __pop_Variant2(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym2.2.clone();
-        let __nt = super::__action29::<>(input, errors, __sym0, __sym1, __sym2);
+        let __nt = super::__action31::<>(input, errors, __sym0, __sym1, __sym2);
         __symbols.push((__start, __Symbol::Variant6(__nt), __end));
         (3, 3)
     }
-    pub(crate) fn __reduce16<
+    pub(crate) fn __reduce15<
         'err,
         'input,
     >(
@@ -1089,18 +1175,23 @@ mod __parse__RawLambdaAST {
         _: core::marker::PhantomData<(&'err (), &'input ())>,
     ) -> (usize, usize)
     {
-        // ValueAccessor = ".", string_accessor, "!" => ActionFn(20);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant2(__symbols);
+        // ValueAccessor = ".", "[", number_accessor, ".", ".", number_accessor, "]", "!" => ActionFn(32);
+        assert!(__symbols.len() >= 8);
+        let __sym7 = __pop_Variant0(__symbols);
+        let __sym6 = __pop_Variant0(__symbols);
+        let __sym5 = __pop_Variant2(__symbols);
+        let __sym4 = __pop_Variant0(__symbols);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant2(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym2.2.clone();
-        let __nt = super::__action20::<>(input, errors, __sym0, __sym1, __sym2);
+        let __end = __sym7.2.clone();
+        let __nt = super::__action32::<>(input, errors, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6, __sym7);
         __symbols.push((__start, __Symbol::Variant6(__nt), __end));
-        (3, 3)
+        (8, 3)
     }
-    pub(crate) fn __reduce17<
+    pub(crate) fn __reduce16<
         'err,
         'input,
     >(
@@ -1111,17 +1202,22 @@ mod __parse__RawLambdaAST {
         _: core::marker::PhantomData<(&'err (), &'input ())>,
     ) -> (usize, usize)
     {
-        // ValueAccessor = ".", string_accessor => ActionFn(21);
-        assert!(__symbols.len() >= 2);
+        // ValueAccessor = "[", number_accessor, ".", ".", number_accessor, "]", "!" => ActionFn(33);
+        assert!(__symbols.len() >= 7);
+        let __sym6 = __pop_Variant0(__symbols);
+        let __sym5 = __pop_Variant0(__symbols);
+        let __sym4 = __pop_Variant2(__symbols);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
         let __sym1 = __pop_Variant2(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym1.2.clone();
-        let __nt = super::__action21::<>(input, errors, __sym0, __sym1);
+        let __end = __sym6.2.clone();
+        let __nt = super::__action33::<>(input, errors, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6);
         __symbols.push((__start, __Symbol::Variant6(__nt), __end));
-        (2, 3)
+        (7, 3)
     }
-    pub(crate) fn __reduce18<
+    pub(crate) fn __reduce17<
         'err,
         'input,
     >(
@@ -1132,15 +1228,22 @@ mod __parse__RawLambdaAST {
         _: core::marker::PhantomData<(&'err (), &'input ())>,
     ) -> (usize, usize)
     {
-        // ValueAccessor = error => ActionFn(7);
-        let __sym0 = __pop_Variant3(__symbols);
+        // ValueAccessor = ".", "[", number_accessor, ".", ".", number_accessor, "]" => ActionFn(34);
+        assert!(__symbols.len() >= 7);
+        let __sym6 = __pop_Variant0(__symbols);
+        let __sym5 = __pop_Variant2(__symbols);
+        let __sym4 = __pop_Variant0(__symbols);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant2(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action7::<>(input, errors, __sym0);
+        let __end = __sym6.2.clone();
+        let __nt = super::__action34::<>(input, errors, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6);
         __symbols.push((__start, __Symbol::Variant6(__nt), __end));
-        (1, 3)
+        (7, 3)
     }
-    pub(crate) fn __reduce19<
+    pub(crate) fn __reduce18<
         'err,
         'input,
     >(
@@ -1151,14 +1254,21 @@ mod __parse__RawLambdaAST {
         _: core::marker::PhantomData<(&'err (), &'input ())>,
     ) -> (usize, usize)
     {
-        // ValueAccessor* =  => ActionFn(12);
-        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
-        let __end = __start.clone();
-        let __nt = super::__action12::<>(input, errors, &__start, &__end);
-        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
-        (0, 4)
+        // ValueAccessor = "[", number_accessor, ".", ".", number_accessor, "]" => ActionFn(35);
+        assert!(__symbols.len() >= 6);
+        let __sym5 = __pop_Variant0(__symbols);
+        let __sym4 = __pop_Variant2(__symbols);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant2(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym5.2.clone();
+        let __nt = super::__action35::<>(input, errors, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
+        __symbols.push((__start, __Symbol::Variant6(__nt), __end));
+        (6, 3)
     }
-    pub(crate) fn __reduce20<
+    pub(crate) fn __reduce19<
         'err,
         'input,
     >(
@@ -1169,15 +1279,20 @@ mod __parse__RawLambdaAST {
         _: core::marker::PhantomData<(&'err (), &'input ())>,
     ) -> (usize, usize)
     {
-        // ValueAccessor* = ValueAccessor+ => ActionFn(13);
-        let __sym0 = __pop_Variant7(__symbols);
+        // ValueAccessor = ".", "[", string_accessor, "]", "!" => ActionFn(36);
+        assert!(__symbols.len() >= 5);
+        let __sym4 = __pop_Variant0(__symbols);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant1(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action13::<>(input, errors, __sym0);
-        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
-        (1, 4)
+        let __end = __sym4.2.clone();
+        let __nt = super::__action36::<>(input, errors, __sym0, __sym1, __sym2, __sym3, __sym4);
+        __symbols.push((__start, __Symbol::Variant6(__nt), __end));
+        (5, 3)
     }
-    pub(crate) fn __reduce21<
+    pub(crate) fn __reduce20<
         'err,
         'input,
     >(
@@ -1188,15 +1303,19 @@ mod __parse__RawLambdaAST {
         _: core::marker::PhantomData<(&'err (), &'input ())>,
     ) -> (usize, usize)
     {
-        // ValueAccessor+ = ValueAccessor => ActionFn(14);
-        let __sym0 = __pop_Variant6(__symbols);
+        // ValueAccessor = "[", string_accessor, "]", "!" => ActionFn(37);
+        assert!(__symbols.len() >= 4);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant1(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action14::<>(input, errors, __sym0);
-        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
-        (1, 5)
+        let __end = __sym3.2.clone();
+        let __nt = super::__action37::<>(input, errors, __sym0, __sym1, __sym2, __sym3);
+        __symbols.push((__start, __Symbol::Variant6(__nt), __end));
+        (4, 3)
     }
-    pub(crate) fn __reduce22<
+    pub(crate) fn __reduce21<
         'err,
         'input,
     >(
@@ -1207,13 +1326,176 @@ mod __parse__RawLambdaAST {
         _: core::marker::PhantomData<(&'err (), &'input ())>,
     ) -> (usize, usize)
     {
-        // ValueAccessor+ = ValueAccessor+, ValueAccessor => ActionFn(15);
-        assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant6(__symbols);
-        let __sym0 = __pop_Variant7(__symbols);
-        let __start = __sym0.0.clone();
+        // ValueAccessor = ".", "[", string_accessor, "]" => ActionFn(38);
+        assert!(__symbols.len() >= 4);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant1(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym3.2.clone();
+        let __nt = super::__action38::<>(input, errors, __sym0, __sym1, __sym2, __sym3);
+        __symbols.push((__start, __Symbol::Variant6(__nt), __end));
+        (4, 3)
+    }
+    pub(crate) fn __reduce22<
+        'err,
+        'input,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexerError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'err (), &'input ())>,
+    ) -> (usize, usize)
+    {
+        // ValueAccessor = "[", string_accessor, "]" => ActionFn(39);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant1(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action39::<>(input, errors, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant6(__nt), __end));
+        (3, 3)
+    }
+    pub(crate) fn __reduce23<
+        'err,
+        'input,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexerError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'err (), &'input ())>,
+    ) -> (usize, usize)
+    {
+        // ValueAccessor = ".", string_accessor, "!" => ActionFn(26);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant1(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action26::<>(input, errors, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant6(__nt), __end));
+        (3, 3)
+    }
+    pub(crate) fn __reduce24<
+        'err,
+        'input,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexerError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'err (), &'input ())>,
+    ) -> (usize, usize)
+    {
+        // ValueAccessor = ".", string_accessor => ActionFn(27);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant1(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action27::<>(input, errors, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant6(__nt), __end));
+        (2, 3)
+    }
+    pub(crate) fn __reduce25<
+        'err,
+        'input,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexerError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'err (), &'input ())>,
+    ) -> (usize, usize)
+    {
+        // ValueAccessor = error => ActionFn(11);
+        let __sym0 = __pop_Variant3(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action11::<>(input, errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant6(__nt), __end));
+        (1, 3)
+    }
+    pub(crate) fn __reduce26<
+        'err,
+        'input,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexerError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'err (), &'input ())>,
+    ) -> (usize, usize)
+    {
+        // ValueAccessor* =  => ActionFn(16);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
+        let __end = __start.clone();
+        let __nt = super::__action16::<>(input, errors, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (0, 4)
+    }
+    pub(crate) fn __reduce27<
+        'err,
+        'input,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexerError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'err (), &'input ())>,
+    ) -> (usize, usize)
+    {
+        // ValueAccessor* = ValueAccessor+ => ActionFn(17);
+        let __sym0 = __pop_Variant7(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action17::<>(input, errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (1, 4)
+    }
+    pub(crate) fn __reduce28<
+        'err,
+        'input,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexerError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'err (), &'input ())>,
+    ) -> (usize, usize)
+    {
+        // ValueAccessor+ = ValueAccessor => ActionFn(18);
+        let __sym0 = __pop_Variant6(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action18::<>(input, errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (1, 5)
+    }
+    pub(crate) fn __reduce29<
+        'err,
+        'input,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexerError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'err (), &'input ())>,
+    ) -> (usize, usize)
+    {
+        // ValueAccessor+ = ValueAccessor+, ValueAccessor => ActionFn(19);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant6(__symbols);
+        let __sym0 = __pop_Variant7(__symbols);
+        let __start = __sym0.0.clone();
         let __end = __sym1.2.clone();
-        let __nt = super::__action15::<>(input, errors, __sym0, __sym1);
+        let __nt = super::__action19::<>(input, errors, __sym0, __sym1);
         __symbols.push((__start, __Symbol::Variant7(__nt), __end));
         (2, 5)
     }
@@ -1264,6 +1546,45 @@ fn __action2<
 fn __action3<
     'err,
     'input,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexerError>>,
+    (_, __0, _): (usize, Token<'input>, usize),
+) -> RawLambdaAST<'input>
+{
+    RawLambdaAST::Functor(Functor::Keys)
+}
+
+#[allow(unused_variables)]
+fn __action4<
+    'err,
+    'input,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexerError>>,
+    (_, __0, _): (usize, Token<'input>, usize),
+) -> RawLambdaAST<'input>
+{
+    RawLambdaAST::Functor(Functor::Last)
+}
+
+#[allow(unused_variables)]
+fn __action5<
+    'err,
+    'input,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexerError>>,
+    (_, key, _): (usize, &'input str, usize),
+) -> RawLambdaAST<'input>
+{
+    RawLambdaAST::Functor(Functor::ContainsKey { key })
+}
+
+#[allow(unused_variables)]
+fn __action6<
+    'err,
+    'input,
 >(
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexerError>>,
@@ -1274,7 +1595,7 @@ fn __action3<
 }
 
 #[allow(unused_variables)]
-fn __action4<
+fn __action7<
     'err,
     'input,
 >(
@@ -1293,7 +1614,29 @@ fn __action4<
 }
 
 #[allow(unused_variables)]
-fn __action5<
+fn __action8<
+    'err,
+    'input,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexerError>>,
+    (_, maybe_dot_selector, _): (usize, core::option::Option<Token<'input>>, usize),
+    (_, _, _): (usize, Token<'input>, usize),
+    (_, start, _): (usize, u32, usize),
+    (_, _, _): (usize, Token<'input>, usize),
+    (_, _, _): (usize, Token<'input>, usize),
+    (_, end, _): (usize, u32, usize),
+    (_, _, _): (usize, Token<'input>, usize),
+    (_, maybe_flatten_sign, _): (usize, core::option::Option<Token<'input>>, usize),
+) -> ValueAccessor<'input>
+{
+    {
+        ValueAccessor::ArraySlice { start, end }
+    }
+}
+
+#[allow(unused_variables)]
+fn __action9<
     'err,
     'input,
 >(
@@ -1312,7 +1655,7 @@ fn __action5<
 }
 
 #[allow(unused_variables)]
-fn __action6<
+fn __action10<
     'err,
     'input,
 >(
@@ -1329,7 +1672,7 @@ fn __action6<
 }
 
 #[allow(unused_variables)]
-fn __action7<
+fn __action11<
     'err,
     'input,
 >(
@@ -1342,7 +1685,7 @@ fn __action7<
 }
 
 #[allow(unused_variables)]
-fn __action8<
+fn __action12<
     'err,
     'input,
 >(
@@ -1355,7 +1698,7 @@ fn __action8<
 }
 
 #[allow(unused_variables)]
-fn __action9<
+fn __action13<
     'err,
     'input,
 >(
@@ -1369,7 +1712,7 @@ fn __action9<
 }
 
 #[allow(unused_variables)]
-fn __action10<
+fn __action14<
     'err,
     'input,
 >(
@@ -1382,7 +1725,7 @@ fn __action10<
 }
 
 #[allow(unused_variables)]
-fn __action11<
+fn __action15<
     'err,
     'input,
 >(
@@ -1396,7 +1739,7 @@ fn __action11<
 }
 
 #[allow(unused_variables)]
-fn __action12<
+fn __action16<
     'err,
     'input,
 >(
@@ -1410,7 +1753,7 @@ fn __action12<
 }
 
 #[allow(unused_variables)]
-fn __action13<
+fn __action17<
     'err,
     'input,
 >(
@@ -1423,7 +1766,7 @@ fn __action13<
 }
 
 #[allow(unused_variables)]
-fn __action14<
+fn __action18<
     'err,
     'input,
 >(
@@ -1436,7 +1779,7 @@ fn __action14<
 }
 
 #[allow(unused_variables)]
-fn __action15<
+fn __action19<
     'err,
     'input,
 >(
@@ -1450,7 +1793,7 @@ fn __action15<
 }
 
 #[allow(unused_variables)]
-fn __action16<
+fn __action20<
     'err,
     'input,
 >(
@@ -1465,13 +1808,13 @@ fn __action16<
 {
     let __start0 = __4.0.clone();
     let __end0 = __4.2.clone();
-    let __temp0 = __action8(
+    let __temp0 = __action12(
         input,
         errors,
         __4,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action4(
+    __action7(
         input,
         errors,
         __0,
@@ -1483,7 +1826,7 @@ fn __action16<
 }
 
 #[allow(unused_variables)]
-fn __action17<
+fn __action21<
     'err,
     'input,
 >(
@@ -1497,14 +1840,14 @@ fn __action17<
 {
     let __start0 = __3.2.clone();
     let __end0 = __3.2.clone();
-    let __temp0 = __action9(
+    let __temp0 = __action13(
         input,
         errors,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action4(
+    __action7(
         input,
         errors,
         __0,
@@ -1516,7 +1859,85 @@ fn __action17<
 }
 
 #[allow(unused_variables)]
-fn __action18<
+fn __action22<
+    'err,
+    'input,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexerError>>,
+    __0: (usize, core::option::Option<Token<'input>>, usize),
+    __1: (usize, Token<'input>, usize),
+    __2: (usize, u32, usize),
+    __3: (usize, Token<'input>, usize),
+    __4: (usize, Token<'input>, usize),
+    __5: (usize, u32, usize),
+    __6: (usize, Token<'input>, usize),
+    __7: (usize, Token<'input>, usize),
+) -> ValueAccessor<'input>
+{
+    let __start0 = __7.0.clone();
+    let __end0 = __7.2.clone();
+    let __temp0 = __action12(
+        input,
+        errors,
+        __7,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action8(
+        input,
+        errors,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __6,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action23<
+    'err,
+    'input,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexerError>>,
+    __0: (usize, core::option::Option<Token<'input>>, usize),
+    __1: (usize, Token<'input>, usize),
+    __2: (usize, u32, usize),
+    __3: (usize, Token<'input>, usize),
+    __4: (usize, Token<'input>, usize),
+    __5: (usize, u32, usize),
+    __6: (usize, Token<'input>, usize),
+) -> ValueAccessor<'input>
+{
+    let __start0 = __6.2.clone();
+    let __end0 = __6.2.clone();
+    let __temp0 = __action13(
+        input,
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action8(
+        input,
+        errors,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __6,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action24<
     'err,
     'input,
 >(
@@ -1531,13 +1952,13 @@ fn __action18<
 {
     let __start0 = __4.0.clone();
     let __end0 = __4.2.clone();
-    let __temp0 = __action8(
+    let __temp0 = __action12(
         input,
         errors,
         __4,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action5(
+    __action9(
         input,
         errors,
         __0,
@@ -1549,7 +1970,7 @@ fn __action18<
 }
 
 #[allow(unused_variables)]
-fn __action19<
+fn __action25<
     'err,
     'input,
 >(
@@ -1563,14 +1984,14 @@ fn __action19<
 {
     let __start0 = __3.2.clone();
     let __end0 = __3.2.clone();
-    let __temp0 = __action9(
+    let __temp0 = __action13(
         input,
         errors,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action5(
+    __action9(
         input,
         errors,
         __0,
@@ -1582,7 +2003,7 @@ fn __action19<
 }
 
 #[allow(unused_variables)]
-fn __action20<
+fn __action26<
     'err,
     'input,
 >(
@@ -1595,13 +2016,13 @@ fn __action20<
 {
     let __start0 = __2.0.clone();
     let __end0 = __2.2.clone();
-    let __temp0 = __action8(
+    let __temp0 = __action12(
         input,
         errors,
         __2,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action6(
+    __action10(
         input,
         errors,
         __0,
@@ -1611,7 +2032,7 @@ fn __action20<
 }
 
 #[allow(unused_variables)]
-fn __action21<
+fn __action27<
     'err,
     'input,
 >(
@@ -1623,14 +2044,14 @@ fn __action21<
 {
     let __start0 = __1.2.clone();
     let __end0 = __1.2.clone();
-    let __temp0 = __action9(
+    let __temp0 = __action13(
         input,
         errors,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action6(
+    __action10(
         input,
         errors,
         __0,
@@ -1640,7 +2061,7 @@ fn __action21<
 }
 
 #[allow(unused_variables)]
-fn __action22<
+fn __action28<
     'err,
     'input,
 >(
@@ -1655,13 +2076,13 @@ fn __action22<
 {
     let __start0 = __0.0.clone();
     let __end0 = __0.2.clone();
-    let __temp0 = __action10(
+    let __temp0 = __action14(
         input,
         errors,
         __0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action16(
+    __action20(
         input,
         errors,
         __temp0,
@@ -1673,7 +2094,7 @@ fn __action22<
 }
 
 #[allow(unused_variables)]
-fn __action23<
+fn __action29<
     'err,
     'input,
 >(
@@ -1687,14 +2108,14 @@ fn __action23<
 {
     let __start0 = __0.0.clone();
     let __end0 = __0.0.clone();
-    let __temp0 = __action11(
+    let __temp0 = __action15(
         input,
         errors,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action16(
+    __action20(
         input,
         errors,
         __temp0,
@@ -1706,7 +2127,7 @@ fn __action23<
 }
 
 #[allow(unused_variables)]
-fn __action24<
+fn __action30<
     'err,
     'input,
 >(
@@ -1720,13 +2141,13 @@ fn __action24<
 {
     let __start0 = __0.0.clone();
     let __end0 = __0.2.clone();
-    let __temp0 = __action10(
+    let __temp0 = __action14(
         input,
         errors,
         __0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action17(
+    __action21(
         input,
         errors,
         __temp0,
@@ -1737,7 +2158,7 @@ fn __action24<
 }
 
 #[allow(unused_variables)]
-fn __action25<
+fn __action31<
     'err,
     'input,
 >(
@@ -1750,14 +2171,14 @@ fn __action25<
 {
     let __start0 = __0.0.clone();
     let __end0 = __0.0.clone();
-    let __temp0 = __action11(
+    let __temp0 = __action15(
         input,
         errors,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action17(
+    __action21(
         input,
         errors,
         __temp0,
@@ -1768,7 +2189,159 @@ fn __action25<
 }
 
 #[allow(unused_variables)]
-fn __action26<
+fn __action32<
+    'err,
+    'input,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexerError>>,
+    __0: (usize, Token<'input>, usize),
+    __1: (usize, Token<'input>, usize),
+    __2: (usize, u32, usize),
+    __3: (usize, Token<'input>, usize),
+    __4: (usize, Token<'input>, usize),
+    __5: (usize, u32, usize),
+    __6: (usize, Token<'input>, usize),
+    __7: (usize, Token<'input>, usize),
+) -> ValueAccessor<'input>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action14(
+        input,
+        errors,
+        __0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action22(
+        input,
+        errors,
+        __temp0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __6,
+        __7,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action33<
+    'err,
+    'input,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexerError>>,
+    __0: (usize, Token<'input>, usize),
+    __1: (usize, u32, usize),
+    __2: (usize, Token<'input>, usize),
+    __3: (usize, Token<'input>, usize),
+    __4: (usize, u32, usize),
+    __5: (usize, Token<'input>, usize),
+    __6: (usize, Token<'input>, usize),
+) -> ValueAccessor<'input>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action15(
+        input,
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action22(
+        input,
+        errors,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __6,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action34<
+    'err,
+    'input,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexerError>>,
+    __0: (usize, Token<'input>, usize),
+    __1: (usize, Token<'input>, usize),
+    __2: (usize, u32, usize),
+    __3: (usize, Token<'input>, usize),
+    __4: (usize, Token<'input>, usize),
+    __5: (usize, u32, usize),
+    __6: (usize, Token<'input>, usize),
+) -> ValueAccessor<'input>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action14(
+        input,
+        errors,
+        __0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action23(
+        input,
+        errors,
+        __temp0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __6,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action35<
+    'err,
+    'input,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexerError>>,
+    __0: (usize, Token<'input>, usize),
+    __1: (usize, u32, usize),
+    __2: (usize, Token<'input>, usize),
+    __3: (usize, Token<'input>, usize),
+    __4: (usize, u32, usize),
+    __5: (usize, Token<'input>, usize),
+) -> ValueAccessor<'input>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action15(
+        input,
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action23(
+        input,
+        errors,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action36<
     'err,
     'input,
 >(
@@ -1783,13 +2356,13 @@ fn __action26<
 {
     let __start0 = __0.0.clone();
     let __end0 = __0.2.clone();
-    let __temp0 = __action10(
+    let __temp0 = __action14(
         input,
         errors,
         __0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action18(
+    __action24(
         input,
         errors,
         __temp0,
@@ -1801,7 +2374,7 @@ fn __action26<
 }
 
 #[allow(unused_variables)]
-fn __action27<
+fn __action37<
     'err,
     'input,
 >(
@@ -1815,14 +2388,14 @@ fn __action27<
 {
     let __start0 = __0.0.clone();
     let __end0 = __0.0.clone();
-    let __temp0 = __action11(
+    let __temp0 = __action15(
         input,
         errors,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action18(
+    __action24(
         input,
         errors,
         __temp0,
@@ -1834,7 +2407,7 @@ fn __action27<
 }
 
 #[allow(unused_variables)]
-fn __action28<
+fn __action38<
     'err,
     'input,
 >(
@@ -1848,13 +2421,13 @@ fn __action28<
 {
     let __start0 = __0.0.clone();
     let __end0 = __0.2.clone();
-    let __temp0 = __action10(
+    let __temp0 = __action14(
         input,
         errors,
         __0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action19(
+    __action25(
         input,
         errors,
         __temp0,
@@ -1865,7 +2438,7 @@ fn __action28<
 }
 
 #[allow(unused_variables)]
-fn __action29<
+fn __action39<
     'err,
     'input,
 >(
@@ -1878,14 +2451,14 @@ fn __action29<
 {
     let __start0 = __0.0.clone();
     let __end0 = __0.0.clone();
-    let __temp0 = __action11(
+    let __temp0 = __action15(
         input,
         errors,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action19(
+    __action25(
         input,
         errors,
         __temp0,
@@ -1896,7 +2469,7 @@ fn __action29<
 }
 
 #[allow(unused_variables)]
-fn __action30<
+fn __action40<
     'err,
     'input,
 >(
@@ -1907,7 +2480,7 @@ fn __action30<
 {
     let __start0 = __0.2.clone();
     let __end0 = __0.2.clone();
-    let __temp0 = __action12(
+    let __temp0 = __action16(
         input,
         errors,
         &__start0,
@@ -1923,7 +2496,7 @@ fn __action30<
 }
 
 #[allow(unused_variables)]
-fn __action31<
+fn __action41<
     'err,
     'input,
 >(
@@ -1935,7 +2508,7 @@ fn __action31<
 {
     let __start0 = __1.0.clone();
     let __end0 = __1.2.clone();
-    let __temp0 = __action13(
+    let __temp0 = __action17(
         input,
         errors,
         __1,
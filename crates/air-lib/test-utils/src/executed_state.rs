@@ -29,8 +29,9 @@ use crate::FoldSubTraceLore;
 use crate::SubTraceDesc;
 
 use air::ExecutionCidState;
-use air_interpreter_cid::value_to_json_cid;
+use air_interpreter_cid::SerializationFormat;
 use air_interpreter_cid::CID;
+use air_interpreter_data::data_cid_format;
 use air_interpreter_data::CanonCidAggregate;
 use air_interpreter_data::ServiceResultAggregate;
 use avm_server::SecurityTetraplet;
@@ -79,7 +80,11 @@ pub fn value_aggregate_cid(
         .unwrap();
 
     let arguments = serde_json::Value::Array(args);
-    let argument_hash = value_to_json_cid(&arguments).unwrap().into_inner().into();
+    let argument_hash = data_cid_format()
+        .value_to_cid(&arguments)
+        .unwrap()
+        .into_inner()
+        .into();
 
     let service_result_agg = ServiceResultAggregate {
         value_cid,
@@ -320,7 +325,7 @@ impl ExecutedCallBuilder {
     }
 
     pub fn unused(self) -> ExecutedState {
-        let value_cid = value_to_json_cid(&self.result).unwrap();
+        let value_cid = data_cid_format().value_to_cid(&self.result).unwrap();
         let value = ValueRef::Unused(value_cid.into());
         ExecutedState::Call(CallResult::Executed(value))
     }
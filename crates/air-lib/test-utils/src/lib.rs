@@ -88,7 +88,7 @@ pub fn trace_from_result(result: &RawAVMOutcome) -> ExecutionTrace {
 }
 
 pub fn data_from_result(result: &RawAVMOutcome) -> InterpreterData {
-    serde_json::from_slice(&result.data).expect("default serializer shouldn't fail")
+    InterpreterData::try_from_slice(&result.data).expect("default serializer shouldn't fail")
 }
 
 pub fn raw_data_from_trace(
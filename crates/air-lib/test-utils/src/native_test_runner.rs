@@ -18,15 +18,26 @@ use crate::test_runner::AirRunner;
 use air_interpreter_interface::RunParameters;
 use avm_server::avm_runner::*;
 use avm_server::into_raw_result;
+use avm_server::KeyPair;
+use avm_server::Signer;
 
 pub struct NativeAirRunner {
     current_peer_id: String,
+    key_pair: Option<KeyPair>,
 }
 
 impl AirRunner for NativeAirRunner {
     fn new(current_peer_id: impl Into<String>) -> Self {
         Self {
             current_peer_id: current_peer_id.into(),
+            key_pair: None,
+        }
+    }
+
+    fn with_key_pair(key_pair: KeyPair) -> Self {
+        Self {
+            current_peer_id: key_pair.peer_id(),
+            key_pair: Some(key_pair),
         }
     }
 
@@ -48,18 +59,33 @@ impl AirRunner for NativeAirRunner {
         let current_peer_id =
             override_current_peer_id.unwrap_or_else(|| self.current_peer_id.clone());
 
-        let outcome = air::execute_air(
-            air.into(),
-            prev_data.into(),
-            data.into(),
-            RunParameters {
-                init_peer_id: init_peer_id.into(),
-                current_peer_id,
-                timestamp,
-                ttl,
-            },
-            raw_call_results,
-        );
+        let air = air.into();
+        let prev_data = prev_data.into();
+        let data = data.into();
+        let run_parameters = RunParameters {
+            init_peer_id: init_peer_id.into(),
+            current_peer_id,
+            timestamp,
+            ttl,
+            require_signatures: self.key_pair.is_some(),
+            instruction_limit: 0,
+            // particles in tests never expire
+            current_time: 0,
+            collect_stats: false,
+        };
+
+        // a host signs results of a current peer, like the sign import of AVMRunner does
+        let outcome = match &self.key_pair {
+            Some(key_pair) => air::execute_air_with_signer(
+                air,
+                prev_data,
+                data,
+                run_parameters,
+                raw_call_results,
+                Box::new(key_pair.clone()),
+            ),
+            None => air::execute_air(air, prev_data, data, run_parameters, raw_call_results),
+        };
         let outcome = RawAVMOutcome::from_interpreter_outcome(outcome)?;
 
         Ok(outcome)
@@ -21,6 +21,7 @@ use crate::wasm_test_runner::WasmAirRunner as AirRunnerImpl;
 
 use super::CallServiceClosure;
 use avm_server::avm_runner::*;
+use avm_server::KeyPair;
 
 use std::collections::HashMap;
 use std::collections::HashSet;
@@ -28,6 +29,9 @@ use std::collections::HashSet;
 pub trait AirRunner {
     fn new(current_call_id: impl Into<String>) -> Self;
 
+    /// Creates a runner that signs produced call results, a peer id is derived from the key pair.
+    fn with_key_pair(key_pair: KeyPair) -> Self;
+
     #[allow(clippy::too_many_arguments)]
     fn call(
         &mut self,
@@ -126,6 +130,15 @@ pub fn create_avm(
     }
 }
 
+pub fn create_avm_with_key_pair(call_service: CallServiceClosure, key_pair: KeyPair) -> TestRunner {
+    let runner = AirRunnerImpl::with_key_pair(key_pair);
+
+    TestRunner {
+        runner,
+        call_service,
+    }
+}
+
 impl TestRunParameters {
     pub fn new(init_peer_id: impl Into<String>, timestamp: u64, ttl: u32) -> Self {
         Self {
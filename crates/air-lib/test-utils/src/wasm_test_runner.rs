@@ -16,6 +16,8 @@
 
 use crate::test_runner::AirRunner;
 use avm_server::avm_runner::*;
+use avm_server::KeyPair;
+use avm_server::Signer;
 
 use once_cell::sync::OnceCell;
 use std::path::PathBuf;
@@ -26,6 +28,7 @@ const AIR_WASM_PATH: &str = "../target/wasm32-wasi/debug/air_interpreter_server.
 
 pub struct WasmAirRunner {
     current_peer_id: String,
+    key_pair: Option<KeyPair>,
     runner: object_pool::Reusable<'static, AVMRunner>,
 }
 
@@ -36,27 +39,40 @@ fn make_pooled_avm_runner() -> AVMRunner {
         PathBuf::from(AIR_WASM_PATH),
         Some(AVM_MAX_HEAP_SIZE),
         logging_mask,
+        None,
+        false,
     )
     .expect("vm should be created")
 }
 
-impl AirRunner for WasmAirRunner {
-    fn new(current_peer_id: impl Into<String>) -> Self {
-        static POOL_CELL: OnceCell<object_pool::Pool<AVMRunner>> = OnceCell::new();
+fn pull_pooled_avm_runner() -> object_pool::Reusable<'static, AVMRunner> {
+    static POOL_CELL: OnceCell<object_pool::Pool<AVMRunner>> = OnceCell::new();
 
-        let pool = POOL_CELL.get_or_init(|| {
-            object_pool::Pool::new(
-                // we create an empty pool and let it fill on demand
-                0,
-                || unreachable!(),
-            )
-        });
+    let pool = POOL_CELL.get_or_init(|| {
+        object_pool::Pool::new(
+            // we create an empty pool and let it fill on demand
+            0,
+            || unreachable!(),
+        )
+    });
 
-        let runner = pool.pull(make_pooled_avm_runner);
+    pool.pull(make_pooled_avm_runner)
+}
 
+impl AirRunner for WasmAirRunner {
+    fn new(current_peer_id: impl Into<String>) -> Self {
         Self {
             current_peer_id: current_peer_id.into(),
-            runner,
+            key_pair: None,
+            runner: pull_pooled_avm_runner(),
+        }
+    }
+
+    fn with_key_pair(key_pair: KeyPair) -> Self {
+        Self {
+            current_peer_id: key_pair.peer_id(),
+            key_pair: Some(key_pair),
+            runner: pull_pooled_avm_runner(),
         }
     }
 
@@ -81,7 +97,10 @@ impl AirRunner for WasmAirRunner {
             init_peer_id,
             timestamp,
             ttl,
+            // particles in tests never expire
+            0,
             current_peer_id,
+            self.key_pair.as_ref(),
             call_results,
         )?)
     }
@@ -111,7 +111,7 @@ impl TraceSlider {
         self.trace.get(position)
     }
 
-    pub(super) fn trace_len(&self) -> usize {
+    pub(crate) fn trace_len(&self) -> usize {
         self.trace.len()
     }
 }
@@ -19,6 +19,7 @@ use super::merger::MergeError;
 use super::state_automata::StateFSMError;
 
 use air_interpreter_data::ExecutedState;
+use air_interpreter_data::MergeConflict;
 use air_interpreter_data::TracePos;
 use thiserror::Error as ThisError;
 
@@ -34,6 +35,24 @@ pub enum TraceHandlerError {
 
     #[error(transparent)]
     StateFSMError(#[from] StateFSMError),
+
+    /// Merge error with the place in traces it happened at, the place is returned separately
+    /// from the error message.
+    #[error("{error}")]
+    MergeConflict {
+        error: MergeError,
+        conflict: Box<MergeConflict>,
+    },
+}
+
+impl TraceHandlerError {
+    /// Returns the place in traces a merge error happened at, if this error is a merge one.
+    pub fn merge_conflict(&self) -> Option<&MergeConflict> {
+        match self {
+            TraceHandlerError::MergeConflict { conflict, .. } => Some(conflict),
+            _ => None,
+        }
+    }
 }
 
 #[derive(ThisError, Debug)]
@@ -47,6 +47,14 @@ impl TraceHandler {
         &self.data_keeper.result_trace
     }
 
+    /// Returns lengths of the whole previous and current traces.
+    pub fn input_trace_lens(&self) -> (usize, usize) {
+        let prev_len = self.data_keeper.prev_slider().trace_len();
+        let current_len = self.data_keeper.current_slider().trace_len();
+
+        (prev_len, current_len)
+    }
+
     pub fn subgraph_sizes(&self) -> (usize, usize) {
         let prev_len = self.data_keeper.prev_slider().subtrace_len();
         let current_len = self.data_keeper.current_slider().subtrace_len();
@@ -54,6 +62,14 @@ impl TraceHandler {
         (prev_len, current_len)
     }
 
+    /// Returns positions of the next states that will be read from the previous and current traces.
+    pub fn subgraph_positions(&self) -> (TracePos, TracePos) {
+        let prev_pos = self.data_keeper.prev_slider().position();
+        let current_pos = self.data_keeper.current_slider().position();
+
+        (prev_pos, current_pos)
+    }
+
     pub fn update_generation(
         &mut self,
         trace_pos: TracePos,
@@ -83,10 +99,22 @@ impl TraceHandler {
     }
 }
 
+impl TraceHandler {
+    fn merge_conflict(&self, start: MergeStart, error: MergeError) -> TraceHandlerError {
+        let conflict = start.into_conflict(&self.data_keeper, &self.fsm_keeper);
+
+        TraceHandlerError::MergeConflict {
+            error,
+            conflict: Box::new(conflict),
+        }
+    }
+}
+
 impl TraceHandler {
     /// Should be called at the beginning of a call execution.
     pub fn meet_call_start(&mut self) -> TraceHandlerResult<MergerCallResult> {
-        try_merge_next_state_as_call(&mut self.data_keeper).map_err(Into::into)
+        let start = MergeStart::from_keeper(&self.data_keeper);
+        try_merge_next_state_as_call(&mut self.data_keeper).map_err(|error| self.merge_conflict(start, error))
     }
 
     /// Should be called when a call instruction was executed successfully. It adds the supplied
@@ -102,8 +130,9 @@ impl TraceHandler {
 }
 
 impl TraceHandler {
-    pub fn meet_ap_start(&mut self) -> TraceHandlerResult<MergerApResult> {
-        try_merge_next_state_as_ap(&mut self.data_keeper).map_err(Into::into)
+    pub fn meet_ap_start(&mut self, kind: StreamKind) -> TraceHandlerResult<MergerApResult> {
+        let start = MergeStart::from_keeper(&self.data_keeper);
+        try_merge_next_state_as_ap(&mut self.data_keeper, kind).map_err(|error| self.merge_conflict(start, error))
     }
 
     pub fn meet_ap_end(&mut self, ap_result: ApResult) {
@@ -112,8 +141,9 @@ impl TraceHandler {
 }
 
 impl TraceHandler {
-    pub fn meet_canon_start(&mut self) -> TraceHandlerResult<MergerCanonResult> {
-        try_merge_next_state_as_canon(&mut self.data_keeper).map_err(Into::into)
+    pub fn meet_canon_start(&mut self, kind: StreamKind) -> TraceHandlerResult<MergerCanonResult> {
+        let start = MergeStart::from_keeper(&self.data_keeper);
+        try_merge_next_state_as_canon(&mut self.data_keeper, kind).map_err(|error| self.merge_conflict(start, error))
     }
 
     pub fn meet_canon_end(&mut self, canon_result: CanonResult) {
@@ -123,7 +153,9 @@ impl TraceHandler {
 
 impl TraceHandler {
     pub fn meet_par_start(&mut self) -> TraceHandlerResult<()> {
-        let ingredients = merger::try_merge_next_state_as_par(&mut self.data_keeper)?;
+        let start = MergeStart::from_keeper(&self.data_keeper);
+        let ingredients = merger::try_merge_next_state_as_par(&mut self.data_keeper)
+            .map_err(|error| self.merge_conflict(start, error))?;
         let par_fsm = ParFSM::from_left_started(ingredients, &mut self.data_keeper)?;
         self.fsm_keeper.push_par(par_fsm);
 
@@ -148,7 +180,9 @@ impl TraceHandler {
 
 impl TraceHandler {
     pub fn meet_fold_start(&mut self, fold_id: u32) -> TraceHandlerResult<()> {
-        let ingredients = try_merge_next_state_as_fold(&mut self.data_keeper)?;
+        let start = MergeStart::from_keeper(&self.data_keeper);
+        let ingredients =
+            try_merge_next_state_as_fold(&mut self.data_keeper).map_err(|error| self.merge_conflict(start, error))?;
         let fold_fsm = FoldFSM::from_fold_start(ingredients, &mut self.data_keeper)?;
         self.fsm_keeper.add_fold(fold_id, fold_fsm);
 
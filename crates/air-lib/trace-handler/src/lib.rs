@@ -29,6 +29,7 @@
 mod data_keeper;
 mod errors;
 mod handler;
+mod merge_conflict;
 pub mod merger;
 mod state_automata;
 
@@ -41,11 +42,15 @@ pub use merger::MergeError;
 pub use state_automata::StateFSMError;
 pub use state_automata::SubgraphType;
 
+pub use air_interpreter_data::ActiveFsm;
+pub use air_interpreter_data::MergeConflict;
+
 pub type TraceHandlerResult<T> = std::result::Result<T, TraceHandlerError>;
 
 use air_interpreter_data::*;
 use data_keeper::DataKeeper;
 use data_keeper::MergeCtx;
+use merge_conflict::MergeStart;
 use merger::MergerFoldResult;
 use merger::ResolvedFold;
 use merger::ResolvedSubTraceDescs;
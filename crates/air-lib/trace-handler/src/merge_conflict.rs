@@ -0,0 +1,76 @@
+/*
+ * Copyright 2023 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use crate::data_keeper::DataKeeper;
+use crate::data_keeper::TraceSlider;
+use crate::state_automata::FSMKeeper;
+
+use air_interpreter_data::MergeConflict;
+use air_interpreter_data::TracePos;
+
+/// Slider positions before a merger reads next states, they are cheap to take before every merge,
+/// while a conflict is built from them only if the merge fails.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct MergeStart {
+    prev_position: TracePos,
+    current_position: TracePos,
+    prev_subtrace_len: usize,
+    current_subtrace_len: usize,
+}
+
+impl MergeStart {
+    pub(crate) fn from_keeper(data_keeper: &DataKeeper) -> Self {
+        let prev_slider = data_keeper.prev_slider();
+        let current_slider = data_keeper.current_slider();
+
+        Self {
+            prev_position: prev_slider.position(),
+            current_position: current_slider.position(),
+            prev_subtrace_len: prev_slider.subtrace_len(),
+            current_subtrace_len: current_slider.subtrace_len(),
+        }
+    }
+
+    /// Mergers don't change FSMs, so the active ones are the same as before the merge.
+    pub(crate) fn into_conflict(self, data_keeper: &DataKeeper, fsm_keeper: &FSMKeeper) -> MergeConflict {
+        let prev_state = state_at(data_keeper.prev_slider(), self.prev_position, self.prev_subtrace_len);
+        let current_state = state_at(
+            data_keeper.current_slider(),
+            self.current_position,
+            self.current_subtrace_len,
+        );
+
+        MergeConflict {
+            prev_position: self.prev_position,
+            current_position: self.current_position,
+            prev_state,
+            current_state,
+            prev_subtrace_len: self.prev_subtrace_len,
+            current_subtrace_len: self.current_subtrace_len,
+            active_fsms: fsm_keeper.active_fsms(),
+        }
+    }
+}
+
+fn state_at(slider: &TraceSlider, position: TracePos, subtrace_len: usize) -> Option<serde_json::Value> {
+    if subtrace_len == 0 {
+        return None;
+    }
+
+    slider
+        .state_at_position(position)
+        .map(|state| serde_json::to_value(state).expect("default serializer shouldn't fail"))
+}
@@ -34,12 +34,18 @@ pub struct MetApResult {
     pub value_source: ValueSource,
 }
 
-pub(crate) fn try_merge_next_state_as_ap(data_keeper: &mut DataKeeper) -> MergeResult<MergerApResult> {
+pub(crate) fn try_merge_next_state_as_ap(
+    data_keeper: &mut DataKeeper,
+    kind: StreamKind,
+) -> MergeResult<MergerApResult> {
     use ExecutedState::Ap;
     use PreparationScheme::*;
 
     let prev_state = data_keeper.prev_slider_mut().next_state();
     let current_state = data_keeper.current_slider_mut().next_state();
+    for state in [&prev_state, &current_state] {
+        check_ap_kind(state, kind)?;
+    }
 
     match (prev_state, current_state) {
         (Some(Ap(prev_ap)), Some(Ap(_))) => prepare_merge_result(prev_ap, Both, data_keeper),
@@ -56,6 +62,20 @@ pub(crate) fn try_merge_next_state_as_ap(data_keeper: &mut DataKeeper) -> MergeR
     }
 }
 
+/// States of stream maps and ordinary streams mustn't be mixed up, even if they look the same.
+fn check_ap_kind(state: &Option<ExecutedState>, expected_kind: StreamKind) -> MergeResult<()> {
+    match state {
+        Some(ExecutedState::Ap(ap_result)) if ap_result.kind != expected_kind => {
+            let ap_error = ApResultError::StreamKindMismatch {
+                ap_result: ap_result.clone(),
+                expected_kind,
+            };
+            Err(MergeError::IncorrectApResult(ap_error))
+        }
+        _ => Ok(()),
+    }
+}
+
 macro_rules! to_maybe_generation {
     ($ap_result:ident, $generations:expr, $error_ty:ident) => {
         match $generations.len() {
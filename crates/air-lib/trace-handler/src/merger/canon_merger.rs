@@ -37,11 +37,17 @@ pub enum MergerCanonResult {
     },
 }
 
-pub(crate) fn try_merge_next_state_as_canon(data_keeper: &mut DataKeeper) -> MergeResult<MergerCanonResult> {
+pub(crate) fn try_merge_next_state_as_canon(
+    data_keeper: &mut DataKeeper,
+    kind: StreamKind,
+) -> MergeResult<MergerCanonResult> {
     use ExecutedState::Canon;
 
     let prev_state = data_keeper.prev_slider_mut().next_state();
     let current_state = data_keeper.current_slider_mut().next_state();
+    for state in [&prev_state, &current_state] {
+        check_canon_kind(state, kind)?;
+    }
 
     match (prev_state, current_state) {
         (Some(Canon(prev_canon)), Some(Canon(current_canon))) => prepare_both_canon_result(prev_canon, &current_canon),
@@ -56,6 +62,20 @@ pub(crate) fn try_merge_next_state_as_canon(data_keeper: &mut DataKeeper) -> Mer
     }
 }
 
+/// A canon of a stream map produces an object, so its state mustn't be used for an ordinary stream.
+fn check_canon_kind(state: &Option<ExecutedState>, expected_kind: StreamKind) -> MergeResult<()> {
+    match state {
+        Some(ExecutedState::Canon(canon_result)) if canon_result.kind != expected_kind => {
+            let canon_error = CanonResultError::StreamKindMismatch {
+                canon_result: canon_result.clone(),
+                expected_kind,
+            };
+            Err(MergeError::IncorrectCanonResult(canon_error))
+        }
+        _ => Ok(()),
+    }
+}
+
 fn prepare_both_canon_result(
     prev_canon_result: CanonResult,
     current_canon_result: &CanonResult,
@@ -22,6 +22,7 @@ use super::KeeperError;
 use super::ValueRef;
 
 use air_interpreter_data::CanonResult;
+use air_interpreter_data::StreamKind;
 use air_interpreter_data::TracePos;
 use thiserror::Error as ThisError;
 
@@ -58,6 +59,13 @@ pub enum ApResultError {
     /// Error occurred when Ap results contains not 1 generation in destination.
     #[error("{0:?} ap result contains inappropriate generation count in destination")]
     InvalidDstGenerations(ApResult),
+
+    /// Error occurred when an ap result was produced for another kind of stream.
+    #[error("{ap_result:?} ap result is produced for {}, but {expected_kind} is expected", ap_result.kind)]
+    StreamKindMismatch {
+        ap_result: ApResult,
+        expected_kind: StreamKind,
+    },
 }
 
 #[derive(ThisError, Debug)]
@@ -83,6 +91,13 @@ pub enum CanonResultError {
         prev_canon_result: CanonResult,
         current_canon_result: CanonResult,
     },
+
+    /// Error occurred when a canon result was produced for another kind of stream.
+    #[error("{canon_result:?} canon result is produced for {}, but {expected_kind} is expected", canon_result.kind)]
+    StreamKindMismatch {
+        canon_result: CanonResult,
+        expected_kind: StreamKind,
+    },
 }
 
 #[derive(ThisError, Debug)]
@@ -65,6 +65,14 @@ impl FoldFSM {
         Ok(fold_fsm)
     }
 
+    pub(crate) fn as_active_fsm(&self, fold_id: u32) -> ActiveFsm {
+        ActiveFsm::Fold {
+            fold_id,
+            prev_states_count: self.prev_fold.fold_states_count,
+            current_states_count: self.current_fold.fold_states_count,
+        }
+    }
+
     pub(crate) fn meet_iteration_start(&mut self, value_pos: TracePos, data_keeper: &mut DataKeeper) -> FSMResult<()> {
         let prev_pos = data_keeper.new_to_prev_pos.get_by_left(&value_pos);
         let current_pos = data_keeper.new_to_current_pos.get_by_left(&value_pos);
@@ -18,6 +18,7 @@ use super::FSMResult;
 use super::FoldFSM;
 use super::ParFSM;
 use super::StateFSMError;
+use crate::ActiveFsm;
 
 use std::collections::HashMap;
 
@@ -50,6 +51,22 @@ impl FSMKeeper {
             .ok_or(StateFSMError::FoldFSMNotFound(fold_id))
     }
 
+    /// Pars are ordered from the outermost, folds follow them ordered by their ids.
+    pub(crate) fn active_fsms(&self) -> Vec<ActiveFsm> {
+        let mut folds = self.fold_map.iter().collect::<Vec<_>>();
+        folds.sort_unstable_by_key(|(fold_id, _)| **fold_id);
+
+        self.par_stack
+            .iter()
+            .map(ParFSM::as_active_fsm)
+            .chain(
+                folds
+                    .into_iter()
+                    .map(|(fold_id, fold_fsm)| fold_fsm.as_active_fsm(*fold_id)),
+            )
+            .collect()
+    }
+
     pub(crate) fn extract_fold(&mut self, fold_id: u32) -> FSMResult<FoldFSM> {
         self.fold_map
             .remove(&fold_id)
@@ -33,6 +33,7 @@ pub(super) use par_fsm::ParFSM;
 use super::data_keeper::KeeperError;
 use super::merger::MergeCtxType;
 use super::merger::MergerParResult;
+use super::ActiveFsm;
 use super::DataKeeper;
 use super::ExecutedState;
 use super::FoldResult;
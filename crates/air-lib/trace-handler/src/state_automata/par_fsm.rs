@@ -63,6 +63,13 @@ impl ParFSM {
         Ok(par_fsm)
     }
 
+    pub(crate) fn as_active_fsm(&self) -> ActiveFsm {
+        ActiveFsm::Par {
+            prev_par: (self.prev_par.left_size, self.prev_par.right_size),
+            current_par: (self.current_par.left_size, self.current_par.right_size),
+        }
+    }
+
     pub(crate) fn left_completed(&mut self, data_keeper: &mut DataKeeper) {
         self.par_builder.track(data_keeper, SubgraphType::Left);
         self.state_handler.handle_subgraph_end(data_keeper, SubgraphType::Left);
@@ -109,7 +109,7 @@ impl<W: io::Write> Beautifier<W> {
 
     /// Emit beautified code for the `air_script`.
     pub fn beautify(&mut self, air_script: &str) -> Result<(), BeautifyError> {
-        let tree = air_parser::parse(air_script).map_err(BeautifyError::Parse)?;
+        let tree = air_parser::parse_with_comments(air_script).map_err(BeautifyError::Parse)?;
         self.beautify_ast(tree)
     }
 
@@ -125,7 +125,9 @@ impl<W: io::Write> Beautifier<W> {
         match node {
             ast::Instruction::Call(call) => self.beautify_call(call, indent),
             ast::Instruction::Ap(ap) => self.beautify_simple(ap, indent),
+            ast::Instruction::ApMap(ap_map) => self.beautify_simple(ap_map, indent),
             ast::Instruction::Canon(canon) => self.beautify_simple(canon, indent),
+            ast::Instruction::CanonMap(canon_map) => self.beautify_simple(canon_map, indent),
             ast::Instruction::Seq(seq) => self.beautify_seq(seq, indent),
             ast::Instruction::Par(par) => self.beautify_par(par, indent),
             ast::Instruction::Xor(xor) => self.beautify_xor(xor, indent),
@@ -138,10 +140,14 @@ impl<W: io::Write> Beautifier<W> {
             ast::Instruction::FoldStream(fold_stream) => {
                 self.beautify_fold_stream(fold_stream, indent)
             }
+            ast::Instruction::FoldStreamMap(fold_stream_map) => {
+                self.beautify_fold_stream_map(fold_stream_map, indent)
+            }
             ast::Instruction::Never(never) => self.beautify_simple(never, indent),
             ast::Instruction::New(new) => self.beautify_new(new, indent),
             ast::Instruction::Next(next) => self.beautify_simple(next, indent),
             ast::Instruction::Null(null) => self.beautify_simple(null, indent),
+            ast::Instruction::Commented(commented) => self.beautify_commented(commented, indent),
             ast::Instruction::Error => self.beautify_simple("error", indent),
         }
     }
@@ -166,6 +172,28 @@ impl<W: io::Write> Beautifier<W> {
         writeln!(&mut self.output, "{instruction}")
     }
 
+    fn beautify_commented(
+        &mut self,
+        commented: &ast::Commented<'_>,
+        indent: usize,
+    ) -> io::Result<()> {
+        for comment in &commented.leading {
+            self.beautify_comment(comment, indent)?;
+        }
+        self.beautify_walker(&commented.instruction, indent)?;
+        for comment in &commented.trailing {
+            self.beautify_comment(comment, indent)?;
+        }
+        Ok(())
+    }
+
+    fn beautify_comment(&mut self, comment: &ast::Comment<'_>, indent: usize) -> io::Result<()> {
+        let text = comment.text.trim_start_matches(';').trim();
+
+        fmt_indent(&mut self.output, indent)?;
+        writeln!(&mut self.output, "# {text}")
+    }
+
     fn beautify_seq(&mut self, seq: &ast::Seq<'_>, indent: usize) -> io::Result<()> {
         self.beautify_walker(&seq.0, indent)?;
         self.beautify_walker(&seq.1, indent)
@@ -215,6 +243,14 @@ impl<W: io::Write> Beautifier<W> {
         compound!(self, indent, fold)
     }
 
+    fn beautify_fold_stream_map(
+        &mut self,
+        fold: &ast::FoldStreamMap<'_>,
+        indent: usize,
+    ) -> io::Result<()> {
+        compound!(self, indent, fold)
+    }
+
     fn beautify_new(&mut self, new: &ast::New<'_>, indent: usize) -> io::Result<()> {
         compound!(self, indent, new)
     }
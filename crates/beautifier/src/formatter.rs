@@ -0,0 +1,71 @@
+/*
+ * Copyright 2023 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use crate::BeautifyError;
+use crate::DEFAULT_INDENT_STEP;
+
+use air_parser::ast;
+
+use std::io;
+
+/// Canonical AIR formatter.
+///
+/// Unlike the `Beautifier`, it emits real AIR: every instruction with nested instructions
+/// is put on its own line and children are indented, the rest is printed on one line,
+/// see `air_parser::ast::print_indented`. Formatted script is parsed to the same AST, except for comments placed among instruction
+/// arguments, they are moved before the instruction.
+pub struct Formatter<W: io::Write> {
+    output: W,
+    indent_step: usize,
+}
+
+impl<W: io::Write> Formatter<W> {
+    /// Formatter for the output with default indentation step.
+    pub fn new(output: W) -> Self {
+        Self {
+            output,
+            indent_step: DEFAULT_INDENT_STEP,
+        }
+    }
+
+    /// Formatter for the output with custom indentation step.
+    pub fn new_with_indent(output: W, indent_step: usize) -> Self {
+        Self {
+            output,
+            indent_step,
+        }
+    }
+
+    /// Unwrap the Formatter into the underlying writer.
+    pub fn into_inner(self) -> W {
+        self.output
+    }
+
+    /// Emit canonically formatted code for the `air_script`.
+    pub fn format(&mut self, air_script: &str) -> Result<(), BeautifyError> {
+        let tree = air_parser::parse_with_comments(air_script).map_err(BeautifyError::Parse)?;
+        self.format_ast(tree)
+    }
+
+    /// Emit canonically formatted code for the `ast`.
+    pub fn format_ast<'i>(
+        &mut self,
+        ast: impl AsRef<ast::Instruction<'i>>,
+    ) -> Result<(), BeautifyError> {
+        let air = ast::print_indented(ast.as_ref(), self.indent_step);
+        Ok(writeln!(&mut self.output, "{air}")?)
+    }
+}
@@ -26,8 +26,10 @@
 )]
 
 mod beautifier;
+mod formatter;
 
 pub use crate::beautifier::{Beautifier, BeautifyError, DEFAULT_INDENT_STEP};
+pub use crate::formatter::Formatter;
 
 use std::io;
 
@@ -40,7 +42,7 @@ pub fn beautify(air_script: &str, output: &mut impl io::Write) -> Result<(), Bea
 /// Beautify the `air_script` to a string with default settings.
 /// Return error on parsing error.
 pub fn beautify_to_string(air_script: &str) -> Result<String, String> {
-    let ast = air_parser::parse(air_script)?;
+    let ast = air_parser::parse_with_comments(air_script)?;
     let mut buffer = vec![];
     let mut beautifier = Beautifier::new(&mut buffer);
 
@@ -49,5 +51,23 @@ pub fn beautify_to_string(air_script: &str) -> Result<String, String> {
     Ok(unsafe { String::from_utf8_unchecked(buffer) })
 }
 
+/// Format the `air_script` canonically with default settings to the `output`.
+pub fn format(air_script: &str, output: &mut impl io::Write) -> Result<(), BeautifyError> {
+    let mut formatter = Formatter::new(output);
+    formatter.format(air_script)
+}
+
+/// Format the `air_script` canonically to a string with default settings.
+/// Return error on parsing error.
+pub fn format_to_string(air_script: &str) -> Result<String, String> {
+    let ast = air_parser::parse_with_comments(air_script)?;
+    let mut buffer = vec![];
+    let mut formatter = Formatter::new(&mut buffer);
+
+    formatter.format_ast(&ast).unwrap();
+    // Safety: safe because Formatter produces valid utf8 strings
+    Ok(unsafe { String::from_utf8_unchecked(buffer) })
+}
+
 #[cfg(test)]
 mod tests;
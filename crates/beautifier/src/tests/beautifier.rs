@@ -254,6 +254,20 @@ fold $var i:
     );
 }
 
+#[test]
+fn fold_stream_map() {
+    let script = r#"(seq (ap ("key" "value") %map) (fold %map i (null)))"#;
+    let output = beautify_to_string(script).unwrap();
+
+    assert_eq!(
+        output,
+        r#"ap ("key" "value") %map
+fold %map i:
+    null
+"#
+    );
+}
+
 #[test]
 fn call_var() {
     let script = "(call \"{0}\" (\"a\" \"b\") [\"stream_1\" \"stream_2\"] streamvar)";
@@ -349,3 +363,21 @@ fn deeply_nested() {
     let expected = include_str!("deeply_nested_expected.txt");
     assert_eq!(output, expected);
 }
+
+#[test]
+fn comments() {
+    let script = r#";; first
+(seq
+    (null) ;; after first
+    (null))"#;
+    let output = beautify_to_string(script).unwrap();
+
+    assert_eq!(
+        output,
+        "# first
+null
+# after first
+null
+"
+    );
+}
@@ -0,0 +1,185 @@
+/*
+ * Copyright 2023 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+#![deny(
+    dead_code,
+    nonstandard_style,
+    unused_imports,
+    unused_mut,
+    unused_variables,
+    unused_unsafe,
+    unreachable_patterns
+)]
+
+use crate::{beautify_to_string, format_to_string, Formatter};
+
+use std::path::{Path, PathBuf};
+
+#[test]
+fn nested_instructions() {
+    let script = r#"(seq (call "peer" ("service" "function") [%init_peer_id%] result) (xor (null) (fail %last_error%)))"#;
+    let output = format_to_string(script).unwrap();
+
+    assert_eq!(
+        output,
+        r#"(seq
+    (call "peer" ("service" "function") [%init_peer_id%] result)
+    (xor
+        (null)
+        (fail %last_error%)
+    )
+)
+"#
+    );
+}
+
+#[test]
+fn call_without_output() {
+    let script = r#"(call "peer" ("service" "function") ["a" 1])"#;
+    let output = format_to_string(script).unwrap();
+
+    assert_eq!(
+        output,
+        r#"(call "peer" ("service" "function") ["a" 1])
+"#
+    );
+}
+
+#[test]
+fn fold_with_last_instruction() {
+    let script = r#"(seq (ap 1 $stream) (fold $stream i (next i) (never)))"#;
+    let output = format_to_string(script).unwrap();
+
+    assert_eq!(
+        output,
+        r#"(seq
+    (ap 1 $stream)
+    (fold $stream i
+        (next i)
+        (never)
+    )
+)
+"#
+    );
+}
+
+#[test]
+fn whole_float_stays_float() {
+    let script = "(ap 1.0 $stream)";
+    let output = format_to_string(script).unwrap();
+
+    assert_eq!(output, "(ap 1.0 $stream)\n");
+}
+
+#[test]
+fn custom_indent() {
+    let script = "(par (null) (never))";
+    let mut formatter = Formatter::new_with_indent(vec![], 2);
+    formatter.format(script).unwrap();
+
+    let output = String::from_utf8(formatter.into_inner()).unwrap();
+    assert_eq!(output, "(par\n  (null)\n  (never)\n)\n");
+}
+
+fn check_round_trip(script: &str) {
+    let formatted = format_to_string(script).unwrap();
+
+    // AST contains positions, so the structure is compared through position-independent outputs
+    assert_eq!(format_to_string(&formatted).unwrap(), formatted);
+    assert_eq!(
+        beautify_to_string(&formatted).unwrap(),
+        beautify_to_string(script).unwrap()
+    );
+}
+
+/// Scripts of the air tests corpus that aren't valid AIR anymore, they're kept for ignored tests.
+const INVALID_CORPUS_SCRIPTS: &[&str] = &["integration/scripts/network_explore.air"];
+
+fn collect_air_scripts(dir: &Path, scripts: &mut Vec<PathBuf>) {
+    for entry in std::fs::read_dir(dir).unwrap() {
+        let path = entry.unwrap().path();
+        if path.is_dir() {
+            collect_air_scripts(&path, scripts);
+        } else if path
+            .extension()
+            .map_or(false, |extension| extension == "air")
+        {
+            scripts.push(path);
+        }
+    }
+}
+
+#[test]
+fn deeply_nested_round_trip() {
+    check_round_trip(include_str!("deeply_nested.air"));
+}
+
+#[test]
+fn air_tests_corpus_round_trip() {
+    let corpus_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("../../air/tests");
+    let mut scripts = vec![];
+    collect_air_scripts(&corpus_dir, &mut scripts);
+    assert!(!scripts.is_empty(), "no scripts found in {:?}", corpus_dir);
+
+    for path in scripts {
+        let script = std::fs::read_to_string(&path).unwrap();
+        let is_invalid = INVALID_CORPUS_SCRIPTS
+            .iter()
+            .any(|invalid| path.ends_with(invalid));
+        match air_parser::parse(&script) {
+            Ok(_) => assert!(
+                !is_invalid,
+                "{:?} is valid, remove it from the invalid list",
+                path
+            ),
+            Err(_) if is_invalid => continue,
+            Err(error) => panic!("{:?} isn't valid AIR: {}", path, error),
+        }
+
+        let result = std::panic::catch_unwind(|| check_round_trip(&script));
+        assert!(result.is_ok(), "{:?} doesn't round-trip", path);
+    }
+}
+
+#[test]
+fn comments_are_kept() {
+    let script = r#";; script
+(seq
+    (null) ;; first
+    (par ;; branches
+        (null)
+        (never)
+        ;; last
+    )
+)
+"#;
+    let output = format_to_string(script).unwrap();
+
+    assert_eq!(
+        output,
+        r#";; script
+(seq
+    (null) ;; first
+    (par
+        ;; branches
+        (null)
+        (never) ;; last
+    )
+)
+"#
+    );
+    assert_eq!(format_to_string(&output).unwrap(), output);
+}
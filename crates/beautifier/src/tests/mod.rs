@@ -25,6 +25,7 @@
 )]
 
 mod beautifier;
+mod formatter;
 
 use crate::{beautify, beautify_to_string, BeautifyError};
 
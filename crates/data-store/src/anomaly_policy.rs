@@ -0,0 +1,439 @@
+/*
+ * Copyright 2023 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use crate::AnomalyThresholds;
+use crate::ExecutionSummary;
+
+use std::collections::hash_map::RandomState;
+use std::collections::HashMap;
+use std::collections::VecDeque;
+use std::hash::BuildHasher;
+use std::hash::Hasher;
+use std::sync::Mutex;
+use std::time::Duration;
+use std::time::Instant;
+
+/// Decides which executions are anomalies worth collecting.
+///
+/// An execution is an anomaly if any of the triggers fires, then anomalies are thinned out
+/// by random sampling, a per-particle rate limit and a global limit, so a storm of anomalies
+/// can't fill a disk.
+/// The policy is shared between concurrently executed particles, so its state is behind a lock.
+#[derive(Debug)]
+pub struct AnomalyPolicy {
+    config: AnomalyPolicyConfig,
+    state: Mutex<PolicyState>,
+}
+
+#[derive(Debug, Clone)]
+pub struct AnomalyPolicyConfig {
+    /// Thresholds of execution time, memory delta and return codes.
+    pub thresholds: AnomalyThresholds,
+
+    /// Data growing by more bytes than this within one execution is an anomaly.
+    /// The trace makes up the most of data, so its growth is estimated without deserialization.
+    pub data_growth: Option<usize>,
+
+    /// An execution producing more call requests than this is an anomaly.
+    pub call_requests_count: Option<usize>,
+
+    /// An execution slower than a percentile of recent executions is an anomaly.
+    pub latency_percentile: Option<LatencyPercentile>,
+
+    /// Limits how many anomalies of the same particle are collected.
+    pub rate_limit: Option<RateLimit>,
+
+    /// Limits how many anomalies of all particles are collected,
+    /// so many misbehaving particles together can't fill a disk either.
+    /// The limit is per period, a total size of kept anomalies is capped by a store,
+    /// e.g. by `FsDataStoreConfig::max_anomalies_size`.
+    pub global_limit: Option<GlobalLimit>,
+
+    /// A share of detected anomalies which are collected, from 0.0 to 1.0.
+    pub sampling_rate: f64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LatencyPercentile {
+    /// A percentile of execution time of recent executions, from 0.0 to 100.0.
+    pub percentile: f64,
+
+    /// How many recent executions are taken into account.
+    pub window_size: usize,
+
+    /// The trigger doesn't fire until the window has that many executions.
+    pub min_samples: usize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RateLimit {
+    /// How many anomalies of a particle are collected within the period.
+    pub max_anomalies: usize,
+
+    pub period: Duration,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GlobalLimit {
+    /// How many anomalies of all particles are collected within the period.
+    pub max_anomalies: usize,
+
+    /// How many bytes of anomalies are collected within the period,
+    /// an anomaly size is estimated by sizes of prev and result data.
+    pub max_bytes: usize,
+
+    pub period: Duration,
+}
+
+#[derive(Debug, Default)]
+struct PolicyState {
+    /// Execution time of recent executions in the order they were checked.
+    latencies: VecDeque<Duration>,
+
+    /// Times when anomalies of a particle were collected within the rate limit period.
+    collected: HashMap<String, VecDeque<Instant>>,
+
+    /// Times and sizes of anomalies of all particles collected within the global limit period.
+    collected_total: VecDeque<(Instant, usize)>,
+}
+
+impl Default for AnomalyPolicyConfig {
+    fn default() -> Self {
+        Self {
+            thresholds: AnomalyThresholds::default(),
+            data_growth: None,
+            call_requests_count: None,
+            latency_percentile: None,
+            rate_limit: Some(RateLimit {
+                max_anomalies: 3,
+                period: Duration::from_secs(60 * 60),
+            }),
+            global_limit: Some(GlobalLimit {
+                max_anomalies: 100,
+                max_bytes: 1024 * 1024 * 1024,
+                period: Duration::from_secs(60 * 60),
+            }),
+            sampling_rate: 1.0,
+        }
+    }
+}
+
+impl AnomalyPolicy {
+    pub fn new(config: AnomalyPolicyConfig) -> Self {
+        Self {
+            config,
+            state: <_>::default(),
+        }
+    }
+
+    pub fn config(&self) -> &AnomalyPolicyConfig {
+        &self.config
+    }
+
+    /// Returns true if the execution is an anomaly which should be collected.
+    /// It must be called for every execution, since recent execution time is tracked.
+    pub fn detect_anomaly(&self, execution: &ExecutionSummary<'_>) -> bool {
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+
+        // latency is checked first to track every execution
+        let is_anomaly = self.is_slow(&mut state.latencies, execution.execution_time)
+            | self.config.thresholds.detect_anomaly(execution)
+            | self.is_data_grown(execution)
+            | self.has_many_call_requests(execution);
+
+        let anomaly_size = execution.prev_data_len + execution.outcome.data.len();
+        let is_collected = is_anomaly
+            && is_sampled(self.config.sampling_rate)
+            && self.fits_global_limit(&mut state.collected_total, anomaly_size)
+            && self.fits_rate_limit(&mut state.collected, execution.particle_id);
+
+        // the global limit is accounted only after the per-particle one has passed
+        if is_collected && self.config.global_limit.is_some() {
+            state
+                .collected_total
+                .push_back((Instant::now(), anomaly_size));
+        }
+
+        is_collected
+    }
+
+    fn is_slow(&self, latencies: &mut VecDeque<Duration>, execution_time: Duration) -> bool {
+        let latency_percentile = match self.config.latency_percentile {
+            Some(latency_percentile) => latency_percentile,
+            None => return false,
+        };
+
+        let is_slow = latencies.len() >= latency_percentile.min_samples
+            && percentile(latencies, latency_percentile.percentile)
+                .map_or(false, |threshold| execution_time > threshold);
+
+        latencies.push_back(execution_time);
+        while latencies.len() > latency_percentile.window_size {
+            latencies.pop_front();
+        }
+
+        is_slow
+    }
+
+    fn is_data_grown(&self, execution: &ExecutionSummary<'_>) -> bool {
+        let data_growth = execution
+            .outcome
+            .data
+            .len()
+            .saturating_sub(execution.prev_data_len);
+
+        self.config
+            .data_growth
+            .map_or(false, |max_growth| data_growth > max_growth)
+    }
+
+    fn has_many_call_requests(&self, execution: &ExecutionSummary<'_>) -> bool {
+        let call_requests_count = execution.outcome.call_requests.len();
+
+        self.config
+            .call_requests_count
+            .map_or(false, |max_count| call_requests_count > max_count)
+    }
+
+    fn fits_rate_limit(
+        &self,
+        collected: &mut HashMap<String, VecDeque<Instant>>,
+        particle_id: &str,
+    ) -> bool {
+        let rate_limit = match self.config.rate_limit {
+            Some(rate_limit) => rate_limit,
+            None => return true,
+        };
+
+        let now = Instant::now();
+        let is_recent = |time: &Instant| now.duration_since(*time) < rate_limit.period;
+
+        // forget particles that have no recent anomalies, so the state doesn't grow forever
+        collected.retain(|_, times| times.back().map_or(false, is_recent));
+
+        let times = collected.entry(particle_id.to_string()).or_default();
+        while times.front().map_or(false, |time| !is_recent(time)) {
+            times.pop_front();
+        }
+
+        if times.len() >= rate_limit.max_anomalies {
+            return false;
+        }
+
+        times.push_back(now);
+        true
+    }
+
+    fn fits_global_limit(
+        &self,
+        collected_total: &mut VecDeque<(Instant, usize)>,
+        anomaly_size: usize,
+    ) -> bool {
+        let global_limit = match self.config.global_limit {
+            Some(global_limit) => global_limit,
+            None => return true,
+        };
+
+        let now = Instant::now();
+        while collected_total.front().map_or(false, |(time, _)| {
+            now.duration_since(*time) >= global_limit.period
+        }) {
+            collected_total.pop_front();
+        }
+
+        let collected_bytes = collected_total.iter().map(|(_, size)| size).sum::<usize>();
+        collected_total.len() < global_limit.max_anomalies
+            && collected_bytes + anomaly_size <= global_limit.max_bytes
+    }
+}
+
+/// Returns the nearest-rank percentile of the values.
+fn percentile(values: &VecDeque<Duration>, percentile: f64) -> Option<Duration> {
+    if values.is_empty() {
+        return None;
+    }
+
+    let mut values = values.iter().copied().collect::<Vec<_>>();
+    values.sort_unstable();
+
+    let rank = (percentile.clamp(0.0, 100.0) / 100.0 * values.len() as f64).ceil() as usize;
+    let index = rank.saturating_sub(1).min(values.len() - 1);
+    Some(values[index])
+}
+
+fn is_sampled(sampling_rate: f64) -> bool {
+    if sampling_rate >= 1.0 {
+        return true;
+    }
+    if sampling_rate <= 0.0 {
+        return false;
+    }
+
+    // every RandomState is seeded with different keys, that's random enough for sampling
+    let random = RandomState::new().build_hasher().finish();
+    (random as f64 / u64::MAX as f64) < sampling_rate
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use avm_interface::raw_outcome::RawAVMOutcome;
+
+    fn outcome(ret_code: i64, data_len: usize) -> RawAVMOutcome {
+        RawAVMOutcome {
+            ret_code,
+            error_message: String::new(),
+            data: vec![0; data_len],
+            call_requests: <_>::default(),
+            next_peer_pks: vec![],
+            stats: None,
+            merge_conflict: None,
+            error_position: None,
+        }
+    }
+
+    fn summary<'a>(
+        particle_id: &'a str,
+        execution_time: Duration,
+        outcome: &'a RawAVMOutcome,
+    ) -> ExecutionSummary<'a> {
+        ExecutionSummary {
+            particle_id,
+            current_peer_id: "peer",
+            execution_time,
+            memory_delta: 0,
+            prev_data_len: 0,
+            outcome,
+        }
+    }
+
+    fn policy(config: AnomalyPolicyConfig) -> AnomalyPolicy {
+        AnomalyPolicy::new(AnomalyPolicyConfig {
+            rate_limit: None,
+            ..config
+        })
+    }
+
+    #[test]
+    fn triggers_fire_independently() {
+        let policy = policy(AnomalyPolicyConfig {
+            data_growth: Some(100),
+            ..<_>::default()
+        });
+        let fast = Duration::from_millis(1);
+
+        assert!(!policy.detect_anomaly(&summary("particle", fast, &outcome(0, 100))));
+        assert!(policy.detect_anomaly(&summary("particle", fast, &outcome(0, 101))));
+        assert!(policy.detect_anomaly(&summary(
+            "particle",
+            Duration::from_secs(2),
+            &outcome(0, 0)
+        )));
+    }
+
+    #[test]
+    fn latency_percentile_over_window() {
+        let policy = policy(AnomalyPolicyConfig {
+            latency_percentile: Some(LatencyPercentile {
+                percentile: 90.0,
+                window_size: 10,
+                min_samples: 10,
+            }),
+            ..<_>::default()
+        });
+        let outcome = outcome(0, 0);
+
+        for millis in 1..=10 {
+            let execution = summary("particle", Duration::from_millis(millis), &outcome);
+            assert!(
+                !policy.detect_anomaly(&execution),
+                "window isn't filled yet"
+            );
+        }
+
+        let slow = summary("particle", Duration::from_millis(11), &outcome);
+        assert!(policy.detect_anomaly(&slow));
+        let usual = summary("particle", Duration::from_millis(5), &outcome);
+        assert!(!policy.detect_anomaly(&usual));
+    }
+
+    #[test]
+    fn anomalies_are_limited_per_particle() {
+        let policy = AnomalyPolicy::new(AnomalyPolicyConfig {
+            thresholds: AnomalyThresholds {
+                error_codes: crate::AnomalyErrorCodes::Any,
+                ..<_>::default()
+            },
+            rate_limit: Some(RateLimit {
+                max_anomalies: 2,
+                period: Duration::from_secs(60),
+            }),
+            ..<_>::default()
+        });
+        let failed = outcome(1, 0);
+        let fast = Duration::from_millis(1);
+
+        assert!(policy.detect_anomaly(&summary("particle_1", fast, &failed)));
+        assert!(policy.detect_anomaly(&summary("particle_1", fast, &failed)));
+        assert!(!policy.detect_anomaly(&summary("particle_1", fast, &failed)));
+        assert!(policy.detect_anomaly(&summary("particle_2", fast, &failed)));
+    }
+
+    #[test]
+    fn anomalies_are_limited_globally() {
+        let policy = AnomalyPolicy::new(AnomalyPolicyConfig {
+            thresholds: AnomalyThresholds {
+                error_codes: crate::AnomalyErrorCodes::Any,
+                ..<_>::default()
+            },
+            rate_limit: None,
+            global_limit: Some(GlobalLimit {
+                max_anomalies: 2,
+                max_bytes: 100,
+                period: Duration::from_secs(60),
+            }),
+            ..<_>::default()
+        });
+        let fast = Duration::from_millis(1);
+
+        assert!(policy.detect_anomaly(&summary("particle_1", fast, &outcome(1, 10))));
+        assert!(
+            !policy.detect_anomaly(&summary("particle_2", fast, &outcome(1, 91))),
+            "bytes limit is exceeded"
+        );
+        assert!(policy.detect_anomaly(&summary("particle_2", fast, &outcome(1, 90))));
+        assert!(
+            !policy.detect_anomaly(&summary("particle_3", fast, &outcome(1, 0))),
+            "count limit is exceeded"
+        );
+    }
+
+    #[test]
+    fn anomalies_are_sampled() {
+        let policy = policy(AnomalyPolicyConfig {
+            thresholds: AnomalyThresholds {
+                error_codes: crate::AnomalyErrorCodes::Any,
+                ..<_>::default()
+            },
+            sampling_rate: 0.0,
+            ..<_>::default()
+        });
+
+        let failed = outcome(1, 0);
+        let execution = summary("particle", Duration::from_millis(1), &failed);
+        assert!(!policy.detect_anomaly(&execution));
+    }
+}
@@ -0,0 +1,91 @@
+/*
+ * Copyright 2023 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use crate::ExecutionSummary;
+
+use std::collections::HashSet;
+use std::time::Duration;
+
+/// Thresholds of an execution beyond which it's treated as an anomaly,
+/// the simplest of `AnomalyPolicy` triggers.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AnomalyThresholds {
+    /// An execution taking longer than this is an anomaly.
+    pub execution_time: Duration,
+
+    /// An execution extending an interpreter heap by more bytes than this is an anomaly.
+    pub memory_delta: usize,
+
+    /// Return codes of an interpreter which are anomalies.
+    pub error_codes: AnomalyErrorCodes,
+}
+
+/// Describes which interpreter return codes are anomalies.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AnomalyErrorCodes {
+    /// Return codes aren't taken into account.
+    Ignore,
+
+    /// Any unsuccessful return code is an anomaly.
+    Any,
+
+    /// Only the listed return codes are anomalies.
+    Only(HashSet<i64>),
+}
+
+impl AnomalyThresholds {
+    pub fn new(
+        execution_time: Duration,
+        memory_delta: usize,
+        error_codes: AnomalyErrorCodes,
+    ) -> Self {
+        Self {
+            execution_time,
+            memory_delta,
+            error_codes,
+        }
+    }
+
+    /// Returns true if any of the thresholds is exceeded.
+    pub fn detect_anomaly(&self, execution: &ExecutionSummary<'_>) -> bool {
+        execution.execution_time > self.execution_time
+            || execution.memory_delta > self.memory_delta
+            || self.error_codes.is_anomaly(execution.outcome.ret_code)
+    }
+}
+
+impl Default for AnomalyThresholds {
+    /// Failed executions are usual, so by default only slow and memory hungry ones are anomalies.
+    fn default() -> Self {
+        Self {
+            execution_time: Duration::from_secs(1),
+            memory_delta: 10 * 1024 * 1024,
+            error_codes: AnomalyErrorCodes::Ignore,
+        }
+    }
+}
+
+impl AnomalyErrorCodes {
+    pub fn is_anomaly(&self, ret_code: i64) -> bool {
+        const INTERPRETER_SUCCESS: i64 = 0;
+
+        match self {
+            AnomalyErrorCodes::Ignore => false,
+            AnomalyErrorCodes::Any => ret_code != INTERPRETER_SUCCESS,
+            AnomalyErrorCodes::Only(error_codes) => error_codes.contains(&ret_code),
+        }
+    }
+}
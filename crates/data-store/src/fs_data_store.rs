@@ -0,0 +1,720 @@
+/*
+ * Copyright 2023 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use crate::AnomalyData;
+use crate::AnomalyPolicy;
+use crate::AnomalyPolicyConfig;
+use crate::DataStore;
+use crate::ExecutionSummary;
+
+use thiserror::Error as ThisError;
+
+use std::fs;
+use std::io;
+use std::io::Write;
+use std::path::Path;
+use std::path::PathBuf;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
+
+/// A data store keeping data of every particle in its own file, so data of a particle
+/// is read and written without touching the others.
+///
+/// Files are replaced atomically: data is written into a temporary file first
+/// and then renamed, so a crash never leaves truncated data behind.
+#[derive(Debug)]
+pub struct FsDataStore {
+    config: FsDataStoreConfig,
+    anomaly_policy: AnomalyPolicy,
+}
+
+#[derive(Debug, Clone)]
+pub struct FsDataStoreConfig {
+    /// A directory where data of particles is stored.
+    pub data_dir: PathBuf,
+
+    /// A directory where data of executions detected as anomalies is stored.
+    pub anomaly_dir: PathBuf,
+
+    /// Describes how files are grouped into directories.
+    pub layout: DirectoryLayout,
+
+    /// A policy used by `detect_anomaly`.
+    pub anomaly_policy: AnomalyPolicyConfig,
+
+    /// Once anomalies of all particles take more bytes than this, the oldest ones are removed.
+    /// Anomalies outlive data of their particles, so without a cap they'd pile up forever.
+    pub max_anomalies_size: Option<u64>,
+}
+
+/// Describes how files are grouped into directories, both for data and anomalies.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum DirectoryLayout {
+    /// `<dir>/<current_peer_id>/<particle_id>`, convenient for a node hosting several peers.
+    #[default]
+    ByPeer,
+
+    /// `<dir>/<particle_id>/<current_peer_id>`, convenient for a local network of peers
+    /// sharing the same store, since all data of a particle is kept together.
+    ByParticle,
+}
+
+#[derive(Debug, ThisError)]
+pub enum FsDataStoreError {
+    #[error("{kind} '{value}' can't be used as a file name")]
+    InvalidKey { kind: &'static str, value: String },
+
+    #[error("failed to create directory {path:?}: {error}")]
+    CreateDir { path: PathBuf, error: io::Error },
+
+    #[error("failed to read {path:?}: {error}")]
+    Read { path: PathBuf, error: io::Error },
+
+    #[error("failed to write {path:?}: {error}")]
+    Write { path: PathBuf, error: io::Error },
+
+    #[error("failed to remove {path:?}: {error}")]
+    Remove { path: PathBuf, error: io::Error },
+
+    #[error("failed to serialize anomaly data: {0}")]
+    AnomalyDataSe(#[from] serde_json::Error),
+
+    #[error("file system task was aborted: {0}")]
+    TaskAborted(String),
+}
+
+type FsResult<T> = Result<T, FsDataStoreError>;
+
+impl FsDataStoreConfig {
+    /// Creates a config keeping data and anomalies in subdirectories of the provided one.
+    pub fn new(root_dir: impl AsRef<Path>) -> Self {
+        let root_dir = root_dir.as_ref();
+
+        Self {
+            data_dir: root_dir.join("data"),
+            anomaly_dir: root_dir.join("anomaly"),
+            layout: DirectoryLayout::default(),
+            anomaly_policy: AnomalyPolicyConfig::default(),
+            max_anomalies_size: Some(1024 * 1024 * 1024),
+        }
+    }
+}
+
+impl FsDataStore {
+    pub fn new(config: FsDataStoreConfig) -> Self {
+        let anomaly_policy = AnomalyPolicy::new(config.anomaly_policy.clone());
+        Self {
+            config,
+            anomaly_policy,
+        }
+    }
+
+    pub fn config(&self) -> &FsDataStoreConfig {
+        &self.config
+    }
+
+    /// Returns a path of a file with data of the particle.
+    pub fn data_path(&self, particle_id: &str, current_peer_id: &str) -> FsResult<PathBuf> {
+        self.particle_path(&self.config.data_dir, particle_id, current_peer_id)
+    }
+
+    /// Returns a path of a directory with anomalies of the particle, an anomaly file is named
+    /// after a time it was collected at.
+    pub fn anomaly_dir(&self, particle_id: &str, current_peer_id: &str) -> FsResult<PathBuf> {
+        self.particle_path(&self.config.anomaly_dir, particle_id, current_peer_id)
+    }
+
+    fn particle_path(
+        &self,
+        dir: &Path,
+        particle_id: &str,
+        current_peer_id: &str,
+    ) -> FsResult<PathBuf> {
+        check_key("particle id", particle_id)?;
+        check_key("current peer id", current_peer_id)?;
+
+        let path = match self.config.layout {
+            DirectoryLayout::ByPeer => dir.join(current_peer_id).join(particle_id),
+            DirectoryLayout::ByParticle => dir.join(particle_id).join(current_peer_id),
+        };
+        Ok(path)
+    }
+}
+
+impl DataStore for FsDataStore {
+    type Error = FsDataStoreError;
+
+    fn initialize(&mut self) -> FsResult<()> {
+        create_dir(&self.config.data_dir)?;
+        create_dir(&self.config.anomaly_dir)
+    }
+
+    fn store_data(
+        &mut self,
+        data: &[u8],
+        particle_id: &str,
+        current_peer_id: &str,
+    ) -> FsResult<()> {
+        let path = self.data_path(particle_id, current_peer_id)?;
+        write_atomically(&path, data)
+    }
+
+    /// Returns empty data for a particle that hasn't been stored yet.
+    fn read_data(&mut self, particle_id: &str, current_peer_id: &str) -> FsResult<Vec<u8>> {
+        let path = self.data_path(particle_id, current_peer_id)?;
+        read_data(path)
+    }
+
+    /// Removes data of the particle, anomalies are kept since they're intended for debugging,
+    /// the oldest ones are removed only over `max_anomalies_size`.
+    fn cleanup_data(&mut self, particle_id: &str, current_peer_id: &str) -> FsResult<()> {
+        let path = self.data_path(particle_id, current_peer_id)?;
+        remove_data(path, self.config.layout)
+    }
+
+    fn detect_anomaly(&self, execution: &ExecutionSummary<'_>) -> bool {
+        self.anomaly_policy.detect_anomaly(execution)
+    }
+
+    fn collect_anomaly_data(
+        &mut self,
+        particle_id: &str,
+        current_peer_id: &str,
+        anomaly_data: AnomalyData<'_>,
+    ) -> FsResult<()> {
+        let anomaly_dir = self.anomaly_dir(particle_id, current_peer_id)?;
+        let anomaly_data = serde_json::to_vec(&anomaly_data)?;
+        write_anomaly(&anomaly_dir, &anomaly_data)?;
+        remove_oldest_anomalies(&self.config.anomaly_dir, self.config.max_anomalies_size)
+    }
+}
+
+/// File system operations are blocking, so they're run on threads dedicated to blocking tasks
+/// to not stall a runtime.
+#[cfg(feature = "async")]
+#[async_trait::async_trait]
+impl crate::AsyncDataStore for FsDataStore {
+    type Error = FsDataStoreError;
+
+    async fn initialize(&mut self) -> FsResult<()> {
+        let data_dir = self.config.data_dir.clone();
+        let anomaly_dir = self.config.anomaly_dir.clone();
+        spawn_blocking(move || {
+            create_dir(&data_dir)?;
+            create_dir(&anomaly_dir)
+        })
+        .await
+    }
+
+    async fn store_data(
+        &self,
+        data: &[u8],
+        particle_id: &str,
+        current_peer_id: &str,
+    ) -> FsResult<()> {
+        let path = self.data_path(particle_id, current_peer_id)?;
+        let data = data.to_vec();
+        spawn_blocking(move || write_atomically(&path, &data)).await
+    }
+
+    /// Returns empty data for a particle that hasn't been stored yet.
+    async fn read_data(&self, particle_id: &str, current_peer_id: &str) -> FsResult<Vec<u8>> {
+        let path = self.data_path(particle_id, current_peer_id)?;
+        spawn_blocking(move || read_data(path)).await
+    }
+
+    /// Removes data of the particle, anomalies are kept since they're intended for debugging,
+    /// the oldest ones are removed only over `max_anomalies_size`.
+    async fn cleanup_data(&self, particle_id: &str, current_peer_id: &str) -> FsResult<()> {
+        let path = self.data_path(particle_id, current_peer_id)?;
+        let layout = self.config.layout;
+        spawn_blocking(move || remove_data(path, layout)).await
+    }
+
+    fn detect_anomaly(&self, execution: &ExecutionSummary<'_>) -> bool {
+        self.anomaly_policy.detect_anomaly(execution)
+    }
+
+    async fn collect_anomaly_data(
+        &self,
+        particle_id: &str,
+        current_peer_id: &str,
+        anomaly_data: AnomalyData<'_>,
+    ) -> FsResult<()> {
+        let anomaly_dir = self.anomaly_dir(particle_id, current_peer_id)?;
+        let anomaly_data = serde_json::to_vec(&anomaly_data)?;
+        let anomalies_root = self.config.anomaly_dir.clone();
+        let max_anomalies_size = self.config.max_anomalies_size;
+        spawn_blocking(move || {
+            write_anomaly(&anomaly_dir, &anomaly_data)?;
+            remove_oldest_anomalies(&anomalies_root, max_anomalies_size)
+        })
+        .await
+    }
+}
+
+#[cfg(feature = "async")]
+async fn spawn_blocking<T: Send + 'static>(
+    task: impl FnOnce() -> FsResult<T> + Send + 'static,
+) -> FsResult<T> {
+    match tokio::task::spawn_blocking(task).await {
+        Ok(result) => result,
+        Err(error) if error.is_panic() => std::panic::resume_unwind(error.into_panic()),
+        Err(error) => Err(FsDataStoreError::TaskAborted(error.to_string())),
+    }
+}
+
+fn read_data(path: PathBuf) -> FsResult<Vec<u8>> {
+    match fs::read(&path) {
+        Ok(data) => Ok(data),
+        Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(vec![]),
+        Err(error) => Err(FsDataStoreError::Read { path, error }),
+    }
+}
+
+fn remove_data(path: PathBuf, layout: DirectoryLayout) -> FsResult<()> {
+    match fs::remove_file(&path) {
+        Ok(()) => {}
+        Err(error) if error.kind() == io::ErrorKind::NotFound => {}
+        Err(error) => return Err(FsDataStoreError::Remove { path, error }),
+    }
+
+    if layout == DirectoryLayout::ByParticle {
+        if let Some(particle_dir) = path.parent() {
+            // the directory is still used if data of other peers is there
+            let _ = fs::remove_dir(particle_dir);
+        }
+    }
+
+    Ok(())
+}
+
+fn write_anomaly(anomaly_dir: &Path, anomaly_data: &[u8]) -> FsResult<()> {
+    write_anomaly_at(anomaly_dir, anomaly_data, unix_time_ms())
+}
+
+/// Removes the oldest anomalies until all of them fit into the size, directories left empty
+/// are removed too. Anomalies being written by others are skipped, as well as files removed
+/// concurrently.
+fn remove_oldest_anomalies(anomalies_root: &Path, max_size: Option<u64>) -> FsResult<()> {
+    let max_size = match max_size {
+        Some(max_size) => max_size,
+        None => return Ok(()),
+    };
+
+    let mut anomalies = vec![];
+    collect_anomaly_files(anomalies_root, &mut anomalies)?;
+    let mut total_size = anomalies.iter().map(|anomaly| anomaly.size).sum::<u64>();
+    if total_size <= max_size {
+        return Ok(());
+    }
+
+    anomalies.sort_unstable_by(|lhs, rhs| {
+        lhs.modified
+            .cmp(&rhs.modified)
+            .then_with(|| lhs.path.cmp(&rhs.path))
+    });
+    for anomaly in anomalies {
+        if total_size <= max_size {
+            break;
+        }
+
+        match fs::remove_file(&anomaly.path) {
+            Ok(()) => {}
+            Err(error) if error.kind() == io::ErrorKind::NotFound => {}
+            Err(error) => {
+                return Err(FsDataStoreError::Remove {
+                    path: anomaly.path,
+                    error,
+                })
+            }
+        }
+        total_size -= anomaly.size;
+
+        // directories are still used if other anomalies are there
+        for dir in anomaly.path.ancestors().skip(1) {
+            if dir == anomalies_root || fs::remove_dir(dir).is_err() {
+                break;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+struct AnomalyFile {
+    path: PathBuf,
+    modified: SystemTime,
+    size: u64,
+}
+
+fn collect_anomaly_files(dir: &Path, anomalies: &mut Vec<AnomalyFile>) -> FsResult<()> {
+    let read_error = |error| FsDataStoreError::Read {
+        path: dir.to_path_buf(),
+        error,
+    };
+
+    let entries = match fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(error) if error.kind() == io::ErrorKind::NotFound => return Ok(()),
+        Err(error) => return Err(read_error(error)),
+    };
+
+    for entry in entries {
+        let entry = entry.map_err(read_error)?;
+        let path = entry.path();
+        let metadata = match entry.metadata() {
+            Ok(metadata) => metadata,
+            Err(error) if error.kind() == io::ErrorKind::NotFound => continue,
+            Err(error) => return Err(FsDataStoreError::Read { path, error }),
+        };
+
+        if metadata.is_dir() {
+            collect_anomaly_files(&path, anomalies)?;
+        } else if !entry.file_name().to_string_lossy().starts_with('.') {
+            anomalies.push(AnomalyFile {
+                path,
+                modified: metadata.modified().unwrap_or(UNIX_EPOCH),
+                size: metadata.len(),
+            });
+        }
+    }
+
+    Ok(())
+}
+
+/// Keys are used as file names, so they must not escape a directory they're put into.
+fn check_key(kind: &'static str, value: &str) -> FsResult<()> {
+    let is_invalid = value.is_empty()
+        || value == "."
+        || value == ".."
+        || value.contains(|c: char| matches!(c, '/' | '\\' | '\0'));
+
+    if is_invalid {
+        return Err(FsDataStoreError::InvalidKey {
+            kind,
+            value: value.to_string(),
+        });
+    }
+
+    Ok(())
+}
+
+fn create_dir(path: &Path) -> FsResult<()> {
+    fs::create_dir_all(path).map_err(|error| FsDataStoreError::CreateDir {
+        path: path.to_path_buf(),
+        error,
+    })
+}
+
+/// Writes data into a temporary file in the same directory and renames it then,
+/// since renaming within a file system replaces the target atomically.
+fn write_atomically(path: &Path, data: &[u8]) -> FsResult<()> {
+    let tmp_path = write_tmp_file(path, data)?;
+
+    if let Err(error) = fs::rename(&tmp_path, path) {
+        let _ = fs::remove_file(&tmp_path);
+        return Err(write_error(path, error));
+    }
+
+    Ok(())
+}
+
+/// Anomalies are named after a time they were collected at, several anomalies collected
+/// within the same millisecond get a suffix. A complete temporary file is hard linked to
+/// an anomaly path, unlike renaming linking fails if the path is taken, so the next suffix
+/// is tried then and concurrent writers never overwrite each other's anomalies.
+fn write_anomaly_at(anomaly_dir: &Path, anomaly_data: &[u8], timestamp: u128) -> FsResult<()> {
+    let tmp_path = write_tmp_file(&anomaly_path(anomaly_dir, timestamp, 0), anomaly_data)?;
+
+    let mut id = 0;
+    let result = loop {
+        let path = anomaly_path(anomaly_dir, timestamp, id);
+        match fs::hard_link(&tmp_path, &path) {
+            Ok(()) => break Ok(()),
+            Err(error) if error.kind() == io::ErrorKind::AlreadyExists => id += 1,
+            Err(error) => break Err(write_error(&path, error)),
+        }
+    };
+
+    let _ = fs::remove_file(&tmp_path);
+    result
+}
+
+fn anomaly_path(anomaly_dir: &Path, timestamp: u128, id: u64) -> PathBuf {
+    match id {
+        0 => anomaly_dir.join(format!("{timestamp}.json")),
+        id => anomaly_dir.join(format!("{timestamp}-{id}.json")),
+    }
+}
+
+/// Writes data into a temporary file next to the path and returns the temporary path.
+/// A temporary file name is unique within the process, so concurrent writes don't clash.
+fn write_tmp_file(path: &Path, data: &[u8]) -> FsResult<PathBuf> {
+    static TMP_FILE_ID: AtomicU64 = AtomicU64::new(0);
+
+    let dir = path.parent().expect("particle paths always have a parent");
+    create_dir(dir)?;
+
+    let file_name = path
+        .file_name()
+        .expect("particle paths always have a file name");
+    let mut tmp_file_name = std::ffi::OsString::from(".");
+    tmp_file_name.push(file_name);
+    tmp_file_name.push(format!(
+        ".{}-{}.tmp",
+        std::process::id(),
+        TMP_FILE_ID.fetch_add(1, Ordering::Relaxed)
+    ));
+    let tmp_path = dir.join(tmp_file_name);
+
+    let result = fs::File::create(&tmp_path).and_then(|mut file| {
+        file.write_all(data)?;
+        file.sync_all()
+    });
+
+    if let Err(error) = result {
+        let _ = fs::remove_file(&tmp_path);
+        return Err(write_error(path, error));
+    }
+
+    Ok(tmp_path)
+}
+
+fn write_error(path: &Path, error: io::Error) -> FsDataStoreError {
+    FsDataStoreError::Write {
+        path: path.to_path_buf(),
+        error,
+    }
+}
+
+fn unix_time_ms() -> u128 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_millis())
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct TestDir(PathBuf);
+
+    impl TestDir {
+        fn new(name: &str) -> Self {
+            let path =
+                std::env::temp_dir().join(format!("avm-data-store-{name}-{}", std::process::id()));
+            let _ = fs::remove_dir_all(&path);
+            Self(path)
+        }
+    }
+
+    impl Drop for TestDir {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.0);
+        }
+    }
+
+    fn create_store(dir: &TestDir, layout: DirectoryLayout) -> FsDataStore {
+        let mut config = FsDataStoreConfig::new(&dir.0);
+        config.layout = layout;
+
+        let mut store = FsDataStore::new(config);
+        store.initialize().expect("initialize store");
+        store
+    }
+
+    #[test]
+    fn data_is_stored_per_particle_and_peer() {
+        let dir = TestDir::new("data");
+        let mut store = create_store(&dir, DirectoryLayout::ByPeer);
+
+        store.store_data(b"data_1", "particle", "peer_1").unwrap();
+        store.store_data(b"data_2", "particle", "peer_2").unwrap();
+        store.store_data(b"data_3", "particle", "peer_1").unwrap();
+
+        assert_eq!(store.read_data("particle", "peer_1").unwrap(), b"data_3");
+        assert_eq!(store.read_data("particle", "peer_2").unwrap(), b"data_2");
+        assert!(store
+            .read_data("other_particle", "peer_1")
+            .unwrap()
+            .is_empty());
+        assert!(dir.0.join("data").join("peer_1").join("particle").is_file());
+
+        let data_dir = dir.0.join("data").join("peer_1");
+        let files_count = fs::read_dir(data_dir).unwrap().count();
+        assert_eq!(files_count, 1, "temporary files must not be left");
+    }
+
+    #[test]
+    fn cleanup_removes_data_of_a_particle() {
+        let dir = TestDir::new("cleanup");
+        let mut store = create_store(&dir, DirectoryLayout::ByParticle);
+
+        store.store_data(b"data", "particle", "peer_1").unwrap();
+        store.store_data(b"data", "particle", "peer_2").unwrap();
+
+        store.cleanup_data("particle", "peer_1").unwrap();
+        assert!(store.read_data("particle", "peer_1").unwrap().is_empty());
+        assert_eq!(store.read_data("particle", "peer_2").unwrap(), b"data");
+
+        store.cleanup_data("particle", "peer_2").unwrap();
+        assert!(!dir.0.join("data").join("particle").exists());
+
+        // cleaning data that doesn't exist isn't an error
+        store.cleanup_data("particle", "peer_2").unwrap();
+    }
+
+    #[test]
+    fn anomalies_are_stored_in_timestamped_files() {
+        let dir = TestDir::new("anomaly");
+        let mut store = create_store(&dir, DirectoryLayout::ByPeer);
+
+        for _ in 0..2 {
+            let anomaly = AnomalyData::new(
+                "(null)",
+                b"particle",
+                b"prev",
+                b"current",
+                b"{}",
+                b"{}",
+                std::time::Duration::from_secs(2),
+                0,
+            );
+            store
+                .collect_anomaly_data("particle", "peer", anomaly)
+                .unwrap();
+        }
+
+        let anomaly_dir = store.anomaly_dir("particle", "peer").unwrap();
+        let anomalies = fs::read_dir(anomaly_dir)
+            .unwrap()
+            .map(|entry| fs::read(entry.unwrap().path()).unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(anomalies.len(), 2);
+
+        let anomaly: AnomalyData<'_> = serde_json::from_slice(&anomalies[0]).unwrap();
+        assert_eq!(anomaly.prev_data.as_ref(), b"prev");
+    }
+
+    #[test]
+    fn concurrent_writes_dont_clash() {
+        let dir = TestDir::new("concurrent");
+        let store = create_store(&dir, DirectoryLayout::ByPeer);
+        let path = store.data_path("particle", "peer").unwrap();
+
+        std::thread::scope(|scope| {
+            for id in 0..8 {
+                let path = &path;
+                scope.spawn(move || write_atomically(path, format!("data_{id}").as_bytes()));
+            }
+        });
+
+        let data = fs::read(&path).unwrap();
+        assert!(String::from_utf8(data).unwrap().starts_with("data_"));
+        let files_count = fs::read_dir(path.parent().unwrap()).unwrap().count();
+        assert_eq!(files_count, 1, "temporary files must not be left");
+    }
+
+    #[test]
+    fn concurrent_anomalies_dont_overwrite_each_other() {
+        let dir = TestDir::new("concurrent-anomaly");
+        let store = create_store(&dir, DirectoryLayout::ByPeer);
+        let anomaly_dir = store.anomaly_dir("particle", "peer").unwrap();
+
+        std::thread::scope(|scope| {
+            for id in 0..8 {
+                let anomaly_dir = &anomaly_dir;
+                scope.spawn(move || {
+                    write_anomaly_at(anomaly_dir, format!("anomaly_{id}").as_bytes(), 1).unwrap()
+                });
+            }
+        });
+
+        let mut anomalies = fs::read_dir(&anomaly_dir)
+            .unwrap()
+            .map(|entry| fs::read_to_string(entry.unwrap().path()).unwrap())
+            .collect::<Vec<_>>();
+        anomalies.sort();
+        let expected = (0..8).map(|id| format!("anomaly_{id}")).collect::<Vec<_>>();
+        assert_eq!(anomalies, expected, "temporary files must not be left");
+    }
+
+    #[test]
+    fn oldest_anomalies_are_removed_over_size() {
+        let dir = TestDir::new("anomaly-size");
+        let mut config = FsDataStoreConfig::new(&dir.0);
+        config.max_anomalies_size = Some(10);
+        let store = FsDataStore::new(config);
+        let anomalies_root = &store.config().anomaly_dir;
+
+        let anomaly_dir_1 = store.anomaly_dir("particle_1", "peer").unwrap();
+        let anomaly_dir_2 = store.anomaly_dir("particle_2", "peer").unwrap();
+        write_anomaly_at(&anomaly_dir_1, b"anomaly", 1).unwrap();
+        write_anomaly_at(&anomaly_dir_2, b"anomaly", 2).unwrap();
+        remove_oldest_anomalies(anomalies_root, Some(100)).unwrap();
+        assert!(anomaly_dir_1.join("1.json").is_file());
+
+        // anomalies with the same modification time are removed in the order of their paths
+        remove_oldest_anomalies(anomalies_root, Some(10)).unwrap();
+        assert!(!anomaly_dir_1.exists(), "empty directories must be removed");
+        assert!(anomaly_dir_2.join("2.json").is_file());
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn async_store_reads_what_was_written() {
+        use crate::AsyncDataStore;
+
+        let dir = TestDir::new("async");
+        let mut store = FsDataStore::new(FsDataStoreConfig::new(&dir.0));
+        AsyncDataStore::initialize(&mut store).await.unwrap();
+
+        AsyncDataStore::store_data(&store, b"data", "particle", "peer")
+            .await
+            .unwrap();
+        let data = AsyncDataStore::read_data(&store, "particle", "peer")
+            .await
+            .unwrap();
+        assert_eq!(data, b"data");
+
+        AsyncDataStore::cleanup_data(&store, "particle", "peer")
+            .await
+            .unwrap();
+        let data = AsyncDataStore::read_data(&store, "particle", "peer")
+            .await
+            .unwrap();
+        assert!(data.is_empty());
+    }
+
+    #[test]
+    fn keys_escaping_directory_are_refused() {
+        let dir = TestDir::new("keys");
+        let mut store = create_store(&dir, DirectoryLayout::ByPeer);
+
+        for particle_id in ["", "..", "../particle", "dir/particle"] {
+            let result = store.store_data(b"data", particle_id, "peer");
+            assert!(
+                matches!(result, Err(FsDataStoreError::InvalidKey { .. })),
+                "{:?} must be refused",
+                particle_id
+            );
+        }
+    }
+}
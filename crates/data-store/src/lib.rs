@@ -26,6 +26,22 @@
     unreachable_patterns
 )]
 
+mod anomaly_policy;
+mod anomaly_thresholds;
+mod fs_data_store;
+
+pub use anomaly_policy::AnomalyPolicy;
+pub use anomaly_policy::AnomalyPolicyConfig;
+pub use anomaly_policy::GlobalLimit;
+pub use anomaly_policy::LatencyPercentile;
+pub use anomaly_policy::RateLimit;
+pub use anomaly_thresholds::AnomalyErrorCodes;
+pub use anomaly_thresholds::AnomalyThresholds;
+pub use fs_data_store::DirectoryLayout;
+pub use fs_data_store::FsDataStore;
+pub use fs_data_store::FsDataStoreConfig;
+pub use fs_data_store::FsDataStoreError;
+
 use avm_interface::raw_outcome::RawAVMOutcome;
 
 use serde::Deserialize;
@@ -59,17 +75,8 @@ pub trait DataStore {
         -> Result<(), Self::Error>;
 
     /// Returns true if an anomaly happened and it's necessary to save execution data
-    /// for debugging purposes.
-    ///  execution_time - time taken by the interpreter to execute provided script
-    ///  memory_delta - count of bytes on which an interpreter heap has been extended
-    ///                 during execution of a particle
-    ///  outcome - a result of AquaVM invocation
-    fn detect_anomaly(
-        &self,
-        execution_time: Duration,
-        memory_delta: usize,
-        outcome: &RawAVMOutcome,
-    ) -> bool;
+    /// for debugging purposes, `AnomalyPolicy` is a ready to use implementation of this check.
+    fn detect_anomaly(&self, execution: &ExecutionSummary<'_>) -> bool;
 
     fn collect_anomaly_data(
         &mut self,
@@ -79,19 +86,80 @@ pub trait DataStore {
     ) -> Result<(), Self::Error>;
 }
 
+/// Async counterpart of `DataStore` for hosts built on top of an async runtime.
+///
+/// Unlike `DataStore`, it's shared between concurrently executed particles,
+/// so all methods except initialization take `&self`.
+#[cfg(feature = "async")]
+#[async_trait::async_trait]
+pub trait AsyncDataStore {
+    type Error;
+
+    async fn initialize(&mut self) -> Result<(), Self::Error>;
+
+    async fn store_data(
+        &self,
+        data: &[u8],
+        particle_id: &str,
+        current_peer_id: &str,
+    ) -> Result<(), Self::Error>;
+
+    async fn read_data(
+        &self,
+        particle_id: &str,
+        current_peer_id: &str,
+    ) -> Result<Vec<u8>, Self::Error>;
+
+    /// Cleanup data that become obsolete.
+    async fn cleanup_data(
+        &self,
+        particle_id: &str,
+        current_peer_id: &str,
+    ) -> Result<(), Self::Error>;
+
+    /// Returns true if an anomaly happened and it's necessary to save execution data
+    /// for debugging purposes, see `DataStore::detect_anomaly` for details.
+    fn detect_anomaly(&self, execution: &ExecutionSummary<'_>) -> bool;
+
+    async fn collect_anomaly_data(
+        &self,
+        particle_id: &str,
+        current_peer_id: &str,
+        anomaly_data: AnomalyData<'_>,
+    ) -> Result<(), Self::Error>;
+}
+
+/// Everything known about an execution when it's checked for an anomaly.
+#[derive(Debug, Clone, Copy)]
+pub struct ExecutionSummary<'a> {
+    pub particle_id: &'a str,
+    pub current_peer_id: &'a str,
+    /// Time taken by the interpreter to execute provided script.
+    pub execution_time: Duration,
+    /// Count of bytes on which an interpreter heap has been extended during execution of a particle.
+    pub memory_delta: usize,
+    /// Size of data stored for the particle before the execution.
+    pub prev_data_len: usize,
+    /// A result of AquaVM invocation.
+    pub outcome: &'a RawAVMOutcome,
+}
+
+/// Byte fields are serialized as strings if they're valid UTF-8, as JSON data, scripts and
+/// call results are, since a JSON serializer writes bytes as arrays of numbers about 4 times
+/// larger. Both representations are accepted by deserialization.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub struct AnomalyData<'data> {
     #[serde(borrow)]
     pub air_script: Cow<'data, str>,
-    #[serde(borrow, with = "serde_bytes")]
+    #[serde(borrow, with = "utf8_or_bytes")]
     pub particle: Cow<'data, [u8]>, // it's byte because of the restriction on trait objects methods
-    #[serde(borrow, with = "serde_bytes")]
+    #[serde(borrow, with = "utf8_or_bytes")]
     pub prev_data: Cow<'data, [u8]>,
-    #[serde(borrow, with = "serde_bytes")]
+    #[serde(borrow, with = "utf8_or_bytes")]
     pub current_data: Cow<'data, [u8]>,
-    #[serde(borrow, with = "serde_bytes")]
+    #[serde(borrow, with = "utf8_or_bytes")]
     pub call_results: Cow<'data, [u8]>,
-    #[serde(borrow, with = "serde_bytes")]
+    #[serde(borrow, with = "utf8_or_bytes")]
     pub avm_outcome: Cow<'data, [u8]>,
     pub execution_time: Duration,
     pub memory_delta: usize,
@@ -122,10 +190,34 @@ impl<'data> AnomalyData<'data> {
     }
 }
 
+mod utf8_or_bytes {
+    use serde::Deserializer;
+    use serde::Serializer;
+
+    use std::borrow::Cow;
+
+    pub(super) fn serialize<S: Serializer>(bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error> {
+        match std::str::from_utf8(bytes) {
+            Ok(string) => serializer.serialize_str(string),
+            Err(_) => serializer.serialize_bytes(bytes),
+        }
+    }
+
+    pub(super) fn deserialize<'de: 'data, 'data, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Cow<'data, [u8]>, D::Error> {
+        serde_bytes::deserialize(deserializer)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    fn bytes_json(bytes: &[u8]) -> String {
+        serde_json::to_string(std::str::from_utf8(bytes).unwrap()).unwrap()
+    }
+
     fn anomaly_json(
         air_script: &str,
         particle: &[u8],
@@ -147,11 +239,11 @@ mod tests {
                 r#"}}"#
             ),
             air_script = serde_json::to_string(air_script).unwrap(),
-            particle = serde_json::to_string(particle).unwrap(),
-            prev_data = serde_json::to_string(prev_data).unwrap(),
-            current_data = serde_json::to_string(current_data).unwrap(),
-            call_results = serde_json::to_string(call_results).unwrap(),
-            avm_outcome = serde_json::to_string(avm_outcome).unwrap(),
+            particle = bytes_json(particle),
+            prev_data = bytes_json(prev_data),
+            current_data = bytes_json(current_data),
+            call_results = bytes_json(call_results),
+            avm_outcome = bytes_json(avm_outcome),
         )
     }
     #[test]
@@ -212,4 +304,33 @@ mod tests {
             )
         )
     }
+
+    #[test]
+    fn anomaly_data_with_byte_arrays() {
+        let anomaly = AnomalyData::new(
+            "(null)",
+            b"{}",
+            &[0x82, 0xff], // not valid UTF-8, like MessagePack data
+            b"{}",
+            b"{}",
+            b"{}",
+            Duration::from_secs(42),
+            123,
+        );
+
+        let json_data = serde_json::to_string(&anomaly).expect("JSON serialize anomaly data");
+        assert!(
+            json_data.contains(r#""prev_data":[130,255]"#),
+            "{}",
+            json_data
+        );
+
+        // anomalies written before byte fields became strings
+        let legacy_json_data = json_data.replace(r#""{}""#, "[123,125]");
+        for json_data in [json_data, legacy_json_data] {
+            let deserialized: AnomalyData<'_> =
+                serde_json::from_str(&json_data).expect("deserialize JSON anomaly data");
+            assert_eq!(deserialized, anomaly);
+        }
+    }
 }
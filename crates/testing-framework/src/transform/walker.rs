@@ -167,6 +167,20 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_translate_with_comments() {
+        let network = Network::empty();
+        let script = r#"(seq
+   (call "peer_id" ("service_id" func) []) ; ok = 42
+   ;; a comment that is kept by the AIR parser
+   (null))"#;
+        let transformed = TransformedAirScript::new_unvalidated(script, network).unwrap();
+        assert_eq!(
+            &*transformed,
+            r#"(seq (call "peer_id" ("service_id..0" func) []) (null))"#
+        );
+    }
+
     #[test]
     fn test_translate_multiple_calls() {
         let script = r#"(seq
@@ -0,0 +1,40 @@
+/*
+ * Copyright 2023 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use air_interpreter_data::TracePos;
+
+use std::fmt;
+
+/// Errors of a malformed trace, they're reported instead of rendering a partial graph.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TraceGraphError {
+    /// Subtraces of a fold state overlap, don't follow the state, or don't fit into
+    /// the subtrace the state belongs to.
+    InvalidFoldSubtraces { pos: TracePos },
+}
+
+impl fmt::Display for TraceGraphError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidFoldSubtraces { pos } => write!(
+                f,
+                "subtraces of the fold state at {pos} overlap or lie outside its subtrace"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for TraceGraphError {}
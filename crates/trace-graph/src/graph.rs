@@ -0,0 +1,180 @@
+/*
+ * Copyright 2023 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use crate::instructions::Instructions;
+use crate::labels::state_label;
+use crate::labels::truncate;
+use crate::trace_tree::FoldIteration;
+use crate::trace_tree::TraceNode;
+use crate::TraceGraphError;
+
+use air_interpreter_data::InterpreterData;
+use air_interpreter_data::TracePos;
+
+/// Instructions are usually much longer than values, but still have to be readable in a node.
+const MAX_INSTRUCTION_LEN: usize = 60;
+
+/// Format-independent graph of a trace, it's rendered by the `render` module.
+#[derive(Debug, Default)]
+pub(crate) struct Graph {
+    pub(crate) nodes: Vec<Node>,
+    pub(crate) edges: Vec<Edge>,
+}
+
+#[derive(Debug)]
+pub(crate) struct Node {
+    pub(crate) id: String,
+    pub(crate) label: String,
+    pub(crate) kind: NodeKind,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum NodeKind {
+    /// A state of the trace.
+    State,
+    /// A fold iteration, it has no state of its own.
+    Iteration,
+}
+
+#[derive(Debug)]
+pub(crate) struct Edge {
+    pub(crate) from: String,
+    pub(crate) to: String,
+    pub(crate) label: Option<String>,
+}
+
+impl Graph {
+    /// Sequential states are chained, par states point to both branches,
+    /// fold states point to their iterations, which point to subtraces before and after next.
+    pub(crate) fn from_data(
+        data: &InterpreterData,
+        instructions: &Instructions,
+    ) -> Result<Self, TraceGraphError> {
+        let mut builder = GraphBuilder {
+            data,
+            instructions,
+            graph: Graph::default(),
+        };
+        builder.add_sequence(&TraceNode::from_trace(&data.trace)?);
+
+        Ok(builder.graph)
+    }
+}
+
+struct GraphBuilder<'data> {
+    data: &'data InterpreterData,
+    instructions: &'data Instructions,
+    graph: Graph,
+}
+
+impl GraphBuilder<'_> {
+    /// Returns an id of the first node of the sequence.
+    fn add_sequence(&mut self, nodes: &[TraceNode<'_>]) -> Option<String> {
+        let mut first = None;
+        let mut prev: Option<String> = None;
+
+        for node in nodes {
+            let id = self.add_trace_node(node);
+            if let Some(prev) = prev {
+                self.add_edge(&prev, &id, None);
+            }
+            first.get_or_insert_with(|| id.clone());
+            prev = Some(id);
+        }
+
+        first
+    }
+
+    fn add_trace_node(&mut self, node: &TraceNode<'_>) -> String {
+        let id = self.add_state(node.pos());
+
+        match node {
+            TraceNode::Par { left, right, .. } => {
+                if let Some(left) = self.add_sequence(left) {
+                    self.add_edge(&id, &left, Some("left"));
+                }
+                if let Some(right) = self.add_sequence(right) {
+                    self.add_edge(&id, &right, Some("right"));
+                }
+            }
+            TraceNode::Fold { iterations, .. } => {
+                for (index, iteration) in iterations.iter().enumerate() {
+                    let iteration_id = self.add_iteration(&id, index, iteration);
+                    self.add_edge(&id, &iteration_id, None);
+                }
+            }
+            TraceNode::State { .. } => {}
+        }
+
+        id
+    }
+
+    fn add_state(&mut self, pos: TracePos) -> String {
+        let state = &self.data.trace[pos];
+        let mut label = format!("#{pos}");
+        if let Some(instruction) = self.instructions.get(pos, state) {
+            label.push('\n');
+            label.push_str(&truncate(instruction.to_string(), MAX_INSTRUCTION_LEN));
+        }
+        label.push('\n');
+        label.push_str(&state_label(state, &self.data.cid_info));
+
+        let id = format!("s{pos}");
+        self.add_node(id, label, NodeKind::State)
+    }
+
+    fn add_iteration(
+        &mut self,
+        fold_id: &str,
+        index: usize,
+        iteration: &FoldIteration<'_>,
+    ) -> String {
+        let lore = iteration.lore;
+        let label = format!("iteration {index}\nvalue at #{}", lore.value_pos);
+        let id = self.add_node(format!("{fold_id}_{index}"), label, NodeKind::Iteration);
+
+        for (subtrace_index, subtrace) in iteration.subtraces.iter().enumerate() {
+            let edge_label = match subtrace_index {
+                0 => "before next".to_string(),
+                1 => "after next".to_string(),
+                _ => format!("subtrace {subtrace_index}"),
+            };
+            if let Some(first) = self.add_sequence(subtrace) {
+                self.add_edge(&id, &first, Some(&edge_label));
+            }
+        }
+
+        id
+    }
+
+    fn add_node(&mut self, id: String, label: String, kind: NodeKind) -> String {
+        self.graph.nodes.push(Node {
+            id: id.clone(),
+            label,
+            kind,
+        });
+
+        id
+    }
+
+    fn add_edge(&mut self, from: &str, to: &str, label: Option<&str>) {
+        self.graph.edges.push(Edge {
+            from: from.to_string(),
+            to: to.to_string(),
+            label: label.map(ToString::to_string),
+        });
+    }
+}
@@ -0,0 +1,147 @@
+/*
+ * Copyright 2023 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use air::ExecutionObserver;
+use air::ExecutionStep;
+use air_interpreter_data::DataFormat;
+use air_interpreter_data::ExecutedState;
+use air_interpreter_data::InterpreterData;
+use air_interpreter_data::TracePos;
+use air_interpreter_interface::CallResults;
+use air_interpreter_interface::RunParameters;
+use air_parser::ast::Instruction;
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+/// The replaying peer isn't used by scripts, so no call is executed locally and no new call requests are made.
+const REPLAY_PEER_ID: &str = "air-trace-graph-replay";
+
+/// Kinds of instructions that leave states in a trace.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum StateKind {
+    Par,
+    Call,
+    Fold,
+    Ap,
+    Canon,
+}
+
+/// Instruction that is supposed to produce a state.
+#[derive(Debug, Clone)]
+pub(crate) struct InstructionLabel {
+    pub(crate) kind: StateKind,
+    pub(crate) text: String,
+}
+
+/// Instructions by positions of states they produced.
+#[derive(Debug, Default)]
+pub(crate) struct Instructions(HashMap<TracePos, InstructionLabel>);
+
+impl Instructions {
+    /// Returns the instruction that produced the state, if the replay reached it.
+    pub(crate) fn get(&self, pos: TracePos, state: &ExecutedState) -> Option<&str> {
+        self.0
+            .get(&pos)
+            .filter(|label| label.kind == StateKind::of_state(state))
+            .map(|label| label.text.as_str())
+    }
+}
+
+impl StateKind {
+    fn of_state(state: &ExecutedState) -> Self {
+        match state {
+            ExecutedState::Par(_) => Self::Par,
+            ExecutedState::Call(_) => Self::Call,
+            ExecutedState::Fold(_) => Self::Fold,
+            ExecutedState::Ap(_) => Self::Ap,
+            ExecutedState::Canon(_) => Self::Canon,
+        }
+    }
+
+    fn of_instruction(instruction: &Instruction<'_>) -> Option<Self> {
+        use Instruction::*;
+
+        match instruction {
+            Par(_) => Some(Self::Par),
+            Call(_) => Some(Self::Call),
+            FoldScalar(_) | FoldStream(_) | FoldStreamMap(_) => Some(Self::Fold),
+            Ap(_) | ApMap(_) => Some(Self::Ap),
+            Canon(_) | CanonMap(_) => Some(Self::Canon),
+            _ => None,
+        }
+    }
+}
+
+/// Executes the script with the data as the previous one, so every state is taken from it
+/// in the same order it was written. An instruction producing a state is the last one observed
+/// at its position, since the next instructions are observed after the state is written.
+pub(crate) fn replay(air_script: &str, data: &InterpreterData) -> Instructions {
+    let prev_data = match data.serialize_with_format(DataFormat::Json) {
+        Ok(prev_data) => prev_data,
+        Err(_) => return Instructions::default(),
+    };
+    let params = RunParameters {
+        init_peer_id: REPLAY_PEER_ID.to_string(),
+        current_peer_id: REPLAY_PEER_ID.to_string(),
+        timestamp: 0,
+        ttl: u32::MAX,
+        require_signatures: false,
+        instruction_limit: 0,
+        // replayed particles shouldn't be refused as expired
+        current_time: 0,
+        collect_stats: false,
+    };
+
+    let call_results =
+        serde_json::to_vec(&CallResults::new()).expect("default serializer shouldn't fail");
+
+    let recorder = Recorder::default();
+    // errors of the replay are ignored, positions observed before them are still valid
+    air::execute_air_with_observer(
+        air_script.to_string(),
+        prev_data,
+        vec![],
+        params,
+        call_results,
+        Box::new(recorder.clone()),
+    );
+
+    let instructions = recorder.0.take();
+    Instructions(instructions)
+}
+
+#[derive(Clone, Default)]
+struct Recorder(Rc<RefCell<HashMap<TracePos, InstructionLabel>>>);
+
+impl ExecutionObserver for Recorder {
+    fn before_instruction(&mut self, step: &ExecutionStep<'_, '_>) {
+        let instruction = step.instruction();
+        let kind = match StateKind::of_instruction(instruction) {
+            Some(kind) => kind,
+            None => return,
+        };
+
+        let text = match step.position() {
+            Some(position) => format!("{position} {instruction}"),
+            None => instruction.to_string(),
+        };
+        self.0
+            .borrow_mut()
+            .insert(step.result_trace_pos(), InstructionLabel { kind, text });
+    }
+}
@@ -0,0 +1,111 @@
+/*
+ * Copyright 2023 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use air_interpreter_cid::CID;
+use air_interpreter_data::CallResult;
+use air_interpreter_data::CidInfo;
+use air_interpreter_data::CidStore;
+use air_interpreter_data::ExecutedState;
+use air_interpreter_data::Sender;
+use air_interpreter_data::ServiceResultAggregate;
+use air_interpreter_data::ValueRef;
+
+use serde_json::Value as JValue;
+
+/// Values longer than this are truncated, graphs with whole values become unreadable.
+const MAX_VALUE_LEN: usize = 40;
+
+/// Describes a state, values are resolved through CID stores of the data.
+pub(crate) fn state_label(state: &ExecutedState, cid_info: &CidInfo) -> String {
+    match state {
+        ExecutedState::Par(par) => {
+            format!("par\nleft: {}, right: {}", par.left_size, par.right_size)
+        }
+        ExecutedState::Call(call) => call_label(call, cid_info),
+        ExecutedState::Fold(fold) => format!("fold\niterations: {}", fold.lore.len()),
+        ExecutedState::Ap(ap) => format!("ap\ngenerations: {:?}", ap.res_generations),
+        ExecutedState::Canon(canon) => {
+            let values = canon
+                .values
+                .iter()
+                .map(|cid| {
+                    cid_info
+                        .canon_store
+                        .get(cid)
+                        .map(|aggregate| value_label(&aggregate.value, &cid_info.value_store))
+                        .unwrap_or_else(|| unknown_cid(cid))
+                })
+                .collect::<Vec<_>>();
+            format!("canon\nvalues: [{}]", values.join(", "))
+        }
+    }
+}
+
+fn call_label(call: &CallResult, cid_info: &CidInfo) -> String {
+    match call {
+        CallResult::RequestSentBy(Sender::PeerId(peer_id)) => {
+            format!("call\nrequest sent by {peer_id}")
+        }
+        CallResult::RequestSentBy(Sender::PeerIdWithCallId { peer_id, call_id }) => {
+            format!("call\nrequest sent by {peer_id}, call id {call_id}")
+        }
+        CallResult::Executed(ValueRef::Scalar(cid)) => {
+            format!("call executed\n{}", service_result_label(cid, cid_info))
+        }
+        CallResult::Executed(ValueRef::Stream { cid, generation }) => format!(
+            "call executed, stream generation {generation}\n{}",
+            service_result_label(cid, cid_info)
+        ),
+        CallResult::Executed(ValueRef::Unused(_)) => "call executed\nunused value".to_string(),
+        CallResult::Failed(cid) => format!("call failed\n{}", service_result_label(cid, cid_info)),
+    }
+}
+
+fn service_result_label(cid: &CID<ServiceResultAggregate>, cid_info: &CidInfo) -> String {
+    match cid_info.service_result_store.get(cid) {
+        Some(service_result) => {
+            let value = value_label(&service_result.value_cid, &cid_info.value_store);
+            match cid_info.tetraplet_store.get(&service_result.tetraplet_cid) {
+                Some(tetraplet) => format!("{value}\nby {}", tetraplet.peer_pk),
+                None => value,
+            }
+        }
+        None => unknown_cid(cid),
+    }
+}
+
+fn value_label(cid: &CID<JValue>, value_store: &CidStore<JValue>) -> String {
+    match value_store.get(cid) {
+        Some(value) => truncate(value.to_string(), MAX_VALUE_LEN),
+        None => unknown_cid(cid),
+    }
+}
+
+fn unknown_cid<Val>(cid: &CID<Val>) -> String {
+    format!(
+        "unknown CID {}",
+        truncate(cid.as_str().to_string(), MAX_VALUE_LEN)
+    )
+}
+
+pub(crate) fn truncate(mut text: String, max_len: usize) -> String {
+    if let Some((byte_pos, _)) = text.char_indices().nth(max_len) {
+        text.truncate(byte_pos);
+        text.push('…');
+    }
+
+    text
+}
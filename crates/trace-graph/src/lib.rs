@@ -0,0 +1,70 @@
+/*
+ * Copyright 2023 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+#![forbid(unsafe_code)]
+#![warn(rust_2018_idioms)]
+#![deny(
+    dead_code,
+    nonstandard_style,
+    unused_imports,
+    unused_mut,
+    unused_variables,
+    unused_unsafe,
+    unreachable_patterns
+)]
+
+mod errors;
+mod graph;
+mod instructions;
+mod labels;
+mod render;
+mod trace_tree;
+
+#[cfg(test)]
+mod tests;
+
+pub use errors::TraceGraphError;
+
+use air_interpreter_data::InterpreterData;
+
+/// Output format of a trace graph.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GraphFormat {
+    /// Graphviz DOT.
+    Dot,
+    /// Mermaid flowchart.
+    Mermaid,
+}
+
+/// Renders the execution trace of the data as a graph: par states point to their branches,
+/// fold states to their iterations, and call, ap and canon states show their values and stream
+/// generations resolved through the data CIDs. States are labelled with instructions of the script
+/// that produced them, they are found by replaying the script over the data. A trace with
+/// malformed par or fold subtraces is rejected.
+pub fn render_trace_graph(
+    air_script: &str,
+    data: &InterpreterData,
+    format: GraphFormat,
+) -> Result<String, TraceGraphError> {
+    let instructions = instructions::replay(air_script, data);
+    let graph = graph::Graph::from_data(data, &instructions)?;
+
+    let rendered = match format {
+        GraphFormat::Dot => render::to_dot(&graph),
+        GraphFormat::Mermaid => render::to_mermaid(&graph),
+    };
+    Ok(rendered)
+}
@@ -0,0 +1,85 @@
+/*
+ * Copyright 2023 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use crate::graph::Graph;
+use crate::graph::NodeKind;
+
+use std::fmt::Write;
+
+pub(crate) fn to_dot(graph: &Graph) -> String {
+    let mut output = String::from("digraph trace {\n    node [shape=box];\n");
+
+    for node in &graph.nodes {
+        let shape = match node.kind {
+            NodeKind::State => "",
+            NodeKind::Iteration => ", shape=ellipse",
+        };
+        writeln!(
+            output,
+            "    {} [label=\"{}\"{shape}];",
+            node.id,
+            escape_dot(&node.label)
+        )
+        .unwrap();
+    }
+    for edge in &graph.edges {
+        let attributes = match &edge.label {
+            Some(label) => format!(" [label=\"{}\"]", escape_dot(label)),
+            None => String::new(),
+        };
+        writeln!(output, "    {} -> {}{attributes};", edge.from, edge.to).unwrap();
+    }
+
+    output.push_str("}\n");
+    output
+}
+
+pub(crate) fn to_mermaid(graph: &Graph) -> String {
+    let mut output = String::from("flowchart TD\n");
+
+    for node in &graph.nodes {
+        let (open, close) = match node.kind {
+            NodeKind::State => ("[", "]"),
+            NodeKind::Iteration => ("([", "])"),
+        };
+        let label = escape_mermaid(&node.label);
+        writeln!(output, "    {}{open}\"{label}\"{close}", node.id).unwrap();
+    }
+    for edge in &graph.edges {
+        let arrow = match &edge.label {
+            Some(label) => format!("-->|\"{}\"|", escape_mermaid(label)),
+            None => "-->".to_string(),
+        };
+        writeln!(output, "    {} {arrow} {}", edge.from, edge.to).unwrap();
+    }
+
+    output
+}
+
+fn escape_dot(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+/// Mermaid labels are HTML, so special characters are replaced by entity codes.
+fn escape_mermaid(text: &str) -> String {
+    text.replace('#', "#35;")
+        .replace('"', "#quot;")
+        .replace('<', "#lt;")
+        .replace('>', "#gt;")
+        .replace('\n', "<br/>")
+}
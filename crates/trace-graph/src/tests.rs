@@ -0,0 +1,174 @@
+/*
+ * Copyright 2023 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use crate::render_trace_graph;
+use crate::GraphFormat;
+use crate::TraceGraphError;
+
+use air_interpreter_data::ExecutedState;
+use air_interpreter_data::InterpreterData;
+use air_interpreter_interface::CallResults;
+use air_interpreter_interface::CallServiceResult;
+use air_interpreter_interface::RunParameters;
+use air_interpreter_interface::INTERPRETER_SUCCESS;
+
+const PEER_ID: &str = "peer";
+
+fn execute(air_script: &str, prev_data: Vec<u8>, call_results: CallResults) -> Vec<u8> {
+    let params = RunParameters {
+        init_peer_id: PEER_ID.to_string(),
+        current_peer_id: PEER_ID.to_string(),
+        timestamp: 0,
+        ttl: u32::MAX,
+        require_signatures: false,
+        instruction_limit: 0,
+        current_time: 0,
+        collect_stats: false,
+    };
+    let call_results = serde_json::to_vec(&call_results).unwrap();
+
+    let outcome = air::execute_air(
+        air_script.to_string(),
+        prev_data,
+        vec![],
+        params,
+        call_results,
+    );
+    assert_eq!(
+        outcome.ret_code, INTERPRETER_SUCCESS,
+        "{}",
+        outcome.error_message
+    );
+
+    outcome.data
+}
+
+const PAR_FOLD_SCRIPT: &str = r#"
+        (seq
+            (seq
+                (ap 1 $stream)
+                (ap 2 $stream))
+            (par
+                (fold $stream v
+                    (seq
+                        (ap v $result)
+                        (next v)))
+                (canon "peer" $stream #canon)))"#;
+
+#[test]
+fn dot_shows_par_branches_and_fold_iterations() {
+    let air_script = PAR_FOLD_SCRIPT;
+    let data = execute(air_script, vec![], CallResults::new());
+    let data = InterpreterData::try_from_slice(&data).unwrap();
+
+    let graph = render_trace_graph(air_script, &data, GraphFormat::Dot).unwrap();
+
+    assert!(graph.starts_with("digraph trace {\n"), "{}", graph);
+    assert!(
+        graph.contains(r##"s0 [label="#0\n4:17 ap 1 $stream\nap\ngenerations: [0]"];"##),
+        "{}",
+        graph
+    );
+    assert!(graph.contains("s0 -> s1;"), "{}", graph);
+    assert!(graph.contains("s1 -> s2;"), "{}", graph);
+    assert!(graph.contains(r#"s2 -> s3 [label="left"];"#), "{}", graph);
+    assert!(graph.contains(r#"s2 -> s6 [label="right"];"#), "{}", graph);
+    assert!(
+        graph.contains(r##"s3_1 [label="iteration 1\nvalue at #1", shape=ellipse];"##),
+        "{}",
+        graph
+    );
+    assert!(graph.contains("s3 -> s3_0;"), "{}", graph);
+    assert!(
+        graph.contains(r#"s3_0 -> s4 [label="before next"];"#),
+        "{}",
+        graph
+    );
+    assert!(
+        graph.contains(r#"s3_1 -> s5 [label="before next"];"#),
+        "{}",
+        graph
+    );
+    assert!(graph.contains(r#"canon\nvalues: [1, 2]"#), "{}", graph);
+}
+
+#[test]
+fn mermaid_shows_call_values_and_requests() {
+    let air_script = r#"
+        (seq
+            (call "peer" ("service" "function") [] $stream)
+            (call "other_peer" ("service" "function") []))"#;
+    let data = execute(air_script, vec![], CallResults::new());
+
+    let call_result = CallServiceResult {
+        ret_code: 0,
+        result: r#""hello""#.to_string(),
+    };
+    let mut call_results = CallResults::new();
+    call_results.insert(1, call_result);
+    let data = execute(air_script, data, call_results);
+    let data = InterpreterData::try_from_slice(&data).unwrap();
+
+    let graph = render_trace_graph(air_script, &data, GraphFormat::Mermaid).unwrap();
+
+    assert!(graph.starts_with("flowchart TD\n"), "{}", graph);
+    assert!(graph.contains(r##"s0["#35;0<br/>3:13 call "##), "{}", graph);
+    assert!(
+        graph.contains("call executed, stream generation 0<br/>#quot;hello#quot;<br/>by peer\"]"),
+        "{}",
+        graph
+    );
+    assert!(
+        graph.contains("call<br/>request sent by peer\"]"),
+        "{}",
+        graph
+    );
+    assert!(graph.contains("s0 --> s1"), "{}", graph);
+}
+
+#[test]
+fn overlapping_fold_subtraces_rejected() {
+    let data = execute(PAR_FOLD_SCRIPT, vec![], CallResults::new());
+    let mut data = InterpreterData::try_from_slice(&data).unwrap();
+    match &mut data.trace[3.into()] {
+        ExecutedState::Fold(fold) => fold.lore[1].subtraces_desc[0].begin_pos = 4.into(),
+        state => panic!("expected a fold state, got {:?}", state),
+    }
+
+    let result = render_trace_graph(PAR_FOLD_SCRIPT, &data, GraphFormat::Dot);
+
+    assert_eq!(
+        result,
+        Err(TraceGraphError::InvalidFoldSubtraces { pos: 3.into() })
+    );
+}
+
+#[test]
+fn backward_fold_subtrace_rejected() {
+    let data = execute(PAR_FOLD_SCRIPT, vec![], CallResults::new());
+    let mut data = InterpreterData::try_from_slice(&data).unwrap();
+    match &mut data.trace[3.into()] {
+        ExecutedState::Fold(fold) => fold.lore[0].subtraces_desc[0].begin_pos = 0.into(),
+        state => panic!("expected a fold state, got {:?}", state),
+    }
+
+    let result = render_trace_graph(PAR_FOLD_SCRIPT, &data, GraphFormat::Dot);
+
+    assert_eq!(
+        result,
+        Err(TraceGraphError::InvalidFoldSubtraces { pos: 3.into() })
+    );
+}
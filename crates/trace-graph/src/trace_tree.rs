@@ -0,0 +1,159 @@
+/*
+ * Copyright 2023 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use crate::TraceGraphError;
+
+use air_interpreter_data::ExecutedState;
+use air_interpreter_data::ExecutionTrace;
+use air_interpreter_data::FoldResult;
+use air_interpreter_data::FoldSubTraceLore;
+use air_interpreter_data::TracePos;
+
+/// Structure of an execution trace: par and fold states own subtraces that follow them.
+pub(crate) enum TraceNode<'data> {
+    Par {
+        pos: TracePos,
+        left: Vec<TraceNode<'data>>,
+        right: Vec<TraceNode<'data>>,
+    },
+    Fold {
+        pos: TracePos,
+        iterations: Vec<FoldIteration<'data>>,
+    },
+    State {
+        pos: TracePos,
+    },
+}
+
+pub(crate) struct FoldIteration<'data> {
+    pub(crate) lore: &'data FoldSubTraceLore,
+    pub(crate) subtraces: Vec<Vec<TraceNode<'data>>>,
+}
+
+impl<'data> TraceNode<'data> {
+    pub(crate) fn from_trace(trace: &'data ExecutionTrace) -> Result<Vec<Self>, TraceGraphError> {
+        from_subtrace(trace, 0, trace.len())
+    }
+
+    pub(crate) fn pos(&self) -> TracePos {
+        match self {
+            Self::Par { pos, .. } | Self::Fold { pos, .. } | Self::State { pos } => *pos,
+        }
+    }
+}
+
+/// Builds nodes of states in [begin, end). Subtraces of a par state are clamped to the subtrace
+/// it belongs to, because inside a fold iteration they also include states brought by next.
+/// Fold subtraces are checked instead, so every state belongs to exactly one node.
+fn from_subtrace(
+    trace: &ExecutionTrace,
+    begin: usize,
+    end: usize,
+) -> Result<Vec<TraceNode<'_>>, TraceGraphError> {
+    let end = end.min(trace.len());
+    let mut nodes = Vec::new();
+    let mut pos = begin;
+
+    while pos < end {
+        let subtrace_begin = pos + 1;
+        let (node, subtrace_len) = match &trace[TracePos::from(pos)] {
+            ExecutedState::Par(par) => {
+                let left_end = subtrace_begin
+                    .saturating_add(par.left_size as usize)
+                    .min(end);
+                let right_end = left_end.saturating_add(par.right_size as usize).min(end);
+                let node = TraceNode::Par {
+                    pos: pos.into(),
+                    left: from_subtrace(trace, subtrace_begin, left_end)?,
+                    right: from_subtrace(trace, left_end, right_end)?,
+                };
+
+                (node, right_end - subtrace_begin)
+            }
+            ExecutedState::Fold(fold) => {
+                let subtrace_len = check_fold_subtraces(fold, pos, end)?;
+                let iterations = fold
+                    .lore
+                    .iter()
+                    .map(|lore| {
+                        let subtraces = lore
+                            .subtraces_desc
+                            .iter()
+                            .map(|desc| {
+                                let desc_begin = usize::from(desc.begin_pos);
+                                let desc_end =
+                                    desc_begin.saturating_add(desc.subtrace_len as usize);
+                                from_subtrace(trace, desc_begin, desc_end)
+                            })
+                            .collect::<Result<_, _>>()?;
+
+                        Ok(FoldIteration { lore, subtraces })
+                    })
+                    .collect::<Result<_, _>>()?;
+                let node = TraceNode::Fold {
+                    pos: pos.into(),
+                    iterations,
+                };
+
+                (node, subtrace_len)
+            }
+            _ => (TraceNode::State { pos: pos.into() }, 0),
+        };
+
+        nodes.push(node);
+        pos = subtrace_begin + subtrace_len;
+    }
+
+    Ok(nodes)
+}
+
+/// Returns the count of states owned by a fold state at the position. Non-empty subtraces
+/// sorted by position must follow the state one after another without overlapping or going
+/// backwards, while empty ones could have any position, as the trace handler allows. The order
+/// of subtraces in lore isn't checked, because after subtraces are placed in reverse.
+fn check_fold_subtraces(
+    fold: &FoldResult,
+    pos: usize,
+    end: usize,
+) -> Result<usize, TraceGraphError> {
+    let error = || TraceGraphError::InvalidFoldSubtraces { pos: pos.into() };
+
+    let mut subtraces = fold
+        .lore
+        .iter()
+        .flat_map(|lore| lore.subtraces_desc.iter())
+        .filter(|desc| desc.subtrace_len != 0)
+        .map(|desc| {
+            let desc_begin = usize::from(desc.begin_pos);
+            let desc_end = desc_begin.checked_add(desc.subtrace_len as usize);
+            desc_end
+                .map(|desc_end| (desc_begin, desc_end))
+                .ok_or_else(error)
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    subtraces.sort_unstable();
+
+    let subtrace_begin = pos + 1;
+    let mut subtrace_end = subtrace_begin;
+    for (desc_begin, desc_end) in subtraces {
+        if desc_begin != subtrace_end || desc_end > end {
+            return Err(error());
+        }
+        subtrace_end = desc_end;
+    }
+
+    Ok(subtrace_end - subtrace_begin)
+}
@@ -0,0 +1,39 @@
+/*
+ * Copyright 2023 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+#![no_main]
+
+use air_fuzz::ExecutionInput;
+use air_interpreter_data::InterpreterData;
+use air_interpreter_interface::INTERPRETER_SUCCESS;
+use libfuzzer_sys::fuzz_target;
+
+// A panic is reported by the fuzzer as a crash, the other outcomes are checked here:
+// any incorrect input must be reported with an error code and a message.
+fuzz_target!(|input: ExecutionInput| {
+    let outcome = input.execute();
+
+    if outcome.ret_code == INTERPRETER_SUCCESS {
+        InterpreterData::try_from_slice(&outcome.data)
+            .expect("successful run should produce valid data");
+    } else {
+        assert!(
+            !outcome.error_message.is_empty(),
+            "error code {} is returned without a message",
+            outcome.ret_code
+        );
+    }
+});
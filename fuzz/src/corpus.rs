@@ -0,0 +1,81 @@
+/*
+ * Copyright 2023 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+/// Peers the scripts of the corpus refer to, generated tetraplets and senders use them too.
+pub const PEERS: &[&str] = &["peer_a", "peer_b", "peer_c"];
+
+/// Scripts covering instructions that read or write states of a trace.
+pub const SCRIPTS: &[&str] = &[
+    r#"
+    (seq
+        (call "peer_a" ("service" "function") [] value)
+        (call "peer_b" ("service" "function") [value] result))
+    "#,
+    r#"
+    (par
+        (call "peer_a" ("service" "function") [] $stream)
+        (par
+            (call "peer_b" ("service" "function") [] $stream)
+            (call "peer_c" ("service" "function") [] $stream)))
+    "#,
+    r#"
+    (seq
+        (call "peer_a" ("service" "function") [] iterable)
+        (fold iterable iterator
+            (par
+                (call "peer_b" ("service" "function") [iterator] $results)
+                (next iterator))))
+    "#,
+    r#"
+    (seq
+        (par
+            (call "peer_a" ("service" "function") [] $stream)
+            (call "peer_b" ("service" "function") [] $stream))
+        (seq
+            (canon "peer_c" $stream #canon)
+            (fold $stream iterator
+                (seq
+                    (ap iterator $mirror)
+                    (next iterator)))))
+    "#,
+    r#"
+    (xor
+        (seq
+            (call "peer_a" ("service" "function") [] value)
+            (match value 1
+                (call "peer_b" ("service" "function") [value])))
+        (call "peer_c" ("service" "function") [%last_error%]))
+    "#,
+    r#"
+    (new $stream
+        (seq
+            (call "peer_a" ("service" "function") [] iterable)
+            (fold iterable iterator
+                (seq
+                    (call "peer_b" ("service" "function") [iterator] $stream)
+                    (next iterator))
+                (canon "peer_c" $stream #canon))))
+    "#,
+    r#"
+    (seq
+        (seq
+            (call "peer_a" ("service" "function") [] value)
+            (ap value.$.field scalar))
+        (xor
+            (call "peer_b" ("service" "function") [scalar])
+            (fail %last_error%)))
+    "#,
+];
@@ -0,0 +1,162 @@
+/*
+ * Copyright 2023 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use crate::GeneratedData;
+use crate::PEERS;
+use crate::SCRIPTS;
+
+use air_interpreter_interface::CallResults;
+use air_interpreter_interface::CallServiceResult;
+use air_interpreter_interface::InterpreterOutcome;
+use air_interpreter_interface::RunParameters;
+use arbitrary::Arbitrary;
+
+/// Limits runaway scripts, e.g. a fold over a stream fed by its own body.
+const INSTRUCTION_LIMIT: u64 = 10_000;
+
+/// Data passed to the interpreter as previous or current one.
+#[derive(Debug, Arbitrary)]
+pub enum FuzzData {
+    Empty,
+    Raw(Vec<u8>),
+    Generated(GeneratedData),
+}
+
+impl FuzzData {
+    pub fn to_bytes(&self) -> Vec<u8> {
+        match self {
+            FuzzData::Empty => vec![],
+            FuzzData::Raw(data) => data.clone(),
+            FuzzData::Generated(data) => data.to_bytes(),
+        }
+    }
+}
+
+/// A result of a service returned by a host, the result is either a valid JSON
+/// or an arbitrary string.
+#[derive(Debug, Arbitrary)]
+pub struct FuzzCallResult {
+    pub call_id: u8,
+    pub ret_code: i32,
+    pub result: String,
+    pub is_json: bool,
+}
+
+/// A run of a script from the corpus on one of the peers the corpus refers to.
+#[derive(Debug, Arbitrary)]
+pub struct ExecutionInput {
+    pub script_id: u8,
+    pub init_peer_id: u8,
+    pub current_peer_id: u8,
+    pub prev_data: FuzzData,
+    pub current_data: FuzzData,
+    pub call_results: Vec<FuzzCallResult>,
+}
+
+impl ExecutionInput {
+    pub fn execute(&self) -> InterpreterOutcome {
+        let air_script = SCRIPTS[self.script_id as usize % SCRIPTS.len()];
+        let params = RunParameters {
+            init_peer_id: peer_id(self.init_peer_id),
+            current_peer_id: peer_id(self.current_peer_id),
+            timestamp: 0,
+            ttl: u32::MAX,
+            require_signatures: false,
+            instruction_limit: INSTRUCTION_LIMIT,
+            current_time: 0,
+            collect_stats: false,
+        };
+
+        air::execute_air(
+            air_script.to_string(),
+            self.prev_data.to_bytes(),
+            self.current_data.to_bytes(),
+            params,
+            self.serialize_call_results(),
+        )
+    }
+
+    fn serialize_call_results(&self) -> Vec<u8> {
+        let call_results = self
+            .call_results
+            .iter()
+            .map(|call_result| {
+                let result = if call_result.is_json {
+                    serde_json::Value::String(call_result.result.clone()).to_string()
+                } else {
+                    call_result.result.clone()
+                };
+                let service_result = CallServiceResult {
+                    ret_code: call_result.ret_code,
+                    result,
+                };
+                (call_result.call_id as u32, service_result)
+            })
+            .collect::<CallResults>();
+
+        serde_json::to_vec(&call_results).expect("default serializer shouldn't fail")
+    }
+}
+
+fn peer_id(id: u8) -> String {
+    PEERS[id as usize % PEERS.len()].to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use air_interpreter_data::InterpreterData;
+    use air_interpreter_interface::INTERPRETER_SUCCESS;
+    use arbitrary::Unstructured;
+
+    /// Inputs are built from pseudo-random bytes with a fixed seed, so that the harness is
+    /// checked against the interpreter without running the fuzzer.
+    #[test]
+    fn generated_inputs_executed() {
+        let mut state = 0x2545_f491_4f6c_dd1d_u64;
+        let mut succeeded = 0;
+
+        for _ in 0..64 {
+            let bytes = (0..4096)
+                .map(|_| {
+                    // xorshift64
+                    state ^= state << 13;
+                    state ^= state >> 7;
+                    state ^= state << 17;
+                    state as u8
+                })
+                .collect::<Vec<_>>();
+            let input = ExecutionInput::arbitrary_take_rest(Unstructured::new(&bytes)).unwrap();
+
+            let outcome = input.execute();
+
+            if outcome.ret_code == INTERPRETER_SUCCESS {
+                InterpreterData::try_from_slice(&outcome.data)
+                    .expect("successful run should produce valid data");
+                succeeded += 1;
+            } else {
+                assert!(
+                    !outcome.error_message.is_empty(),
+                    "error code {} is returned without a message",
+                    outcome.ret_code
+                );
+            }
+        }
+
+        assert_ne!(succeeded, 0, "no generated input was executed successfully");
+    }
+}
@@ -0,0 +1,46 @@
+/*
+ * Copyright 2023 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! Structured inputs for the fuzzing targets.
+//!
+//! Raw bytes rarely get past deserialization and CID verification, so besides them
+//! the targets use generated interpreter data: traces whose states refer to CIDs
+//! actually stored in the data and whose par and fold sizes are mostly consistent.
+//! The generator occasionally shifts sizes and positions and leaves CIDs dangling
+//! to reach the error paths of the trace merger.
+
+#![forbid(unsafe_code)]
+#![warn(rust_2018_idioms)]
+#![deny(
+    dead_code,
+    nonstandard_style,
+    unused_imports,
+    unused_mut,
+    unused_variables,
+    unused_unsafe,
+    unreachable_patterns
+)]
+
+mod corpus;
+mod execution;
+mod trace_generator;
+
+pub use corpus::PEERS;
+pub use corpus::SCRIPTS;
+pub use execution::ExecutionInput;
+pub use execution::FuzzCallResult;
+pub use execution::FuzzData;
+pub use trace_generator::GeneratedData;
@@ -0,0 +1,317 @@
+/*
+ * Copyright 2023 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use crate::PEERS;
+
+use air::ExecutionCidState;
+use air_interpreter_cid::CID;
+use air_interpreter_data::ApResult;
+use air_interpreter_data::CallResult;
+use air_interpreter_data::CallServiceFailed;
+use air_interpreter_data::CanonCidAggregate;
+use air_interpreter_data::CanonResult;
+use air_interpreter_data::CidTracker;
+use air_interpreter_data::DataFormat;
+use air_interpreter_data::ExecutedState;
+use air_interpreter_data::FoldLore;
+use air_interpreter_data::FoldResult;
+use air_interpreter_data::FoldSubTraceLore;
+use air_interpreter_data::InterpreterData;
+use air_interpreter_data::ServiceResultAggregate;
+use air_interpreter_data::SubTraceDesc;
+use arbitrary::Arbitrary;
+use arbitrary::Unstructured;
+use polyplets::SecurityTetraplet;
+use serde::Serialize;
+use serde_json::json;
+use serde_json::Value as JValue;
+
+use std::rc::Rc;
+
+const MAX_DEPTH: usize = 4;
+const MAX_STATES: usize = 64;
+const MAX_SUBTRACE_STATES: usize = 3;
+const MAX_FOLD_ITERATIONS: usize = 4;
+const MAX_GENERATION: u32 = 3;
+
+/// Interpreter data with a generated trace, serialized in a generated format.
+#[derive(Debug)]
+pub struct GeneratedData {
+    pub data: InterpreterData,
+    pub format: DataFormat,
+}
+
+impl GeneratedData {
+    pub fn to_bytes(&self) -> Vec<u8> {
+        self.data
+            .serialize_with_format(self.format)
+            .expect("generated data should be serializable")
+    }
+}
+
+impl<'a> Arbitrary<'a> for GeneratedData {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        let mut generator = TraceGenerator::new(u.arbitrary()?);
+        generator.subtrace(u, 0)?;
+
+        let data = InterpreterData::from_execution_result(
+            generator.states.into(),
+            <_>::default(),
+            <_>::default(),
+            generator.cid_state.into(),
+            u.int_in_range(0..=8)?,
+            semver::Version::new(1, 1, 1),
+        );
+        let format = if u.arbitrary()? {
+            DataFormat::Json
+        } else {
+            DataFormat::MsgPack
+        };
+
+        Ok(Self { data, format })
+    }
+}
+
+struct TraceGenerator {
+    states: Vec<ExecutedState>,
+    cid_state: ExecutionCidState,
+    /// Collects CIDs which aren't put into the data, so states referring to them are dangling.
+    dangling_cid_state: ExecutionCidState,
+    /// Whether sizes, positions and CIDs could be spoiled.
+    adversarial: bool,
+}
+
+impl TraceGenerator {
+    fn new(adversarial: bool) -> Self {
+        Self {
+            states: vec![],
+            cid_state: ExecutionCidState::new(),
+            dangling_cid_state: ExecutionCidState::new(),
+            adversarial,
+        }
+    }
+
+    fn subtrace(&mut self, u: &mut Unstructured<'_>, depth: usize) -> arbitrary::Result<()> {
+        let states_count = u.int_in_range(0..=MAX_SUBTRACE_STATES)?;
+        for _ in 0..states_count {
+            if self.states.len() >= MAX_STATES || u.is_empty() {
+                break;
+            }
+            self.state(u, depth)?;
+        }
+
+        Ok(())
+    }
+
+    fn state(&mut self, u: &mut Unstructured<'_>, depth: usize) -> arbitrary::Result<()> {
+        // par and fold are nested only up to the limit to keep traces small
+        let max_kind = if depth < MAX_DEPTH { 4 } else { 2 };
+        match u.int_in_range(0..=max_kind)? {
+            0 => self.call(u),
+            1 => self.ap(u),
+            2 => self.canon(u),
+            3 => self.par(u, depth),
+            _ => self.fold(u, depth),
+        }
+    }
+
+    fn call(&mut self, u: &mut Unstructured<'_>) -> arbitrary::Result<()> {
+        let call_result = match u.int_in_range(0..=4)? {
+            0 => {
+                let peer_id = Rc::new(u.choose(PEERS)?.to_string());
+                if u.arbitrary()? {
+                    CallResult::sent_peer_id_with_call_id(peer_id, u.int_in_range(0..=8)?)
+                } else {
+                    CallResult::sent_peer_id(peer_id)
+                }
+            }
+            1 => {
+                let value = generate_value(u)?;
+                CallResult::executed_scalar(self.service_result(u, value)?)
+            }
+            2 => {
+                let value = generate_value(u)?;
+                let generation = u.int_in_range(0..=MAX_GENERATION)?;
+                CallResult::executed_stream(self.service_result(u, value)?, generation)
+            }
+            3 => {
+                // unused values aren't put into the value store
+                let value_cid = CidTracker::<JValue>::new()
+                    .record_value(generate_value(u)?)
+                    .expect("CID of a generated value should be calculated");
+                CallResult::executed_unused(value_cid)
+            }
+            _ => {
+                let message = Rc::new(String::arbitrary(u)?);
+                let value = CallServiceFailed::new(u.arbitrary()?, message).to_value();
+                CallResult::failed(self.service_result(u, value)?)
+            }
+        };
+
+        self.states.push(ExecutedState::Call(call_result));
+        Ok(())
+    }
+
+    fn ap(&mut self, u: &mut Unstructured<'_>) -> arbitrary::Result<()> {
+        let generation = u.int_in_range(0..=MAX_GENERATION)?;
+        self.states
+            .push(ExecutedState::Ap(ApResult::new(generation)));
+        Ok(())
+    }
+
+    fn canon(&mut self, u: &mut Unstructured<'_>) -> arbitrary::Result<()> {
+        let tetraplet = generate_tetraplet(u)?;
+        let values_count = u.int_in_range(0..=MAX_SUBTRACE_STATES)?;
+        let mut values = Vec::with_capacity(values_count);
+        for _ in 0..values_count {
+            values.push((generate_value(u)?, generate_tetraplet(u)?));
+        }
+
+        let cid_state = self.cid_state(u)?;
+        let tetraplet = record(&mut cid_state.tetraplet_tracker, tetraplet);
+        let values = values
+            .into_iter()
+            .map(|(value, tetraplet)| {
+                let aggregate = CanonCidAggregate {
+                    value: record(&mut cid_state.value_tracker, value),
+                    tetraplet: record(&mut cid_state.tetraplet_tracker, tetraplet),
+                };
+                record(&mut cid_state.canon_tracker, aggregate)
+            })
+            .collect();
+
+        self.states
+            .push(ExecutedState::Canon(CanonResult::new(tetraplet, values)));
+        Ok(())
+    }
+
+    fn par(&mut self, u: &mut Unstructured<'_>, depth: usize) -> arbitrary::Result<()> {
+        let par_pos = self.states.len();
+        self.states.push(ExecutedState::par(0, 0));
+
+        let left_begin = self.states.len();
+        self.subtrace(u, depth + 1)?;
+        let right_begin = self.states.len();
+        self.subtrace(u, depth + 1)?;
+
+        let left_size = self.spoil(u, right_begin - left_begin)?;
+        let right_size = self.spoil(u, self.states.len() - right_begin)?;
+        self.states[par_pos] = ExecutedState::par(left_size, right_size);
+
+        Ok(())
+    }
+
+    /// Iterations are laid out one after another, each one has a subtrace before
+    /// and a subtrace after the next instruction.
+    fn fold(&mut self, u: &mut Unstructured<'_>, depth: usize) -> arbitrary::Result<()> {
+        let fold_pos = self.states.len();
+        self.states.push(ExecutedState::Fold(FoldResult::default()));
+
+        let iterations_count = u.int_in_range(0..=MAX_FOLD_ITERATIONS)?;
+        let mut lore = FoldLore::with_capacity(iterations_count);
+        for _ in 0..iterations_count {
+            // an iterable value is produced by some state before the fold
+            let value_pos = u.int_in_range(0..=fold_pos)?;
+            let value_pos = self.spoil(u, value_pos)?;
+
+            let mut subtraces_desc = Vec::with_capacity(2);
+            for _ in 0..2 {
+                let begin_pos = self.states.len();
+                self.subtrace(u, depth + 1)?;
+                let subtrace_len = self.spoil(u, self.states.len() - begin_pos)?;
+                let begin_pos = self.spoil(u, begin_pos)?;
+                subtraces_desc.push(SubTraceDesc::new(begin_pos.into(), subtrace_len));
+            }
+
+            lore.push(FoldSubTraceLore {
+                value_pos: value_pos.into(),
+                subtraces_desc,
+            });
+        }
+
+        self.states[fold_pos] = ExecutedState::Fold(FoldResult { lore });
+        Ok(())
+    }
+
+    fn service_result(
+        &mut self,
+        u: &mut Unstructured<'_>,
+        value: JValue,
+    ) -> arbitrary::Result<Rc<CID<ServiceResultAggregate>>> {
+        let tetraplet = generate_tetraplet(u)?;
+        let cid_state = self.cid_state(u)?;
+
+        let aggregate = ServiceResultAggregate {
+            value_cid: record(&mut cid_state.value_tracker, value),
+            argument_hash: "".into(),
+            tetraplet_cid: record(&mut cid_state.tetraplet_tracker, tetraplet),
+        };
+        Ok(record(&mut cid_state.service_result_agg_tracker, aggregate))
+    }
+
+    /// Returns a CID state where values of the next state should be recorded.
+    fn cid_state(&mut self, u: &mut Unstructured<'_>) -> arbitrary::Result<&mut ExecutionCidState> {
+        if self.adversarial && u.ratio(1u8, 8u8)? {
+            Ok(&mut self.dangling_cid_state)
+        } else {
+            Ok(&mut self.cid_state)
+        }
+    }
+
+    /// Returns the actual size or position, or shifts it a bit in the adversarial mode.
+    fn spoil(&self, u: &mut Unstructured<'_>, actual: usize) -> arbitrary::Result<usize> {
+        if !self.adversarial || !u.ratio(1u8, 4u8)? {
+            return Ok(actual);
+        }
+
+        let delta = u.int_in_range(1..=2)?;
+        if u.arbitrary()? {
+            Ok(actual + delta)
+        } else {
+            Ok(actual.saturating_sub(delta))
+        }
+    }
+}
+
+fn record<Val: Serialize>(tracker: &mut CidTracker<Val>, value: Val) -> Rc<CID<Val>> {
+    tracker
+        .record_value(value)
+        .expect("CID of a generated value should be calculated")
+}
+
+fn generate_value(u: &mut Unstructured<'_>) -> arbitrary::Result<JValue> {
+    let value = match u.int_in_range(0..=4)? {
+        0 => JValue::Null,
+        1 => json!(u.int_in_range(0..=3u8)?),
+        2 => json!(u.choose(PEERS)?),
+        3 => {
+            let len = u.int_in_range(0..=MAX_SUBTRACE_STATES)?;
+            JValue::Array((0..len).map(|id| json!(id)).collect())
+        }
+        _ => json!({ "field": u.int_in_range(0..=3u8)? }),
+    };
+
+    Ok(value)
+}
+
+fn generate_tetraplet(u: &mut Unstructured<'_>) -> arbitrary::Result<SecurityTetraplet> {
+    Ok(SecurityTetraplet::new(
+        *u.choose(PEERS)?,
+        "service",
+        "function",
+        "",
+    ))
+}
@@ -30,10 +30,10 @@ pub(crate) struct Args {
     input: Option<PathBuf>,
 }
 
-fn read_script(args: &Args) -> Result<String> {
+pub(crate) fn read_script(input: &Option<PathBuf>) -> Result<String> {
     use std::io::Read;
 
-    let air_script = match &args.input {
+    let air_script = match input {
         Some(in_path) => std::fs::read_to_string(in_path)?,
         None => {
             let mut buffer = String::new();
@@ -47,8 +47,8 @@ fn read_script(args: &Args) -> Result<String> {
     Ok(air_script)
 }
 
-fn build_output(args: &Args) -> Result<Box<dyn io::Write>> {
-    let output: Box<dyn io::Write> = match &args.output {
+pub(crate) fn build_output(output: &Option<PathBuf>) -> Result<Box<dyn io::Write>> {
+    let output: Box<dyn io::Write> = match output {
         Some(out_path) => {
             let file = std::fs::File::create(out_path)?;
             Box::new(file)
@@ -62,8 +62,8 @@ fn build_output(args: &Args) -> Result<Box<dyn io::Write>> {
 }
 
 pub(crate) fn beautify(args: Args) -> Result<()> {
-    let air_script = read_script(&args).context("failed to read the input")?;
-    let output = build_output(&args).context("failed to open the output")?;
+    let air_script = read_script(&args.input).context("failed to read the input")?;
+    let output = build_output(&args.output).context("failed to open the output")?;
 
     Beautifier::new_with_indent(output, args.indent_step).beautify(&air_script)?;
     Ok(())
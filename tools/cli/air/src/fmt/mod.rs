@@ -0,0 +1,61 @@
+/*
+ * Copyright 2023 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use crate::beautify::build_output;
+use crate::beautify::read_script;
+
+use air_beautifier::Formatter;
+use anyhow::{bail, Context, Result};
+use clap::Parser;
+
+use std::io::Write;
+use std::path::PathBuf;
+
+#[derive(Parser)]
+#[clap(about = "Format an AIR script canonically, the result is still a valid AIR script")]
+pub(crate) struct Args {
+    #[clap(short, long, default_value_t = air_beautifier::DEFAULT_INDENT_STEP)]
+    indent_step: usize,
+    /// Don't output the formatted script, but fail if the input isn't formatted
+    #[clap(long)]
+    check: bool,
+    #[clap(short, long, conflicts_with = "check")]
+    output: Option<PathBuf>,
+    input: Option<PathBuf>,
+}
+
+pub(crate) fn fmt(args: Args) -> Result<()> {
+    let air_script = read_script(&args.input).context("failed to read the input")?;
+
+    let mut formatter = Formatter::new_with_indent(vec![], args.indent_step);
+    formatter.format(&air_script)?;
+    let formatted = formatter.into_inner();
+
+    if args.check {
+        if formatted != air_script.as_bytes() {
+            let input_name = match &args.input {
+                Some(in_path) => in_path.display().to_string(),
+                None => "<stdin>".to_string(),
+            };
+            bail!("{input_name} is not formatted");
+        }
+        return Ok(());
+    }
+
+    let mut output = build_output(&args.output).context("failed to open the output")?;
+    output.write_all(&formatted)?;
+    Ok(())
+}
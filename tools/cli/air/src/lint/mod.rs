@@ -0,0 +1,44 @@
+/*
+ * Copyright 2023 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use crate::beautify::read_script;
+
+use air_parser::SourcePosition;
+use anyhow::{anyhow, bail, Context, Result};
+use clap::Parser;
+
+use std::path::PathBuf;
+
+#[derive(Parser)]
+#[clap(about = "Check an AIR script for constructions that most likely behave not as intended")]
+pub(crate) struct Args {
+    input: Option<PathBuf>,
+}
+
+pub(crate) fn lint(args: Args) -> Result<()> {
+    let air_script = read_script(&args.input).context("failed to read the input")?;
+    let warnings = air_parser::lint::lint(&air_script).map_err(|error| anyhow!(error))?;
+
+    for warning in &warnings {
+        let position = SourcePosition::from_air_pos(&air_script, warning.span().left);
+        println!("{position}: warning: {warning}");
+    }
+
+    if !warnings.is_empty() {
+        bail!("{} warning(s) found", warnings.len());
+    }
+    Ok(())
+}
@@ -27,6 +27,8 @@
 )]
 
 mod beautify;
+mod fmt;
+mod lint;
 mod trace;
 
 use clap::Parser;
@@ -42,10 +44,21 @@ struct Cli {
 enum Subcommand {
     #[clap(alias = "b")]
     Beautify(self::beautify::Args),
+    Debug(self::trace::debug::Args),
+    Fmt(self::fmt::Args),
+    Lint(self::lint::Args),
+    Reduce(self::trace::reduce::Args),
     #[clap(alias = "r")]
     Run(self::trace::run::Args),
     #[clap(alias = "s")]
     Stats(self::trace::stats::Args),
+    #[clap(subcommand)]
+    Trace(TraceSubcommand),
+}
+
+#[derive(clap::Subcommand)]
+enum TraceSubcommand {
+    Graph(self::trace::graph::Args),
 }
 
 fn main() -> anyhow::Result<()> {
@@ -54,5 +67,10 @@ fn main() -> anyhow::Result<()> {
         Subcommand::Run(args) => self::trace::run::run(args),
         Subcommand::Stats(args) => self::trace::stats::stats(args),
         Subcommand::Beautify(args) => self::beautify::beautify(args),
+        Subcommand::Debug(args) => self::trace::debug::debug(args),
+        Subcommand::Fmt(args) => self::fmt::fmt(args),
+        Subcommand::Lint(args) => self::lint::lint(args),
+        Subcommand::Reduce(args) => self::trace::reduce::reduce(args),
+        Subcommand::Trace(TraceSubcommand::Graph(args)) => self::trace::graph::graph(args),
     }
 }
@@ -0,0 +1,229 @@
+/*
+ * Copyright 2023 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+mod breakpoint;
+mod debugger;
+
+use self::breakpoint::Breakpoint;
+use self::debugger::{prompt, Debugger};
+use super::run::{load_data_or_default, print_merge_conflict, read_call_results};
+use super::utils::unix_timestamp_now;
+
+use air_interpreter_interface::RunParameters;
+use air_interpreter_interface::INTERPRETER_SUCCESS;
+use avm_interface::raw_outcome::RawAVMOutcome;
+use avm_interface::{into_raw_result, CallRequests, CallResults, CallServiceResult};
+
+use anyhow::{anyhow, Context as _};
+use clap::Parser;
+
+use std::path::PathBuf;
+
+#[derive(Parser, Debug)]
+#[clap(about = "Execute AIR script step by step with the native AquaVM")]
+pub(crate) struct Args {
+    #[clap(long = "prev-data")]
+    prev_data_path: Option<PathBuf>,
+    #[clap(long = "current-data")]
+    current_data_path: Option<PathBuf>,
+    #[clap(long = "call-results")]
+    call_results_path: Option<PathBuf>,
+
+    #[clap(long, default_value = "some_id")]
+    init_peer_id: String,
+    #[clap(long, default_value = "some_id")]
+    current_peer_id: String,
+    #[clap(long, help = "default: current time")]
+    timestamp: Option<u64>,
+    #[clap(long, help = "default: max possible ttl")]
+    ttl: Option<u32>,
+
+    #[clap(
+        short = 'b',
+        long = "break",
+        value_name = "LINE[:COLUMN]",
+        help = "Stop before instructions at the position"
+    )]
+    breakpoints: Vec<Breakpoint>,
+
+    #[clap(help = "AIR script to debug; stdin is used for commands")]
+    air_script_path: PathBuf,
+}
+
+pub(crate) fn debug(args: Args) -> anyhow::Result<()> {
+    let air_script =
+        std::fs::read_to_string(&args.air_script_path).context("failed to read AIR script")?;
+    let mut prev_data = load_data_or_default(args.prev_data_path.as_ref(), "")
+        .context("failed to read prev_data")?
+        .into_bytes();
+    let mut current_data = load_data_or_default(args.current_data_path.as_ref(), "")
+        .context("failed to read current_data")?
+        .into_bytes();
+    let mut call_results = read_call_results(args.call_results_path.as_deref())?;
+
+    let run_parameters = RunParameters {
+        init_peer_id: args.init_peer_id,
+        current_peer_id: args.current_peer_id,
+        timestamp: args.timestamp.unwrap_or_else(unix_timestamp_now),
+        ttl: args.ttl.unwrap_or(u32::MAX),
+        require_signatures: false,
+        instruction_limit: 0,
+        // replayed particles shouldn't be refused as expired
+        current_time: 0,
+        collect_stats: false,
+    };
+
+    let debugger = Debugger::new(args.breakpoints);
+    loop {
+        let raw_call_results = serde_json::to_vec(&into_raw_result(call_results))?;
+        let outcome = air::execute_air_with_observer(
+            air_script.clone(),
+            prev_data,
+            current_data,
+            run_parameters.clone(),
+            raw_call_results,
+            Box::new(debugger.clone()),
+        );
+        let outcome = RawAVMOutcome::from_interpreter_outcome(outcome)?;
+        print_outcome(&outcome);
+
+        if debugger.is_quit() || outcome.ret_code != INTERPRETER_SUCCESS {
+            return Ok(());
+        }
+
+        call_results = answer_call_requests(&outcome.call_requests)?;
+        if call_results.is_empty() {
+            return Ok(());
+        }
+
+        println!("executing the script again with the call results");
+        prev_data = outcome.data;
+        current_data = vec![];
+    }
+}
+
+fn print_outcome(outcome: &RawAVMOutcome) {
+    println!("ret_code: {}", outcome.ret_code);
+    if !outcome.error_message.is_empty() {
+        println!("error_message: {}", outcome.error_message);
+        print_merge_conflict(&outcome.merge_conflict);
+    }
+    println!("next_peer_pks: {:?}", outcome.next_peer_pks);
+}
+
+/// Asks for the result of every call request, skipped requests stay unanswered.
+fn answer_call_requests(call_requests: &CallRequests) -> anyhow::Result<CallResults> {
+    let mut call_ids = call_requests.keys().copied().collect::<Vec<_>>();
+    call_ids.sort_unstable();
+    if !call_ids.is_empty() {
+        println!("answer call requests with 'JSON', 'ok JSON' or 'err CODE JSON', an empty line skips a request");
+    }
+
+    let mut call_results = CallResults::new();
+    for call_id in call_ids {
+        let request = &call_requests[&call_id];
+        println!(
+            "call request {call_id}: {} {} {}",
+            request.service_id,
+            request.function_name,
+            serde_json::to_string(&request.arguments)?,
+        );
+
+        loop {
+            let line = match prompt("result> ")? {
+                Some(line) => line,
+                None => return Ok(call_results),
+            };
+            if line.is_empty() {
+                break;
+            }
+
+            match parse_call_result(&line) {
+                Ok(result) => {
+                    call_results.insert(call_id, result);
+                    break;
+                }
+                Err(error) => println!("{error:#}"),
+            }
+        }
+    }
+
+    Ok(call_results)
+}
+
+fn parse_call_result(line: &str) -> anyhow::Result<CallServiceResult> {
+    if let Some(result) = line.strip_prefix("ok ") {
+        return Ok(CallServiceResult::ok(parse_json(result)?));
+    }
+
+    if let Some(rest) = line.strip_prefix("err ") {
+        let (ret_code, result) = rest
+            .trim_start()
+            .split_once(char::is_whitespace)
+            .ok_or_else(|| anyhow!("expected 'err CODE JSON'"))?;
+        let ret_code = ret_code.parse().context("invalid error code")?;
+        return Ok(CallServiceResult::err(ret_code, parse_json(result)?));
+    }
+
+    Ok(CallServiceResult::ok(parse_json(line)?))
+}
+
+fn parse_json(value: &str) -> anyhow::Result<serde_json::Value> {
+    serde_json::from_str(value.trim()).context("invalid JSON")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use serde_json::json;
+
+    #[test]
+    fn call_results_are_parsed() {
+        assert_eq!(
+            parse_call_result(r#"{"key": [1, 2]}"#).unwrap(),
+            CallServiceResult::ok(json!({"key": [1, 2]}))
+        );
+        assert_eq!(
+            parse_call_result(r#"ok  "value" "#).unwrap(),
+            CallServiceResult::ok(json!("value"))
+        );
+        assert_eq!(
+            parse_call_result(r#"err 42 "failed""#).unwrap(),
+            CallServiceResult::err(42, json!("failed"))
+        );
+        assert_eq!(
+            parse_call_result("err  -1   null").unwrap(),
+            CallServiceResult::err(-1, json!(null))
+        );
+    }
+
+    #[test]
+    fn malformed_call_results_are_refused() {
+        for line in [
+            "not json",
+            "ok",
+            "ok {",
+            "err",
+            "err 42",
+            "err code null",
+            "err 42 {",
+            "okay 1",
+        ] {
+            assert!(parse_call_result(line).is_err(), "{line:?} must be refused");
+        }
+    }
+}
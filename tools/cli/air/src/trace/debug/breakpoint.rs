@@ -0,0 +1,126 @@
+/*
+ * Copyright 2023 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use air_parser::SourcePosition;
+use anyhow::{anyhow, Context};
+
+use std::fmt;
+use std::str::FromStr;
+
+/// Stops execution before instructions starting on the line, or at the exact column if it's set.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct Breakpoint {
+    line: usize,
+    column: Option<usize>,
+}
+
+impl Breakpoint {
+    pub(super) fn matches(&self, position: SourcePosition) -> bool {
+        self.line == position.line && self.column.map_or(true, |column| column == position.column)
+    }
+}
+
+impl FromStr for Breakpoint {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (line, column) = match s.split_once(':') {
+            Some((line, column)) => (line, Some(column)),
+            None => (s, None),
+        };
+
+        let line = parse_number(line).context("invalid breakpoint line")?;
+        let column = column
+            .map(parse_number)
+            .transpose()
+            .context("invalid breakpoint column")?;
+
+        Ok(Self { line, column })
+    }
+}
+
+impl fmt::Display for Breakpoint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.column {
+            Some(column) => write!(f, "{}:{column}", self.line),
+            None => write!(f, "{}", self.line),
+        }
+    }
+}
+
+// lines and columns start from 1, as in error messages and lint warnings
+fn parse_number(s: &str) -> anyhow::Result<usize> {
+    match s.trim().parse()? {
+        0 => Err(anyhow!("positions start from 1")),
+        number => Ok(number),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn breakpoints_are_parsed() {
+        let line: Breakpoint = "3".parse().unwrap();
+        assert_eq!(
+            line,
+            Breakpoint {
+                line: 3,
+                column: None
+            }
+        );
+        assert_eq!(line.to_string(), "3");
+
+        let column: Breakpoint = " 3 : 7 ".parse().unwrap();
+        assert_eq!(
+            column,
+            Breakpoint {
+                line: 3,
+                column: Some(7)
+            }
+        );
+        assert_eq!(column.to_string(), "3:7");
+    }
+
+    #[test]
+    fn malformed_breakpoints_are_refused() {
+        for breakpoint in ["", "0", "3:0", "line", "3:", ":7", "3:7:1", "-1", "3.5"] {
+            assert!(
+                breakpoint.parse::<Breakpoint>().is_err(),
+                "{breakpoint:?} must be refused"
+            );
+        }
+    }
+
+    #[test]
+    fn breakpoints_match_positions() {
+        let line = Breakpoint {
+            line: 3,
+            column: None,
+        };
+        assert!(line.matches(SourcePosition::new(3, 1)));
+        assert!(line.matches(SourcePosition::new(3, 10)));
+        assert!(!line.matches(SourcePosition::new(4, 1)));
+
+        let column = Breakpoint {
+            line: 3,
+            column: Some(7),
+        };
+        assert!(column.matches(SourcePosition::new(3, 7)));
+        assert!(!column.matches(SourcePosition::new(3, 8)));
+    }
+}
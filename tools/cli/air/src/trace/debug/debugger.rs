@@ -0,0 +1,196 @@
+/*
+ * Copyright 2023 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use super::breakpoint::Breakpoint;
+
+use air::ExecutionObserver;
+use air::ExecutionStep;
+use air_parser::SourcePosition;
+
+use std::cell::RefCell;
+use std::io::{BufRead, Write};
+use std::rc::Rc;
+
+const HELP: &str = "commands:
+  s, step              execute the next instruction (also an empty line)
+  c, continue          run until the next breakpoint
+  b, break LINE[:COL]  set a breakpoint
+  d, delete LINE[:COL] delete a breakpoint
+  l, list              list breakpoints
+  p, print             print the current step again
+  q, quit              run the rest of the script without stopping
+  h, help              print this help";
+
+/// Interactive session shared between interpreter runs, so breakpoints survive answering call requests.
+#[derive(Clone)]
+pub(super) struct Debugger {
+    session: Rc<RefCell<Session>>,
+}
+
+struct Session {
+    breakpoints: Vec<Breakpoint>,
+    mode: Mode,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Mode {
+    Step,
+    Continue,
+    Quit,
+}
+
+impl Debugger {
+    /// Stops at the first instruction if there are no breakpoints.
+    pub(super) fn new(breakpoints: Vec<Breakpoint>) -> Self {
+        let mode = if breakpoints.is_empty() {
+            Mode::Step
+        } else {
+            Mode::Continue
+        };
+        let session = Session { breakpoints, mode };
+
+        Self {
+            session: Rc::new(RefCell::new(session)),
+        }
+    }
+
+    pub(super) fn is_quit(&self) -> bool {
+        self.session.borrow().mode == Mode::Quit
+    }
+}
+
+impl ExecutionObserver for Debugger {
+    fn before_instruction(&mut self, step: &ExecutionStep<'_, '_>) {
+        let mut session = self.session.borrow_mut();
+        if !session.should_stop(step.position()) {
+            return;
+        }
+
+        print_step(step);
+        session.interact(step);
+    }
+}
+
+impl Session {
+    fn should_stop(&self, position: Option<SourcePosition>) -> bool {
+        match self.mode {
+            Mode::Step => true,
+            Mode::Continue => position.map_or(false, |position| {
+                self.breakpoints
+                    .iter()
+                    .any(|breakpoint| breakpoint.matches(position))
+            }),
+            Mode::Quit => false,
+        }
+    }
+
+    fn interact(&mut self, step: &ExecutionStep<'_, '_>) {
+        loop {
+            let line = match prompt("(air debug) ") {
+                Ok(Some(line)) => line,
+                Ok(None) => {
+                    self.mode = Mode::Quit;
+                    return;
+                }
+                Err(error) => {
+                    eprintln!("failed to read a command: {error}");
+                    self.mode = Mode::Quit;
+                    return;
+                }
+            };
+
+            let (command, argument) = match line.split_once(char::is_whitespace) {
+                Some((command, argument)) => (command, argument.trim()),
+                None => (line.as_str(), ""),
+            };
+
+            match command {
+                "" | "s" | "step" => {
+                    self.mode = Mode::Step;
+                    return;
+                }
+                "c" | "continue" => {
+                    self.mode = Mode::Continue;
+                    return;
+                }
+                "q" | "quit" => {
+                    self.mode = Mode::Quit;
+                    return;
+                }
+                "b" | "break" => match argument.parse::<Breakpoint>() {
+                    Ok(breakpoint) => {
+                        if !self.breakpoints.contains(&breakpoint) {
+                            self.breakpoints.push(breakpoint);
+                        }
+                        println!("breakpoint set at {breakpoint}");
+                    }
+                    Err(error) => println!("{error:#}"),
+                },
+                "d" | "delete" => match argument.parse::<Breakpoint>() {
+                    Ok(breakpoint) => {
+                        let count = self.breakpoints.len();
+                        self.breakpoints.retain(|known| *known != breakpoint);
+                        if self.breakpoints.len() == count {
+                            println!("no breakpoint at {breakpoint}");
+                        } else {
+                            println!("breakpoint at {breakpoint} deleted");
+                        }
+                    }
+                    Err(error) => println!("{error:#}"),
+                },
+                "l" | "list" => {
+                    if self.breakpoints.is_empty() {
+                        println!("no breakpoints");
+                    }
+                    for breakpoint in &self.breakpoints {
+                        println!("{breakpoint}");
+                    }
+                }
+                "p" | "print" => print_step(step),
+                "h" | "help" => println!("{HELP}"),
+                unknown => {
+                    println!("unknown command '{unknown}', type 'help' for the list of commands")
+                }
+            }
+        }
+    }
+}
+
+fn print_step(step: &ExecutionStep<'_, '_>) {
+    let (prev_pos, current_pos) = step.trace_positions();
+
+    match step.position() {
+        Some(position) => println!("{position}: {}", step.instruction()),
+        None => println!("{}", step.instruction()),
+    }
+    println!(
+        "trace positions: prev {prev_pos}, current {current_pos}, result {}",
+        step.result_trace_pos()
+    );
+    println!("{}", step.context());
+}
+
+/// Returns None when stdin is closed.
+pub(super) fn prompt(text: &str) -> std::io::Result<Option<String>> {
+    print!("{text}");
+    std::io::stdout().flush()?;
+
+    let mut line = String::new();
+    match std::io::stdin().lock().read_line(&mut line)? {
+        0 => Ok(None),
+        _ => Ok(Some(line.trim().to_owned())),
+    }
+}
@@ -0,0 +1,62 @@
+/*
+ * Copyright 2023 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use crate::beautify::{build_output, read_script};
+
+use air::interpreter_data::InterpreterData;
+use air_trace_graph::GraphFormat;
+use anyhow::Context;
+use clap::Parser;
+
+use std::io::Write;
+use std::path::PathBuf;
+
+#[derive(Parser, Debug)]
+#[clap(about = "Render an execution trace of AquaVM data as a DOT or Mermaid graph")]
+pub(crate) struct Args {
+    #[clap(long = "data", help = "interpreter data containing the trace")]
+    data_path: PathBuf,
+    #[clap(long, value_enum, default_value_t = Format::Dot)]
+    format: Format,
+    #[clap(short, long)]
+    output: Option<PathBuf>,
+    #[clap(help = "AIR script the data was produced by, read from stdin by default")]
+    input: Option<PathBuf>,
+}
+
+#[derive(clap::ValueEnum, Clone, Copy, Debug)]
+enum Format {
+    Dot,
+    Mermaid,
+}
+
+pub(crate) fn graph(args: Args) -> anyhow::Result<()> {
+    let air_script = read_script(&args.input).context("failed to read AIR script")?;
+    let data = std::fs::read(&args.data_path).context("failed to read data")?;
+    let data = InterpreterData::try_from_slice(&data).context("failed to parse data")?;
+
+    let format = match args.format {
+        Format::Dot => GraphFormat::Dot,
+        Format::Mermaid => GraphFormat::Mermaid,
+    };
+    let graph = air_trace_graph::render_trace_graph(&air_script, &data, format)
+        .context("failed to render the trace graph")?;
+
+    let mut output = build_output(&args.output).context("failed to open the output")?;
+    output.write_all(graph.as_bytes())?;
+
+    Ok(())
+}
@@ -14,6 +14,9 @@
  * limitations under the License.
  */
 
+pub(crate) mod debug;
+pub(crate) mod graph;
+pub(crate) mod reduce;
 pub(crate) mod run;
 pub(crate) mod stats;
 mod utils;
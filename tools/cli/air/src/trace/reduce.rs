@@ -0,0 +1,349 @@
+/*
+ * Copyright 2023 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+mod data;
+mod ddmin;
+mod script;
+
+use super::run::data::anomaly::parse_call_results;
+use super::run::get_runner;
+use super::run::runner::{AirRunner, InterpreterTrap};
+use avm_data_store::AnomalyData;
+use avm_interface::raw_outcome::RawAVMOutcome;
+use avm_interface::{CallResults, ParticleParameters};
+
+use anyhow::{bail, Context as _};
+use clap::{Parser, ValueEnum};
+
+use std::panic::AssertUnwindSafe;
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+/// Execution time of a single run is noisy, so the latency predicate checks the median one.
+const LATENCY_RUNS_COUNT: usize = 5;
+
+#[derive(Parser, Debug)]
+#[clap(about = "Shrink anomaly data to a minimal reproducer of the anomaly")]
+pub(crate) struct Args {
+    #[clap(long, value_enum, default_value = "error-code")]
+    predicate: Predicate,
+    #[clap(
+        long = "latency-threshold",
+        value_name = "MILLISECONDS",
+        help = "Execution time kept by the latency predicate"
+    )]
+    latency_threshold_ms: Option<u64>,
+
+    #[clap(long)]
+    max_heap_size: Option<u64>,
+    #[clap(long)]
+    native: bool,
+    #[clap(
+        long = "interpreter",
+        env = "AIR_INTERPRETER_WASM_PATH",
+        default_value = "target/wasm32-wasi/release/air_interpreter_server.wasm"
+    )]
+    air_interpreter_path: PathBuf,
+
+    #[clap(short, long = "output", help = "default: stdout")]
+    output_path: Option<PathBuf>,
+    #[clap(help = "Anomaly data to reduce")]
+    anomaly_data_path: PathBuf,
+}
+
+/// A property of an execution that must hold for every reduced anomaly.
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+enum Predicate {
+    /// The interpreter returns the same error code and message as with the original anomaly.
+    ErrorCode,
+    /// The interpreter panics or its wasm module traps.
+    Panic,
+    /// The median execution time of several runs is longer than the latency threshold.
+    Latency,
+}
+
+#[derive(Clone)]
+struct Bundle {
+    air_script: String,
+    prev_data: Vec<u8>,
+    current_data: Vec<u8>,
+    call_results: CallResults,
+    particle: ParticleParameters<'static>,
+}
+
+struct Run {
+    /// `None` if the interpreter panicked or the runner failed.
+    outcome: Option<RawAVMOutcome>,
+    /// True if the interpreter panicked or its wasm module trapped.
+    crashed: bool,
+    execution_time: Duration,
+}
+
+struct Reducer {
+    runner: Box<dyn AirRunner>,
+    predicate: Predicate,
+    expected_ret_code: i64,
+    /// Error message of the original anomaly without a position of the failed instruction.
+    expected_error_message: String,
+    latency_threshold: Duration,
+    runs_count: usize,
+}
+
+pub(crate) fn reduce(args: Args) -> anyhow::Result<()> {
+    let anomaly_json =
+        std::fs::read_to_string(&args.anomaly_data_path).context("failed to read anomaly data")?;
+    let anomaly: AnomalyData<'_> =
+        serde_json::from_str(&anomaly_json).context("failed to parse anomaly data")?;
+    let particle: ParticleParameters<'static> = serde_json::from_reader(anomaly.particle.as_ref())
+        .context("anomaly particle is not a valid JSON")?;
+
+    let original = Bundle {
+        air_script: anomaly.air_script.to_string(),
+        prev_data: anomaly.prev_data.to_vec(),
+        current_data: anomaly.current_data.to_vec(),
+        call_results: parse_call_results(&anomaly.call_results)?,
+        particle,
+    };
+
+    let latency_threshold = match (args.predicate, args.latency_threshold_ms) {
+        (Predicate::Latency, None) => bail!("the latency predicate requires --latency-threshold"),
+        (_, latency_threshold_ms) => {
+            Duration::from_millis(latency_threshold_ms.unwrap_or_default())
+        }
+    };
+    let runner = get_runner(args.native, &args.air_interpreter_path, args.max_heap_size)?;
+    let mut reducer = Reducer {
+        runner,
+        predicate: args.predicate,
+        expected_ret_code: 0,
+        expected_error_message: String::new(),
+        latency_threshold,
+        runs_count: 0,
+    };
+
+    // panics are expected while reducing, their messages would only clutter the output
+    let panic_hook = std::panic::take_hook();
+    std::panic::set_hook(Box::new(|_| {}));
+    let result = reducer
+        .check_original(&original)
+        .map(|_| reducer.reduce(original.clone()));
+    std::panic::set_hook(panic_hook);
+    let reduced = result?;
+
+    let last_run = reducer.run(&reduced);
+    eprintln!(
+        "reduced in {} runs: script {} -> {} bytes, prev_data {} -> {} bytes, current_data {} -> {} bytes, call results {} -> {}",
+        reducer.runs_count,
+        original.air_script.len(),
+        reduced.air_script.len(),
+        original.prev_data.len(),
+        reduced.prev_data.len(),
+        original.current_data.len(),
+        reduced.current_data.len(),
+        original.call_results.len(),
+        reduced.call_results.len(),
+    );
+
+    let reduced_json = to_anomaly_json(&reduced, &anomaly.particle, &last_run)?;
+    match args.output_path {
+        Some(output_path) => {
+            std::fs::write(output_path, reduced_json).context("failed to write reduced anomaly")
+        }
+        None => {
+            println!("{reduced_json}");
+            Ok(())
+        }
+    }
+}
+
+impl Reducer {
+    /// Checks that the predicate holds for the original anomaly, the error code and message
+    /// it returns are the ones expected from the reduced ones.
+    fn check_original(&mut self, original: &Bundle) -> anyhow::Result<()> {
+        let run = self.run(original);
+        if let (Predicate::ErrorCode, Some(outcome)) = (self.predicate, &run.outcome) {
+            if outcome.ret_code == air::INTERPRETER_SUCCESS {
+                bail!(
+                    "the original anomaly is executed successfully, there is no error code to keep"
+                );
+            }
+            self.expected_ret_code = outcome.ret_code;
+            self.expected_error_message = outcome.error_message.clone();
+        }
+
+        if !self.holds_for(&run) {
+            bail!("the predicate doesn't hold for the original anomaly");
+        }
+
+        Ok(())
+    }
+
+    /// Shrinks every part of the bundle in turn until none of them can be shrunk anymore,
+    /// since a smaller script could make smaller data sufficient and vice versa.
+    fn reduce(&mut self, mut bundle: Bundle) -> Bundle {
+        loop {
+            let size_before = bundle_size(&bundle);
+
+            self.reduce_script(&mut bundle);
+            self.reduce_call_results(&mut bundle);
+            self.reduce_data(&mut bundle, |bundle| &mut bundle.prev_data);
+            self.reduce_data(&mut bundle, |bundle| &mut bundle.current_data);
+
+            if bundle_size(&bundle) >= size_before {
+                return bundle;
+            }
+        }
+    }
+
+    fn reduce_script(&mut self, bundle: &mut Bundle) {
+        // the script is parsed again after every accepted simplification, spans change
+        while let Some(air_script) = script::simplifications(&bundle.air_script)
+            .into_iter()
+            .find(|air_script| {
+                let candidate = Bundle {
+                    air_script: air_script.clone(),
+                    ..bundle.clone()
+                };
+                self.holds(&candidate)
+            })
+        {
+            bundle.air_script = air_script;
+        }
+    }
+
+    fn reduce_call_results(&mut self, bundle: &mut Bundle) {
+        let mut call_results = bundle.call_results.clone().into_iter().collect::<Vec<_>>();
+        call_results.sort_by_key(|(call_id, _)| *call_id);
+
+        let mut candidate = bundle.clone();
+        let call_results = ddmin::minimize(call_results, |call_results| {
+            candidate.call_results = call_results.iter().cloned().collect();
+            self.holds(&candidate)
+        });
+        bundle.call_results = call_results.into_iter().collect();
+    }
+
+    fn reduce_data(&mut self, bundle: &mut Bundle, part: impl Fn(&mut Bundle) -> &mut Vec<u8>) {
+        let original = part(bundle).clone();
+        let mut candidate = bundle.clone();
+        let reduced = data::minimize(&original, |reduced| {
+            *part(&mut candidate) = reduced.to_vec();
+            self.holds(&candidate)
+        });
+        *part(bundle) = reduced;
+    }
+
+    fn holds(&mut self, bundle: &Bundle) -> bool {
+        let run = self.run(bundle);
+        self.holds_for(&run)
+    }
+
+    fn holds_for(&self, run: &Run) -> bool {
+        match self.predicate {
+            Predicate::ErrorCode => run.outcome.as_ref().map_or(false, |outcome| {
+                outcome.ret_code == self.expected_ret_code
+                    && outcome.error_message == self.expected_error_message
+            }),
+            Predicate::Panic => run.crashed,
+            Predicate::Latency => run.execution_time > self.latency_threshold,
+        }
+    }
+
+    /// Executes the bundle once, or several times for the latency predicate
+    /// to get the median execution time.
+    fn run(&mut self, bundle: &Bundle) -> Run {
+        if self.predicate != Predicate::Latency {
+            return self.execute(bundle);
+        }
+
+        let mut runs = (0..LATENCY_RUNS_COUNT)
+            .map(|_| self.execute(bundle))
+            .collect::<Vec<_>>();
+        runs.sort_by_key(|run| run.execution_time);
+        runs.swap_remove(LATENCY_RUNS_COUNT / 2)
+    }
+
+    fn execute(&mut self, bundle: &Bundle) -> Run {
+        self.runs_count += 1;
+
+        let particle = &bundle.particle;
+        let runner = &mut self.runner;
+        let start_time = Instant::now();
+        let result = std::panic::catch_unwind(AssertUnwindSafe(|| {
+            runner.call_tracing(
+                bundle.air_script.clone(),
+                bundle.prev_data.clone(),
+                bundle.current_data.clone(),
+                particle.init_peer_id.to_string(),
+                particle.timestamp,
+                particle.ttl,
+                particle.current_peer_id.to_string(),
+                bundle.call_results.clone(),
+                "warn".to_owned(),
+                1,
+            )
+        }));
+
+        let execution_time = start_time.elapsed();
+
+        match result {
+            Ok(Ok(outcome)) => Run {
+                outcome: Some(outcome),
+                crashed: false,
+                execution_time,
+            },
+            Ok(Err(error)) => Run {
+                outcome: None,
+                crashed: error.downcast_ref::<InterpreterTrap>().is_some(),
+                execution_time,
+            },
+            // the native runner executes the interpreter in this process
+            Err(_) => Run {
+                outcome: None,
+                crashed: true,
+                execution_time,
+            },
+        }
+    }
+}
+
+fn bundle_size(bundle: &Bundle) -> usize {
+    bundle.air_script.len()
+        + bundle.prev_data.len()
+        + bundle.current_data.len()
+        + bundle.call_results.len()
+}
+
+/// The reduced anomaly is in the same format as the original one, so `air run --anomaly` loads it.
+fn to_anomaly_json(bundle: &Bundle, particle: &[u8], run: &Run) -> anyhow::Result<String> {
+    let call_results = serde_json::to_vec(&bundle.call_results)?;
+    let avm_outcome = match &run.outcome {
+        Some(outcome) => serde_json::to_vec(outcome)?,
+        None => vec![],
+    };
+
+    let anomaly = AnomalyData::new(
+        &bundle.air_script,
+        particle,
+        &bundle.prev_data,
+        &bundle.current_data,
+        &call_results,
+        &avm_outcome,
+        run.execution_time,
+        0,
+    );
+    Ok(serde_json::to_string(&anomaly)?)
+}
@@ -0,0 +1,111 @@
+/*
+ * Copyright 2023 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use super::ddmin;
+use air::interpreter_data::{DataFormat, ExecutedState, ExecutionTrace, InterpreterData};
+
+/// Shrinks interpreter data while the test holds: tries to drop it entirely first, then drops
+/// states of the trace together with values of CID stores only these states refer to.
+/// Data that can't be deserialized is only tried to be dropped.
+pub(super) fn minimize(data: &[u8], mut holds: impl FnMut(&[u8]) -> bool) -> Vec<u8> {
+    if data.is_empty() || holds(&[]) {
+        return vec![];
+    }
+
+    let (format, original) = match (
+        DataFormat::detect(data),
+        InterpreterData::try_from_slice(data),
+    ) {
+        (Ok(format), Ok(original)) => (format, original),
+        _ => return data.to_vec(),
+    };
+
+    let states = original.trace.iter().cloned().collect::<Vec<_>>();
+    let states = ddmin::minimize(states, |states| {
+        with_trace(&original, states, format).map_or(false, |data| holds(&data))
+    });
+
+    match with_trace(&original, &states, format) {
+        // ddmin has already checked the reduced trace
+        Some(reduced) if states.len() < original.trace.len() => reduced,
+        // only CID stores are collected, they're checked since ddmin doesn't run the whole trace
+        Some(reduced) if holds(&reduced) => reduced,
+        _ => data.to_vec(),
+    }
+}
+
+fn with_trace(
+    original: &InterpreterData,
+    states: &[ExecutedState],
+    format: DataFormat,
+) -> Option<Vec<u8>> {
+    let trace = ExecutionTrace::from(states.to_vec());
+    let cid_info = air::retain_reachable_cids(&trace, original.cid_info.clone());
+    let data = InterpreterData {
+        trace,
+        cid_info,
+        ..original.clone()
+    };
+
+    data.serialize_with_format(format).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use air::interpreter_data::{data_version, ApResult};
+
+    fn has_ap_state(data: &[u8], generation: u32) -> bool {
+        InterpreterData::try_from_slice(data).map_or(false, |data| {
+            data.trace
+                .iter()
+                .any(|state| state == &ExecutedState::Ap(ApResult::new(generation)))
+        })
+    }
+
+    #[test]
+    fn trace_is_reduced_to_required_states() {
+        let mut data = InterpreterData::new(data_version().clone());
+        data.trace = (0..10)
+            .map(|generation| ExecutedState::Ap(ApResult::new(generation)))
+            .collect::<Vec<_>>()
+            .into();
+        let data = data.serialize_with_format(DataFormat::Json).unwrap();
+
+        let reduced = minimize(&data, |data| has_ap_state(data, 7));
+
+        let reduced = InterpreterData::try_from_slice(&reduced).unwrap();
+        let expected_trace = ExecutionTrace::from(vec![ExecutedState::Ap(ApResult::new(7))]);
+        assert_eq!(reduced.trace, expected_trace);
+    }
+
+    #[test]
+    fn unneeded_data_is_dropped() {
+        let data = InterpreterData::new(data_version().clone())
+            .serialize_with_format(DataFormat::Json)
+            .unwrap();
+
+        assert!(minimize(&data, |_| true).is_empty());
+    }
+
+    #[test]
+    fn undecodable_data_is_kept() {
+        let data = b"not interpreter data";
+
+        assert_eq!(minimize(data, |data| !data.is_empty()), data.to_vec());
+    }
+}
@@ -0,0 +1,107 @@
+/*
+ * Copyright 2023 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+/// Minimizes items while the test holds with the delta debugging algorithm: the items are split
+/// into chunks, a chunk is dropped if the test holds without it, and chunks get smaller until
+/// no single item could be dropped.
+pub(super) fn minimize<T: Clone>(mut items: Vec<T>, mut holds: impl FnMut(&[T]) -> bool) -> Vec<T> {
+    let mut chunks_count = 2;
+
+    while !items.is_empty() {
+        let chunk_size = (items.len() + chunks_count - 1) / chunks_count;
+        let mut is_reduced = false;
+
+        let mut chunk_start = 0;
+        while chunk_start < items.len() {
+            let chunk_end = (chunk_start + chunk_size).min(items.len());
+            let complement = items[..chunk_start]
+                .iter()
+                .chain(&items[chunk_end..])
+                .cloned()
+                .collect::<Vec<_>>();
+
+            if holds(&complement) {
+                // the next chunk starts at the same position now
+                items = complement;
+                is_reduced = true;
+            } else {
+                chunk_start = chunk_end;
+            }
+        }
+
+        if is_reduced {
+            chunks_count = (chunks_count - 1).max(2);
+        } else if chunk_size == 1 {
+            break;
+        } else {
+            chunks_count = (chunks_count * 2).min(items.len());
+        }
+    }
+
+    items
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn only_required_items_are_kept() {
+        let items = (0..20).collect::<Vec<_>>();
+        let reduced = minimize(items, |items| items.contains(&3) && items.contains(&17));
+
+        assert_eq!(reduced, vec![3, 17]);
+    }
+
+    #[test]
+    fn result_is_one_minimal() {
+        let holds = |items: &[u32]| items.iter().sum::<u32>() >= 10;
+        let reduced = minimize((1..=8).collect(), holds);
+
+        assert!(holds(&reduced));
+        for idx in 0..reduced.len() {
+            let mut without_item = reduced.clone();
+            without_item.remove(idx);
+            assert!(
+                !holds(&without_item),
+                "{reduced:?} could lose {idx}-th item"
+            );
+        }
+    }
+
+    #[test]
+    fn minimization_terminates() {
+        let items = (0..64).collect::<Vec<_>>();
+
+        let mut checks_count = 0;
+        let reduced = minimize(items.clone(), |_| {
+            checks_count += 1;
+            true
+        });
+        assert!(reduced.is_empty());
+        assert!(checks_count <= items.len(), "{checks_count} checks");
+
+        let mut checks_count = 0;
+        let reduced = minimize(items.clone(), |_| {
+            checks_count += 1;
+            false
+        });
+        assert_eq!(reduced, items);
+        assert!(checks_count <= 2 * items.len(), "{checks_count} checks");
+
+        assert!(minimize(Vec::<u32>::new(), |_| true).is_empty());
+    }
+}
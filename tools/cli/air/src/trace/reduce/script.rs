@@ -0,0 +1,147 @@
+/*
+ * Copyright 2023 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use air_parser::ast::{Instruction, Par, Seq, Span, Xor};
+
+use std::rc::Rc;
+
+const NULL: &str = "(null)";
+
+/// A simplification replaces an instruction either with one of its subinstructions or with null.
+enum Replacement {
+    Null,
+    Instruction(Span),
+}
+
+/// Returns scripts each having a single instruction simplified. Outer instructions go first,
+/// since their simplification drops more at once.
+pub(super) fn simplifications(air_script: &str) -> Vec<String> {
+    let instruction = match air_parser::parse(air_script) {
+        Ok(instruction) => instruction,
+        Err(_) => return vec![],
+    };
+
+    let mut replacements = vec![];
+    collect_replacements(&instruction, &mut replacements);
+
+    replacements
+        .into_iter()
+        .map(|(span, replacement)| {
+            let replacement = match replacement {
+                Replacement::Null => NULL,
+                Replacement::Instruction(span) => {
+                    &air_script[usize::from(span.left)..usize::from(span.right)]
+                }
+            };
+            format!(
+                "{}{}{}",
+                &air_script[..usize::from(span.left)],
+                replacement,
+                &air_script[usize::from(span.right)..]
+            )
+        })
+        .collect()
+}
+
+fn collect_replacements(
+    instruction: &Instruction<'_>,
+    replacements: &mut Vec<(Span, Replacement)>,
+) {
+    let instruction = instruction.without_comments();
+    let span = match instruction.span() {
+        Some(span) => span,
+        None => return,
+    };
+
+    // a fold body can't replace the fold, since it uses the iterator
+    let (subinstructions, is_replaceable_by_them) = match instruction {
+        Instruction::Null(_) | Instruction::Error => return,
+        Instruction::Seq(Seq(left, right, _))
+        | Instruction::Par(Par(left, right, _))
+        | Instruction::Xor(Xor(left, right, _)) => (vec![&**left, &**right], true),
+        Instruction::Match(match_) => (vec![&*match_.instruction], true),
+        Instruction::MisMatch(mismatch) => (vec![&*mismatch.instruction], true),
+        Instruction::New(new) => (vec![&*new.instruction], true),
+        Instruction::FoldScalar(fold) => {
+            (fold_body(&fold.instruction, &fold.last_instruction), false)
+        }
+        Instruction::FoldStream(fold) => {
+            (fold_body(&fold.instruction, &fold.last_instruction), false)
+        }
+        Instruction::FoldStreamMap(fold) => {
+            (fold_body(&fold.instruction, &fold.last_instruction), false)
+        }
+        _ => (vec![], false),
+    };
+
+    replacements.push((span, Replacement::Null));
+    if is_replaceable_by_them {
+        let subinstruction_spans = subinstructions
+            .iter()
+            .filter_map(|subinstruction| subinstruction.span());
+        replacements.extend(
+            subinstruction_spans
+                .map(|subinstruction_span| (span, Replacement::Instruction(subinstruction_span))),
+        );
+    }
+
+    for subinstruction in subinstructions {
+        collect_replacements(subinstruction, replacements);
+    }
+}
+
+fn fold_body<'a, 'i>(
+    instruction: &'a Rc<Instruction<'i>>,
+    last_instruction: &'a Option<Rc<Instruction<'i>>>,
+) -> Vec<&'a Instruction<'i>> {
+    std::iter::once(&**instruction)
+        .chain(last_instruction.as_deref())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn instructions_are_replaced_by_null_and_subinstructions() {
+        let simplifications = simplifications("(seq (null) (ap 1 $stream))");
+
+        assert_eq!(
+            simplifications,
+            vec!["(null)", "(null)", "(ap 1 $stream)", "(seq (null) (null))",]
+        );
+    }
+
+    #[test]
+    fn fold_is_not_replaced_by_its_body() {
+        let air_script =
+            r#"(seq (call "peer" ("service" "function") [] iterable) (fold iterable i (next i)))"#;
+        let simplifications = simplifications(air_script);
+
+        assert!(simplifications.contains(
+            &r#"(seq (call "peer" ("service" "function") [] iterable) (null))"#.to_string()
+        ));
+        assert!(simplifications
+            .iter()
+            .all(|air_script| air_script.contains("(fold") || !air_script.contains("(next i)")));
+    }
+
+    #[test]
+    fn invalid_script_has_no_simplifications() {
+        assert!(simplifications("(seq (null)").is_empty());
+    }
+}
@@ -14,14 +14,15 @@
  * limitations under the License.
  */
 
-mod data;
+pub(super) mod data;
 mod native;
-mod runner;
+pub(super) mod runner;
 #[cfg(feature = "wasm")]
 mod wasm;
 
 use self::runner::AirRunner;
 use avm_interface::CallResults;
+use avm_interface::MergeConflict;
 
 use anyhow::Context as _;
 use clap::{Parser, Subcommand};
@@ -87,7 +88,10 @@ pub(crate) fn run(args: Args) -> anyhow::Result<()> {
     };
     let particle = execution_data.particle;
 
-    let call_results = read_call_results(args.call_results_path.as_deref())?;
+    let call_results = match (&args.call_results_path, execution_data.call_results) {
+        (None, Some(call_results)) => call_results,
+        (call_results_path, _) => read_call_results(call_results_path.as_deref())?,
+    };
 
     let repeat = args.repeat.unwrap_or(1);
     for _ in 0..repeat {
@@ -107,14 +111,22 @@ pub(crate) fn run(args: Args) -> anyhow::Result<()> {
             .context("Failed to execute the script")?;
         if args.repeat.is_none() {
             println!("{result:?}");
+            print_merge_conflict(&result.merge_conflict);
         }
     }
 
     Ok(())
 }
 
+/// Renders a conflict report if the execution failed on merging data.
+pub(super) fn print_merge_conflict(merge_conflict: &Option<MergeConflict>) {
+    if let Some(conflict) = merge_conflict {
+        eprintln!("merge conflict:\n{conflict}");
+    }
+}
+
 #[cfg(feature = "wasm")]
-fn get_runner(
+pub(super) fn get_runner(
     native: bool,
     air_interpreter_wasm_path: &Path,
     max_heap_size: Option<u64>,
@@ -128,7 +140,7 @@ fn get_runner(
 }
 
 #[cfg(not(feature = "wasm"))]
-fn get_runner(
+pub(super) fn get_runner(
     _native: bool,
     _air_interpreter_wasm_path: &Path,
     _max_heap_size: Option<u64>,
@@ -151,7 +163,7 @@ pub fn init_tracing(tracing_params: String, trace_mode: u8) {
         builder.init();
     }
 }
-fn read_call_results(call_results_path: Option<&Path>) -> anyhow::Result<CallResults> {
+pub(super) fn read_call_results(call_results_path: Option<&Path>) -> anyhow::Result<CallResults> {
     match call_results_path {
         None => Ok(CallResults::default()),
         Some(call_results_path) => {
@@ -163,7 +175,7 @@ fn read_call_results(call_results_path: Option<&Path>) -> anyhow::Result<CallRes
     }
 }
 
-fn load_data_or_default(
+pub(super) fn load_data_or_default(
     data_path: Option<impl AsRef<Path>>,
     default: &str,
 ) -> anyhow::Result<String> {
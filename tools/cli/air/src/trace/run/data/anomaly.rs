@@ -17,6 +17,7 @@
 use super::super::load_data;
 use super::ExecutionData;
 use avm_data_store::AnomalyData;
+use avm_interface::CallResults;
 use avm_interface::ParticleParameters;
 
 use anyhow::Context;
@@ -42,11 +43,22 @@ pub(crate) fn load(args: &AnomalyDataArgs) -> anyhow::Result<super::ExecutionDat
     let particle: ParticleParameters<'static> =
         serde_json::from_reader(&*anomaly_data.particle.to_vec())
             .context("Anomaly particle is not a valid JSON")?;
+    let call_results = parse_call_results(&anomaly_data.call_results)?;
 
     Ok(ExecutionData {
         air_script,
         prev_data,
         current_data,
         particle,
+        call_results: Some(call_results),
     })
 }
+
+/// Call results are absent in anomalies of executions without them.
+pub(crate) fn parse_call_results(call_results: &[u8]) -> anyhow::Result<CallResults> {
+    if call_results.is_empty() {
+        return Ok(CallResults::default());
+    }
+
+    serde_json::from_slice(call_results).context("Anomaly call_results is not a valid JSON")
+}
@@ -17,6 +17,7 @@
 pub(crate) mod anomaly;
 pub(crate) mod plain;
 
+use avm_interface::CallResults;
 use avm_interface::ParticleParameters;
 
 pub(crate) struct ExecutionData<'ctx> {
@@ -24,4 +25,6 @@ pub(crate) struct ExecutionData<'ctx> {
     pub(crate) current_data: String,
     pub(crate) prev_data: String,
     pub(crate) particle: ParticleParameters<'ctx>,
+    /// Call results bundled with the data, `--call-results` takes precedence over them.
+    pub(crate) call_results: Option<CallResults>,
 }
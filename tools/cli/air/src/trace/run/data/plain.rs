@@ -71,6 +71,7 @@ pub(crate) fn load(args: &PlainDataArgs) -> anyhow::Result<ExecutionData<'_>> {
         prev_data,
         current_data,
         particle,
+        call_results: None,
     })
 }
 
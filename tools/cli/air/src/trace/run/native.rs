@@ -50,6 +50,11 @@ impl AirRunner for NativeAvmRunner {
                 current_peer_id,
                 timestamp,
                 ttl,
+                require_signatures: false,
+                instruction_limit: 0,
+                // replayed particles shouldn't be refused as expired
+                current_time: 0,
+                collect_stats: false,
             },
             raw_call_results,
         );
@@ -33,3 +33,13 @@ pub(crate) trait AirRunner {
         tracing_output_mode: u8,
     ) -> anyhow::Result<RawAVMOutcome>;
 }
+
+/// Marks runner errors caused by the interpreter crash, e.g. a wasm trap.
+#[derive(Debug)]
+pub(crate) struct InterpreterTrap;
+
+impl std::fmt::Display for InterpreterTrap {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "the interpreter has trapped")
+    }
+}
@@ -14,7 +14,9 @@
  * limitations under the License.
  */
 use super::runner::AirRunner;
+use super::runner::InterpreterTrap;
 use air_test_utils::avm_runner::AVMRunner;
+use air_test_utils::RunnerError;
 use std::path::Path;
 
 pub(crate) struct WasmAvmRunner(AVMRunner);
@@ -33,18 +35,27 @@ impl AirRunner for WasmAvmRunner {
         tracing_params: String,
         tracing_output_mode: u8,
     ) -> anyhow::Result<avm_interface::raw_outcome::RawAVMOutcome> {
-        Ok(self.0.call_tracing(
+        let outcome = self.0.call_tracing(
             air,
             prev_data,
             data,
             init_peer_id,
             timestamp,
             ttl,
+            // replayed particles shouldn't be refused as expired
+            0,
             current_peer_id,
+            None,
             call_results,
             tracing_params,
             tracing_output_mode,
-        )?)
+        );
+
+        outcome.map_err(|error| match error {
+            // Marine fails a call if the interpreter module traps
+            RunnerError::MarineError(_) => anyhow::Error::new(error).context(InterpreterTrap),
+            error => error.into(),
+        })
     }
 }
 
@@ -56,5 +67,7 @@ pub(crate) fn create_wasm_avm_runner(
         air_interpreter_wasm_path.to_owned(),
         max_heap_size,
         0,
+        None,
+        false,
     )?)))
 }
@@ -0,0 +1,322 @@
+/*
+ * Copyright 2023 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use air_parser::ast::*;
+use air_parser::AIRLexer;
+use air_parser::AirPos;
+use air_parser::ParserDiagnostic;
+use air_parser::Token;
+
+use std::collections::HashMap;
+use std::ops::Range;
+use std::rc::Rc;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum VariableKind {
+    Scalar,
+    Stream,
+    CanonStream,
+    StreamMap,
+}
+
+/// A place in a script where a variable is mentioned.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Occurrence {
+    pub(crate) name: String,
+    pub(crate) kind: VariableKind,
+    pub(crate) span: Range<usize>,
+
+    /// Name of an instruction that defines or writes to a variable at this place.
+    pub(crate) defined_by: Option<&'static str>,
+
+    /// Span of the innermost fold or new that introduces this name, occurrences with the same
+    /// name and scope refer to the same variable, None stands for the whole script.
+    scope: Option<Span>,
+}
+
+/// Everything that is known about variables of one script.
+pub(crate) struct Analysis {
+    pub(crate) diagnostics: Vec<ParserDiagnostic>,
+    occurrences: Vec<Occurrence>,
+}
+
+impl Analysis {
+    pub(crate) fn new(air_script: &str) -> Self {
+        let (instruction, diagnostics) = air_parser::parse_with_diagnostics(air_script);
+
+        let mut collector = DefinitionCollector::default();
+        if let Some(instruction) = &instruction {
+            collector.visit(instruction);
+        }
+
+        // the lexer reports variables together with their positions even if the parser failed,
+        // so references are available for a partially written script as well
+        let occurrences = AIRLexer::new(air_script)
+            .filter_map(Result::ok)
+            .filter_map(|(start, token, _)| {
+                let (name, kind) = variable_name(&token)?;
+                let start = usize::from(start);
+
+                Some(Occurrence {
+                    name: name.to_string(),
+                    kind,
+                    span: start..start + name.len(),
+                    defined_by: collector.definitions.get(&start).copied(),
+                    scope: collector.scope_of(name, start.into()),
+                })
+            })
+            .collect();
+
+        Self {
+            diagnostics,
+            occurrences,
+        }
+    }
+
+    pub(crate) fn occurrence_at(&self, offset: usize) -> Option<&Occurrence> {
+        self.occurrences
+            .iter()
+            .find(|occurrence| occurrence.span.start <= offset && offset <= occurrence.span.end)
+    }
+
+    /// Returns all occurrences of the same variable including the supplied one.
+    pub(crate) fn references<'analysis>(
+        &'analysis self,
+        occurrence: &'analysis Occurrence,
+    ) -> impl Iterator<Item = &'analysis Occurrence> {
+        self.occurrences
+            .iter()
+            .filter(move |other| other.name == occurrence.name && other.scope == occurrence.scope)
+    }
+
+    /// Returns all places where the variable is defined, streams usually have several of them.
+    pub(crate) fn definitions<'analysis>(
+        &'analysis self,
+        occurrence: &'analysis Occurrence,
+    ) -> impl Iterator<Item = &'analysis Occurrence> {
+        self.references(occurrence)
+            .filter(|other| other.defined_by.is_some())
+    }
+
+    pub(crate) fn describe_kind(&self, occurrence: &Occurrence) -> &'static str {
+        let is_iterator = self
+            .definitions(occurrence)
+            .any(|definition| definition.defined_by == Some(FOLD));
+
+        match occurrence.kind {
+            VariableKind::Scalar if is_iterator => "fold iterator",
+            VariableKind::Scalar => "scalar",
+            VariableKind::Stream => "stream",
+            VariableKind::CanonStream => "canon stream",
+            VariableKind::StreamMap => "stream map",
+        }
+    }
+}
+
+const CALL: &str = "call";
+const AP: &str = "ap";
+const CANON: &str = "canon";
+const FOLD: &str = "fold";
+const NEW: &str = "new";
+
+pub(crate) fn variable_name<'i>(token: &Token<'i>) -> Option<(&'i str, VariableKind)> {
+    match *token {
+        Token::Scalar { name, .. } | Token::ScalarWithLambda { name, .. } => {
+            Some((name, VariableKind::Scalar))
+        }
+        Token::Stream { name, .. } | Token::StreamWithLambda { name, .. } => {
+            Some((name, VariableKind::Stream))
+        }
+        Token::CanonStream { name, .. } | Token::CanonStreamWithLambda { name, .. } => {
+            Some((name, VariableKind::CanonStream))
+        }
+        Token::StreamMap { name, .. } => Some((name, VariableKind::StreamMap)),
+        _ => None,
+    }
+}
+
+/// Collects definition sites and scopes introduced by fold and new from AST.
+#[derive(Default)]
+struct DefinitionCollector<'i> {
+    definitions: HashMap<usize, &'static str>,
+    scopes: Vec<(&'i str, Span)>,
+}
+
+impl<'i> DefinitionCollector<'i> {
+    fn visit(&mut self, instruction: &Instruction<'i>) {
+        match instruction {
+            Instruction::Call(call) => match &call.output {
+                CallOutputValue::Scalar(scalar) => self.met_definition(scalar.position, CALL),
+                CallOutputValue::Stream(stream) => self.met_definition(stream.position, CALL),
+                CallOutputValue::None => {}
+            },
+            Instruction::Ap(ap) => match &ap.result {
+                ApResult::Scalar(scalar) => self.met_definition(scalar.position, AP),
+                ApResult::Stream(stream) => self.met_definition(stream.position, AP),
+            },
+            Instruction::ApMap(ap_map) => self.met_definition(ap_map.map.position, AP),
+            Instruction::Canon(canon) => self.met_definition(canon.canon_stream.position, CANON),
+            Instruction::CanonMap(canon_map) => {
+                self.met_definition(canon_map.scalar.position, CANON)
+            }
+            Instruction::Seq(Seq(left, right, _))
+            | Instruction::Par(Par(left, right, _))
+            | Instruction::Xor(Xor(left, right, _)) => {
+                self.visit(left);
+                self.visit(right);
+            }
+            Instruction::Match(match_) => self.visit(&match_.instruction),
+            Instruction::MisMatch(mismatch) => self.visit(&mismatch.instruction),
+            Instruction::FoldScalar(fold) => self.met_fold(
+                &fold.iterator,
+                fold.span,
+                &fold.instruction,
+                &fold.last_instruction,
+            ),
+            Instruction::FoldStream(fold) => self.met_fold(
+                &fold.iterator,
+                fold.span,
+                &fold.instruction,
+                &fold.last_instruction,
+            ),
+            Instruction::FoldStreamMap(fold) => self.met_fold(
+                &fold.iterator,
+                fold.span,
+                &fold.instruction,
+                &fold.last_instruction,
+            ),
+            Instruction::Commented(commented) => self.visit(&commented.instruction),
+            Instruction::New(new) => {
+                let (name, position) = match &new.argument {
+                    NewArgument::Scalar(scalar) => (scalar.name, scalar.position),
+                    NewArgument::Stream(stream) => (stream.name, stream.position),
+                    NewArgument::CanonStream(canon_stream) => {
+                        (canon_stream.name, canon_stream.position)
+                    }
+                    NewArgument::StreamMap(stream_map) => (stream_map.name, stream_map.position),
+                };
+                self.met_definition(position, NEW);
+                self.scopes.push((name, new.span));
+                self.visit(&new.instruction);
+            }
+            Instruction::Fail(_)
+            | Instruction::Never(_)
+            | Instruction::Next(_)
+            | Instruction::Null(_)
+            | Instruction::Error => {}
+        }
+    }
+
+    fn met_definition(&mut self, position: AirPos, instruction_name: &'static str) {
+        self.definitions.insert(position.into(), instruction_name);
+    }
+
+    fn met_fold(
+        &mut self,
+        iterator: &Scalar<'i>,
+        span: Span,
+        instruction: &Rc<Instruction<'i>>,
+        last_instruction: &Option<Rc<Instruction<'i>>>,
+    ) {
+        self.met_definition(iterator.position, FOLD);
+        self.scopes.push((iterator.name, span));
+        self.visit(instruction);
+        if let Some(last_instruction) = last_instruction {
+            self.visit(last_instruction);
+        }
+    }
+
+    fn scope_of(&self, name: &str, position: AirPos) -> Option<Span> {
+        self.scopes
+            .iter()
+            .filter(|(scope_name, span)| *scope_name == name && span.contains_position(position))
+            .map(|(_, span)| *span)
+            .min_by_key(|span| span.right - span.left)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn spans<'a>(occurrences: impl Iterator<Item = &'a Occurrence>) -> Vec<Range<usize>> {
+        occurrences
+            .map(|occurrence| occurrence.span.clone())
+            .collect()
+    }
+
+    #[test]
+    fn stream_has_all_writes_as_definitions() {
+        let script = r#"
+            (seq
+                (call "peer" ("" "") [] $stream)
+                (seq
+                    (ap 1 $stream)
+                    (canon "peer" $stream #canon)))
+            "#;
+
+        let analysis = Analysis::new(script);
+        assert!(analysis.diagnostics.is_empty());
+
+        let last_use = script.rfind("$stream").unwrap();
+        let occurrence = analysis.occurrence_at(last_use).unwrap();
+        assert_eq!(occurrence.kind, VariableKind::Stream);
+        assert_eq!(occurrence.defined_by, None);
+
+        let writes = script.match_indices("$stream").take(2);
+        let expected = writes
+            .map(|(start, name)| start..start + name.len())
+            .collect::<Vec<_>>();
+        assert_eq!(spans(analysis.definitions(occurrence)), expected);
+        assert_eq!(analysis.references(occurrence).count(), 3);
+    }
+
+    #[test]
+    fn fold_iterators_are_scoped() {
+        let script = r#"
+            (seq
+                (fold #canon i
+                    (seq
+                        (call "peer" ("" "") [i])
+                        (next i)))
+                (fold #canon i
+                    (next i)))
+            "#;
+
+        let analysis = Analysis::new(script);
+        let first_use = script.find("[i]").unwrap() + 1;
+        let occurrence = analysis.occurrence_at(first_use).unwrap();
+        assert_eq!(analysis.describe_kind(occurrence), "fold iterator");
+
+        let first_iterator = script.find(" i\n").unwrap() + 1;
+        assert_eq!(
+            spans(analysis.definitions(occurrence)),
+            vec![first_iterator..first_iterator + 1]
+        );
+        assert_eq!(analysis.references(occurrence).count(), 3);
+    }
+
+    #[test]
+    fn occurrences_are_found_in_invalid_script() {
+        let script = r#"(seq (call "peer" ("" "") [] $stream) (ap $stream"#;
+
+        let analysis = Analysis::new(script);
+        assert!(!analysis.diagnostics.is_empty());
+
+        let occurrence = analysis.occurrence_at(script.len() - 1).unwrap();
+        assert_eq!(analysis.references(occurrence).count(), 2);
+    }
+}
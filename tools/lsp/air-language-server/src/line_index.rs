@@ -0,0 +1,88 @@
+/*
+ * Copyright 2023 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use lsp_types::Position;
+use lsp_types::Range;
+
+/// Converts byte offsets used by the parser to LSP positions and back.
+///
+/// LSP counts characters in UTF-16 code units, so a line prefix is re-encoded on each conversion.
+pub(crate) struct LineIndex<'text> {
+    text: &'text str,
+    line_starts: Vec<usize>,
+}
+
+impl<'text> LineIndex<'text> {
+    pub(crate) fn new(text: &'text str) -> Self {
+        let line_starts = std::iter::once(0)
+            .chain(text.match_indices('\n').map(|(offset, _)| offset + 1))
+            .collect();
+
+        Self { text, line_starts }
+    }
+
+    pub(crate) fn position(&self, offset: usize) -> Position {
+        let offset = offset.min(self.text.len());
+        let line = match self.line_starts.binary_search(&offset) {
+            Ok(line) => line,
+            Err(next_line) => next_line - 1,
+        };
+
+        let line_start = self.line_starts[line];
+        let character = self.text[line_start..offset].encode_utf16().count();
+        Position::new(line as u32, character as u32)
+    }
+
+    pub(crate) fn range(&self, span: std::ops::Range<usize>) -> Range {
+        Range::new(self.position(span.start), self.position(span.end))
+    }
+
+    pub(crate) fn offset(&self, position: Position) -> usize {
+        let line_start = match self.line_starts.get(position.line as usize) {
+            Some(&line_start) => line_start,
+            None => return self.text.len(),
+        };
+
+        let mut utf16_count = 0;
+        for (offset, ch) in self.text[line_start..].char_indices() {
+            if utf16_count >= position.character as usize || ch == '\n' {
+                return line_start + offset;
+            }
+            utf16_count += ch.len_utf16();
+        }
+
+        self.text.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn offsets_round_trip() {
+        let text = "(seq\n  (null) ; ☃\n  (null))";
+        let index = LineIndex::new(text);
+
+        let offset = text.find("(null))").unwrap();
+        let position = index.position(offset);
+        assert_eq!(position, Position::new(2, 2));
+        assert_eq!(index.offset(position), offset);
+
+        let snowman_end = text.find('☃').unwrap() + '☃'.len_utf8();
+        assert_eq!(index.position(snowman_end), Position::new(1, 12));
+    }
+}
@@ -0,0 +1,75 @@
+/*
+ * Copyright 2023 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+#![forbid(unsafe_code)]
+#![warn(rust_2018_idioms)]
+#![deny(
+    dead_code,
+    nonstandard_style,
+    unused_imports,
+    unused_mut,
+    unused_variables,
+    unused_unsafe,
+    unreachable_patterns
+)]
+
+mod analysis;
+mod line_index;
+mod semantic_tokens;
+mod server;
+
+use lsp_server::Connection;
+use lsp_types::HoverProviderCapability;
+use lsp_types::OneOf;
+use lsp_types::SemanticTokensFullOptions;
+use lsp_types::SemanticTokensLegend;
+use lsp_types::SemanticTokensOptions;
+use lsp_types::ServerCapabilities;
+use lsp_types::TextDocumentSyncCapability;
+use lsp_types::TextDocumentSyncKind;
+
+fn main() -> anyhow::Result<()> {
+    // stdout is used by the protocol, so the server communicates only through stdio
+    let (connection, io_threads) = Connection::stdio();
+
+    let capabilities = serde_json::to_value(server_capabilities())?;
+    connection.initialize(capabilities)?;
+
+    server::Server::new(connection).run()?;
+    io_threads.join()?;
+
+    Ok(())
+}
+
+fn server_capabilities() -> ServerCapabilities {
+    let semantic_tokens = SemanticTokensOptions {
+        legend: SemanticTokensLegend {
+            token_types: semantic_tokens::TOKEN_TYPES.to_vec(),
+            token_modifiers: vec![],
+        },
+        full: Some(SemanticTokensFullOptions::Bool(true)),
+        ..<_>::default()
+    };
+
+    ServerCapabilities {
+        text_document_sync: Some(TextDocumentSyncCapability::Kind(TextDocumentSyncKind::FULL)),
+        hover_provider: Some(HoverProviderCapability::Simple(true)),
+        definition_provider: Some(OneOf::Left(true)),
+        references_provider: Some(OneOf::Left(true)),
+        semantic_tokens_provider: Some(semantic_tokens.into()),
+        ..<_>::default()
+    }
+}
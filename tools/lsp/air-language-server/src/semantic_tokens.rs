@@ -0,0 +1,124 @@
+/*
+ * Copyright 2023 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use crate::analysis::variable_name;
+use crate::line_index::LineIndex;
+
+use air_parser::AIRLexer;
+use air_parser::Token;
+use lsp_types::SemanticToken;
+use lsp_types::SemanticTokenType;
+
+use std::ops::Range;
+
+/// Token types in the order they are announced in the server legend.
+pub(crate) const TOKEN_TYPES: &[SemanticTokenType] = &[
+    SemanticTokenType::KEYWORD,
+    SemanticTokenType::VARIABLE,
+    SemanticTokenType::PROPERTY,
+    SemanticTokenType::STRING,
+    SemanticTokenType::NUMBER,
+    SemanticTokenType::MACRO,
+    SemanticTokenType::COMMENT,
+];
+
+const KEYWORD: u32 = 0;
+const VARIABLE: u32 = 1;
+const PROPERTY: u32 = 2;
+const STRING: u32 = 3;
+const NUMBER: u32 = 4;
+const MACRO: u32 = 5;
+const COMMENT: u32 = 6;
+
+/// Classifies lexer tokens, lambdas applied to variables are reported as properties.
+pub(crate) fn semantic_tokens(air_script: &str, line_index: &LineIndex<'_>) -> Vec<SemanticToken> {
+    let mut previous_line = 0;
+    let mut previous_start = 0;
+    let mut tokens = Vec::new();
+
+    let classified = AIRLexer::new(air_script)
+        .filter_map(Result::ok)
+        .flat_map(|(start, token, end)| classify(&token, start.into()..end.into()));
+
+    for (span, token_type) in classified {
+        let start = line_index.position(span.start);
+        let end = line_index.position(span.end);
+        // multi-line tokens aren't supported by all clients
+        if start.line != end.line || start == end {
+            continue;
+        }
+
+        let delta_line = start.line - previous_line;
+        let delta_start = if delta_line == 0 {
+            start.character - previous_start
+        } else {
+            start.character
+        };
+
+        tokens.push(SemanticToken {
+            delta_line,
+            delta_start,
+            length: end.character - start.character,
+            token_type,
+            token_modifiers_bitset: 0,
+        });
+
+        previous_line = start.line;
+        previous_start = start.character;
+    }
+
+    tokens
+}
+
+fn classify(token: &Token<'_>, span: Range<usize>) -> Vec<(Range<usize>, u32)> {
+    if let Some((name, _)) = variable_name(token) {
+        let name_end = span.start + name.len();
+        let mut classified = vec![(span.start..name_end, VARIABLE)];
+        if name_end < span.end {
+            classified.push((name_end..span.end, PROPERTY));
+        }
+        return classified;
+    }
+
+    let token_type = match token {
+        Token::Call
+        | Token::Canon
+        | Token::Ap
+        | Token::Seq
+        | Token::Par
+        | Token::Fail
+        | Token::Fold
+        | Token::Xor
+        | Token::Never
+        | Token::New
+        | Token::Next
+        | Token::Null
+        | Token::Match
+        | Token::MisMatch
+        | Token::Boolean(_) => KEYWORD,
+        Token::StringLiteral(_) => STRING,
+        Token::I64(_) | Token::F64(_) => NUMBER,
+        Token::InitPeerId
+        | Token::LastError
+        | Token::LastErrorWithLambda(_)
+        | Token::Timestamp
+        | Token::TTL => MACRO,
+        Token::Comment(_) => COMMENT,
+        _ => return vec![],
+    };
+
+    vec![(span, token_type)]
+}
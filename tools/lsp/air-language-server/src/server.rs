@@ -0,0 +1,253 @@
+/*
+ * Copyright 2023 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use crate::analysis::Analysis;
+use crate::line_index::LineIndex;
+use crate::semantic_tokens::semantic_tokens;
+
+use anyhow::anyhow;
+use anyhow::Result;
+use lsp_server::Connection;
+use lsp_server::ErrorCode;
+use lsp_server::Message;
+use lsp_server::Response;
+use lsp_types::notification::DidChangeTextDocument;
+use lsp_types::notification::DidCloseTextDocument;
+use lsp_types::notification::DidOpenTextDocument;
+use lsp_types::notification::Notification;
+use lsp_types::notification::PublishDiagnostics;
+use lsp_types::request::GotoDefinition;
+use lsp_types::request::HoverRequest;
+use lsp_types::request::References;
+use lsp_types::request::Request;
+use lsp_types::request::SemanticTokensFullRequest;
+use lsp_types::*;
+
+use std::collections::HashMap;
+
+/// An opened document together with its analysis, it's recomputed on every change,
+/// since AIR scripts are small enough to be parsed from scratch.
+struct Document {
+    text: String,
+    analysis: Analysis,
+}
+
+pub(crate) struct Server {
+    connection: Connection,
+    documents: HashMap<Url, Document>,
+}
+
+impl Server {
+    pub(crate) fn new(connection: Connection) -> Self {
+        Self {
+            connection,
+            documents: HashMap::new(),
+        }
+    }
+
+    pub(crate) fn run(mut self) -> Result<()> {
+        let receiver = self.connection.receiver.clone();
+
+        for message in &receiver {
+            match message {
+                Message::Request(request) => {
+                    if self.connection.handle_shutdown(&request)? {
+                        return Ok(());
+                    }
+                    self.on_request(request)?;
+                }
+                Message::Notification(notification) => self.on_notification(notification)?,
+                Message::Response(_) => {}
+            }
+        }
+
+        Ok(())
+    }
+
+    fn on_request(&self, request: lsp_server::Request) -> Result<()> {
+        let response = match request.method.as_str() {
+            HoverRequest::METHOD => self.handle::<HoverRequest>(request, Self::hover)?,
+            GotoDefinition::METHOD => self.handle::<GotoDefinition>(request, Self::definition)?,
+            References::METHOD => self.handle::<References>(request, Self::references)?,
+            SemanticTokensFullRequest::METHOD => {
+                self.handle::<SemanticTokensFullRequest>(request, Self::semantic_tokens)?
+            }
+            method => Response::new_err(
+                request.id,
+                ErrorCode::MethodNotFound as i32,
+                format!("unsupported request {method}"),
+            ),
+        };
+
+        self.connection.sender.send(Message::Response(response))?;
+        Ok(())
+    }
+
+    fn handle<R: Request>(
+        &self,
+        request: lsp_server::Request,
+        handler: fn(&Self, R::Params) -> R::Result,
+    ) -> Result<Response> {
+        let (id, params) = request
+            .extract::<R::Params>(R::METHOD)
+            .map_err(|error| anyhow!("invalid {} request: {error:?}", R::METHOD))?;
+
+        Ok(Response::new_ok(id, handler(self, params)))
+    }
+
+    fn on_notification(&mut self, notification: lsp_server::Notification) -> Result<()> {
+        match notification.method.as_str() {
+            DidOpenTextDocument::METHOD => {
+                let params = extract_notification::<DidOpenTextDocument>(notification)?;
+                let document = params.text_document;
+                self.update_document(document.uri, document.text, document.version)
+            }
+            DidChangeTextDocument::METHOD => {
+                let params = extract_notification::<DidChangeTextDocument>(notification)?;
+                // the server asks for full document sync, so the last change contains the whole text
+                match params.content_changes.into_iter().last() {
+                    Some(change) => self.update_document(
+                        params.text_document.uri,
+                        change.text,
+                        params.text_document.version,
+                    ),
+                    None => Ok(()),
+                }
+            }
+            DidCloseTextDocument::METHOD => {
+                let params = extract_notification::<DidCloseTextDocument>(notification)?;
+                let uri = params.text_document.uri;
+                self.documents.remove(&uri);
+                self.publish_diagnostics(uri, vec![], None)
+            }
+            _ => Ok(()),
+        }
+    }
+
+    fn update_document(&mut self, uri: Url, text: String, version: i32) -> Result<()> {
+        let analysis = Analysis::new(&text);
+        let line_index = LineIndex::new(&text);
+        let diagnostics = analysis
+            .diagnostics
+            .iter()
+            .map(|diagnostic| Diagnostic {
+                range: line_index.range(diagnostic.span.left.into()..diagnostic.span.right.into()),
+                severity: Some(DiagnosticSeverity::ERROR),
+                source: Some("air".to_string()),
+                message: diagnostic.message.clone(),
+                ..<_>::default()
+            })
+            .collect();
+
+        self.documents
+            .insert(uri.clone(), Document { text, analysis });
+        self.publish_diagnostics(uri, diagnostics, Some(version))
+    }
+
+    fn publish_diagnostics(
+        &self,
+        uri: Url,
+        diagnostics: Vec<Diagnostic>,
+        version: Option<i32>,
+    ) -> Result<()> {
+        let params = PublishDiagnosticsParams::new(uri, diagnostics, version);
+        let notification =
+            lsp_server::Notification::new(PublishDiagnostics::METHOD.to_string(), params);
+        self.connection
+            .sender
+            .send(Message::Notification(notification))?;
+        Ok(())
+    }
+
+    fn hover(&self, params: HoverParams) -> Option<Hover> {
+        let position = params.text_document_position_params;
+        let document = self.documents.get(&position.text_document.uri)?;
+        let line_index = LineIndex::new(&document.text);
+        let analysis = &document.analysis;
+
+        let occurrence = analysis.occurrence_at(line_index.offset(position.position))?;
+        let mut value = format!(
+            "{} `{}`",
+            analysis.describe_kind(occurrence),
+            occurrence.name
+        );
+        for definition in analysis.definitions(occurrence) {
+            let line = line_index.position(definition.span.start).line + 1;
+            let instruction = definition.defined_by.unwrap_or_default();
+            value.push_str(&format!("\n\n* defined by `{instruction}` on line {line}"));
+        }
+
+        Some(Hover {
+            contents: HoverContents::Markup(MarkupContent {
+                kind: MarkupKind::Markdown,
+                value,
+            }),
+            range: Some(line_index.range(occurrence.span.clone())),
+        })
+    }
+
+    fn definition(&self, params: GotoDefinitionParams) -> Option<GotoDefinitionResponse> {
+        let position = params.text_document_position_params;
+        let uri = position.text_document.uri;
+        let document = self.documents.get(&uri)?;
+        let line_index = LineIndex::new(&document.text);
+        let analysis = &document.analysis;
+
+        let occurrence = analysis.occurrence_at(line_index.offset(position.position))?;
+        let locations = analysis
+            .definitions(occurrence)
+            .map(|definition| Location::new(uri.clone(), line_index.range(definition.span.clone())))
+            .collect();
+
+        Some(GotoDefinitionResponse::Array(locations))
+    }
+
+    fn references(&self, params: ReferenceParams) -> Option<Vec<Location>> {
+        let position = params.text_document_position;
+        let uri = position.text_document.uri;
+        let document = self.documents.get(&uri)?;
+        let line_index = LineIndex::new(&document.text);
+        let analysis = &document.analysis;
+
+        let occurrence = analysis.occurrence_at(line_index.offset(position.position))?;
+        let include_declaration = params.context.include_declaration;
+        let locations = analysis
+            .references(occurrence)
+            .filter(|reference| include_declaration || reference.defined_by.is_none())
+            .map(|reference| Location::new(uri.clone(), line_index.range(reference.span.clone())))
+            .collect();
+
+        Some(locations)
+    }
+
+    fn semantic_tokens(&self, params: SemanticTokensParams) -> Option<SemanticTokensResult> {
+        let document = self.documents.get(&params.text_document.uri)?;
+        let line_index = LineIndex::new(&document.text);
+
+        Some(SemanticTokensResult::Tokens(SemanticTokens {
+            result_id: None,
+            data: semantic_tokens(&document.text, &line_index),
+        }))
+    }
+}
+
+fn extract_notification<N: Notification>(
+    notification: lsp_server::Notification,
+) -> Result<N::Params> {
+    notification
+        .extract::<N::Params>(N::METHOD)
+        .map_err(|error| anyhow!("invalid {} notification: {error:?}", N::METHOD))
+}